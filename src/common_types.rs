@@ -78,6 +78,34 @@ impl Dimension {
         }
         return None;
     }
+
+    /// Dimensions of a space separated sequence of references (sqref), single cells as 1x1 dimensions.
+    ///
+    /// Ex: `B2:B10 D4`
+    pub(crate) fn from_sqref(sqref: &str) -> Vec<Self> {
+        return sqref
+            .split_whitespace()
+            .filter_map(|reference| {
+                let reference = reference.replace('$', "");
+                if reference.contains(':') {
+                    return Self::from_a1(reference.as_bytes());
+                }
+                let coordinate = Coordinate::from_a1(reference.as_bytes())?;
+                Some(Self {
+                    start: coordinate,
+                    end: coordinate,
+                })
+            })
+            .collect();
+    }
+
+    /// Whether the coordinate is within the dimension
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        return self.start.row <= coordinate.row
+            && coordinate.row <= self.end.row
+            && self.start.col <= coordinate.col
+            && coordinate.col <= self.end.col;
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
@@ -108,3 +136,25 @@ impl XlsxDatetime {
         return None;
     }
 }
+
+/// Formats as `YYYY-MM-DDThh:mm:ss`, followed by the offset (RFC 3339) if any.
+impl std::fmt::Display for XlsxDatetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.offset {
+            Some(offset) => write!(
+                f,
+                "{}",
+                DateTime::<FixedOffset>::from_naive_utc_and_offset(self.datetime, offset)
+                    .format("%Y-%m-%dT%H:%M:%S%:z")
+            ),
+            None => write!(f, "{}", self.datetime.format("%Y-%m-%dT%H:%M:%S")),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for XlsxDatetime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
@@ -1,4 +1,3 @@
-#[cfg(feature = "drawing")]
 use std::collections::BTreeMap;
 
 use anyhow::{bail, Context};
@@ -12,40 +11,118 @@ use std::{
 use zip::{read::ZipFile, ZipArchive};
 
 #[cfg(feature = "drawing")]
-use crate::packaging::relationship::load_drawing_relationships;
-
-#[cfg(feature = "drawing")]
-use crate::raw::drawing::worksheet_drawing::XlsxWorksheetDrawing;
+use crate::{
+    common_types::Coordinate,
+    processed::{
+        drawing::{chart::data_reference::ChartDataReference, vml::VmlDrawing},
+        spreadsheet::sheet::worksheet::cell::Cell,
+    },
+    raw::{
+        drawing::{
+            chart::XlsxChartSpace,
+            diagram::{
+                data_model::XlsxDiagramDataModel, definition::XlsxDiagramDefinition,
+                drawing::XlsxDiagramDrawing, XlsxDiagrams,
+            },
+            ink::XlsxInk,
+            vml::XlsxVmlDrawing,
+            worksheet_drawing::XlsxWorksheetDrawing,
+        },
+        spreadsheet::sheet::drawing::XlsxDrawing,
+    },
+};
 
 use crate::{
-    packaging::relationship::{
-        load_sheet_relationships, load_workbook_relationships, zip_path_for_id, zip_path_for_type,
-        XlsxRelationships,
+    packaging::{
+        content_types::XlsxContentTypes,
+        part::{is_relationships_path, PackagePart},
+        relationship::{
+            first_zip_path_for_type, load_part_relationships, rel_for_id, resolve_target_path,
+            zip_path_for_id, zip_path_for_type, XlsxRelationships,
+        },
     },
+    processed::document_properties::DocumentProperties,
     processed::spreadsheet::{
-        sheet::worksheet::{calculation_reference::CalculationReferenceMode, Worksheet},
+        calculation_chain::CalculationChain,
+        connection::{query_table::QueryTable, Connection},
+        custom_view::{custom_sheet_view::CustomSheetView, CustomView},
+        external_link::ExternalLink,
+        pivot_cache::{cache_field::PivotCacheField, PivotCache},
+        sheet::{
+            chartsheet::Chartsheet,
+            sheet_properties::SheetProperties,
+            worksheet::{
+                calculation_reference::CalculationReferenceMode,
+                ole_object::{OleObject, OleObjectBytes},
+                pivot_table::{layout::PivotLayout, PivotTable},
+                slicer::Slicer,
+                timeline::Timeline,
+                Worksheet,
+            },
+        },
         sheet_basic_info::{SheetBasicInfo, SheetType},
+        vba_project::VbaProject,
+        xml_map::XmlMap,
     },
     raw::{
-        drawing::theme::XlsxTheme,
+        document_properties::{
+            core_properties::XlsxCoreProperties,
+            custom_properties::{load_custom_properties, XlsxCustomProperties},
+            extended_properties::XlsxExtendedProperties,
+        },
+        drawing::{scheme::color_scheme::XlsxColorScheme, theme::XlsxTheme},
+        extension_list::{
+            handler::{ExtensionHandler, ExtensionHandlers},
+            XlsxExtension,
+        },
         spreadsheet::{
+            active_x::XlsxActiveXControl,
+            calculation_chain::{load_calculation_chain, XlsxCalculationChain},
+            connection::{load_connections, XlsxConnections},
+            external_link::XlsxExternalLink,
+            form_control_properties::XlsxFormControlProperties,
+            pivot_cache::{
+                pivot_cache_records::{load_pivot_cache_records, XlsxPivotCacheRecords},
+                XlsxPivotCacheDefinition,
+            },
+            pivot_table::XlsxPivotTableDefinition,
+            query_table::XlsxQueryTable,
             shared_string::shared_string_table::XlsxSharedStringTable,
-            sheet::worksheet::XlsxWorksheet, stylesheet::XlsxStyleSheet, table::XlsxTable,
+            single_xml_cell::{load_single_xml_cells, XlsxSingleXmlCell, XlsxSingleXmlCells},
+            sheet::{
+                chartsheet::XlsxChartsheet, sheet_properties::XlsxSheetProperties,
+                worksheet::{
+                    sparkline::{XlsxSparklineGroups, SPARKLINE_GROUPS_EXTENSION_URI},
+                    XlsxWorksheet,
+                },
+            },
+            slicer::{load_slicers, slicer_cache::XlsxSlicerCacheDefinition, XlsxSlicer},
+            stylesheet::XlsxStyleSheet,
+            table::XlsxTable,
+            timeline::{load_timelines, timeline_cache::XlsxTimelineCacheDefinition, XlsxTimeline},
+            vba_project::XlsxVbaProject,
             workbook::XlsxWorkbook,
+            xml_map::XlsxMapInfo,
         },
     },
 };
 
-pub(crate) type XmlReader<'a, R> = Reader<BufReader<ZipFile<'a, R>>>;
+/// Reader of an xml part within the zip archive
+pub type XmlReader<'a, R> = Reader<BufReader<ZipFile<'a, R>>>;
 
 /// A struct representing xml zipped excel file
 pub struct Excel<RS> {
     zip: ZipArchive<RS>,
+    workbook_path: String,
     workbook_relationships: XlsxRelationships,
     stylesheet: Option<Box<XlsxStyleSheet>>,
     theme: Option<Box<XlsxTheme>>,
     shared_strings: Option<Box<XlsxSharedStringTable>>,
     workbook: Option<Box<XlsxWorkbook>>,
+    sheets: Option<Vec<SheetBasicInfo>>,
+    slicer_caches: Option<Vec<XlsxSlicerCacheDefinition>>,
+    timeline_caches: Option<Vec<XlsxTimelineCacheDefinition>>,
+    extension_handlers: ExtensionHandlers,
 }
 
 // initialization
@@ -59,14 +136,26 @@ impl Excel<BufReader<File>> {
 impl<RS: Read + Seek> Excel<RS> {
     pub fn from_reader(reader: RS) -> anyhow::Result<Excel<RS>> {
         let mut zip = ZipArchive::new(reader)?;
-        let relationships = load_workbook_relationships(&mut zip)?;
+        // the workbook is the officeDocument part of the package
+        let package_rels = load_part_relationships(&mut zip, "").unwrap_or(vec![]);
+        let workbook_path = first_zip_path_for_type(&package_rels, "relationships/officeDocument")
+            .unwrap_or("xl/workbook.xml".to_string());
+        let relationships = load_part_relationships(&mut zip, &workbook_path)?;
+        if relationships.is_empty() {
+            bail!("Failed to get relationships.");
+        }
         Ok(Self {
             zip,
+            workbook_path,
             workbook_relationships: relationships,
             stylesheet: None,
             theme: None,
             shared_strings: None,
             workbook: None,
+            sheets: None,
+            slicer_caches: None,
+            timeline_caches: None,
+            extension_handlers: ExtensionHandlers::new(),
         })
     }
 }
@@ -74,6 +163,9 @@ impl<RS: Read + Seek> Excel<RS> {
 /// functions for getting raw parsed results
 impl<RS: Read + Seek> Excel<RS> {
     /// Get relationship parsed from xl/_rels/workbook.xml.rels
+    ///
+    /// Targets of package parts are resolved to zip paths. Ex: `worksheets/sheet1.xml` is `xl/worksheets/sheet1.xml`.
+    /// External targets and hyperlinks are kept as written.
     pub fn get_raw_workbook_relationship(&mut self) -> XlsxRelationships {
         return self.workbook_relationships.clone();
     }
@@ -81,7 +173,8 @@ impl<RS: Read + Seek> Excel<RS> {
     /// Get stylesheet parsed from xl/styles.xml
     pub fn get_raw_stylesheet(&mut self) -> anyhow::Result<Option<Box<XlsxStyleSheet>>> {
         if self.stylesheet.is_none() {
-            self.stylesheet = Some(Box::new(XlsxStyleSheet::load(&mut self.zip)?));
+            let path = self.workbook_part_path("relationships/styles", "styles.xml");
+            self.stylesheet = Some(Box::new(XlsxStyleSheet::load(&mut self.zip, &path)?));
         }
         return Ok(self.stylesheet.clone());
     }
@@ -100,7 +193,9 @@ impl<RS: Read + Seek> Excel<RS> {
     /// Get shared string parsed from xl/sharedStrings.xml
     pub fn get_raw_shared_strings(&mut self) -> anyhow::Result<Option<Box<XlsxSharedStringTable>>> {
         if self.shared_strings.is_none() {
-            self.shared_strings = Some(Box::new(XlsxSharedStringTable::load(&mut self.zip)?));
+            let path = self.workbook_part_path("relationships/sharedStrings", "sharedStrings.xml");
+            self.shared_strings =
+                Some(Box::new(XlsxSharedStringTable::load(&mut self.zip, &path)?));
         }
         return Ok(self.shared_strings.clone());
     }
@@ -108,7 +203,10 @@ impl<RS: Read + Seek> Excel<RS> {
     /// Get workbook parsed from xl/workbook.xml
     pub fn get_raw_workbook(&mut self) -> anyhow::Result<Option<Box<XlsxWorkbook>>> {
         if self.workbook.is_none() {
-            self.workbook = Some(Box::new(XlsxWorkbook::load(&mut self.zip)?));
+            self.workbook = Some(Box::new(XlsxWorkbook::load(
+                &mut self.zip,
+                &self.workbook_path,
+            )?));
         }
         return Ok(self.workbook.clone());
     }
@@ -134,7 +232,41 @@ impl<RS: Read + Seek> Excel<RS> {
         if sheet.r#type != SheetType::WorkSheet {
             bail!("Sheet specified is not a worksheet")
         };
-        return XlsxWorksheet::load(&mut self.zip, &sheet.path);
+        let mut worksheet = XlsxWorksheet::load(&mut self.zip, &sheet.path)?;
+
+        // a malformed extension leaves the worksheet without sparklines instead of failing
+        worksheet.sparkline_groups = worksheet
+            .extension_list
+            .as_ref()
+            .and_then(|list| list.iter().find(|e| e.has_uri(SPARKLINE_GROUPS_EXTENSION_URI)))
+            .and_then(|e| self.extension_handlers.decode_as::<XlsxSparklineGroups>(e))
+            .and_then(|r| r.ok());
+
+        return Ok(worksheet);
+    }
+
+    /// Get a specific chartsheet parsed from xl/chartsheets/sheet{}.xml
+    ///
+    /// * name: chartsheet name
+    pub fn get_raw_chartsheet_with_name(&mut self, name: &str) -> anyhow::Result<XlsxChartsheet> {
+        let sheet = self.get_sheet_with_name(name)?;
+        return self.get_raw_chartsheet(&sheet);
+    }
+
+    /// Get a specific chartsheet parsed from xl/chartsheets/sheet{}.xml
+    ///
+    /// * id: chartsheet sheet id
+    pub fn get_raw_chartsheet_with_sheet_id(&mut self, id: &u64) -> anyhow::Result<XlsxChartsheet> {
+        let sheet = self.get_sheet_with_sheet_id(id)?;
+        return self.get_raw_chartsheet(&sheet);
+    }
+
+    /// Get a specific chartsheet parsed from xl/chartsheets/sheet{}.xml
+    pub fn get_raw_chartsheet(&mut self, sheet: &SheetBasicInfo) -> anyhow::Result<XlsxChartsheet> {
+        if sheet.r#type != SheetType::ChartSheet {
+            bail!("Sheet specified is not a chartsheet")
+        };
+        return XlsxChartsheet::load(&mut self.zip, &sheet.path);
     }
 
     /// Get relationships for a sheet parsed from xl/worksheets/_rels/sheet{}.xml.rels
@@ -160,12 +292,16 @@ impl<RS: Read + Seek> Excel<RS> {
     }
 
     /// Get relationship for a sheet parsed from xl/worksheets/_rels/sheet{}.xml.rels
+    ///
+    /// Targets of package parts are resolved to zip paths. Ex: `../drawings/drawing1.xml` is `xl/drawings/drawing1.xml`.
+    /// External targets and hyperlinks are kept as written.
+    ///
+    /// Empty if the sheet does not have relationships.
     pub fn get_raw_sheet_relationship(
         &mut self,
         sheet: &SheetBasicInfo,
     ) -> anyhow::Result<XlsxRelationships> {
-        let worksheet_rels = load_sheet_relationships(&mut self.zip, &sheet.path)?;
-        return Ok(worksheet_rels);
+        return load_part_relationships(&mut self.zip, &sheet.path);
     }
 
     /// Get a specific worksheet parsed from xl/worksheets/sheet{}.xml
@@ -200,6 +336,236 @@ impl<RS: Read + Seek> Excel<RS> {
         return self.get_raw_tables(raw_worksheet, worksheet_rels);
     }
 
+    /// Get pivot tables defined in a worksheet parsed from xl/pivotTables/pivotTable{}.xml, ..., xl/pivotTables/pivotTable{n}.xml
+    pub fn get_raw_pivot_tables_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<XlsxPivotTableDefinition>> {
+        if sheet.r#type != SheetType::WorkSheet {
+            bail!("Sheet specified is not a worksheet")
+        };
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return Ok(self.get_raw_pivot_tables(worksheet_rels));
+    }
+
+    /// Get pivot cache definition parsed from xl/pivotCache/pivotCacheDefinition{}.xml
+    ///
+    /// * cache_id: `cacheId` of the pivot cache, referenced by pivot tables
+    pub fn get_raw_pivot_cache_definition(
+        &mut self,
+        cache_id: &u64,
+    ) -> anyhow::Result<XlsxPivotCacheDefinition> {
+        let path = self.pivot_cache_definition_path(cache_id)?;
+        return XlsxPivotCacheDefinition::load(&mut self.zip, &path);
+    }
+
+    /// Get pivot cache records parsed from xl/pivotCache/pivotCacheRecords{}.xml
+    ///
+    /// * cache_id: `cacheId` of the pivot cache, referenced by pivot tables
+    ///
+    /// NOTE: records are empty if the workbook is saved without the pivot cache data.
+    pub fn get_raw_pivot_cache_records(
+        &mut self,
+        cache_id: &u64,
+    ) -> anyhow::Result<XlsxPivotCacheRecords> {
+        let path = self.pivot_cache_definition_path(cache_id)?;
+        let definition = XlsxPivotCacheDefinition::load(&mut self.zip, &path)?;
+        return self.get_raw_records_for_definition(&path, definition);
+    }
+
+    /// Get external link parsed from xl/externalLinks/externalLink{}.xml
+    ///
+    /// * index: 1-based index of the link used in formulas. Ex: `1` for `[1]Prices!B2`
+    pub fn get_raw_external_link(&mut self, index: &u64) -> anyhow::Result<XlsxExternalLink> {
+        let path = self.external_link_path(index)?;
+        return XlsxExternalLink::load(&mut self.zip, &path);
+    }
+
+    /// Get VBA project parsed from xl/vbaProject.bin of a macro-enabled workbook
+    ///
+    /// None if the workbook does not have a VBA project.
+    pub fn get_raw_vba_project(&mut self) -> anyhow::Result<Option<XlsxVbaProject>> {
+        let Some(path) =
+            first_zip_path_for_type(&self.workbook_relationships, "relationships/vbaProject")
+        else {
+            return Ok(None);
+        };
+        let bytes = self.get_part_bytes(&path)?;
+        return Ok(Some(XlsxVbaProject::load(bytes)?));
+    }
+
+    /// Get core properties parsed from docProps/core.xml
+    ///
+    /// None if the package does not have a core properties part.
+    pub fn get_raw_core_properties(&mut self) -> anyhow::Result<Option<XlsxCoreProperties>> {
+        let Some(path) = self.document_properties_path("metadata/core-properties")? else {
+            return Ok(None);
+        };
+        return Ok(Some(XlsxCoreProperties::load(&mut self.zip, &path)?));
+    }
+
+    /// Get extended properties parsed from docProps/app.xml
+    ///
+    /// None if the package does not have an extended properties part.
+    pub fn get_raw_extended_properties(
+        &mut self,
+    ) -> anyhow::Result<Option<XlsxExtendedProperties>> {
+        let Some(path) = self.document_properties_path("relationships/extended-properties")? else {
+            return Ok(None);
+        };
+        return Ok(Some(XlsxExtendedProperties::load(&mut self.zip, &path)?));
+    }
+
+    /// Get custom properties parsed from docProps/custom.xml
+    ///
+    /// None if the package does not have a custom properties part.
+    pub fn get_raw_custom_properties(&mut self) -> anyhow::Result<Option<XlsxCustomProperties>> {
+        let Some(path) = self.document_properties_path("relationships/custom-properties")? else {
+            return Ok(None);
+        };
+        return Ok(Some(load_custom_properties(&mut self.zip, &path)?));
+    }
+
+    /// Get properties of the form controls of a worksheet parsed from xl/ctrlProps/ctrlProp{}.xml
+    ///
+    /// (r_id, properties): r_id is the relationship id in the worksheet relationships, referenced by a control.
+    pub fn get_raw_form_control_properties_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<BTreeMap<String, XlsxFormControlProperties>> {
+        let raw_worksheet = self.get_raw_worksheet(sheet)?;
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return Ok(self.get_form_control_properties_in_rel(&raw_worksheet, &worksheet_rels));
+    }
+
+    /// Get ActiveX controls of a worksheet parsed from xl/activeX/activeX{}.xml
+    ///
+    /// (r_id, control): r_id is the relationship id in the worksheet relationships, referenced by a control.
+    pub fn get_raw_active_x_controls_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<BTreeMap<String, XlsxActiveXControl>> {
+        let raw_worksheet = self.get_raw_worksheet(sheet)?;
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return Ok(self.get_active_x_controls_in_rel(&raw_worksheet, &worksheet_rels));
+    }
+
+    /// Get slicers of a worksheet parsed from xl/slicers/slicer{}.xml
+    pub fn get_raw_slicers_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<XlsxSlicer>> {
+        if sheet.r#type != SheetType::WorkSheet {
+            bail!("Sheet specified is not a worksheet")
+        };
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return Ok(self.get_raw_slicers(&worksheet_rels));
+    }
+
+    /// Get slicer caches of the workbook parsed from xl/slicerCaches/slicerCache{}.xml
+    pub fn get_raw_slicer_caches(&mut self) -> Vec<XlsxSlicerCacheDefinition> {
+        if let Some(caches) = self.slicer_caches.as_ref() {
+            return caches.clone();
+        }
+        let paths = paths_for_type_suffix(&self.workbook_relationships, "/slicerCache");
+        let caches: Vec<XlsxSlicerCacheDefinition> = paths
+            .into_iter()
+            .filter_map(|p| XlsxSlicerCacheDefinition::load(&mut self.zip, &p).ok())
+            .collect();
+        self.slicer_caches = Some(caches.clone());
+        return caches;
+    }
+
+    /// Get timelines of a worksheet parsed from xl/timelines/timeline{}.xml
+    pub fn get_raw_timelines_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<XlsxTimeline>> {
+        if sheet.r#type != SheetType::WorkSheet {
+            bail!("Sheet specified is not a worksheet")
+        };
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return Ok(self.get_raw_timelines(&worksheet_rels));
+    }
+
+    /// Get timeline caches of the workbook parsed from xl/timelineCaches/timelineCache{}.xml
+    pub fn get_raw_timeline_caches(&mut self) -> Vec<XlsxTimelineCacheDefinition> {
+        if let Some(caches) = self.timeline_caches.as_ref() {
+            return caches.clone();
+        }
+        let paths = paths_for_type_suffix(&self.workbook_relationships, "/timelineCache");
+        let caches: Vec<XlsxTimelineCacheDefinition> = paths
+            .into_iter()
+            .filter_map(|p| XlsxTimelineCacheDefinition::load(&mut self.zip, &p).ok())
+            .collect();
+        self.timeline_caches = Some(caches.clone());
+        return caches;
+    }
+
+    /// Get calculation chain parsed from xl/calcChain.xml
+    ///
+    /// Empty if the workbook does not have formulas or is saved without the chain.
+    pub fn get_raw_calculation_chain(&mut self) -> anyhow::Result<XlsxCalculationChain> {
+        let path = self.workbook_part_path("relationships/calcChain", "calcChain.xml");
+        return load_calculation_chain(&mut self.zip, &path);
+    }
+
+    /// Get data connections of the workbook parsed from xl/connections.xml
+    pub fn get_raw_connections(&mut self) -> anyhow::Result<XlsxConnections> {
+        let path = self.workbook_part_path("relationships/connections", "connections.xml");
+        return load_connections(&mut self.zip, &path);
+    }
+
+    /// Get query tables of a worksheet parsed from xl/queryTables/queryTable{}.xml
+    ///
+    /// (table, query table): table is the table populated by the query table, None for a query table not in a table.
+    pub fn get_raw_query_tables_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<(Option<XlsxTable>, XlsxQueryTable)>> {
+        if sheet.r#type != SheetType::WorkSheet {
+            bail!("Sheet specified is not a worksheet")
+        };
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        let tables = self.get_raw_tables_in_rel(&worksheet_rels);
+        return Ok(self.get_raw_query_tables(&worksheet_rels, &tables));
+    }
+
+    /// Get XML maps and schemas of the workbook parsed from xl/xmlMaps.xml
+    pub fn get_raw_xml_maps(&mut self) -> anyhow::Result<XlsxMapInfo> {
+        let path = self.workbook_part_path("relationships/xmlMaps", "xmlMaps.xml");
+        return XlsxMapInfo::load(&mut self.zip, &path);
+    }
+
+    /// Get cells of a worksheet mapped to XML maps parsed from xl/tables/tableSingleCells{}.xml
+    pub fn get_raw_single_xml_cells_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<XlsxSingleXmlCells> {
+        if sheet.r#type != SheetType::WorkSheet {
+            bail!("Sheet specified is not a worksheet")
+        };
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return self.get_raw_single_xml_cells_in_rel(&worksheet_rels);
+    }
+
+    /// Register a handler decoding the extensions (`extLst`) with a specific uri.
+    ///
+    /// Replaces the handler previously registered for the same uri, including the built-in ones.
+    pub fn register_extension_handler<H: ExtensionHandler + 'static>(&mut self, handler: H) {
+        self.extension_handlers.register(handler);
+    }
+
+    /// Decode an extension of a raw element (ex: `XlsxWorksheet.extension_list`) with the handler registered for its uri.
+    ///
+    /// None if no handler is registered for the uri, or the output of the handler is not `T`.
+    pub fn decode_extension<T: 'static>(
+        &self,
+        extension: &XlsxExtension,
+    ) -> Option<anyhow::Result<T>> {
+        return self.extension_handlers.decode_as::<T>(extension);
+    }
+
     /// Get XlsxWorksheetDrawing that defines all drawing objects within the worksheet parsed from xl/drawings/drawing{}.xml
     #[cfg(feature = "drawing")]
     pub fn get_raw_drawing_for_worksheet(
@@ -208,26 +574,146 @@ impl<RS: Read + Seek> Excel<RS> {
     ) -> anyhow::Result<Option<(XlsxWorksheetDrawing, XlsxRelationships)>> {
         let raw_worksheet = self.get_raw_worksheet(&sheet)?;
         let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
-        return self.get_raw_drawing(raw_worksheet, worksheet_rels);
+        return self.get_raw_drawing(raw_worksheet.drawing, worksheet_rels);
+    }
+
+    /// Get XlsxVmlDrawing that defines the comment boxes and legacy form controls of the worksheet parsed from xl/drawings/vmlDrawing{}.vml
+    #[cfg(feature = "drawing")]
+    pub fn get_raw_legacy_drawing_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Option<(XlsxVmlDrawing, XlsxRelationships)>> {
+        let raw_worksheet = self.get_raw_worksheet(sheet)?;
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return self.get_raw_vml_drawing(raw_worksheet.legacy_drawing, worksheet_rels);
+    }
+
+    /// Get XlsxVmlDrawing that defines the header and footer images of the worksheet parsed from xl/drawings/vmlDrawing{}.vml
+    #[cfg(feature = "drawing")]
+    pub fn get_raw_header_footer_drawing_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Option<(XlsxVmlDrawing, XlsxRelationships)>> {
+        let raw_worksheet = self.get_raw_worksheet(sheet)?;
+        let worksheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        return self
+            .get_raw_vml_drawing(raw_worksheet.legacy_drawing_header_footer, worksheet_rels);
+    }
+
+    /// Get charts used in the drawing of a worksheet parsed from xl/charts/chart{}.xml
+    ///
+    /// (r_id, chart): r_id is the relationship id in the drawing relationships, referenced by a graphic frame.
+    #[cfg(feature = "drawing")]
+    pub fn get_raw_charts_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<BTreeMap<String, XlsxChartSpace>> {
+        let Some(drawing) = self.get_raw_drawing_for_worksheet(sheet)? else {
+            return Ok(BTreeMap::new());
+        };
+        return Ok(self.get_charts_in_rel(drawing.1));
+    }
+}
+
+/// functions for accessing the parts of the package (Open Packaging Conventions)
+impl<RS: Read + Seek> Excel<RS> {
+    /// Get content types parsed from [Content_Types].xml
+    pub fn get_raw_content_types(&mut self) -> anyhow::Result<XlsxContentTypes> {
+        return XlsxContentTypes::load(&mut self.zip);
+    }
+
+    /// Get relationships of a part parsed from `{folder}/_rels/{file}.rels`, with targets resolved to zip paths.
+    ///
+    /// * path: zip path of the part. Ex: `xl/workbook.xml`. Empty for the package relationships (_rels/.rels).
+    pub fn get_part_relationships(&mut self, path: &str) -> anyhow::Result<XlsxRelationships> {
+        return load_part_relationships(&mut self.zip, path);
+    }
+
+    /// Get all parts of the package with their content types and relationships
+    pub fn get_parts(&mut self) -> anyhow::Result<Vec<PackagePart>> {
+        let content_types = self.get_raw_content_types()?;
+        let paths: Vec<String> = self
+            .zip
+            .file_names()
+            .filter(|n| !n.ends_with('/') && !n.eq_ignore_ascii_case("[Content_Types].xml"))
+            .map(|n| n.to_string())
+            .collect();
+
+        let mut parts: Vec<PackagePart> = vec![];
+        for path in paths.into_iter() {
+            parts.push(self.part_for_path(&content_types, path)?);
+        }
+        return Ok(parts);
+    }
+
+    /// Get a part of the package with its content type and relationships
+    ///
+    /// * path: zip path of the part (case-insensitive). Ex: `xl/worksheets/sheet1.xml`
+    pub fn get_part(&mut self, path: &str) -> anyhow::Result<PackagePart> {
+        let Some(actual_path) = get_actual_path(&mut self.zip, path.trim_start_matches('/')) else {
+            bail!("Part: `{}` does not exist.", path)
+        };
+        let content_types = self.get_raw_content_types()?;
+        return self.part_for_path(&content_types, actual_path);
+    }
+
+    /// Get the content of a part as bytes
+    ///
+    /// * path: zip path of the part (case-insensitive). Ex: `xl/media/image1.png`
+    pub fn get_part_bytes(&mut self, path: &str) -> anyhow::Result<Vec<u8>> {
+        return self.get_bytes_for_path(path.trim_start_matches('/'));
+    }
+
+    /// Get a reader of a xml part, configured as the readers used by the crate (empty elements are expanded).
+    ///
+    /// * path: zip path of the part (case-insensitive). Ex: `xl/vbaProjectSignature.xml`
+    pub fn get_part_xml_reader(&mut self, path: &str) -> anyhow::Result<XmlReader<'_, RS>> {
+        let Some(reader) = xml_reader(&mut self.zip, path.trim_start_matches('/')) else {
+            bail!("Part: `{}` does not exist.", path)
+        };
+        return Ok(reader);
     }
 }
 
 /// functions for getting processed parsed results
 impl<RS: Read + Seek> Excel<RS> {
     /// Get a list of sheets in the workbook
+    ///
+    /// The list (including the sheet properties read from each sheet part) is loaded once and cached.
+    /// Sheet properties that cannot be read are left to their defaults.
     pub fn get_sheets(&mut self) -> anyhow::Result<Vec<SheetBasicInfo>> {
+        if let Some(sheets) = self.sheets.as_ref() {
+            return Ok(sheets.clone());
+        }
+
         let Some(workbook) = self.get_raw_workbook()?.clone() else {
             return Ok(vec![]);
         };
         let Some(sheets) = workbook.sheets.clone() else {
             return Ok(vec![]);
         };
-        let sheets: anyhow::Result<Vec<SheetBasicInfo>> = sheets
+        let mut sheets: Vec<SheetBasicInfo> = sheets
             .iter()
             .map(|s| SheetBasicInfo::from_raw(s.clone(), &self.workbook_relationships))
-            .collect();
+            .collect::<anyhow::Result<Vec<SheetBasicInfo>>>()?;
 
-        return sheets;
+        // optional metadata: a malformed sheetPr, stylesheet or theme must not fail the list of sheets
+        let stylesheet_colors = self.get_raw_stylesheet().ok().flatten().and_then(|s| s.colors);
+        let color_scheme = self.get_color_scheme().ok().flatten();
+
+        for sheet in sheets.iter_mut() {
+            let raw_properties = XlsxSheetProperties::load_from_sheet(&mut self.zip, &sheet.path)
+                .ok()
+                .flatten();
+            sheet.properties = SheetProperties::from_raw(
+                raw_properties,
+                stylesheet_colors.clone(),
+                color_scheme.clone(),
+            );
+        }
+
+        self.sheets = Some(sheets.clone());
+        return Ok(sheets);
     }
 
     /// Get worksheet (processed)
@@ -266,6 +752,8 @@ impl<RS: Read + Seek> Excel<RS> {
 
         let tables = self.get_raw_tables(raw_worksheet.clone(), worksheet_rels.clone())?;
 
+        let pivot_tables = self.get_pivot_tables(worksheet_rels.clone());
+
         #[cfg(feature = "drawing")]
         let mut drawing_rel: XlsxRelationships = vec![];
         #[cfg(feature = "drawing")]
@@ -273,13 +761,39 @@ impl<RS: Read + Seek> Excel<RS> {
 
         #[cfg(feature = "drawing")]
         if let Some(drawing) =
-            self.get_raw_drawing(raw_worksheet.clone(), worksheet_rels.clone())?
+            self.get_raw_drawing(raw_worksheet.clone().drawing, worksheet_rels.clone())?
         {
             drawing_rel = drawing.1;
             raw_drawing = Some(Box::new(drawing.0));
         }
         #[cfg(feature = "drawing")]
         let bytes = self.get_image_bytes_in_rel(drawing_rel.clone());
+        #[cfg(feature = "drawing")]
+        let charts = self.get_charts_in_rel(drawing_rel.clone());
+        #[cfg(feature = "drawing")]
+        let inks = self.get_inks_in_rel(drawing_rel.clone());
+        #[cfg(feature = "drawing")]
+        let diagrams = self.get_diagrams_in_rel(drawing_rel.clone());
+        #[cfg(feature = "drawing")]
+        let legacy_drawing = self
+            .get_vml_drawing(raw_worksheet.clone().legacy_drawing, worksheet_rels.clone())
+            .ok()
+            .flatten()
+            .map(Box::new);
+        #[cfg(feature = "drawing")]
+        let legacy_drawing_header_footer = self
+            .get_vml_drawing(
+                raw_worksheet.clone().legacy_drawing_header_footer,
+                worksheet_rels.clone(),
+            )
+            .ok()
+            .flatten()
+            .map(Box::new);
+        #[cfg(feature = "drawing")]
+        let form_control_properties =
+            self.get_form_control_properties_in_rel(&raw_worksheet, &worksheet_rels);
+        #[cfg(feature = "drawing")]
+        let active_x_controls = self.get_active_x_controls_in_rel(&raw_worksheet, &worksheet_rels);
 
         let worksheet = Worksheet::from_raw(
             sheet.clone().name,
@@ -287,6 +801,7 @@ impl<RS: Read + Seek> Excel<RS> {
             Box::new(raw_worksheet),
             Box::new(worksheet_rels),
             Box::new(tables),
+            pivot_tables,
             Box::new(raw_workbook.clone().defined_names.unwrap_or(vec![])),
             self.is_1904(*raw_workbook.clone()),
             self.calculation_mode(*raw_workbook.clone()),
@@ -299,10 +814,520 @@ impl<RS: Read + Seek> Excel<RS> {
             raw_drawing,
             #[cfg(feature = "drawing")]
             Box::new(bytes),
+            #[cfg(feature = "drawing")]
+            Box::new(charts),
+            #[cfg(feature = "drawing")]
+            Box::new(inks),
+            #[cfg(feature = "drawing")]
+            Box::new(diagrams),
+            #[cfg(feature = "drawing")]
+            legacy_drawing,
+            #[cfg(feature = "drawing")]
+            legacy_drawing_header_footer,
+            #[cfg(feature = "drawing")]
+            form_control_properties,
+            #[cfg(feature = "drawing")]
+            active_x_controls,
         );
 
         Ok(worksheet)
     }
+
+    /// Get pivot cache (processed) including the records
+    ///
+    /// * cache_id: `cacheId` of the pivot cache, referenced by pivot tables (`PivotTable.cache_id`)
+    pub fn get_pivot_cache(&mut self, cache_id: &u64) -> anyhow::Result<PivotCache> {
+        let path = self.pivot_cache_definition_path(cache_id)?;
+        let definition = XlsxPivotCacheDefinition::load(&mut self.zip, &path)?;
+        let records = self.get_raw_records_for_definition(&path, definition.clone())?;
+        return Ok(PivotCache::from_raw(*cache_id, definition, records));
+    }
+
+    /// Compute the output of a pivot table from the records of its pivot cache
+    pub fn get_pivot_table_layout(
+        &mut self,
+        pivot_table: &PivotTable,
+    ) -> anyhow::Result<PivotLayout> {
+        let cache = self.get_pivot_cache(&pivot_table.cache_id)?;
+        return Ok(pivot_table.compute_layout(&cache));
+    }
+
+    /// Get external link (processed) with the target path of the linked workbook and the cached values
+    ///
+    /// * index: 1-based index of the link used in formulas. Ex: `1` for `[1]Prices!B2`
+    pub fn get_external_link(&mut self, index: &u64) -> anyhow::Result<ExternalLink> {
+        let path = self.external_link_path(index)?;
+        let raw = XlsxExternalLink::load(&mut self.zip, &path)?;
+        let link_rels = load_part_relationships(&mut self.zip, &path).unwrap_or(vec![]);
+        return Ok(ExternalLink::from_raw(raw, *index, &link_rels));
+    }
+
+    /// Get the bytes of an OLE object of a worksheet: the embedded part, the file packaged by OLE Packager objects and the preview image
+    ///
+    /// * object: an object from `Worksheet::get_ole_objects`
+    pub fn get_ole_object_bytes(&mut self, object: &OleObject) -> anyhow::Result<OleObjectBytes> {
+        let payload = match object.embedded_path() {
+            Some(path) => Some(self.get_bytes_for_path(path)?),
+            None => None,
+        };
+        let preview_image = match object.preview_image.as_ref() {
+            Some(image) => Some(self.get_bytes_for_path(&image.path)?),
+            None => None,
+        };
+        return Ok(OleObjectBytes::from_raw(payload, preview_image));
+    }
+
+    /// Get VBA project (processed) with the source code of the modules, document modules mapped to sheets by code names
+    ///
+    /// None if the workbook does not have a VBA project.
+    pub fn get_vba_project(&mut self) -> anyhow::Result<Option<VbaProject>> {
+        let Some(raw) = self.get_raw_vba_project()? else {
+            return Ok(None);
+        };
+        let workbook_code_name = self
+            .get_raw_workbook()?
+            .and_then(|w| w.workbook_properties)
+            .and_then(|p| p.code_name);
+        let sheets = self.get_sheets()?;
+        return Ok(Some(VbaProject::from_raw(raw, workbook_code_name, &sheets)));
+    }
+
+    /// Get document properties: core (author, title, dates...), extended (application, company...) and custom properties
+    pub fn get_document_properties(&mut self) -> anyhow::Result<DocumentProperties> {
+        let core = self.get_raw_core_properties()?;
+        let extended = self.get_raw_extended_properties()?;
+        let custom = self.get_raw_custom_properties()?;
+        return Ok(DocumentProperties::from_raw(core, extended, custom));
+    }
+
+    /// Get all external links (processed) in the workbook, in the order of their indexes
+    pub fn get_external_links(&mut self) -> anyhow::Result<Vec<ExternalLink>> {
+        let Some(workbook) = self.get_raw_workbook()? else {
+            return Ok(vec![]);
+        };
+        let count = workbook.external_references.unwrap_or(vec![]).len() as u64;
+
+        let mut links: Vec<ExternalLink> = vec![];
+        for index in 1..=count {
+            links.push(self.get_external_link(&index)?);
+        }
+        return Ok(links);
+    }
+
+    /// Get all pivot caches (processed) in the workbook
+    pub fn get_pivot_caches(&mut self) -> anyhow::Result<Vec<PivotCache>> {
+        let Some(workbook) = self.get_raw_workbook()? else {
+            return Ok(vec![]);
+        };
+        let cache_ids: Vec<u64> = workbook
+            .pivot_caches
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter_map(|c| c.cache_id)
+            .collect();
+
+        let mut caches: Vec<PivotCache> = vec![];
+        for cache_id in cache_ids.iter() {
+            caches.push(self.get_pivot_cache(cache_id)?);
+        }
+        return Ok(caches);
+    }
+
+    /// Get the cells with formulas in the order of the last calculation with the calculation settings of the workbook
+    /// (calculation mode, full calculation on load, iteration and etc.).
+    pub fn calculation_chain(&mut self) -> anyhow::Result<CalculationChain> {
+        let chain = self.get_raw_calculation_chain()?;
+        let properties = self
+            .get_raw_workbook()?
+            .and_then(|w| w.calculation_propertis);
+        let sheets = self.get_sheets()?;
+        return Ok(CalculationChain::from_raw(chain, properties, &sheets));
+    }
+
+    /// Get slicers (processed) of a worksheet joined with their slicer caches, items resolved by the pivot caches
+    pub fn get_slicers_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<Slicer>> {
+        let raw_slicers = self.get_raw_slicers_for_worksheet(sheet)?;
+        if raw_slicers.is_empty() {
+            return Ok(vec![]);
+        }
+        let caches = self.get_raw_slicer_caches();
+        let mut cache_fields: BTreeMap<u64, Vec<PivotCacheField>> = BTreeMap::new();
+
+        let mut slicers: Vec<Slicer> = vec![];
+        for raw in raw_slicers.into_iter() {
+            let cache = caches.iter().find(|c| c.name.is_some() && c.name == raw.cache);
+            let pivot_cache_id = cache
+                .and_then(|c| c.data.as_ref())
+                .and_then(|d| d.tabular.as_ref())
+                .and_then(|t| t.pivot_cache_id);
+            let source_name = cache.and_then(|c| c.source_name.as_ref());
+            let cache_field = match (pivot_cache_id, source_name) {
+                (Some(cache_id), Some(source_name)) => cache_fields
+                    .entry(cache_id)
+                    .or_insert_with(|| {
+                        self.get_raw_pivot_cache_definition(&cache_id)
+                            .ok()
+                            .and_then(|d| d.cache_fields)
+                            .unwrap_or(vec![])
+                            .into_iter()
+                            .map(PivotCacheField::from_raw)
+                            .collect()
+                    })
+                    .iter()
+                    .find(|f| &f.name == source_name),
+                _ => None,
+            };
+            slicers.push(Slicer::from_raw(raw, cache.cloned(), cache_field));
+        }
+
+        return Ok(slicers);
+    }
+
+    /// Get timelines (processed) of a worksheet joined with their timeline caches
+    pub fn get_timelines_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<Timeline>> {
+        let raw_timelines = self.get_raw_timelines_for_worksheet(sheet)?;
+        if raw_timelines.is_empty() {
+            return Ok(vec![]);
+        }
+        let caches = self.get_raw_timeline_caches();
+
+        return Ok(raw_timelines
+            .into_iter()
+            .map(|raw| {
+                let cache = caches
+                    .iter()
+                    .find(|c| c.name.is_some() && c.name == raw.cache)
+                    .cloned();
+                Timeline::from_raw(raw, cache)
+            })
+            .collect());
+    }
+
+    /// Get query tables (processed) of a worksheet with the fields resolved to the columns of their tables
+    pub fn get_query_tables_for_worksheet(
+        &mut self,
+        sheet: &SheetBasicInfo,
+    ) -> anyhow::Result<Vec<QueryTable>> {
+        let raw_query_tables = self.get_raw_query_tables_for_worksheet(sheet)?;
+        return Ok(raw_query_tables
+            .into_iter()
+            .map(|(table, query_table)| {
+                QueryTable::from_raw(query_table, &sheet.name, table.as_ref())
+            })
+            .collect());
+    }
+
+    /// Get all data connections (processed) in the workbook,
+    /// with the query tables and the tables based on xml maps filled by each connection.
+    pub fn get_connections(&mut self) -> anyhow::Result<Vec<Connection>> {
+        let raw_connections = self.get_raw_connections()?;
+        if raw_connections.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut query_tables: Vec<QueryTable> = vec![];
+        // (connection id, table display name)
+        let mut tables: Vec<(u64, String)> = vec![];
+        for sheet in self.get_sheets()?.into_iter() {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            // tables are loaded once for both the query tables and the tables filled by a connection
+            let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
+            let raw_tables = self.get_raw_tables_in_rel(&worksheet_rels);
+            query_tables.extend(
+                self.get_raw_query_tables(&worksheet_rels, &raw_tables)
+                    .into_iter()
+                    .map(|(table, query_table)| {
+                        QueryTable::from_raw(query_table, &sheet.name, table.as_ref())
+                    }),
+            );
+            tables.extend(raw_tables.into_iter().filter_map(|(_, t)| {
+                Some((t.connection_id?, t.display_name.unwrap_or_default()))
+            }));
+        }
+
+        let connections: Vec<Connection> = raw_connections
+            .into_iter()
+            .map(|raw| {
+                let id = raw.id;
+                let connection_query_tables: Vec<QueryTable> = query_tables
+                    .iter()
+                    .filter(|q| id.is_some() && q.connection_id == id)
+                    .cloned()
+                    .collect();
+                let connection_tables: Vec<String> = tables
+                    .iter()
+                    .filter(|t| Some(t.0) == id)
+                    .map(|t| t.1.clone())
+                    .collect();
+                Connection::from_raw(raw, connection_query_tables, connection_tables)
+            })
+            .collect();
+
+        return Ok(connections);
+    }
+
+    /// Get XML maps (processed) of the workbook with the table columns and single cells mapped to their elements
+    pub fn get_xml_maps(&mut self) -> anyhow::Result<Vec<XmlMap>> {
+        let map_info = self.get_raw_xml_maps()?;
+        if map_info.maps.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // (sheet name, table)
+        let mut tables: Vec<(String, XlsxTable)> = vec![];
+        // (sheet name, cell)
+        let mut cells: Vec<(String, XlsxSingleXmlCell)> = vec![];
+        for sheet in self.get_sheets()?.into_iter() {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            // relationships are loaded once for both the tables and the single cells
+            let worksheet_rels = self.get_raw_sheet_relationship(&sheet).unwrap_or(vec![]);
+            for (_, table) in self.get_raw_tables_in_rel(&worksheet_rels).into_iter() {
+                tables.push((sheet.name.clone(), table));
+            }
+            for cell in self.get_raw_single_xml_cells_in_rel(&worksheet_rels)?.into_iter() {
+                cells.push((sheet.name.clone(), cell));
+            }
+        }
+
+        let maps: Vec<XmlMap> = map_info
+            .maps
+            .into_iter()
+            .map(|map| {
+                XmlMap::from_raw(
+                    map,
+                    &map_info.schemas,
+                    map_info.selection_namespaces.clone(),
+                    &tables,
+                    &cells,
+                )
+            })
+            .collect();
+        return Ok(maps);
+    }
+
+    /// Export the data of the ranges mapped to a XML map as the XML document the map represents.
+    ///
+    /// map_id: `ID` of the map
+    pub fn export_xml_map(&mut self, map_id: &u64) -> anyhow::Result<String> {
+        let Some(map) = self
+            .get_xml_maps()?
+            .into_iter()
+            .find(|m| &m.id == map_id)
+        else {
+            bail!("XML map with id {} does not exist", map_id)
+        };
+
+        let mut worksheets: BTreeMap<String, Worksheet> = BTreeMap::new();
+        let sheet_names = map
+            .tables
+            .iter()
+            .map(|t| t.sheet_name.clone())
+            .chain(map.cells.iter().map(|c| c.sheet_name.clone()));
+        for name in sheet_names {
+            if !worksheets.contains_key(&name) {
+                let worksheet = self.get_worksheet_with_name(&name)?;
+                worksheets.insert(name, worksheet);
+            }
+        }
+
+        return map.export(&worksheets);
+    }
+
+    /// Get custom views (processed)
+    ///
+    /// Each custom workbook view joined with the custom sheet views of the worksheets with the same guid.
+    pub fn get_custom_views(&mut self) -> anyhow::Result<Vec<CustomView>> {
+        let Some(workbook) = self.get_raw_workbook()? else {
+            return Ok(vec![]);
+        };
+        let Some(raw_views) = workbook.custom_workbook_views.clone() else {
+            return Ok(vec![]);
+        };
+        let defined_names = workbook.defined_names.clone().unwrap_or(vec![]);
+
+        // (sheet index, sheet, worksheet)
+        let mut worksheets: Vec<(usize, SheetBasicInfo, XlsxWorksheet)> = vec![];
+        for (index, sheet) in self.get_sheets()?.into_iter().enumerate() {
+            if sheet.r#type != SheetType::WorkSheet {
+                continue;
+            }
+            // only the custom sheet views and the hidden rows and columns are needed
+            let worksheet = XlsxWorksheet::load_for_custom_views(&mut self.zip, &sheet.path)?;
+            worksheets.push((index, sheet, worksheet));
+        }
+
+        let views: Vec<CustomView> = raw_views
+            .into_iter()
+            .map(|raw_view| {
+                let mut view = CustomView::from_raw(raw_view, vec![]);
+                for (index, sheet, worksheet) in worksheets.iter() {
+                    let raw_sheet_view = worksheet
+                        .custom_sheet_views
+                        .clone()
+                        .unwrap_or(vec![])
+                        .into_iter()
+                        .find(|v| {
+                            v.guid
+                                .as_ref()
+                                .is_some_and(|g| g.eq_ignore_ascii_case(&view.guid))
+                        });
+                    if let Some(raw_sheet_view) = raw_sheet_view {
+                        let sheet_view = CustomSheetView::from_raw(
+                            raw_sheet_view,
+                            &view,
+                            sheet,
+                            *index,
+                            worksheet,
+                            &defined_names,
+                        );
+                        view.sheets.push(sheet_view);
+                    }
+                }
+                view
+            })
+            .collect();
+
+        return Ok(views);
+    }
+
+    /// Rows and columns of the used range of a worksheet visible under a custom view.
+    ///
+    /// The used range is the one recorded in the `dimension` element of the worksheet.
+    /// Returns (rows, columns), 1-based.
+    ///
+    /// * sheet_view: settings of the worksheet in the view. Ex: `view.get_sheet_view("Sheet1")` for a view from `get_custom_views`
+    pub fn get_visible_rows_and_columns_for_custom_view(
+        &mut self,
+        sheet_view: &CustomSheetView,
+    ) -> anyhow::Result<(Vec<u64>, Vec<u64>)> {
+        let sheet = self.get_sheet_with_name(&sheet_view.sheet_name)?;
+        let Some(dimension) = XlsxWorksheet::load_dimension(&mut self.zip, &sheet.path)? else {
+            return Ok((vec![], vec![]));
+        };
+
+        return Ok((
+            sheet_view.visible_rows(&dimension),
+            sheet_view.visible_columns(&dimension),
+        ));
+    }
+
+    /// Get chartsheet (processed)
+    ///
+    /// name: Chartsheet name
+    pub fn get_chartsheet_with_name(&mut self, name: &str) -> anyhow::Result<Chartsheet> {
+        let sheet = self.get_sheet_with_name(name)?;
+        return self.get_chartsheet(&sheet);
+    }
+
+    /// Get chartsheet (processed)
+    ///
+    /// id: Chartsheet sheet id
+    pub fn get_chartsheet_with_sheet_id(&mut self, id: &u64) -> anyhow::Result<Chartsheet> {
+        let sheet = self.get_sheet_with_sheet_id(id)?;
+        return self.get_chartsheet(&sheet);
+    }
+
+    /// Get chartsheet (processed)
+    ///
+    /// With the `drawing` feature, the chart is obtained by following the drawing relationship of the sheet.
+    pub fn get_chartsheet(&mut self, sheet: &SheetBasicInfo) -> anyhow::Result<Chartsheet> {
+        let raw_chartsheet = self.get_raw_chartsheet(sheet)?;
+
+        let stylesheet = self
+            .get_raw_stylesheet()?
+            .context("Style sheet not availalble")?;
+
+        let theme = self.get_raw_theme()?;
+
+        #[cfg(feature = "drawing")]
+        let raw_workbook = self.get_raw_workbook()?.context("workbook not available")?;
+        #[cfg(feature = "drawing")]
+        let chartsheet_rels = self.get_raw_sheet_relationship(sheet).unwrap_or(vec![]);
+        #[cfg(feature = "drawing")]
+        let mut drawing_rel: XlsxRelationships = vec![];
+        #[cfg(feature = "drawing")]
+        let mut raw_drawing: Option<XlsxWorksheetDrawing> = None;
+
+        #[cfg(feature = "drawing")]
+        if let Some(drawing) =
+            self.get_raw_drawing(raw_chartsheet.clone().drawing, chartsheet_rels)?
+        {
+            drawing_rel = drawing.1;
+            raw_drawing = Some(drawing.0);
+        }
+        #[cfg(feature = "drawing")]
+        let charts = self.get_charts_in_rel(drawing_rel.clone());
+
+        let chartsheet = Chartsheet::from_raw(
+            sheet.clone().name,
+            sheet.sheet_id,
+            raw_chartsheet,
+            stylesheet,
+            theme,
+            #[cfg(feature = "drawing")]
+            drawing_rel,
+            #[cfg(feature = "drawing")]
+            raw_drawing,
+            #[cfg(feature = "drawing")]
+            charts,
+            #[cfg(feature = "drawing")]
+            raw_workbook.defined_names.unwrap_or(vec![]),
+        );
+
+        Ok(chartsheet)
+    }
+
+    /// Get the current worksheet cells referenced by a chart data reference (series name, categories, values, ...).
+    ///
+    /// Cells are returned range by range, row by row, to be compared with the values cached in the chart (`ChartDataReference.values`).
+    /// Cells outside of the used range of the worksheet are returned as empty cells.
+    #[cfg(feature = "drawing")]
+    pub fn get_cells_for_chart_reference(
+        &mut self,
+        reference: &ChartDataReference,
+    ) -> anyhow::Result<Vec<Cell>> {
+        let mut worksheets: BTreeMap<String, Worksheet> = BTreeMap::new();
+        let mut cells: Vec<Cell> = vec![];
+
+        for range in reference.ranges.iter() {
+            let Some(sheet_name) = range.sheet_name.clone() else {
+                bail!(
+                    "Sheet is not specified in chart reference: `{}`.",
+                    reference.formula.clone().unwrap_or_default()
+                )
+            };
+            if !worksheets.contains_key(&sheet_name) {
+                let worksheet = self.get_worksheet_with_name(&sheet_name)?;
+                worksheets.insert(sheet_name.clone(), worksheet);
+            }
+            let Some(worksheet) = worksheets.get(&sheet_name) else {
+                continue;
+            };
+
+            let (start, end) = (range.dimension.start, range.dimension.end);
+            for row in start.row..=end.row {
+                for col in start.col..=end.col {
+                    let coordinate = Coordinate::from_point((row, col));
+                    let cell = worksheet
+                        .get_cell(coordinate)
+                        .unwrap_or(Cell::default(coordinate));
+                    cells.push(cell);
+                }
+            }
+        }
+
+        return Ok(cells);
+    }
 }
 
 /// private helper functions
@@ -335,28 +1360,262 @@ impl<RS: Read + Seek> Excel<RS> {
         };
     }
 
+    /// get a list of pivot tables used in a worksheet
+    fn get_raw_pivot_tables(
+        &mut self,
+        worksheet_rels: XlsxRelationships,
+    ) -> Vec<XlsxPivotTableDefinition> {
+        let paths = zip_path_for_type(&worksheet_rels, "relationships/pivotTable");
+        return paths
+            .into_iter()
+            .filter_map(|p| XlsxPivotTableDefinition::load(&mut self.zip, &p.1).ok())
+            .collect();
+    }
+
+    /// get a list of pivot tables used in a worksheet with field names and items resolved by the pivot caches
+    fn get_pivot_tables(&mut self, worksheet_rels: XlsxRelationships) -> Vec<PivotTable> {
+        let raw_tables = self.get_raw_pivot_tables(worksheet_rels);
+        let mut cache_fields: BTreeMap<u64, Vec<PivotCacheField>> = BTreeMap::new();
+
+        let mut pivot_tables: Vec<PivotTable> = vec![];
+        for raw in raw_tables.into_iter() {
+            let cache_id = raw.cache_id.unwrap_or(0);
+            let fields = cache_fields.entry(cache_id).or_insert_with(|| {
+                self.get_raw_pivot_cache_definition(&cache_id)
+                    .ok()
+                    .and_then(|d| d.cache_fields)
+                    .unwrap_or(vec![])
+                    .into_iter()
+                    .map(PivotCacheField::from_raw)
+                    .collect()
+            });
+            pivot_tables.push(PivotTable::from_raw(raw, fields));
+        }
+
+        return pivot_tables;
+    }
+
+    /// get tables in the worksheet relationships with their zip paths
+    fn get_raw_tables_in_rel(
+        &mut self,
+        worksheet_rels: &XlsxRelationships,
+    ) -> Vec<(String, XlsxTable)> {
+        return paths_for_type_suffix(worksheet_rels, "/table")
+            .into_iter()
+            .filter_map(|p| {
+                let table = XlsxTable::load(&mut self.zip, &p).ok()?;
+                return Some((p, table));
+            })
+            .collect();
+    }
+
+    /// get cells mapped to XML maps in the relationships of a worksheet
+    fn get_raw_single_xml_cells_in_rel(
+        &mut self,
+        worksheet_rels: &XlsxRelationships,
+    ) -> anyhow::Result<XlsxSingleXmlCells> {
+        let mut cells: XlsxSingleXmlCells = vec![];
+        for path in paths_for_type_suffix(worksheet_rels, "/tableSingleCells") {
+            cells.extend(load_single_xml_cells(&mut self.zip, &path)?);
+        }
+        return Ok(cells);
+    }
+
+    /// get query tables in the relationships of the tables of a worksheet, and in the worksheet relationships for query tables not in a table
+    ///
+    /// * tables: (zip path, table) of the tables in the worksheet relationships
+    fn get_raw_query_tables(
+        &mut self,
+        worksheet_rels: &XlsxRelationships,
+        tables: &[(String, XlsxTable)],
+    ) -> Vec<(Option<XlsxTable>, XlsxQueryTable)> {
+        let mut query_tables: Vec<(Option<XlsxTable>, XlsxQueryTable)> = vec![];
+
+        for (table_path, table) in tables.iter() {
+            let table_rels = load_part_relationships(&mut self.zip, table_path).unwrap_or(vec![]);
+            for path in paths_for_type_suffix(&table_rels, "/queryTable").into_iter() {
+                if let Ok(query_table) = XlsxQueryTable::load(&mut self.zip, &path) {
+                    query_tables.push((Some(table.clone()), query_table));
+                }
+            }
+        }
+
+        for path in paths_for_type_suffix(worksheet_rels, "/queryTable").into_iter() {
+            if let Ok(query_table) = XlsxQueryTable::load(&mut self.zip, &path) {
+                query_tables.push((None, query_table));
+            }
+        }
+
+        return query_tables;
+    }
+
+    /// get slicers parsed from the slicer parts in the worksheet relationships
+    fn get_raw_slicers(&mut self, worksheet_rels: &XlsxRelationships) -> Vec<XlsxSlicer> {
+        let paths = paths_for_type_suffix(worksheet_rels, "/slicer");
+        return paths
+            .into_iter()
+            .filter_map(|p| load_slicers(&mut self.zip, &p).ok())
+            .flatten()
+            .collect();
+    }
+
+    /// get timelines parsed from the timeline parts in the worksheet relationships
+    fn get_raw_timelines(&mut self, worksheet_rels: &XlsxRelationships) -> Vec<XlsxTimeline> {
+        let paths = paths_for_type_suffix(worksheet_rels, "/timeline");
+        return paths
+            .into_iter()
+            .filter_map(|p| load_timelines(&mut self.zip, &p).ok())
+            .flatten()
+            .collect();
+    }
+
+    /// path of the pivot cache definition with the `cacheId` from the workbook `pivotCaches`
+    fn pivot_cache_definition_path(&mut self, cache_id: &u64) -> anyhow::Result<String> {
+        let workbook = self.get_raw_workbook()?.context("workbook not available")?;
+        let caches = workbook.pivot_caches.unwrap_or(vec![]);
+        let Some(cache) = caches.iter().find(|c| c.cache_id == Some(*cache_id)) else {
+            bail!("Pivot cache with id: `{}` does not exist.", cache_id)
+        };
+        let Some(path) = cache
+            .id
+            .clone()
+            .and_then(|id| zip_path_for_id(&self.workbook_relationships, &id))
+        else {
+            bail!(
+                "Pivot cache definition does not exist for id: `{}`.",
+                cache_id
+            )
+        };
+        return Ok(path);
+    }
+
+    /// part at the actual zip `path` with its content type and relationships
+    fn part_for_path(
+        &mut self,
+        content_types: &XlsxContentTypes,
+        path: String,
+    ) -> anyhow::Result<PackagePart> {
+        let relationships = if is_relationships_path(&path) {
+            vec![]
+        } else {
+            load_part_relationships(&mut self.zip, &path)?
+        };
+        return Ok(PackagePart {
+            content_type: content_types.content_type_for(&path),
+            path,
+            relationships,
+        });
+    }
+
+    /// zip path of the workbook part with the relationship `type` from the workbook relationships
+    ///
+    /// * default_target: target relative to the workbook used if the relationship does not exist. Ex: `styles.xml`
+    fn workbook_part_path(&self, r#type: &str, default_target: &str) -> String {
+        return first_zip_path_for_type(&self.workbook_relationships, r#type)
+            .unwrap_or(resolve_target_path(&self.workbook_path, default_target));
+    }
+
+    /// path of the document properties part with the relationship `type` from the package relationships (_rels/.rels)
+    fn document_properties_path(&mut self, r#type: &str) -> anyhow::Result<Option<String>> {
+        let package_rels = load_part_relationships(&mut self.zip, "")?;
+        return Ok(first_zip_path_for_type(&package_rels, r#type));
+    }
+
+    /// path of the external link at the 1-based `index` of the workbook `externalReferences`
+    fn external_link_path(&mut self, index: &u64) -> anyhow::Result<String> {
+        let workbook = self.get_raw_workbook()?.context("workbook not available")?;
+        let references = workbook.external_references.unwrap_or(vec![]);
+        let Some(reference) = index
+            .checked_sub(1)
+            .and_then(|i| references.get(i as usize))
+        else {
+            bail!("External link with index: `{}` does not exist.", index)
+        };
+        let Some(path) = reference
+            .id
+            .clone()
+            .and_then(|id| zip_path_for_id(&self.workbook_relationships, &id))
+        else {
+            bail!("External link part does not exist for index: `{}`.", index)
+        };
+        return Ok(path);
+    }
+
+    /// get records of a pivot cache definition from the relationship of the definition
+    fn get_raw_records_for_definition(
+        &mut self,
+        definition_path: &str,
+        definition: XlsxPivotCacheDefinition,
+    ) -> anyhow::Result<XlsxPivotCacheRecords> {
+        let Some(id) = definition.id else {
+            return Ok(vec![]);
+        };
+        let definition_rels =
+            load_part_relationships(&mut self.zip, definition_path).unwrap_or(vec![]);
+        let Some(path) = zip_path_for_id(&definition_rels, &id) else {
+            return Ok(vec![]);
+        };
+        return load_pivot_cache_records(&mut self.zip, &path);
+    }
+
     /// get
-    /// - `XlsxWorksheetDrawing` parsed from xl/drawings/drawing{}.xml that defines all drawing objects within the worksheet
+    /// - `XlsxWorksheetDrawing` parsed from xl/drawings/drawing{}.xml that defines all drawing objects within the worksheet or chartsheet
     /// - `Relationship` from the xl/drawings/_rels/drawing{}.xml.rel
     #[cfg(feature = "drawing")]
     fn get_raw_drawing(
         &mut self,
-        raw_worksheet: XlsxWorksheet,
-        worksheet_rels: XlsxRelationships,
+        drawing: Option<XlsxDrawing>,
+        sheet_rels: XlsxRelationships,
     ) -> anyhow::Result<Option<(XlsxWorksheetDrawing, XlsxRelationships)>> {
-        let Some(drawing) = raw_worksheet.drawing else {
+        let Some(drawing) = drawing else {
             return Ok(None);
         };
-        let Some(path) = zip_path_for_id(&worksheet_rels, &drawing.id) else {
+        let Some(path) = zip_path_for_id(&sheet_rels, &drawing.id) else {
             return Ok(None);
         };
-        let drawing_rels = load_drawing_relationships(&mut self.zip, &path).unwrap_or(vec![]);
+        let drawing_rels = load_part_relationships(&mut self.zip, &path).unwrap_or(vec![]);
         return Ok(Some((
             XlsxWorksheetDrawing::load(&mut self.zip, &path)?,
             drawing_rels,
         )));
     }
 
+    /// get
+    /// - `XlsxVmlDrawing` parsed from xl/drawings/vmlDrawing{}.vml referenced by `legacyDrawing` or `legacyDrawingHF`
+    /// - `Relationship` from the xl/drawings/_rels/vmlDrawing{}.vml.rels
+    #[cfg(feature = "drawing")]
+    fn get_raw_vml_drawing(
+        &mut self,
+        drawing: Option<XlsxDrawing>,
+        sheet_rels: XlsxRelationships,
+    ) -> anyhow::Result<Option<(XlsxVmlDrawing, XlsxRelationships)>> {
+        let Some(drawing) = drawing else {
+            return Ok(None);
+        };
+        let Some(path) = zip_path_for_id(&sheet_rels, &drawing.id) else {
+            return Ok(None);
+        };
+        let drawing_rels = load_part_relationships(&mut self.zip, &path).unwrap_or(vec![]);
+        return Ok(Some((
+            XlsxVmlDrawing::load(&mut self.zip, &path)?,
+            drawing_rels,
+        )));
+    }
+
+    /// get the processed legacy (VML) drawing with the bytes of its images
+    #[cfg(feature = "drawing")]
+    fn get_vml_drawing(
+        &mut self,
+        drawing: Option<XlsxDrawing>,
+        sheet_rels: XlsxRelationships,
+    ) -> anyhow::Result<Option<VmlDrawing>> {
+        let Some((raw, drawing_rels)) = self.get_raw_vml_drawing(drawing, sheet_rels)? else {
+            return Ok(None);
+        };
+        let bytes = self.get_image_bytes_in_rel(drawing_rels.clone());
+        return Ok(Some(VmlDrawing::from_raw(raw, drawing_rels, bytes)));
+    }
+
     /// get a list of image bytes defined in a drawing relationships
     ///
     /// (r_id, bytes): Example: `("rId1", some bytes)`
@@ -375,7 +1634,141 @@ impl<RS: Read + Seek> Excel<RS> {
         return bytes;
     }
 
+    /// get the form control properties referenced by the controls of a worksheet
+    ///
+    /// (r_id, properties): Example: `("rId4", properties parsed from xl/ctrlProps/ctrlProp1.xml)`
+    fn get_form_control_properties_in_rel(
+        &mut self,
+        raw_worksheet: &XlsxWorksheet,
+        worksheet_rels: &XlsxRelationships,
+    ) -> BTreeMap<String, XlsxFormControlProperties> {
+        let mut properties: BTreeMap<String, XlsxFormControlProperties> = BTreeMap::new();
+        for id in raw_worksheet
+            .controls
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter_map(|c| c.id)
+        {
+            let Some(rel) = rel_for_id(worksheet_rels, &id) else {
+                continue;
+            };
+            if !rel.r#type.ends_with("/ctrlProp") {
+                continue;
+            }
+            if let Ok(p) = XlsxFormControlProperties::load(&mut self.zip, &rel.target) {
+                properties.insert(id, p);
+            }
+        }
+        return properties;
+    }
+
+    /// get the ActiveX controls referenced by the controls of a worksheet
+    ///
+    /// (r_id, control): Example: `("rId5", control parsed from xl/activeX/activeX1.xml)`
+    fn get_active_x_controls_in_rel(
+        &mut self,
+        raw_worksheet: &XlsxWorksheet,
+        worksheet_rels: &XlsxRelationships,
+    ) -> BTreeMap<String, XlsxActiveXControl> {
+        let mut controls: BTreeMap<String, XlsxActiveXControl> = BTreeMap::new();
+        for id in raw_worksheet
+            .controls
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter_map(|c| c.id)
+        {
+            let Some(rel) = rel_for_id(worksheet_rels, &id) else {
+                continue;
+            };
+            if !rel.r#type.ends_with("/control") {
+                continue;
+            }
+            if let Ok(c) = XlsxActiveXControl::load(&mut self.zip, &rel.target) {
+                controls.insert(id, c);
+            }
+        }
+        return controls;
+    }
+
+    /// get charts defined in a drawing relationships
+    ///
+    /// (r_id, chart): Example: `("rId1", chart parsed from xl/charts/chart1.xml)`
+    #[cfg(feature = "drawing")]
+    fn get_charts_in_rel(
+        &mut self,
+        drawing_rel: XlsxRelationships,
+    ) -> BTreeMap<String, XlsxChartSpace> {
+        let rels: Vec<(String, String)> = drawing_rel
+            .iter()
+            .filter(|r| r.r#type.ends_with("/chart"))
+            .map(|r| (r.id.to_string(), r.target.to_string()))
+            .collect();
+        let mut charts: BTreeMap<String, XlsxChartSpace> = BTreeMap::new();
+        for rel in rels.into_iter() {
+            if let Ok(chart) = XlsxChartSpace::load(&mut self.zip, &rel.1) {
+                charts.insert(rel.0, chart);
+            }
+        }
+        return charts;
+    }
+
+    /// ink annotations (InkML) referenced by the content parts of a drawing
+    #[cfg(feature = "drawing")]
+    fn get_inks_in_rel(&mut self, drawing_rel: XlsxRelationships) -> BTreeMap<String, XlsxInk> {
+        let rels: Vec<(String, String)> = drawing_rel
+            .iter()
+            .filter(|r| r.r#type.ends_with("/customXml") || r.r#type.ends_with("/ink"))
+            .map(|r| (r.id.to_string(), r.target.to_string()))
+            .collect();
+        let mut inks: BTreeMap<String, XlsxInk> = BTreeMap::new();
+        for rel in rels.into_iter() {
+            if let Ok(ink) = XlsxInk::load(&mut self.zip, &rel.1) {
+                if !ink.traces.is_empty() {
+                    inks.insert(rel.0, ink);
+                }
+            }
+        }
+        return inks;
+    }
+
+    /// parts of the diagrams (SmartArt) referenced by the graphic frames of a drawing
     #[cfg(feature = "drawing")]
+    fn get_diagrams_in_rel(&mut self, drawing_rel: XlsxRelationships) -> XlsxDiagrams {
+        let mut diagrams = XlsxDiagrams::default();
+        for rel in drawing_rel.iter() {
+            let id = rel.id.to_string();
+            if rel.r#type.ends_with("/diagramData") {
+                if let Ok(data_model) = XlsxDiagramDataModel::load(&mut self.zip, &rel.target) {
+                    diagrams.data_models.insert(id, data_model);
+                }
+            } else if rel.r#type.ends_with("/diagramLayout") {
+                if let Ok(layout) = XlsxDiagramDefinition::load(&mut self.zip, &rel.target) {
+                    diagrams.layouts.insert(id, layout);
+                }
+            } else if rel.r#type.ends_with("/diagramQuickStyle") {
+                if let Ok(style) = XlsxDiagramDefinition::load(&mut self.zip, &rel.target) {
+                    diagrams.quick_styles.insert(id, style);
+                }
+            } else if rel.r#type.ends_with("/diagramColors") {
+                if let Ok(colors) = XlsxDiagramDefinition::load(&mut self.zip, &rel.target) {
+                    diagrams.colors.insert(id, colors);
+                }
+            } else if rel.r#type.ends_with("/diagramDrawing") {
+                if let Ok(drawing) = XlsxDiagramDrawing::load(&mut self.zip, &rel.target) {
+                    let drawing_rels =
+                        load_part_relationships(&mut self.zip, &rel.target).unwrap_or(vec![]);
+                    let image_bytes = self.get_image_bytes_in_rel(drawing_rels.clone());
+                    diagrams.drawings.insert(id.clone(), drawing);
+                    diagrams.drawing_relationships.insert(id.clone(), drawing_rels);
+                    diagrams.drawing_image_bytes.insert(id, image_bytes);
+                }
+            }
+        }
+        return diagrams;
+    }
+
     fn get_bytes_for_path(&mut self, path: &str) -> anyhow::Result<Vec<u8>> {
         let zip = &mut self.zip;
         let path = get_actual_path(zip, path)
@@ -408,6 +1801,16 @@ impl<RS: Read + Seek> Excel<RS> {
         return Ok(target.to_owned());
     }
 
+    fn get_color_scheme(&mut self) -> anyhow::Result<Option<XlsxColorScheme>> {
+        let Some(theme) = self.get_raw_theme()? else {
+            return Ok(None);
+        };
+        let Some(theme_elements) = theme.theme_elements else {
+            return Ok(None);
+        };
+        return Ok(theme_elements.color_scheme);
+    }
+
     fn is_1904(&self, workbook: XlsxWorkbook) -> bool {
         let Some(properties) = workbook.workbook_properties else {
             return false;
@@ -438,15 +1841,30 @@ pub(crate) fn xml_reader<'a, RS: Read + Seek>(
         return None;
     };
     let mut xml_reader = Reader::from_reader(BufReader::new(zip));
+    configure_xml_reader(&mut xml_reader);
 
+    return Some(xml_reader);
+}
+
+/// Configuration shared by the xml readers of the crate
+pub(crate) fn configure_xml_reader<R>(xml_reader: &mut Reader<R>) {
     let config = xml_reader.config_mut();
     config.allow_unmatched_ends = false; // default false
     config.check_comments = false; // default false
     config.check_end_names = false; // default true
     config.trim_text(false); // default false
     config.expand_empty_elements = true; // default false
+}
 
-    return Some(xml_reader);
+/// zip paths of the relationships whose type ends with `type` (case-insensitive).
+///
+/// Ex: `/slicer` for `http://schemas.microsoft.com/office/2007/relationships/slicer`, excluding `.../slicerCache`
+fn paths_for_type_suffix(relationships: &XlsxRelationships, r#type: &str) -> Vec<String> {
+    return relationships
+        .iter()
+        .filter(|r| r.r#type.to_lowercase().ends_with(&r#type.to_lowercase()))
+        .map(|r| r.target.to_string())
+        .collect();
 }
 
 fn get_actual_path<'a, RS: Read + Seek>(zip: &'a mut ZipArchive<RS>, path: &str) -> Option<String> {
@@ -1,10 +1,13 @@
-use std::io::Read;
+use std::io::BufRead;
 
 use anyhow::bail;
-use quick_xml::events::{BytesStart, Event};
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
 use regex::Regex;
 
-use crate::{excel::XmlReader, raw::drawing::st_types::st_percentage_to_float};
+use crate::raw::drawing::st_types::st_percentage_to_float;
 
 /// Converting Attributes string to boolean
 pub(crate) fn string_to_bool(str: &str) -> Option<bool> {
@@ -78,7 +81,7 @@ pub(crate) fn extract_val_attribute(e: &BytesStart) -> anyhow::Result<Option<Str
 }
 
 pub(crate) fn extract_text_contents(
-    reader: &mut XmlReader<impl Read>,
+    reader: &mut Reader<impl BufRead>,
     tag: &[u8],
 ) -> anyhow::Result<String> {
     let mut text = String::new();
@@ -100,6 +103,32 @@ pub(crate) fn extract_text_contents(
     return Ok(text);
 }
 
+/// Split the areas of a reference on the commas outside of quoted sheet names.
+///
+/// Ex: `'P&L, 2024'!$3:$5,Sheet1!$8:$8` => [`'P&L, 2024'!$3:$5`, `Sheet1!$8:$8`]
+pub(crate) fn split_reference_areas(reference: &str) -> Vec<String> {
+    let mut areas: Vec<String> = vec![];
+    let mut current = String::new();
+    // `''` (escaped quote) toggles twice
+    let mut in_quote = false;
+    for c in reference.chars() {
+        match c {
+            '\'' => {
+                in_quote = !in_quote;
+                current.push(c);
+            }
+            ',' if !in_quote => {
+                areas.push(current.clone());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    areas.push(current);
+
+    return areas;
+}
+
 /// Convert A1 reference dimension to (row, col) (1 based index).
 ///
 /// - top left (row, column),
@@ -342,8 +371,10 @@ pub(crate) fn hsla_to_rgba(hsla: (f64, f64, f64, f64)) -> anyhow::Result<(u32, u
     if !check_alpha(&a) {
         bail!("invalid alpha value")
     }
+    // achromatic: gray of the lightness
     if s == 0.0 {
-        return Ok((255, 255, 255, a));
+        let gray = (l * 255.0).round() as u32;
+        return Ok((gray, gray, gray, a));
     }
 
     let q = if l < 0.5 {
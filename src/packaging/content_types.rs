@@ -0,0 +1,202 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/system.io.packaging.package?view=windowsdesktop-8.0
+///
+/// Content types of the parts in the package, parsed from [Content_Types].xml.
+///
+/// Example
+/// ```
+/// <Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+///     <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+///     <Default Extension="xml" ContentType="application/xml"/>
+///     <Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
+///     <Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>
+/// </Types>
+/// ```
+/// Types
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxContentTypes {
+    // Child Elements
+    /// Default: content types by file extension
+    pub defaults: Vec<XlsxDefaultContentType>,
+
+    /// Override: content types of specific parts, taking precedence over `defaults`
+    pub overrides: Vec<XlsxOverrideContentType>,
+}
+
+impl XlsxContentTypes {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
+        let path = "[Content_Types].xml";
+        let mut content_types = Self {
+            defaults: vec![],
+            overrides: vec![],
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            bail!("Failed to get content types.");
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Default" => {
+                    if let Some(default) = XlsxDefaultContentType::load(e)? {
+                        content_types.defaults.push(default);
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Override" => {
+                    if let Some(r#override) = XlsxOverrideContentType::load(e)? {
+                        content_types.overrides.push(r#override);
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"Types" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(content_types);
+    }
+
+    /// Content type of the part at the zip `path`: the override for the part if any, else the default for its extension.
+    ///
+    /// Part names and extensions are compared case-insensitively.
+    pub fn content_type_for(&self, path: &str) -> Option<String> {
+        let part_name = path.trim_start_matches('/');
+        if let Some(r#override) = self.overrides.iter().find(|o| {
+            o.part_name
+                .trim_start_matches('/')
+                .eq_ignore_ascii_case(part_name)
+        }) {
+            return Some(r#override.content_type.clone());
+        }
+
+        let file_name = part_name.rsplit('/').next().unwrap_or(part_name);
+        let (_, extension) = file_name.rsplit_once('.')?;
+        return self
+            .defaults
+            .iter()
+            .find(|d| d.extension.eq_ignore_ascii_case(extension))
+            .map(|d| d.content_type.clone());
+    }
+
+    /// Zip paths of the parts overridden with the `content_type`. Ex: all worksheets.
+    pub fn paths_for_content_type(&self, content_type: &str) -> Vec<String> {
+        return self
+            .overrides
+            .iter()
+            .filter(|o| o.content_type.eq_ignore_ascii_case(content_type))
+            .map(|o| o.part_name.trim_start_matches('/').to_string())
+            .collect();
+    }
+}
+
+/// https://learn.microsoft.com/en-us/openxml/structure-of-an-openxml-package
+///
+/// Default content type of the parts with a file extension.
+///
+/// Example
+/// ```
+/// <Default Extension="png" ContentType="image/png"/>
+/// ```
+/// Default
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDefaultContentType {
+    // attributes
+    /// Extension: file extension without the dot. Ex: `png`
+    pub extension: String,
+
+    /// ContentType. Ex: `image/png`
+    pub content_type: String,
+}
+
+impl XlsxDefaultContentType {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Option<Self>> {
+        let mut extension: Option<String> = None;
+        let mut content_type: Option<String> = None;
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"Extension" => extension = Some(string_value),
+                        b"ContentType" => content_type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let (Some(extension), Some(content_type)) = (extension, content_type) else {
+            return Ok(None);
+        };
+
+        return Ok(Some(Self {
+            extension,
+            content_type,
+        }));
+    }
+}
+
+/// https://learn.microsoft.com/en-us/openxml/structure-of-an-openxml-package
+///
+/// Content type of a specific part.
+///
+/// Example
+/// ```
+/// <Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
+/// ```
+/// Override
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOverrideContentType {
+    // attributes
+    /// PartName: absolute part name. Ex: `/xl/workbook.xml`
+    pub part_name: String,
+
+    /// ContentType
+    pub content_type: String,
+}
+
+impl XlsxOverrideContentType {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Option<Self>> {
+        let mut part_name: Option<String> = None;
+        let mut content_type: Option<String> = None;
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"PartName" => part_name = Some(string_value),
+                        b"ContentType" => content_type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let (Some(part_name), Some(content_type)) = (part_name, content_type) else {
+            return Ok(None);
+        };
+
+        return Ok(Some(Self {
+            part_name,
+            content_type,
+        }));
+    }
+}
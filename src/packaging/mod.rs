@@ -1 +1,3 @@
+pub mod content_types;
+pub mod part;
 pub mod relationship;
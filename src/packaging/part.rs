@@ -0,0 +1,32 @@
+use crate::packaging::relationship::XlsxRelationships;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/system.io.packaging.packagepart?view=windowsdesktop-8.0
+///
+/// A part of the package (a file in the zip archive) with its content type and relationships.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackagePart {
+    /// Zip path of the part. Ex: `xl/worksheets/sheet1.xml`
+    pub path: String,
+
+    /// Content type from [Content_Types].xml.
+    ///
+    /// None if neither an override nor a default for the extension exists.
+    pub content_type: Option<String>,
+
+    /// Relationships of the part from its relationships part (`{folder}/_rels/{file}.rels`), with targets resolved to zip paths.
+    ///
+    /// Empty for relationships parts themselves and for parts without relationships.
+    pub relationships: XlsxRelationships,
+}
+
+impl PackagePart {
+    /// Whether the part is a relationships part. Ex: `xl/_rels/workbook.xml.rels`
+    pub fn is_relationships_part(&self) -> bool {
+        return is_relationships_path(&self.path);
+    }
+}
+
+pub(crate) fn is_relationships_path(path: &str) -> bool {
+    return path.to_lowercase().ends_with(".rels")
+        && (path.starts_with("_rels/") || path.contains("/_rels/"));
+}
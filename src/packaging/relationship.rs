@@ -1,4 +1,4 @@
-use anyhow::{bail, Context};
+use anyhow::bail;
 use quick_xml::events::{BytesStart, Event};
 use std::io::{Read, Seek};
 use zip::ZipArchive;
@@ -18,52 +18,18 @@ pub(crate) const EXTERNAL_TARGET_MODE: &str = "External";
 /// ```
 pub type XlsxRelationships = Vec<XlsxRelationship>;
 
-/// get relationships of a workbook
-pub(crate) fn load_workbook_relationships(
-    zip: &mut ZipArchive<impl Read + Seek>,
-) -> anyhow::Result<XlsxRelationships> {
-    let path = "xl/_rels/workbook.xml.rels";
-    let Some(mut reader) = xml_reader(zip, path) else {
-        bail!("Failed to get relationships.");
-    };
-
-    let mut buf = Vec::new();
-    let mut relationships: Vec<XlsxRelationship> = vec![];
-
-    loop {
-        buf.clear();
-
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Relationship" => {
-                let Some(rel) = XlsxRelationship::load(e)? else {
-                    continue;
-                };
-
-                relationships.push(rel);
-            }
-            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"Relationships" => break,
-            Ok(Event::Eof) => break,
-            Err(e) => bail!(e.to_string()),
-            _ => (),
-        }
-    }
-
-    Ok(relationships)
-}
-
-/// get relationships of a specific sheet within a workbook
-pub(crate) fn load_sheet_relationships(
+/// get relationships of any part of the package, with targets resolved to zip paths.
+///
+/// * part_path: zip path of the source part. Ex: `xl/workbook.xml`. Empty for the package itself (_rels/.rels).
+///
+/// Empty if the part does not have relationships.
+pub(crate) fn load_part_relationships(
     zip: &mut ZipArchive<impl Read + Seek>,
-    sheet_path: &str,
+    part_path: &str,
 ) -> anyhow::Result<XlsxRelationships> {
-    let last_folder_index = sheet_path
-        .rfind('/')
-        .context("sheet is not within a folder.")?;
-    let (base_folder, file_name) = sheet_path.split_at(last_folder_index);
-    let path = format!("{}/_rels{}.rels", base_folder, file_name);
-
+    let path = relationships_path_for_part(part_path);
     let Some(mut reader) = xml_reader(zip, &path) else {
-        bail!("Relationships does not exist for sheet {}.", sheet_path);
+        return Ok(vec![]);
     };
 
     let mut buf = Vec::new();
@@ -77,14 +43,8 @@ pub(crate) fn load_sheet_relationships(
                 let Some(mut rel) = XlsxRelationship::load(e)? else {
                     continue;
                 };
-                let target = rel.clone().target;
-                // format relative paths
-                if target.starts_with("../") {
-                    let new_index = base_folder
-                        .rfind('/')
-                        .context("base folder is not within a parent folder.")?;
-                    let full_path = format!("{}{}", &base_folder[..new_index], &target[2..]);
-                    rel.target = full_path;
+                if rel.targets_part() {
+                    rel.target = resolve_target_path(part_path, &rel.target);
                 }
 
                 relationships.push(rel);
@@ -99,52 +59,44 @@ pub(crate) fn load_sheet_relationships(
     Ok(relationships)
 }
 
-/// get relationships of a specific drawing within a workbook
-pub(crate) fn load_drawing_relationships(
-    zip: &mut ZipArchive<impl Read + Seek>,
-    drawing_path: &str,
-) -> anyhow::Result<XlsxRelationships> {
-    let last_folder_index = drawing_path
-        .rfind('/')
-        .context("drawing is not within a folder.")?;
-    let (base_folder, file_name) = drawing_path.split_at(last_folder_index);
-    let path = format!("{}/_rels{}.rels", base_folder, file_name);
-
-    let Some(mut reader) = xml_reader(zip, &path) else {
-        bail!("Relationships does not exist for drawing {}.", drawing_path);
+/// zip path of the relationships part of a part. Ex: `xl/_rels/workbook.xml.rels` for `xl/workbook.xml`, `_rels/.rels` for the package (empty path).
+pub(crate) fn relationships_path_for_part(part_path: &str) -> String {
+    let part_path = part_path.trim_start_matches('/');
+    return match part_path.rsplit_once('/') {
+        Some((folder, file_name)) => format!("{}/_rels/{}.rels", folder, file_name),
+        None => format!("_rels/{}.rels", part_path),
     };
+}
 
-    let mut buf = Vec::new();
-    let mut relationships: Vec<XlsxRelationship> = vec![];
-
-    loop {
-        buf.clear();
-
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Relationship" => {
-                let Some(mut rel) = XlsxRelationship::load(e)? else {
-                    continue;
-                };
-                let target = rel.clone().target;
-                // format relative paths
-                if target.starts_with("../") {
-                    let new_index = base_folder
-                        .rfind('/')
-                        .context("base folder is not within a parent folder.")?;
-                    let full_path = format!("{}{}", &base_folder[..new_index], &target[2..]);
-                    rel.target = full_path;
-                }
+/// zip path of a relationship target.
+///
+/// Absolute targets (`/xl/styles.xml`) are relative to the package root, others to the folder of the source part.
+/// `.` and `..` segments are resolved. Ex: `../media/image1.png` from `xl/drawings/drawing1.xml` is `xl/media/image1.png`.
+///
+/// * source_path: zip path of the source part. Empty for the package itself.
+pub(crate) fn resolve_target_path(source_path: &str, target: &str) -> String {
+    let mut segments: Vec<&str> = vec![];
+    if !target.starts_with('/') {
+        segments = source_path
+            .trim_start_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect();
+        // source file name
+        segments.pop();
+    }
 
-                relationships.push(rel);
+    for segment in target.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
             }
-            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"Relationships" => break,
-            Ok(Event::Eof) => break,
-            Err(e) => bail!(e.to_string()),
-            _ => (),
+            _ => segments.push(segment),
         }
     }
 
-    Ok(relationships)
+    return segments.join("/");
 }
 
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.packaging.ipackagerelationship?view=openxml-3.0.1
@@ -196,9 +148,18 @@ impl XlsxRelationship {
             target_mode,
         }))
     }
+
+    /// Whether the target is a part of the package.
+    ///
+    /// False for external resources and hyperlinks to locations within the document. Ex: `#Sheet1!A1`
+    pub(crate) fn targets_part(&self) -> bool {
+        return self.target_mode != Some(EXTERNAL_TARGET_MODE.to_string())
+            && !self.r#type.ends_with("/hyperlink")
+            && !self.target.starts_with('#');
+    }
 }
 
-/// (id, formated path)
+/// (id, zip path)
 pub(crate) fn zip_path_for_type(
     relationships: &Vec<XlsxRelationship>,
     r#type: &str,
@@ -211,19 +172,15 @@ pub(crate) fn zip_path_for_type(
                 .to_lowercase()
                 .contains(&r#type.to_lowercase())
         })
-        .map(|r| (r.id.to_string(), format_target_path(&r.target)))
+        .map(|r| (r.id.to_string(), r.target.to_string()))
         .collect();
     return filtered;
 }
 
+/// zip path of the relationship with `id`.
+///
+/// Targets that are not parts of the package (external resources, locations within the document) are returned as written.
 pub(crate) fn zip_path_for_id(relationships: &Vec<XlsxRelationship>, id: &str) -> Option<String> {
-    let Some(rel) = rel_for_id(relationships, id) else {
-        return None;
-    };
-    return Some(format_target_path(&rel.target));
-}
-
-pub(crate) fn raw_target_for_id(relationships: &Vec<XlsxRelationship>, id: &str) -> Option<String> {
     let Some(rel) = rel_for_id(relationships, id) else {
         return None;
     };
@@ -242,14 +199,15 @@ pub(crate) fn rel_for_id(
     return filtered.first().cloned();
 }
 
-pub(crate) fn format_target_path(target: &str) -> String {
-    return if target.starts_with("/xl/") {
-        target[1..].to_string()
-    } else if target.starts_with("xl/") {
-        target.to_string()
-    } else if target.starts_with("/") {
-        format!("xl{}", target)
-    } else {
-        format!("xl/{}", target)
-    };
+/// zip path of the first relationship whose type ends with `type` (case-insensitive).
+///
+/// Ex: `relationships/styles` for `http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles`
+pub(crate) fn first_zip_path_for_type(
+    relationships: &[XlsxRelationship],
+    r#type: &str,
+) -> Option<String> {
+    let rel = relationships
+        .iter()
+        .find(|r| r.r#type.to_lowercase().ends_with(&r#type.to_lowercase()))?;
+    return Some(rel.target.clone());
 }
@@ -0,0 +1,104 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::XlsxDatetime, raw::document_properties::core_properties::XlsxCoreProperties,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.packaging.coreFilePropertiesPart?view=openxml-3.0.1
+///
+/// Core properties of the document.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CoreProperties {
+    /// Title of the document
+    pub title: Option<String>,
+
+    /// Topic of the document
+    pub subject: Option<String>,
+
+    /// Author: the user who created the document
+    pub creator: Option<String>,
+
+    /// Keywords used for search. Ex: `budget; 2024`
+    pub keywords: Option<String>,
+
+    /// Comments
+    pub description: Option<String>,
+
+    /// Category
+    pub category: Option<String>,
+
+    /// Status. Ex: `Draft`, `Final`
+    pub content_status: Option<String>,
+
+    /// User who last saved the document
+    pub last_modified_by: Option<String>,
+
+    /// Revision number
+    pub revision: Option<String>,
+
+    /// Version number
+    pub version: Option<String>,
+
+    /// Identifier
+    pub identifier: Option<String>,
+
+    /// Language. Ex: `en-US`
+    pub language: Option<String>,
+
+    /// Creation date
+    pub created: Option<XlsxDatetime>,
+
+    /// Date of the last save
+    pub modified: Option<XlsxDatetime>,
+
+    /// Date of the last print
+    pub last_printed: Option<XlsxDatetime>,
+}
+
+impl CoreProperties {
+    pub(crate) fn default() -> Self {
+        Self {
+            title: None,
+            subject: None,
+            creator: None,
+            keywords: None,
+            description: None,
+            category: None,
+            content_status: None,
+            last_modified_by: None,
+            revision: None,
+            version: None,
+            identifier: None,
+            language: None,
+            created: None,
+            modified: None,
+            last_printed: None,
+        }
+    }
+
+    pub(crate) fn from_raw(raw: Option<XlsxCoreProperties>) -> Self {
+        let Some(raw) = raw else {
+            return Self::default();
+        };
+
+        return Self {
+            title: raw.title,
+            subject: raw.subject,
+            creator: raw.creator,
+            keywords: raw.keywords,
+            description: raw.description,
+            category: raw.category,
+            content_status: raw.content_status,
+            last_modified_by: raw.last_modified_by,
+            revision: raw.revision,
+            version: raw.version,
+            identifier: raw.identifier,
+            language: raw.language,
+            created: raw.created,
+            modified: raw.modified,
+            last_printed: raw.last_printed,
+        };
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::XlsxDatetime,
+    helper::{string_to_bool, string_to_float, string_to_int},
+    raw::document_properties::custom_properties::XlsxCustomProperty,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.customproperties.customdocumentproperty?view=openxml-3.0.1
+///
+/// A user defined property of the document.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CustomProperty {
+    /// Name of the property
+    pub name: String,
+
+    /// Value of the property.
+    ///
+    /// None if the property has no value element.
+    pub value: Option<CustomPropertyValue>,
+
+    /// Name of the defined name the value is linked to
+    pub link_target: Option<String>,
+}
+
+impl CustomProperty {
+    pub(crate) fn from_raw(raw: XlsxCustomProperty) -> Self {
+        let value = match (raw.value_type, raw.value) {
+            (Some(value_type), Some(value)) => {
+                Some(CustomPropertyValue::from_raw(&value_type, value))
+            }
+            _ => None,
+        };
+
+        return Self {
+            name: raw.name.unwrap_or_default(),
+            value,
+            link_target: raw.link_target,
+        };
+    }
+}
+
+/// Typed value of a custom property, from its variant type element.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CustomPropertyValue {
+    /// vt:bool
+    Boolean(bool),
+    /// vt:filetime, vt:date
+    DateTime(XlsxDatetime),
+    /// vt:i1 to vt:i8, vt:ui1 to vt:ui8, vt:int, vt:uint
+    Integer(i64),
+    /// vt:r4, vt:r8, vt:decimal
+    Number(f64),
+    /// vt:lpwstr, vt:lpstr, vt:bstr, and any value that cannot be converted
+    String(String),
+}
+
+impl CustomPropertyValue {
+    /// * value_type: local name of the variant type element. Ex: `lpwstr`, `bool`, `i4`, `r8`, `filetime`
+    pub(crate) fn from_raw(value_type: &str, value: String) -> Self {
+        let converted = match value_type {
+            "bool" => string_to_bool(value.trim()).map(Self::Boolean),
+            "filetime" | "date" => XlsxDatetime::from_string(value.trim()).map(Self::DateTime),
+            "i1" | "i2" | "i4" | "i8" | "int" | "ui1" | "ui2" | "ui4" | "ui8" | "uint" => {
+                string_to_int(value.trim()).map(Self::Integer)
+            }
+            "r4" | "r8" | "decimal" => string_to_float(value.trim()).map(Self::Number),
+            _ => None,
+        };
+
+        return converted.unwrap_or(Self::String(value));
+    }
+}
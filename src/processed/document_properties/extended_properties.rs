@@ -0,0 +1,142 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    helper::string_to_unsignedint,
+    raw::document_properties::extended_properties::XlsxExtendedProperties,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.extendedproperties.properties?view=openxml-3.0.1
+///
+/// Application specific properties of the document.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ExtendedProperties {
+    /// Name of the application that created the document. Ex: `Microsoft Excel`
+    pub application: Option<String>,
+
+    /// Version of the application. Ex: `16.0300`
+    pub app_version: Option<String>,
+
+    /// Company
+    pub company: Option<String>,
+
+    /// Manager
+    pub manager: Option<String>,
+
+    /// Template the document is based on
+    pub template: Option<String>,
+
+    /// Base path of relative hyperlinks
+    pub hyperlink_base: Option<String>,
+
+    /// Total editing time in minutes
+    pub total_time: Option<u64>,
+
+    /// Security level (bit flags). 1: password protected, 2: read-only recommended, 4: read-only enforced, 8: locked for annotation
+    pub doc_security: i64,
+
+    /// Whether the thumbnail is cropped (true) or scaled (false)
+    pub scale_crop: bool,
+
+    /// Whether hyperlinks are up to date
+    pub links_up_to_date: bool,
+
+    /// Whether the document is shared
+    pub shared_doc: bool,
+
+    /// Whether hyperlinks have been changed by another application
+    pub hyperlinks_changed: bool,
+
+    /// Groups of the document parts and their number of parts. Ex: `Worksheets` 3
+    pub heading_pairs: Vec<HeadingPair>,
+
+    /// Names of the document parts, in the order of `heading_pairs`. Ex: sheet names
+    pub titles_of_parts: Vec<String>,
+}
+
+impl ExtendedProperties {
+    pub(crate) fn default() -> Self {
+        Self {
+            application: None,
+            app_version: None,
+            company: None,
+            manager: None,
+            template: None,
+            hyperlink_base: None,
+            total_time: None,
+            doc_security: 0,
+            scale_crop: false,
+            links_up_to_date: false,
+            shared_doc: false,
+            hyperlinks_changed: false,
+            heading_pairs: vec![],
+            titles_of_parts: vec![],
+        }
+    }
+
+    pub(crate) fn from_raw(raw: Option<XlsxExtendedProperties>) -> Self {
+        let Some(raw) = raw else {
+            return Self::default();
+        };
+
+        let heading_pairs: Vec<HeadingPair> = raw
+            .heading_pairs
+            .unwrap_or(vec![])
+            .chunks_exact(2)
+            .map(|pair| HeadingPair {
+                name: pair[0].clone(),
+                count: string_to_unsignedint(&pair[1]).unwrap_or(0),
+            })
+            .collect();
+
+        return Self {
+            application: raw.application,
+            app_version: raw.app_version,
+            company: raw.company,
+            manager: raw.manager,
+            template: raw.template,
+            hyperlink_base: raw.hyperlink_base,
+            total_time: raw.total_time,
+            doc_security: raw.doc_security.unwrap_or(0),
+            scale_crop: raw.scale_crop.unwrap_or(false),
+            links_up_to_date: raw.links_up_to_date.unwrap_or(false),
+            shared_doc: raw.shared_doc.unwrap_or(false),
+            hyperlinks_changed: raw.hyperlinks_changed.unwrap_or(false),
+            heading_pairs,
+            titles_of_parts: raw.titles_of_parts.unwrap_or(vec![]),
+        };
+    }
+
+    /// Titles of the parts in the group `name`. Ex: `Worksheets` for the worksheet names.
+    pub fn titles_for_heading(&self, name: &str) -> Vec<String> {
+        let mut start: usize = 0;
+        for pair in &self.heading_pairs {
+            let count = pair.count as usize;
+            if pair.name == name {
+                return self
+                    .titles_of_parts
+                    .iter()
+                    .skip(start)
+                    .take(count)
+                    .cloned()
+                    .collect();
+            }
+            start += count;
+        }
+        return vec![];
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.extendedproperties.headingpairs?view=openxml-3.0.1
+///
+/// A group of document parts and the number of parts in it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HeadingPair {
+    /// Name of the group. Ex: `Worksheets`, `Named Ranges`
+    pub name: String,
+
+    /// Number of parts of the group in `titles_of_parts`
+    pub count: u64,
+}
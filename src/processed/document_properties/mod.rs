@@ -0,0 +1,55 @@
+pub mod core_properties;
+pub mod custom_properties;
+pub mod extended_properties;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use core_properties::CoreProperties;
+use custom_properties::CustomProperty;
+use extended_properties::ExtendedProperties;
+
+use crate::raw::document_properties::{
+    core_properties::XlsxCoreProperties, custom_properties::XlsxCustomProperties,
+    extended_properties::XlsxExtendedProperties,
+};
+
+/// Metadata of the package, parsed from the parts under docProps/ referenced by _rels/.rels.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DocumentProperties {
+    /// Core properties (docProps/core.xml): author, title, dates...
+    pub core: CoreProperties,
+
+    /// Extended properties (docProps/app.xml): application, company...
+    pub extended: ExtendedProperties,
+
+    /// User defined properties (docProps/custom.xml)
+    pub custom: Vec<CustomProperty>,
+}
+
+impl DocumentProperties {
+    pub(crate) fn from_raw(
+        core: Option<XlsxCoreProperties>,
+        extended: Option<XlsxExtendedProperties>,
+        custom: Option<XlsxCustomProperties>,
+    ) -> Self {
+        return Self {
+            core: CoreProperties::from_raw(core),
+            extended: ExtendedProperties::from_raw(extended),
+            custom: custom
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(CustomProperty::from_raw)
+                .collect(),
+        };
+    }
+
+    /// Get a custom property by name (case-insensitive).
+    pub fn get_custom_property(&self, name: &str) -> Option<&CustomProperty> {
+        return self
+            .custom
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name));
+    }
+}
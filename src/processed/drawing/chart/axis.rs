@@ -0,0 +1,305 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::drawing::{line::outline::Outline, text::shape_text_body::ShapeTextBody},
+    raw::drawing::{
+        chart::{
+            axis::XlsxChartAxis, chart_lines::XlsxChartLines, number_format::XlsxChartNumberFormat,
+        },
+        scheme::color_scheme::XlsxColorScheme,
+    },
+};
+
+use super::{shape_outline, text_body, title::ChartTitle};
+
+/// - CategoryAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.categoryaxis?view=openxml-3.0.1
+/// - ValueAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.valueaxis?view=openxml-3.0.1
+/// - DateAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.dateaxis?view=openxml-3.0.1
+/// - SeriesAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.seriesaxis?view=openxml-3.0.1
+///
+/// An axis of the plot area.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartAxis {
+    pub axis_type: AxisType,
+
+    pub id: u64,
+
+    /// Id of the axis this axis crosses
+    pub cross_axis_id: Option<u64>,
+
+    pub position: AxisPosition,
+
+    /// Whether the axis is deleted (hidden)
+    pub deleted: bool,
+
+    /// Whether the axis runs from max to min
+    pub reversed: bool,
+
+    /// Fixed minimum. None if it is automatic.
+    pub min: Option<f64>,
+
+    /// Fixed maximum. None if it is automatic.
+    pub max: Option<f64>,
+
+    /// Logarithmic base. None if the scale is linear.
+    pub log_base: Option<f64>,
+
+    /// Fixed major unit. None if it is automatic.
+    pub major_unit: Option<f64>,
+
+    /// Fixed minor unit. None if it is automatic.
+    pub minor_unit: Option<f64>,
+
+    /// None if major gridlines are not shown.
+    pub major_gridlines: Option<ChartGridlines>,
+
+    /// None if minor gridlines are not shown.
+    pub minor_gridlines: Option<ChartGridlines>,
+
+    pub number_format: Option<ChartNumberFormat>,
+
+    pub major_tick_mark: TickMark,
+
+    pub minor_tick_mark: TickMark,
+
+    pub tick_label_position: TickLabelPosition,
+
+    pub crosses: AxisCrosses,
+
+    /// Only for value axes.
+    /// Whether the value axis crosses the category axis between categories (true) or at the midpoint of a category (false).
+    pub cross_between: bool,
+
+    pub title: Option<ChartTitle>,
+
+    /// Axis line
+    pub outline: Option<Outline>,
+
+    pub text_properties: Option<ShapeTextBody>,
+}
+
+impl ChartAxis {
+    pub(crate) fn from_raw(raw: XlsxChartAxis, color_scheme: Option<XlsxColorScheme>) -> Self {
+        let scaling = raw.scaling.clone();
+
+        return Self {
+            axis_type: AxisType::from_string(&raw.axis_type),
+            id: raw.axis_id.unwrap_or(0),
+            cross_axis_id: raw.cross_axis_id,
+            position: AxisPosition::from_string(raw.axis_position),
+            deleted: raw.delete.unwrap_or(false),
+            reversed: scaling
+                .clone()
+                .and_then(|s| s.orientation)
+                .map(|o| o == "maxMin")
+                .unwrap_or(false),
+            min: scaling.clone().and_then(|s| s.min),
+            max: scaling.clone().and_then(|s| s.max),
+            log_base: scaling.and_then(|s| s.log_base),
+            major_unit: raw.major_unit,
+            minor_unit: raw.minor_unit,
+            major_gridlines: ChartGridlines::from_raw(raw.major_gridlines, color_scheme.clone()),
+            minor_gridlines: ChartGridlines::from_raw(raw.minor_gridlines, color_scheme.clone()),
+            number_format: ChartNumberFormat::from_raw(raw.number_format),
+            major_tick_mark: TickMark::from_string(raw.major_tick_mark),
+            minor_tick_mark: TickMark::from_string(raw.minor_tick_mark),
+            tick_label_position: TickLabelPosition::from_string(raw.tick_label_position),
+            crosses: AxisCrosses::from_raw(raw.crosses, raw.crosses_at),
+            cross_between: raw.cross_between.map(|c| c == "between").unwrap_or(true),
+            title: ChartTitle::from_raw(raw.title, color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme.clone()),
+            text_properties: text_body(raw.text_properties, color_scheme),
+        };
+    }
+}
+
+/// majorGridlines (Major Gridlines), minorGridlines (Minor Gridlines)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartGridlines {
+    /// None if the default line is used
+    pub outline: Option<Outline>,
+}
+
+impl ChartGridlines {
+    pub(crate) fn from_raw(
+        raw: Option<XlsxChartLines>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+        return Some(Self {
+            outline: shape_outline(raw.shape_properties, color_scheme),
+        });
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.numberingformat?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartNumberFormat {
+    pub format_code: String,
+
+    /// Whether the format is linked to the source cells
+    pub source_linked: bool,
+}
+
+impl ChartNumberFormat {
+    pub(crate) fn from_raw(raw: Option<XlsxChartNumberFormat>) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+        return Some(Self {
+            format_code: raw.format_code.unwrap_or("General".to_string()),
+            source_linked: raw.source_linked.unwrap_or(false),
+        });
+    }
+}
+
+/// * Category: catAx
+/// * Date: dateAx
+/// * Series: serAx
+/// * Value: valAx
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AxisType {
+    Category,
+    Date,
+    Series,
+    Value,
+}
+
+impl AxisType {
+    pub(crate) fn from_string(s: &str) -> Self {
+        return match s {
+            "dateAx" => Self::Date,
+            "serAx" => Self::Series,
+            "valAx" => Self::Value,
+            _ => Self::Category,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.axispositionvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AxisPosition {
+    Bottom,
+    Left,
+    Right,
+    Top,
+}
+
+impl AxisPosition {
+    pub(crate) fn default() -> Self {
+        Self::Bottom
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "b" => Self::Bottom,
+            "l" => Self::Left,
+            "r" => Self::Right,
+            "t" => Self::Top,
+            _ => Self::default(),
+        };
+    }
+
+    /// Whether the axis is drawn vertically.
+    pub fn is_vertical(&self) -> bool {
+        return *self == Self::Left || *self == Self::Right;
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.tickmarkvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TickMark {
+    Cross,
+    Inside,
+    None,
+    Outside,
+}
+
+impl TickMark {
+    pub(crate) fn default() -> Self {
+        Self::Cross
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "cross" => Self::Cross,
+            "in" => Self::Inside,
+            "none" => Self::None,
+            "out" => Self::Outside,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.ticklabelpositionvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TickLabelPosition {
+    High,
+    Low,
+    NextTo,
+    None,
+}
+
+impl TickLabelPosition {
+    pub(crate) fn default() -> Self {
+        Self::NextTo
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "high" => Self::High,
+            "low" => Self::Low,
+            "nextTo" => Self::NextTo,
+            "none" => Self::None,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// Where the axis crosses the perpendicular axis.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AxisCrosses {
+    AutoZero,
+    Max,
+    Min,
+    /// crossesAt (Crossing Value)
+    Value(f64),
+}
+
+impl AxisCrosses {
+    pub(crate) fn from_raw(crosses: Option<String>, crosses_at: Option<f64>) -> Self {
+        if let Some(value) = crosses_at {
+            return Self::Value(value);
+        }
+
+        return match crosses.unwrap_or_default().as_ref() {
+            "max" => Self::Max,
+            "min" => Self::Min,
+            _ => Self::AutoZero,
+        };
+    }
+}
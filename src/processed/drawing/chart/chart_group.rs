@@ -0,0 +1,208 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::drawing::{
+    chart::chart_group::XlsxChartGroup, scheme::color_scheme::XlsxColorScheme,
+};
+
+use super::{data_labels::DataLabels, series::ChartSeries};
+
+/// A group of series plotted with the same chart type against the same axes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartGroup {
+    pub chart_type: ChartType,
+
+    /// Bar charts only
+    pub bar_direction: BarDirection,
+
+    pub grouping: ChartGrouping,
+
+    /// Whether each data point has a different color: single series or pie charts
+    pub vary_colors: bool,
+
+    pub series: Vec<ChartSeries>,
+
+    /// Data labels applied to all series in the group
+    pub data_labels: Option<DataLabels>,
+
+    /// Space between bar clusters as a percentage of the bar width: Bar charts
+    pub gap_width: u64,
+
+    /// Overlap of bars in a cluster, -100 - 100: Bar charts
+    pub overlap: i64,
+
+    /// Angle of the first slice in degrees, clockwise from 12 o'clock: Pie and doughnut charts
+    pub first_slice_angle: u64,
+
+    /// Size of the hole in percent of the radius: Doughnut charts
+    pub hole_size: u64,
+
+    /// Scatter charts
+    ///
+    /// * line
+    /// * lineMarker
+    /// * marker
+    /// * none
+    /// * smooth
+    /// * smoothMarker
+    pub scatter_style: Option<String>,
+
+    /// Whether markers are shown: Line charts
+    pub show_marker: bool,
+
+    /// Ids of the axes the group is plotted against
+    pub axis_ids: Vec<u64>,
+}
+
+impl ChartGroup {
+    pub(crate) fn from_raw(raw: XlsxChartGroup, color_scheme: Option<XlsxColorScheme>) -> Self {
+        let chart_type = ChartType::from_string(&raw.chart_type);
+        let mut series: Vec<ChartSeries> = raw
+            .series
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|s| ChartSeries::from_raw(s, color_scheme.clone()))
+            .collect();
+        series.sort_by_key(|s| s.order);
+
+        let default_grouping = match chart_type {
+            ChartType::Bar | ChartType::Bar3D => ChartGrouping::Clustered,
+            _ => ChartGrouping::Standard,
+        };
+
+        return Self {
+            chart_type,
+            bar_direction: BarDirection::from_string(raw.bar_direction),
+            grouping: ChartGrouping::from_string(raw.grouping).unwrap_or(default_grouping),
+            vary_colors: raw.vary_colors.unwrap_or(false),
+            series,
+            data_labels: DataLabels::from_raw(raw.data_labels, color_scheme),
+            gap_width: raw.gap_width.unwrap_or(150),
+            overlap: raw.overlap.unwrap_or(0),
+            first_slice_angle: raw.first_slice_angle.unwrap_or(0),
+            hole_size: raw.hole_size.unwrap_or(10),
+            scatter_style: raw.scatter_style,
+            show_marker: raw.marker.unwrap_or(true),
+            axis_ids: raw.axis_ids.unwrap_or(vec![]),
+        };
+    }
+}
+
+/// Type of a chart group, from the element name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ChartType {
+    Area,
+    Area3D,
+    Bar,
+    Bar3D,
+    Bubble,
+    Doughnut,
+    Line,
+    Line3D,
+    OfPie,
+    Pie,
+    Pie3D,
+    Radar,
+    Scatter,
+    Stock,
+    Surface,
+    Surface3D,
+}
+
+impl ChartType {
+    /// Pie, pie 3D, doughnut and pie of pie charts
+    pub fn is_pie_type(&self) -> bool {
+        return matches!(self, Self::Doughnut | Self::OfPie | Self::Pie | Self::Pie3D);
+    }
+
+    /// Charts drawing series as lines: line, radar, scatter and stock charts
+    pub fn is_line_type(&self) -> bool {
+        return matches!(
+            self,
+            Self::Line | Self::Line3D | Self::Radar | Self::Scatter | Self::Stock
+        );
+    }
+
+    pub(crate) fn from_string(s: &str) -> Self {
+        return match s {
+            "areaChart" => Self::Area,
+            "area3DChart" => Self::Area3D,
+            "barChart" => Self::Bar,
+            "bar3DChart" => Self::Bar3D,
+            "bubbleChart" => Self::Bubble,
+            "doughnutChart" => Self::Doughnut,
+            "lineChart" => Self::Line,
+            "line3DChart" => Self::Line3D,
+            "ofPieChart" => Self::OfPie,
+            "pieChart" => Self::Pie,
+            "pie3DChart" => Self::Pie3D,
+            "radarChart" => Self::Radar,
+            "scatterChart" => Self::Scatter,
+            "stockChart" => Self::Stock,
+            "surfaceChart" => Self::Surface,
+            "surface3DChart" => Self::Surface3D,
+            _ => Self::Bar,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.bardirectionvalues?view=openxml-3.0.1
+///
+/// * Bar: horizontal bars
+/// * Column: vertical bars
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum BarDirection {
+    Bar,
+    Column,
+}
+
+impl BarDirection {
+    pub(crate) fn default() -> Self {
+        Self::Column
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "bar" => Self::Bar,
+            "col" => Self::Column,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// - BarGroupingValues: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.bargroupingvalues?view=openxml-3.0.1
+/// - GroupingValues: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.groupingvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ChartGrouping {
+    Clustered,
+    PercentStacked,
+    Stacked,
+    Standard,
+}
+
+impl ChartGrouping {
+    pub(crate) fn from_string(s: Option<String>) -> Option<Self> {
+        let Some(s) = s else {
+            return None;
+        };
+
+        return match s.as_ref() {
+            "clustered" => Some(Self::Clustered),
+            "percentStacked" => Some(Self::PercentStacked),
+            "stacked" => Some(Self::Stacked),
+            "standard" => Some(Self::Standard),
+            _ => None,
+        };
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::drawing::{
+        fill::Fill, line::outline::Outline, text::shape_text_body::ShapeTextBody,
+    },
+    raw::drawing::{chart::data_labels::XlsxDataLabels, scheme::color_scheme::XlsxColorScheme},
+};
+
+use super::{axis::ChartNumberFormat, shape_fill, shape_outline, text_body};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.datalabels?view=openxml-3.0.1
+///
+/// Data label settings for a series or a chart group.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DataLabels {
+    /// Whether the data labels are deleted
+    pub deleted: bool,
+
+    /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.datalabelpositionvalues?view=openxml-3.0.1
+    ///
+    /// Ex: bestFit, b, ctr, inBase, inEnd, l, outEnd, r, t.
+    /// None if it is not specified.
+    pub position: Option<String>,
+
+    pub number_format: Option<ChartNumberFormat>,
+
+    /// Separator between multiple label contents
+    pub separator: Option<String>,
+
+    pub show_bubble_size: bool,
+
+    pub show_category_name: bool,
+
+    pub show_leader_lines: bool,
+
+    pub show_legend_key: bool,
+
+    pub show_percent: bool,
+
+    pub show_series_name: bool,
+
+    pub show_value: bool,
+
+    pub fill: Option<Fill>,
+
+    pub outline: Option<Outline>,
+
+    pub text_properties: Option<ShapeTextBody>,
+}
+
+impl DataLabels {
+    pub(crate) fn from_raw(
+        raw: Option<XlsxDataLabels>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+
+        return Some(Self {
+            deleted: raw.delete.unwrap_or(false),
+            position: raw.position,
+            number_format: ChartNumberFormat::from_raw(raw.number_format),
+            separator: raw.separator,
+            show_bubble_size: raw.show_bubble_size.unwrap_or(false),
+            show_category_name: raw.show_category_name.unwrap_or(false),
+            show_leader_lines: raw.show_leader_lines.unwrap_or(false),
+            show_legend_key: raw.show_legend_key.unwrap_or(false),
+            show_percent: raw.show_percent.unwrap_or(false),
+            show_series_name: raw.show_series_name.unwrap_or(false),
+            show_value: raw.show_value.unwrap_or(false),
+            fill: shape_fill(raw.shape_properties.clone(), color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme.clone()),
+            text_properties: text_body(raw.text_properties, color_scheme),
+        });
+    }
+
+    /// Whether any label content is shown.
+    pub fn is_visible(&self) -> bool {
+        return !self.deleted
+            && (self.show_value
+                || self.show_category_name
+                || self.show_series_name
+                || self.show_percent
+                || self.show_bubble_size);
+    }
+}
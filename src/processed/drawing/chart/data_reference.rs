@@ -0,0 +1,301 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    helper::{split_reference_areas, string_to_float},
+    raw::drawing::chart::{
+        data_source::XlsxChartDataSource,
+        multi_level_string_reference::XlsxMultiLevelStringReference,
+        number_reference::{XlsxNumberData, XlsxNumberReference},
+        string_reference::{XlsxStringData, XlsxStringReference},
+    },
+};
+
+/// Data used by a chart series (name, categories, values, ...).
+///
+/// Either a reference to worksheet cells together with the values cached in the chart part at the time the file was saved,
+/// or literal values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartDataReference {
+    /// Formula referencing the cells. Ex: `Sheet1!$B$2:$B$4`
+    ///
+    /// None for literal values.
+    pub formula: Option<String>,
+
+    /// Ranges referenced by the formula.
+    ///
+    /// Empty if the formula is not a plain range reference (ex: defined names).
+    pub ranges: Vec<ChartRange>,
+
+    /// Format code of numeric values
+    pub format_code: Option<String>,
+
+    /// Number of points
+    pub point_count: u64,
+
+    /// Cached (or literal) values by point index.
+    ///
+    /// None for points without a value.
+    /// For multi-level categories, this is the innermost level.
+    pub values: Vec<Option<ChartValue>>,
+
+    /// All levels of a multi-level category reference, innermost first.
+    ///
+    /// Empty for single level data.
+    pub levels: Vec<Vec<Option<ChartValue>>>,
+}
+
+/// A range referenced by chart data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartRange {
+    /// None if the reference does not specify a sheet
+    pub sheet_name: Option<String>,
+
+    pub dimension: Dimension,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ChartValue {
+    Number(f64),
+    Text(String),
+}
+
+impl ChartValue {
+    /// numeric representation of the value, None for text that cannot be parsed as a number.
+    pub fn as_number(&self) -> Option<f64> {
+        return match self {
+            Self::Number(n) => Some(*n),
+            Self::Text(t) => string_to_float(t),
+        };
+    }
+
+    pub fn as_text(&self) -> String {
+        return match self {
+            Self::Number(n) => n.to_string(),
+            Self::Text(t) => t.to_owned(),
+        };
+    }
+}
+
+impl ChartDataReference {
+    pub(crate) fn from_data_source(raw: Option<XlsxChartDataSource>) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+
+        if let Some(reference) = raw.number_reference {
+            return Some(Self::from_number_reference(reference));
+        }
+        if let Some(reference) = raw.string_reference {
+            return Some(Self::from_string_reference(reference));
+        }
+        if let Some(reference) = raw.multi_level_string_reference {
+            return Some(Self::from_multi_level_string_reference(reference));
+        }
+        if let Some(literal) = raw.number_literal {
+            return Some(Self::from_number_data(None, Some(literal)));
+        }
+        if let Some(literal) = raw.string_literal {
+            return Some(Self::from_string_data(None, Some(literal)));
+        }
+
+        return None;
+    }
+
+    pub(crate) fn from_number_reference(raw: XlsxNumberReference) -> Self {
+        return Self::from_number_data(raw.formula, raw.number_cache);
+    }
+
+    pub(crate) fn from_string_reference(raw: XlsxStringReference) -> Self {
+        return Self::from_string_data(raw.formula, raw.string_cache);
+    }
+
+    fn from_multi_level_string_reference(raw: XlsxMultiLevelStringReference) -> Self {
+        let point_count = raw.point_count.unwrap_or(0);
+        let levels: Vec<Vec<Option<ChartValue>>> = raw
+            .levels
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|l| Self::string_values(Some(l), raw.point_count))
+            .collect();
+
+        return Self {
+            ranges: parse_reference_formula(raw.formula.clone()),
+            formula: raw.formula,
+            format_code: None,
+            point_count,
+            values: levels.first().cloned().unwrap_or(vec![]),
+            levels,
+        };
+    }
+
+    fn from_number_data(formula: Option<String>, data: Option<XlsxNumberData>) -> Self {
+        let point_count = data.clone().and_then(|d| d.point_count).unwrap_or(0);
+
+        let Some(data) = data else {
+            return Self {
+                ranges: parse_reference_formula(formula.clone()),
+                formula,
+                format_code: None,
+                point_count,
+                values: vec![None; value_slot_count(Some(point_count), 0)],
+                levels: vec![],
+            };
+        };
+
+        let points = data.points.unwrap_or(vec![]);
+        let mut values: Vec<Option<ChartValue>> =
+            vec![None; value_slot_count(data.point_count, points.len())];
+        for point in points.into_iter() {
+            let (Some(index), Some(value)) = (point.index, point.value) else {
+                continue;
+            };
+            let Some(slot) = values.get_mut(index as usize) else {
+                continue;
+            };
+            *slot = match string_to_float(&value) {
+                Some(n) => Some(ChartValue::Number(n)),
+                None => Some(ChartValue::Text(value)),
+            };
+        }
+
+        return Self {
+            ranges: parse_reference_formula(formula.clone()),
+            formula,
+            format_code: data.format_code,
+            point_count,
+            values,
+            levels: vec![],
+        };
+    }
+
+    fn from_string_data(formula: Option<String>, data: Option<XlsxStringData>) -> Self {
+        let point_count = data.clone().and_then(|d| d.point_count).unwrap_or(0);
+
+        return Self {
+            ranges: parse_reference_formula(formula.clone()),
+            formula,
+            format_code: None,
+            point_count,
+            values: Self::string_values(data, Some(point_count)),
+            levels: vec![],
+        };
+    }
+
+    /// * point_count: ptCount of the reference, used if the data does not specify its own
+    fn string_values(
+        data: Option<XlsxStringData>,
+        point_count: Option<u64>,
+    ) -> Vec<Option<ChartValue>> {
+        let Some(data) = data else {
+            return vec![None; value_slot_count(point_count, 0)];
+        };
+
+        let points = data.points.unwrap_or(vec![]);
+        let mut values: Vec<Option<ChartValue>> =
+            vec![None; value_slot_count(data.point_count.or(point_count), points.len())];
+        for point in points.into_iter() {
+            let (Some(index), Some(value)) = (point.index, point.value) else {
+                continue;
+            };
+            let Some(slot) = values.get_mut(index as usize) else {
+                continue;
+            };
+            *slot = Some(ChartValue::Text(value));
+        }
+
+        return values;
+    }
+
+    /// Cached values as numbers.
+    ///
+    /// None for points without a value or with a non numeric value.
+    pub fn numbers(&self) -> Vec<Option<f64>> {
+        return self
+            .values
+            .iter()
+            .map(|v| v.clone().and_then(|v| v.as_number()))
+            .collect();
+    }
+
+    /// Cached values as text.
+    ///
+    /// Empty string for points without a value.
+    pub fn texts(&self) -> Vec<String> {
+        return self
+            .values
+            .iter()
+            .map(|v| v.clone().map(|v| v.as_text()).unwrap_or(String::new()))
+            .collect();
+    }
+}
+
+/// Parse a chart reference formula into ranges.
+///
+/// Supported forms:
+/// - `Sheet1!$A$1:$A$3`
+/// - `'Sheet 1'!$A$1` (quoted sheet name, `''` as escaped quote)
+/// - `(Sheet1!$A$1:$A$3,Sheet1!$C$1:$C$3)` (multiple areas)
+///
+/// References that are not ranges (ex: defined names) are ignored.
+pub(crate) fn parse_reference_formula(formula: Option<String>) -> Vec<ChartRange> {
+    let Some(formula) = formula else {
+        return vec![];
+    };
+    let formula = formula.trim();
+    let formula = formula.strip_prefix('=').unwrap_or(formula);
+    let formula = if formula.starts_with('(') && formula.ends_with(')') {
+        &formula[1..formula.len() - 1]
+    } else {
+        formula
+    };
+
+    return split_reference_areas(formula)
+        .into_iter()
+        .filter_map(|a| parse_reference_area(a.trim()))
+        .collect();
+}
+
+/// Largest number of points of cached data: a series cannot span more cells than the rows of a sheet
+const MAX_POINT_COUNT: u64 = 1_048_576;
+
+/// Number of values of cached data: ptCount, or the number of points if ptCount is missing.
+///
+/// Points with an `idx` beyond it are ignored, so that a corrupted index does not allocate values up to it.
+fn value_slot_count(point_count: Option<u64>, points: usize) -> usize {
+    return point_count.unwrap_or(points as u64).min(MAX_POINT_COUNT) as usize;
+}
+
+fn parse_reference_area(area: &str) -> Option<ChartRange> {
+    let (sheet_name, address) = match area.rfind('!') {
+        Some(index) => {
+            let sheet = &area[..index];
+            let sheet = if sheet.starts_with('\'') && sheet.ends_with('\'') && sheet.len() >= 2 {
+                sheet[1..sheet.len() - 1].replace("''", "'")
+            } else {
+                sheet.to_string()
+            };
+            (Some(sheet), &area[index + 1..])
+        }
+        None => (None, area),
+    };
+
+    let address = address.replace('$', "");
+    let dimension = if address.contains(':') {
+        Dimension::from_a1(address.as_bytes())?
+    } else {
+        let coordinate = Coordinate::from_a1(address.as_bytes())?;
+        Dimension {
+            start: coordinate,
+            end: coordinate,
+        }
+    };
+
+    return Some(ChartRange {
+        sheet_name,
+        dimension,
+    });
+}
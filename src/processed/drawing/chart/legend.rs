@@ -0,0 +1,97 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::drawing::{
+        fill::Fill, line::outline::Outline, text::shape_text_body::ShapeTextBody,
+    },
+    raw::drawing::{chart::legend::XlsxLegend, scheme::color_scheme::XlsxColorScheme},
+};
+
+use super::{shape_fill, shape_outline, text_body};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.legend?view=openxml-3.0.1
+///
+/// Legend of a chart.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Legend {
+    pub position: LegendPosition,
+
+    /// Whether the legend is allowed to overlap the plot area.
+    pub overlay: bool,
+
+    /// Index of legend entries that are deleted (not shown).
+    pub deleted_entries: Vec<u64>,
+
+    pub fill: Option<Fill>,
+
+    pub outline: Option<Outline>,
+
+    pub text_properties: Option<ShapeTextBody>,
+}
+
+impl Legend {
+    pub(crate) fn from_raw(
+        raw: Option<XlsxLegend>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+
+        let deleted_entries: Vec<u64> = raw
+            .legend_entries
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter(|e| e.delete.unwrap_or(false))
+            .filter_map(|e| e.index)
+            .collect();
+
+        return Some(Self {
+            position: LegendPosition::from_string(raw.legend_position),
+            overlay: raw.overlay.unwrap_or(false),
+            deleted_entries,
+            fill: shape_fill(raw.shape_properties.clone(), color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme.clone()),
+            text_properties: text_body(raw.text_properties, color_scheme),
+        });
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.legendpositionvalues?view=openxml-3.0.1
+///
+/// * Bottom
+/// * Left
+/// * Right
+/// * Top
+/// * TopRight
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LegendPosition {
+    Bottom,
+    Left,
+    Right,
+    Top,
+    TopRight,
+}
+
+impl LegendPosition {
+    pub(crate) fn default() -> Self {
+        Self::Right
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "b" => Self::Bottom,
+            "l" => Self::Left,
+            "r" => Self::Right,
+            "t" => Self::Top,
+            "tr" => Self::TopRight,
+            _ => Self::default(),
+        };
+    }
+}
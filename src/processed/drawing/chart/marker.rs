@@ -0,0 +1,88 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::drawing::{fill::Fill, line::outline::Outline},
+    raw::drawing::{chart::marker::XlsxMarker, scheme::color_scheme::XlsxColorScheme},
+};
+
+use super::{shape_fill, shape_outline};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.marker?view=openxml-3.0.1
+///
+/// Data marker of a line or scatter series.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartMarker {
+    pub symbol: MarkerSymbol,
+
+    /// 2 - 72 points. None if it is not specified.
+    pub size: Option<u64>,
+
+    pub fill: Option<Fill>,
+
+    pub outline: Option<Outline>,
+}
+
+impl ChartMarker {
+    pub(crate) fn from_raw(
+        raw: Option<XlsxMarker>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+
+        return Some(Self {
+            symbol: MarkerSymbol::from_string(raw.symbol),
+            size: raw.size,
+            fill: shape_fill(raw.shape_properties.clone(), color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme),
+        });
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.markerstylevalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MarkerSymbol {
+    Auto,
+    Circle,
+    Dash,
+    Diamond,
+    Dot,
+    None,
+    Picture,
+    Plus,
+    Square,
+    Star,
+    Triangle,
+    X,
+}
+
+impl MarkerSymbol {
+    pub(crate) fn default() -> Self {
+        Self::Auto
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "auto" => Self::Auto,
+            "circle" => Self::Circle,
+            "dash" => Self::Dash,
+            "diamond" => Self::Diamond,
+            "dot" => Self::Dot,
+            "none" => Self::None,
+            "picture" => Self::Picture,
+            "plus" => Self::Plus,
+            "square" => Self::Square,
+            "star" => Self::Star,
+            "triangle" => Self::Triangle,
+            "x" => Self::X,
+            _ => Self::default(),
+        };
+    }
+}
@@ -0,0 +1,363 @@
+pub mod axis;
+pub mod chart_group;
+pub mod data_labels;
+pub mod data_reference;
+pub mod legend;
+pub mod marker;
+pub mod series;
+pub mod svg;
+pub mod title;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use crate::{
+    common_types::HexColor,
+    helper::{hex_to_rgba, rgba_to_hex},
+    processed::drawing::{
+        fill::Fill, line::outline::Outline, text::shape_text_body::ShapeTextBody,
+    },
+    raw::drawing::{
+        chart::XlsxChartSpace,
+        color::color_transforms::{apply_color_transformations, XlsxColorTransform},
+        scheme::color_scheme::XlsxColorScheme,
+        shape::shape_properties::XlsxShapeProperties,
+        text::{paragraph::text_paragraphs::XlsxRunType, shape_text_body::XlsxShapeTextBody},
+    },
+};
+
+use axis::ChartAxis;
+use chart_group::ChartGroup;
+use legend::Legend;
+use series::ChartSeries;
+use title::ChartTitle;
+
+/// - ChartSpace: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.chartspace?view=openxml-3.0.1
+/// - Chart: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.chart?view=openxml-3.0.1
+///
+/// A chart parsed from xl/charts/chart{}.xml.
+///
+/// A chart with more than one group (ex: bar + line) is a combo chart.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Chart {
+    pub title: Option<ChartTitle>,
+
+    /// Whether the automatic title (series name of a single series chart) is deleted
+    pub auto_title_deleted: bool,
+
+    /// Chart groups in the plot area in drawing order
+    pub groups: Vec<ChartGroup>,
+
+    pub axes: Vec<ChartAxis>,
+
+    /// None if the chart has no legend
+    pub legend: Option<Legend>,
+
+    pub display_blanks_as: DisplayBlanksAs,
+
+    /// Whether only visible cells are plotted
+    pub plot_visible_only: bool,
+
+    /// Whether the chart uses the 1904 date system
+    pub is_1904: bool,
+
+    pub rounded_corners: bool,
+
+    /// Built-in chart style, 1 - 48
+    pub style: u64,
+
+    /// Chart area fill
+    pub fill: Option<Fill>,
+
+    /// Chart area border
+    pub outline: Option<Outline>,
+
+    pub plot_area_fill: Option<Fill>,
+
+    pub plot_area_outline: Option<Outline>,
+
+    /// Default text properties of the chart
+    pub text_properties: Option<ShapeTextBody>,
+
+    /// Theme colors used for automatic series and point fills
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    pub(crate) color_scheme: Option<XlsxColorScheme>,
+}
+
+impl Chart {
+    pub(crate) fn from_raw(raw: XlsxChartSpace, color_scheme: Option<XlsxColorScheme>) -> Self {
+        let chart = raw.chart.clone();
+        let plot_area = chart.clone().and_then(|c| c.plot_area);
+
+        let groups: Vec<ChartGroup> = plot_area
+            .clone()
+            .and_then(|p| p.chart_groups)
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|g| ChartGroup::from_raw(g, color_scheme.clone()))
+            .collect();
+
+        let axes: Vec<ChartAxis> = plot_area
+            .clone()
+            .and_then(|p| p.axes)
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|a| ChartAxis::from_raw(a, color_scheme.clone()))
+            .collect();
+
+        let plot_area_properties = plot_area.and_then(|p| p.shape_properties);
+
+        return Self {
+            title: ChartTitle::from_raw(chart.clone().and_then(|c| c.title), color_scheme.clone()),
+            auto_title_deleted: chart
+                .clone()
+                .and_then(|c| c.auto_title_deleted)
+                .unwrap_or(false),
+            groups,
+            axes,
+            legend: Legend::from_raw(chart.clone().and_then(|c| c.legend), color_scheme.clone()),
+            display_blanks_as: DisplayBlanksAs::from_string(
+                chart.clone().and_then(|c| c.display_blanks_as),
+            ),
+            plot_visible_only: chart.and_then(|c| c.plot_visible_only).unwrap_or(true),
+            is_1904: raw.date1904.unwrap_or(false),
+            rounded_corners: raw.rounded_corners.unwrap_or(true),
+            style: raw.style.unwrap_or(2),
+            fill: shape_fill(raw.shape_properties.clone(), color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme.clone()),
+            plot_area_fill: shape_fill(plot_area_properties.clone(), color_scheme.clone()),
+            plot_area_outline: shape_outline(plot_area_properties, color_scheme.clone()),
+            text_properties: text_body(raw.text_properties, color_scheme.clone()),
+            color_scheme,
+        };
+    }
+
+    /// Whether the chart combines more than one chart type (ex: bar + line).
+    pub fn is_combo(&self) -> bool {
+        return self.groups.len() > 1;
+    }
+
+    /// All series of all groups.
+    pub fn series(&self) -> Vec<ChartSeries> {
+        return self.groups.iter().flat_map(|g| g.series.clone()).collect();
+    }
+
+    /// Axis with the id.
+    pub fn axis(&self, id: u64) -> Option<ChartAxis> {
+        return self.axes.iter().find(|a| a.id == id).cloned();
+    }
+
+    /// Automatic fill for the n-th series (or point of a vary colors group).
+    ///
+    /// Excel cycles through accent1 - accent6 of the theme,
+    /// and uses darker or lighter variants of the accents once all six are used.
+    pub fn automatic_fill(&self, index: u64) -> Fill {
+        let Some(scheme) = self.color_scheme.clone() else {
+            return Fill::SolidFill(DEFAULT_ACCENTS[(index % 6) as usize].to_string());
+        };
+
+        let base = scheme
+            .get_color_by_index(4 + index % 6)
+            .unwrap_or(DEFAULT_ACCENTS[(index % 6) as usize].to_string());
+
+        let transforms = match (index / 6) % 6 {
+            0 => vec![],
+            1 => vec![XlsxColorTransform::LumModulation(60000)],
+            2 => vec![
+                XlsxColorTransform::LumModulation(80000),
+                XlsxColorTransform::LumOffset(20000),
+            ],
+            3 => vec![XlsxColorTransform::LumModulation(80000)],
+            4 => vec![
+                XlsxColorTransform::LumModulation(60000),
+                XlsxColorTransform::LumOffset(40000),
+            ],
+            _ => vec![XlsxColorTransform::LumModulation(50000)],
+        };
+
+        let Ok(rgba) = hex_to_rgba(&base, None) else {
+            return Fill::SolidFill(base);
+        };
+
+        let color: HexColor =
+            rgba_to_hex(apply_color_transformations(rgba, transforms), None).unwrap_or(base);
+
+        return Fill::SolidFill(color);
+    }
+
+    /// Fill of a series: the explicit `spPr` fill if any, otherwise the automatic theme fill.
+    ///
+    /// For line and scatter series, the line color is used.
+    pub fn series_fill(&self, series: &ChartSeries) -> Fill {
+        let is_line = self
+            .groups
+            .iter()
+            .find(|g| g.series.iter().any(|s| s.index == series.index))
+            .map(|g| g.chart_type.is_line_type())
+            .unwrap_or(false);
+
+        if is_line {
+            if let Some(outline) = &series.outline {
+                if outline.fill != Fill::NoFill {
+                    return outline.fill.clone();
+                }
+            }
+        } else if let Some(fill) = &series.fill {
+            return fill.clone();
+        }
+
+        return self.automatic_fill(series.index);
+    }
+
+    /// Fill of a single data point of a series.
+    ///
+    /// Data point overrides first,
+    /// then the automatic fill of the point for vary colors groups with a single series,
+    /// then the series fill.
+    pub fn point_fill(&self, group: &ChartGroup, series: &ChartSeries, point_index: u64) -> Fill {
+        if let Some(point) = series.data_points.iter().find(|p| p.index == point_index) {
+            if let Some(fill) = &point.fill {
+                return fill.clone();
+            }
+        }
+
+        let vary_colors =
+            group.vary_colors && (group.series.len() == 1 || group.chart_type.is_pie_type());
+        if vary_colors && series.fill.is_none() {
+            return self.automatic_fill(point_index);
+        }
+
+        return self.series_fill(series);
+    }
+
+    /// Text of the chart title.
+    ///
+    /// Single series charts without an explicit title use the series name unless the automatic title is deleted.
+    pub fn title_text(&self) -> Option<String> {
+        if let Some(title) = &self.title {
+            if let Some(text) = &title.text {
+                return Some(text.to_owned());
+            }
+            let series = self.series();
+            if series.len() == 1 {
+                return series[0].name.clone().or(Some("Chart Title".to_string()));
+            }
+            return Some("Chart Title".to_string());
+        }
+
+        if self.auto_title_deleted {
+            return None;
+        }
+
+        let series = self.series();
+        if series.len() == 1 && self.groups.iter().all(|g| g.chart_type.is_pie_type()) {
+            return series[0].name.clone();
+        }
+
+        return None;
+    }
+}
+
+/// Office theme accent1 - accent6, used when the workbook has no theme
+const DEFAULT_ACCENTS: [&str; 6] = [
+    "#156082ff",
+    "#e97132ff",
+    "#196b24ff",
+    "#0f9ed5ff",
+    "#a02b93ff",
+    "#4ea72eff",
+];
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.displayblanksasvalues?view=openxml-3.0.1
+///
+/// * Gap: blank cells are left as gaps
+/// * Span: blank cells are spanned with a line
+/// * Zero: blank cells are plotted as zero
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DisplayBlanksAs {
+    Gap,
+    Span,
+    Zero,
+}
+
+impl DisplayBlanksAs {
+    pub(crate) fn default() -> Self {
+        Self::Zero
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "gap" => Self::Gap,
+            "span" => Self::Span,
+            "zero" => Self::Zero,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// fill defined in a chart element `spPr`
+pub(crate) fn shape_fill(
+    raw: Option<XlsxShapeProperties>,
+    color_scheme: Option<XlsxColorScheme>,
+) -> Option<Fill> {
+    let Some(raw) = raw else { return None };
+    return Fill::from_shape_properties(raw, None, vec![], BTreeMap::new(), color_scheme);
+}
+
+/// outline defined in a chart element `spPr`
+pub(crate) fn shape_outline(
+    raw: Option<XlsxShapeProperties>,
+    color_scheme: Option<XlsxColorScheme>,
+) -> Option<Outline> {
+    let Some(raw) = raw else { return None };
+    return Outline::from_raw(raw.outline, color_scheme, None);
+}
+
+/// text body (`rich` or `txPr`) of a chart element
+pub(crate) fn text_body(
+    raw: Option<XlsxShapeTextBody>,
+    color_scheme: Option<XlsxColorScheme>,
+) -> Option<ShapeTextBody> {
+    return ShapeTextBody::from_raw(
+        raw,
+        vec![],
+        vec![],
+        BTreeMap::new(),
+        color_scheme,
+        None,
+        None,
+    );
+}
+
+/// plain text of a text body, paragraphs joined with new lines
+pub(crate) fn text_body_plain_text(raw: &XlsxShapeTextBody) -> String {
+    let paragraphs: Vec<String> = raw
+        .text_paragraph
+        .clone()
+        .unwrap_or(vec![])
+        .into_iter()
+        .map(|p| {
+            p.runs
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|r| match r {
+                    XlsxRunType::Text(run) => run.text.unwrap_or(String::new()),
+                    XlsxRunType::TextField(field) => field.text.unwrap_or(String::new()),
+                    XlsxRunType::LineBreak(_) => "\n".to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join("")
+        })
+        .collect();
+
+    return paragraphs.join("\n");
+}
@@ -0,0 +1,164 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::drawing::{fill::Fill, line::outline::Outline},
+    raw::drawing::{
+        chart::{data_point::XlsxDataPoint, series::XlsxChartSeries},
+        scheme::color_scheme::XlsxColorScheme,
+    },
+};
+
+use super::{
+    data_labels::DataLabels, data_reference::ChartDataReference, marker::ChartMarker, shape_fill,
+    shape_outline,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.barchartseries?view=openxml-3.0.1
+///
+/// A series of a chart group.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartSeries {
+    /// Index of the series within the chart, used to pick the default color.
+    pub index: u64,
+
+    /// Plotting order of the series.
+    pub order: u64,
+
+    /// Series name (cached value of `name_reference` if the name references a cell).
+    pub name: Option<String>,
+
+    /// Cell reference the name comes from.
+    pub name_reference: Option<ChartDataReference>,
+
+    /// cat (Category Axis Data)
+    pub categories: Option<ChartDataReference>,
+
+    /// val (Values)
+    pub values: Option<ChartDataReference>,
+
+    /// xVal (X Values): Scatter and bubble charts
+    pub x_values: Option<ChartDataReference>,
+
+    /// yVal (Y Values): Scatter and bubble charts
+    pub y_values: Option<ChartDataReference>,
+
+    /// bubbleSize (Bubble Size): Bubble charts
+    pub bubble_sizes: Option<ChartDataReference>,
+
+    /// None if the series uses the automatic (theme) fill
+    pub fill: Option<Fill>,
+
+    /// None if the series uses the automatic (theme) line
+    pub outline: Option<Outline>,
+
+    pub marker: Option<ChartMarker>,
+
+    pub data_labels: Option<DataLabels>,
+
+    /// Formatting overrides for individual points
+    pub data_points: Vec<ChartDataPoint>,
+
+    /// Whether the line is smoothed: Line and scatter charts
+    pub smooth: bool,
+
+    /// Whether to invert the fill for negative values: Bar charts
+    pub invert_if_negative: bool,
+
+    /// Distance a slice is moved from the center in percent: Pie charts
+    pub explosion: u64,
+}
+
+impl ChartSeries {
+    pub(crate) fn from_raw(raw: XlsxChartSeries, color_scheme: Option<XlsxColorScheme>) -> Self {
+        let mut name: Option<String> = None;
+        let mut name_reference: Option<ChartDataReference> = None;
+
+        if let Some(tx) = raw.text {
+            if let Some(reference) = tx.string_reference {
+                let reference = ChartDataReference::from_string_reference(reference);
+                name = Some(reference.texts().join(" "));
+                name_reference = Some(reference);
+            } else {
+                name = tx.value;
+            }
+        }
+
+        let data_points: Vec<ChartDataPoint> = raw
+            .data_points
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|p| ChartDataPoint::from_raw(p, color_scheme.clone()))
+            .collect();
+
+        return Self {
+            index: raw.index.unwrap_or(0),
+            order: raw.order.unwrap_or(raw.index.unwrap_or(0)),
+            name,
+            name_reference,
+            categories: ChartDataReference::from_data_source(raw.categories),
+            values: ChartDataReference::from_data_source(raw.values),
+            x_values: ChartDataReference::from_data_source(raw.x_values),
+            y_values: ChartDataReference::from_data_source(raw.y_values),
+            bubble_sizes: ChartDataReference::from_data_source(raw.bubble_size),
+            fill: shape_fill(raw.shape_properties.clone(), color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme.clone()),
+            marker: ChartMarker::from_raw(raw.marker, color_scheme.clone()),
+            data_labels: DataLabels::from_raw(raw.data_labels, color_scheme),
+            data_points,
+            smooth: raw.smooth.unwrap_or(false),
+            invert_if_negative: raw.invert_if_negative.unwrap_or(false),
+            explosion: raw.explosion.unwrap_or(0),
+        };
+    }
+
+    /// All data references used by the series.
+    pub fn references(&self) -> Vec<ChartDataReference> {
+        return [
+            self.name_reference.clone(),
+            self.categories.clone(),
+            self.values.clone(),
+            self.x_values.clone(),
+            self.y_values.clone(),
+            self.bubble_sizes.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.datapoint?view=openxml-3.0.1
+///
+/// Formatting of a single data point that overrides the series formatting.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartDataPoint {
+    pub index: u64,
+
+    pub fill: Option<Fill>,
+
+    pub outline: Option<Outline>,
+
+    pub marker: Option<ChartMarker>,
+
+    /// None if the series value is used
+    pub explosion: Option<u64>,
+
+    /// None if the series value is used
+    pub invert_if_negative: Option<bool>,
+}
+
+impl ChartDataPoint {
+    pub(crate) fn from_raw(raw: XlsxDataPoint, color_scheme: Option<XlsxColorScheme>) -> Self {
+        return Self {
+            index: raw.index.unwrap_or(0),
+            fill: shape_fill(raw.shape_properties.clone(), color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme.clone()),
+            marker: ChartMarker::from_raw(raw.marker, color_scheme),
+            explosion: raw.explosion,
+            invert_if_negative: raw.invert_if_negative,
+        };
+    }
+}
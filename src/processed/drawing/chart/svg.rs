@@ -0,0 +1,2476 @@
+use std::{collections::BTreeMap, f64::consts::PI};
+
+use crate::{
+    helper::hex_to_rgba,
+    processed::drawing::{
+        fill::{gradient_fill::GradientFillTypeValues, Fill},
+        line::{
+            line_dash::LineDashTypeValues, outline::Outline, preset_line_dash::PresetLineDashValues,
+        },
+    },
+};
+
+use super::{
+    axis::{AxisPosition, AxisType, ChartAxis, TickLabelPosition},
+    chart_group::{BarDirection, ChartGroup, ChartGrouping, ChartType},
+    data_labels::DataLabels,
+    data_reference::ChartValue,
+    legend::{Legend, LegendPosition},
+    marker::MarkerSymbol,
+    series::ChartSeries,
+    Chart, DisplayBlanksAs,
+};
+
+const FONT_FAMILY: &str = "Calibri, Arial, sans-serif";
+
+const TEXT_COLOR: &str = "#595959";
+
+/// default color of gridlines, category axis line and chart area border
+const LINE_COLOR: &str = "#d9d9d9";
+
+/// 9pt
+const LABEL_FONT_SIZE: f64 = 12.0;
+
+/// 14pt
+const TITLE_FONT_SIZE: f64 = 18.67;
+
+const PADDING: f64 = 8.0;
+
+/// pt to px
+const PT_TO_PX: f64 = 4.0 / 3.0;
+
+impl Chart {
+    /// Render the chart as a SVG document of `width` x `height` pixels.
+    ///
+    /// Supported chart types:
+    /// - bar and column (clustered, stacked, percent stacked)
+    /// - line (standard, stacked, percent stacked)
+    /// - area (standard, stacked, percent stacked)
+    /// - pie and doughnut
+    /// - scatter and bubble
+    ///
+    /// Series of other chart types (radar, surface, ...) are not drawn,
+    /// but the title, legend and axes of the chart are.
+    ///
+    /// Values are the values cached in the chart part.
+    pub fn to_svg(&self, width: f64, height: f64) -> String {
+        let mut writer = SvgWriter::new();
+
+        let background = self
+            .fill
+            .clone()
+            .unwrap_or(Fill::SolidFill("#ffffffff".to_string()));
+        let fill = writer.fill_attributes(&background);
+        let stroke = writer.stroke_attributes(self.outline.as_ref(), Some(LINE_COLOR), 0.75);
+        let radius = if self.rounded_corners { 6.0 } else { 0.0 };
+        writer.push(format!(
+            r#"<rect x="0.5" y="0.5" width="{}" height="{}" rx="{}"{}{}/>"#,
+            num(width - 1.0),
+            num(height - 1.0),
+            num(radius),
+            fill,
+            stroke
+        ));
+
+        let mut area = Rect {
+            x: PADDING,
+            y: PADDING,
+            width: width - PADDING * 2.0,
+            height: height - PADDING * 2.0,
+        };
+
+        if let Some(text) = self.title_text() {
+            let line_count = text.lines().count().max(1) as f64;
+            let mut y = area.y + TITLE_FONT_SIZE;
+            for line in text.lines() {
+                writer.text(
+                    area.x + area.width / 2.0,
+                    y,
+                    line,
+                    TITLE_FONT_SIZE,
+                    "middle",
+                    None,
+                );
+                y += TITLE_FONT_SIZE * 1.2;
+            }
+
+            let overlay = self.title.as_ref().map(|t| t.overlay).unwrap_or(false);
+            if !overlay {
+                let title_height = line_count * TITLE_FONT_SIZE * 1.2 + 6.0;
+                area.y += title_height;
+                area.height -= title_height;
+            }
+        }
+
+        if let Some(legend) = &self.legend {
+            let entries = self.legend_entries(legend);
+            if !entries.is_empty() {
+                area = render_legend(&mut writer, legend, &entries, area);
+            }
+        }
+
+        if area.width > 0.0 && area.height > 0.0 {
+            if let Some(group) = self.groups.iter().find(|g| g.chart_type.is_pie_type()) {
+                self.render_pie(&mut writer, group, area);
+            } else {
+                self.render_plot_area(&mut writer, area);
+            }
+        }
+
+        return writer.finish(width, height);
+    }
+
+    /// Legend entries: one per series, or one per category for vary colors groups.
+    fn legend_entries(&self, legend: &Legend) -> Vec<LegendEntry> {
+        let mut entries: Vec<LegendEntry> = vec![];
+
+        for group in &self.groups {
+            let is_line = group.chart_type.is_line_type();
+
+            if group.vary_colors && (group.series.len() == 1 || group.chart_type.is_pie_type()) {
+                let Some(series) = group.series.first() else {
+                    continue;
+                };
+                for (index, label) in category_labels(series).into_iter().enumerate() {
+                    entries.push(LegendEntry {
+                        label,
+                        fill: self.point_fill(group, series, index as u64),
+                        is_line,
+                    });
+                }
+                continue;
+            }
+
+            for series in &group.series {
+                let is_line = is_line && series_has_line(group, series);
+                entries.push(LegendEntry {
+                    label: series_name(series),
+                    fill: self.series_fill(series),
+                    is_line,
+                });
+            }
+        }
+
+        return entries
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !legend.deleted_entries.contains(&(*index as u64)))
+            .map(|(_, entry)| entry)
+            .collect();
+    }
+
+    fn render_pie(&self, writer: &mut SvgWriter, group: &ChartGroup, area: Rect) {
+        let series_list: Vec<&ChartSeries> = if group.chart_type == ChartType::Doughnut {
+            group.series.iter().collect()
+        } else {
+            group.series.iter().take(1).collect()
+        };
+        if series_list.is_empty() {
+            return;
+        }
+
+        let max_explosion = series_list
+            .iter()
+            .flat_map(|s| {
+                let mut explosions = vec![s.explosion];
+                explosions.extend(s.data_points.iter().filter_map(|p| p.explosion));
+                explosions
+            })
+            .max()
+            .unwrap_or(0) as f64;
+
+        let center_x = area.x + area.width / 2.0;
+        let center_y = area.y + area.height / 2.0;
+        let radius = area.width.min(area.height) / 2.0 * 0.9 / (1.0 + max_explosion / 100.0);
+        let hole = if group.chart_type == ChartType::Doughnut {
+            radius * group.hole_size as f64 / 100.0
+        } else {
+            0.0
+        };
+        let ring = (radius - hole) / series_list.len() as f64;
+
+        let mut labels: Vec<String> = vec![];
+
+        for (ring_index, series) in series_list.iter().enumerate() {
+            let values: Vec<f64> = series
+                .values
+                .as_ref()
+                .map(|v| v.numbers())
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|v| v.unwrap_or(0.0).abs())
+                .collect();
+            let total: f64 = values.iter().sum();
+            if total <= 0.0 {
+                continue;
+            }
+
+            let categories = category_labels(series);
+            let data_labels = series.data_labels.as_ref().or(group.data_labels.as_ref());
+            let format_code = series.values.as_ref().and_then(|v| v.format_code.clone());
+
+            let outer = radius - ring * ring_index as f64;
+            let inner = if hole > 0.0 { outer - ring } else { 0.0 };
+            let mut angle = group.first_slice_angle as f64;
+
+            for (index, value) in values.iter().enumerate() {
+                let sweep = value / total * 360.0;
+                if sweep <= 0.0 {
+                    continue;
+                }
+
+                let point = series.data_points.iter().find(|p| p.index == index as u64);
+                let explosion = point.and_then(|p| p.explosion).unwrap_or(series.explosion) as f64;
+                let middle = angle + sweep / 2.0;
+                let (offset_x, offset_y) = polar(0.0, 0.0, radius * explosion / 100.0, middle);
+                let slice_x = center_x + offset_x;
+                let slice_y = center_y + offset_y;
+
+                let fill = writer.fill_attributes(&self.point_fill(group, series, index as u64));
+                let outline = point
+                    .and_then(|p| p.outline.as_ref())
+                    .or(series.outline.as_ref());
+                let stroke = writer.stroke_attributes(outline, Some("#ffffff"), 0.75);
+                writer.push(format!(
+                    r#"<path d="{}"{}{}/>"#,
+                    slice_path(slice_x, slice_y, inner, outer, angle, angle + sweep),
+                    fill,
+                    stroke
+                ));
+
+                if let Some(data_labels) = data_labels.filter(|l| l.is_visible()) {
+                    let label_radius = if inner > 0.0 {
+                        (inner + outer) / 2.0
+                    } else if data_labels.position.as_deref() == Some("outEnd") {
+                        outer + LABEL_FONT_SIZE
+                    } else {
+                        outer * 0.65
+                    };
+                    let (x, y) = polar(slice_x, slice_y, label_radius, middle);
+                    let text = label_text(
+                        data_labels,
+                        series,
+                        categories.get(index).cloned(),
+                        Some(*value),
+                        Some(value / total),
+                        format_code.clone(),
+                    );
+                    labels.push(text_element(
+                        x,
+                        y + LABEL_FONT_SIZE / 3.0,
+                        &text,
+                        LABEL_FONT_SIZE,
+                        "middle",
+                        None,
+                    ));
+                }
+
+                angle += sweep;
+            }
+        }
+
+        for label in labels {
+            writer.push(label);
+        }
+    }
+
+    fn render_plot_area(&self, writer: &mut SvgWriter, area: Rect) {
+        let horizontal = self
+            .groups
+            .iter()
+            .any(|g| g.chart_type == ChartType::Bar || g.chart_type == ChartType::Bar3D)
+            && self
+                .groups
+                .iter()
+                .any(|g| g.bar_direction == BarDirection::Bar);
+
+        let categories = self.categories();
+        let category_count = categories.len().max(1);
+
+        let scales = self.value_scales();
+
+        // axes drawn around the plot area, with the space their labels need
+        let mut axes: Vec<(ChartAxis, AxisSide)> = vec![];
+        for group in &self.groups {
+            for id in &group.axis_ids {
+                let Some(axis) = self.axis(*id) else { continue };
+                if axes.iter().any(|(a, _)| a.id == axis.id) {
+                    continue;
+                }
+                let side = AxisSide::for_axis(&axis, horizontal, group);
+                axes.push((axis, side));
+            }
+        }
+
+        let mut plot = area;
+        for (axis, side) in &axes {
+            if axis.deleted {
+                continue;
+            }
+            let mut size = 0.0;
+            if axis.tick_label_position != TickLabelPosition::None {
+                size += if side.is_vertical() {
+                    let labels: Vec<String> = match scales.get(&axis.id) {
+                        Some(scale) => scale.ticks().iter().map(|t| scale.label(*t)).collect(),
+                        None => categories.clone(),
+                    };
+                    labels
+                        .iter()
+                        .map(|l| text_width(l, LABEL_FONT_SIZE))
+                        .fold(0.0, f64::max)
+                        + 6.0
+                } else {
+                    LABEL_FONT_SIZE * 1.4
+                };
+            }
+            if axis.title.is_some() {
+                size += LABEL_FONT_SIZE * 1.4 + 4.0;
+            }
+            match side {
+                AxisSide::Bottom => plot.height -= size,
+                AxisSide::Top => {
+                    plot.y += size;
+                    plot.height -= size;
+                }
+                AxisSide::Left => {
+                    plot.x += size;
+                    plot.width -= size;
+                }
+                AxisSide::Right => plot.width -= size,
+            }
+        }
+        plot.x += 4.0;
+        plot.width -= 8.0;
+        plot.y += 4.0;
+        plot.height -= 8.0;
+        if plot.width <= 0.0 || plot.height <= 0.0 {
+            return;
+        }
+
+        if self.plot_area_fill.is_some() || self.plot_area_outline.is_some() {
+            let fill = writer.fill_attributes(&self.plot_area_fill.clone().unwrap_or(Fill::NoFill));
+            let stroke = writer.stroke_attributes(self.plot_area_outline.as_ref(), None, 0.75);
+            writer.push(format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}"{}{}/>"#,
+                num(plot.x),
+                num(plot.y),
+                num(plot.width),
+                num(plot.height),
+                fill,
+                stroke
+            ));
+        }
+
+        // gridlines
+        for (axis, side) in &axes {
+            let Some(gridlines) = &axis.major_gridlines else {
+                continue;
+            };
+            let fractions: Vec<f64> = match scales.get(&axis.id) {
+                Some(scale) => scale.ticks().iter().map(|t| scale.fraction(*t)).collect(),
+                None if self.categories_between(axis) => (0..=category_count)
+                    .map(|i| i as f64 / category_count as f64)
+                    .collect(),
+                None => (0..category_count)
+                    .map(|i| i as f64 / (category_count.max(2) - 1) as f64)
+                    .collect(),
+            };
+            let stroke =
+                writer.stroke_attributes(gridlines.outline.as_ref(), Some(LINE_COLOR), 0.75);
+            for fraction in fractions {
+                let (x1, y1, x2, y2) = if side.is_vertical() {
+                    let y = plot.y + plot.height * (1.0 - fraction);
+                    (plot.x, y, plot.x + plot.width, y)
+                } else {
+                    let x = plot.x + plot.width * fraction;
+                    (x, plot.y, x, plot.y + plot.height)
+                };
+                writer.push(line_element(x1, y1, x2, y2, &stroke));
+            }
+        }
+
+        // series: areas behind bars, bars behind lines
+        let mut labels: Vec<String> = vec![];
+        let mut groups: Vec<&ChartGroup> = self.groups.iter().collect();
+        groups.sort_by_key(|g| match g.chart_type {
+            ChartType::Area | ChartType::Area3D => 0,
+            ChartType::Bar | ChartType::Bar3D => 1,
+            _ => 2,
+        });
+        for group in groups {
+            let context = self.group_context(group, &scales, plot, horizontal, category_count);
+            let Some(context) = context else { continue };
+
+            match group.chart_type {
+                ChartType::Area | ChartType::Area3D => {
+                    self.render_area(writer, &mut labels, group, &context)
+                }
+                ChartType::Bar | ChartType::Bar3D => {
+                    self.render_bars(writer, &mut labels, group, &context)
+                }
+                ChartType::Line | ChartType::Line3D | ChartType::Stock => {
+                    self.render_lines(writer, &mut labels, group, &context)
+                }
+                ChartType::Scatter | ChartType::Bubble => {
+                    self.render_scatter(writer, &mut labels, group, &context, &scales)
+                }
+                _ => {}
+            }
+        }
+
+        // axes
+        for (axis, side) in &axes {
+            if axis.deleted {
+                continue;
+            }
+            self.render_axis(writer, axis, *side, plot, &scales, &categories);
+        }
+
+        for label in labels {
+            writer.push(label);
+        }
+    }
+
+    /// Category labels of the chart, from the first series with categories.
+    fn categories(&self) -> Vec<String> {
+        let series = self.series();
+        let count = series
+            .iter()
+            .map(|s| {
+                s.values
+                    .as_ref()
+                    .map(|v| (v.point_count as usize).max(v.values.len()))
+                    .unwrap_or(0)
+            })
+            .max()
+            .unwrap_or(0);
+
+        let mut labels = series
+            .iter()
+            .find(|s| s.categories.is_some())
+            .map(category_labels)
+            .unwrap_or(vec![]);
+        while labels.len() < count {
+            labels.push((labels.len() + 1).to_string());
+        }
+
+        return labels;
+    }
+
+    /// Value scales by axis id.
+    ///
+    /// For scatter and bubble charts, both axes are value axes.
+    fn value_scales(&self) -> BTreeMap<u64, ValueScale> {
+        let mut ranges: BTreeMap<u64, (f64, f64, bool, Option<String>)> = BTreeMap::new();
+
+        let mut extend = |id: u64, values: Vec<f64>, percent: bool, format: Option<String>| {
+            let entry =
+                ranges
+                    .entry(id)
+                    .or_insert((f64::INFINITY, f64::NEG_INFINITY, percent, format));
+            for value in values {
+                entry.0 = entry.0.min(value);
+                entry.1 = entry.1.max(value);
+            }
+        };
+
+        for group in &self.groups {
+            if group.chart_type.is_pie_type() {
+                continue;
+            }
+            let axes: Vec<ChartAxis> = group
+                .axis_ids
+                .iter()
+                .filter_map(|id| self.axis(*id))
+                .collect();
+            let format = group
+                .series
+                .first()
+                .and_then(|s| s.values.as_ref().or(s.y_values.as_ref()))
+                .and_then(|v| v.format_code.clone());
+
+            if group.chart_type == ChartType::Scatter || group.chart_type == ChartType::Bubble {
+                let x_axis = axes.iter().find(|a| !a.position.is_vertical());
+                let y_axis = axes.iter().find(|a| a.position.is_vertical());
+                for series in &group.series {
+                    let (xs, ys) = scatter_values(series);
+                    if let Some(axis) = x_axis {
+                        let format = series.x_values.as_ref().and_then(|v| v.format_code.clone());
+                        extend(axis.id, xs, false, format);
+                    }
+                    if let Some(axis) = y_axis {
+                        extend(
+                            axis.id,
+                            ys.into_iter().flatten().collect(),
+                            false,
+                            format.clone(),
+                        );
+                    }
+                }
+                continue;
+            }
+
+            let Some(value_axis) = axes.iter().find(|a| a.axis_type == AxisType::Value) else {
+                continue;
+            };
+            let percent = group.grouping == ChartGrouping::PercentStacked;
+            let stacks = stacked_values(group, self.display_blanks_as.clone());
+            let values: Vec<f64> = stacks
+                .iter()
+                .flat_map(|s| s.iter().flatten().flat_map(|(base, top)| [*base, *top]))
+                .collect();
+            extend(value_axis.id, values, percent, format);
+        }
+
+        return ranges
+            .into_iter()
+            .map(|(id, (min, max, percent, format))| {
+                let axis = self.axis(id);
+                (
+                    id,
+                    ValueScale::new(min, max, axis.as_ref(), percent, format),
+                )
+            })
+            .collect();
+    }
+
+    fn group_context(
+        &self,
+        group: &ChartGroup,
+        scales: &BTreeMap<u64, ValueScale>,
+        plot: Rect,
+        horizontal: bool,
+        category_count: usize,
+    ) -> Option<GroupContext> {
+        let axes: Vec<ChartAxis> = group
+            .axis_ids
+            .iter()
+            .filter_map(|id| self.axis(*id))
+            .collect();
+
+        if group.chart_type == ChartType::Scatter || group.chart_type == ChartType::Bubble {
+            let y_axis = axes.iter().find(|a| a.position.is_vertical())?;
+            return Some(GroupContext {
+                plot,
+                category_vertical: false,
+                category_count,
+                between: false,
+                category_reversed: false,
+                scale: scales.get(&y_axis.id)?.clone(),
+            });
+        }
+
+        let value_axis = axes.iter().find(|a| a.axis_type == AxisType::Value)?;
+        let category_axis = axes.iter().find(|a| a.axis_type != AxisType::Value);
+        let is_bar = group.chart_type == ChartType::Bar || group.chart_type == ChartType::Bar3D;
+
+        return Some(GroupContext {
+            plot,
+            category_vertical: horizontal,
+            category_count,
+            between: category_axis
+                .map(|a| self.categories_between(a))
+                .unwrap_or(is_bar || value_axis.cross_between),
+            category_reversed: category_axis.map(|a| a.reversed).unwrap_or(false),
+            scale: scales.get(&value_axis.id)?.clone(),
+        });
+    }
+
+    /// Whether points of a category axis are placed between tick marks (in the middle of the category band),
+    /// rather than on them.
+    ///
+    /// Always true for bar charts, otherwise given by the crossing value axis.
+    fn categories_between(&self, category_axis: &ChartAxis) -> bool {
+        let is_bar = self.groups.iter().any(|g| {
+            (g.chart_type == ChartType::Bar || g.chart_type == ChartType::Bar3D)
+                && g.axis_ids.contains(&category_axis.id)
+        });
+        if is_bar {
+            return true;
+        }
+
+        return category_axis
+            .cross_axis_id
+            .and_then(|id| self.axis(id))
+            .map(|a| a.cross_between)
+            .unwrap_or(true);
+    }
+
+    fn render_bars(
+        &self,
+        writer: &mut SvgWriter,
+        labels: &mut Vec<String>,
+        group: &ChartGroup,
+        context: &GroupContext,
+    ) {
+        let stacked = group.grouping == ChartGrouping::Stacked
+            || group.grouping == ChartGrouping::PercentStacked;
+        let bar_count = if stacked {
+            1
+        } else {
+            group.series.len().max(1)
+        } as f64;
+        let overlap = if stacked {
+            1.0
+        } else {
+            group.overlap as f64 / 100.0
+        };
+        let gap = group.gap_width as f64 / 100.0;
+        let band = 1.0 / context.category_count as f64;
+        let bar = band / (1.0 + (bar_count - 1.0) * (1.0 - overlap) + gap);
+        let cluster = bar * (1.0 + (bar_count - 1.0) * (1.0 - overlap));
+
+        let stacks = stacked_values(group, DisplayBlanksAs::Gap);
+
+        for (series_index, series) in group.series.iter().enumerate() {
+            let slot = if stacked { 0.0 } else { series_index as f64 };
+            let categories = category_labels(series);
+            let data_labels = series.data_labels.as_ref().or(group.data_labels.as_ref());
+            let format_code = series.values.as_ref().and_then(|v| v.format_code.clone());
+            let raw_values = series
+                .values
+                .as_ref()
+                .map(|v| v.numbers())
+                .unwrap_or(vec![]);
+
+            for (index, stack) in stacks[series_index].iter().enumerate() {
+                let Some((base, top)) = stack else { continue };
+
+                let mut start =
+                    index as f64 * band + (band - cluster) / 2.0 + slot * bar * (1.0 - overlap);
+                if context.category_reversed {
+                    start = 1.0 - start - bar;
+                }
+                let rect = context.rect(
+                    start,
+                    start + bar,
+                    context.scale.fraction(*base),
+                    context.scale.fraction(*top),
+                );
+
+                let point = series.data_points.iter().find(|p| p.index == index as u64);
+                let invert = point
+                    .and_then(|p| p.invert_if_negative)
+                    .unwrap_or(series.invert_if_negative);
+                let fill = if invert && top < base {
+                    Fill::SolidFill("#ffffffff".to_string())
+                } else {
+                    self.point_fill(group, series, index as u64)
+                };
+                let fill = writer.fill_attributes(&fill);
+                let outline = point
+                    .and_then(|p| p.outline.as_ref())
+                    .or(series.outline.as_ref());
+                let stroke = writer.stroke_attributes(outline, None, 0.75);
+                writer.push(format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="{}"{}{}/>"#,
+                    num(rect.x),
+                    num(rect.y),
+                    num(rect.width),
+                    num(rect.height),
+                    fill,
+                    stroke
+                ));
+
+                let Some(data_labels) = data_labels.filter(|l| l.is_visible()) else {
+                    continue;
+                };
+                let value = raw_values.get(index).cloned().flatten();
+                let text = label_text(
+                    data_labels,
+                    series,
+                    categories.get(index).cloned(),
+                    value,
+                    None,
+                    format_code.clone(),
+                );
+                let default_position = if stacked { "ctr" } else { "outEnd" };
+                let position = data_labels
+                    .position
+                    .clone()
+                    .unwrap_or(default_position.to_string());
+                let positive = top >= base;
+                let (x, y, anchor) =
+                    bar_label_position(&rect, &position, context.category_vertical, positive);
+                labels.push(text_element(x, y, &text, LABEL_FONT_SIZE, anchor, None));
+            }
+        }
+    }
+
+    fn render_lines(
+        &self,
+        writer: &mut SvgWriter,
+        labels: &mut Vec<String>,
+        group: &ChartGroup,
+        context: &GroupContext,
+    ) {
+        let stacks = stacked_values(group, self.display_blanks_as.clone());
+
+        for (series_index, series) in group.series.iter().enumerate() {
+            let points: Vec<Option<(f64, f64)>> = stacks[series_index]
+                .iter()
+                .enumerate()
+                .map(|(index, stack)| {
+                    stack.map(|(_, top)| {
+                        context.point(
+                            context.category_fraction(index as f64),
+                            context.scale.fraction(top),
+                        )
+                    })
+                })
+                .collect();
+
+            let color = self.series_fill(series);
+            if series_has_line(group, series) {
+                render_polyline(
+                    writer,
+                    &points,
+                    &color,
+                    series,
+                    self.display_blanks_as.clone(),
+                );
+            }
+            if group.show_marker {
+                for (index, point) in points.iter().enumerate() {
+                    let Some((x, y)) = point else { continue };
+                    render_marker(writer, self, group, series, index as u64, *x, *y, false);
+                }
+            }
+
+            self.push_point_labels(labels, group, series, &points, "r");
+        }
+    }
+
+    fn render_area(
+        &self,
+        writer: &mut SvgWriter,
+        labels: &mut Vec<String>,
+        group: &ChartGroup,
+        context: &GroupContext,
+    ) {
+        let stacks = stacked_values(group, DisplayBlanksAs::Zero);
+
+        for (series_index, series) in group.series.iter().enumerate() {
+            let stack: Vec<(f64, f64)> = stacks[series_index]
+                .iter()
+                .map(|s| s.unwrap_or((0.0, 0.0)))
+                .collect();
+            if stack.is_empty() {
+                continue;
+            }
+
+            let mut tops: Vec<(f64, f64)> = vec![];
+            let mut bases: Vec<(f64, f64)> = vec![];
+            for (index, (base, top)) in stack.iter().enumerate() {
+                let category = context.category_fraction(index as f64);
+                tops.push(context.point(category, context.scale.fraction(*top)));
+                bases.push(context.point(category, context.scale.fraction(*base)));
+            }
+            bases.reverse();
+
+            let path = tops
+                .iter()
+                .chain(bases.iter())
+                .enumerate()
+                .map(|(i, (x, y))| {
+                    format!("{}{} {}", if i == 0 { "M" } else { "L" }, num(*x), num(*y))
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            let fill = writer.fill_attributes(&self.series_fill(series));
+            let stroke = writer.stroke_attributes(series.outline.as_ref(), None, 0.75);
+            writer.push(format!(r#"<path d="{} Z"{}{}/>"#, path, fill, stroke));
+
+            let points: Vec<Option<(f64, f64)>> = tops
+                .iter()
+                .zip(stack.iter())
+                .map(|(top, (base, value))| {
+                    let middle = context.scale.fraction((base + value) / 2.0);
+                    let (x, y) = *top;
+                    if context.category_vertical {
+                        Some((context.plot.x + context.plot.width * middle, y))
+                    } else {
+                        Some((x, context.plot.y + context.plot.height * (1.0 - middle)))
+                    }
+                })
+                .collect();
+            self.push_point_labels(labels, group, series, &points, "ctr");
+        }
+    }
+
+    fn render_scatter(
+        &self,
+        writer: &mut SvgWriter,
+        labels: &mut Vec<String>,
+        group: &ChartGroup,
+        context: &GroupContext,
+        scales: &BTreeMap<u64, ValueScale>,
+    ) {
+        let x_axis = group
+            .axis_ids
+            .iter()
+            .filter_map(|id| self.axis(*id))
+            .find(|a| !a.position.is_vertical());
+        let Some(x_scale) = x_axis.and_then(|a| scales.get(&a.id)) else {
+            return;
+        };
+        let plot = context.plot;
+
+        let max_bubble = group
+            .series
+            .iter()
+            .flat_map(|s| {
+                s.bubble_sizes
+                    .as_ref()
+                    .map(|b| b.numbers())
+                    .unwrap_or(vec![])
+            })
+            .flatten()
+            .map(f64::abs)
+            .fold(0.0, f64::max);
+
+        let style = group.scatter_style.clone().unwrap_or("marker".to_string());
+
+        for series in &group.series {
+            let (xs, ys) = scatter_values(series);
+            let points: Vec<Option<(f64, f64)>> = xs
+                .iter()
+                .zip(ys.iter())
+                .map(|(x, y)| {
+                    y.map(|y| {
+                        (
+                            plot.x + plot.width * x_scale.fraction(*x),
+                            plot.y + plot.height * (1.0 - context.scale.fraction(y)),
+                        )
+                    })
+                })
+                .collect();
+
+            if group.chart_type == ChartType::Bubble {
+                let sizes = series
+                    .bubble_sizes
+                    .as_ref()
+                    .map(|b| b.numbers())
+                    .unwrap_or(vec![]);
+                let max_radius = plot.width.min(plot.height) / 8.0;
+                for (index, point) in points.iter().enumerate() {
+                    let Some((x, y)) = point else { continue };
+                    let size = sizes.get(index).cloned().flatten().unwrap_or(0.0).abs();
+                    if max_bubble <= 0.0 || size <= 0.0 {
+                        continue;
+                    }
+                    let radius = max_radius * (size / max_bubble).sqrt();
+                    let fill =
+                        writer.fill_attributes(&self.point_fill(group, series, index as u64));
+                    let stroke = writer.stroke_attributes(series.outline.as_ref(), None, 0.75);
+                    writer.push(format!(
+                        r#"<circle cx="{}" cy="{}" r="{}"{}{} fill-opacity="0.75"/>"#,
+                        num(*x),
+                        num(*y),
+                        num(radius),
+                        fill,
+                        stroke
+                    ));
+                }
+                self.push_point_labels(labels, group, series, &points, "ctr");
+                continue;
+            }
+
+            let has_line = style != "marker" && style != "none" && series_has_line(group, series);
+            if has_line {
+                let color = self.series_fill(series);
+                render_polyline(writer, &points, &color, series, DisplayBlanksAs::Gap);
+            }
+
+            let has_marker = !(style == "line" || style == "smooth" || style == "none")
+                || series
+                    .marker
+                    .as_ref()
+                    .map(|m| m.symbol != MarkerSymbol::Auto)
+                    .unwrap_or(false);
+            if has_marker {
+                for (index, point) in points.iter().enumerate() {
+                    let Some((x, y)) = point else { continue };
+                    render_marker(writer, self, group, series, index as u64, *x, *y, true);
+                }
+            }
+
+            self.push_point_labels(labels, group, series, &points, "r");
+        }
+    }
+
+    /// Data labels of line, area, scatter and bubble series.
+    fn push_point_labels(
+        &self,
+        labels: &mut Vec<String>,
+        group: &ChartGroup,
+        series: &ChartSeries,
+        points: &[Option<(f64, f64)>],
+        default_position: &str,
+    ) {
+        let Some(data_labels) = series
+            .data_labels
+            .as_ref()
+            .or(group.data_labels.as_ref())
+            .filter(|l| l.is_visible())
+        else {
+            return;
+        };
+
+        let categories = category_labels(series);
+        let values = series
+            .values
+            .as_ref()
+            .or(series.y_values.as_ref())
+            .map(|v| v.numbers())
+            .unwrap_or(vec![]);
+        let format_code = series
+            .values
+            .as_ref()
+            .or(series.y_values.as_ref())
+            .and_then(|v| v.format_code.clone());
+        let position = data_labels
+            .position
+            .clone()
+            .unwrap_or(default_position.to_string());
+
+        for (index, point) in points.iter().enumerate() {
+            let Some((x, y)) = point else { continue };
+            let text = label_text(
+                data_labels,
+                series,
+                categories.get(index).cloned(),
+                values.get(index).cloned().flatten(),
+                None,
+                format_code.clone(),
+            );
+            let (x, y, anchor) = match position.as_str() {
+                "t" => (*x, y - 6.0, "middle"),
+                "b" => (*x, y + LABEL_FONT_SIZE + 4.0, "middle"),
+                "l" => (x - 6.0, y + LABEL_FONT_SIZE / 3.0, "end"),
+                "ctr" => (*x, y + LABEL_FONT_SIZE / 3.0, "middle"),
+                _ => (x + 6.0, y + LABEL_FONT_SIZE / 3.0, "start"),
+            };
+            labels.push(text_element(x, y, &text, LABEL_FONT_SIZE, anchor, None));
+        }
+    }
+
+    fn render_axis(
+        &self,
+        writer: &mut SvgWriter,
+        axis: &ChartAxis,
+        side: AxisSide,
+        plot: Rect,
+        scales: &BTreeMap<u64, ValueScale>,
+        categories: &[String],
+    ) {
+        let scale = scales.get(&axis.id);
+
+        // axis line: shown by default on category axes only
+        let default_color = if scale.is_none() {
+            Some(LINE_COLOR)
+        } else {
+            None
+        };
+        let stroke = writer.stroke_attributes(axis.outline.as_ref(), default_color, 0.75);
+        if !stroke.contains(r#"stroke="none""#) {
+            let (x1, y1, x2, y2) = match side {
+                AxisSide::Bottom => (
+                    plot.x,
+                    plot.y + plot.height,
+                    plot.x + plot.width,
+                    plot.y + plot.height,
+                ),
+                AxisSide::Top => (plot.x, plot.y, plot.x + plot.width, plot.y),
+                AxisSide::Left => (plot.x, plot.y, plot.x, plot.y + plot.height),
+                AxisSide::Right => (
+                    plot.x + plot.width,
+                    plot.y,
+                    plot.x + plot.width,
+                    plot.y + plot.height,
+                ),
+            };
+            writer.push(line_element(x1, y1, x2, y2, &stroke));
+        }
+
+        let mut label_size = 0.0;
+        if axis.tick_label_position != TickLabelPosition::None {
+            let ticks: Vec<(f64, String)> = match scale {
+                Some(scale) => scale
+                    .ticks()
+                    .into_iter()
+                    .map(|t| (scale.fraction(t), scale.label(t)))
+                    .collect(),
+                None => {
+                    let count = categories.len().max(1) as f64;
+                    categories
+                        .iter()
+                        .enumerate()
+                        .map(|(i, label)| {
+                            let fraction = if self.categories_between(axis) {
+                                (i as f64 + 0.5) / count
+                            } else if count > 1.0 {
+                                i as f64 / (count - 1.0)
+                            } else {
+                                0.5
+                            };
+                            let fraction = if axis.reversed {
+                                1.0 - fraction
+                            } else {
+                                fraction
+                            };
+                            (fraction, label.to_owned())
+                        })
+                        .collect()
+                }
+            };
+
+            // skip category labels that would overlap
+            let length = if side.is_vertical() {
+                plot.height
+            } else {
+                plot.width
+            };
+            let step = if scale.is_none() && !side.is_vertical() && !ticks.is_empty() {
+                let widest = ticks
+                    .iter()
+                    .map(|(_, l)| text_width(l, LABEL_FONT_SIZE) + 4.0)
+                    .fold(0.0, f64::max);
+                ((widest * ticks.len() as f64 / length).ceil() as usize).max(1)
+            } else {
+                1
+            };
+
+            for (fraction, label) in ticks.iter().step_by(step) {
+                let (x, y, anchor) = match side {
+                    AxisSide::Bottom => (
+                        plot.x + plot.width * fraction,
+                        plot.y + plot.height + LABEL_FONT_SIZE + 2.0,
+                        "middle",
+                    ),
+                    AxisSide::Top => (plot.x + plot.width * fraction, plot.y - 4.0, "middle"),
+                    AxisSide::Left => (
+                        plot.x - 4.0,
+                        plot.y + plot.height * (1.0 - fraction) + LABEL_FONT_SIZE / 3.0,
+                        "end",
+                    ),
+                    AxisSide::Right => (
+                        plot.x + plot.width + 4.0,
+                        plot.y + plot.height * (1.0 - fraction) + LABEL_FONT_SIZE / 3.0,
+                        "start",
+                    ),
+                };
+                writer.text(x, y, label, LABEL_FONT_SIZE, anchor, None);
+                label_size = f64::max(
+                    label_size,
+                    if side.is_vertical() {
+                        text_width(label, LABEL_FONT_SIZE) + 6.0
+                    } else {
+                        LABEL_FONT_SIZE * 1.4
+                    },
+                );
+            }
+        }
+
+        let Some(title) = &axis.title else { return };
+        let text = title.text.clone().unwrap_or("Axis Title".to_string());
+        let offset = label_size + LABEL_FONT_SIZE + 4.0;
+        match side {
+            AxisSide::Bottom => writer.text(
+                plot.x + plot.width / 2.0,
+                plot.y + plot.height + offset + 4.0,
+                &text,
+                LABEL_FONT_SIZE,
+                "middle",
+                None,
+            ),
+            AxisSide::Top => writer.text(
+                plot.x + plot.width / 2.0,
+                plot.y - offset,
+                &text,
+                LABEL_FONT_SIZE,
+                "middle",
+                None,
+            ),
+            AxisSide::Left => writer.text(
+                plot.x - offset,
+                plot.y + plot.height / 2.0,
+                &text,
+                LABEL_FONT_SIZE,
+                "middle",
+                Some(-90.0),
+            ),
+            AxisSide::Right => writer.text(
+                plot.x + plot.width + offset,
+                plot.y + plot.height / 2.0,
+                &text,
+                LABEL_FONT_SIZE,
+                "middle",
+                Some(90.0),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AxisSide {
+    Bottom,
+    Left,
+    Right,
+    Top,
+}
+
+impl AxisSide {
+    /// side of the plot area an axis is drawn on
+    fn for_axis(axis: &ChartAxis, horizontal: bool, group: &ChartGroup) -> Self {
+        let is_scatter =
+            group.chart_type == ChartType::Scatter || group.chart_type == ChartType::Bubble;
+        let vertical = if is_scatter {
+            axis.position.is_vertical()
+        } else {
+            // value axis is vertical unless bars are horizontal
+            (axis.axis_type == AxisType::Value) != horizontal
+        };
+
+        let position = axis.position.clone();
+        return match (vertical, position.is_vertical()) {
+            (true, true) => {
+                if position == AxisPosition::Right {
+                    Self::Right
+                } else {
+                    Self::Left
+                }
+            }
+            (true, false) => Self::Left,
+            (false, false) => {
+                if position == AxisPosition::Top {
+                    Self::Top
+                } else {
+                    Self::Bottom
+                }
+            }
+            (false, true) => Self::Bottom,
+        };
+    }
+
+    fn is_vertical(&self) -> bool {
+        return *self == Self::Left || *self == Self::Right;
+    }
+}
+
+/// Geometry shared by the series of a group.
+struct GroupContext {
+    plot: Rect,
+
+    /// Whether categories run along the vertical axis (horizontal bars)
+    category_vertical: bool,
+
+    category_count: usize,
+
+    /// Whether points are placed between tick marks (in the middle of the category band)
+    between: bool,
+
+    category_reversed: bool,
+
+    scale: ValueScale,
+}
+
+impl GroupContext {
+    fn category_fraction(&self, index: f64) -> f64 {
+        let count = self.category_count as f64;
+        let fraction = if self.between {
+            (index + 0.5) / count
+        } else if count > 1.0 {
+            index / (count - 1.0)
+        } else {
+            0.5
+        };
+        return if self.category_reversed {
+            1.0 - fraction
+        } else {
+            fraction
+        };
+    }
+
+    fn point(&self, category: f64, value: f64) -> (f64, f64) {
+        if self.category_vertical {
+            return (
+                self.plot.x + self.plot.width * value,
+                self.plot.y + self.plot.height * (1.0 - category),
+            );
+        }
+        return (
+            self.plot.x + self.plot.width * category,
+            self.plot.y + self.plot.height * (1.0 - value),
+        );
+    }
+
+    fn rect(
+        &self,
+        category_start: f64,
+        category_end: f64,
+        value_start: f64,
+        value_end: f64,
+    ) -> Rect {
+        let (x1, y1) = self.point(category_start, value_start);
+        let (x2, y2) = self.point(category_end, value_end);
+        return Rect {
+            x: x1.min(x2),
+            y: y1.min(y2),
+            width: (x2 - x1).abs(),
+            height: (y2 - y1).abs(),
+        };
+    }
+}
+
+/// Mapping of values to the 0 - 1 range of a value axis.
+#[derive(Debug, Clone)]
+struct ValueScale {
+    min: f64,
+    max: f64,
+    major_unit: f64,
+    log_base: Option<f64>,
+    reversed: bool,
+    format_code: Option<String>,
+}
+
+impl ValueScale {
+    fn new(
+        data_min: f64,
+        data_max: f64,
+        axis: Option<&ChartAxis>,
+        percent: bool,
+        format_code: Option<String>,
+    ) -> Self {
+        let (data_min, data_max) = if data_min.is_finite() && data_max.is_finite() {
+            (data_min, data_max)
+        } else {
+            (0.0, 1.0)
+        };
+        let reversed = axis.map(|a| a.reversed).unwrap_or(false);
+        let format_code = axis
+            .and_then(|a| a.number_format.clone())
+            .filter(|f| !f.source_linked || format_code.is_none())
+            .map(|f| f.format_code)
+            .or(format_code);
+
+        let log_base = axis.and_then(|a| a.log_base).filter(|b| *b > 1.0);
+        if let Some(base) = log_base {
+            // logarithms are only defined for positive values: bounds <= 0 of the file are ignored
+            let low = data_min.max(f64::MIN_POSITIVE);
+            let min = axis
+                .and_then(|a| a.min)
+                .filter(|m| *m > 0.0)
+                .unwrap_or(base.powf(low.log(base).floor()));
+            let max = axis
+                .and_then(|a| a.max)
+                .filter(|m| *m > 0.0)
+                .unwrap_or(base.powf(data_max.max(min).log(base).ceil()));
+            return Self {
+                min,
+                max: if max > min { max } else { min * base },
+                major_unit: base,
+                log_base,
+                reversed,
+                format_code,
+            };
+        }
+
+        if percent {
+            let min = axis.and_then(|a| a.min).unwrap_or(data_min.min(0.0));
+            let max = axis.and_then(|a| a.max).unwrap_or(1.0);
+            return Self {
+                min,
+                max: if max > min { max } else { min + 1.0 },
+                major_unit: axis
+                    .and_then(|a| a.major_unit)
+                    .filter(|u| *u > 0.0)
+                    .unwrap_or(0.1),
+                log_base,
+                reversed,
+                format_code: Some("0%".to_string()),
+            };
+        }
+
+        let mut low = if data_min > 0.0 { 0.0 } else { data_min };
+        let mut high = if data_max < 0.0 { 0.0 } else { data_max };
+        // headroom above the largest value, as Excel does
+        if high > 0.0 {
+            high += (high - low) * 0.05;
+        }
+        if let Some(min) = axis.and_then(|a| a.min) {
+            low = min;
+        }
+        if let Some(max) = axis.and_then(|a| a.max) {
+            high = max;
+        }
+        if high <= low {
+            high = low + 1.0;
+        }
+
+        let major_unit = axis
+            .and_then(|a| a.major_unit)
+            .filter(|u| *u > 0.0)
+            .unwrap_or(nice_step((high - low) / 5.0));
+
+        // explicit bounds are kept, with the maximum above the minimum
+        let min = match axis.and_then(|a| a.min) {
+            Some(_) => low,
+            None => (low / major_unit).floor() * major_unit,
+        };
+        let max = match axis.and_then(|a| a.max) {
+            Some(_) => high,
+            None => (high / major_unit).ceil() * major_unit,
+        };
+
+        return Self {
+            min,
+            max,
+            major_unit,
+            log_base,
+            reversed,
+            format_code,
+        };
+    }
+
+    fn fraction(&self, value: f64) -> f64 {
+        let fraction = match self.log_base {
+            Some(base) => {
+                let value = value.max(self.min);
+                (value.log(base) - self.min.log(base)) / (self.max.log(base) - self.min.log(base))
+            }
+            None => (value - self.min) / (self.max - self.min),
+        };
+        let fraction = fraction.clamp(0.0, 1.0);
+        return if self.reversed {
+            1.0 - fraction
+        } else {
+            fraction
+        };
+    }
+
+    fn ticks(&self) -> Vec<f64> {
+        let mut ticks: Vec<f64> = vec![];
+        if let Some(base) = self.log_base {
+            let mut tick = self.min;
+            while tick <= self.max * (1.0 + 1e-9) && ticks.len() < 100 {
+                ticks.push(tick);
+                tick *= base;
+            }
+            return ticks;
+        }
+
+        let count = ((self.max - self.min) / self.major_unit + 1e-9).floor() as usize;
+        for i in 0..=count.min(100) {
+            ticks.push(self.min + self.major_unit * i as f64);
+        }
+        return ticks;
+    }
+
+    fn label(&self, value: f64) -> String {
+        return format_number(value, self.format_code.as_deref());
+    }
+}
+
+struct LegendEntry {
+    label: String,
+    fill: Fill,
+    is_line: bool,
+}
+
+/// SVG elements, with definitions (gradients) referenced by them.
+struct SvgWriter {
+    definitions: Vec<String>,
+    elements: Vec<String>,
+}
+
+impl SvgWriter {
+    fn new() -> Self {
+        return Self {
+            definitions: vec![],
+            elements: vec![],
+        };
+    }
+
+    fn push(&mut self, element: String) {
+        self.elements.push(element);
+    }
+
+    fn text(&mut self, x: f64, y: f64, text: &str, size: f64, anchor: &str, rotate: Option<f64>) {
+        self.elements
+            .push(text_element(x, y, text, size, anchor, rotate));
+    }
+
+    /// SVG paint of a fill: a color, a gradient reference or none.
+    fn paint(&mut self, fill: &Fill) -> (String, f64) {
+        return match fill {
+            Fill::SolidFill(hex) => svg_color(hex),
+            Fill::NoFill => ("none".to_string(), 1.0),
+            Fill::PatternFill(pattern) => svg_color(&pattern.foreground_color),
+            Fill::BlipFill(_) => ("#bfbfbf".to_string(), 1.0),
+            Fill::GradientFill(gradient) => {
+                let id = format!("gradient{}", self.definitions.len() + 1);
+                let angle = match &gradient.gradient_type {
+                    GradientFillTypeValues::Linear(linear) => linear.angle,
+                    GradientFillTypeValues::Path(_) => 90.0,
+                };
+                let stops: Vec<String> = gradient
+                    .gradient_stops
+                    .iter()
+                    .map(|stop| {
+                        let (color, opacity) = svg_color(&stop.color);
+                        format!(
+                            r#"<stop offset="{}" stop-color="{}" stop-opacity="{}"/>"#,
+                            num(stop.position),
+                            color,
+                            num(opacity)
+                        )
+                    })
+                    .collect();
+                self.definitions.push(format!(
+                    r#"<linearGradient id="{}" gradientTransform="rotate({})">{}</linearGradient>"#,
+                    id,
+                    num(angle),
+                    stops.join("")
+                ));
+                (format!("url(#{})", id), 1.0)
+            }
+        };
+    }
+
+    fn fill_attributes(&mut self, fill: &Fill) -> String {
+        let (paint, opacity) = self.paint(fill);
+        if opacity < 1.0 {
+            return format!(r#" fill="{}" fill-opacity="{}""#, paint, num(opacity));
+        }
+        return format!(r#" fill="{}""#, paint);
+    }
+
+    /// Stroke attributes of an outline.
+    ///
+    /// `default_color` is used if there is no outline. No stroke if both are None.
+    fn stroke_attributes(
+        &mut self,
+        outline: Option<&Outline>,
+        default_color: Option<&str>,
+        default_width: f64,
+    ) -> String {
+        let Some(outline) = outline else {
+            return match default_color {
+                Some(color) => format!(
+                    r#" stroke="{}" stroke-width="{}""#,
+                    color,
+                    num(default_width * PT_TO_PX)
+                ),
+                None => r#" stroke="none""#.to_string(),
+            };
+        };
+
+        let (paint, opacity) = self.paint(&outline.fill);
+        if paint == "none" {
+            return r#" stroke="none""#.to_string();
+        }
+
+        let width = outline.width * PT_TO_PX;
+        let mut attributes = format!(r#" stroke="{}" stroke-width="{}""#, paint, num(width));
+        if opacity < 1.0 {
+            attributes += &format!(r#" stroke-opacity="{}""#, num(opacity));
+        }
+        if let Some(dash) = dash_array(&outline.dash, width) {
+            attributes += &format!(r#" stroke-dasharray="{}""#, dash);
+        }
+        return attributes;
+    }
+
+    fn finish(self, width: f64, height: f64) -> String {
+        let definitions = if self.definitions.is_empty() {
+            String::new()
+        } else {
+            format!("<defs>{}</defs>", self.definitions.join(""))
+        };
+
+        return format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" font-family="{}">{}{}</svg>"#,
+            num(width),
+            num(height),
+            num(width),
+            num(height),
+            FONT_FAMILY,
+            definitions,
+            self.elements.join("")
+        );
+    }
+}
+
+/// Draw the legend and return the area left for the plot.
+fn render_legend(
+    writer: &mut SvgWriter,
+    legend: &Legend,
+    entries: &[LegendEntry],
+    area: Rect,
+) -> Rect {
+    let entry_height = LABEL_FONT_SIZE * 1.5;
+    let swatch = 14.0;
+    let entry_width =
+        |entry: &LegendEntry| swatch + 4.0 + text_width(&entry.label, LABEL_FONT_SIZE);
+
+    let vertical = matches!(
+        legend.position,
+        LegendPosition::Left | LegendPosition::Right | LegendPosition::TopRight
+    );
+
+    // rows of entries
+    let rows: Vec<Vec<&LegendEntry>> = if vertical {
+        entries.iter().map(|e| vec![e]).collect()
+    } else {
+        let mut rows: Vec<Vec<&LegendEntry>> = vec![];
+        let mut current: Vec<&LegendEntry> = vec![];
+        let mut current_width = 0.0;
+        for entry in entries {
+            let width = entry_width(entry) + 12.0;
+            if !current.is_empty() && current_width + width > area.width {
+                rows.push(current);
+                current = vec![];
+                current_width = 0.0;
+            }
+            current.push(entry);
+            current_width += width;
+        }
+        if !current.is_empty() {
+            rows.push(current);
+        }
+        rows
+    };
+
+    let legend_width = if vertical {
+        entries.iter().map(entry_width).fold(0.0, f64::max) + 8.0
+    } else {
+        area.width
+    };
+    let legend_height = rows.len() as f64 * entry_height;
+
+    let (x, y) = match legend.position {
+        LegendPosition::Bottom => (area.x, area.y + area.height - legend_height),
+        LegendPosition::Top => (area.x, area.y),
+        LegendPosition::Left => (area.x, area.y + (area.height - legend_height) / 2.0),
+        LegendPosition::Right => (
+            area.x + area.width - legend_width,
+            area.y + (area.height - legend_height) / 2.0,
+        ),
+        LegendPosition::TopRight => (area.x + area.width - legend_width, area.y),
+    };
+
+    if legend.fill.is_some() || legend.outline.is_some() {
+        let fill = writer.fill_attributes(&legend.fill.clone().unwrap_or(Fill::NoFill));
+        let stroke = writer.stroke_attributes(legend.outline.as_ref(), None, 0.75);
+        writer.push(format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}"{}{}/>"#,
+            num(x),
+            num(y),
+            num(legend_width),
+            num(legend_height),
+            fill,
+            stroke
+        ));
+    }
+
+    for (row_index, row) in rows.iter().enumerate() {
+        let row_width: f64 = row.iter().map(|e| entry_width(e) + 12.0).sum::<f64>() - 12.0;
+        let mut entry_x = if vertical {
+            x + 4.0
+        } else {
+            x + (legend_width - row_width) / 2.0
+        };
+        let middle = y + entry_height * (row_index as f64 + 0.5);
+
+        for entry in row {
+            if entry.is_line {
+                let (paint, _) = writer.paint(&entry.fill);
+                writer.push(line_element(
+                    entry_x,
+                    middle,
+                    entry_x + swatch,
+                    middle,
+                    &format!(r#" stroke="{}" stroke-width="3""#, paint),
+                ));
+            } else {
+                let fill = writer.fill_attributes(&entry.fill);
+                writer.push(format!(
+                    r#"<rect x="{}" y="{}" width="7" height="7"{}/>"#,
+                    num(entry_x + (swatch - 7.0) / 2.0),
+                    num(middle - 3.5),
+                    fill
+                ));
+            }
+            writer.text(
+                entry_x + swatch + 4.0,
+                middle + LABEL_FONT_SIZE / 3.0,
+                &entry.label,
+                LABEL_FONT_SIZE,
+                "start",
+                None,
+            );
+            entry_x += entry_width(entry) + 12.0;
+        }
+    }
+
+    if legend.overlay {
+        return area;
+    }
+
+    let mut area = area;
+    match legend.position {
+        LegendPosition::Bottom => area.height -= legend_height + 4.0,
+        LegendPosition::Top => {
+            area.y += legend_height + 4.0;
+            area.height -= legend_height + 4.0;
+        }
+        LegendPosition::Left => {
+            area.x += legend_width + 4.0;
+            area.width -= legend_width + 4.0;
+        }
+        LegendPosition::Right | LegendPosition::TopRight => area.width -= legend_width + 4.0,
+    }
+    return area;
+}
+
+/// Line through the points of a series.
+fn render_polyline(
+    writer: &mut SvgWriter,
+    points: &[Option<(f64, f64)>],
+    color: &Fill,
+    series: &ChartSeries,
+    blanks: DisplayBlanksAs,
+) {
+    // segments of consecutive points
+    let mut segments: Vec<Vec<(f64, f64)>> = vec![vec![]];
+    for point in points {
+        match point {
+            Some(point) => segments.last_mut().unwrap().push(*point),
+            None => {
+                if blanks == DisplayBlanksAs::Gap {
+                    segments.push(vec![]);
+                }
+            }
+        }
+    }
+
+    let mut outline = series.outline.clone();
+    if let Some(outline) = outline.as_mut() {
+        if outline.fill == Fill::NoFill {
+            return;
+        }
+        outline.fill = color.clone();
+    }
+    let stroke = match outline.as_ref() {
+        Some(outline) => writer.stroke_attributes(Some(outline), None, 2.25),
+        None => {
+            let (paint, _) = writer.paint(color);
+            format!(r#" stroke="{}" stroke-width="3""#, paint)
+        }
+    };
+
+    for segment in segments.into_iter().filter(|s| s.len() > 1) {
+        let path = if series.smooth {
+            smooth_path(&segment)
+        } else {
+            segment
+                .iter()
+                .enumerate()
+                .map(|(i, (x, y))| {
+                    format!("{}{} {}", if i == 0 { "M" } else { "L" }, num(*x), num(*y))
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        writer.push(format!(
+            r#"<path d="{}" fill="none" stroke-linejoin="round" stroke-linecap="round"{}/>"#,
+            path, stroke
+        ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_marker(
+    writer: &mut SvgWriter,
+    chart: &Chart,
+    group: &ChartGroup,
+    series: &ChartSeries,
+    index: u64,
+    x: f64,
+    y: f64,
+    is_scatter: bool,
+) {
+    let point_marker = series
+        .data_points
+        .iter()
+        .find(|p| p.index == index)
+        .and_then(|p| p.marker.clone());
+    let marker = point_marker.or(series.marker.clone());
+
+    let symbol = marker
+        .as_ref()
+        .map(|m| m.symbol.clone())
+        .unwrap_or(MarkerSymbol::Auto);
+    let symbol = match symbol {
+        MarkerSymbol::None => return,
+        // automatic markers cycle through the symbols
+        MarkerSymbol::Auto => match series.index % 9 {
+            0 => MarkerSymbol::Circle,
+            1 => MarkerSymbol::Square,
+            2 => MarkerSymbol::Diamond,
+            3 => MarkerSymbol::Triangle,
+            4 => MarkerSymbol::X,
+            5 => MarkerSymbol::Star,
+            6 => MarkerSymbol::Dot,
+            7 => MarkerSymbol::Dash,
+            _ => MarkerSymbol::Plus,
+        },
+        symbol => symbol,
+    };
+    let symbol = if is_scatter && marker.is_none() {
+        MarkerSymbol::Circle
+    } else {
+        symbol
+    };
+
+    let size = marker.as_ref().and_then(|m| m.size).unwrap_or(5) as f64 * PT_TO_PX;
+    let half = size / 2.0;
+
+    let color = chart.point_fill(group, series, index);
+    let fill = marker
+        .as_ref()
+        .and_then(|m| m.fill.clone())
+        .unwrap_or(color.clone());
+    let fill = writer.fill_attributes(&fill);
+    let stroke = match marker.as_ref().and_then(|m| m.outline.as_ref()) {
+        Some(outline) => writer.stroke_attributes(Some(outline), None, 0.75),
+        None => {
+            let (paint, _) = writer.paint(&color);
+            format!(r#" stroke="{}" stroke-width="1""#, paint)
+        }
+    };
+
+    let element = match symbol {
+        MarkerSymbol::Circle | MarkerSymbol::Picture => format!(
+            r#"<circle cx="{}" cy="{}" r="{}"{}{}/>"#,
+            num(x),
+            num(y),
+            num(half),
+            fill,
+            stroke
+        ),
+        MarkerSymbol::Dot => format!(
+            r#"<circle cx="{}" cy="{}" r="{}"{}{}/>"#,
+            num(x),
+            num(y),
+            num(half / 2.0),
+            fill,
+            stroke
+        ),
+        MarkerSymbol::Square => format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}"{}{}/>"#,
+            num(x - half),
+            num(y - half),
+            num(size),
+            num(size),
+            fill,
+            stroke
+        ),
+        MarkerSymbol::Dash => format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}"{}{}/>"#,
+            num(x - half),
+            num(y - size / 8.0),
+            num(size),
+            num(size / 4.0),
+            fill,
+            stroke
+        ),
+        MarkerSymbol::Diamond => polygon(
+            &[(x, y - half), (x + half, y), (x, y + half), (x - half, y)],
+            &fill,
+            &stroke,
+        ),
+        MarkerSymbol::Triangle => polygon(
+            &[(x, y - half), (x + half, y + half), (x - half, y + half)],
+            &fill,
+            &stroke,
+        ),
+        MarkerSymbol::Star => {
+            let points: Vec<(f64, f64)> = (0..10)
+                .map(|i| {
+                    let radius = if i % 2 == 0 { half } else { half / 2.5 };
+                    polar(x, y, radius, i as f64 * 36.0)
+                })
+                .collect();
+            polygon(&points, &fill, &stroke)
+        }
+        MarkerSymbol::X => format!(
+            r#"<path d="M{} {} L{} {} M{} {} L{} {}" fill="none"{}/>"#,
+            num(x - half),
+            num(y - half),
+            num(x + half),
+            num(y + half),
+            num(x - half),
+            num(y + half),
+            num(x + half),
+            num(y - half),
+            stroke
+        ),
+        _ => format!(
+            r#"<path d="M{} {} L{} {} M{} {} L{} {}" fill="none"{}/>"#,
+            num(x - half),
+            num(y),
+            num(x + half),
+            num(y),
+            num(x),
+            num(y - half),
+            num(x),
+            num(y + half),
+            stroke
+        ),
+    };
+    writer.push(element);
+}
+
+/// Base and top of each point of each series of a group, in axis units.
+///
+/// Stacked groups accumulate positive and negative values separately,
+/// percent stacked groups are normalized to 0 - 1.
+///
+/// None for blank points, unless blanks are displayed as zero.
+fn stacked_values(group: &ChartGroup, blanks: DisplayBlanksAs) -> Vec<Vec<Option<(f64, f64)>>> {
+    let values: Vec<Vec<Option<f64>>> = group
+        .series
+        .iter()
+        .map(|s| {
+            s.values
+                .as_ref()
+                .map(|v| v.numbers())
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|v| match blanks {
+                    DisplayBlanksAs::Zero => Some(v.unwrap_or(0.0)),
+                    _ => v,
+                })
+                .collect()
+        })
+        .collect();
+
+    let count = values.iter().map(|v| v.len()).max().unwrap_or(0);
+    let stacked =
+        group.grouping == ChartGrouping::Stacked || group.grouping == ChartGrouping::PercentStacked;
+    let percent = group.grouping == ChartGrouping::PercentStacked;
+
+    if !stacked {
+        return values
+            .into_iter()
+            .map(|series| series.into_iter().map(|v| v.map(|v| (0.0, v))).collect())
+            .collect();
+    }
+
+    let totals: Vec<f64> = (0..count)
+        .map(|i| {
+            values
+                .iter()
+                .map(|s| s.get(i).cloned().flatten().unwrap_or(0.0).abs())
+                .sum()
+        })
+        .collect();
+
+    let mut positive = vec![0.0; count];
+    let mut negative = vec![0.0; count];
+    let mut stacks: Vec<Vec<Option<(f64, f64)>>> = vec![];
+    for series in values {
+        let mut stack: Vec<Option<(f64, f64)>> = vec![];
+        for (i, value) in series.into_iter().enumerate() {
+            let Some(value) = value else {
+                stack.push(None);
+                continue;
+            };
+            let value = if percent && totals[i] > 0.0 {
+                value / totals[i]
+            } else {
+                value
+            };
+            let accumulator = if value >= 0.0 {
+                &mut positive[i]
+            } else {
+                &mut negative[i]
+            };
+            let base = *accumulator;
+            *accumulator += value;
+            stack.push(Some((base, *accumulator)));
+        }
+        stacks.push(stack);
+    }
+    return stacks;
+}
+
+/// X values (point numbers if the x values are not numeric) and y values of a scatter series
+fn scatter_values(series: &ChartSeries) -> (Vec<f64>, Vec<Option<f64>>) {
+    let ys = series
+        .y_values
+        .as_ref()
+        .or(series.values.as_ref())
+        .map(|v| v.numbers())
+        .unwrap_or(vec![]);
+    let xs = series
+        .x_values
+        .as_ref()
+        .map(|v| v.numbers())
+        .unwrap_or(vec![]);
+
+    let numeric = !xs.is_empty() && xs.iter().all(|x| x.is_some());
+    let xs: Vec<f64> = (0..ys.len())
+        .map(|i| {
+            if numeric {
+                xs.get(i).cloned().flatten().unwrap_or(0.0)
+            } else {
+                (i + 1) as f64
+            }
+        })
+        .collect();
+
+    return (xs, ys);
+}
+
+/// Whether the series is drawn with a line: line and scatter series without a `noFill` line
+fn series_has_line(group: &ChartGroup, series: &ChartSeries) -> bool {
+    if !group.chart_type.is_line_type() {
+        return false;
+    }
+    return series
+        .outline
+        .as_ref()
+        .map(|o| o.fill != Fill::NoFill)
+        .unwrap_or(true);
+}
+
+fn series_name(series: &ChartSeries) -> String {
+    return series
+        .name
+        .clone()
+        .unwrap_or(format!("Series{}", series.index + 1));
+}
+
+fn category_labels(series: &ChartSeries) -> Vec<String> {
+    let Some(categories) = &series.categories else {
+        let count = series.values.as_ref().map(|v| v.values.len()).unwrap_or(0);
+        return (1..=count).map(|i| i.to_string()).collect();
+    };
+
+    return categories
+        .values
+        .iter()
+        .map(|v| match v {
+            Some(ChartValue::Number(n)) => format_number(*n, categories.format_code.as_deref()),
+            Some(ChartValue::Text(t)) => t.to_owned(),
+            None => String::new(),
+        })
+        .collect();
+}
+
+/// Text of a data label
+fn label_text(
+    labels: &DataLabels,
+    series: &ChartSeries,
+    category: Option<String>,
+    value: Option<f64>,
+    percent: Option<f64>,
+    format_code: Option<String>,
+) -> String {
+    let format_code = labels
+        .number_format
+        .clone()
+        .filter(|f| !f.source_linked || format_code.is_none())
+        .map(|f| f.format_code)
+        .or(format_code);
+
+    let mut parts: Vec<String> = vec![];
+    if labels.show_series_name {
+        parts.push(series_name(series));
+    }
+    if labels.show_category_name {
+        parts.push(category.unwrap_or_default());
+    }
+    if labels.show_value {
+        if let Some(value) = value {
+            parts.push(format_number(value, format_code.as_deref()));
+        }
+    }
+    if labels.show_percent {
+        if let Some(percent) = percent {
+            parts.push(format_number(percent, Some("0%")));
+        }
+    }
+
+    return parts.join(&labels.separator.clone().unwrap_or(", ".to_string()));
+}
+
+fn bar_label_position(
+    rect: &Rect,
+    position: &str,
+    category_vertical: bool,
+    positive: bool,
+) -> (f64, f64, &'static str) {
+    let center_x = rect.x + rect.width / 2.0;
+    let center_y = rect.y + rect.height / 2.0 + LABEL_FONT_SIZE / 3.0;
+
+    if category_vertical {
+        let (end, base) = if positive {
+            (rect.x + rect.width, rect.x)
+        } else {
+            (rect.x, rect.x + rect.width)
+        };
+        let direction = if positive { 1.0 } else { -1.0 };
+        let anchor_out = if positive { "start" } else { "end" };
+        let anchor_in = if positive { "end" } else { "start" };
+        return match position {
+            "outEnd" => (end + 4.0 * direction, center_y, anchor_out),
+            "inEnd" => (end - 4.0 * direction, center_y, anchor_in),
+            "inBase" => (base + 4.0 * direction, center_y, anchor_out),
+            _ => (center_x, center_y, "middle"),
+        };
+    }
+
+    let (end, base) = if positive {
+        (rect.y, rect.y + rect.height)
+    } else {
+        (rect.y + rect.height, rect.y)
+    };
+    return match position {
+        "outEnd" if positive => (center_x, end - 4.0, "middle"),
+        "outEnd" => (center_x, end + LABEL_FONT_SIZE + 2.0, "middle"),
+        "inEnd" if positive => (center_x, end + LABEL_FONT_SIZE + 2.0, "middle"),
+        "inEnd" => (center_x, end - 4.0, "middle"),
+        "inBase" if positive => (center_x, base - 4.0, "middle"),
+        "inBase" => (center_x, base + LABEL_FONT_SIZE + 2.0, "middle"),
+        _ => (center_x, center_y, "middle"),
+    };
+}
+
+/// Format a number with a (simplified) Excel number format code.
+///
+/// Supports decimals, thousands separators, percent and literal prefix / suffix.
+fn format_number(value: f64, format_code: Option<&str>) -> String {
+    let code = format_code.unwrap_or("General");
+    let section = code.split(';').next().unwrap_or("");
+    if section.is_empty() || section.eq_ignore_ascii_case("general") {
+        return format_general(value);
+    }
+
+    // remove colors, conditions, escapes and quotes
+    let mut cleaned = String::new();
+    let mut in_bracket = false;
+    for c in section.chars() {
+        match c {
+            '[' => in_bracket = true,
+            ']' => in_bracket = false,
+            '"' | '\\' | '_' | '*' => {}
+            _ if !in_bracket => cleaned.push(c),
+            _ => {}
+        }
+    }
+
+    let Some(first) = cleaned.find(['0', '#', '?']) else {
+        return format_general(value);
+    };
+    let last = cleaned.rfind(['0', '#', '?']).unwrap_or(first);
+    let prefix = &cleaned[..first];
+    let pattern = &cleaned[first..=last];
+    let suffix = &cleaned[last + 1..];
+
+    let percent = cleaned.contains('%');
+    let value = if percent { value * 100.0 } else { value };
+    let decimals = pattern
+        .split_once('.')
+        .map(|(_, d)| d.chars().filter(|c| matches!(c, '0' | '#' | '?')).count())
+        .unwrap_or(0);
+    let thousands = pattern.contains(',');
+
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = formatted
+        .split_once('.')
+        .map(|(i, f)| (i.to_string(), Some(f.to_string())))
+        .unwrap_or((formatted.clone(), None));
+    let integer = if thousands {
+        group_thousands(&integer)
+    } else {
+        integer
+    };
+
+    let mut number = integer;
+    if let Some(fraction) = fraction {
+        number = format!("{}.{}", number, fraction);
+    }
+    let sign = if value < 0.0 && formatted.chars().any(|c| c != '0' && c != '.') {
+        "-"
+    } else {
+        ""
+    };
+
+    return format!("{}{}{}{}", sign, prefix, number, suffix);
+}
+
+fn format_general(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{}", value as i64);
+    }
+    let formatted = format!("{:.9}", value);
+    return formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string();
+}
+
+fn group_thousands(integer: &str) -> String {
+    let digits: Vec<char> = integer.chars().collect();
+    let mut grouped = String::new();
+    for (i, c) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(*c);
+    }
+    return grouped;
+}
+
+/// 1, 2 or 5 times a power of ten, at least `rough`
+fn nice_step(rough: f64) -> f64 {
+    if rough <= 0.0 || !rough.is_finite() {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(rough.log10().floor());
+    let normalized = rough / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    return nice * magnitude;
+}
+
+/// svg color and opacity of a `#rrggbbaa` hex color
+fn svg_color(hex: &str) -> (String, f64) {
+    let Ok((r, g, b, a)) = hex_to_rgba(hex, None) else {
+        return ("#000000".to_string(), 1.0);
+    };
+    return (format!("#{:02x}{:02x}{:02x}", r, g, b), a);
+}
+
+fn dash_array(dash: &LineDashTypeValues, width: f64) -> Option<String> {
+    let pattern: Vec<f64> = match dash {
+        LineDashTypeValues::CustomDash(stops) => {
+            if stops.is_empty() {
+                return None;
+            }
+            stops
+                .iter()
+                .flat_map(|s| [s.dash_length, s.space_length])
+                .collect()
+        }
+        LineDashTypeValues::PresetDash(preset) => match preset {
+            PresetLineDashValues::Solid => return None,
+            PresetLineDashValues::Dot | PresetLineDashValues::SystemDot => vec![1.0, 1.0],
+            PresetLineDashValues::Dash | PresetLineDashValues::SystemDash => vec![4.0, 3.0],
+            PresetLineDashValues::LargeDash => vec![8.0, 3.0],
+            PresetLineDashValues::DashDot | PresetLineDashValues::SystemDashDot => {
+                vec![4.0, 3.0, 1.0, 3.0]
+            }
+            PresetLineDashValues::LargeDashDot => vec![8.0, 3.0, 1.0, 3.0],
+            PresetLineDashValues::LargeDashDotDot | PresetLineDashValues::SystemDashDotDot => {
+                vec![8.0, 3.0, 1.0, 3.0, 1.0, 3.0]
+            }
+        },
+    };
+
+    return Some(
+        pattern
+            .iter()
+            .map(|p| num(p * width))
+            .collect::<Vec<String>>()
+            .join(" "),
+    );
+}
+
+/// Point at `radius` from the center, `angle` degrees clockwise from 12 o'clock
+fn polar(center_x: f64, center_y: f64, radius: f64, angle: f64) -> (f64, f64) {
+    let radians = angle * PI / 180.0;
+    return (
+        center_x + radius * radians.sin(),
+        center_y - radius * radians.cos(),
+    );
+}
+
+/// Path of a pie slice (`inner` 0) or a doughnut segment
+fn slice_path(
+    center_x: f64,
+    center_y: f64,
+    inner: f64,
+    outer: f64,
+    start: f64,
+    end: f64,
+) -> String {
+    // a full circle can not be drawn with a single arc
+    if end - start >= 359.999 {
+        let mut path = format!(
+            "M{} {} A{} {} 0 1 1 {} {} A{} {} 0 1 1 {} {} Z",
+            num(center_x),
+            num(center_y - outer),
+            num(outer),
+            num(outer),
+            num(center_x),
+            num(center_y + outer),
+            num(outer),
+            num(outer),
+            num(center_x),
+            num(center_y - outer)
+        );
+        if inner > 0.0 {
+            path += &format!(
+                " M{} {} A{} {} 0 1 0 {} {} A{} {} 0 1 0 {} {} Z",
+                num(center_x),
+                num(center_y - inner),
+                num(inner),
+                num(inner),
+                num(center_x),
+                num(center_y + inner),
+                num(inner),
+                num(inner),
+                num(center_x),
+                num(center_y - inner)
+            );
+        }
+        return path;
+    }
+
+    let large_arc = if end - start > 180.0 { 1 } else { 0 };
+    let (outer_start_x, outer_start_y) = polar(center_x, center_y, outer, start);
+    let (outer_end_x, outer_end_y) = polar(center_x, center_y, outer, end);
+    let mut path = format!(
+        "M{} {} A{} {} 0 {} 1 {} {}",
+        num(outer_start_x),
+        num(outer_start_y),
+        num(outer),
+        num(outer),
+        large_arc,
+        num(outer_end_x),
+        num(outer_end_y)
+    );
+
+    if inner > 0.0 {
+        let (inner_end_x, inner_end_y) = polar(center_x, center_y, inner, end);
+        let (inner_start_x, inner_start_y) = polar(center_x, center_y, inner, start);
+        path += &format!(
+            " L{} {} A{} {} 0 {} 0 {} {} Z",
+            num(inner_end_x),
+            num(inner_end_y),
+            num(inner),
+            num(inner),
+            large_arc,
+            num(inner_start_x),
+            num(inner_start_y)
+        );
+    } else {
+        path += &format!(" L{} {} Z", num(center_x), num(center_y));
+    }
+    return path;
+}
+
+/// Cubic bezier path through the points (Catmull-Rom spline)
+fn smooth_path(points: &[(f64, f64)]) -> String {
+    let mut path = format!("M{} {}", num(points[0].0), num(points[0].1));
+    for i in 0..points.len() - 1 {
+        let p0 = if i == 0 { points[0] } else { points[i - 1] };
+        let p1 = points[i];
+        let p2 = points[i + 1];
+        let p3 = if i + 2 < points.len() {
+            points[i + 2]
+        } else {
+            p2
+        };
+        let control1 = (p1.0 + (p2.0 - p0.0) / 6.0, p1.1 + (p2.1 - p0.1) / 6.0);
+        let control2 = (p2.0 - (p3.0 - p1.0) / 6.0, p2.1 - (p3.1 - p1.1) / 6.0);
+        path += &format!(
+            " C{} {} {} {} {} {}",
+            num(control1.0),
+            num(control1.1),
+            num(control2.0),
+            num(control2.1),
+            num(p2.0),
+            num(p2.1)
+        );
+    }
+    return path;
+}
+
+fn polygon(points: &[(f64, f64)], fill: &str, stroke: &str) -> String {
+    let points: Vec<String> = points
+        .iter()
+        .map(|(x, y)| format!("{},{}", num(*x), num(*y)))
+        .collect();
+    return format!(
+        r#"<polygon points="{}"{}{}/>"#,
+        points.join(" "),
+        fill,
+        stroke
+    );
+}
+
+fn line_element(x1: f64, y1: f64, x2: f64, y2: f64, stroke: &str) -> String {
+    return format!(
+        r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+        num(x1),
+        num(y1),
+        num(x2),
+        num(y2),
+        stroke
+    );
+}
+
+fn text_element(
+    x: f64,
+    y: f64,
+    text: &str,
+    size: f64,
+    anchor: &str,
+    rotate: Option<f64>,
+) -> String {
+    let transform = match rotate {
+        Some(angle) => format!(
+            r#" transform="rotate({} {} {})""#,
+            num(angle),
+            num(x),
+            num(y)
+        ),
+        None => String::new(),
+    };
+    return format!(
+        r#"<text x="{}" y="{}" font-size="{}" fill="{}" text-anchor="{}"{}>{}</text>"#,
+        num(x),
+        num(y),
+        num(size),
+        TEXT_COLOR,
+        anchor,
+        transform,
+        escape(text)
+    );
+}
+
+/// Approximate width of a text in pixels
+fn text_width(text: &str, size: f64) -> f64 {
+    return text.chars().count() as f64 * size * 0.55;
+}
+
+fn escape(text: &str) -> String {
+    return text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
+
+/// number with at most 2 decimals
+fn num(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == 0.0 {
+        return "0".to_string();
+    }
+    return format!("{}", rounded);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processed::drawing::chart::axis::{AxisCrosses, TickMark};
+
+    fn value_axis(
+        min: Option<f64>,
+        max: Option<f64>,
+        log_base: Option<f64>,
+        major_unit: Option<f64>,
+    ) -> ChartAxis {
+        return ChartAxis {
+            axis_type: AxisType::Value,
+            id: 1,
+            cross_axis_id: None,
+            position: AxisPosition::default(),
+            deleted: false,
+            reversed: false,
+            min,
+            max,
+            log_base,
+            major_unit,
+            minor_unit: None,
+            major_gridlines: None,
+            minor_gridlines: None,
+            number_format: None,
+            major_tick_mark: TickMark::default(),
+            minor_tick_mark: TickMark::default(),
+            tick_label_position: TickLabelPosition::default(),
+            crosses: AxisCrosses::AutoZero,
+            cross_between: true,
+            title: None,
+            outline: None,
+            text_properties: None,
+        };
+    }
+
+    fn assert_finite(scale: &ValueScale) {
+        for value in [-10.0, 0.0, 0.5, 1.0, 50.0, 1000.0] {
+            let fraction = scale.fraction(value);
+            assert!(
+                fraction.is_finite() && (0.0..=1.0).contains(&fraction),
+                "{} -> {}",
+                value,
+                fraction
+            );
+        }
+    }
+
+    #[test]
+    fn log_scale_ignores_bounds_that_are_not_positive() {
+        let axis = value_axis(Some(0.0), Some(-5.0), Some(10.0), None);
+        let scale = ValueScale::new(2.0, 500.0, Some(&axis), false, None);
+
+        assert_eq!((scale.min, scale.max), (1.0, 1000.0));
+        assert_finite(&scale);
+        assert_eq!(scale.ticks(), vec![1.0, 10.0, 100.0, 1000.0]);
+    }
+
+    #[test]
+    fn percent_scale_with_invalid_unit_and_bounds() {
+        let axis = value_axis(Some(0.5), Some(0.5), None, Some(0.0));
+        let scale = ValueScale::new(0.0, 1.0, Some(&axis), true, None);
+
+        assert_eq!(scale.major_unit, 0.1);
+        assert!(scale.max > scale.min);
+        assert_finite(&scale);
+        assert!(scale.ticks().len() <= 101);
+    }
+
+    #[test]
+    fn linear_scale_with_maximum_below_minimum() {
+        let axis = value_axis(Some(10.0), Some(5.0), None, Some(-1.0));
+        let scale = ValueScale::new(0.0, 20.0, Some(&axis), false, None);
+
+        assert_eq!(scale.min, 10.0);
+        assert!(scale.max > scale.min);
+        assert!(scale.major_unit > 0.0);
+        assert_finite(&scale);
+    }
+}
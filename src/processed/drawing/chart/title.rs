@@ -0,0 +1,80 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::drawing::{
+        fill::Fill, line::outline::Outline, text::shape_text_body::ShapeTextBody,
+    },
+    raw::drawing::{
+        chart::title::XlsxChartTitle, scheme::color_scheme::XlsxColorScheme,
+        text::shape_text_body::XlsxShapeTextBody,
+    },
+};
+
+use super::{
+    data_reference::ChartDataReference, shape_fill, shape_outline, text_body, text_body_plain_text,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.title?view=openxml-3.0.1
+///
+/// Title of a chart or an axis.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartTitle {
+    /// Plain text of the title.
+    ///
+    /// None if the title is generated automatically (ex: series name for a single series chart).
+    pub text: Option<String>,
+
+    /// Cell reference the title text comes from.
+    pub text_reference: Option<ChartDataReference>,
+
+    /// Formatted title text
+    pub rich_text: Option<ShapeTextBody>,
+
+    /// Default text properties
+    pub text_properties: Option<ShapeTextBody>,
+
+    /// Whether the title is allowed to overlap the plot area.
+    pub overlay: bool,
+
+    pub fill: Option<Fill>,
+
+    pub outline: Option<Outline>,
+}
+
+impl ChartTitle {
+    pub(crate) fn from_raw(
+        raw: Option<XlsxChartTitle>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+
+        let mut text: Option<String> = None;
+        let mut text_reference: Option<ChartDataReference> = None;
+        let mut rich_text: Option<XlsxShapeTextBody> = None;
+
+        if let Some(tx) = raw.text {
+            if let Some(rich) = tx.rich_text {
+                text = Some(text_body_plain_text(&rich));
+                rich_text = Some(rich);
+            } else if let Some(reference) = tx.string_reference {
+                let reference = ChartDataReference::from_string_reference(reference);
+                text = Some(reference.texts().join(" "));
+                text_reference = Some(reference);
+            } else {
+                text = tx.value;
+            }
+        }
+
+        return Some(Self {
+            text,
+            text_reference,
+            rich_text: text_body(rich_text, color_scheme.clone()),
+            text_properties: text_body(raw.text_properties, color_scheme.clone()),
+            overlay: raw.overlay.unwrap_or(false),
+            fill: shape_fill(raw.shape_properties.clone(), color_scheme.clone()),
+            outline: shape_outline(raw.shape_properties, color_scheme),
+        });
+    }
+}
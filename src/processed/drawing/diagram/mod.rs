@@ -0,0 +1,338 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use crate::{
+    processed::drawing::worksheet_drawing::{
+        content_type::DrawingContentType, spreadsheet_shape::Shape,
+    },
+    raw::{
+        drawing::{
+            diagram::{
+                data_model::{XlsxDiagramDataModel, XlsxDiagramPoint},
+                definition::XlsxDiagramDefinition,
+                XlsxDiagrams,
+            },
+            graphic::graphic_data::XlsxGraphicData,
+            scheme::color_scheme::XlsxColorScheme,
+            text::{paragraph::text_paragraphs::XlsxRunType, shape_text_body::XlsxShapeTextBody},
+            theme::XlsxTheme,
+        },
+        spreadsheet::workbook::defined_name::XlsxDefinedNames,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.relationshipids?view=openxml-3.0.1
+///
+/// Diagram (SmartArt) displayed in a graphic frame, such as an organization chart.
+///
+/// The content is read from the data model (xl/diagrams/data{}.xml) and the shapes from the drawing last rendered by the application (xl/diagrams/drawing{}.xml).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Diagram {
+    /// Layout definition (xl/diagrams/layout{}.xml). Ex: `urn:microsoft.com/office/officeart/2005/8/layout/orgChart1`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub layout: Option<DiagramDefinition>,
+
+    /// Quick style definition (xl/diagrams/quickStyle{}.xml)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub quick_style: Option<DiagramDefinition>,
+
+    /// Colors definition (xl/diagrams/colors{}.xml)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub colors: Option<DiagramDefinition>,
+
+    /// Top level nodes of the hierarchy, in order. Ex: the head of an organization chart
+    pub nodes: Vec<DiagramNode>,
+
+    /// Pre-rendered shapes, positioned relatively to the graphic frame.
+    ///
+    /// Empty if the drawing part was not saved with the diagram.
+    pub shapes: Vec<Shape>,
+}
+
+impl Diagram {
+    /// None if the graphic data does not reference a diagram data model
+    pub(crate) fn from_raw(
+        graphic_data: &XlsxGraphicData,
+        diagrams: &XlsxDiagrams,
+        defined_names: XlsxDefinedNames,
+        color_scheme: Option<XlsxColorScheme>,
+        theme: Option<Box<XlsxTheme>>,
+    ) -> Option<Self> {
+        let data_model = diagrams
+            .data_models
+            .get(graphic_data.diagram_data_id.as_ref()?)?;
+
+        let document = data_model
+            .points
+            .iter()
+            .find(|p| p.r#type.as_deref() == Some("doc"));
+
+        let definition = |id: &Option<String>,
+                          definitions: &BTreeMap<String, XlsxDiagramDefinition>,
+                          type_id: Option<String>| {
+            DiagramDefinition::from_raw(id.as_ref().and_then(|id| definitions.get(id)), type_id)
+        };
+
+        let drawing_id = data_model.drawing_id.as_ref();
+        let raw_shapes = drawing_id
+            .and_then(|id| diagrams.drawings.get(id))
+            .map(|d| d.shapes.clone())
+            .unwrap_or(vec![]);
+        // images of blip fills are referenced by the relationships of the diagram drawing
+        let drawing_relationships = drawing_id
+            .and_then(|id| diagrams.drawing_relationships.get(id))
+            .cloned()
+            .unwrap_or(vec![]);
+        let image_bytes = drawing_id
+            .and_then(|id| diagrams.drawing_image_bytes.get(id))
+            .cloned()
+            .unwrap_or(BTreeMap::new());
+        let shape_model_ids: Vec<Option<String>> =
+            raw_shapes.iter().map(|s| s.model_id.clone()).collect();
+        let shapes: Vec<Shape> = raw_shapes
+            .into_iter()
+            .map(|s| {
+                let references = DrawingContentType::get_references(
+                    s.shape.shape_style.clone(),
+                    color_scheme.clone(),
+                    theme.clone(),
+                );
+                Shape::from_raw(
+                    s.shape,
+                    None,
+                    None,
+                    None,
+                    None,
+                    drawing_relationships.clone(),
+                    image_bytes.clone(),
+                    defined_names.clone(),
+                    color_scheme.clone(),
+                    references.0,
+                    references.1,
+                    references.2,
+                    references.3,
+                )
+            })
+            .collect();
+
+        let mut visited: Vec<String> = vec![];
+        let nodes = match document.and_then(|d| d.model_id.clone()) {
+            Some(id) => DiagramNode::children_of(&id, data_model, &shape_model_ids, &mut visited),
+            None => vec![],
+        };
+
+        return Some(Self {
+            layout: definition(
+                &graphic_data.diagram_layout_id,
+                &diagrams.layouts,
+                document.and_then(|d| d.layout_type_id.clone()),
+            ),
+            quick_style: definition(
+                &graphic_data.diagram_quick_style_id,
+                &diagrams.quick_styles,
+                document.and_then(|d| d.quick_style_type_id.clone()),
+            ),
+            colors: definition(
+                &graphic_data.diagram_colors_id,
+                &diagrams.colors,
+                document.and_then(|d| d.color_type_id.clone()),
+            ),
+            nodes,
+            shapes,
+        });
+    }
+}
+
+/// Header of a layout, quick style or colors definition of a diagram
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DiagramDefinition {
+    /// Ex: `urn:microsoft.com/office/officeart/2005/8/layout/orgChart1`
+    pub unique_id: String,
+
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub title: Option<String>,
+
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub description: Option<String>,
+
+    /// Ex: `hierarchy`
+    pub categories: Vec<String>,
+}
+
+impl DiagramDefinition {
+    /// * type_id: identifier of the definition set on the document point, used if the definition part is missing
+    pub(crate) fn from_raw(
+        raw: Option<&XlsxDiagramDefinition>,
+        type_id: Option<String>,
+    ) -> Option<Self> {
+        let Some(raw) = raw else {
+            return type_id.map(|id| Self {
+                unique_id: id,
+                title: None,
+                description: None,
+                categories: vec![],
+            });
+        };
+
+        return Some(Self {
+            unique_id: raw.unique_id.clone().or(type_id).unwrap_or(String::new()),
+            title: raw.title.clone().filter(|t| !t.is_empty()),
+            description: raw.description.clone().filter(|d| !d.is_empty()),
+            categories: raw.categories.clone(),
+        });
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.point?view=openxml-3.0.1
+///
+/// A node of the diagram with its text and its child nodes. Ex: a person of an organization chart.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DiagramNode {
+    /// modelId of the point. Ex: `{1}`
+    pub model_id: String,
+
+    pub r#type: DiagramNodeTypeValues,
+
+    /// Text of the node, with paragraphs separated by `\n`
+    pub text: String,
+
+    /// Indexes of the shapes rendering the node in `Diagram.shapes`
+    pub shape_indexes: Vec<usize>,
+
+    /// Child nodes, in order
+    pub children: Vec<DiagramNode>,
+}
+
+impl DiagramNode {
+    /// child nodes of a point, following the parOf connections ordered by srcOrd
+    ///
+    /// * shape_model_ids: modelId of each shape of the diagram drawing
+    /// * visited: model ids already added, to stop on cyclic connections
+    fn children_of(
+        parent_id: &str,
+        data_model: &XlsxDiagramDataModel,
+        shape_model_ids: &Vec<Option<String>>,
+        visited: &mut Vec<String>,
+    ) -> Vec<Self> {
+        visited.push(parent_id.to_string());
+
+        let mut connections: Vec<_> = data_model
+            .connections
+            .iter()
+            .filter(|c| {
+                c.source_id.as_deref() == Some(parent_id)
+                    && c.r#type.as_deref().unwrap_or("parOf") == "parOf"
+            })
+            .collect();
+        connections.sort_by_key(|c| c.source_order.unwrap_or(0));
+
+        let mut children: Vec<Self> = vec![];
+        for connection in connections {
+            let Some(id) = connection.destination_id.as_ref() else {
+                continue;
+            };
+            if visited.contains(id) {
+                continue;
+            }
+            let Some(point) = data_model
+                .points
+                .iter()
+                .find(|p| p.model_id.as_ref() == Some(id))
+            else {
+                continue;
+            };
+            let r#type = DiagramNodeTypeValues::from_string(point.r#type.clone());
+            if r#type == DiagramNodeTypeValues::Other {
+                continue;
+            }
+
+            // presentation points created for the node, rendered as shapes of the drawing
+            let presentation_ids: Vec<&String> = data_model
+                .points
+                .iter()
+                .filter(|p| p.presentation_association_id.as_ref() == Some(id))
+                .filter_map(|p| p.model_id.as_ref())
+                .collect();
+            let shape_indexes: Vec<usize> = shape_model_ids
+                .iter()
+                .enumerate()
+                .filter(|(_, m)| {
+                    m.as_ref()
+                        .is_some_and(|m| m == id || presentation_ids.contains(&m))
+                })
+                .map(|(i, _)| i)
+                .collect();
+
+            children.push(Self {
+                model_id: id.to_string(),
+                r#type,
+                text: point_text(point),
+                shape_indexes,
+                children: Self::children_of(id, data_model, shape_model_ids, visited),
+            });
+        }
+
+        return children;
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.pointvalues?view=openxml-3.0.1
+///
+/// * Node
+/// * Assistant
+/// * Other: document, presentation and transition points, not listed in the hierarchy
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DiagramNodeTypeValues {
+    Node,
+    Assistant,
+    Other,
+}
+
+impl DiagramNodeTypeValues {
+    pub(crate) fn default() -> Self {
+        Self::Node
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else { return Self::default() };
+        return match s.as_ref() {
+            "node" => Self::Node,
+            "asst" => Self::Assistant,
+            _ => Self::Other,
+        };
+    }
+}
+
+fn point_text(point: &XlsxDiagramPoint) -> String {
+    let Some(XlsxShapeTextBody {
+        text_paragraph: Some(paragraphs),
+        ..
+    }) = point.text_body.as_ref()
+    else {
+        return String::new();
+    };
+
+    let lines: Vec<String> = paragraphs
+        .iter()
+        .map(|p| {
+            p.runs
+                .clone()
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|r| match r {
+                    XlsxRunType::Text(run) => run.text.unwrap_or(String::new()),
+                    XlsxRunType::LineBreak(_) => "\n".to_string(),
+                    XlsxRunType::TextField(field) => field.text.unwrap_or(String::new()),
+                })
+                .collect::<String>()
+        })
+        .collect();
+    return lines.join("\n");
+}
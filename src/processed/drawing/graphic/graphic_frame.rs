@@ -1,15 +1,21 @@
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
+use std::collections::BTreeMap;
+
 use crate::{
     packaging::relationship::XlsxRelationships,
     processed::drawing::{
-        shape::shape_properties::ShapeProperties,
+        chart::Chart, diagram::Diagram, shape::shape_properties::ShapeProperties,
         worksheet_drawing::non_visual_properties::NonVisualDrawingProperty,
     },
     raw::{
         drawing::{
+            chart::XlsxChartSpace,
+            diagram::XlsxDiagrams,
             graphic::graphic_frame::XlsxGraphicFrame,
+            scheme::color_scheme::XlsxColorScheme,
+            theme::XlsxTheme,
             worksheet_drawing::{
                 client_data::XlsxClientData, spreadsheet_extent::XlsxSpreadsheetExtent,
                 spreadsheet_position::XlsxSpreadsheetPosition,
@@ -28,6 +34,7 @@ use crate::{
 /// Possible objects (Partial):
 /// - Charts
 /// - Diagram
+/// - Slicers and timelines
 ///
 /// Example
 /// ```
@@ -84,6 +91,30 @@ pub struct GraphicFrame {
 
     /// This element specifies all non-visual properties.
     pub non_visual_properties: NonVisualDrawingProperty,
+
+    /// Chart referenced by the frame.
+    ///
+    /// None if the graphic object is not a chart.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub chart: Option<Chart>,
+
+    /// Name of the slicer displayed in the frame (`Slicer.name`).
+    ///
+    /// None if the graphic object is not a slicer.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub slicer_name: Option<String>,
+
+    /// Name of the timeline displayed in the frame (`Timeline.name`).
+    ///
+    /// None if the graphic object is not a timeline.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub timeline_name: Option<String>,
+
+    /// Diagram (SmartArt) referenced by the frame.
+    ///
+    /// None if the graphic object is not a diagram.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub diagram: Option<Diagram>,
 }
 
 impl GraphicFrame {
@@ -97,12 +128,34 @@ impl GraphicFrame {
         client_data: Option<XlsxClientData>,
         drawing_relationship: XlsxRelationships,
         defined_names: XlsxDefinedNames,
+        charts: &BTreeMap<String, XlsxChartSpace>,
+        diagrams: &XlsxDiagrams,
+        color_scheme: Option<XlsxColorScheme>,
+        theme: Option<Box<XlsxTheme>>,
     ) -> Self {
         let mut uri: String = String::new();
+        let mut chart: Option<Chart> = None;
+        let mut slicer_name: Option<String> = None;
+        let mut timeline_name: Option<String> = None;
+        let mut diagram: Option<Diagram> = None;
 
         if let Some(graphic) = raw.clone().graphic {
             if let Some(data) = graphic.graphic_data {
-                uri = data.uri.unwrap_or(String::new())
+                diagram = Diagram::from_raw(
+                    &data,
+                    diagrams,
+                    defined_names.clone(),
+                    color_scheme.clone(),
+                    theme,
+                );
+                uri = data.uri.unwrap_or(String::new());
+                slicer_name = data.slicer_name;
+                timeline_name = data.timeline_name;
+                if let Some(id) = data.chart_id {
+                    chart = charts
+                        .get(&id)
+                        .map(|c| Chart::from_raw(c.clone(), color_scheme.clone()));
+                }
             }
         }
 
@@ -122,6 +175,10 @@ impl GraphicFrame {
                     drawing_relationship,
                     defined_names,
                 ),
+            chart,
+            slicer_name,
+            timeline_name,
+            diagram,
         };
     }
 }
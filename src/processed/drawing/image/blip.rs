@@ -96,7 +96,7 @@ impl BlipSourceType {
         return None;
     }
 
-    fn parse_rel_helper(
+    pub(crate) fn parse_rel_helper(
         r_id: &str,
         drawing_relationships: XlsxRelationships,
         // get bytes for a rel_id
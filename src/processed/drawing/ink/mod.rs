@@ -0,0 +1,391 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::HexColor,
+    helper::{format_hex_string, string_to_bool, string_to_float, string_to_unsignedint},
+    raw::drawing::ink::{
+        brush::XlsxInkBrush,
+        context::{XlsxInkChannel, XlsxInkContext},
+        trace::XlsxInkTrace,
+        XlsxInk,
+    },
+};
+
+/// https://www.w3.org/TR/InkML/#ink
+///
+/// Ink annotation drawn with a pen or a finger: the strokes with their brushes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Ink {
+    pub traces: Vec<InkTrace>,
+}
+
+impl Ink {
+    pub(crate) fn from_raw(raw: XlsxInk) -> Self {
+        let traces: Vec<InkTrace> = raw
+            .traces
+            .iter()
+            .map(|t| InkTrace::from_raw(t, &raw.contexts, &raw.brushes))
+            .collect();
+        return Self { traces };
+    }
+}
+
+/// https://www.w3.org/TR/InkML/#trace
+///
+/// A stroke of the annotation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InkTrace {
+    pub id: Option<String>,
+
+    pub brush: Option<InkBrush>,
+
+    /// Points of the stroke, in the order they were drawn
+    pub points: Vec<InkPoint>,
+}
+
+impl InkTrace {
+    pub(crate) fn from_raw(
+        raw: &XlsxInkTrace,
+        contexts: &Vec<XlsxInkContext>,
+        brushes: &Vec<XlsxInkBrush>,
+    ) -> Self {
+        let context = raw
+            .context_ref
+            .as_ref()
+            .and_then(|r| {
+                contexts
+                    .iter()
+                    .find(|c| c.id.as_deref() == Some(strip_ref(r)))
+            })
+            .or(contexts.first());
+
+        let brush_ref = raw
+            .brush_ref
+            .clone()
+            .or(context.and_then(|c| c.brush_ref.clone()));
+        let brush = brush_ref.and_then(|r| InkBrush::from_ref(&r, brushes));
+
+        let channels: Vec<XlsxInkChannel> = context.map(|c| c.channels.clone()).unwrap_or(vec![]);
+        let channel_names: Vec<String> = if channels.is_empty() {
+            vec!["X".to_string(), "Y".to_string()]
+        } else {
+            channels
+                .iter()
+                .map(|c| c.name.clone().unwrap_or("".to_string()))
+                .collect()
+        };
+        let index_of = |name: &str| channel_names.iter().position(|n| n == name);
+
+        let x_scale = context.map_or(HIMETRIC_TO_POINT, |c| points_per_unit(c, "X"));
+        let y_scale = context.map_or(HIMETRIC_TO_POINT, |c| points_per_unit(c, "Y"));
+        let max_force = index_of("F")
+            .and_then(|i| channels.get(i))
+            .and_then(|c| c.max);
+
+        let points: Vec<InkPoint> = decode_trace(&raw.value, channel_names.len())
+            .into_iter()
+            .map(|values| {
+                let value = |name: &str| index_of(name).and_then(|i| values.get(i).cloned());
+                InkPoint {
+                    x: value("X").unwrap_or(0.0) * x_scale,
+                    y: value("Y").unwrap_or(0.0) * y_scale,
+                    pressure: value("F").map(|f| match max_force {
+                        Some(max) if max > 0.0 => f / max,
+                        _ => f,
+                    }),
+                    time: value("T"),
+                }
+            })
+            .collect();
+
+        return Self {
+            id: raw.id.clone(),
+            brush,
+            points,
+        };
+    }
+}
+
+/// A point of a stroke
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InkPoint {
+    /// In points, in the coordinate space of the ink
+    pub x: f64,
+
+    /// In points, in the coordinate space of the ink
+    pub y: f64,
+
+    /// Tip force, 0.0 - 1.0 when the maximum of the channel is known
+    pub pressure: Option<f64>,
+
+    /// Time channel value as written by the device
+    pub time: Option<f64>,
+}
+
+/// https://www.w3.org/TR/InkML/#brush
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InkBrush {
+    pub id: Option<String>,
+
+    /// Color of the pen including the transparency as alpha. Ex: `#e71224ff`
+    pub color: Option<HexColor>,
+
+    /// Width of the pen tip in points
+    pub width: Option<f64>,
+
+    /// Height of the pen tip in points
+    pub height: Option<f64>,
+
+    /// 0 (opaque) - 255
+    pub transparency: u64,
+
+    /// ellipse or rectangle
+    pub tip: String,
+
+    /// Whether the brush is a highlighter (rasterOp maskPen)
+    pub highlighter: bool,
+
+    /// Whether the width does not vary with the tip force
+    pub ignore_pressure: bool,
+
+    /// Whether the strokes are smoothed with bezier curves
+    pub fit_to_curve: bool,
+}
+
+impl InkBrush {
+    /// * brush_ref: Ex: `#br0`
+    pub(crate) fn from_ref(brush_ref: &str, brushes: &Vec<XlsxInkBrush>) -> Option<Self> {
+        let raw = brushes
+            .iter()
+            .find(|b| b.id.as_deref() == Some(strip_ref(brush_ref)))?;
+
+        // properties of the brush referenced by brushRef are overridden by the brush's own
+        let mut properties = match raw.brush_ref.as_ref() {
+            Some(parent) if parent != brush_ref => brushes
+                .iter()
+                .find(|b| b.id.as_deref() == Some(strip_ref(parent)))
+                .map(|b| b.properties.clone())
+                .unwrap_or(vec![]),
+            _ => vec![],
+        };
+        properties.extend(raw.properties.clone());
+
+        let property = |name: &str| {
+            properties
+                .iter()
+                .rev()
+                .find(|p| p.name.as_deref() == Some(name))
+        };
+        let value = |name: &str| property(name).and_then(|p| p.value.clone());
+        let length = |name: &str| {
+            property(name).and_then(|p| {
+                let value = string_to_float(p.value.as_deref()?)?;
+                length_to_points(value, p.units.as_deref())
+            })
+        };
+
+        let transparency = value("transparency")
+            .and_then(|t| string_to_unsignedint(&t))
+            .unwrap_or(0)
+            .min(255);
+        let color = value("color")
+            .and_then(|c| format_hex_string(c.trim_start_matches('#'), None).ok())
+            .map(|c| format!("{}{:02x}", &c[..7], 255 - transparency));
+
+        return Some(Self {
+            id: raw.id.clone(),
+            color,
+            width: length("width"),
+            height: length("height"),
+            transparency,
+            tip: value("tip").unwrap_or("ellipse".to_string()),
+            highlighter: value("rasterOp").is_some_and(|r| r == "maskPen"),
+            ignore_pressure: value("ignorePressure")
+                .and_then(|v| string_to_bool(&v))
+                .unwrap_or(false),
+            fit_to_curve: value("fitToCurve")
+                .and_then(|v| string_to_bool(&v))
+                .unwrap_or(false),
+        });
+    }
+}
+
+const POINTS_PER_CM: f64 = 72.0 / 2.54;
+
+/// Office writes ink coordinates in himetric (1/1000th of a centimeter)
+const HIMETRIC_TO_POINT: f64 = POINTS_PER_CM / 1000.0;
+
+fn strip_ref(r: &str) -> &str {
+    return r.trim_start_matches('#');
+}
+
+fn length_to_points(value: f64, units: Option<&str>) -> Option<f64> {
+    let points_per_unit = match units.unwrap_or("cm") {
+        "cm" => POINTS_PER_CM,
+        "mm" => POINTS_PER_CM / 10.0,
+        "in" => 72.0,
+        "pt" => 1.0,
+        "pc" => 12.0,
+        "himetric" => HIMETRIC_TO_POINT,
+        _ => return None,
+    };
+    return Some(value * points_per_unit);
+}
+
+/// Points per value of a coordinate channel, from the `resolution` channel property (Ex: `1000` `1/cm`) or the units of the channel.
+fn points_per_unit(context: &XlsxInkContext, channel: &str) -> f64 {
+    let resolution = context
+        .channel_properties
+        .iter()
+        .find(|p| p.channel.as_deref() == Some(channel) && p.name.as_deref() == Some("resolution"));
+    if let Some(resolution) = resolution {
+        let units = resolution
+            .units
+            .as_deref()
+            .and_then(|u| u.strip_prefix("1/"));
+        if let (Some(value), Some(points)) = (resolution.value, length_to_points(1.0, units)) {
+            if value > 0.0 {
+                return points / value;
+            }
+        }
+    }
+
+    let units = context
+        .channels
+        .iter()
+        .find(|c| c.name.as_deref() == Some(channel))
+        .and_then(|c| c.units.clone());
+    return match units.as_deref() {
+        Some("himetric") | None => HIMETRIC_TO_POINT,
+        Some(units) => length_to_points(1.0, Some(units)).unwrap_or(HIMETRIC_TO_POINT),
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DifferenceMode {
+    Explicit,
+    First,
+    Second,
+}
+
+/// Decode the values of each point of a trace.
+///
+/// Values prefixed by `'` are first differences and values prefixed by `"` second differences.
+/// The mode of a channel applies to its following values until changed by another prefix (`!` for explicit values).
+fn decode_trace(value: &str, channel_count: usize) -> Vec<Vec<f64>> {
+    let channel_count = channel_count.max(1);
+    let mut modes = vec![DifferenceMode::Explicit; channel_count];
+    let mut last_values = vec![0.0; channel_count];
+    let mut last_differences = vec![0.0; channel_count];
+
+    let mut points: Vec<Vec<f64>> = vec![];
+    for encoded_point in value.split(',') {
+        let tokens = tokenize_point(encoded_point);
+        if tokens.is_empty() {
+            continue;
+        }
+
+        for (index, (mode, number)) in tokens.into_iter().take(channel_count).enumerate() {
+            if let Some(mode) = mode {
+                modes[index] = mode;
+            }
+            // `*` or `?`: value unchanged
+            let Some(number) = number else {
+                last_differences[index] = 0.0;
+                continue;
+            };
+            let difference = match modes[index] {
+                DifferenceMode::Explicit => number - last_values[index],
+                DifferenceMode::First => number,
+                DifferenceMode::Second => last_differences[index] + number,
+            };
+            last_values[index] += difference;
+            last_differences[index] = difference;
+        }
+        points.push(last_values.clone());
+    }
+
+    return points;
+}
+
+/// (difference mode prefix, value) of each channel of a point. Ex: `'250'-3` -> [(First, 250), (First, -3)]
+fn tokenize_point(s: &str) -> Vec<(Option<DifferenceMode>, Option<f64>)> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens: Vec<(Option<DifferenceMode>, Option<f64>)> = vec![];
+    let mut mode: Option<DifferenceMode> = None;
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '!' => mode = Some(DifferenceMode::Explicit),
+            '\'' => mode = Some(DifferenceMode::First),
+            '"' => mode = Some(DifferenceMode::Second),
+            '*' | '?' => tokens.push((mode.take(), None)),
+            '-' | '+' | '.' | '0'..='9' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() {
+                    let c = chars[i];
+                    let is_exponent_sign =
+                        (c == '-' || c == '+') && (chars[i - 1] == 'e' || chars[i - 1] == 'E');
+                    if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || is_exponent_sign {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                let number: String = chars[start..i].iter().collect();
+                tokens.push((mode.take(), number.parse::<f64>().ok()));
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    return tokens;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_trace_with_first_and_second_differences() {
+        let points = decode_trace("10 20, '1 '2, \"1 \"1", 2);
+        assert_eq!(
+            points,
+            vec![vec![10.0, 20.0], vec![11.0, 22.0], vec![13.0, 25.0]]
+        );
+    }
+
+    #[test]
+    fn decode_trace_keeps_the_difference_mode_of_each_channel() {
+        // the first channel switches back to explicit values, the second one stays in first differences
+        let points = decode_trace("10 20,'1'-3,!5 2", 2);
+        assert_eq!(
+            points,
+            vec![vec![10.0, 20.0], vec![11.0, 17.0], vec![5.0, 19.0]]
+        );
+    }
+
+    #[test]
+    fn decode_trace_with_unchanged_values() {
+        let points = decode_trace("10 20,'2 '2,* *,\"1 \"1", 2);
+        assert_eq!(
+            points,
+            vec![
+                vec![10.0, 20.0],
+                vec![12.0, 22.0],
+                vec![12.0, 22.0],
+                vec![13.0, 23.0]
+            ]
+        );
+    }
+}
@@ -1,10 +1,14 @@
+pub mod chart;
 pub mod common_types;
+pub mod diagram;
 pub mod effect;
 pub mod fill;
 pub mod graphic;
 pub mod image;
+pub mod ink;
 pub mod line;
 pub mod scene;
 pub mod shape;
 pub mod text;
+pub mod vml;
 pub mod worksheet_drawing;
@@ -0,0 +1,204 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{common_types::Coordinate, raw::drawing::vml::client_data::XlsxVmlClientData};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.spreadsheet.clientdata?view=openxml-3.0.1
+///
+/// Spreadsheet related data of a VML shape: the object type, and the data of comments and form controls.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlClientData {
+    /// None if the object type is not specified or not supported
+    pub object_type: Option<VmlObjectType>,
+
+    /// Cell a comment box belongs to
+    pub cell: Option<Coordinate>,
+
+    /// Whether a comment box is always displayed (instead of on hover)
+    pub visible: bool,
+
+    /// Cell linked to the value of the control. Ex: `$C$1`
+    pub linked_cell: Option<String>,
+
+    /// Input range of list boxes and dropdowns. Ex: `$E$1:$E$5`
+    pub input_range: Option<String>,
+
+    /// Name of the macro assigned to the object
+    pub r#macro: Option<String>,
+
+    /// State of check boxes and option buttons
+    pub checked: Option<VmlCheckedState>,
+
+    /// 1 based index of the selected item of list boxes and dropdowns.
+    ///
+    /// None if nothing is selected.
+    pub selected_index: Option<u64>,
+
+    /// Items of list boxes and dropdowns that are not linked to an input range
+    pub list_items: Vec<String>,
+
+    /// Number of lines displayed by dropdowns
+    pub drop_lines: Option<u64>,
+
+    /// Current value of scroll bars and spinners
+    pub value: Option<i64>,
+
+    /// Minimum value of scroll bars and spinners
+    pub min: Option<i64>,
+
+    /// Maximum value of scroll bars and spinners
+    pub max: Option<i64>,
+
+    /// Increment of scroll bars and spinners
+    pub increment: Option<u64>,
+
+    /// Page increment of scroll bars
+    pub page_increment: Option<u64>,
+
+    /// Whether a scroll bar is horizontal
+    pub horizontal: bool,
+
+    /// Whether the first option button of a group
+    pub first_button: bool,
+
+    /// Whether the control is displayed without 3D effects
+    pub no_three_d: bool,
+
+    /// Whether the object is locked when the sheet is protected
+    pub locked: bool,
+
+    /// Whether the object is printed
+    pub print_object: bool,
+
+    /// Whether the control is disabled
+    pub disabled: bool,
+
+    /// Ex: Left, Center, Right, Justify, Distributed
+    pub text_horizontal_align: Option<String>,
+
+    /// Ex: Top, Center, Bottom, Justify, Distributed
+    pub text_vertical_align: Option<String>,
+}
+
+impl VmlClientData {
+    pub(crate) fn from_raw(raw: XlsxVmlClientData) -> Self {
+        let cell = match (raw.row, raw.column) {
+            (Some(row), Some(col)) => Some(Coordinate::from_point((row + 1, col + 1))),
+            _ => None,
+        };
+
+        return Self {
+            object_type: VmlObjectType::from_string(raw.object_type),
+            cell,
+            visible: raw.visible.unwrap_or(false),
+            linked_cell: raw.fmla_link,
+            input_range: raw.fmla_range,
+            r#macro: raw.fmla_macro,
+            checked: VmlCheckedState::from_u64(raw.checked),
+            selected_index: raw.selected.filter(|s| *s > 0),
+            list_items: raw.list_items,
+            drop_lines: raw.drop_lines,
+            value: raw.value,
+            min: raw.min,
+            max: raw.max,
+            increment: raw.increment,
+            page_increment: raw.page,
+            horizontal: raw.horizontal.unwrap_or(false),
+            first_button: raw.first_button.unwrap_or(false),
+            no_three_d: raw.no_three_d.unwrap_or(false),
+            locked: raw.locked.unwrap_or(true),
+            print_object: raw.print_object.unwrap_or(true),
+            disabled: raw.disabled.unwrap_or(false),
+            text_horizontal_align: raw.text_horizontal_align,
+            text_vertical_align: raw.text_vertical_align,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.spreadsheet.objectvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VmlObjectType {
+    Button,
+    Checkbox,
+    Dialog,
+    Dropdown,
+    EditBox,
+    GroupBox,
+    Label,
+    Line,
+    ListBox,
+    Movie,
+    /// Comment box
+    Note,
+    Picture,
+    OptionButton,
+    Rectangle,
+    ScrollBar,
+    Spinner,
+    Shape,
+    Group,
+    Rect,
+}
+
+impl VmlObjectType {
+    pub(crate) fn from_string(s: Option<String>) -> Option<Self> {
+        let Some(s) = s else { return None };
+        return match s.as_ref() {
+            "Button" => Some(Self::Button),
+            "Checkbox" => Some(Self::Checkbox),
+            "Dialog" => Some(Self::Dialog),
+            "Drop" => Some(Self::Dropdown),
+            "Edit" => Some(Self::EditBox),
+            "GBox" => Some(Self::GroupBox),
+            "Label" => Some(Self::Label),
+            "LineA" => Some(Self::Line),
+            "List" => Some(Self::ListBox),
+            "Movie" => Some(Self::Movie),
+            "Note" => Some(Self::Note),
+            "Pict" => Some(Self::Picture),
+            "Radio" => Some(Self::OptionButton),
+            "RectA" => Some(Self::Rectangle),
+            "Scroll" => Some(Self::ScrollBar),
+            "Spin" => Some(Self::Spinner),
+            "Shape" => Some(Self::Shape),
+            "Group" => Some(Self::Group),
+            "Rect" => Some(Self::Rect),
+            _ => None,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.spreadsheet.checked?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VmlCheckedState {
+    Unchecked,
+    Checked,
+    Mixed,
+}
+
+impl VmlCheckedState {
+    pub(crate) fn from_string(s: Option<String>) -> Option<Self> {
+        let Some(s) = s else { return None };
+        return match s.as_ref() {
+            "Unchecked" => Some(Self::Unchecked),
+            "Checked" => Some(Self::Checked),
+            "Mixed" => Some(Self::Mixed),
+            _ => None,
+        };
+    }
+
+    pub(crate) fn from_u64(value: Option<u64>) -> Option<Self> {
+        let Some(value) = value else { return None };
+        return match value {
+            0 => Some(Self::Unchecked),
+            1 => Some(Self::Checked),
+            2 => Some(Self::Mixed),
+            _ => None,
+        };
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use super::{vml_color_to_hex, vml_fraction_to_float};
+use crate::{
+    common_types::HexColor,
+    helper::string_to_float,
+    packaging::relationship::XlsxRelationships,
+    processed::drawing::image::blip::BlipSourceType,
+    raw::drawing::vml::{shape::XlsxVmlShape, shape_type::XlsxVmlShapeType},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.fill?view=openxml-3.0.1
+///
+/// Fill of a VML shape, combined from the `fill` element and the `filled`/`fillcolor` attributes of the shape and its template.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlFill {
+    /// Whether the shape is filled
+    pub filled: bool,
+
+    /// Primary color. None if not specified (white by default)
+    pub color: Option<HexColor>,
+
+    /// Secondary color of gradients and patterns
+    pub color2: Option<HexColor>,
+
+    /// Opacity of the primary color between 0 and 1
+    pub opacity: f64,
+
+    pub fill_type: VmlFillType,
+
+    /// Angle of gradients in degree
+    pub angle: Option<f64>,
+
+    /// Image of picture, pattern, tile and frame fills
+    pub image: Option<BlipSourceType>,
+}
+
+impl VmlFill {
+    pub(crate) fn from_raw(
+        shape: &XlsxVmlShape,
+        shape_type: Option<&XlsxVmlShapeType>,
+        drawing_rels: XlsxRelationships,
+        image_bytes: BTreeMap<String, Vec<u8>>,
+    ) -> Self {
+        let fill = shape
+            .fill
+            .clone()
+            .or(shape_type.and_then(|t| t.fill.clone()));
+        let filled = fill
+            .as_ref()
+            .and_then(|f| f.on)
+            .or(shape.filled)
+            .or(shape_type.and_then(|t| t.filled))
+            .unwrap_or(true);
+        let color = fill
+            .as_ref()
+            .and_then(|f| f.color.clone())
+            .or(shape.fill_color.clone())
+            .or(shape_type.and_then(|t| t.fill_color.clone()));
+
+        let Some(fill) = fill else {
+            return Self {
+                filled,
+                color: vml_color_to_hex(color),
+                color2: None,
+                opacity: 1.0,
+                fill_type: VmlFillType::Solid,
+                angle: None,
+                image: None,
+            };
+        };
+
+        let image = fill
+            .relid
+            .clone()
+            .or(fill.id.clone())
+            .and_then(|id| BlipSourceType::parse_rel_helper(&id, drawing_rels, image_bytes));
+
+        return Self {
+            filled,
+            color: vml_color_to_hex(color),
+            color2: vml_color_to_hex(fill.color2),
+            opacity: fill
+                .opacity
+                .and_then(|o| vml_fraction_to_float(&o))
+                .unwrap_or(1.0),
+            fill_type: VmlFillType::from_string(fill.r#type),
+            angle: fill.angle.and_then(|a| string_to_float(&a)),
+            image,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.filltypevalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VmlFillType {
+    Solid,
+    Gradient,
+    GradientRadial,
+    Tile,
+    Pattern,
+    Frame,
+}
+
+impl VmlFillType {
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else { return Self::Solid };
+        return match s.as_ref() {
+            "gradient" => Self::Gradient,
+            "gradientRadial" => Self::GradientRadial,
+            "tile" => Self::Tile,
+            "pattern" => Self::Pattern,
+            "frame" => Self::Frame,
+            _ => Self::Solid,
+        };
+    }
+}
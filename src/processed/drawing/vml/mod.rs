@@ -0,0 +1,151 @@
+pub mod client_data;
+pub mod fill;
+pub mod shape;
+pub mod stroke;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use client_data::VmlObjectType;
+use shape::VmlShape;
+
+use crate::{
+    common_types::HexColor,
+    helper::{format_hex_string, string_to_float},
+    packaging::relationship::XlsxRelationships,
+    raw::drawing::vml::XlsxVmlDrawing,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml?view=openxml-3.0.1
+///
+/// Legacy (VML) drawing of a worksheet parsed from xl/drawings/vmlDrawing{}.vml.
+///
+/// Contains comment boxes and legacy form controls (referenced by `legacyDrawing`), or header/footer images (referenced by `legacyDrawingHF`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlDrawing {
+    pub shapes: Vec<VmlShape>,
+}
+
+impl VmlDrawing {
+    pub(crate) fn from_raw(
+        raw: XlsxVmlDrawing,
+        drawing_rels: XlsxRelationships,
+        image_bytes: BTreeMap<String, Vec<u8>>,
+    ) -> Self {
+        let shapes = raw
+            .shapes
+            .into_iter()
+            .map(|s| {
+                VmlShape::from_raw(
+                    s,
+                    &raw.shape_types,
+                    drawing_rels.clone(),
+                    image_bytes.clone(),
+                )
+            })
+            .collect();
+        return Self { shapes };
+    }
+
+    /// Get the shape with the id referenced by `shapeId` of OLE objects and controls.
+    pub fn get_shape_with_shape_id(&self, shape_id: &u64) -> Option<VmlShape> {
+        return self
+            .shapes
+            .iter()
+            .find(|s| s.shape_id.as_ref() == Some(shape_id))
+            .cloned();
+    }
+
+    /// Get shapes of an object type. Ex: `VmlObjectType::Note` for comment boxes.
+    pub fn get_shapes_with_object_type(&self, object_type: &VmlObjectType) -> Vec<VmlShape> {
+        return self
+            .shapes
+            .iter()
+            .filter(|s| {
+                s.client_data.as_ref().and_then(|c| c.object_type.as_ref()) == Some(object_type)
+            })
+            .cloned()
+            .collect();
+    }
+}
+
+/// Convert a VML color to hex.
+///
+/// VML colors are either hex (`#ffffe1`, `#fc0`), named (`black`), or system colors with a fallback index (`infoBackground [80]`).
+pub(crate) fn vml_color_to_hex(color: Option<String>) -> Option<HexColor> {
+    let Some(color) = color else {
+        return None;
+    };
+    // remove the index of system colors. Ex: `infoBackground [80]`
+    let color = color.split('[').next().unwrap_or("").trim().to_string();
+
+    if let Some(hex) = color.strip_prefix('#') {
+        let hex = if hex.len() == 3 {
+            hex.chars().flat_map(|c| [c, c]).collect::<String>()
+        } else {
+            hex.to_string()
+        };
+        return format_hex_string(&hex, None).ok();
+    }
+
+    let hex = match color.to_ascii_lowercase().as_ref() {
+        "black" | "windowtext" | "infotext" | "buttontext" => "000000",
+        "white" | "window" => "ffffff",
+        "infobackground" => "ffffe1",
+        "buttonface" => "f0f0f0",
+        "red" => "ff0000",
+        "lime" => "00ff00",
+        "blue" => "0000ff",
+        "yellow" => "ffff00",
+        "aqua" => "00ffff",
+        "fuchsia" => "ff00ff",
+        "green" => "008000",
+        "navy" => "000080",
+        "maroon" => "800000",
+        "purple" => "800080",
+        "olive" => "808000",
+        "teal" => "008080",
+        "gray" => "808080",
+        "silver" => "c0c0c0",
+        _ => return None,
+    };
+    return format_hex_string(hex, None).ok();
+}
+
+/// Convert a VML (CSS) length to point.
+///
+/// Ex: `59.25pt`, `1in`, `2.5cm`, `10mm`, `12px`. Lengths without unit are in pixels.
+pub(crate) fn vml_length_to_pt(length: &str) -> Option<f64> {
+    let length = length.trim();
+    let units: [(&str, f64); 6] = [
+        ("pt", 1.0),
+        ("px", 0.75),
+        ("in", 72.0),
+        ("cm", 72.0 / 2.54),
+        ("mm", 72.0 / 25.4),
+        ("pc", 12.0),
+    ];
+    for (unit, scale) in units.into_iter() {
+        if let Some(value) = length.strip_suffix(unit) {
+            return string_to_float(value.trim()).map(|v| v * scale);
+        }
+    }
+    return string_to_float(length).map(|v| v * 0.75);
+}
+
+/// Convert a VML fraction to a float between 0 and 1.
+///
+/// Ex: `0.5`, `50%`, or 16.16 fixed number `32768f`
+pub(crate) fn vml_fraction_to_float(fraction: &str) -> Option<f64> {
+    let fraction = fraction.trim();
+    if let Some(value) = fraction.strip_suffix('f') {
+        return string_to_float(value).map(|v| v / 65536.0);
+    }
+    if let Some(value) = fraction.strip_suffix('%') {
+        return string_to_float(value).map(|v| v / 100.0);
+    }
+    return string_to_float(fraction);
+}
@@ -0,0 +1,254 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use super::{client_data::VmlClientData, fill::VmlFill, stroke::VmlStroke, vml_length_to_pt};
+use crate::{
+    helper::{string_to_float, string_to_int, string_to_unsignedint},
+    packaging::relationship::XlsxRelationships,
+    processed::drawing::{
+        image::blip::BlipSourceType,
+        worksheet_drawing::{anchor_type::DrawingAnchorType, cell_marker::CellMarker},
+    },
+    raw::drawing::vml::{
+        anchor::XlsxVmlAnchor, client_data::XlsxVmlClientData, shape::XlsxVmlShape,
+        shape_type::XlsxVmlShapeType,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.shape?view=openxml-3.0.1
+///
+/// A legacy (VML) shape: a comment box, a legacy form control, or a header/footer image.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlShape {
+    /// Ex: `_x0000_s1025`, or the position of header/footer images (`LH`, `CH`, `RH`, `LF`, `CF`, `RF`)
+    pub id: Option<String>,
+
+    /// Numeric id of the shape referenced by `shapeId` of OLE objects and controls. Ex: `1025` for `_x0000_s1025`
+    pub shape_id: Option<u64>,
+
+    /// Template of the shape
+    pub shape_type: Option<VmlShapeType>,
+
+    pub style: VmlShapeStyle,
+
+    /// Cells the shape is anchored to.
+    ///
+    /// None for shapes without client data, ex: header/footer images.
+    pub anchor: Option<DrawingAnchorType>,
+
+    pub fill: VmlFill,
+
+    pub stroke: VmlStroke,
+
+    /// Text displayed in the shape, ex: text of comment boxes and labels of form controls
+    pub text: Option<String>,
+
+    /// Image drawn by the shape, ex: header/footer images
+    pub image: Option<VmlImage>,
+
+    /// Alternative text
+    pub alt_text: Option<String>,
+
+    pub client_data: Option<VmlClientData>,
+}
+
+impl VmlShape {
+    pub(crate) fn from_raw(
+        raw: XlsxVmlShape,
+        shape_types: &[XlsxVmlShapeType],
+        drawing_rels: XlsxRelationships,
+        image_bytes: BTreeMap<String, Vec<u8>>,
+    ) -> Self {
+        let raw_shape_type = raw.r#type.as_ref().and_then(|t| {
+            let id = t.trim_start_matches('#');
+            shape_types.iter().find(|s| s.id.as_deref() == Some(id))
+        });
+
+        let shape_id = raw
+            .spid
+            .as_ref()
+            .or(raw.id.as_ref())
+            .and_then(|id| id.rsplit_once("_s"))
+            .and_then(|(_, n)| string_to_unsignedint(n));
+
+        let fill = VmlFill::from_raw(
+            &raw,
+            raw_shape_type,
+            drawing_rels.clone(),
+            image_bytes.clone(),
+        );
+        let stroke = VmlStroke::from_raw(&raw, raw_shape_type);
+
+        let text = raw
+            .textbox
+            .map(|t| t.text.trim().to_string())
+            .filter(|t| !t.is_empty());
+
+        let image = raw.image_data.map(|i| VmlImage {
+            title: i.title,
+            source: i
+                .relid
+                .or(i.id)
+                .and_then(|id| BlipSourceType::parse_rel_helper(&id, drawing_rels, image_bytes)),
+        });
+
+        let anchor = raw.client_data.as_ref().and_then(Self::anchor);
+
+        return Self {
+            id: raw.id,
+            shape_id,
+            shape_type: raw_shape_type.map(|t| VmlShapeType::from_raw(t.clone())),
+            style: VmlShapeStyle::from_string(raw.style),
+            anchor,
+            fill,
+            stroke,
+            text,
+            image,
+            alt_text: raw.alt,
+            client_data: raw.client_data.map(VmlClientData::from_raw),
+        };
+    }
+
+    /// Excel writes `MoveWithCells` when the object does NOT move with the cells, and `SizeWithCells` when the object does NOT resize with the cells.
+    fn anchor(client_data: &XlsxVmlClientData) -> Option<DrawingAnchorType> {
+        let Some(anchor) = client_data.anchor.clone() else {
+            return None;
+        };
+        let (from, to) = Self::cell_markers(anchor);
+        if client_data.move_with_cells == Some(true) {
+            return Some(DrawingAnchorType::AbsoluteAnchor);
+        }
+        if client_data.size_with_cells == Some(true) {
+            return Some(DrawingAnchorType::OneCellAnchor(from));
+        }
+        return Some(DrawingAnchorType::TwoCellAnchor(from, to));
+    }
+
+    fn cell_markers(anchor: XlsxVmlAnchor) -> (CellMarker, CellMarker) {
+        // offsets are in pixel
+        let from = CellMarker {
+            col: anchor.left_column + 1,
+            col_offset: anchor.left_offset as f64 * 0.75,
+            row: anchor.top_row + 1,
+            row_offset: anchor.top_offset as f64 * 0.75,
+        };
+        let to = CellMarker {
+            col: anchor.right_column + 1,
+            col_offset: anchor.right_offset as f64 * 0.75,
+            row: anchor.bottom_row + 1,
+            row_offset: anchor.bottom_offset as f64 * 0.75,
+        };
+        return (from, to);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.shapetype?view=openxml-3.0.1
+///
+/// Template of a VML shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlShapeType {
+    /// Ex: `_x0000_t202`
+    pub id: Option<String>,
+
+    /// Preset shape type number. Ex: 202 for text boxes (comments), 201 for form controls, 75 for pictures.
+    pub preset: Option<u64>,
+
+    /// Width and height of the coordinate space of the path. Ex: `21600,21600`
+    pub coordsize: Option<String>,
+
+    /// Path of the edges. Ex: `m,l,21600r21600,l21600,xe`
+    pub path: Option<String>,
+}
+
+impl VmlShapeType {
+    pub(crate) fn from_raw(raw: XlsxVmlShapeType) -> Self {
+        return Self {
+            id: raw.id,
+            preset: raw.spt,
+            coordsize: raw.coordsize,
+            path: raw.path,
+        };
+    }
+}
+
+/// CSS styles of a VML shape.
+///
+/// Ex: `position:absolute;margin-left:59.25pt;margin-top:1.5pt;width:108pt;height:59.25pt;z-index:1;visibility:hidden`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlShapeStyle {
+    /// Ex: absolute, relative
+    pub position: Option<String>,
+
+    /// Left position in point
+    pub left: Option<f64>,
+
+    /// Top position in point
+    pub top: Option<f64>,
+
+    /// Width in point
+    pub width: Option<f64>,
+
+    /// Height in point
+    pub height: Option<f64>,
+
+    /// Rotation in degree
+    pub rotation: Option<f64>,
+
+    pub z_index: Option<i64>,
+
+    /// Whether the shape is hidden. Ex: comment boxes displayed only on hover
+    pub hidden: bool,
+}
+
+impl VmlShapeStyle {
+    pub(crate) fn from_string(style: Option<String>) -> Self {
+        let mut shape_style = Self {
+            position: None,
+            left: None,
+            top: None,
+            width: None,
+            height: None,
+            rotation: None,
+            z_index: None,
+            hidden: false,
+        };
+        let Some(style) = style else {
+            return shape_style;
+        };
+
+        for declaration in style.split(';') {
+            let Some((property, value)) = declaration.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match property.trim() {
+                "position" => shape_style.position = Some(value.to_string()),
+                "margin-left" | "left" => shape_style.left = vml_length_to_pt(value),
+                "margin-top" | "top" => shape_style.top = vml_length_to_pt(value),
+                "width" => shape_style.width = vml_length_to_pt(value),
+                "height" => shape_style.height = vml_length_to_pt(value),
+                "rotation" => shape_style.rotation = string_to_float(value),
+                "z-index" => shape_style.z_index = string_to_int(value),
+                "visibility" => shape_style.hidden = value == "hidden",
+                _ => {}
+            }
+        }
+
+        return shape_style;
+    }
+}
+
+/// Image drawn by a VML shape, ex: header/footer images
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlImage {
+    pub title: Option<String>,
+
+    /// None if the image relationship is not found
+    pub source: Option<BlipSourceType>,
+}
@@ -0,0 +1,67 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::{vml_color_to_hex, vml_fraction_to_float, vml_length_to_pt};
+use crate::{
+    common_types::HexColor,
+    raw::drawing::vml::{shape::XlsxVmlShape, shape_type::XlsxVmlShapeType},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.stroke?view=openxml-3.0.1
+///
+/// Outline of a VML shape, combined from the `stroke` element and the `stroked`/`strokecolor`/`strokeweight` attributes of the shape and its template.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VmlStroke {
+    /// Whether the outline is drawn
+    pub stroked: bool,
+
+    /// None if not specified (black by default)
+    pub color: Option<HexColor>,
+
+    /// Width in point
+    pub weight: f64,
+
+    /// Ex: solid, dot, dash, dashDot, longDash, or a custom pattern such as `1 1`
+    pub dash_style: Option<String>,
+
+    /// Opacity between 0 and 1
+    pub opacity: f64,
+}
+
+impl VmlStroke {
+    pub(crate) fn from_raw(shape: &XlsxVmlShape, shape_type: Option<&XlsxVmlShapeType>) -> Self {
+        let stroke = shape
+            .stroke
+            .clone()
+            .or(shape_type.and_then(|t| t.stroke.clone()));
+        let stroked = stroke
+            .as_ref()
+            .and_then(|s| s.on)
+            .or(shape.stroked)
+            .or(shape_type.and_then(|t| t.stroked))
+            .unwrap_or(true);
+        let color = stroke
+            .as_ref()
+            .and_then(|s| s.color.clone())
+            .or(shape.stroke_color.clone())
+            .or(shape_type.and_then(|t| t.stroke_color.clone()));
+        let weight = stroke
+            .as_ref()
+            .and_then(|s| s.weight.clone())
+            .or(shape.stroke_weight.clone())
+            .and_then(|w| vml_length_to_pt(&w))
+            .unwrap_or(0.75);
+
+        return Self {
+            stroked,
+            color: vml_color_to_hex(color),
+            weight,
+            dash_style: stroke.as_ref().and_then(|s| s.dash_style.clone()),
+            opacity: stroke
+                .and_then(|s| s.opacity)
+                .and_then(|o| vml_fraction_to_float(&o))
+                .unwrap_or(1.0),
+        };
+    }
+}
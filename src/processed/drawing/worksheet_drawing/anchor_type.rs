@@ -1,8 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-use crate::raw::drawing::worksheet_drawing::{
-    one_cell_anchor::XlsxOneCellAnchor, two_cell_anchor::XlsxTwoCellAnchor,
+use crate::raw::{
+    drawing::worksheet_drawing::{
+        one_cell_anchor::XlsxOneCellAnchor, two_cell_anchor::XlsxTwoCellAnchor,
+    },
+    spreadsheet::sheet::worksheet::object_anchor::XlsxObjectAnchor,
 };
 
 use super::cell_marker::CellMarker;
@@ -36,4 +39,18 @@ impl DrawingAnchorType {
     pub(crate) fn from_one_cell_anchor(raw: XlsxOneCellAnchor) -> Self {
         return Self::OneCellAnchor(CellMarker::from_raw(raw.from));
     }
+
+    /// anchor of embedded objects and controls (`objectPr` and `controlPr`)
+    pub(crate) fn from_object_anchor(raw: XlsxObjectAnchor) -> Self {
+        let move_with_cells = raw.move_with_cells.unwrap_or(false);
+        let size_with_cells = raw.size_with_cells.unwrap_or(false);
+
+        return match (move_with_cells, size_with_cells) {
+            (true, true) => {
+                Self::TwoCellAnchor(CellMarker::from_raw(raw.from), CellMarker::from_raw(raw.to))
+            }
+            (true, false) => Self::OneCellAnchor(CellMarker::from_raw(raw.from)),
+            _ => Self::AbsoluteAnchor,
+        };
+    }
 }
@@ -0,0 +1,541 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use crate::{
+    processed::{
+        drawing::{
+            common_types::{extent::Extents, offset::Offset},
+            shape::{shape_properties::ShapeProperties, transform_2d::Transform2D},
+        },
+        spreadsheet::sheet::worksheet::cell::cell_property::CellProperty,
+    },
+    raw::{
+        drawing::worksheet_drawing::{
+            absolute_anchor::XlsxAbsoluteAnchor, one_cell_anchor::XlsxOneCellAnchor,
+            two_cell_anchor::XlsxTwoCellAnchor,
+        },
+        spreadsheet::sheet::{
+            sheet_format_properties::XlsxSheetFormatProperties, worksheet::XlsxWorksheet,
+        },
+    },
+};
+
+use super::{cell_marker::CellMarker, content_type::DrawingContentType};
+
+/// English Metric Units (EMU) per point
+const EMU_PER_POINT: f64 = 12700.0;
+
+/// Absolute rectangle of a drawing within the sheet, in points from the top left corner of the sheet.
+///
+/// The rectangle is the one of the shape before rotation: the shape is flipped and rotated around its center.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DrawingBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+
+    /// Clockwise rotation in degrees (0 - 360), including the rotation of the parent groups
+    pub rotation: f64,
+
+    /// Including the flips of the parent groups
+    pub horizontal_flip: bool,
+
+    /// Including the flips of the parent groups
+    pub vertical_flip: bool,
+
+    /// Bounds of the contents of a group shape, in the order of `GroupShape.contents`
+    pub children: Vec<DrawingBounds>,
+}
+
+impl DrawingBounds {
+    /// Same bounds with x, y, width and height in EMUs (12,700 EMUs per point)
+    pub fn to_emu(&self) -> Self {
+        return Self {
+            x: (self.x * EMU_PER_POINT).round(),
+            y: (self.y * EMU_PER_POINT).round(),
+            width: (self.width * EMU_PER_POINT).round(),
+            height: (self.height * EMU_PER_POINT).round(),
+            rotation: self.rotation,
+            horizontal_flip: self.horizontal_flip,
+            vertical_flip: self.vertical_flip,
+            children: self.children.iter().map(|c| c.to_emu()).collect(),
+        };
+    }
+
+    /// Axis aligned rectangle enclosing the rotated shape, without children
+    pub fn bounding_box(&self) -> Self {
+        let radian = self.rotation.to_radians();
+        let (sin, cos) = (radian.sin().abs(), radian.cos().abs());
+        let width = self.width * cos + self.height * sin;
+        let height = self.width * sin + self.height * cos;
+        let (center_x, center_y) = self.center();
+
+        return Self {
+            x: center_x - width / 2.0,
+            y: center_y - height / 2.0,
+            width,
+            height,
+            rotation: 0.0,
+            horizontal_flip: false,
+            vertical_flip: false,
+            children: vec![],
+        };
+    }
+
+    fn center(&self) -> (f64, f64) {
+        return (self.x + self.width / 2.0, self.y + self.height / 2.0);
+    }
+
+    /// * rectangle: (x, y, width, height) of the content in the sheet
+    pub(crate) fn from_content(
+        content: &DrawingContentType,
+        rectangle: (f64, f64, f64, f64),
+    ) -> Self {
+        let transform = transform_of(content);
+        let mut bounds = Self {
+            x: rectangle.0,
+            y: rectangle.1,
+            width: rectangle.2,
+            height: rectangle.3,
+            rotation: normalize_degree(transform.rotation),
+            horizontal_flip: transform.horizontal_flip,
+            vertical_flip: transform.vertical_flip,
+            children: vec![],
+        };
+        bounds.children = bounds.children_of(content);
+        return bounds;
+    }
+
+    /// bounds of the contents of a group, placed from the child coordinate space (`chOff`, `chExt`) of the group into the sheet
+    fn children_of(&self, content: &DrawingContentType) -> Vec<Self> {
+        let DrawingContentType::GroupShape(group) = content else {
+            return vec![];
+        };
+        let properties = &group.visual_properties;
+        let child_position = properties
+            .child_position
+            .clone()
+            .unwrap_or(properties.position.clone());
+        let child_size = properties.child_size.unwrap_or(properties.size);
+        let scale_x = if child_size.width > 0.0 {
+            self.width / child_size.width
+        } else {
+            1.0
+        };
+        let scale_y = if child_size.height > 0.0 {
+            self.height / child_size.height
+        } else {
+            1.0
+        };
+
+        let (group_center_x, group_center_y) = self.center();
+        let radian = self.rotation.to_radians();
+        // a single flip of the group reverses the direction of the rotations of the children
+        let rotation_sign = if self.horizontal_flip != self.vertical_flip {
+            -1.0
+        } else {
+            1.0
+        };
+
+        return group
+            .contents
+            .iter()
+            .map(|child| {
+                let (position, size) = position_and_size_of(child);
+                let width = size.width * scale_x;
+                let height = size.height * scale_y;
+                let mut center_x = self.x + (position.x - child_position.x) * scale_x + width / 2.0;
+                let mut center_y =
+                    self.y + (position.y - child_position.y) * scale_y + height / 2.0;
+
+                if self.horizontal_flip {
+                    center_x = 2.0 * group_center_x - center_x;
+                }
+                if self.vertical_flip {
+                    center_y = 2.0 * group_center_y - center_y;
+                }
+                let (dx, dy) = (center_x - group_center_x, center_y - group_center_y);
+                center_x = group_center_x + dx * radian.cos() - dy * radian.sin();
+                center_y = group_center_y + dx * radian.sin() + dy * radian.cos();
+
+                let transform = transform_of(child);
+                let mut bounds = Self {
+                    x: center_x - width / 2.0,
+                    y: center_y - height / 2.0,
+                    width,
+                    height,
+                    rotation: normalize_degree(self.rotation + rotation_sign * transform.rotation),
+                    horizontal_flip: transform.horizontal_flip != self.horizontal_flip,
+                    vertical_flip: transform.vertical_flip != self.vertical_flip,
+                    children: vec![],
+                };
+                bounds.children = bounds.children_of(child);
+                bounds
+            })
+            .collect();
+    }
+}
+
+fn visual_properties_of(content: &DrawingContentType) -> Option<&ShapeProperties> {
+    return match content {
+        DrawingContentType::Picture(p) => Some(&p.visual_properties),
+        DrawingContentType::Shape(s) => Some(&s.visual_properties),
+        DrawingContentType::GroupShape(g) => Some(&g.visual_properties),
+        DrawingContentType::ConnectionShape(c) => Some(&c.visual_properties),
+        DrawingContentType::GraphicFrame(f) => Some(&f.visual_properties),
+        DrawingContentType::ContentPart(_) => None,
+    };
+}
+
+fn transform_of(content: &DrawingContentType) -> Transform2D {
+    if let DrawingContentType::ContentPart(part) = content {
+        return part.transform.clone();
+    }
+    return visual_properties_of(content)
+        .map(|p| p.transform_2d.clone())
+        .unwrap_or(Transform2D::default());
+}
+
+fn position_and_size_of(content: &DrawingContentType) -> (Offset, Extents) {
+    return visual_properties_of(content)
+        .map(|p| (p.position.clone(), p.size))
+        .unwrap_or((Offset::default(), Extents::default()));
+}
+
+fn normalize_degree(degree: f64) -> f64 {
+    return degree.rem_euclid(360.0);
+}
+
+/// Location of an anchor as written in the drawing part.
+///
+/// Unlike `DrawingAnchorType`, markers are kept for two cell anchors edited as one cell or absolute.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AnchorPlacement {
+    /// from, to
+    TwoCell(CellMarker, CellMarker),
+
+    /// from, size
+    OneCell(CellMarker, Extents),
+
+    /// position, size
+    Absolute(Offset, Extents),
+}
+
+impl AnchorPlacement {
+    pub(crate) fn from_two_cell_anchor(raw: &XlsxTwoCellAnchor) -> Self {
+        return Self::TwoCell(
+            CellMarker::from_raw(raw.from.clone()),
+            CellMarker::from_raw(raw.to.clone()),
+        );
+    }
+
+    pub(crate) fn from_one_cell_anchor(raw: &XlsxOneCellAnchor) -> Self {
+        return Self::OneCell(
+            CellMarker::from_raw(raw.from.clone()),
+            Extents::from_raw(raw.extent.clone()),
+        );
+    }
+
+    pub(crate) fn from_absolute_anchor(raw: &XlsxAbsoluteAnchor) -> Self {
+        return Self::Absolute(
+            Offset::from_spreadsheet_position(raw.position.clone()),
+            Extents::from_raw(raw.extent.clone()),
+        );
+    }
+
+    /// (x, y, width, height) in points
+    pub(crate) fn rectangle(&self, geometry: &SheetGeometry) -> (f64, f64, f64, f64) {
+        return match self {
+            Self::TwoCell(from, to) => {
+                let (x, y) = geometry.marker_position(from);
+                let (to_x, to_y) = geometry.marker_position(to);
+                (x, y, (to_x - x).max(0.0), (to_y - y).max(0.0))
+            }
+            Self::OneCell(from, size) => {
+                let (x, y) = geometry.marker_position(from);
+                (x, y, size.width, size.height)
+            }
+            Self::Absolute(position, size) => (position.x, position.y, size.width, size.height),
+        };
+    }
+}
+
+/// Column widths and row heights of a sheet in points, to resolve cell markers
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SheetGeometry {
+    /// width of the columns without column information
+    default_column_width: f64,
+
+    /// height of the rows without row information, 0 if rows are hidden by default (`zeroHeight`)
+    default_row_height: f64,
+
+    /// (min, max, width) of the column information, 0 if hidden
+    columns: Vec<(u64, u64, f64)>,
+
+    /// (row index, height) of the rows with a height or hidden flag, 0 if hidden
+    rows: BTreeMap<u64, f64>,
+}
+
+impl SheetGeometry {
+    /// Chartsheets do not have any rows or columns
+    pub(crate) fn default() -> Self {
+        return Self::from_format_properties(None);
+    }
+
+    fn from_format_properties(sheet_format_properties: Option<XlsxSheetFormatProperties>) -> Self {
+        let hidden_by_default = sheet_format_properties
+            .as_ref()
+            .and_then(|p| p.zero_height)
+            .unwrap_or(false);
+        let default_row_height = if hidden_by_default {
+            0.0
+        } else {
+            CellProperty::default_cell_height(sheet_format_properties.clone())
+        };
+
+        return Self {
+            default_column_width: CellProperty::cell_width(None, sheet_format_properties),
+            default_row_height,
+            columns: vec![],
+            rows: BTreeMap::new(),
+        };
+    }
+
+    pub(crate) fn from_worksheet(worksheet: &XlsxWorksheet) -> Self {
+        let sheet_format_properties = worksheet.sheet_format_properties.clone();
+        let mut geometry = Self::from_format_properties(sheet_format_properties.clone());
+
+        geometry.columns = worksheet
+            .column_infos
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter_map(|c| {
+                let min = c.min_column?;
+                let max = c.max_column.unwrap_or(min);
+                let width = if c.hidden == Some(true) {
+                    0.0
+                } else {
+                    CellProperty::cell_width(Some(c), sheet_format_properties.clone())
+                };
+                Some((min, max, width))
+            })
+            .collect();
+
+        // height of a visible row without height, even if rows are hidden by default
+        let visible_row_height = CellProperty::default_cell_height(sheet_format_properties);
+        let rows = worksheet.sheet_data.as_ref().and_then(|d| d.rows.as_ref());
+        for row in rows.into_iter().flatten() {
+            let Some(index) = row.row_index else {
+                continue;
+            };
+            if row.height.is_none() && row.hidden.is_none() {
+                continue;
+            }
+            let hidden = row.hidden.unwrap_or(geometry.default_row_height == 0.0);
+            let height = if hidden {
+                0.0
+            } else {
+                row.height.unwrap_or(visible_row_height)
+            };
+            geometry.rows.insert(index, height);
+        }
+
+        return geometry;
+    }
+
+    /// width of a column in points (1 based index), 0 if hidden
+    pub(crate) fn column_width(&self, column: u64) -> f64 {
+        return self
+            .columns
+            .iter()
+            .find(|(min, max, _)| (*min..=*max).contains(&column))
+            .map(|c| c.2)
+            .unwrap_or(self.default_column_width);
+    }
+
+    /// height of a row in points (1 based index), 0 if hidden
+    pub(crate) fn row_height(&self, row: u64) -> f64 {
+        return *self.rows.get(&row).unwrap_or(&self.default_row_height);
+    }
+
+    /// distance in points from the left of the sheet to the left of a column (1 based index)
+    pub(crate) fn column_start(&self, column: u64) -> f64 {
+        let preceding = column.saturating_sub(1);
+        let mut start = preceding as f64 * self.default_column_width;
+        for (min, max, width) in self.columns.iter() {
+            let max = (*max).min(preceding);
+            if *min <= max {
+                start += (max - min + 1) as f64 * (width - self.default_column_width);
+            }
+        }
+        return start;
+    }
+
+    /// distance in points from the top of the sheet to the top of a row (1 based index)
+    pub(crate) fn row_start(&self, row: u64) -> f64 {
+        let preceding = row.saturating_sub(1);
+        let mut start = preceding as f64 * self.default_row_height;
+        for (_, height) in self.rows.range(..row) {
+            start += height - self.default_row_height;
+        }
+        return start;
+    }
+
+    /// (x, y) of a cell marker. Offsets do not exceed the size of the cell: 0 within hidden rows and columns.
+    fn marker_position(&self, marker: &CellMarker) -> (f64, f64) {
+        // not `clamp`, which panics if the size of the cell is negative or NaN
+        let x = self.column_start(marker.col)
+            + marker
+                .col_offset
+                .max(0.0)
+                .min(self.column_width(marker.col));
+        let y = self.row_start(marker.row)
+            + marker.row_offset.max(0.0).min(self.row_height(marker.row));
+        return (x, y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processed::drawing::worksheet_drawing::{
+        group_shape::GroupShape, non_visual_properties::NonVisualDrawingProperty,
+    };
+
+    /// (x, y, width, height) in the coordinate space of the parent group
+    fn group(
+        rectangle: (f64, f64, f64, f64),
+        transform: Transform2D,
+        child_size: Option<Extents>,
+        contents: Vec<DrawingContentType>,
+    ) -> DrawingContentType {
+        let mut visual_properties = ShapeProperties::default();
+        visual_properties.position = Offset {
+            x: rectangle.0,
+            y: rectangle.1,
+        };
+        visual_properties.size = Extents {
+            width: rectangle.2,
+            height: rectangle.3,
+        };
+        visual_properties.child_position = Some(Offset { x: 0.0, y: 0.0 });
+        visual_properties.child_size = child_size;
+        visual_properties.transform_2d = transform;
+
+        return DrawingContentType::GroupShape(GroupShape {
+            contents,
+            visual_properties,
+            non_visual_properties: NonVisualDrawingProperty::default(),
+        });
+    }
+
+    fn transform(rotation: f64, horizontal_flip: bool, vertical_flip: bool) -> Transform2D {
+        return Transform2D {
+            horizontal_flip,
+            vertical_flip,
+            rotation,
+        };
+    }
+
+    fn assert_rectangle(bounds: &DrawingBounds, expected: (f64, f64, f64, f64, f64)) {
+        let actual = (
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+            bounds.rotation,
+        );
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        assert!(
+            close(actual.0, expected.0)
+                && close(actual.1, expected.1)
+                && close(actual.2, expected.2)
+                && close(actual.3, expected.3)
+                && close(actual.4, expected.4),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn children_of_rotated_group() {
+        let content = group(
+            (0.0, 0.0, 200.0, 100.0),
+            transform(90.0, false, false),
+            Some(Extents {
+                width: 400.0,
+                height: 200.0,
+            }),
+            vec![
+                group(
+                    (0.0, 0.0, 200.0, 100.0),
+                    transform(30.0, false, false),
+                    None,
+                    vec![],
+                ),
+                group(
+                    (200.0, 100.0, 200.0, 100.0),
+                    transform(0.0, false, true),
+                    None,
+                    vec![],
+                ),
+            ],
+        );
+
+        let bounds = DrawingBounds::from_content(&content, (0.0, 0.0, 200.0, 100.0));
+        assert_eq!(bounds.children.len(), 2);
+
+        // scaled by half, then rotated around the center of the group (100, 50)
+        let rotated = &bounds.children[0];
+        assert_rectangle(rotated, (75.0, -25.0, 100.0, 50.0, 120.0));
+        assert!(!rotated.horizontal_flip && !rotated.vertical_flip);
+
+        let flipped = &bounds.children[1];
+        assert_rectangle(flipped, (25.0, 75.0, 100.0, 50.0, 90.0));
+        assert!(!flipped.horizontal_flip && flipped.vertical_flip);
+    }
+
+    #[test]
+    fn children_of_flipped_group() {
+        let content = group(
+            (0.0, 0.0, 100.0, 50.0),
+            transform(0.0, true, false),
+            None,
+            vec![group(
+                (0.0, 0.0, 20.0, 10.0),
+                transform(30.0, false, false),
+                None,
+                vec![],
+            )],
+        );
+
+        let bounds = DrawingBounds::from_content(&content, (10.0, 20.0, 100.0, 50.0));
+
+        // mirrored around the center of the group, rotation reversed by the single flip
+        let child = &bounds.children[0];
+        assert_rectangle(child, (90.0, 20.0, 20.0, 10.0, 330.0));
+        assert!(child.horizontal_flip && !child.vertical_flip);
+    }
+
+    #[test]
+    fn marker_position_within_hidden_column() {
+        let mut geometry = SheetGeometry::default();
+        geometry.columns = vec![(2, 2, 0.0)];
+        let marker = CellMarker {
+            col: 2,
+            col_offset: 12.0,
+            row: 1,
+            row_offset: -3.0,
+        };
+
+        let (x, y) = geometry.marker_position(&marker);
+        assert_eq!(x, geometry.column_start(2));
+        assert_eq!(y, 0.0);
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use crate::{
+    packaging::relationship::XlsxRelationships,
+    processed::drawing::{
+        ink::Ink,
+        shape::{black_white_mode::BlackWhiteModeValues, transform_2d::Transform2D},
+    },
+    raw::{
+        drawing::{
+            ink::XlsxInk,
+            worksheet_drawing::{client_data::XlsxClientData, content_part::XlsxContentPart},
+        },
+        spreadsheet::workbook::defined_name::XlsxDefinedNames,
+    },
+};
+
+use super::non_visual_properties::NonVisualDrawingProperty;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.drawing.contentpart?view=openxml-3.0.1
+///
+/// Drawing referencing content stored in another part, such as ink annotations (InkML).
+///
+/// contentPart (Content Part)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ContentPart {
+    /// Strokes of the annotation. None if the referenced part is not ink.
+    pub ink: Option<Ink>,
+
+    /// Reference to custom function
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub r#macro: Option<String>,
+
+    /// Publish to Server Flag
+    pub published: bool,
+
+    pub black_white_mode: BlackWhiteModeValues,
+
+    pub transform: Transform2D,
+
+    /// This element specifies all non-visual properties for a content part.
+    pub non_visual_properties: NonVisualDrawingProperty,
+}
+
+impl ContentPart {
+    pub(crate) fn from_raw(
+        raw: XlsxContentPart,
+        client_data: Option<XlsxClientData>,
+        drawing_relationship: XlsxRelationships,
+        inks: BTreeMap<String, XlsxInk>,
+        defined_names: XlsxDefinedNames,
+    ) -> Self {
+        let ink = raw
+            .id
+            .as_ref()
+            .and_then(|id| inks.get(id))
+            .map(|ink| Ink::from_raw(ink.clone()));
+
+        let application_properties = raw.application_non_visual_drawing_properties.clone();
+
+        return Self {
+            ink,
+            r#macro: application_properties
+                .as_ref()
+                .and_then(|p| p.r#macro.clone())
+                .filter(|m| !m.is_empty()),
+            published: application_properties
+                .and_then(|p| p.f_published)
+                .unwrap_or(false),
+            black_white_mode: BlackWhiteModeValues::from_string(raw.black_white_mode),
+            transform: Transform2D::from_transform_2d(raw.transform_2d),
+            non_visual_properties: NonVisualDrawingProperty::from_content_part_properties(
+                raw.excel_non_visual_content_part_shape_properties,
+                client_data,
+                drawing_relationship,
+                defined_names,
+            ),
+        };
+    }
+}
@@ -13,13 +13,17 @@ use crate::{
     },
     raw::{
         drawing::{
+            chart::XlsxChartSpace,
+            diagram::XlsxDiagrams,
             graphic::graphic_frame::XlsxGraphicFrame,
             image::picture::XlsxPicture,
+            ink::XlsxInk,
             scheme::color_scheme::XlsxColorScheme,
             shape::{connection_shape::XlsxConnectionShape, shape_style::XlsxShapeStyle},
             theme::XlsxTheme,
             worksheet_drawing::{
-                client_data::XlsxClientData, drawing_content_type::XlsxWorksheetDrawingContentType,
+                client_data::XlsxClientData, content_part::XlsxContentPart,
+                drawing_content_type::XlsxWorksheetDrawingContentType,
                 group_shape::XlsxGroupShape, spreadsheet_extent::XlsxSpreadsheetExtent,
                 spreadsheet_position::XlsxSpreadsheetPosition, spreadsheet_shape::XlsxShape,
             },
@@ -28,7 +32,7 @@ use crate::{
     },
 };
 
-use super::{group_shape::GroupShape, spreadsheet_shape::Shape};
+use super::{content_part::ContentPart, group_shape::GroupShape, spreadsheet_shape::Shape};
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -39,6 +43,7 @@ pub enum DrawingContentType {
     GroupShape(GroupShape),
     ConnectionShape(ConnectionShape),
     GraphicFrame(GraphicFrame),
+    ContentPart(ContentPart),
 }
 
 impl DrawingContentType {
@@ -50,6 +55,8 @@ impl DrawingContentType {
         parent_group_fill: Option<Fill>,
         drawing_relationship: XlsxRelationships,
         image_bytes: BTreeMap<String, Vec<u8>>,
+        charts: &BTreeMap<String, XlsxChartSpace>,
+        diagrams: &XlsxDiagrams,
         defined_names: XlsxDefinedNames,
         color_scheme: Option<XlsxColorScheme>,
         theme: Option<Box<XlsxTheme>>,
@@ -76,6 +83,8 @@ impl DrawingContentType {
                     client_data,
                     drawing_relationship,
                     image_bytes,
+                    charts,
+                    diagrams,
                     defined_names,
                     color_scheme,
                     theme,
@@ -117,11 +126,34 @@ impl DrawingContentType {
                     client_data,
                     drawing_relationship,
                     defined_names,
+                    charts,
+                    diagrams,
+                    color_scheme,
+                    theme,
                 ));
             }
         };
     }
 
+    /// content part of an anchor (Ex: ink annotations)
+    pub(crate) fn from_content_part(
+        raw: Option<XlsxContentPart>,
+        client_data: Option<XlsxClientData>,
+        drawing_relationship: XlsxRelationships,
+        inks: BTreeMap<String, XlsxInk>,
+        defined_names: XlsxDefinedNames,
+    ) -> Option<Self> {
+        let Some(raw) = raw else { return None };
+        let content_part = ContentPart::from_raw(
+            raw,
+            client_data,
+            drawing_relationship,
+            inks,
+            defined_names,
+        );
+        return Some(Self::ContentPart(content_part));
+    }
+
     fn from_graphic_frame(
         graphic: XlsxGraphicFrame,
         extent: Option<XlsxSpreadsheetExtent>,
@@ -129,6 +161,10 @@ impl DrawingContentType {
         client_data: Option<XlsxClientData>,
         drawing_relationship: XlsxRelationships,
         defined_names: XlsxDefinedNames,
+        charts: &BTreeMap<String, XlsxChartSpace>,
+        diagrams: &XlsxDiagrams,
+        color_scheme: Option<XlsxColorScheme>,
+        theme: Option<Box<XlsxTheme>>,
     ) -> Self {
         let graphic_frame = GraphicFrame::from_spreadsheet_graphic_frame(
             graphic,
@@ -137,6 +173,10 @@ impl DrawingContentType {
             client_data.clone(),
             drawing_relationship.clone(),
             defined_names.clone(),
+            charts,
+            diagrams,
+            color_scheme,
+            theme,
         );
 
         return Self::GraphicFrame(graphic_frame);
@@ -149,6 +189,8 @@ impl DrawingContentType {
         client_data: Option<XlsxClientData>,
         drawing_relationship: XlsxRelationships,
         image_bytes: BTreeMap<String, Vec<u8>>,
+        charts: &BTreeMap<String, XlsxChartSpace>,
+        diagrams: &XlsxDiagrams,
         defined_names: XlsxDefinedNames,
         color_scheme: Option<XlsxColorScheme>,
         theme: Option<Box<XlsxTheme>>,
@@ -160,6 +202,8 @@ impl DrawingContentType {
             client_data.clone(),
             drawing_relationship.clone(),
             image_bytes.clone(),
+            charts,
+            diagrams,
             defined_names.clone(),
             color_scheme.clone(),
             theme.clone(),
@@ -271,7 +315,7 @@ impl DrawingContentType {
     }
 
     /// get references from theme elemet defined in `XlsxShapeStyle`
-    fn get_references(
+    pub(crate) fn get_references(
         raw: Option<XlsxShapeStyle>,
         color_scheme: Option<XlsxColorScheme>,
         theme: Option<Box<XlsxTheme>>,
@@ -4,6 +4,8 @@ use serde::Serialize;
 use std::collections::BTreeMap;
 
 use crate::raw::drawing::{
+    chart::XlsxChartSpace,
+    diagram::XlsxDiagrams,
     scheme::color_scheme::XlsxColorScheme,
     theme::XlsxTheme,
     worksheet_drawing::{
@@ -79,6 +81,8 @@ impl GroupShape {
         client_data: Option<XlsxClientData>,
         drawing_relationship: XlsxRelationships,
         image_bytes: BTreeMap<String, Vec<u8>>,
+        charts: &BTreeMap<String, XlsxChartSpace>,
+        diagrams: &XlsxDiagrams,
         defined_names: XlsxDefinedNames,
         color_scheme: Option<XlsxColorScheme>,
         theme: Option<Box<XlsxTheme>>,
@@ -111,6 +115,8 @@ impl GroupShape {
                     fill.clone(),
                     drawing_relationship.clone(),
                     image_bytes.clone(),
+                    charts,
+                    diagrams,
                     defined_names.clone(),
                     color_scheme.clone(),
                     theme.clone(),
@@ -2,7 +2,8 @@
 use serde::Serialize;
 
 use crate::raw::drawing::non_visual_properties::{
-    connection_shape_locks::XlsxConnectionShapeLocks, graphic_frame_locks::XlsxGraphicFrameLocks,
+    connection_shape_locks::XlsxConnectionShapeLocks, content_part_locks::XlsxContentPartLocks,
+    graphic_frame_locks::XlsxGraphicFrameLocks,
     group_shape_locks::XlsxGroupShapeLocks, picture_locks::XlsxPictureLocks,
     shape_locks::XlsxShapeLocks,
 };
@@ -220,6 +221,49 @@ impl LockTypeValues {
         return locks;
     }
 
+    pub(crate) fn from_content_part_locks(raw: Option<XlsxContentPartLocks>) -> Vec<Self> {
+        let Some(raw) = raw else { return vec![] };
+        let mut locks: Vec<Self> = vec![];
+        if raw.no_adjust_handles == Some(true) {
+            locks.push(Self::NoAdjustHandles);
+        }
+        if raw.no_change_arrowheads == Some(true) {
+            locks.push(Self::NoChangeArrowheads);
+        }
+        if raw.no_aspect_ratio_change == Some(true) {
+            locks.push(Self::NoChangeAspectRatio);
+        }
+
+        if raw.no_change_shape_type == Some(true) {
+            locks.push(Self::NoChangeShapeType);
+        }
+
+        if raw.no_edit_points == Some(true) {
+            locks.push(Self::NoEditPoints);
+        }
+
+        if raw.no_grouping == Some(true) {
+            locks.push(Self::NoGrouping);
+        }
+
+        if raw.no_move == Some(true) {
+            locks.push(Self::NoMove);
+        }
+
+        if raw.no_resize == Some(true) {
+            locks.push(Self::NoResize);
+        }
+
+        if raw.no_select == Some(true) {
+            locks.push(Self::NoSelect);
+        }
+
+        if raw.no_rotation == Some(true) {
+            locks.push(Self::NoRotation);
+        }
+        return locks;
+    }
+
     pub(crate) fn from_graphic_frame_locks(raw: Option<XlsxGraphicFrameLocks>) -> Vec<Self> {
         let Some(raw) = raw else { return vec![] };
         let mut locks: Vec<Self> = vec![];
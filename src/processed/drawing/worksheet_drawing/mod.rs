@@ -1,5 +1,7 @@
 pub mod anchor_type;
+pub mod bounds;
 pub mod cell_marker;
+pub mod content_part;
 pub mod content_type;
 pub mod group_shape;
 pub mod lock_type;
@@ -7,14 +9,180 @@ pub mod non_visual_properties;
 pub mod spreadsheet_shape;
 
 use anchor_type::DrawingAnchorType;
+use bounds::{AnchorPlacement, DrawingBounds, SheetGeometry};
 use content_type::DrawingContentType;
 
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    packaging::relationship::XlsxRelationships,
+    raw::{
+        drawing::{
+            chart::XlsxChartSpace, diagram::XlsxDiagrams, ink::XlsxInk,
+            scheme::color_scheme::XlsxColorScheme, theme::XlsxTheme,
+            worksheet_drawing::XlsxWorksheetDrawingType,
+        },
+        spreadsheet::workbook::defined_name::XlsxDefinedNames,
+    },
+};
+
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct WorksheetDrawing {
     pub anchor: DrawingAnchorType,
     pub content: DrawingContentType,
+
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    placement: AnchorPlacement,
+
+    /// shared by the drawings of a sheet
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    sheet_geometry: Arc<SheetGeometry>,
+}
+
+impl WorksheetDrawing {
+    /// Drawing of an anchor in xl/drawings/drawing{}.xml, shared by worksheets and chartsheets.
+    ///
+    /// None if the anchor does not contain any supported content.
+    pub(crate) fn from_raw(
+        raw: XlsxWorksheetDrawingType,
+        drawing_rels: XlsxRelationships,
+        image_bytes: BTreeMap<String, Vec<u8>>,
+        charts: &BTreeMap<String, XlsxChartSpace>,
+        inks: BTreeMap<String, XlsxInk>,
+        diagrams: &XlsxDiagrams,
+        defined_names: XlsxDefinedNames,
+        color_scheme: Option<XlsxColorScheme>,
+        theme: Option<Box<XlsxTheme>>,
+        sheet_geometry: Arc<SheetGeometry>,
+    ) -> Option<Self> {
+        match raw {
+            XlsxWorksheetDrawingType::AbsoluteAnchor(absolute_anchor) => {
+                let Some(content) = DrawingContentType::from_raw(
+                    absolute_anchor.clone().drawing_content,
+                    absolute_anchor.clone().extent,
+                    absolute_anchor.clone().position,
+                    absolute_anchor.clone().client_data,
+                    None,
+                    drawing_rels.clone(),
+                    image_bytes,
+                    charts,
+                    diagrams,
+                    defined_names.clone(),
+                    color_scheme,
+                    theme,
+                )
+                .or_else(|| {
+                    DrawingContentType::from_content_part(
+                        absolute_anchor.clone().content_part,
+                        absolute_anchor.clone().client_data,
+                        drawing_rels,
+                        inks,
+                        defined_names,
+                    )
+                }) else {
+                    return None;
+                };
+
+                let anchor = DrawingAnchorType::AbsoluteAnchor;
+                let placement = AnchorPlacement::from_absolute_anchor(&absolute_anchor);
+
+                return Some(Self {
+                    anchor,
+                    content,
+                    placement,
+                    sheet_geometry,
+                });
+            }
+            XlsxWorksheetDrawingType::OneCellAnchor(one_cell_anchor_drawing) => {
+                let Some(content) = DrawingContentType::from_raw(
+                    one_cell_anchor_drawing.clone().drawing_content,
+                    one_cell_anchor_drawing.clone().extent,
+                    None,
+                    one_cell_anchor_drawing.clone().client_data,
+                    None,
+                    drawing_rels.clone(),
+                    image_bytes,
+                    charts,
+                    diagrams,
+                    defined_names.clone(),
+                    color_scheme,
+                    theme,
+                )
+                .or_else(|| {
+                    DrawingContentType::from_content_part(
+                        one_cell_anchor_drawing.clone().content_part,
+                        one_cell_anchor_drawing.clone().client_data,
+                        drawing_rels,
+                        inks,
+                        defined_names,
+                    )
+                }) else {
+                    return None;
+                };
+
+                let anchor =
+                    DrawingAnchorType::from_one_cell_anchor(one_cell_anchor_drawing.clone());
+                let placement = AnchorPlacement::from_one_cell_anchor(&one_cell_anchor_drawing);
+
+                return Some(Self {
+                    anchor,
+                    content,
+                    placement,
+                    sheet_geometry,
+                });
+            }
+            XlsxWorksheetDrawingType::TwoCellAnchor(two_cell_anchor_drawing) => {
+                let Some(content) = DrawingContentType::from_raw(
+                    two_cell_anchor_drawing.clone().drawing_content,
+                    None,
+                    None,
+                    two_cell_anchor_drawing.clone().client_data,
+                    None,
+                    drawing_rels.clone(),
+                    image_bytes,
+                    charts,
+                    diagrams,
+                    defined_names.clone(),
+                    color_scheme,
+                    theme,
+                )
+                .or_else(|| {
+                    DrawingContentType::from_content_part(
+                        two_cell_anchor_drawing.clone().content_part,
+                        two_cell_anchor_drawing.clone().client_data,
+                        drawing_rels,
+                        inks,
+                        defined_names,
+                    )
+                }) else {
+                    return None;
+                };
+
+                let anchor =
+                    DrawingAnchorType::from_two_cell_anchor(two_cell_anchor_drawing.clone());
+                let placement = AnchorPlacement::from_two_cell_anchor(&two_cell_anchor_drawing);
+
+                return Some(Self {
+                    anchor,
+                    content,
+                    placement,
+                    sheet_geometry,
+                });
+            }
+        }
+    }
+
+    /// Absolute rectangle of the drawing within the sheet, in points.
+    ///
+    /// Cell markers are resolved with the widths of the columns and the heights of the rows (0 if hidden), or the defaults of the sheet format properties.
+    /// Contents of group shapes are placed from the child coordinate space of the groups, including their rotation and flips.
+    /// Use `DrawingBounds::to_emu` for EMUs.
+    pub fn bounds(&self) -> DrawingBounds {
+        let rectangle = self.placement.rectangle(&self.sheet_geometry);
+        return DrawingBounds::from_content(&self.content, rectangle);
+    }
 }
@@ -7,6 +7,7 @@ use crate::{
     raw::{
         drawing::{
             non_visual_properties::{
+                excel_non_visual_content_part_shape_properties::XlsxExcelNonVisualContentPartShapeProperties,
                 non_visual_connection_shape_properties::XlsxNonVisualConnectionShapeProperties,
                 non_visual_drawing_properties::XlsxNonVisualDrawingProperties,
                 non_visual_graphic_frame_properties::XlsxNonVisualGraphicFrameProperties,
@@ -199,6 +200,31 @@ impl NonVisualDrawingProperty {
         return Self::add_client_data_properties(properties, client_data);
     }
 
+    pub(crate) fn from_content_part_properties(
+        raw: Option<XlsxExcelNonVisualContentPartShapeProperties>,
+        client_data: Option<XlsxClientData>,
+        drawing_relationship: XlsxRelationships,
+        defined_names: XlsxDefinedNames,
+    ) -> Self {
+        let mut properties = Self::default();
+
+        let Some(raw) = raw else {
+            return Self::add_client_data_properties(properties, client_data);
+        };
+        if let Some(ink_properties) = raw.clone().non_visual_ink_content_part_properties {
+            properties.locks =
+                LockTypeValues::from_content_part_locks(ink_properties.content_part_locks);
+        };
+
+        properties = Self::add_non_drawing_properties(
+            properties,
+            raw.clone().non_visual_drawing_properties,
+            drawing_relationship,
+            defined_names,
+        );
+        return Self::add_client_data_properties(properties, client_data);
+    }
+
     fn add_non_drawing_properties(
         mut properties: Self,
         drawing_properties: Option<XlsxNonVisualDrawingProperties>,
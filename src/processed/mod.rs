@@ -1,3 +1,4 @@
+pub mod document_properties;
 pub mod shared;
 pub mod spreadsheet;
 
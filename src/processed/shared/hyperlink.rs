@@ -6,7 +6,7 @@ use serde::Serialize;
 use crate::{
     common_types::{Coordinate, Dimension},
     packaging::relationship::{
-        raw_target_for_id, rel_for_id, XlsxRelationships, EXTERNAL_TARGET_MODE,
+        rel_for_id, zip_path_for_id, XlsxRelationships, EXTERNAL_TARGET_MODE,
     },
     raw::{
         drawing::text::hyperlink_on_event::XlsxHyperlinkOnEvent,
@@ -35,7 +35,7 @@ impl Hyperlink {
             // if let Some(v) = worksheet_rels.get(&r_id) {
             //     return Some(Self::External(ExternalHyperlink::from_string(v)));
             // }
-            if let Some(v) = raw_target_for_id(&worksheet_rels.clone(), &r_id) {
+            if let Some(v) = zip_path_for_id(&worksheet_rels.clone(), &r_id) {
                 return Some(Self::External(ExternalHyperlink::from_string(&v)));
             }
         }
@@ -0,0 +1,196 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::Coordinate,
+    processed::spreadsheet::{
+        sheet::worksheet::calculation_reference::CalculationReferenceMode,
+        sheet_basic_info::SheetBasicInfo,
+    },
+    raw::spreadsheet::{
+        calculation_chain::XlsxCalculationChain,
+        workbook::calculation_properties::XlsxCalculationProperties,
+    },
+};
+
+/// - CalculationChain: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.calculationchain?view=openxml-3.0.1
+/// - CalculationProperties: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.calculationproperties?view=openxml-3.0.1
+///
+/// The order in which the formulas were last calculated parsed from xl/calcChain.xml,
+/// with the calculation settings of the workbook.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CalculationChain {
+    /// Cells with formulas in the order of the last calculation
+    pub entries: Vec<CalculationChainEntry>,
+
+    pub mode: CalculationMode,
+
+    /// Whether all formulas are calculated when the workbook is opened
+    pub full_calculation_on_load: bool,
+
+    /// Whether all formulas are calculated at every calculation
+    pub force_full_calculation: bool,
+
+    pub calculation_on_save: bool,
+
+    /// Whether the last calculation completed
+    pub calculation_completed: bool,
+
+    /// False when calculating with the precision of the displayed values
+    pub full_precision: bool,
+
+    /// Iteration settings of circular references. None if iterative calculation is disabled.
+    pub iteration: Option<CalculationIteration>,
+
+    pub reference_mode: CalculationReferenceMode,
+
+    /// Version of the engine of the last calculation
+    pub calculation_id: Option<i64>,
+}
+
+impl CalculationChain {
+    /// * sheets: sheets of the workbook, to resolve the sheet ids of the entries
+    pub(crate) fn from_raw(
+        chain: XlsxCalculationChain,
+        properties: Option<XlsxCalculationProperties>,
+        sheets: &Vec<SheetBasicInfo>,
+    ) -> Self {
+        // the sheet id of an entry is the one of the previous entry if not specified
+        let mut sheet_id: u64 = 0;
+        let mut entries: Vec<CalculationChainEntry> = vec![];
+        for cell in chain.into_iter() {
+            if let Some(id) = cell.sheet_id {
+                sheet_id = id;
+            }
+            let Some(coordinate) = cell.reference else {
+                continue;
+            };
+            entries.push(CalculationChainEntry {
+                sheet_id,
+                sheet_name: sheets
+                    .iter()
+                    .find(|s| s.sheet_id == sheet_id)
+                    .map(|s| s.name.clone()),
+                coordinate,
+                array: cell.array.unwrap_or(false),
+                new_dependency_level: cell.new_dependency_level.unwrap_or(false),
+                child_chain: cell.child_chain.unwrap_or(false),
+                new_thread: cell.new_thread.unwrap_or(false),
+            });
+        }
+
+        let Some(properties) = properties else {
+            return Self {
+                entries,
+                mode: CalculationMode::default(),
+                full_calculation_on_load: false,
+                force_full_calculation: false,
+                calculation_on_save: true,
+                calculation_completed: true,
+                full_precision: true,
+                iteration: None,
+                reference_mode: CalculationReferenceMode::default(),
+                calculation_id: None,
+            };
+        };
+
+        let iteration = if properties.iterate.unwrap_or(false) {
+            Some(CalculationIteration {
+                count: properties.iterate_count.unwrap_or(100),
+                delta: properties.iterate_delta.unwrap_or(0.001),
+            })
+        } else {
+            None
+        };
+
+        return Self {
+            entries,
+            mode: CalculationMode::from_string(properties.calculation_mode),
+            full_calculation_on_load: properties.full_calculation_on_load.unwrap_or(false),
+            force_full_calculation: properties.force_full_calculation.unwrap_or(false),
+            calculation_on_save: properties.calculation_on_save.unwrap_or(true),
+            calculation_completed: properties.calculation_completed.unwrap_or(true),
+            full_precision: properties.full_precision.unwrap_or(true),
+            iteration,
+            reference_mode: CalculationReferenceMode::from_string(properties.reference_mode)
+                .unwrap_or(CalculationReferenceMode::default()),
+            calculation_id: properties.calculation_id,
+        };
+    }
+
+    /// Entries of a sheet in the order of the last calculation
+    pub fn entries_for_sheet(&self, sheet_id: u64) -> Vec<&CalculationChainEntry> {
+        return self
+            .entries
+            .iter()
+            .filter(|e| e.sheet_id == sheet_id)
+            .collect();
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.calculationcell?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CalculationChainEntry {
+    /// `sheetId` of the sheet of the cell
+    pub sheet_id: u64,
+
+    /// None if the sheet does not exist in the workbook
+    pub sheet_name: Option<String>,
+
+    pub coordinate: Coordinate,
+
+    /// Whether the cell is part of an array formula
+    pub array: bool,
+
+    /// Whether the cell starts a new level of dependencies: it depends on cells calculated before it
+    pub new_dependency_level: bool,
+
+    /// Whether the cell is calculated in a child chain
+    pub child_chain: bool,
+
+    /// Whether the cell is calculated on a new thread
+    pub new_thread: bool,
+}
+
+/// Iterative calculation settings of circular references
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CalculationIteration {
+    /// Maximum number of iterations
+    pub count: i64,
+
+    /// Maximum change between iterations to stop
+    pub delta: f64,
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.calculatemodevalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CalculationMode {
+    Auto,
+    /// Automatic except the data tables
+    AutoNoTable,
+    Manual,
+}
+
+impl CalculationMode {
+    pub(crate) fn default() -> Self {
+        Self::Auto
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "auto" => Self::Auto,
+            "autoNoTable" => Self::AutoNoTable,
+            "manual" => Self::Manual,
+            _ => Self::default(),
+        };
+    }
+}
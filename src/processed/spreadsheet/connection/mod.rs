@@ -0,0 +1,409 @@
+pub mod query_table;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use query_table::QueryTable;
+
+use crate::raw::spreadsheet::connection::{
+    parameter::XlsxParameter, text_import_properties::XlsxTextImportProperties,
+    web_query_properties::XlsxWebQueryProperties, XlsxConnection,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.connection?view=openxml-3.0.1
+///
+/// An external data connection parsed from xl/connections.xml, with the query tables and tables it populates.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Connection {
+    /// Id referenced by query tables, tables (xml maps) and pivot caches (`connectionId`)
+    pub id: u64,
+
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub connection_type: ConnectionType,
+
+    /// Connection string of ODBC, OLE DB and ADO connections
+    pub connection_string: Option<String>,
+
+    /// Command text: SQL statement, table name, cube name, ...
+    pub command_text: Option<String>,
+
+    pub command_type: Option<CommandType>,
+
+    /// Name of the Power Query query loaded by the connection.
+    ///
+    /// From the `Location` of a mashup connection string, or the name of the connection (`Query - {name}`).
+    pub power_query_name: Option<String>,
+
+    /// Path of the database file
+    pub source_file: Option<String>,
+
+    /// Path of the office data connection file (.odc)
+    pub odc_file: Option<String>,
+
+    pub refresh: ConnectionRefresh,
+
+    pub credentials: CredentialsMethod,
+
+    pub save_password: bool,
+
+    /// Whether the connection is deleted but kept for the query tables using it
+    pub deleted: bool,
+
+    pub parameters: Vec<QueryParameter>,
+
+    pub web_query: Option<WebQuery>,
+
+    pub text_import: Option<TextImport>,
+
+    /// Path of the local cube file of an OLAP connection
+    pub olap_local_connection: Option<String>,
+
+    /// Query tables filled by the connection
+    pub query_tables: Vec<QueryTable>,
+
+    /// Display names of the tables based on xml maps filled by the connection
+    pub tables: Vec<String>,
+}
+
+impl Connection {
+    pub(crate) fn from_raw(
+        raw: XlsxConnection,
+        query_tables: Vec<QueryTable>,
+        tables: Vec<String>,
+    ) -> Self {
+        let database = raw.database_properties.clone();
+        let name = raw.name.clone().unwrap_or("".to_string());
+        let connection_string = database.clone().and_then(|d| d.connection);
+
+        return Self {
+            id: raw.id.unwrap_or(0),
+            power_query_name: power_query_name(&name, connection_string.as_deref()),
+            name,
+            description: raw.description,
+            connection_type: ConnectionType::from_raw(raw.r#type),
+            connection_string,
+            command_text: database
+                .clone()
+                .and_then(|d| d.command.or(d.server_command)),
+            command_type: database
+                .and_then(|d| d.command_type)
+                .map(CommandType::from_raw),
+            source_file: raw.source_file,
+            odc_file: raw.odc_file,
+            refresh: ConnectionRefresh {
+                background: raw.background.unwrap_or(false),
+                refresh_on_load: raw.refresh_on_load.unwrap_or(false),
+                interval: raw.interval.unwrap_or(0),
+                keep_alive: raw.keep_alive.unwrap_or(false),
+                save_data: raw.save_data.unwrap_or(false),
+                refreshed_version: raw.refreshed_version,
+            },
+            credentials: CredentialsMethod::from_string(raw.credentials),
+            save_password: raw.save_password.unwrap_or(false),
+            deleted: raw.deleted.unwrap_or(false),
+            parameters: raw
+                .parameters
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(QueryParameter::from_raw)
+                .collect(),
+            web_query: raw.web_query_properties.map(WebQuery::from_raw),
+            text_import: raw.text_import_properties.map(TextImport::from_raw),
+            olap_local_connection: raw.olap_properties.and_then(|o| o.local_connection),
+            query_tables,
+            tables,
+        };
+    }
+}
+
+/// Power Query connections use the mashup provider with the query name as `Location`,
+/// and are named `Query - {query name}` by default.
+fn power_query_name(name: &str, connection_string: Option<&str>) -> Option<String> {
+    if let Some(connection_string) = connection_string {
+        if connection_string.contains("Microsoft.Mashup.OleDb") {
+            let location = connection_string
+                .split(';')
+                .filter_map(|p| p.split_once('='))
+                .find(|(key, _)| key.trim().eq_ignore_ascii_case("Location"))
+                .map(|(_, value)| value.trim().trim_matches('"').to_string());
+            if location.is_some() {
+                return location;
+            }
+        }
+    }
+    return name.strip_prefix("Query - ").map(|n| n.to_string());
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.connection.type?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ConnectionType {
+    Odbc,
+    Dao,
+    File,
+    WebQuery,
+    OleDb,
+    Text,
+    AdoRecordSet,
+    Dsp,
+    Unknown(u64),
+}
+
+impl ConnectionType {
+    pub(crate) fn from_raw(r#type: Option<u64>) -> Self {
+        return match r#type {
+            Some(1) => Self::Odbc,
+            Some(2) => Self::Dao,
+            Some(3) => Self::File,
+            Some(4) => Self::WebQuery,
+            Some(5) => Self::OleDb,
+            Some(6) => Self::Text,
+            Some(7) => Self::AdoRecordSet,
+            Some(8) => Self::Dsp,
+            Some(t) => Self::Unknown(t),
+            None => Self::Unknown(0),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.databaseproperties.commandtype?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CommandType {
+    Cube,
+    Sql,
+    Table,
+    Default,
+    List,
+    Unknown(u64),
+}
+
+impl CommandType {
+    pub(crate) fn from_raw(r#type: u64) -> Self {
+        return match r#type {
+            1 => Self::Cube,
+            2 => Self::Sql,
+            3 => Self::Table,
+            4 => Self::Default,
+            5 => Self::List,
+            t => Self::Unknown(t),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.credentialsmethodvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CredentialsMethod {
+    /// Windows authentication
+    Integrated,
+    None,
+    /// Credentials saved with the connection
+    Stored,
+    Prompt,
+}
+
+impl CredentialsMethod {
+    pub(crate) fn default() -> Self {
+        Self::Integrated
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "integrated" => Self::Integrated,
+            "none" => Self::None,
+            "stored" => Self::Stored,
+            "prompt" => Self::Prompt,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// Refresh settings of a connection
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ConnectionRefresh {
+    /// Whether the refresh runs in the background
+    pub background: bool,
+
+    pub refresh_on_load: bool,
+
+    /// Automatic refresh interval in minutes. 0 if not refreshed automatically.
+    pub interval: u64,
+
+    /// Whether the connection is kept open after a refresh
+    pub keep_alive: bool,
+
+    /// Whether the data returned is saved with the workbook
+    pub save_data: bool,
+
+    /// Version of the application of the last refresh
+    pub refreshed_version: Option<u64>,
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.parameter?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct QueryParameter {
+    pub name: Option<String>,
+
+    pub value: QueryParameterValue,
+
+    pub refresh_on_change: bool,
+
+    /// SQL data type. Ex: 4 for integer, 12 for varchar
+    pub sql_type: Option<i64>,
+}
+
+impl QueryParameter {
+    pub(crate) fn from_raw(raw: XlsxParameter) -> Self {
+        let value = match raw.parameter_type.as_deref() {
+            Some("cell") => QueryParameterValue::Cell(raw.cell.clone().unwrap_or_default()),
+            Some("value") => {
+                if let Some(b) = raw.boolean {
+                    QueryParameterValue::Boolean(b)
+                } else if let Some(d) = raw.double {
+                    QueryParameterValue::Double(d)
+                } else if let Some(i) = raw.integer {
+                    QueryParameterValue::Integer(i)
+                } else {
+                    QueryParameterValue::String(raw.string.clone().unwrap_or_default())
+                }
+            }
+            _ => QueryParameterValue::Prompt(raw.prompt.clone().unwrap_or_default()),
+        };
+
+        return Self {
+            name: raw.name,
+            value,
+            refresh_on_change: raw.refresh_on_change.unwrap_or(false),
+            sql_type: raw.sql_type,
+        };
+    }
+}
+
+/// Source of the value of a query parameter
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum QueryParameterValue {
+    /// Asked to the user with the prompt text
+    Prompt(String),
+    /// Value of a cell. Ex: `Inputs!$B$1`
+    Cell(String),
+    Boolean(bool),
+    Double(f64),
+    Integer(i64),
+    String(String),
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.webqueryproperties?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct WebQuery {
+    pub url: Option<String>,
+
+    /// String sent with the POST method. None for GET.
+    pub post: Option<String>,
+
+    /// Names or 1-based indexes of the html tables imported. Empty for the entire page.
+    pub tables: Vec<String>,
+
+    /// Formatting imported: none, rtf, all
+    pub html_format: String,
+
+    /// Whether the source is a xml file
+    pub xml: bool,
+}
+
+impl WebQuery {
+    pub(crate) fn from_raw(raw: XlsxWebQueryProperties) -> Self {
+        return Self {
+            url: raw.url,
+            post: raw.post,
+            tables: raw.tables,
+            html_format: raw.html_format.unwrap_or("none".to_string()),
+            xml: raw.xml.unwrap_or(false),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.textproperties?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TextImport {
+    pub source_file: Option<String>,
+
+    pub code_page: u64,
+
+    /// 1-based row the import starts from
+    pub first_row: u64,
+
+    /// Whether the fields are delimited. False for fixed width fields.
+    pub delimited: bool,
+
+    /// Delimiters of the fields
+    pub delimiters: Vec<String>,
+
+    /// Whether consecutive delimiters are treated as one
+    pub consecutive: bool,
+
+    /// Text qualifier: doubleQuote, singleQuote, none
+    pub qualifier: String,
+
+    pub decimal: String,
+
+    pub thousands: String,
+
+    /// Type of each field. Ex: general, text, skip, YMD
+    pub field_types: Vec<String>,
+}
+
+impl TextImport {
+    pub(crate) fn from_raw(raw: XlsxTextImportProperties) -> Self {
+        let mut delimiters: Vec<String> = vec![];
+        if raw.tab.unwrap_or(true) {
+            delimiters.push("\t".to_string());
+        }
+        if raw.comma.unwrap_or(false) {
+            delimiters.push(",".to_string());
+        }
+        if raw.semicolon.unwrap_or(false) {
+            delimiters.push(";".to_string());
+        }
+        if raw.space.unwrap_or(false) {
+            delimiters.push(" ".to_string());
+        }
+        if let Some(delimiter) = raw.delimiter.clone() {
+            delimiters.push(delimiter);
+        }
+
+        return Self {
+            source_file: raw.source_file,
+            code_page: raw.code_page.unwrap_or(1252),
+            first_row: raw.first_row.unwrap_or(1),
+            delimited: raw.delimited.unwrap_or(true),
+            delimiters,
+            consecutive: raw.consecutive.unwrap_or(false),
+            qualifier: raw.qualifier.unwrap_or("doubleQuote".to_string()),
+            decimal: raw.decimal.unwrap_or(".".to_string()),
+            thousands: raw.thousands.unwrap_or(",".to_string()),
+            field_types: raw
+                .text_fields
+                .into_iter()
+                .map(|t| t.unwrap_or("general".to_string()))
+                .collect(),
+        };
+    }
+}
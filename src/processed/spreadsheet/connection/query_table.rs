@@ -0,0 +1,165 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::{
+    query_table::{query_table_field::XlsxQueryTableField, XlsxQueryTable},
+    table::XlsxTable,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.querytable?view=openxml-3.0.1
+///
+/// A query table parsed from xl/queryTables/queryTable{}.xml: the range filled with the data returned by a connection.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct QueryTable {
+    pub name: String,
+
+    /// Id of the connection in xl/connections.xml (`Connection.id`)
+    pub connection_id: Option<u64>,
+
+    /// Worksheet containing the query table
+    pub sheet_name: String,
+
+    /// Display name of the table populated by the query.
+    ///
+    /// None for a query table not in a table: its range is the defined name with the name of the query table.
+    pub table_name: Option<String>,
+
+    /// Fields returned by the query, in the order of the last refresh
+    pub fields: Vec<QueryTableField>,
+
+    /// Names of the fields removed by the user, not returned at refresh
+    pub deleted_fields: Vec<String>,
+
+    /// Whether the first row contains the field names
+    pub headers: bool,
+
+    pub row_numbers: bool,
+
+    /// How the range is adjusted when the number of rows returned changes
+    pub grow_shrink_type: GrowShrinkType,
+
+    pub refresh_on_load: bool,
+
+    pub background_refresh: bool,
+
+    pub disable_refresh: bool,
+
+    /// Whether the formats applied by the user are kept at refresh
+    pub preserve_formatting: bool,
+
+    pub adjust_column_width: bool,
+
+    /// Whether the formulas in the columns next to the data are filled down at refresh
+    pub fill_formulas: bool,
+}
+
+impl QueryTable {
+    /// * table: the table populated by the query table, related with the table relationships
+    pub(crate) fn from_raw(
+        raw: XlsxQueryTable,
+        sheet_name: &str,
+        table: Option<&XlsxTable>,
+    ) -> Self {
+        let refresh = raw.query_table_refresh.clone();
+        let fields: Vec<QueryTableField> = refresh
+            .clone()
+            .and_then(|r| r.fields)
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|f| QueryTableField::from_raw(f, table))
+            .collect();
+
+        return Self {
+            name: raw.name.unwrap_or("".to_string()),
+            connection_id: raw.connection_id,
+            sheet_name: sheet_name.to_string(),
+            table_name: table.and_then(|t| t.display_name.clone()),
+            fields,
+            deleted_fields: refresh.map(|r| r.deleted_fields).unwrap_or(vec![]),
+            headers: raw.headers.unwrap_or(true),
+            row_numbers: raw.row_numbers.unwrap_or(false),
+            grow_shrink_type: GrowShrinkType::from_string(raw.grow_shrink_type),
+            refresh_on_load: raw.refresh_on_load.unwrap_or(false),
+            background_refresh: raw.background_refresh.unwrap_or(true),
+            disable_refresh: raw.disable_refresh.unwrap_or(false),
+            preserve_formatting: raw.preserve_formatting.unwrap_or(true),
+            adjust_column_width: raw.adjust_column_width.unwrap_or(true),
+            fill_formulas: raw.fill_formulas.unwrap_or(false),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.querytablefield?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct QueryTableField {
+    /// Id referenced by the table columns (`queryTableFieldId`)
+    pub id: u64,
+
+    pub name: Option<String>,
+
+    /// Name of the table column showing the field
+    pub table_column: Option<String>,
+
+    /// False for columns added by the user next to the returned data
+    pub data_bound: bool,
+
+    pub fill_formulas: bool,
+}
+
+impl QueryTableField {
+    pub(crate) fn from_raw(raw: XlsxQueryTableField, table: Option<&XlsxTable>) -> Self {
+        let columns = table
+            .and_then(|t| t.table_columns.clone())
+            .unwrap_or(vec![]);
+        // the column refers to the field with `queryTableFieldId`, the field to the column with `tableColumnId`
+        let table_column = columns
+            .iter()
+            .find(|c| c.query_table_field_id.is_some() && c.query_table_field_id == raw.id)
+            .or(columns
+                .iter()
+                .find(|c| raw.table_column_id.is_some() && c.id == raw.table_column_id))
+            .and_then(|c| c.name.clone());
+
+        return Self {
+            id: raw.id.unwrap_or(0),
+            name: raw.name,
+            table_column,
+            data_bound: raw.data_bound.unwrap_or(true),
+            fill_formulas: raw.fill_formulas.unwrap_or(false),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.growshrinkvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GrowShrinkType {
+    /// Insert or delete rows
+    InsertDelete,
+    /// Insert rows, clear the rows not used
+    InsertClear,
+    /// Overwrite the cells below, clear the rows not used
+    OverwriteClear,
+}
+
+impl GrowShrinkType {
+    pub(crate) fn default() -> Self {
+        Self::InsertDelete
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "insertDelete" => Self::InsertDelete,
+            "insertClear" => Self::InsertClear,
+            "overwriteClear" => Self::OverwriteClear,
+            _ => Self::default(),
+        };
+    }
+}
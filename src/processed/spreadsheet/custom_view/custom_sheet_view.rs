@@ -0,0 +1,431 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::CustomView;
+use crate::{
+    common_types::{Coordinate, Dimension},
+    helper::{a1_address_to_row_col, split_reference_areas},
+    processed::spreadsheet::{
+        sheet::{chartsheet::chartsheet_page_setup::PageOrientation, page_margins::PageMargins},
+        sheet_basic_info::{SheetBasicInfo, SheetVisibleState},
+    },
+    raw::spreadsheet::{
+        sheet::worksheet::{
+            custom_sheet_view::XlsxCustomSheetView,
+            pane::{XlsxPane, XlsxSelection},
+            XlsxWorksheet,
+        },
+        workbook::defined_name::XlsxDefinedNames,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.customsheetview?view=openxml-3.0.1
+///
+/// Display and print settings of a worksheet in a custom view.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CustomSheetView {
+    pub sheet_name: String,
+
+    pub sheet_id: u64,
+
+    pub state: SheetVisibleState,
+
+    pub view: SheetViewType,
+
+    /// Zoom percentage, 10 - 400
+    pub zoom_scale: u64,
+
+    pub top_left_cell: Option<Coordinate>,
+
+    pub pane: Option<ViewPane>,
+
+    pub selection: Option<ViewSelection>,
+
+    pub show_grid_lines: bool,
+
+    /// Whether the row and column headers are displayed
+    pub show_headers: bool,
+
+    pub show_formulas: bool,
+
+    pub show_zeros: bool,
+
+    pub show_outline_symbols: bool,
+
+    pub show_page_breaks: bool,
+
+    pub show_ruler: bool,
+
+    /// Rows hidden in the view (1-based, inclusive ranges)
+    pub hidden_rows: Vec<IndexRange>,
+
+    /// Columns hidden in the view (1-based, inclusive ranges)
+    pub hidden_columns: Vec<IndexRange>,
+
+    /// Whether an auto filter is applied in the view
+    pub filter: bool,
+
+    /// Range of the auto filter
+    pub filter_range: Option<Dimension>,
+
+    /// Whether only unique values are shown (advanced filter)
+    pub filter_unique: bool,
+
+    /// Whether the auto filter drop down buttons are displayed
+    pub show_auto_filter: bool,
+
+    /// None if the print settings are not part of the view
+    pub print_settings: Option<PrintSettings>,
+}
+
+impl CustomSheetView {
+    /// * sheet_index: 0-based index of the sheet in the workbook, used by `localSheetId` of the defined names
+    pub(crate) fn from_raw(
+        raw: XlsxCustomSheetView,
+        view: &CustomView,
+        sheet: &SheetBasicInfo,
+        sheet_index: usize,
+        worksheet: &XlsxWorksheet,
+        defined_names: &XlsxDefinedNames,
+    ) -> Self {
+        let prefix = CustomView::defined_name_prefix(&view.guid);
+        let defined_name_value = |suffix: &str| -> Option<String> {
+            let name = format!("{}{}", prefix, suffix);
+            defined_names
+                .iter()
+                .find(|d| {
+                    d.local_sheet_id == Some(sheet_index as i64)
+                        && d.name
+                            .as_ref()
+                            .is_some_and(|n| n.eq_ignore_ascii_case(&name))
+                })
+                .and_then(|d| d.value.clone())
+        };
+
+        // the view keeps the current state of the sheet if hidden rows and columns are not part of it
+        let (hidden_rows, hidden_columns) = if view.include_hidden_rows_columns {
+            let hidden_rows = match raw.hidden_rows {
+                Some(true) => IndexRange::from_defined_name(defined_name_value("Rows"), true),
+                _ => vec![],
+            };
+            let hidden_columns = match raw.hidden_columns {
+                Some(true) => IndexRange::from_defined_name(defined_name_value("Cols"), false),
+                _ => vec![],
+            };
+            (hidden_rows, hidden_columns)
+        } else {
+            IndexRange::hidden_in_worksheet(worksheet)
+        };
+
+        let print_settings = if view.include_print_settings {
+            Some(PrintSettings::from_raw(
+                &raw,
+                defined_name_value("PrintArea"),
+                defined_name_value("PrintTitles"),
+            ))
+        } else {
+            None
+        };
+
+        return Self {
+            sheet_name: sheet.name.clone(),
+            sheet_id: sheet.sheet_id,
+            state: match raw.state.as_deref() {
+                Some("hidden") => SheetVisibleState::Hidden,
+                Some("veryHidden") => SheetVisibleState::VeryHidden,
+                _ => SheetVisibleState::Visible,
+            },
+            view: SheetViewType::from_string(raw.view),
+            zoom_scale: raw.scale.unwrap_or(100),
+            top_left_cell: raw.top_left_cell,
+            pane: raw.pane.map(|p| ViewPane::from_raw(p)),
+            selection: raw.selection.map(|s| ViewSelection::from_raw(s)),
+            show_grid_lines: raw.show_grid_lines.unwrap_or(true),
+            show_headers: raw.show_row_col.unwrap_or(true),
+            show_formulas: raw.show_formulas.unwrap_or(false),
+            show_zeros: raw.show_zeros.unwrap_or(true),
+            show_outline_symbols: raw.outline_symbols.unwrap_or(true),
+            show_page_breaks: raw.show_page_breaks.unwrap_or(false),
+            show_ruler: raw.show_ruler.unwrap_or(true),
+            hidden_rows,
+            hidden_columns,
+            filter: raw.filter.unwrap_or(false),
+            filter_range: raw.auto_filter.and_then(|f| f.r#ref),
+            filter_unique: raw.filter_unique.unwrap_or(false),
+            show_auto_filter: raw.show_auto_filter.unwrap_or(false),
+            print_settings,
+        };
+    }
+
+    pub fn is_row_hidden(&self, row: u64) -> bool {
+        return self.hidden_rows.iter().any(|r| r.contains(row));
+    }
+
+    pub fn is_column_hidden(&self, col: u64) -> bool {
+        return self.hidden_columns.iter().any(|r| r.contains(col));
+    }
+
+    /// Rows of the dimension visible in the view
+    pub fn visible_rows(&self, dimension: &Dimension) -> Vec<u64> {
+        return (dimension.start.row..=dimension.end.row)
+            .filter(|row| !self.is_row_hidden(*row))
+            .collect();
+    }
+
+    /// Columns of the dimension visible in the view
+    pub fn visible_columns(&self, dimension: &Dimension) -> Vec<u64> {
+        return (dimension.start.col..=dimension.end.col)
+            .filter(|col| !self.is_column_hidden(*col))
+            .collect();
+    }
+}
+
+/// Consecutive rows or columns (1-based, inclusive)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct IndexRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl IndexRange {
+    pub fn contains(&self, index: u64) -> bool {
+        return self.start <= index && index <= self.end;
+    }
+
+    /// * value: areas of a `.wvu.Rows` or `.wvu.Cols` defined name. Ex: `Sheet1!$3:$5,Sheet1!$8:$8` or `'P&L, 2024'!$B:$C`
+    pub(crate) fn from_defined_name(value: Option<String>, rows: bool) -> Vec<Self> {
+        let Some(value) = value else {
+            return vec![];
+        };
+
+        return split_reference_areas(&value)
+            .into_iter()
+            .filter_map(|area| {
+                let reference = area.rsplit('!').next()?.replace('$', "");
+                let (start, end) = reference
+                    .split_once(':')
+                    .unwrap_or((&reference, &reference));
+                let index = |address: &str| -> Option<u64> {
+                    let (row, col) = a1_address_to_row_col(address.trim().as_bytes()).ok()?;
+                    if rows {
+                        row
+                    } else {
+                        col
+                    }
+                };
+                Some(Self {
+                    start: index(start)?,
+                    end: index(end)?,
+                })
+            })
+            .collect();
+    }
+
+    /// (rows, columns) currently hidden in the worksheet
+    pub(crate) fn hidden_in_worksheet(worksheet: &XlsxWorksheet) -> (Vec<Self>, Vec<Self>) {
+        let rows: Vec<Self> = worksheet
+            .sheet_data
+            .as_ref()
+            .and_then(|d| d.rows.clone())
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter(|r| r.hidden == Some(true))
+            .filter_map(|r| {
+                let index = r.row_index?;
+                Some(Self {
+                    start: index,
+                    end: index,
+                })
+            })
+            .collect();
+
+        let columns: Vec<Self> = worksheet
+            .column_infos
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter(|c| c.hidden == Some(true))
+            .filter_map(|c| {
+                Some(Self {
+                    start: c.min_column?,
+                    end: c.max_column?,
+                })
+            })
+            .collect();
+
+        return (rows, columns);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetviewvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SheetViewType {
+    Normal,
+    PageBreakPreview,
+    PageLayout,
+}
+
+impl SheetViewType {
+    pub(crate) fn default() -> Self {
+        Self::Normal
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "normal" => Self::Normal,
+            "pageBreakPreview" => Self::PageBreakPreview,
+            "pageLayout" => Self::PageLayout,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pane?view=openxml-3.0.1
+///
+/// Split or frozen panes of a view.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ViewPane {
+    /// frozen, frozenSplit or split
+    pub state: String,
+
+    /// Number of columns frozen, or position of the split in 1/20th of a point
+    pub x_split: f64,
+
+    /// Number of rows frozen, or position of the split in 1/20th of a point
+    pub y_split: f64,
+
+    /// Top left visible cell of the bottom right pane
+    pub top_left_cell: Option<Coordinate>,
+
+    /// bottomLeft, bottomRight, topLeft or topRight
+    pub active_pane: String,
+}
+
+impl ViewPane {
+    pub(crate) fn from_raw(raw: XlsxPane) -> Self {
+        return Self {
+            state: raw.state.unwrap_or("split".to_string()),
+            x_split: raw.x_split.unwrap_or(0.0),
+            y_split: raw.y_split.unwrap_or(0.0),
+            top_left_cell: raw.top_left_cell,
+            active_pane: raw.active_pane.unwrap_or("topLeft".to_string()),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.selection?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ViewSelection {
+    pub active_cell: Option<Coordinate>,
+
+    /// Selected ranges
+    pub ranges: Vec<Dimension>,
+}
+
+impl ViewSelection {
+    pub(crate) fn from_raw(raw: XlsxSelection) -> Self {
+        return Self {
+            active_cell: raw.active_cell,
+            ranges: raw.sqref.unwrap_or(vec![]),
+        };
+    }
+}
+
+/// Print settings of a worksheet in a custom view
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PrintSettings {
+    pub page_margins: PageMargins,
+
+    pub orientation: PageOrientation,
+
+    /// Index of a predefined paper size. Ex: 1 - Letter, 9 - A4
+    pub paper_size: u64,
+
+    /// Print scale percentage, 10 - 400
+    pub scale: u64,
+
+    /// Whether the sheet is scaled to `fit_to_width` x `fit_to_height` pages instead of `scale`
+    pub fit_to_page: bool,
+
+    /// Number of pages wide, 0 for automatic
+    pub fit_to_width: u64,
+
+    /// Number of pages tall, 0 for automatic
+    pub fit_to_height: u64,
+
+    /// downThenOver or overThenDown
+    pub page_order: String,
+
+    pub black_and_white: bool,
+
+    pub draft: bool,
+
+    pub print_grid_lines: bool,
+
+    /// Whether the row and column headings are printed
+    pub print_headings: bool,
+
+    pub horizontal_centered: bool,
+
+    pub vertical_centered: bool,
+
+    /// Ex: `Sheet1!$A$1:$F$20`
+    pub print_area: Option<String>,
+
+    /// Rows and columns repeated on each page. Ex: `Sheet1!$1:$2`
+    pub print_titles: Option<String>,
+}
+
+impl PrintSettings {
+    pub(crate) fn from_raw(
+        raw: &XlsxCustomSheetView,
+        print_area: Option<String>,
+        print_titles: Option<String>,
+    ) -> Self {
+        let setup = raw.page_setup.clone();
+        let options = raw.print_options.clone();
+        return Self {
+            page_margins: PageMargins::from_raw(raw.page_margins.clone()),
+            orientation: PageOrientation::from_string(
+                setup.as_ref().and_then(|s| s.orientation.clone()),
+            ),
+            paper_size: setup.as_ref().and_then(|s| s.paper_size).unwrap_or(1),
+            scale: setup.as_ref().and_then(|s| s.scale).unwrap_or(100),
+            fit_to_page: raw.fit_to_page.unwrap_or(false),
+            fit_to_width: setup.as_ref().and_then(|s| s.fit_to_width).unwrap_or(1),
+            fit_to_height: setup.as_ref().and_then(|s| s.fit_to_height).unwrap_or(1),
+            page_order: setup
+                .as_ref()
+                .and_then(|s| s.page_order.clone())
+                .unwrap_or("downThenOver".to_string()),
+            black_and_white: setup
+                .as_ref()
+                .and_then(|s| s.black_and_white)
+                .unwrap_or(false),
+            draft: setup.as_ref().and_then(|s| s.draft).unwrap_or(false),
+            print_grid_lines: options.as_ref().and_then(|o| o.grid_lines).unwrap_or(false),
+            print_headings: options.as_ref().and_then(|o| o.headings).unwrap_or(false),
+            horizontal_centered: options
+                .as_ref()
+                .and_then(|o| o.horizontal_centered)
+                .unwrap_or(false),
+            vertical_centered: options
+                .as_ref()
+                .and_then(|o| o.vertical_centered)
+                .unwrap_or(false),
+            print_area,
+            print_titles,
+        };
+    }
+}
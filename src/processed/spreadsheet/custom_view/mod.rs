@@ -0,0 +1,114 @@
+pub mod custom_sheet_view;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use custom_sheet_view::CustomSheetView;
+
+use crate::raw::spreadsheet::workbook::custom_workbook_view::XlsxCustomWorkbookView;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.customworkbookview?view=openxml-3.0.1
+///
+/// A named custom view (View > Custom Views): the window settings of the workbook
+/// joined with the display and print settings of each worksheet in the view.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CustomView {
+    pub name: String,
+
+    /// Ex: `{CE6681F1-E999-414D-8446-68A031534B57}`
+    pub guid: String,
+
+    /// sheetId of the sheet displayed when the view is applied
+    pub active_sheet_id: u64,
+
+    /// Whether the view is a personal view of a shared workbook user
+    pub personal_view: bool,
+
+    /// Whether the hidden rows, columns and filter settings are part of the view
+    pub include_hidden_rows_columns: bool,
+
+    /// Whether the print settings are part of the view
+    pub include_print_settings: bool,
+
+    pub window: CustomViewWindow,
+
+    pub show_formula_bar: bool,
+
+    pub show_status_bar: bool,
+
+    pub show_sheet_tabs: bool,
+
+    pub show_horizontal_scroll: bool,
+
+    pub show_vertical_scroll: bool,
+
+    /// How embedded objects are displayed: all, placeholders or none
+    pub show_objects: String,
+
+    /// Settings of the worksheets in the view
+    pub sheets: Vec<CustomSheetView>,
+}
+
+impl CustomView {
+    pub(crate) fn from_raw(raw: XlsxCustomWorkbookView, sheets: Vec<CustomSheetView>) -> Self {
+        return Self {
+            name: raw.name.unwrap_or("".to_string()),
+            guid: raw.guid.unwrap_or("".to_string()),
+            active_sheet_id: raw.active_sheet_id.unwrap_or(0).max(0) as u64,
+            personal_view: raw.personal_view.unwrap_or(false),
+            include_hidden_rows_columns: raw.include_hidden_row_col.unwrap_or(true),
+            include_print_settings: raw.include_print_settings.unwrap_or(true),
+            window: CustomViewWindow {
+                x: raw.x_window.unwrap_or(0),
+                y: raw.y_window.unwrap_or(0),
+                width: raw.window_width,
+                height: raw.window_height,
+                maximized: raw.maximized.unwrap_or(false),
+                minimized: raw.minimized.unwrap_or(false),
+                tab_ratio: raw.tab_ratio.unwrap_or(600),
+            },
+            show_formula_bar: raw.show_formula_bar.unwrap_or(true),
+            show_status_bar: raw.show_statusbar.unwrap_or(true),
+            show_sheet_tabs: raw.show_sheet_tabs.unwrap_or(true),
+            show_horizontal_scroll: raw.show_horizontal_scroll.unwrap_or(true),
+            show_vertical_scroll: raw.show_vertical_scroll.unwrap_or(true),
+            show_objects: raw.show_objects.unwrap_or("all".to_string()),
+            sheets,
+        };
+    }
+
+    /// Settings of a worksheet in the view. None if the sheet is not part of the view.
+    pub fn get_sheet_view(&self, sheet_name: &str) -> Option<CustomSheetView> {
+        return self
+            .sheets
+            .iter()
+            .find(|s| s.sheet_name == sheet_name)
+            .cloned();
+    }
+
+    /// Prefix of the hidden defined names storing the hidden rows, columns, print area and print titles of the view.
+    ///
+    /// Ex: `Z_CE6681F1_E999_414D_8446_68A031534B57_.wvu.` for `{CE6681F1-E999-414D-8446-68A031534B57}`
+    pub(crate) fn defined_name_prefix(guid: &str) -> String {
+        let guid = guid
+            .trim_matches(|c| c == '{' || c == '}')
+            .replace('-', "_");
+        return format!("Z_{}_.wvu.", guid);
+    }
+}
+
+/// Position and size of the workbook window in a custom view, in twips (1/20th of a point).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CustomViewWindow {
+    pub x: i64,
+    pub y: i64,
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+    pub maximized: bool,
+    pub minimized: bool,
+
+    /// Ratio between the sheet tabs bar and the horizontal scroll bar, in 1/1000th
+    pub tab_ratio: i64,
+}
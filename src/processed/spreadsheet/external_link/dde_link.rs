@@ -0,0 +1,72 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::external_link::dde_link::{XlsxDdeItem, XlsxDdeLink};
+
+use super::ExternalValue;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ddelink?view=openxml-3.0.1
+///
+/// A Dynamic Data Exchange (DDE) link. Ex: `=Excel|'C:\Book1.xls'!R1C1`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DdeLink {
+    /// Application of the DDE server. Ex: `Excel`
+    pub service: Option<String>,
+
+    /// Topic of the DDE server, usually a file. Ex: `C:\Book1.xls`
+    pub topic: Option<String>,
+
+    pub items: Vec<DdeItem>,
+}
+
+impl DdeLink {
+    pub(crate) fn from_raw(raw: XlsxDdeLink) -> Self {
+        return Self {
+            service: raw.dde_service,
+            topic: raw.dde_topic,
+            items: raw.dde_items.into_iter().map(DdeItem::from_raw).collect(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ddeitem?view=openxml-3.0.1
+///
+/// An item of a DDE link with its cached values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DdeItem {
+    /// Ex: `R1C1`
+    pub name: Option<String>,
+
+    /// Whether the server notifies the application of changes
+    pub advise: bool,
+
+    /// Whether the item is an OLE link
+    pub ole: bool,
+
+    /// Whether the data is an image
+    pub prefer_picture: bool,
+
+    /// Values by row: `values[row][column]`
+    pub values: Vec<Vec<ExternalValue>>,
+}
+
+impl DdeItem {
+    pub(crate) fn from_raw(raw: XlsxDdeItem) -> Self {
+        let columns = raw.cols.unwrap_or(1).max(1) as usize;
+        let values: Vec<ExternalValue> = raw
+            .values
+            .into_iter()
+            .map(|v| ExternalValue::from_raw(v.r#type, v.value))
+            .collect();
+
+        return Self {
+            name: raw.name,
+            advise: raw.advise.unwrap_or(false),
+            ole: raw.ole.unwrap_or(false),
+            prefer_picture: raw.prefer_pic.unwrap_or(false),
+            values: values.chunks(columns).map(|c| c.to_vec()).collect(),
+        };
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::Coordinate,
+    packaging::relationship::{zip_path_for_id, XlsxRelationships},
+    raw::spreadsheet::external_link::{
+        external_book::{XlsxExternalBook, XlsxExternalDefinedName},
+        external_sheet_data::XlsxExternalSheetData,
+    },
+};
+
+use super::ExternalValue;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalbook?view=openxml-3.0.1
+///
+/// Link to another workbook with the values cached at the last update of the link.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ExternalBook {
+    /// Path of the external workbook. Ex: `Prices.xlsx`, `file:///C:\data\Prices.xlsx`
+    pub target: Option<String>,
+
+    /// Names of the sheets of the external workbook
+    pub sheet_names: Vec<String>,
+
+    pub defined_names: Vec<ExternalDefinedName>,
+
+    /// Cached values by sheet
+    pub sheets: Vec<ExternalSheetData>,
+}
+
+impl ExternalBook {
+    pub(crate) fn from_raw(raw: XlsxExternalBook, link_rels: &XlsxRelationships) -> Self {
+        let sheet_names = raw.sheet_names.unwrap_or(vec![]);
+
+        return Self {
+            target: raw.id.and_then(|id| zip_path_for_id(link_rels, &id)),
+            defined_names: raw
+                .defined_names
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|n| ExternalDefinedName::from_raw(n, &sheet_names))
+                .collect(),
+            sheets: raw
+                .sheet_data_set
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|s| ExternalSheetData::from_raw(s, &sheet_names))
+                .collect(),
+            sheet_names,
+        };
+    }
+
+    /// Cached value of a cell of the external workbook.
+    ///
+    /// Ex: `get_cell_value("Prices", Coordinate::from_a1(b"B2"))` for `[1]Prices!B2`.
+    pub fn get_cell_value(
+        &self,
+        sheet_name: &str,
+        coordinate: Coordinate,
+    ) -> Option<&ExternalValue> {
+        let sheet = self.sheets.iter().find(|s| {
+            s.sheet_name
+                .as_ref()
+                .is_some_and(|n| n.eq_ignore_ascii_case(sheet_name))
+        })?;
+        return sheet
+            .cells
+            .iter()
+            .find(|c| c.coordinate == coordinate)
+            .map(|c| &c.value);
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externaldefinedname?view=openxml-3.0.1
+///
+/// A defined name of the external workbook.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ExternalDefinedName {
+    pub name: String,
+
+    /// Ex: `=Prices!$B$2`
+    pub refers_to: Option<String>,
+
+    /// Sheet of the name. None for names scoped to the workbook.
+    pub sheet_name: Option<String>,
+}
+
+impl ExternalDefinedName {
+    pub(crate) fn from_raw(raw: XlsxExternalDefinedName, sheet_names: &[String]) -> Self {
+        return Self {
+            name: raw.name.unwrap_or_default(),
+            refers_to: raw.refers_to,
+            sheet_name: raw
+                .sheet_id
+                .and_then(|id| sheet_names.get(id as usize).cloned()),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalsheetdata?view=openxml-3.0.1
+///
+/// Cached values of a sheet of the external workbook.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ExternalSheetData {
+    /// Zero-based index of the sheet in `sheet_names`
+    pub sheet_index: u64,
+
+    pub sheet_name: Option<String>,
+
+    /// Whether the last update of the link failed for this sheet
+    pub refresh_error: bool,
+
+    pub cells: Vec<ExternalCell>,
+}
+
+impl ExternalSheetData {
+    pub(crate) fn from_raw(raw: XlsxExternalSheetData, sheet_names: &[String]) -> Self {
+        let sheet_index = raw.sheet_id.unwrap_or(0);
+        let cells: Vec<ExternalCell> = raw
+            .rows
+            .into_iter()
+            .flat_map(|r| r.cells)
+            .filter_map(|c| {
+                Some(ExternalCell {
+                    coordinate: c.coordinate?,
+                    value: ExternalValue::from_raw(c.r#type, c.value),
+                })
+            })
+            .collect();
+
+        return Self {
+            sheet_index,
+            sheet_name: sheet_names.get(sheet_index as usize).cloned(),
+            refresh_error: raw.refresh_error.unwrap_or(false),
+            cells,
+        };
+    }
+}
+
+/// A cached cell of the external workbook.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ExternalCell {
+    pub coordinate: Coordinate,
+
+    pub value: ExternalValue,
+}
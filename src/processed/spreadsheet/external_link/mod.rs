@@ -0,0 +1,96 @@
+pub mod dde_link;
+pub mod external_book;
+pub mod ole_link;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use dde_link::DdeLink;
+use external_book::ExternalBook;
+use ole_link::OleLink;
+
+use crate::{
+    helper::{string_to_bool, string_to_float},
+    packaging::relationship::XlsxRelationships,
+    raw::spreadsheet::external_link::XlsxExternalLink,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externallink?view=openxml-3.0.1
+///
+/// An external link parsed from xl/externalLinks/externalLink{}.xml.
+///
+/// Formulas refer to the link by its index. Ex: `[1]Prices!B2` for the link at index 1.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ExternalLink {
+    /// 1-based index of the link in the workbook `externalReferences`
+    pub index: u64,
+
+    /// Link to another workbook
+    pub external_book: Option<ExternalBook>,
+
+    /// DDE link to an application
+    pub dde_link: Option<DdeLink>,
+
+    /// Link to an OLE object
+    pub ole_link: Option<OleLink>,
+}
+
+impl ExternalLink {
+    /// * link_rels: relationships of the external link part, holding the paths of the linked files
+    pub(crate) fn from_raw(
+        raw: XlsxExternalLink,
+        index: u64,
+        link_rels: &XlsxRelationships,
+    ) -> Self {
+        return Self {
+            index,
+            external_book: raw
+                .external_book
+                .map(|b| ExternalBook::from_raw(b, link_rels)),
+            dde_link: raw.dde_link.map(DdeLink::from_raw),
+            ole_link: raw.ole_link.map(|o| OleLink::from_raw(o, link_rels)),
+        };
+    }
+
+    /// Path of the linked file: the workbook of `external_book`, or the object of `ole_link`.
+    pub fn target(&self) -> Option<String> {
+        if let Some(book) = &self.external_book {
+            return book.target.clone();
+        }
+        return self.ole_link.as_ref().and_then(|o| o.target.clone());
+    }
+}
+
+/// A cached value of an external cell or a DDE item.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ExternalValue {
+    Boolean(bool),
+    Error(String),
+    /// No value
+    Nil,
+    Number(f64),
+    String(String),
+}
+
+impl ExternalValue {
+    /// * type: `t` attribute. Ex: `b`, `e`, `n` (default), `nil`, `str`
+    pub(crate) fn from_raw(r#type: Option<String>, value: Option<String>) -> Self {
+        let Some(value) = value else {
+            return Self::Nil;
+        };
+
+        return match r#type.as_deref() {
+            Some("b") => Self::Boolean(string_to_bool(&value).unwrap_or(false)),
+            Some("e") => Self::Error(value),
+            Some("nil") => Self::Nil,
+            None | Some("n") => match string_to_float(&value) {
+                Some(n) => Self::Number(n),
+                None => Self::String(value),
+            },
+            _ => Self::String(value),
+        };
+    }
+}
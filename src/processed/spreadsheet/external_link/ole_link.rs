@@ -0,0 +1,61 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    packaging::relationship::{zip_path_for_id, XlsxRelationships},
+    raw::spreadsheet::external_link::ole_link::{XlsxOleItem, XlsxOleLink},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.olelink?view=openxml-3.0.1
+///
+/// A link to an OLE object of another application.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OleLink {
+    /// Path of the linked file
+    pub target: Option<String>,
+
+    /// Programmatic identifier of the application. Ex: `Word.Document.12`
+    pub prog_id: Option<String>,
+
+    pub items: Vec<OleItem>,
+}
+
+impl OleLink {
+    pub(crate) fn from_raw(raw: XlsxOleLink, link_rels: &XlsxRelationships) -> Self {
+        return Self {
+            target: raw.id.and_then(|id| zip_path_for_id(link_rels, &id)),
+            prog_id: raw.prog_id,
+            items: raw.ole_items.into_iter().map(OleItem::from_raw).collect(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.oleitem?view=openxml-3.0.1
+///
+/// An item of an OLE link.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OleItem {
+    pub name: Option<String>,
+
+    /// Whether the server notifies the application of changes
+    pub advise: bool,
+
+    /// Whether the object is displayed as an icon
+    pub icon: bool,
+
+    /// Whether the object is an image
+    pub prefer_picture: bool,
+}
+
+impl OleItem {
+    pub(crate) fn from_raw(raw: XlsxOleItem) -> Self {
+        return Self {
+            name: raw.name,
+            advise: raw.advise.unwrap_or(false),
+            icon: raw.icon.unwrap_or(false),
+            prefer_picture: raw.prefer_pic.unwrap_or(false),
+        };
+    }
+}
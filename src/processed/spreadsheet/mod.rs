@@ -1,2 +1,9 @@
+pub mod calculation_chain;
+pub mod connection;
+pub mod custom_view;
+pub mod external_link;
+pub mod pivot_cache;
 pub mod sheet;
 pub mod sheet_basic_info;
+pub mod vba_project;
+pub mod xml_map;
@@ -0,0 +1,149 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::pivot_cache::{
+    cache_field::XlsxCacheField, cache_value::XlsxPivotCacheValue, field_group::XlsxFieldGroup,
+};
+
+use super::cache_value::PivotCacheValue;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cachefield?view=openxml-3.0.1
+///
+/// A field of the pivot cache.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotCacheField {
+    pub name: String,
+
+    pub caption: Option<String>,
+
+    /// Whether the field comes from the source data.
+    ///
+    /// Calculated and grouped fields are not database fields.
+    pub is_database_field: bool,
+
+    /// Formula of a calculated field.
+    pub formula: Option<String>,
+
+    pub number_format_id: u64,
+
+    /// Unique values of the field, referenced by index from records and pivot items.
+    pub shared_items: Vec<PivotCacheValue>,
+
+    /// Grouping of the field. Ex: dates grouped by months.
+    pub group: Option<PivotFieldGroup>,
+}
+
+impl PivotCacheField {
+    pub(crate) fn from_raw(raw: XlsxCacheField) -> Self {
+        let shared_items: Vec<PivotCacheValue> = raw
+            .shared_items
+            .map(|s| s.items)
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|v| PivotCacheValue::from_raw(v, &[]))
+            .collect();
+
+        return Self {
+            name: raw.name.unwrap_or_default(),
+            caption: raw.caption,
+            is_database_field: raw.database_field.unwrap_or(true),
+            formula: raw.formula,
+            number_format_id: raw.num_fmt_id.unwrap_or(0),
+            shared_items,
+            group: raw.field_group.map(PivotFieldGroup::from_raw),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.fieldgroup?view=openxml-3.0.1
+///
+/// Grouping of a cache field.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotFieldGroup {
+    /// Index of the cache field whose values are grouped.
+    pub base: Option<u64>,
+
+    /// Index of the cache field of the parent group.
+    pub parent: Option<u64>,
+
+    /// None for discrete (manual) grouping
+    pub group_by: Option<PivotGroupBy>,
+
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub start_number: Option<f64>,
+    pub end_number: Option<f64>,
+
+    /// Size of each numeric range
+    pub interval: Option<f64>,
+
+    /// Group item index for each shared item of the base field (discrete grouping)
+    pub discrete: Vec<u64>,
+
+    /// Group items (names of the groups)
+    pub items: Vec<PivotCacheValue>,
+}
+
+impl PivotFieldGroup {
+    pub(crate) fn from_raw(raw: XlsxFieldGroup) -> Self {
+        let range = raw.range_properties;
+        let items: Vec<XlsxPivotCacheValue> = raw.group_items.unwrap_or(vec![]);
+
+        return Self {
+            base: raw.base,
+            parent: raw.par,
+            group_by: range.clone().map(|r| PivotGroupBy::from_string(r.group_by)),
+            start_date: range.clone().and_then(|r| r.start_date),
+            end_date: range.clone().and_then(|r| r.end_date),
+            start_number: range.clone().and_then(|r| r.start_num),
+            end_number: range.clone().and_then(|r| r.end_num),
+            interval: range.and_then(|r| r.group_interval),
+            discrete: raw.discrete_properties.unwrap_or(vec![]),
+            items: items
+                .into_iter()
+                .map(|v| PivotCacheValue::from_raw(v, &[]))
+                .collect(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.groupbyvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotGroupBy {
+    Range,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Months,
+    Quarters,
+    Years,
+}
+
+impl PivotGroupBy {
+    pub(crate) fn default() -> Self {
+        Self::Range
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "range" => Self::Range,
+            "seconds" => Self::Seconds,
+            "minutes" => Self::Minutes,
+            "hours" => Self::Hours,
+            "days" => Self::Days,
+            "months" => Self::Months,
+            "quarters" => Self::Quarters,
+            "years" => Self::Years,
+            _ => Self::default(),
+        };
+    }
+}
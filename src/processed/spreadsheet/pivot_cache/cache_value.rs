@@ -0,0 +1,57 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::pivot_cache::cache_value::XlsxPivotCacheValue;
+
+/// A value of a pivot cache: a shared item, a group item or a record value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotCacheValue {
+    Boolean(bool),
+    /// ISO 8601 date time. Ex: `2024-01-01T00:00:00`
+    DateTime(String),
+    Error(String),
+    /// No value (blank)
+    Missing,
+    Number(f64),
+    String(String),
+}
+
+impl PivotCacheValue {
+    /// `shared_items`: shared items of the field, used to resolve `x` (index) values of records.
+    pub(crate) fn from_raw(raw: XlsxPivotCacheValue, shared_items: &[XlsxPivotCacheValue]) -> Self {
+        return match raw {
+            XlsxPivotCacheValue::Boolean(b) => Self::Boolean(b),
+            XlsxPivotCacheValue::DateTime(d) => Self::DateTime(d),
+            XlsxPivotCacheValue::Error(e) => Self::Error(e),
+            XlsxPivotCacheValue::Missing => Self::Missing,
+            XlsxPivotCacheValue::Number(n) => Self::Number(n),
+            XlsxPivotCacheValue::String(s) => Self::String(s),
+            XlsxPivotCacheValue::SharedItemIndex(index) => match shared_items.get(index as usize) {
+                Some(XlsxPivotCacheValue::SharedItemIndex(_)) | None => Self::Missing,
+                Some(item) => Self::from_raw(item.clone(), &[]),
+            },
+        };
+    }
+
+    /// Numeric value. None for non numeric values.
+    pub fn as_number(&self) -> Option<f64> {
+        return match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        };
+    }
+
+    /// Text as displayed in the pivot table.
+    pub fn to_text(&self) -> String {
+        return match self {
+            Self::Boolean(b) => (if *b { "TRUE" } else { "FALSE" }).to_string(),
+            Self::DateTime(d) => d.to_owned(),
+            Self::Error(e) => e.to_owned(),
+            Self::Missing => "(blank)".to_string(),
+            Self::Number(n) => n.to_string(),
+            Self::String(s) => s.to_owned(),
+        };
+    }
+}
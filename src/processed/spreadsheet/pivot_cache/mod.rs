@@ -0,0 +1,223 @@
+pub mod cache_field;
+pub mod cache_value;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use cache_field::PivotCacheField;
+use cache_value::PivotCacheValue;
+
+use crate::{
+    common_types::Dimension,
+    raw::spreadsheet::pivot_cache::{
+        cache_source::XlsxCacheSource, cache_value::XlsxPivotCacheValue,
+        pivot_cache_records::XlsxPivotCacheRecords, XlsxPivotCacheDefinition,
+    },
+};
+
+/// - PivotCacheDefinition: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotcachedefinition?view=openxml-3.0.1
+/// - PivotCacheRecords: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotcacherecords?view=openxml-3.0.1
+///
+/// A pivot cache parsed from xl/pivotCache/pivotCacheDefinition{}.xml and xl/pivotCache/pivotCacheRecords{}.xml.
+///
+/// The records are a copy of the source data at the last refresh,
+/// and are often the only copy of the data when the source is external or removed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotCache {
+    /// Id referenced by pivot tables
+    pub cache_id: u64,
+
+    pub source: PivotCacheSource,
+
+    pub fields: Vec<PivotCacheField>,
+
+    /// Records with one value per field, in the order of `fields`.
+    ///
+    /// Values of fields that are not database fields (grouped or calculated) are `Missing`.
+    pub records: Vec<Vec<PivotCacheValue>>,
+
+    pub refreshed_by: Option<String>,
+
+    /// Serial date time of the last refresh
+    pub refreshed_date: Option<f64>,
+
+    pub refresh_on_load: bool,
+
+    /// Whether the cache needs to be refreshed before use
+    pub invalid: bool,
+
+    /// Whether the records are saved with the workbook
+    pub save_data: bool,
+}
+
+impl PivotCache {
+    pub(crate) fn from_raw(
+        cache_id: u64,
+        definition: XlsxPivotCacheDefinition,
+        records: XlsxPivotCacheRecords,
+    ) -> Self {
+        let raw_fields = definition.cache_fields.clone().unwrap_or(vec![]);
+
+        // shared items of each field, referenced by index from the records
+        let shared_items: Vec<Vec<XlsxPivotCacheValue>> = raw_fields
+            .iter()
+            .map(|f| {
+                f.shared_items
+                    .as_ref()
+                    .map(|s| s.items.clone())
+                    .unwrap_or(vec![])
+            })
+            .collect();
+
+        let fields: Vec<PivotCacheField> = raw_fields
+            .into_iter()
+            .map(PivotCacheField::from_raw)
+            .collect();
+
+        // records only contain values for database fields
+        let database_field_indexes: Vec<usize> = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.is_database_field)
+            .map(|(index, _)| index)
+            .collect();
+
+        let records: Vec<Vec<PivotCacheValue>> = records
+            .into_iter()
+            .map(|record| {
+                let mut values: Vec<PivotCacheValue> = vec![PivotCacheValue::Missing; fields.len()];
+                for (value, field_index) in record.into_iter().zip(database_field_indexes.iter()) {
+                    values[*field_index] =
+                        PivotCacheValue::from_raw(value, &shared_items[*field_index]);
+                }
+                values
+            })
+            .collect();
+
+        return Self {
+            cache_id,
+            source: PivotCacheSource::from_raw(definition.cache_source),
+            fields,
+            records,
+            refreshed_by: definition.refreshed_by,
+            refreshed_date: definition.refreshed_date,
+            refresh_on_load: definition.refresh_on_load.unwrap_or(false),
+            invalid: definition.invalid.unwrap_or(false),
+            save_data: definition.save_data.unwrap_or(true),
+        };
+    }
+
+    /// Records as a flat table: the database field names as headers and one row per record.
+    pub fn records_table(&self) -> PivotCacheTable {
+        let indexes: Vec<usize> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.is_database_field)
+            .map(|(index, _)| index)
+            .collect();
+
+        return PivotCacheTable {
+            headers: indexes
+                .iter()
+                .map(|i| self.fields[*i].name.clone())
+                .collect(),
+            rows: self
+                .records
+                .iter()
+                .map(|r| {
+                    indexes
+                        .iter()
+                        .map(|i| r.get(*i).cloned().unwrap_or(PivotCacheValue::Missing))
+                        .collect()
+                })
+                .collect(),
+        };
+    }
+}
+
+/// Pivot cache records as a flat table
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotCacheTable {
+    /// Field names
+    pub headers: Vec<String>,
+
+    pub rows: Vec<Vec<PivotCacheValue>>,
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cachesource?view=openxml-3.0.1
+///
+/// Source of the data in the pivot cache.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotCacheSource {
+    pub source_type: PivotCacheSourceType,
+
+    /// Sheet of the source range
+    pub sheet: Option<String>,
+
+    /// Source range
+    pub dimension: Option<Dimension>,
+
+    /// Defined name or table name used as source
+    pub name: Option<String>,
+
+    /// Id of the connection in xl/connections.xml for external sources
+    pub connection_id: Option<u64>,
+}
+
+impl PivotCacheSource {
+    pub(crate) fn from_raw(raw: Option<XlsxCacheSource>) -> Self {
+        let Some(raw) = raw else {
+            return Self {
+                source_type: PivotCacheSourceType::default(),
+                sheet: None,
+                dimension: None,
+                name: None,
+                connection_id: None,
+            };
+        };
+        let worksheet_source = raw.worksheet_source;
+
+        return Self {
+            source_type: PivotCacheSourceType::from_string(raw.r#type),
+            sheet: worksheet_source.clone().and_then(|s| s.sheet),
+            dimension: worksheet_source.clone().and_then(|s| s.r#ref),
+            name: worksheet_source.and_then(|s| s.name),
+            connection_id: raw.connection_id,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sourcevalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotCacheSourceType {
+    Worksheet,
+    External,
+    Consolidation,
+    Scenario,
+}
+
+impl PivotCacheSourceType {
+    pub(crate) fn default() -> Self {
+        Self::Worksheet
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "worksheet" => Self::Worksheet,
+            "external" => Self::External,
+            "consolidation" => Self::Consolidation,
+            "scenario" => Self::Scenario,
+            _ => Self::default(),
+        };
+    }
+}
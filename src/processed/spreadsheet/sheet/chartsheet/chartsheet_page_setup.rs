@@ -0,0 +1,119 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::sheet::chartsheet::chartsheet_page_setup::XlsxChartsheetPageSetup;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.chartsheetpagesetup?view=openxml-3.0.1
+///
+/// Print settings of a chartsheet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartsheetPageSetup {
+    /// Index of a predefined paper size. Ex: 1 - Letter, 9 - A4
+    pub paper_size: u64,
+
+    /// Height of custom paper with units. Ex: `297mm`
+    pub paper_height: Option<String>,
+
+    /// Width of custom paper with units. Ex: `210mm`
+    pub paper_width: Option<String>,
+
+    pub orientation: PageOrientation,
+
+    /// Page number of the first printed page, used only if `use_first_page_number` is true.
+    pub first_page_number: u64,
+
+    pub use_first_page_number: bool,
+
+    /// Whether the printer defaults are used instead of the settings here.
+    pub use_printer_defaults: bool,
+
+    pub black_and_white: bool,
+
+    /// Whether the sheet is printed without graphics.
+    pub draft: bool,
+
+    pub horizontal_dpi: u64,
+
+    pub vertical_dpi: u64,
+
+    pub copies: u64,
+}
+
+impl ChartsheetPageSetup {
+    pub(crate) fn default() -> Self {
+        Self {
+            paper_size: 1,
+            paper_height: None,
+            paper_width: None,
+            orientation: PageOrientation::default(),
+            first_page_number: 1,
+            use_first_page_number: false,
+            use_printer_defaults: true,
+            black_and_white: false,
+            draft: false,
+            horizontal_dpi: 600,
+            vertical_dpi: 600,
+            copies: 1,
+        }
+    }
+
+    pub(crate) fn from_raw(setup: Option<XlsxChartsheetPageSetup>) -> Self {
+        let default = Self::default();
+        let Some(setup) = setup else {
+            return default;
+        };
+
+        return Self {
+            paper_size: setup.paper_size.unwrap_or(default.paper_size),
+            paper_height: setup.paper_height,
+            paper_width: setup.paper_width,
+            orientation: PageOrientation::from_string(setup.orientation),
+            first_page_number: setup.first_page_number.unwrap_or(default.first_page_number),
+            use_first_page_number: setup
+                .use_first_page_number
+                .unwrap_or(default.use_first_page_number),
+            use_printer_defaults: setup
+                .use_printer_defaults
+                .unwrap_or(default.use_printer_defaults),
+            black_and_white: setup.black_and_white.unwrap_or(default.black_and_white),
+            draft: setup.draft.unwrap_or(default.draft),
+            horizontal_dpi: setup.horizontal_dpi.unwrap_or(default.horizontal_dpi),
+            vertical_dpi: setup.vertical_dpi.unwrap_or(default.vertical_dpi),
+            copies: setup.copies.unwrap_or(default.copies),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.orientationvalues?view=openxml-3.0.1
+///
+/// * Default: orientation of the printer
+/// * Landscape
+/// * Portrait
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PageOrientation {
+    Default,
+    Landscape,
+    Portrait,
+}
+
+impl PageOrientation {
+    pub(crate) fn default() -> Self {
+        Self::Default
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "default" => Self::Default,
+            "landscape" => Self::Landscape,
+            "portrait" => Self::Portrait,
+            _ => Self::default(),
+        };
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::sheet::chartsheet::chartsheet_view::XlsxChartsheetView;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.chartsheetview?view=openxml-3.0.1
+///
+/// A window view of a chartsheet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ChartsheetView {
+    /// Whether the sheet tab is selected.
+    pub tab_selected: bool,
+
+    /// Zero-based index of the workbook view this sheet view belongs to.
+    pub workbook_view_id: u64,
+
+    /// Zoom percentage, 10 - 400.
+    pub zoom_scale: u64,
+
+    /// Whether the chart is scaled to fit the window.
+    pub zoom_to_fit: bool,
+}
+
+impl ChartsheetView {
+    pub(crate) fn from_raw(view: XlsxChartsheetView) -> Self {
+        return Self {
+            tab_selected: view.tab_selected.unwrap_or(false),
+            workbook_view_id: view.workbook_view_id.unwrap_or(0),
+            zoom_scale: view.zoom_scale.unwrap_or(100),
+            zoom_to_fit: view.zoom_to_fit.unwrap_or(false),
+        };
+    }
+}
@@ -0,0 +1,135 @@
+pub mod chartsheet_page_setup;
+pub mod chartsheet_view;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[cfg(feature = "drawing")]
+use std::{collections::BTreeMap, sync::Arc};
+
+#[cfg(feature = "drawing")]
+use crate::{
+    packaging::relationship::XlsxRelationships,
+    processed::drawing::{
+        chart::Chart,
+        graphic::graphic_frame::GraphicFrame,
+        worksheet_drawing::{
+            bounds::SheetGeometry, content_type::DrawingContentType, WorksheetDrawing,
+        },
+    },
+    raw::{
+        drawing::{
+            chart::XlsxChartSpace, diagram::XlsxDiagrams,
+            worksheet_drawing::XlsxWorksheetDrawing,
+        },
+        spreadsheet::workbook::defined_name::XlsxDefinedNames,
+    },
+};
+
+use chartsheet_page_setup::ChartsheetPageSetup;
+use chartsheet_view::ChartsheetView;
+
+use crate::{
+    processed::spreadsheet::sheet::{page_margins::PageMargins, sheet_properties::SheetProperties},
+    raw::{
+        drawing::theme::XlsxTheme,
+        spreadsheet::{sheet::chartsheet::XlsxChartsheet, stylesheet::XlsxStyleSheet},
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.chartsheet?view=openxml-3.0.1
+///
+/// A sheet containing a single chart, parsed from xl/chartsheets/sheet{}.xml.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Chartsheet {
+    pub name: String,
+    pub sheet_id: u64,
+
+    /// sheetPr (Chart Sheet Properties): tab color, code name and etc.
+    pub properties: SheetProperties,
+
+    pub views: Vec<ChartsheetView>,
+
+    pub page_margins: PageMargins,
+
+    pub page_setup: ChartsheetPageSetup,
+
+    /// Graphic frame holding the chart of the sheet.
+    ///
+    /// None if the sheet does not reference a drawing or the drawing does not contain a graphic frame.
+    #[cfg(feature = "drawing")]
+    pub graphic_frame: Option<GraphicFrame>,
+}
+
+impl Chartsheet {
+    /// Chart of the sheet.
+    #[cfg(feature = "drawing")]
+    pub fn chart(&self) -> Option<Chart> {
+        return self.graphic_frame.clone().and_then(|f| f.chart);
+    }
+}
+
+impl Chartsheet {
+    pub(crate) fn from_raw(
+        name: String,
+        sheet_id: u64,
+        chartsheet: XlsxChartsheet,
+        stylesheet: Box<XlsxStyleSheet>,
+        theme: Option<Box<XlsxTheme>>,
+        #[cfg(feature = "drawing")] drawing_rels: XlsxRelationships,
+        #[cfg(feature = "drawing")] raw_drawing: Option<XlsxWorksheetDrawing>,
+        #[cfg(feature = "drawing")] charts: BTreeMap<String, XlsxChartSpace>,
+        #[cfg(feature = "drawing")] defined_names: XlsxDefinedNames,
+    ) -> Self {
+        let color_scheme = if let Some(theme) = theme.clone() {
+            theme.theme_elements.and_then(|e| e.color_scheme)
+        } else {
+            None
+        };
+
+        #[cfg(feature = "drawing")]
+        let graphic_frame = raw_drawing
+            .and_then(|d| d.drawings)
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter_map(|raw| {
+                WorksheetDrawing::from_raw(
+                    raw,
+                    drawing_rels.clone(),
+                    BTreeMap::new(),
+                    &charts,
+                    BTreeMap::new(),
+                    &XlsxDiagrams::default(),
+                    defined_names.clone(),
+                    color_scheme.clone(),
+                    theme.clone(),
+                    Arc::new(SheetGeometry::default()),
+                )
+            })
+            .find_map(|d| match d.content {
+                DrawingContentType::GraphicFrame(frame) => Some(frame),
+                _ => None,
+            });
+
+        return Self {
+            name,
+            sheet_id,
+            properties: SheetProperties::from_raw(
+                chartsheet.sheet_properties,
+                stylesheet.colors.clone(),
+                color_scheme,
+            ),
+            views: chartsheet
+                .sheet_views
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(ChartsheetView::from_raw)
+                .collect(),
+            page_margins: PageMargins::from_raw(chartsheet.page_margins),
+            page_setup: ChartsheetPageSetup::from_raw(chartsheet.page_setup),
+            #[cfg(feature = "drawing")]
+            graphic_frame,
+        };
+    }
+}
@@ -1 +1,4 @@
+pub mod chartsheet;
+pub mod page_margins;
+pub mod sheet_properties;
 pub mod worksheet;
@@ -0,0 +1,52 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::sheet::page_margins::XlsxPageMargins;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagemargins?view=openxml-3.0.1
+///
+/// Page margins used when printing the sheet, in inches.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PageMargins {
+    pub left: f64,
+    pub right: f64,
+    pub top: f64,
+    pub bottom: f64,
+
+    /// Margin of the header from the top of the page.
+    pub header: f64,
+
+    /// Margin of the footer from the bottom of the page.
+    pub footer: f64,
+}
+
+impl PageMargins {
+    /// Excel "Normal" margins
+    pub(crate) fn default() -> Self {
+        Self {
+            left: 0.7,
+            right: 0.7,
+            top: 0.75,
+            bottom: 0.75,
+            header: 0.3,
+            footer: 0.3,
+        }
+    }
+
+    pub(crate) fn from_raw(margins: Option<XlsxPageMargins>) -> Self {
+        let default = Self::default();
+        let Some(margins) = margins else {
+            return default;
+        };
+
+        return Self {
+            left: margins.left.unwrap_or(default.left),
+            right: margins.right.unwrap_or(default.right),
+            top: margins.top.unwrap_or(default.top),
+            bottom: margins.bottom.unwrap_or(default.bottom),
+            header: margins.header.unwrap_or(default.header),
+            footer: margins.footer.unwrap_or(default.footer),
+        };
+    }
+}
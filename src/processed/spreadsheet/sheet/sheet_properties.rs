@@ -0,0 +1,164 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::HexColor,
+    raw::{
+        drawing::scheme::color_scheme::XlsxColorScheme,
+        spreadsheet::{
+            sheet::{
+                outline_properties::XlsxOutlineProperties, sheet_properties::XlsxSheetProperties,
+            },
+            stylesheet::color::stylesheet_colors::XlsxStyleSheetColors,
+        },
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetproperties?view=openxml-3.0.1
+///
+/// Sheet-level properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SheetProperties {
+    /// Sheet tab color.
+    ///
+    /// None if the tab uses the default color.
+    pub tab_color: Option<HexColor>,
+
+    /// Stable name of the sheet used by code (VBA) to reference the sheet.
+    pub code_name: Option<String>,
+
+    /// Whether the conditional formatting calculations shall be evaluated.
+    pub enable_format_conditions_calculation: bool,
+
+    /// Whether the worksheet has one or more autofilters or advanced filters on.
+    pub filter_mode: bool,
+
+    /// Whether the worksheet should be published.
+    pub published: bool,
+
+    /// Whether horizontal scrolling is synchronized across multiple grouped sheets.
+    pub sync_horizontal: bool,
+
+    /// Whether vertical scrolling is synchronized across multiple grouped sheets.
+    pub sync_vertical: bool,
+
+    /// Anchor cell for a worksheet when horizontal or vertical scrolling is synchronized.
+    pub sync_ref: Option<String>,
+
+    /// Whether the Transition Formula Entry (Lotus compatibility) option is enabled.
+    pub transition_entry: bool,
+
+    /// Whether the Transition Formula Evaluation (Lotus compatibility) option is enabled.
+    pub transition_evaluation: bool,
+
+    /// Whether the Fit to Page print option is enabled.
+    pub fit_to_page: bool,
+
+    /// Whether the sheet displays Automatic Page Breaks.
+    pub auto_page_breaks: bool,
+
+    /// Outline (grouping) settings
+    pub outline: OutlineProperties,
+}
+
+impl SheetProperties {
+    pub(crate) fn default() -> Self {
+        Self {
+            tab_color: None,
+            code_name: None,
+            enable_format_conditions_calculation: true,
+            filter_mode: false,
+            published: true,
+            sync_horizontal: false,
+            sync_vertical: false,
+            sync_ref: None,
+            transition_entry: false,
+            transition_evaluation: false,
+            fit_to_page: false,
+            auto_page_breaks: true,
+            outline: OutlineProperties::default(),
+        }
+    }
+
+    pub(crate) fn from_raw(
+        properties: Option<XlsxSheetProperties>,
+        stylesheet_colors: Option<XlsxStyleSheetColors>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Self {
+        let Some(properties) = properties else {
+            return Self::default();
+        };
+
+        let tab_color: Option<HexColor> = match properties.tab_color {
+            Some(c) => c.to_hex(stylesheet_colors, color_scheme),
+            None => None,
+        };
+
+        let page_setup = properties.page_setup_properties.clone();
+
+        return Self {
+            tab_color,
+            code_name: properties.code_name,
+            enable_format_conditions_calculation: properties
+                .enable_format_conditions_calculation
+                .unwrap_or(true),
+            filter_mode: properties.filter_mode.unwrap_or(false),
+            published: properties.published.unwrap_or(true),
+            sync_horizontal: properties.sync_horizontal.unwrap_or(false),
+            sync_vertical: properties.sync_vertical.unwrap_or(false),
+            sync_ref: properties.sync_ref,
+            transition_entry: properties.transition_entry.unwrap_or(false),
+            transition_evaluation: properties.transition_evaluation.unwrap_or(false),
+            fit_to_page: page_setup
+                .clone()
+                .and_then(|p| p.fit_to_page)
+                .unwrap_or(false),
+            auto_page_breaks: page_setup.and_then(|p| p.auto_page_breaks).unwrap_or(true),
+            outline: OutlineProperties::from_raw(properties.outline_properties),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.outlineproperties?view=openxml-3.0.1
+///
+/// Sheet-level settings related to outlines (grouping).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OutlineProperties {
+    /// Whether to apply styles in the outline.
+    pub apply_styles: bool,
+
+    /// Whether the sheet has outline symbols visible.
+    pub show_outline_symbols: bool,
+
+    /// Whether summary rows appear below detail in an outline.
+    pub summary_below: bool,
+
+    /// Whether summary columns appear to the right of detail in an outline.
+    pub summary_right: bool,
+}
+
+impl OutlineProperties {
+    pub(crate) fn default() -> Self {
+        Self {
+            apply_styles: false,
+            show_outline_symbols: true,
+            summary_below: true,
+            summary_right: true,
+        }
+    }
+
+    pub(crate) fn from_raw(properties: Option<XlsxOutlineProperties>) -> Self {
+        let Some(properties) = properties else {
+            return Self::default();
+        };
+
+        return Self {
+            apply_styles: properties.apply_styles.unwrap_or(false),
+            show_outline_symbols: properties.show_outline_symbols.unwrap_or(true),
+            summary_below: properties.summary_below.unwrap_or(true),
+            summary_right: properties.summary_right.unwrap_or(true),
+        };
+    }
+}
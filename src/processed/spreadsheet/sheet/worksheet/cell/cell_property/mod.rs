@@ -226,7 +226,8 @@ impl CellProperty {
         };
     }
 
-    fn cell_width(
+    /// width of a column in points, from the column information or the defaults of the sheet format properties
+    pub(crate) fn cell_width(
         col_info: Option<XlsxColumnInformation>,
         sheet_format_properties: Option<XlsxSheetFormatProperties>,
     ) -> f64 {
@@ -250,7 +251,8 @@ impl CellProperty {
         return DEFAULT_CELL_WIDTH;
     }
 
-    fn cell_height(
+    /// height of a row in points, from the row information or the default of the sheet format properties
+    pub(crate) fn cell_height(
         row_info: XlsxRow,
         sheet_format_properties: Option<XlsxSheetFormatProperties>,
     ) -> f64 {
@@ -258,6 +260,13 @@ impl CellProperty {
             return f;
         }
 
+        return Self::default_cell_height(sheet_format_properties);
+    }
+
+    /// height of the rows without row information
+    pub(crate) fn default_cell_height(
+        sheet_format_properties: Option<XlsxSheetFormatProperties>,
+    ) -> f64 {
         if let Some(sheet_format_properties) = sheet_format_properties {
             return sheet_format_properties
                 .default_row_height
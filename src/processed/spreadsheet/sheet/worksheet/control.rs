@@ -0,0 +1,329 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+
+use crate::{
+    helper::string_to_unsignedint,
+    packaging::relationship::{rel_for_id, XlsxRelationships},
+    processed::drawing::{
+        vml::{
+            client_data::{VmlCheckedState, VmlObjectType},
+            shape::VmlShape,
+            VmlDrawing,
+        },
+        worksheet_drawing::{
+            anchor_type::DrawingAnchorType, non_visual_properties::NonVisualDrawingProperty,
+        },
+    },
+    raw::spreadsheet::{
+        active_x::XlsxActiveXControl, form_control_properties::XlsxFormControlProperties,
+        sheet::worksheet::control::XlsxControl,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.control?view=openxml-3.0.1
+///
+/// A form control (check box, dropdown, option button and etc.) or an ActiveX control of the worksheet.
+///
+/// Properties are read from xl/ctrlProps/ctrlProp{}.xml, and from the legacy (VML) shape of the control when the properties part is not available.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Control {
+    pub control_type: ControlType,
+
+    /// Cells the control is anchored to
+    pub anchor: Option<DrawingAnchorType>,
+
+    /// id (shape id), name, alt text (description), hidden, print and lock settings
+    pub non_visual_properties: NonVisualDrawingProperty,
+
+    /// Cell linked to the value of the control. Ex: `$C$1`
+    pub linked_cell: Option<String>,
+
+    /// Input range of list boxes and dropdowns. Ex: `$E$1:$E$5`
+    pub input_range: Option<String>,
+
+    /// State of check boxes and option buttons
+    pub checked: Option<VmlCheckedState>,
+
+    /// 1 based indexes of the selected items of list boxes and dropdowns
+    pub selected_indexes: Vec<u64>,
+
+    /// Items of list boxes and dropdowns that are not linked to an input range
+    pub list_items: Vec<String>,
+
+    /// Current value of scroll bars and spinners
+    pub value: Option<i64>,
+
+    /// Minimum value of scroll bars and spinners
+    pub min: Option<i64>,
+
+    /// Maximum value of scroll bars and spinners
+    pub max: Option<i64>,
+
+    /// Increment of scroll bars and spinners
+    pub increment: Option<u64>,
+
+    /// Name of the macro assigned to the control. Ex: `[0]!Approve`
+    pub r#macro: Option<String>,
+
+    /// Text displayed in the control, ex: the label of check boxes and buttons
+    pub text: Option<String>,
+
+    /// Data of ActiveX controls
+    pub active_x: Option<ActiveXControl>,
+}
+
+impl Control {
+    pub(crate) fn from_raw(
+        raw: XlsxControl,
+        worksheet_rels: &XlsxRelationships,
+        form_control_properties: &BTreeMap<String, XlsxFormControlProperties>,
+        active_x_controls: &BTreeMap<String, XlsxActiveXControl>,
+        legacy_drawing: Option<&VmlDrawing>,
+    ) -> Self {
+        let properties = raw.control_properties.clone();
+        let form_control = raw
+            .id
+            .as_ref()
+            .and_then(|id| form_control_properties.get(id))
+            .cloned();
+        let active_x = raw
+            .id
+            .as_ref()
+            .and_then(|id| active_x_controls.get(id))
+            .cloned()
+            .map(ActiveXControl::from_raw);
+        let is_active_x = raw
+            .id
+            .as_ref()
+            .and_then(|id| rel_for_id(worksheet_rels, id))
+            .is_some_and(|r| r.r#type.ends_with("/control"));
+
+        let shape: Option<VmlShape> = match (legacy_drawing, raw.shape_id) {
+            (Some(drawing), Some(shape_id)) => drawing.get_shape_with_shape_id(&shape_id),
+            _ => None,
+        };
+        let client_data = shape.as_ref().and_then(|s| s.client_data.clone());
+
+        let control_type = if is_active_x || active_x.is_some() {
+            ControlType::ActiveX
+        } else if let Some(object_type) = form_control.as_ref().and_then(|f| f.object_type.clone())
+        {
+            ControlType::from_string(Some(object_type))
+        } else {
+            ControlType::from_vml_object_type(
+                client_data.as_ref().and_then(|c| c.object_type.clone()),
+            )
+        };
+
+        let anchor = properties
+            .as_ref()
+            .and_then(|p| p.anchor.clone())
+            .map(DrawingAnchorType::from_object_anchor)
+            .or(shape.as_ref().and_then(|s| s.anchor.clone()));
+
+        let mut non_visual_properties = NonVisualDrawingProperty::default();
+        non_visual_properties.id = raw.shape_id.unwrap_or(0);
+        non_visual_properties.name = raw.name.clone().unwrap_or_default();
+        non_visual_properties.hidden = shape.as_ref().is_some_and(|s| s.style.hidden);
+        non_visual_properties.description = properties
+            .as_ref()
+            .and_then(|p| p.alt_text.clone())
+            .or(shape.as_ref().and_then(|s| s.alt_text.clone()))
+            .unwrap_or_default();
+        non_visual_properties.print_with_sheet = properties
+            .as_ref()
+            .and_then(|p| p.print)
+            .or(client_data.as_ref().map(|c| c.print_object))
+            .unwrap_or(true);
+        non_visual_properties.lock_with_sheet = properties
+            .as_ref()
+            .and_then(|p| p.locked)
+            .or(client_data.as_ref().map(|c| c.locked))
+            .unwrap_or(true);
+
+        let r#macro = properties
+            .as_ref()
+            .and_then(|p| p.r#macro.clone())
+            .or(client_data.as_ref().and_then(|c| c.r#macro.clone()));
+        let text = shape.as_ref().and_then(|s| s.text.clone());
+
+        // ActiveX controls
+        if let Some(properties) = properties
+            .as_ref()
+            .filter(|_| control_type == ControlType::ActiveX)
+        {
+            return Self {
+                control_type,
+                anchor,
+                non_visual_properties,
+                linked_cell: properties.linked_cell.clone(),
+                input_range: properties.list_fill_range.clone(),
+                checked: None,
+                selected_indexes: vec![],
+                list_items: vec![],
+                value: None,
+                min: None,
+                max: None,
+                increment: None,
+                r#macro,
+                text,
+                active_x,
+            };
+        }
+
+        // form controls
+        if let Some(form_control) = form_control {
+            let selected_indexes: Vec<u64> = match form_control.multi_selection.clone() {
+                Some(multi) => multi
+                    .split(',')
+                    .filter_map(|s| string_to_unsignedint(s.trim()))
+                    .collect(),
+                None => form_control.selected.into_iter().collect(),
+            };
+            return Self {
+                control_type,
+                anchor,
+                non_visual_properties,
+                linked_cell: form_control.fmla_link,
+                input_range: form_control.fmla_range,
+                checked: VmlCheckedState::from_string(form_control.checked),
+                selected_indexes: selected_indexes.into_iter().filter(|s| *s > 0).collect(),
+                list_items: form_control.items,
+                value: form_control.value,
+                min: form_control.min,
+                max: form_control.max,
+                increment: form_control.increment,
+                r#macro,
+                text,
+                active_x,
+            };
+        }
+
+        // properties from the legacy drawing only
+        let client_data = client_data.as_ref();
+        return Self {
+            control_type,
+            anchor,
+            non_visual_properties,
+            linked_cell: client_data.and_then(|c| c.linked_cell.clone()),
+            input_range: client_data.and_then(|c| c.input_range.clone()),
+            checked: client_data.and_then(|c| c.checked.clone()),
+            selected_indexes: client_data
+                .and_then(|c| c.selected_index)
+                .into_iter()
+                .collect(),
+            list_items: client_data
+                .map(|c| c.list_items.clone())
+                .unwrap_or_default(),
+            value: client_data.and_then(|c| c.value),
+            min: client_data.and_then(|c| c.min),
+            max: client_data.and_then(|c| c.max),
+            increment: client_data.and_then(|c| c.increment),
+            r#macro,
+            text,
+            active_x,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.objecttypevalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ControlType {
+    Button,
+    CheckBox,
+    Dialog,
+    Dropdown,
+    EditBox,
+    GroupBox,
+    Label,
+    ListBox,
+    OptionButton,
+    ScrollBar,
+    Spinner,
+    ActiveX,
+    Unknown,
+}
+
+impl ControlType {
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else { return Self::Unknown };
+        return match s.as_ref() {
+            "Button" => Self::Button,
+            "CheckBox" => Self::CheckBox,
+            "Dialog" => Self::Dialog,
+            "Drop" => Self::Dropdown,
+            "EditBox" => Self::EditBox,
+            "GBox" => Self::GroupBox,
+            "Label" => Self::Label,
+            "List" => Self::ListBox,
+            "Radio" => Self::OptionButton,
+            "Scroll" => Self::ScrollBar,
+            "Spin" => Self::Spinner,
+            _ => Self::Unknown,
+        };
+    }
+
+    pub(crate) fn from_vml_object_type(object_type: Option<VmlObjectType>) -> Self {
+        let Some(object_type) = object_type else {
+            return Self::Unknown;
+        };
+        return match object_type {
+            VmlObjectType::Button => Self::Button,
+            VmlObjectType::Checkbox => Self::CheckBox,
+            VmlObjectType::Dialog => Self::Dialog,
+            VmlObjectType::Dropdown => Self::Dropdown,
+            VmlObjectType::EditBox => Self::EditBox,
+            VmlObjectType::GroupBox => Self::GroupBox,
+            VmlObjectType::Label => Self::Label,
+            VmlObjectType::ListBox => Self::ListBox,
+            VmlObjectType::OptionButton => Self::OptionButton,
+            VmlObjectType::ScrollBar => Self::ScrollBar,
+            VmlObjectType::Spinner => Self::Spinner,
+            _ => Self::Unknown,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office.activex.activexcontroldata?view=openxml-3.0.1
+///
+/// Data of an ActiveX control parsed from xl/activeX/activeX{}.xml.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ActiveXControl {
+    /// Class id of the control. Ex: `{8BD21D40-EC42-11CE-9E0D-00AA006002F3}` for a check box
+    pub class_id: Option<String>,
+
+    /// How the properties are stored. Ex: persistPropertyBag, persistStorage
+    pub persistence: Option<String>,
+
+    /// Properties stored in the xml (`persistPropertyBag`): (name, value). Ex: `("Caption", "Submit")`
+    pub properties: Vec<(String, String)>,
+}
+
+impl ActiveXControl {
+    pub(crate) fn from_raw(raw: XlsxActiveXControl) -> Self {
+        return Self {
+            class_id: raw.class_id,
+            persistence: raw.persistence,
+            properties: raw
+                .properties
+                .into_iter()
+                .filter_map(|p| Some((p.name?, p.value.unwrap_or_default())))
+                .collect(),
+        };
+    }
+
+    /// Get the value of a property persisted in the xml. Ex: `Caption`
+    pub fn get_property(&self, name: &str) -> Option<String> {
+        return self
+            .properties
+            .iter()
+            .find(|p| p.0.eq_ignore_ascii_case(name))
+            .map(|p| p.1.clone());
+    }
+}
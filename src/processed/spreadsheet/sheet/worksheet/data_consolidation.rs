@@ -0,0 +1,80 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::pivot_table::data_field::DataConsolidateFunction;
+use crate::{
+    common_types::Dimension,
+    packaging::relationship::{zip_path_for_id, XlsxRelationships},
+    raw::spreadsheet::sheet::worksheet::data_consolidate::{
+        XlsxDataConsolidate, XlsxDataReference,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.dataconsolidate?view=openxml-3.0.1
+///
+/// Settings of the last data consolidation (Data > Consolidate) of a worksheet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DataConsolidation {
+    /// Function aggregating the sources
+    pub function: DataConsolidateFunction,
+
+    pub sources: Vec<ConsolidationSource>,
+
+    /// Whether the labels in the top row of the sources are used
+    pub top_labels: bool,
+
+    /// Whether the labels in the left column of the sources are used
+    pub left_labels: bool,
+
+    /// Whether the consolidated data is linked to the sources
+    pub link: bool,
+}
+
+impl DataConsolidation {
+    pub(crate) fn from_raw(raw: XlsxDataConsolidate, worksheet_rels: &XlsxRelationships) -> Self {
+        return Self {
+            function: DataConsolidateFunction::from_string(raw.function),
+            sources: raw
+                .data_references
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|r| ConsolidationSource::from_raw(r, worksheet_rels))
+                .collect(),
+            top_labels: raw.top_labels.unwrap_or(false),
+            left_labels: raw.left_labels.or(raw.start_labels).unwrap_or(false),
+            link: raw.link.unwrap_or(false),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.datareference?view=openxml-3.0.1
+///
+/// A range consolidated: either a range of a sheet or a defined name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ConsolidationSource {
+    pub sheet_name: Option<String>,
+
+    pub range: Option<Dimension>,
+
+    pub defined_name: Option<String>,
+
+    /// Target of the external workbook containing the range, None for a range of this workbook
+    pub external_workbook: Option<String>,
+}
+
+impl ConsolidationSource {
+    pub(crate) fn from_raw(raw: XlsxDataReference, worksheet_rels: &XlsxRelationships) -> Self {
+        return Self {
+            sheet_name: raw.sheet,
+            range: raw
+                .reference
+                .and_then(|r| Dimension::from_sqref(&r).first().copied()),
+            defined_name: raw.name,
+            external_workbook: raw
+                .r_id
+                .and_then(|id| zip_path_for_id(worksheet_rels, &id)),
+        };
+    }
+}
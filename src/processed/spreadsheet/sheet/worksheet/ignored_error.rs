@@ -0,0 +1,86 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    raw::spreadsheet::sheet::worksheet::ignored_error::XlsxIgnoredError,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ignorederror?view=openxml-3.0.1
+///
+/// Error checking warnings deliberately ignored on ranges of a worksheet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct IgnoredError {
+    pub ranges: Vec<Dimension>,
+
+    /// Kinds of warnings ignored on the ranges
+    pub kinds: Vec<IgnoredErrorType>,
+}
+
+impl IgnoredError {
+    pub(crate) fn from_raw(raw: XlsxIgnoredError) -> Self {
+        let flags = [
+            (
+                raw.number_stored_as_text,
+                IgnoredErrorType::NumberStoredAsText,
+            ),
+            (raw.formula, IgnoredErrorType::InconsistentFormula),
+            (raw.formula_range, IgnoredErrorType::FormulaRange),
+            (raw.unlocked_formula, IgnoredErrorType::UnlockedFormula),
+            (
+                raw.empty_cell_reference,
+                IgnoredErrorType::EmptyCellReference,
+            ),
+            (raw.eval_error, IgnoredErrorType::EvaluationError),
+            (
+                raw.list_data_validation,
+                IgnoredErrorType::ListDataValidation,
+            ),
+            (raw.calculated_column, IgnoredErrorType::CalculatedColumn),
+            (raw.two_digit_text_year, IgnoredErrorType::TwoDigitTextYear),
+        ];
+
+        return Self {
+            ranges: raw
+                .sqref
+                .map(|s| Dimension::from_sqref(&s))
+                .unwrap_or(vec![]),
+            kinds: flags
+                .into_iter()
+                .filter(|(flag, _)| flag.unwrap_or(false))
+                .map(|(_, kind)| kind)
+                .collect(),
+        };
+    }
+
+    /// Whether the coordinate is within the ranges
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        return self.ranges.iter().any(|r| r.contains(coordinate));
+    }
+}
+
+/// Kinds of error checking warnings
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum IgnoredErrorType {
+    /// Number formatted as text or preceded by an apostrophe
+    NumberStoredAsText,
+    /// Formula inconsistent with the formulas of the adjacent cells
+    InconsistentFormula,
+    /// Formula omitting cells adjacent to its range
+    FormulaRange,
+    /// Unlocked cell containing a formula
+    UnlockedFormula,
+    /// Formula referring to empty cells
+    EmptyCellReference,
+    /// Formula resulting in an error
+    EvaluationError,
+    /// Value not valid for the data validation of a table column
+    ListDataValidation,
+    /// Formula inconsistent with the calculated column formula of a table
+    CalculatedColumn,
+    /// Date as text with a two digit year
+    TwoDigitTextYear,
+}
@@ -1,12 +1,22 @@
 pub mod calculation_reference;
 pub mod cell;
+#[cfg(feature = "drawing")]
+pub mod control;
+pub mod data_consolidation;
+pub mod ignored_error;
+pub mod ole_object;
+pub mod pivot_table;
+pub mod scenario;
+pub mod slicer;
+pub mod sparkline;
 pub mod table;
+pub mod timeline;
 
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
 #[cfg(feature = "drawing")]
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 use anyhow::bail;
 use std::{
@@ -15,21 +25,47 @@ use std::{
 };
 
 #[cfg(feature = "drawing")]
-use crate::processed::drawing::worksheet_drawing::{
-    anchor_type::DrawingAnchorType, content_type::DrawingContentType, WorksheetDrawing,
+use crate::processed::drawing::{
+    vml::VmlDrawing,
+    worksheet_drawing::{
+        bounds::SheetGeometry, content_type::DrawingContentType, WorksheetDrawing,
+    },
+};
+
+#[cfg(feature = "drawing")]
+use crate::raw::{
+    drawing::{
+        chart::XlsxChartSpace, diagram::XlsxDiagrams, ink::XlsxInk,
+        worksheet_drawing::XlsxWorksheetDrawing,
+    },
+    spreadsheet::{
+        active_x::XlsxActiveXControl, form_control_properties::XlsxFormControlProperties,
+    },
 };
 
 #[cfg(feature = "drawing")]
-use crate::raw::drawing::worksheet_drawing::{XlsxWorksheetDrawing, XlsxWorksheetDrawingType};
+use control::Control;
 
 use calculation_reference::CalculationReferenceMode;
 use cell::{cell_property::CellProperty, cell_value::CellValueType, Cell};
+use data_consolidation::DataConsolidation;
+use ignored_error::{IgnoredError, IgnoredErrorType};
+use ole_object::OleObject;
+use pivot_table::PivotTable;
+use scenario::Scenarios;
+#[cfg(feature = "drawing")]
+use slicer::Slicer;
+use sparkline::SparklineGroup;
 use table::Table;
+#[cfg(feature = "drawing")]
+use timeline::Timeline;
 
 use crate::{
     common_types::{Coordinate, Dimension},
     packaging::relationship::XlsxRelationships,
-    processed::shared::hyperlink::Hyperlink,
+    processed::{
+        shared::hyperlink::Hyperlink, spreadsheet::sheet::sheet_properties::SheetProperties,
+    },
     raw::{
         drawing::{scheme::color_scheme::XlsxColorScheme, theme::XlsxTheme},
         spreadsheet::{
@@ -57,6 +93,9 @@ pub struct Worksheet {
     pub name: String,
     pub sheet_id: u64,
 
+    /// sheetPr (Sheet Properties): tab color, code name, filter mode, fit to page and etc.
+    pub properties: SheetProperties,
+
     /// None if the sheet does not contain any data
     pub dimension: Option<Dimension>,
 
@@ -64,6 +103,9 @@ pub struct Worksheet {
 
     pub tables: Vec<Table>,
 
+    /// Pivot tables whose field names and items are resolved with their pivot caches
+    pub pivot_tables: Vec<PivotTable>,
+
     /// Value that indicates whether to use a 1900 or 1904 date base when converting serial values in the workbook to dates.
     ///
     /// - true: workbook uses the 1904 backward compatibility date system.
@@ -105,6 +147,39 @@ pub struct Worksheet {
     #[cfg(feature = "drawing")]
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
     image_bytes: Box<BTreeMap<String, Vec<u8>>>,
+
+    // (r_id, chart)
+    #[cfg(feature = "drawing")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    charts: Box<BTreeMap<String, XlsxChartSpace>>,
+
+    // (r_id, ink) of the content parts
+    #[cfg(feature = "drawing")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    inks: Box<BTreeMap<String, XlsxInk>>,
+
+    // parts of the diagrams (SmartArt) of the graphic frames
+    #[cfg(feature = "drawing")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    diagrams: Box<XlsxDiagrams>,
+
+    #[cfg(feature = "drawing")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    legacy_drawing: Option<Box<VmlDrawing>>,
+
+    #[cfg(feature = "drawing")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    legacy_drawing_header_footer: Option<Box<VmlDrawing>>,
+
+    // (r_id, properties) of the form controls
+    #[cfg(feature = "drawing")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    form_control_properties: BTreeMap<String, XlsxFormControlProperties>,
+
+    // (r_id, control) of the ActiveX controls
+    #[cfg(feature = "drawing")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    active_x_controls: BTreeMap<String, XlsxActiveXControl>,
 }
 
 impl Worksheet {
@@ -201,6 +276,86 @@ impl Worksheet {
         })
     }
 
+    /// get all embedded and linked OLE objects within a worksheet.
+    ///
+    /// The bytes of the embedded parts and preview images are read with `Excel::get_ole_object_bytes`.
+    pub fn get_ole_objects(&self) -> Vec<OleObject> {
+        return self
+            .raw_sheet
+            .ole_objects
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|o| OleObject::from_raw(o, &self.worksheet_rels))
+            .collect();
+    }
+
+    /// get all sparkline groups within a worksheet.
+    pub fn get_sparkline_groups(&self) -> Vec<SparklineGroup> {
+        let color_scheme = self.get_color_scheme();
+        return self
+            .raw_sheet
+            .sparkline_groups
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|g| {
+                SparklineGroup::from_raw(g, self.stylesheet.colors.clone(), color_scheme.clone())
+            })
+            .collect();
+    }
+
+    /// get what-if scenarios of the worksheet. None if the worksheet does not have any.
+    pub fn get_scenarios(&self) -> Option<Scenarios> {
+        return self
+            .raw_sheet
+            .scenarios
+            .clone()
+            .map(|s| Scenarios::from_raw(s));
+    }
+
+    /// get settings of the last data consolidation of the worksheet. None if the worksheet has never been consolidated.
+    pub fn get_data_consolidation(&self) -> Option<DataConsolidation> {
+        return self
+            .raw_sheet
+            .data_consolidate
+            .clone()
+            .map(|c| DataConsolidation::from_raw(c, &self.worksheet_rels));
+    }
+
+    /// get all error checking warnings ignored within a worksheet.
+    pub fn get_ignored_errors(&self) -> Vec<IgnoredError> {
+        return self
+            .raw_sheet
+            .ignored_errors
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|e| IgnoredError::from_raw(e))
+            .collect();
+    }
+
+    /// get kinds of error checking warnings ignored for a specific coordinate.
+    pub fn get_ignored_errors_for_cell(&self, coordinate: Coordinate) -> Vec<IgnoredErrorType> {
+        let mut kinds: Vec<IgnoredErrorType> = vec![];
+        for error in self.get_ignored_errors().into_iter() {
+            if !error.contains(&coordinate) {
+                continue;
+            }
+            for kind in error.kinds.into_iter() {
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+        }
+        return kinds;
+    }
+
+    /// get cells added to the Watch Window within a worksheet.
+    pub fn get_cell_watches(&self) -> Vec<Coordinate> {
+        return self.raw_sheet.cell_watches.clone().unwrap_or(vec![]);
+    }
+
     /// get all drawings within a worksheet.
     #[cfg(feature = "drawing")]
     pub fn get_drawings(&self) -> Vec<WorksheetDrawing> {
@@ -208,75 +363,82 @@ impl Worksheet {
         let Some(raw_drawings) = self.raw_drawing.clone() else {
             return drawings;
         };
+        let sheet_geometry = Arc::new(SheetGeometry::from_worksheet(&self.raw_sheet));
         for raw in raw_drawings.drawings.unwrap_or(vec![]).into_iter() {
-            match raw {
-                XlsxWorksheetDrawingType::AbsoluteAnchor(absolute_anchor) => {
-                    let Some(content) = DrawingContentType::from_raw(
-                        absolute_anchor.clone().drawing_content,
-                        absolute_anchor.clone().extent,
-                        absolute_anchor.clone().position,
-                        absolute_anchor.clone().client_data,
-                        None,
-                        *self.drawing_rels.clone(),
-                        *self.image_bytes.clone(),
-                        *self.defined_names.clone(),
-                        self.get_color_scheme(),
-                        self.theme.clone(),
-                    ) else {
-                        continue;
-                    };
-
-                    let anchor = DrawingAnchorType::AbsoluteAnchor;
-
-                    drawings.push(WorksheetDrawing { anchor, content });
-                }
-                XlsxWorksheetDrawingType::OneCellAnchor(one_cell_anchor_drawing) => {
-                    let Some(content) = DrawingContentType::from_raw(
-                        one_cell_anchor_drawing.clone().drawing_content,
-                        one_cell_anchor_drawing.clone().extent,
-                        None,
-                        one_cell_anchor_drawing.clone().client_data,
-                        None,
-                        *self.drawing_rels.clone(),
-                        *self.image_bytes.clone(),
-                        *self.defined_names.clone(),
-                        self.get_color_scheme(),
-                        self.theme.clone(),
-                    ) else {
-                        continue;
-                    };
-
-                    let anchor =
-                        DrawingAnchorType::from_one_cell_anchor(one_cell_anchor_drawing.clone());
-
-                    drawings.push(WorksheetDrawing { anchor, content });
-                }
-                XlsxWorksheetDrawingType::TwoCellAnchor(two_cell_anchor_drawing) => {
-                    let Some(content) = DrawingContentType::from_raw(
-                        two_cell_anchor_drawing.clone().drawing_content,
-                        None,
-                        None,
-                        two_cell_anchor_drawing.clone().client_data,
-                        None,
-                        *self.drawing_rels.clone(),
-                        *self.image_bytes.clone(),
-                        *self.defined_names.clone(),
-                        self.get_color_scheme(),
-                        self.theme.clone(),
-                    ) else {
-                        continue;
-                    };
-
-                    let anchor =
-                        DrawingAnchorType::from_two_cell_anchor(two_cell_anchor_drawing.clone());
-
-                    drawings.push(WorksheetDrawing { anchor, content });
-                }
-            }
+            let Some(drawing) = WorksheetDrawing::from_raw(
+                raw,
+                *self.drawing_rels.clone(),
+                *self.image_bytes.clone(),
+                &self.charts,
+                *self.inks.clone(),
+                &self.diagrams,
+                *self.defined_names.clone(),
+                self.get_color_scheme(),
+                self.theme.clone(),
+                Arc::clone(&sheet_geometry),
+            ) else {
+                continue;
+            };
+            drawings.push(drawing);
         }
 
         return drawings;
     }
+
+    /// get the drawing of the graphic frame displaying a slicer, with its anchor.
+    #[cfg(feature = "drawing")]
+    pub fn get_drawing_for_slicer(&self, slicer: &Slicer) -> Option<WorksheetDrawing> {
+        return self.get_drawings().into_iter().find(|d| match &d.content {
+            DrawingContentType::GraphicFrame(frame) => {
+                frame.slicer_name.as_ref() == Some(&slicer.name)
+            }
+            _ => false,
+        });
+    }
+
+    /// get the drawing of the graphic frame displaying a timeline, with its anchor.
+    #[cfg(feature = "drawing")]
+    pub fn get_drawing_for_timeline(&self, timeline: &Timeline) -> Option<WorksheetDrawing> {
+        return self.get_drawings().into_iter().find(|d| match &d.content {
+            DrawingContentType::GraphicFrame(frame) => {
+                frame.timeline_name.as_ref() == Some(&timeline.name)
+            }
+            _ => false,
+        });
+    }
+
+    /// get the legacy (VML) drawing of a worksheet containing comment boxes and legacy form controls.
+    #[cfg(feature = "drawing")]
+    pub fn get_legacy_drawing(&self) -> Option<VmlDrawing> {
+        return self.legacy_drawing.clone().map(|d| *d);
+    }
+
+    /// get the legacy (VML) drawing of a worksheet containing the header and footer images.
+    #[cfg(feature = "drawing")]
+    pub fn get_header_footer_drawing(&self) -> Option<VmlDrawing> {
+        return self.legacy_drawing_header_footer.clone().map(|d| *d);
+    }
+
+    /// get all form controls and ActiveX controls within a worksheet.
+    #[cfg(feature = "drawing")]
+    pub fn get_controls(&self) -> Vec<Control> {
+        return self
+            .raw_sheet
+            .controls
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|c| {
+                Control::from_raw(
+                    c,
+                    &self.worksheet_rels,
+                    &self.form_control_properties,
+                    &self.active_x_controls,
+                    self.legacy_drawing.as_deref(),
+                )
+            })
+            .collect();
+    }
 }
 
 impl Worksheet {
@@ -286,6 +448,7 @@ impl Worksheet {
         worksheet: Box<XlsxWorksheet>,
         worksheet_rels: Box<XlsxRelationships>,
         tables: Box<Vec<XlsxTable>>,
+        pivot_tables: Vec<PivotTable>,
         defined_names: Box<XlsxDefinedNames>,
         is_1904: bool,
         calculation_reference_mode: Option<CalculationReferenceMode>,
@@ -295,6 +458,16 @@ impl Worksheet {
         #[cfg(feature = "drawing")] drawing_rels: Box<XlsxRelationships>,
         #[cfg(feature = "drawing")] raw_drawing: Option<Box<XlsxWorksheetDrawing>>,
         #[cfg(feature = "drawing")] image_bytes: Box<BTreeMap<String, Vec<u8>>>,
+        #[cfg(feature = "drawing")] charts: Box<BTreeMap<String, XlsxChartSpace>>,
+        #[cfg(feature = "drawing")] inks: Box<BTreeMap<String, XlsxInk>>,
+        #[cfg(feature = "drawing")] diagrams: Box<XlsxDiagrams>,
+        #[cfg(feature = "drawing")] legacy_drawing: Option<Box<VmlDrawing>>,
+        #[cfg(feature = "drawing")] legacy_drawing_header_footer: Option<Box<VmlDrawing>>,
+        #[cfg(feature = "drawing")] form_control_properties: BTreeMap<
+            String,
+            XlsxFormControlProperties,
+        >,
+        #[cfg(feature = "drawing")] active_x_controls: BTreeMap<String, XlsxActiveXControl>,
     ) -> Self {
         let default_table_style_name = if let Some(style) = stylesheet.clone().table_styles {
             style.default_table_style
@@ -307,12 +480,25 @@ impl Worksheet {
             .map(|t| Table::from_raw(t, default_table_style_name.clone()))
             .collect();
 
+        let color_scheme = if let Some(theme) = theme.clone() {
+            theme.theme_elements.and_then(|e| e.color_scheme)
+        } else {
+            None
+        };
+        let properties = SheetProperties::from_raw(
+            worksheet.sheet_properties.clone(),
+            stylesheet.colors.clone(),
+            color_scheme,
+        );
+
         return Self {
             name,
             sheet_id,
+            properties,
             dimension: Self::get_dimension(*worksheet.clone()),
             merged_cells: worksheet.merge_cells.clone().unwrap_or(vec![]),
             tables,
+            pivot_tables,
             is_1904,
             calculation_reference_mode: calculation_reference_mode
                 .unwrap_or(CalculationReferenceMode::default()),
@@ -328,6 +514,20 @@ impl Worksheet {
             drawing_rels,
             #[cfg(feature = "drawing")]
             image_bytes,
+            #[cfg(feature = "drawing")]
+            charts,
+            #[cfg(feature = "drawing")]
+            inks,
+            #[cfg(feature = "drawing")]
+            diagrams,
+            #[cfg(feature = "drawing")]
+            legacy_drawing,
+            #[cfg(feature = "drawing")]
+            legacy_drawing_header_footer,
+            #[cfg(feature = "drawing")]
+            form_control_properties,
+            #[cfg(feature = "drawing")]
+            active_x_controls,
         };
     }
 }
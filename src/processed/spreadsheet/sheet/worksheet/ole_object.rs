@@ -0,0 +1,204 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[cfg(feature = "drawing")]
+use crate::processed::drawing::worksheet_drawing::anchor_type::DrawingAnchorType;
+use crate::{
+    packaging::relationship::{rel_for_id, XlsxRelationships, EXTERNAL_TARGET_MODE},
+    raw::spreadsheet::{ole_package::XlsxOlePackage, sheet::worksheet::ole_object::XlsxOleObject},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.oleobject?view=openxml-3.0.1
+///
+/// An embedded or linked OLE object of the worksheet. Ex: a Word document, a PDF, a packaged file.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OleObject {
+    /// Program of the object. Ex: `Word.Document.12`, `Package`, `AcroExch.Document.DC`
+    pub prog_id: Option<String>,
+
+    /// Id of the legacy (VML) shape of the object
+    pub shape_id: Option<u64>,
+
+    /// Cells the object is anchored to
+    #[cfg(feature = "drawing")]
+    pub anchor: Option<DrawingAnchorType>,
+
+    /// Whether the object is linked to an external file instead of embedded
+    pub is_linked: bool,
+
+    /// Reference to the external link of a linked object. Ex: `[1]!''''`
+    pub link: Option<String>,
+
+    /// Zip path of the embedded part (Ex: `xl/embeddings/oleObject1.bin`), or path of the linked file
+    pub target: Option<String>,
+
+    /// Update mode of a linked object
+    pub update_mode: Option<OleUpdateMode>,
+
+    /// Whether the object is displayed as an icon instead of its content
+    pub display_as_icon: bool,
+
+    /// Alternative text
+    pub alt_text: Option<String>,
+
+    /// Name of the macro run when the object is activated
+    pub r#macro: Option<String>,
+
+    /// Image displayed for the object
+    pub preview_image: Option<OlePreviewImage>,
+}
+
+impl OleObject {
+    pub(crate) fn from_raw(raw: XlsxOleObject, worksheet_rels: &XlsxRelationships) -> Self {
+        let rel = raw
+            .id
+            .as_ref()
+            .and_then(|id| rel_for_id(worksheet_rels, id));
+        let is_external = rel
+            .as_ref()
+            .is_some_and(|r| r.target_mode == Some(EXTERNAL_TARGET_MODE.to_string()));
+
+        let properties = raw.object_properties;
+        let preview_image = properties
+            .as_ref()
+            .and_then(|p| p.id.as_ref())
+            .and_then(|id| OlePreviewImage::from_rel(id, worksheet_rels));
+
+        return Self {
+            prog_id: raw.prog_id,
+            shape_id: raw.shape_id,
+            #[cfg(feature = "drawing")]
+            anchor: properties
+                .as_ref()
+                .and_then(|p| p.anchor.clone())
+                .map(DrawingAnchorType::from_object_anchor),
+            is_linked: raw.link.is_some() || is_external,
+            link: raw.link,
+            target: rel.map(|r| r.target),
+            update_mode: OleUpdateMode::from_string(raw.ole_update),
+            display_as_icon: raw.dv_aspect.as_deref() == Some("DVASPECT_ICON"),
+            alt_text: properties.as_ref().and_then(|p| p.alt_text.clone()),
+            r#macro: properties.as_ref().and_then(|p| p.r#macro.clone()),
+            preview_image,
+        };
+    }
+
+    /// Zip path of the embedded part. None for linked objects.
+    pub(crate) fn embedded_path(&self) -> Option<&str> {
+        if self.is_linked {
+            return None;
+        }
+        return self.target.as_deref();
+    }
+}
+
+/// Bytes of an OLE object, read from the package with `Excel::get_ole_object_bytes`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OleObjectBytes {
+    /// Bytes of the embedded part: an OLE compound file (.bin) or a package (Ex: .docx).
+    ///
+    /// None for linked objects.
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    pub payload: Option<Vec<u8>>,
+
+    /// File wrapped by an OLE1 "Package" object
+    pub package: Option<OlePackage>,
+
+    /// Bytes of the preview image
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    pub preview_image: Option<Vec<u8>>,
+}
+
+impl OleObjectBytes {
+    pub(crate) fn from_raw(payload: Option<Vec<u8>>, preview_image: Option<Vec<u8>>) -> Self {
+        let package = payload
+            .as_ref()
+            .and_then(|p| XlsxOlePackage::load(p).ok().flatten())
+            .map(OlePackage::from_raw);
+        return Self {
+            payload,
+            package,
+            preview_image,
+        };
+    }
+}
+
+/// Image displayed for an OLE object. Ex: `xl/media/image1.emf`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OlePreviewImage {
+    /// File name. Ex: `image1.emf`
+    pub name: String,
+
+    /// Zip path
+    pub path: String,
+}
+
+impl OlePreviewImage {
+    fn from_rel(r_id: &str, worksheet_rels: &XlsxRelationships) -> Option<Self> {
+        let rel = rel_for_id(worksheet_rels, r_id).filter(|r| r.targets_part())?;
+        let name = rel
+            .target
+            .rsplit('/')
+            .next()
+            .unwrap_or(&rel.target)
+            .to_string();
+        return Some(Self {
+            name,
+            path: rel.target,
+        });
+    }
+}
+
+/// https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleds/df4f6c8b-e6f8-4ed4-9cd4-5a5b4bb3c9a6
+///
+/// A file wrapped by an OLE1 "Package" object.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OlePackage {
+    /// Original file name. Ex: `notes.txt`
+    pub file_name: String,
+
+    /// Full path of the file when it was embedded
+    pub source_path: String,
+
+    /// Content of the original file
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    pub contents: Vec<u8>,
+}
+
+impl OlePackage {
+    pub(crate) fn from_raw(raw: XlsxOlePackage) -> Self {
+        return Self {
+            file_name: raw.file_name,
+            source_path: raw.source_path,
+            contents: raw.contents,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.oleupdatevalues?view=openxml-3.0.1
+///
+/// When a linked object is updated.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum OleUpdateMode {
+    /// Updated automatically
+    Always,
+    /// Updated on request
+    OnCall,
+}
+
+impl OleUpdateMode {
+    pub(crate) fn from_string(s: Option<String>) -> Option<Self> {
+        let Some(s) = s else { return None };
+        return match s.as_ref() {
+            "OLEUPDATE_ALWAYS" => Some(Self::Always),
+            "OLEUPDATE_ONCALL" => Some(Self::OnCall),
+            _ => None,
+        };
+    }
+}
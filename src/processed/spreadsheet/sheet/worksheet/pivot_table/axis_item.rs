@@ -0,0 +1,84 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::pivot_table::{
+    page_field::XlsxPageField, row_column_item::XlsxRowColumnItem,
+};
+
+use super::pivot_field::PivotItemType;
+
+/// A field on the row or column axis.
+///
+/// * Field: index of the pivot field
+/// * Values: the virtual "Values" field, used when there is more than one data field
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotFieldReference {
+    Field(u64),
+    Values,
+}
+
+impl PivotFieldReference {
+    pub(crate) fn from_raw(x: i64) -> Self {
+        return if x < 0 {
+            Self::Values
+        } else {
+            Self::Field(x as u64)
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.rowitem?view=openxml-3.0.1
+///
+/// A row or column of the pivot table as last rendered by the spreadsheet application.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotAxisItem {
+    pub item_type: PivotItemType,
+
+    /// Number of leading fields whose items are the same as the previous row or column
+    pub repeated_count: u64,
+
+    /// Index of the data field, for axes with the "Values" field
+    pub data_field_index: u64,
+
+    /// Item indexes of the fields on the axis, starting from the field at `repeated_count`
+    pub item_indexes: Vec<u64>,
+}
+
+impl PivotAxisItem {
+    pub(crate) fn from_raw(raw: XlsxRowColumnItem) -> Self {
+        return Self {
+            item_type: PivotItemType::from_string(raw.r#type),
+            repeated_count: raw.repeated_items_count.unwrap_or(0),
+            data_field_index: raw.data_field_index.unwrap_or(0),
+            item_indexes: raw.member_property_indexes,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagefield?view=openxml-3.0.1
+///
+/// A field in the filter (page) area.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotPageField {
+    /// Index of the pivot field
+    pub field: u64,
+
+    /// Index of the selected item in the pivot field items. None if all items are selected.
+    pub selected_item: Option<u64>,
+
+    pub name: Option<String>,
+}
+
+impl PivotPageField {
+    pub(crate) fn from_raw(raw: XlsxPageField) -> Self {
+        return Self {
+            field: raw.fld.unwrap_or(0).max(0) as u64,
+            selected_item: raw.item,
+            name: raw.name,
+        };
+    }
+}
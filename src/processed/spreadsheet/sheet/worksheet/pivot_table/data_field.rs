@@ -0,0 +1,151 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::raw::spreadsheet::pivot_table::data_field::XlsxDataField;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.datafield?view=openxml-3.0.1
+///
+/// A field aggregated in the values area of the pivot table.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotDataField {
+    /// Display name. Ex: `Sum of Sales`
+    pub name: Option<String>,
+
+    /// Index of the pivot field aggregated
+    pub field: u64,
+
+    pub function: DataConsolidateFunction,
+
+    pub show_data_as: ShowDataAs,
+
+    /// Field used by `show_data_as` calculations
+    pub base_field: Option<i64>,
+
+    /// Item used by `show_data_as` calculations
+    pub base_item: Option<u64>,
+
+    pub number_format_id: Option<u64>,
+}
+
+impl PivotDataField {
+    pub(crate) fn from_raw(raw: XlsxDataField) -> Self {
+        return Self {
+            name: raw.name,
+            field: raw.fld.unwrap_or(0),
+            function: DataConsolidateFunction::from_string(raw.subtotal),
+            show_data_as: ShowDataAs::from_string(raw.show_data_as),
+            base_field: raw.base_field,
+            base_item: raw.base_item,
+            number_format_id: raw.num_fmt_id,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.dataconsolidatefunctionvalues?view=openxml-3.0.1
+///
+/// Aggregation of data fields and field subtotals.
+///
+/// * Count: number of non empty values (COUNTA)
+/// * CountNums: number of numeric values (COUNT)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DataConsolidateFunction {
+    Average,
+    Count,
+    CountNums,
+    Max,
+    Min,
+    Product,
+    StdDev,
+    StdDevp,
+    Sum,
+    Var,
+    Varp,
+}
+
+impl DataConsolidateFunction {
+    pub(crate) fn default() -> Self {
+        Self::Sum
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "average" => Self::Average,
+            "count" => Self::Count,
+            "countNums" => Self::CountNums,
+            "max" => Self::Max,
+            "min" => Self::Min,
+            "product" => Self::Product,
+            "stdDev" => Self::StdDev,
+            "stdDevp" => Self::StdDevp,
+            "sum" => Self::Sum,
+            "var" => Self::Var,
+            "varp" => Self::Varp,
+            _ => Self::default(),
+        };
+    }
+
+    /// Caption used in data field names and subtotals. Ex: `Sum`
+    pub fn caption(&self) -> &str {
+        return match self {
+            Self::Average => "Average",
+            Self::Count => "Count",
+            Self::CountNums => "Count",
+            Self::Max => "Max",
+            Self::Min => "Min",
+            Self::Product => "Product",
+            Self::StdDev => "StdDev",
+            Self::StdDevp => "StdDevp",
+            Self::Sum => "Sum",
+            Self::Var => "Var",
+            Self::Varp => "Varp",
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.showdataasvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ShowDataAs {
+    Normal,
+    Difference,
+    Percent,
+    PercentDiff,
+    RunTotal,
+    PercentOfRow,
+    PercentOfCol,
+    PercentOfTotal,
+    Index,
+}
+
+impl ShowDataAs {
+    pub(crate) fn default() -> Self {
+        Self::Normal
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "normal" => Self::Normal,
+            "difference" => Self::Difference,
+            "percent" => Self::Percent,
+            "percentDiff" => Self::PercentDiff,
+            "runTotal" => Self::RunTotal,
+            "percentOfRow" => Self::PercentOfRow,
+            "percentOfCol" => Self::PercentOfCol,
+            "percentOfTotal" => Self::PercentOfTotal,
+            "index" => Self::Index,
+            _ => Self::default(),
+        };
+    }
+}
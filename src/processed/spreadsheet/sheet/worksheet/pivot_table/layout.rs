@@ -0,0 +1,1243 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::processed::spreadsheet::pivot_cache::{
+    cache_field::{PivotFieldGroup, PivotGroupBy},
+    cache_value::PivotCacheValue,
+    PivotCache,
+};
+
+use super::{
+    axis_item::PivotFieldReference,
+    data_field::DataConsolidateFunction,
+    pivot_field::{PivotField, PivotItemType},
+    PivotTable,
+};
+
+/// Output of a pivot table computed from the records of its pivot cache.
+///
+/// Lines of each axis follow the model of `rowItems` and `colItems`.
+/// `cells` is the resulting grid with headers, item labels and aggregated values,
+/// starting at the top left of the pivot table (excluding the filter area).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotLayout {
+    /// Lines of the row axis, in display order
+    pub rows: Vec<PivotLayoutLine>,
+
+    /// Lines of the column axis, in display order
+    pub columns: Vec<PivotLayoutLine>,
+
+    /// Fields in the filter area
+    pub filters: Vec<PivotLayoutFilter>,
+
+    /// Cells by row then column
+    pub cells: Vec<Vec<PivotLayoutCell>>,
+
+    /// Zero-based row offset of the first data row in `cells`
+    pub first_data_row: u64,
+
+    /// Zero-based column offset of the first data column in `cells`
+    pub first_data_column: u64,
+}
+
+/// A row or a column of the computed pivot table.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotLayoutLine {
+    /// * Data: an item
+    /// * Default, Sum, CountA, ...: a subtotal of the last item
+    /// * Grand: grand total
+    pub line_type: PivotItemType,
+
+    /// Items from the outermost field of the axis
+    pub items: Vec<PivotLayoutItem>,
+
+    /// Number of leading items that are the same as in the previous line
+    pub repeated_count: u64,
+
+    /// Index of the data field, for axes with the "Values" field
+    pub data_field_index: Option<u64>,
+
+    /// Whether the line holds aggregated values.
+    ///
+    /// False for group headers whose subtotals are shown at the bottom of the group.
+    pub has_values: bool,
+}
+
+/// An item of a line.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotLayoutItem {
+    pub field: PivotFieldReference,
+
+    /// Index of the item in `PivotField::items`, or index of the data field for the "Values" field
+    pub index: u64,
+
+    pub caption: String,
+}
+
+/// A field in the filter area and its selection.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotLayoutFilter {
+    /// Index of the pivot field
+    pub field: u64,
+
+    pub name: String,
+
+    /// `(All)`, the caption of the selected item, or `(Multiple Items)` if some items are hidden
+    pub caption: String,
+}
+
+/// A cell of the computed pivot table.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotLayoutCell {
+    pub cell_type: PivotLayoutCellType,
+
+    /// Caption for headers and labels, aggregated value for values. `Missing` for empty cells.
+    pub value: PivotCacheValue,
+
+    /// Index of the line in `PivotLayout::rows`
+    pub row: Option<u64>,
+
+    /// Index of the line in `PivotLayout::columns`
+    pub column: Option<u64>,
+
+    /// Index of the data field aggregated
+    pub data_field_index: Option<u64>,
+
+    /// Indentation level of row labels in compact form
+    pub indent: u64,
+}
+
+impl PivotLayoutCell {
+    fn empty() -> Self {
+        Self {
+            cell_type: PivotLayoutCellType::Empty,
+            value: PivotCacheValue::Missing,
+            row: None,
+            column: None,
+            data_field_index: None,
+            indent: 0,
+        }
+    }
+
+    fn header(caption: String) -> Self {
+        Self {
+            cell_type: PivotLayoutCellType::Header,
+            value: PivotCacheValue::String(caption),
+            ..Self::empty()
+        }
+    }
+}
+
+/// * Empty
+/// * Header: captions such as the data field name, `Row Labels` or field names
+/// * RowLabel, ColumnLabel: item, subtotal and grand total captions
+/// * Value: aggregated value of a data field
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotLayoutCellType {
+    Empty,
+    Header,
+    RowLabel,
+    ColumnLabel,
+    Value,
+}
+
+impl PivotTable {
+    /// Compute the output of the pivot table from the records of its pivot cache.
+    ///
+    /// Records are filtered by the page field selections and hidden items.
+    /// Fields are laid out in compact, outline or tabular form
+    /// with their subtotals and the grand totals of the table.
+    pub fn compute_layout(&self, cache: &PivotCache) -> PivotLayout {
+        return LayoutEngine::new(self, cache).layout();
+    }
+}
+
+/// An item of an axis level with its records,
+/// and its pivot field and whether it is expanded (None for data fields of the "Values" field).
+type AxisChild<'a> = (PivotLayoutItem, Vec<usize>, Option<(&'a PivotField, bool)>);
+
+/// A line with the records aggregated in it.
+struct AxisLine {
+    line: PivotLayoutLine,
+    records: Vec<usize>,
+}
+
+struct LayoutEngine<'a> {
+    table: &'a PivotTable,
+    cache: &'a PivotCache,
+    row_fields: Vec<PivotFieldReference>,
+    column_fields: Vec<PivotFieldReference>,
+    /// Pivot item index of each field (None if the field is not on an axis), by record
+    keys: Vec<Vec<Option<u64>>>,
+}
+
+impl<'a> LayoutEngine<'a> {
+    fn new(table: &'a PivotTable, cache: &'a PivotCache) -> Self {
+        let mut row_fields = table.row_fields.clone();
+        let mut column_fields = table.column_fields.clone();
+        let has_values = row_fields.contains(&PivotFieldReference::Values)
+            || column_fields.contains(&PivotFieldReference::Values);
+        if table.data_fields.len() > 1 && !has_values {
+            if table.data_on_rows {
+                row_fields.push(PivotFieldReference::Values);
+            } else {
+                column_fields.push(PivotFieldReference::Values);
+            }
+        }
+
+        let mut axis_fields: Vec<usize> =
+            table.page_fields.iter().map(|p| p.field as usize).collect();
+        for reference in row_fields.iter().chain(column_fields.iter()) {
+            if let PivotFieldReference::Field(f) = reference {
+                axis_fields.push(*f as usize);
+            }
+        }
+
+        // cache item index to pivot item index, by field
+        let item_indexes: Vec<HashMap<u64, u64>> = table
+            .fields
+            .iter()
+            .map(|field| {
+                field
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| item.item_type == PivotItemType::Data)
+                    .filter_map(|(index, item)| item.index.map(|x| (x, index as u64)))
+                    .collect()
+            })
+            .collect();
+
+        let shared_item_indexes: Vec<HashMap<String, usize>> = cache
+            .fields
+            .iter()
+            .map(|field| {
+                field
+                    .shared_items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (value_key(value), index))
+                    .collect()
+            })
+            .collect();
+
+        let keys: Vec<Vec<Option<u64>>> = cache
+            .records
+            .iter()
+            .map(|record| {
+                let mut keys: Vec<Option<u64>> = vec![None; table.fields.len()];
+                for f in axis_fields.iter() {
+                    if *f >= keys.len() {
+                        continue;
+                    }
+                    keys[*f] = cache_item_index(cache, &shared_item_indexes, *f, record)
+                        .and_then(|x| item_indexes[*f].get(&(x as u64)).copied());
+                }
+                keys
+            })
+            .collect();
+
+        return Self {
+            table,
+            cache,
+            row_fields,
+            column_fields,
+            keys,
+        };
+    }
+
+    fn layout(&self) -> PivotLayout {
+        let records: Vec<usize> = (0..self.keys.len())
+            .filter(|r| self.is_visible(*r))
+            .collect();
+
+        let rows = self.axis_lines(&self.row_fields, &records, self.table.column_grand_totals);
+        let columns = self.axis_lines(&self.column_fields, &records, self.table.row_grand_totals);
+
+        let compact_rows = self.row_fields.iter().all(|r| match r {
+            PivotFieldReference::Field(f) => self
+                .table
+                .fields
+                .get(*f as usize)
+                .is_none_or(|f| f.compact && f.outline),
+            PivotFieldReference::Values => self.table.compact,
+        });
+        let label_columns = if compact_rows || self.row_fields.is_empty() {
+            1
+        } else {
+            self.row_fields.len()
+        };
+        let header_rows = if self.column_fields.is_empty() {
+            1
+        } else {
+            self.column_fields.len() + 1
+        };
+
+        let mut cells: Vec<Vec<PivotLayoutCell>> =
+            vec![
+                vec![PivotLayoutCell::empty(); label_columns + columns.len()];
+                header_rows + rows.len()
+            ];
+
+        // headers
+        if !self.column_fields.is_empty() {
+            if self.table.data_fields.len() == 1 {
+                cells[0][0] = PivotLayoutCell::header(self.data_field_name(0));
+            }
+            if compact_rows {
+                let caption = self
+                    .table
+                    .column_header_caption
+                    .clone()
+                    .unwrap_or("Column Labels".to_string());
+                cells[0][label_columns] = PivotLayoutCell::header(caption);
+            } else {
+                for (index, reference) in self.column_fields.iter().enumerate() {
+                    if let Some(cell) = cells[0].get_mut(label_columns + index) {
+                        *cell = PivotLayoutCell::header(self.field_name(reference));
+                    }
+                }
+            }
+        } else {
+            for (index, column) in columns.iter().enumerate() {
+                if let Some(data_field_index) = self.data_field_index(&column.line, None) {
+                    cells[0][label_columns + index] =
+                        PivotLayoutCell::header(self.data_field_name(data_field_index as usize));
+                }
+            }
+        }
+        if !self.row_fields.is_empty() {
+            let header_row = header_rows - 1;
+            if compact_rows {
+                let caption = self
+                    .table
+                    .row_header_caption
+                    .clone()
+                    .unwrap_or("Row Labels".to_string());
+                cells[header_row][0] = PivotLayoutCell::header(caption);
+            } else {
+                for (index, reference) in self.row_fields.iter().enumerate() {
+                    cells[header_row][index] = PivotLayoutCell::header(self.field_name(reference));
+                }
+            }
+        }
+
+        // column labels
+        for (index, column) in columns.iter().enumerate() {
+            for level in 0..self.column_fields.len() {
+                let Some(caption) = self.line_caption(&column.line, level) else {
+                    continue;
+                };
+                cells[1 + level][label_columns + index] = PivotLayoutCell {
+                    cell_type: PivotLayoutCellType::ColumnLabel,
+                    value: PivotCacheValue::String(caption),
+                    column: Some(index as u64),
+                    data_field_index: self.data_field_index(&column.line, None),
+                    ..PivotLayoutCell::empty()
+                };
+            }
+        }
+
+        // row labels and values
+        for (row_index, row) in rows.iter().enumerate() {
+            let cell_row = &mut cells[header_rows + row_index];
+            let levels: Vec<usize> = if compact_rows {
+                vec![row.line.items.len().saturating_sub(1)]
+            } else {
+                (0..self.row_fields.len()).collect()
+            };
+            for level in levels {
+                let Some(caption) = self.line_caption(&row.line, level) else {
+                    continue;
+                };
+                let column = if compact_rows { 0 } else { level };
+                cell_row[column] = PivotLayoutCell {
+                    cell_type: PivotLayoutCellType::RowLabel,
+                    value: PivotCacheValue::String(caption),
+                    row: Some(row_index as u64),
+                    data_field_index: self.data_field_index(&row.line, None),
+                    indent: if compact_rows && row.line.line_type != PivotItemType::Grand {
+                        level as u64
+                    } else {
+                        0
+                    },
+                    ..PivotLayoutCell::empty()
+                };
+            }
+
+            for (column_index, column) in columns.iter().enumerate() {
+                let data_field_index = self.data_field_index(&row.line, Some(&column.line));
+                cell_row[label_columns + column_index] = PivotLayoutCell {
+                    cell_type: PivotLayoutCellType::Value,
+                    value: self.value(row, column, data_field_index),
+                    row: Some(row_index as u64),
+                    column: Some(column_index as u64),
+                    data_field_index,
+                    indent: 0,
+                };
+            }
+        }
+
+        return PivotLayout {
+            rows: rows.into_iter().map(|r| r.line).collect(),
+            columns: columns.into_iter().map(|c| c.line).collect(),
+            filters: self.filters(),
+            cells,
+            first_data_row: header_rows as u64,
+            first_data_column: label_columns as u64,
+        };
+    }
+
+    /// Whether a record passes the page field selections and hidden items of the fields on the axes
+    fn is_visible(&self, record: usize) -> bool {
+        let keys = &self.keys[record];
+        for page_field in self.table.page_fields.iter() {
+            if let Some(selected) = page_field.selected_item {
+                if keys.get(page_field.field as usize).copied().flatten() != Some(selected) {
+                    return false;
+                }
+            }
+        }
+
+        for (index, key) in keys.iter().enumerate() {
+            let Some(field) = self.table.fields.get(index) else {
+                continue;
+            };
+            if !self.is_axis_field(index as u64) {
+                continue;
+            }
+            let Some(key) = key else {
+                return false;
+            };
+            if field.items.get(*key as usize).is_some_and(|i| i.hidden) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    fn is_axis_field(&self, field: u64) -> bool {
+        let reference = PivotFieldReference::Field(field);
+        return self.row_fields.contains(&reference)
+            || self.column_fields.contains(&reference)
+            || self.table.page_fields.iter().any(|p| p.field == field);
+    }
+
+    fn axis_lines(
+        &self,
+        references: &[PivotFieldReference],
+        records: &[usize],
+        grand_totals: bool,
+    ) -> Vec<AxisLine> {
+        let mut lines: Vec<AxisLine> = vec![];
+        if references.is_empty() {
+            lines.push(AxisLine {
+                line: PivotLayoutLine {
+                    line_type: PivotItemType::Data,
+                    items: vec![],
+                    repeated_count: 0,
+                    data_field_index: None,
+                    has_values: true,
+                },
+                records: records.to_vec(),
+            });
+            return lines;
+        }
+
+        self.push_axis_lines(references, 0, &mut vec![], records, &mut lines);
+
+        let has_field = references
+            .iter()
+            .any(|r| matches!(r, PivotFieldReference::Field(_)));
+        if grand_totals && has_field {
+            let data_field_indexes: Vec<Option<u64>> = if self.has_values_below(references, 0) {
+                (0..self.table.data_fields.len() as u64).map(Some).collect()
+            } else {
+                vec![None]
+            };
+            for data_field_index in data_field_indexes {
+                lines.push(AxisLine {
+                    line: PivotLayoutLine {
+                        line_type: PivotItemType::Grand,
+                        items: vec![],
+                        repeated_count: 0,
+                        data_field_index,
+                        has_values: true,
+                    },
+                    records: records.to_vec(),
+                });
+            }
+        }
+
+        // number of leading items shared with the previous line
+        for index in 1..lines.len() {
+            let (previous, current) = lines.split_at_mut(index);
+            let previous = &previous[index - 1].line;
+            let current = &mut current[0].line;
+            let shared = previous
+                .items
+                .iter()
+                .zip(current.items.iter())
+                .take_while(|(p, c)| p.field == c.field && p.index == c.index)
+                .count();
+            current.repeated_count = shared.min(current.items.len().saturating_sub(1)) as u64;
+        }
+
+        return lines;
+    }
+
+    fn push_axis_lines(
+        &self,
+        references: &[PivotFieldReference],
+        level: usize,
+        path: &mut Vec<PivotLayoutItem>,
+        records: &[usize],
+        lines: &mut Vec<AxisLine>,
+    ) {
+        let is_last = level + 1 == references.len();
+
+        let children: Vec<AxisChild> = match &references[level] {
+            PivotFieldReference::Values => (0..self.table.data_fields.len())
+                .map(|index| {
+                    let item = PivotLayoutItem {
+                        field: PivotFieldReference::Values,
+                        index: index as u64,
+                        caption: self.data_field_name(index),
+                    };
+                    (item, records.to_vec(), None)
+                })
+                .collect(),
+            PivotFieldReference::Field(f) => {
+                let Some(field) = self.table.fields.get(*f as usize) else {
+                    return;
+                };
+                let mut children = vec![];
+                for (index, item) in field.items.iter().enumerate() {
+                    if item.item_type != PivotItemType::Data || item.hidden {
+                        continue;
+                    }
+                    let item_records: Vec<usize> = records
+                        .iter()
+                        .filter(|r| self.keys[**r][*f as usize] == Some(index as u64))
+                        .copied()
+                        .collect();
+                    if item_records.is_empty() && !field.show_all {
+                        continue;
+                    }
+                    let layout_item = PivotLayoutItem {
+                        field: PivotFieldReference::Field(*f),
+                        index: index as u64,
+                        caption: item.caption().unwrap_or_default(),
+                    };
+                    children.push((layout_item, item_records, Some((field, item.show_details))));
+                }
+                children
+            }
+        };
+
+        for (item, item_records, field) in children {
+            path.push(item);
+            match field {
+                _ if is_last => {
+                    lines.push(self.axis_line(
+                        PivotItemType::Data,
+                        path,
+                        None,
+                        true,
+                        &item_records,
+                    ));
+                }
+                // collapsed item
+                Some((_, false)) => {
+                    lines.push(self.axis_line(
+                        PivotItemType::Data,
+                        path,
+                        None,
+                        true,
+                        &item_records,
+                    ));
+                }
+                Some((field, true)) => {
+                    let subtotals = subtotal_types(field);
+                    let is_tabular = !field.outline;
+                    let subtotal_top = !is_tabular
+                        && field.subtotal_top
+                        && subtotals == vec![PivotItemType::Default];
+                    if !is_tabular {
+                        lines.push(self.axis_line(
+                            PivotItemType::Data,
+                            path,
+                            None,
+                            subtotal_top,
+                            &item_records,
+                        ));
+                    }
+                    self.push_axis_lines(references, level + 1, path, &item_records, lines);
+                    if !subtotal_top {
+                        let data_field_indexes: Vec<Option<u64>> =
+                            if self.has_values_below(references, level + 1) {
+                                (0..self.table.data_fields.len() as u64).map(Some).collect()
+                            } else {
+                                vec![None]
+                            };
+                        for subtotal in subtotals {
+                            for data_field_index in data_field_indexes.iter() {
+                                lines.push(self.axis_line(
+                                    subtotal.clone(),
+                                    path,
+                                    *data_field_index,
+                                    true,
+                                    &item_records,
+                                ));
+                            }
+                        }
+                    }
+                }
+                // "Values" followed by other fields
+                None => {
+                    lines.push(self.axis_line(
+                        PivotItemType::Data,
+                        path,
+                        None,
+                        false,
+                        &item_records,
+                    ));
+                    self.push_axis_lines(references, level + 1, path, &item_records, lines);
+                }
+            }
+            path.pop();
+        }
+    }
+
+    fn axis_line(
+        &self,
+        line_type: PivotItemType,
+        path: &[PivotLayoutItem],
+        data_field_index: Option<u64>,
+        has_values: bool,
+        records: &[usize],
+    ) -> AxisLine {
+        let data_field_index = data_field_index.or(path
+            .iter()
+            .find(|i| i.field == PivotFieldReference::Values)
+            .map(|i| i.index));
+
+        return AxisLine {
+            line: PivotLayoutLine {
+                line_type,
+                items: path.to_vec(),
+                repeated_count: 0,
+                data_field_index,
+                has_values,
+            },
+            records: records.to_vec(),
+        };
+    }
+
+    /// Whether the axis has the "Values" field, with more than one data field, at or below the level
+    fn has_values_below(&self, references: &[PivotFieldReference], level: usize) -> bool {
+        return self.table.data_fields.len() > 1
+            && references
+                .iter()
+                .skip(level)
+                .any(|r| *r == PivotFieldReference::Values);
+    }
+
+    /// Data field of a row, or of a cell if `column` is given
+    fn data_field_index(
+        &self,
+        row: &PivotLayoutLine,
+        column: Option<&PivotLayoutLine>,
+    ) -> Option<u64> {
+        return row
+            .data_field_index
+            .or(column.and_then(|c| c.data_field_index))
+            .or(if self.table.data_fields.len() == 1 {
+                Some(0)
+            } else {
+                None
+            });
+    }
+
+    fn value(
+        &self,
+        row: &AxisLine,
+        column: &AxisLine,
+        data_field_index: Option<u64>,
+    ) -> PivotCacheValue {
+        if !row.line.has_values || !column.line.has_values {
+            return PivotCacheValue::Missing;
+        }
+        let Some(data_field) =
+            data_field_index.and_then(|i| self.table.data_fields.get(i as usize))
+        else {
+            return PivotCacheValue::Missing;
+        };
+
+        let function = subtotal_function(&row.line.line_type)
+            .or(subtotal_function(&column.line.line_type))
+            .unwrap_or(data_field.function.clone());
+
+        // records of both lines, both sorted
+        let mut values: Vec<&PivotCacheValue> = vec![];
+        let (mut r, mut c) = (0, 0);
+        while r < row.records.len() && c < column.records.len() {
+            if row.records[r] < column.records[c] {
+                r += 1;
+            } else if row.records[r] > column.records[c] {
+                c += 1;
+            } else {
+                let record = &self.cache.records[row.records[r]];
+                values.push(
+                    record
+                        .get(data_field.field as usize)
+                        .unwrap_or(&PivotCacheValue::Missing),
+                );
+                r += 1;
+                c += 1;
+            }
+        }
+        if values.is_empty() {
+            return PivotCacheValue::Missing;
+        }
+
+        return aggregate(&function, &values);
+    }
+
+    /// Caption of a line at a level of its axis
+    fn line_caption(&self, line: &PivotLayoutLine, level: usize) -> Option<String> {
+        return match line.line_type {
+            PivotItemType::Grand => {
+                if level != 0 {
+                    return None;
+                }
+                let caption = self
+                    .table
+                    .grand_total_caption
+                    .clone()
+                    .unwrap_or("Grand Total".to_string());
+                match line.data_field_index {
+                    Some(index) if self.table.data_fields.len() > 1 => {
+                        Some(format!("Total {}", self.data_field_name(index as usize)))
+                    }
+                    _ => Some(caption),
+                }
+            }
+            PivotItemType::Data => {
+                if level < line.repeated_count as usize {
+                    return None;
+                }
+                line.items.get(level).map(|i| i.caption.clone())
+            }
+            PivotItemType::Blank => None,
+            _ => {
+                let item = line.items.last()?;
+                if level + 1 != line.items.len() {
+                    return None;
+                }
+                let mut caption = match subtotal_function(&line.line_type) {
+                    Some(function) => format!("{} {}", item.caption, function.caption()),
+                    None => {
+                        let custom = match item.field {
+                            PivotFieldReference::Field(f) => self
+                                .table
+                                .fields
+                                .get(f as usize)
+                                .and_then(|f| f.subtotal_caption.clone()),
+                            PivotFieldReference::Values => None,
+                        };
+                        custom.unwrap_or(format!("{} Total", item.caption))
+                    }
+                };
+                // data field below the subtotalled field
+                let on_path = line
+                    .items
+                    .iter()
+                    .any(|i| i.field == PivotFieldReference::Values);
+                if let (Some(index), false) = (line.data_field_index, on_path) {
+                    caption = format!("{} {}", caption, self.data_field_name(index as usize));
+                }
+                Some(caption)
+            }
+        };
+    }
+
+    /// Name of a data field. Ex: `Sum of Sales`
+    fn data_field_name(&self, index: usize) -> String {
+        let Some(data_field) = self.table.data_fields.get(index) else {
+            return String::new();
+        };
+        if let Some(name) = &data_field.name {
+            return name.to_owned();
+        }
+        let field_name = self
+            .table
+            .fields
+            .get(data_field.field as usize)
+            .map(|f| f.name.clone())
+            .unwrap_or_default();
+        return format!("{} of {}", data_field.function.caption(), field_name);
+    }
+
+    fn field_name(&self, reference: &PivotFieldReference) -> String {
+        return match reference {
+            PivotFieldReference::Field(f) => self
+                .table
+                .fields
+                .get(*f as usize)
+                .map(|f| f.name.clone())
+                .unwrap_or_default(),
+            PivotFieldReference::Values => self.table.data_caption.clone(),
+        };
+    }
+
+    fn filters(&self) -> Vec<PivotLayoutFilter> {
+        return self
+            .table
+            .page_fields
+            .iter()
+            .map(|page_field| {
+                let field = self.table.fields.get(page_field.field as usize);
+                let name = page_field
+                    .name
+                    .clone()
+                    .or(field.map(|f| f.name.clone()))
+                    .unwrap_or_default();
+                let selected = page_field
+                    .selected_item
+                    .and_then(|i| field.and_then(|f| f.items.get(i as usize)))
+                    .and_then(|i| i.caption());
+                let has_hidden = field.is_some_and(|f| f.items.iter().any(|i| i.hidden));
+                let caption = match selected {
+                    Some(caption) => caption,
+                    None if has_hidden => "(Multiple Items)".to_string(),
+                    None => "(All)".to_string(),
+                };
+
+                PivotLayoutFilter {
+                    field: page_field.field,
+                    name,
+                    caption,
+                }
+            })
+            .collect();
+    }
+}
+
+/// Key of a cache value for lookups, distinguishing value types
+fn value_key(value: &PivotCacheValue) -> String {
+    return format!("{:?}", value);
+}
+
+/// Index of the shared item (or group item) of a field for a record
+fn cache_item_index(
+    cache: &PivotCache,
+    shared_item_indexes: &[HashMap<String, usize>],
+    field: usize,
+    record: &[PivotCacheValue],
+) -> Option<usize> {
+    let cache_field = cache.fields.get(field)?;
+    let Some(group) = cache_field.group.as_ref().filter(|g| !g.items.is_empty()) else {
+        let value = record.get(field)?;
+        return shared_item_indexes
+            .get(field)?
+            .get(&value_key(value))
+            .copied();
+    };
+
+    let base = group.base.map(|b| b as usize).unwrap_or(field);
+    let value = record.get(base)?;
+    if !group.discrete.is_empty() {
+        let base_index = shared_item_indexes.get(base)?.get(&value_key(value))?;
+        return group.discrete.get(*base_index).map(|i| *i as usize);
+    }
+
+    return range_group_index(group, value);
+}
+
+/// Index of the group item of a value in a range grouping.
+///
+/// Group items start with the `<start` item and end with the `>end` item.
+fn range_group_index(group: &PivotFieldGroup, value: &PivotCacheValue) -> Option<usize> {
+    let last = group.items.len().checked_sub(1)?;
+    let group_by = group.group_by.clone().unwrap_or(PivotGroupBy::Range);
+
+    let index = match value {
+        PivotCacheValue::Number(n) => {
+            let start = group.start_number?;
+            let end = group.end_number?;
+            if *n < start {
+                0
+            } else if *n > end {
+                last
+            } else {
+                1 + ((n - start) / group.interval.unwrap_or(1.0)).floor() as usize
+            }
+        }
+        PivotCacheValue::DateTime(d) => {
+            if group.start_date.as_ref().is_some_and(|s| d < s) {
+                return Some(0);
+            }
+            if group.end_date.as_ref().is_some_and(|e| d > e) {
+                return Some(last);
+            }
+            let date = NaiveDateTime::parse_from_str(d, "%Y-%m-%dT%H:%M:%S").ok()?;
+            match group_by {
+                PivotGroupBy::Range => {
+                    let start = group
+                        .start_date
+                        .as_ref()
+                        .and_then(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S").ok())?;
+                    let days = (date - start).num_days() as f64;
+                    1 + (days / group.interval.unwrap_or(1.0)).floor() as usize
+                }
+                PivotGroupBy::Seconds => 1 + date.second() as usize,
+                PivotGroupBy::Minutes => 1 + date.minute() as usize,
+                PivotGroupBy::Hours => 1 + date.hour() as usize,
+                // day items include February 29
+                PivotGroupBy::Days => {
+                    NaiveDate::from_ymd_opt(2000, date.month(), date.day())?.ordinal() as usize
+                }
+                PivotGroupBy::Months => date.month() as usize,
+                PivotGroupBy::Quarters => 1 + (date.month0() / 3) as usize,
+                PivotGroupBy::Years => {
+                    let year = date.year().to_string();
+                    return group.items.iter().position(|i| i.to_text() == year);
+                }
+            }
+        }
+        _ => return None,
+    };
+
+    return Some(index.min(last));
+}
+
+/// Subtotal lines of a field
+fn subtotal_types(field: &PivotField) -> Vec<PivotItemType> {
+    if !field.subtotals.is_empty() {
+        return field.subtotals.iter().map(subtotal_type).collect();
+    }
+    if field.default_subtotal {
+        return vec![PivotItemType::Default];
+    }
+    return vec![];
+}
+
+fn subtotal_type(function: &DataConsolidateFunction) -> PivotItemType {
+    return match function {
+        DataConsolidateFunction::Average => PivotItemType::Avg,
+        DataConsolidateFunction::Count => PivotItemType::CountA,
+        DataConsolidateFunction::CountNums => PivotItemType::Count,
+        DataConsolidateFunction::Max => PivotItemType::Max,
+        DataConsolidateFunction::Min => PivotItemType::Min,
+        DataConsolidateFunction::Product => PivotItemType::Product,
+        DataConsolidateFunction::StdDev => PivotItemType::StdDev,
+        DataConsolidateFunction::StdDevp => PivotItemType::StdDevP,
+        DataConsolidateFunction::Sum => PivotItemType::Sum,
+        DataConsolidateFunction::Var => PivotItemType::Var,
+        DataConsolidateFunction::Varp => PivotItemType::VarP,
+    };
+}
+
+/// Function of a custom subtotal line. None for other lines.
+fn subtotal_function(line_type: &PivotItemType) -> Option<DataConsolidateFunction> {
+    return match line_type {
+        PivotItemType::Avg => Some(DataConsolidateFunction::Average),
+        PivotItemType::CountA => Some(DataConsolidateFunction::Count),
+        PivotItemType::Count => Some(DataConsolidateFunction::CountNums),
+        PivotItemType::Max => Some(DataConsolidateFunction::Max),
+        PivotItemType::Min => Some(DataConsolidateFunction::Min),
+        PivotItemType::Product => Some(DataConsolidateFunction::Product),
+        PivotItemType::StdDev => Some(DataConsolidateFunction::StdDev),
+        PivotItemType::StdDevP => Some(DataConsolidateFunction::StdDevp),
+        PivotItemType::Sum => Some(DataConsolidateFunction::Sum),
+        PivotItemType::Var => Some(DataConsolidateFunction::Var),
+        PivotItemType::VarP => Some(DataConsolidateFunction::Varp),
+        _ => None,
+    };
+}
+
+/// Aggregate values of a data field.
+///
+/// Errors in the values are propagated, except for counts.
+fn aggregate(function: &DataConsolidateFunction, values: &[&PivotCacheValue]) -> PivotCacheValue {
+    let is_count = matches!(
+        function,
+        DataConsolidateFunction::Count | DataConsolidateFunction::CountNums
+    );
+    if !is_count {
+        if let Some(error) = values
+            .iter()
+            .find(|v| matches!(v, PivotCacheValue::Error(_)))
+        {
+            return (*error).clone();
+        }
+    }
+
+    let numbers: Vec<f64> = values.iter().filter_map(|v| v.as_number()).collect();
+    let count = numbers.len() as f64;
+    let sum: f64 = numbers.iter().sum();
+    let variance = |sample: bool| -> Option<f64> {
+        let divisor = if sample { count - 1.0 } else { count };
+        if divisor <= 0.0 {
+            return None;
+        }
+        let mean = sum / count;
+        return Some(numbers.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / divisor);
+    };
+
+    let result: Option<f64> = match function {
+        DataConsolidateFunction::Count => Some(
+            values
+                .iter()
+                .filter(|v| ***v != PivotCacheValue::Missing)
+                .count() as f64,
+        ),
+        DataConsolidateFunction::CountNums => Some(count),
+        DataConsolidateFunction::Sum => Some(sum),
+        DataConsolidateFunction::Average => {
+            if numbers.is_empty() {
+                None
+            } else {
+                Some(sum / count)
+            }
+        }
+        DataConsolidateFunction::Max => {
+            Some(numbers.iter().copied().reduce(f64::max).unwrap_or(0.0))
+        }
+        DataConsolidateFunction::Min => {
+            Some(numbers.iter().copied().reduce(f64::min).unwrap_or(0.0))
+        }
+        DataConsolidateFunction::Product => {
+            if numbers.is_empty() {
+                Some(0.0)
+            } else {
+                Some(numbers.iter().product())
+            }
+        }
+        DataConsolidateFunction::StdDev => variance(true).map(f64::sqrt),
+        DataConsolidateFunction::StdDevp => variance(false).map(f64::sqrt),
+        DataConsolidateFunction::Var => variance(true),
+        DataConsolidateFunction::Varp => variance(false),
+    };
+
+    return match result {
+        Some(n) => PivotCacheValue::Number(n),
+        None => PivotCacheValue::Error("#DIV/0!".to_string()),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processed::spreadsheet::{
+        pivot_cache::{cache_field::PivotCacheField, PivotCacheSource},
+        sheet::worksheet::pivot_table::{
+            data_field::{PivotDataField, ShowDataAs},
+            pivot_field::{PivotAxis, PivotItem, PivotSortType},
+            PivotTableLocation, PivotTableStyle,
+        },
+    };
+
+    fn text(s: &str) -> PivotCacheValue {
+        return PivotCacheValue::String(s.to_string());
+    }
+
+    fn cache_field(name: &str, shared_items: Vec<PivotCacheValue>) -> PivotCacheField {
+        return PivotCacheField {
+            name: name.to_string(),
+            caption: None,
+            is_database_field: true,
+            formula: None,
+            number_format_id: 0,
+            shared_items,
+            group: None,
+        };
+    }
+
+    fn item(item_type: PivotItemType, index: Option<u64>, value: Option<&str>) -> PivotItem {
+        return PivotItem {
+            item_type,
+            index,
+            value: value.map(text),
+            name: None,
+            hidden: false,
+            missing: false,
+            show_details: true,
+        };
+    }
+
+    fn pivot_field(name: &str, axis: Option<PivotAxis>, items: Vec<PivotItem>) -> PivotField {
+        return PivotField {
+            name: name.to_string(),
+            axis,
+            is_data_field: false,
+            items,
+            default_subtotal: true,
+            subtotals: vec![],
+            subtotal_caption: None,
+            subtotal_top: true,
+            show_all: false,
+            compact: true,
+            outline: true,
+            insert_blank_row: false,
+            sort_type: PivotSortType::Manual,
+            number_format_id: None,
+        };
+    }
+
+    /// Region and Product on rows (compact form), Sum of Sales as values,
+    /// with Sum and Max subtotals for each region.
+    fn region_sales() -> (PivotTable, PivotCache) {
+        let records = [
+            ("A", "x", 10.0),
+            ("A", "y", 5.0),
+            ("B", "x", 7.0),
+            ("A", "x", 3.0),
+        ];
+        let cache = PivotCache {
+            cache_id: 1,
+            source: PivotCacheSource::from_raw(None),
+            fields: vec![
+                cache_field("Region", vec![text("A"), text("B")]),
+                cache_field("Product", vec![text("x"), text("y")]),
+                cache_field("Sales", vec![]),
+            ],
+            records: records
+                .iter()
+                .map(|(r, p, s)| vec![text(r), text(p), PivotCacheValue::Number(*s)])
+                .collect(),
+            refreshed_by: None,
+            refreshed_date: None,
+            refresh_on_load: false,
+            invalid: false,
+            save_data: true,
+        };
+
+        let mut region = pivot_field(
+            "Region",
+            Some(PivotAxis::Row),
+            vec![
+                item(PivotItemType::Data, Some(0), Some("A")),
+                item(PivotItemType::Data, Some(1), Some("B")),
+                item(PivotItemType::Sum, None, None),
+                item(PivotItemType::Max, None, None),
+            ],
+        );
+        region.subtotals = vec![DataConsolidateFunction::Sum, DataConsolidateFunction::Max];
+        let product = pivot_field(
+            "Product",
+            Some(PivotAxis::Row),
+            vec![
+                item(PivotItemType::Data, Some(0), Some("x")),
+                item(PivotItemType::Data, Some(1), Some("y")),
+                item(PivotItemType::Default, None, None),
+            ],
+        );
+        let mut sales = pivot_field("Sales", None, vec![]);
+        sales.is_data_field = true;
+
+        let table = PivotTable {
+            name: "PivotTable1".to_string(),
+            cache_id: 1,
+            location: PivotTableLocation::from_raw(None),
+            fields: vec![region, product, sales],
+            row_fields: vec![PivotFieldReference::Field(0), PivotFieldReference::Field(1)],
+            column_fields: vec![],
+            page_fields: vec![],
+            data_fields: vec![PivotDataField {
+                name: None,
+                field: 2,
+                function: DataConsolidateFunction::Sum,
+                show_data_as: ShowDataAs::Normal,
+                base_field: None,
+                base_item: None,
+                number_format_id: None,
+            }],
+            row_items: vec![],
+            column_items: vec![],
+            style: PivotTableStyle::from_raw(None),
+            row_grand_totals: true,
+            column_grand_totals: true,
+            data_on_rows: false,
+            data_position: None,
+            data_caption: "Values".to_string(),
+            grand_total_caption: None,
+            row_header_caption: None,
+            column_header_caption: None,
+            error_caption: None,
+            show_error: false,
+            missing_caption: None,
+            show_missing: true,
+            compact: true,
+            outline: true,
+            indent: 1,
+            subtotal_hidden_items: false,
+        };
+
+        return (table, cache);
+    }
+
+    #[test]
+    fn layout_with_custom_subtotals() {
+        let (table, cache) = region_sales();
+        let layout = table.compute_layout(&cache);
+
+        let line_types: Vec<PivotItemType> =
+            layout.rows.iter().map(|r| r.line_type.clone()).collect();
+        assert_eq!(
+            line_types,
+            vec![
+                PivotItemType::Data,
+                PivotItemType::Data,
+                PivotItemType::Data,
+                PivotItemType::Sum,
+                PivotItemType::Max,
+                PivotItemType::Data,
+                PivotItemType::Data,
+                PivotItemType::Sum,
+                PivotItemType::Max,
+                PivotItemType::Grand,
+            ]
+        );
+        // custom subtotals are shown at the bottom of the group
+        assert!(!layout.rows[0].has_values);
+        assert_eq!(layout.rows[1].repeated_count, 1);
+
+        assert_eq!((layout.first_data_row, layout.first_data_column), (1, 1));
+        assert_eq!(layout.cells[0][0].value, text("Row Labels"));
+        assert_eq!(layout.cells[0][1].value, text("Sum of Sales"));
+
+        let rows: Vec<(String, u64, PivotCacheValue)> = layout.cells[1..]
+            .iter()
+            .map(|r| (r[0].value.to_text(), r[0].indent, r[1].value.clone()))
+            .collect();
+        let number = PivotCacheValue::Number;
+        assert_eq!(
+            rows,
+            vec![
+                ("A".to_string(), 0, PivotCacheValue::Missing),
+                ("x".to_string(), 1, number(13.0)),
+                ("y".to_string(), 1, number(5.0)),
+                ("A Sum".to_string(), 0, number(18.0)),
+                ("A Max".to_string(), 0, number(10.0)),
+                ("B".to_string(), 0, PivotCacheValue::Missing),
+                ("x".to_string(), 1, number(7.0)),
+                ("B Sum".to_string(), 0, number(7.0)),
+                ("B Max".to_string(), 0, number(7.0)),
+                ("Grand Total".to_string(), 0, number(25.0)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,268 @@
+pub mod axis_item;
+pub mod data_field;
+pub mod layout;
+pub mod pivot_field;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use axis_item::{PivotAxisItem, PivotFieldReference, PivotPageField};
+use data_field::PivotDataField;
+use pivot_field::PivotField;
+
+use crate::{
+    common_types::Dimension,
+    processed::spreadsheet::pivot_cache::cache_field::PivotCacheField,
+    raw::spreadsheet::pivot_table::{
+        location::XlsxLocation, pivot_table_style_info::XlsxPivotTableStyleInfo,
+        XlsxPivotTableDefinition,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivottabledefinition?view=openxml-3.0.1
+///
+/// A pivot table parsed from xl/pivotTables/pivotTable{}.xml.
+///
+/// Field names and item values are resolved with the pivot cache of the table.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotTable {
+    pub name: String,
+
+    /// Id of the pivot cache, see `Excel::get_pivot_cache`
+    pub cache_id: u64,
+
+    pub location: PivotTableLocation,
+
+    /// Fields in the order of the cache fields
+    pub fields: Vec<PivotField>,
+
+    pub row_fields: Vec<PivotFieldReference>,
+
+    pub column_fields: Vec<PivotFieldReference>,
+
+    /// Fields in the filter area
+    pub page_fields: Vec<PivotPageField>,
+
+    /// Fields in the values area
+    pub data_fields: Vec<PivotDataField>,
+
+    /// Rows as last rendered
+    pub row_items: Vec<PivotAxisItem>,
+
+    /// Columns as last rendered
+    pub column_items: Vec<PivotAxisItem>,
+
+    pub style: PivotTableStyle,
+
+    /// Whether grand totals are shown for rows (as a column at the right)
+    pub row_grand_totals: bool,
+
+    /// Whether grand totals are shown for columns (as a row at the bottom)
+    pub column_grand_totals: bool,
+
+    /// Whether the "Values" field is on the row axis
+    pub data_on_rows: bool,
+
+    /// Position of the "Values" field on its axis
+    pub data_position: Option<u64>,
+
+    /// Caption of the "Values" field
+    pub data_caption: String,
+
+    pub grand_total_caption: Option<String>,
+
+    pub row_header_caption: Option<String>,
+
+    pub column_header_caption: Option<String>,
+
+    /// Caption shown for errors, if `show_error` is true
+    pub error_caption: Option<String>,
+
+    pub show_error: bool,
+
+    /// Caption shown for empty values, if `show_missing` is true
+    pub missing_caption: Option<String>,
+
+    pub show_missing: bool,
+
+    /// Whether new fields use the compact form
+    pub compact: bool,
+
+    /// Whether new fields use the outline form
+    pub outline: bool,
+
+    /// Indentation (in characters) of the row items of compact fields
+    pub indent: u64,
+
+    /// Whether hidden items are included in subtotals
+    pub subtotal_hidden_items: bool,
+}
+
+impl PivotTable {
+    pub(crate) fn from_raw(
+        raw: XlsxPivotTableDefinition,
+        cache_fields: &[PivotCacheField],
+    ) -> Self {
+        let fields: Vec<PivotField> = raw
+            .pivot_fields
+            .unwrap_or(vec![])
+            .into_iter()
+            .enumerate()
+            .map(|(index, f)| PivotField::from_raw(f, cache_fields.get(index)))
+            .collect();
+
+        return Self {
+            name: raw.name.unwrap_or_default(),
+            cache_id: raw.cache_id.unwrap_or(0),
+            location: PivotTableLocation::from_raw(raw.location),
+            fields,
+            row_fields: raw
+                .row_fields
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(PivotFieldReference::from_raw)
+                .collect(),
+            column_fields: raw
+                .col_fields
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(PivotFieldReference::from_raw)
+                .collect(),
+            page_fields: raw
+                .page_fields
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(PivotPageField::from_raw)
+                .collect(),
+            data_fields: raw
+                .data_fields
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(PivotDataField::from_raw)
+                .collect(),
+            row_items: raw
+                .row_items
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(PivotAxisItem::from_raw)
+                .collect(),
+            column_items: raw
+                .col_items
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(PivotAxisItem::from_raw)
+                .collect(),
+            style: PivotTableStyle::from_raw(raw.pivot_table_style_info),
+            row_grand_totals: raw.row_grand_totals.unwrap_or(true),
+            column_grand_totals: raw.col_grand_totals.unwrap_or(true),
+            data_on_rows: raw.data_on_rows.unwrap_or(false),
+            data_position: raw.data_position,
+            data_caption: raw.data_caption.unwrap_or("Values".to_string()),
+            grand_total_caption: raw.grand_total_caption,
+            row_header_caption: raw.row_header_caption,
+            column_header_caption: raw.col_header_caption,
+            error_caption: raw.error_caption,
+            show_error: raw.show_error.unwrap_or(false),
+            missing_caption: raw.missing_caption,
+            show_missing: raw.show_missing.unwrap_or(true),
+            compact: raw.compact.unwrap_or(true),
+            outline: raw.outline.unwrap_or(false),
+            indent: raw.indent.unwrap_or(1),
+            subtotal_hidden_items: raw.subtotal_hidden_items.unwrap_or(false),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.location?view=openxml-3.0.1
+///
+/// Location of the pivot table on the sheet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotTableLocation {
+    /// Range of the pivot table, excluding the filter area
+    pub dimension: Dimension,
+
+    /// Zero-based row offset of the column header row
+    pub first_header_row: u64,
+
+    /// Zero-based row offset of the first data row
+    pub first_data_row: u64,
+
+    /// Zero-based column offset of the first data column
+    pub first_data_column: u64,
+
+    /// Number of rows of the filter area per column
+    pub row_page_count: u64,
+
+    /// Number of columns of the filter area
+    pub column_page_count: u64,
+}
+
+impl PivotTableLocation {
+    pub(crate) fn from_raw(raw: Option<XlsxLocation>) -> Self {
+        let Some(raw) = raw else {
+            return Self {
+                dimension: Dimension::default(),
+                first_header_row: 0,
+                first_data_row: 0,
+                first_data_column: 0,
+                row_page_count: 0,
+                column_page_count: 0,
+            };
+        };
+
+        return Self {
+            dimension: raw.r#ref.unwrap_or(Dimension::default()),
+            first_header_row: raw.first_header_row.unwrap_or(0),
+            first_data_row: raw.first_data_row.unwrap_or(0),
+            first_data_column: raw.first_data_col.unwrap_or(0),
+            row_page_count: raw.row_page_count.unwrap_or(0),
+            column_page_count: raw.col_page_count.unwrap_or(0),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivottablestyle?view=openxml-3.0.1
+///
+/// Style applied to the pivot table.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotTableStyle {
+    /// Style name. Ex: `PivotStyleLight16`
+    pub name: Option<String>,
+
+    pub show_row_headers: bool,
+
+    pub show_column_headers: bool,
+
+    pub show_row_stripes: bool,
+
+    pub show_column_stripes: bool,
+
+    pub show_last_column: bool,
+}
+
+impl PivotTableStyle {
+    pub(crate) fn from_raw(raw: Option<XlsxPivotTableStyleInfo>) -> Self {
+        let Some(raw) = raw else {
+            return Self {
+                name: None,
+                show_row_headers: false,
+                show_column_headers: false,
+                show_row_stripes: false,
+                show_column_stripes: false,
+                show_last_column: false,
+            };
+        };
+
+        return Self {
+            name: raw.name,
+            show_row_headers: raw.show_row_headers.unwrap_or(false),
+            show_column_headers: raw.show_col_headers.unwrap_or(false),
+            show_row_stripes: raw.show_row_stripes.unwrap_or(false),
+            show_column_stripes: raw.show_col_stripes.unwrap_or(false),
+            show_last_column: raw.show_last_column.unwrap_or(false),
+        };
+    }
+}
@@ -0,0 +1,285 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::spreadsheet::pivot_cache::{
+        cache_field::PivotCacheField, cache_value::PivotCacheValue,
+    },
+    raw::spreadsheet::pivot_table::pivot_field::{XlsxPivotField, XlsxPivotItem},
+};
+
+use super::data_field::DataConsolidateFunction;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotfield?view=openxml-3.0.1
+///
+/// A field of the pivot table, based on the cache field with the same index.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotField {
+    /// Custom name of the field, or the cache field name
+    pub name: String,
+
+    /// None if the field is not on the row, column or page axis
+    pub axis: Option<PivotAxis>,
+
+    /// Whether the field is in the values area
+    pub is_data_field: bool,
+
+    /// Items in display order, including subtotal items
+    pub items: Vec<PivotItem>,
+
+    /// Whether the default subtotal (the aggregation of the data field) is shown
+    pub default_subtotal: bool,
+
+    /// Custom subtotals
+    pub subtotals: Vec<DataConsolidateFunction>,
+
+    pub subtotal_caption: Option<String>,
+
+    /// Whether subtotals are shown at the top of each group (outline and compact forms)
+    pub subtotal_top: bool,
+
+    /// Whether items without data are shown
+    pub show_all: bool,
+
+    pub compact: bool,
+
+    pub outline: bool,
+
+    pub insert_blank_row: bool,
+
+    pub sort_type: PivotSortType,
+
+    pub number_format_id: Option<u64>,
+}
+
+impl PivotField {
+    pub(crate) fn from_raw(raw: XlsxPivotField, cache_field: Option<&PivotCacheField>) -> Self {
+        let mut subtotals: Vec<DataConsolidateFunction> = vec![];
+        let flags = [
+            (raw.sum_subtotal, DataConsolidateFunction::Sum),
+            (raw.count_a_subtotal, DataConsolidateFunction::Count),
+            (raw.avg_subtotal, DataConsolidateFunction::Average),
+            (raw.max_subtotal, DataConsolidateFunction::Max),
+            (raw.min_subtotal, DataConsolidateFunction::Min),
+            (raw.product_subtotal, DataConsolidateFunction::Product),
+            (raw.count_subtotal, DataConsolidateFunction::CountNums),
+            (raw.std_dev_subtotal, DataConsolidateFunction::StdDev),
+            (raw.std_dev_p_subtotal, DataConsolidateFunction::StdDevp),
+            (raw.var_subtotal, DataConsolidateFunction::Var),
+            (raw.var_p_subtotal, DataConsolidateFunction::Varp),
+        ];
+        for (flag, function) in flags {
+            if flag == Some(true) {
+                subtotals.push(function);
+            }
+        }
+
+        let name = raw
+            .name
+            .clone()
+            .or(cache_field.map(|f| f.name.clone()))
+            .unwrap_or_default();
+
+        return Self {
+            name,
+            axis: raw.axis.map(|a| PivotAxis::from_string(Some(a))),
+            is_data_field: raw.data_field.unwrap_or(false),
+            items: raw
+                .items
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|i| PivotItem::from_raw(i, cache_field))
+                .collect(),
+            default_subtotal: raw.default_subtotal.unwrap_or(true),
+            subtotals,
+            subtotal_caption: raw.subtotal_caption,
+            subtotal_top: raw.subtotal_top.unwrap_or(true),
+            show_all: raw.show_all.unwrap_or(true),
+            compact: raw.compact.unwrap_or(true),
+            outline: raw.outline.unwrap_or(true),
+            insert_blank_row: raw.insert_blank_row.unwrap_or(false),
+            sort_type: PivotSortType::from_string(raw.sort_type),
+            number_format_id: raw.num_fmt_id,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.item?view=openxml-3.0.1
+///
+/// An item of a pivot field.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PivotItem {
+    pub item_type: PivotItemType,
+
+    /// Index of the shared item (or group item) in the cache field. None for subtotal items.
+    pub index: Option<u64>,
+
+    /// Value of the item in the cache
+    pub value: Option<PivotCacheValue>,
+
+    /// Custom caption
+    pub name: Option<String>,
+
+    /// Whether the item is hidden (filtered out)
+    pub hidden: bool,
+
+    /// Whether the item no longer exists in the source data
+    pub missing: bool,
+
+    /// Whether the details of the item are shown (expanded)
+    pub show_details: bool,
+}
+
+impl PivotItem {
+    pub(crate) fn from_raw(raw: XlsxPivotItem, cache_field: Option<&PivotCacheField>) -> Self {
+        let value = match (raw.x, cache_field) {
+            (Some(x), Some(field)) => {
+                // grouped fields list group items instead of shared items
+                let items = match &field.group {
+                    Some(group) if !group.items.is_empty() => &group.items,
+                    _ => &field.shared_items,
+                };
+                items.get(x as usize).cloned()
+            }
+            _ => None,
+        };
+
+        return Self {
+            item_type: PivotItemType::from_string(raw.t),
+            index: raw.x,
+            value,
+            name: raw.n,
+            hidden: raw.h.unwrap_or(false),
+            missing: raw.m.unwrap_or(false),
+            show_details: raw.sd.unwrap_or(true),
+        };
+    }
+
+    /// Caption of the item: the custom name or the cache value.
+    pub fn caption(&self) -> Option<String> {
+        return self
+            .name
+            .clone()
+            .or(self.value.as_ref().map(|v| v.to_text()));
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.itemvalues?view=openxml-3.0.1
+///
+/// * Data: an item of the field
+/// * Default, Sum, CountA, ...: subtotal items
+/// * Grand: grand total
+/// * Blank: blank line
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotItemType {
+    Data,
+    Default,
+    Sum,
+    CountA,
+    Avg,
+    Max,
+    Min,
+    Product,
+    Count,
+    StdDev,
+    StdDevP,
+    Var,
+    VarP,
+    Grand,
+    Blank,
+}
+
+impl PivotItemType {
+    pub(crate) fn default() -> Self {
+        Self::Data
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "data" => Self::Data,
+            "default" => Self::Default,
+            "sum" => Self::Sum,
+            "countA" => Self::CountA,
+            "avg" => Self::Avg,
+            "max" => Self::Max,
+            "min" => Self::Min,
+            "product" => Self::Product,
+            "count" => Self::Count,
+            "stdDev" => Self::StdDev,
+            "stdDevP" => Self::StdDevP,
+            "var" => Self::Var,
+            "varP" => Self::VarP,
+            "grand" => Self::Grand,
+            "blank" => Self::Blank,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivottableaxisvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotAxis {
+    Row,
+    Column,
+    Page,
+    Values,
+}
+
+impl PivotAxis {
+    pub(crate) fn default() -> Self {
+        Self::Row
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "axisRow" => Self::Row,
+            "axisCol" => Self::Column,
+            "axisPage" => Self::Page,
+            "axisValues" => Self::Values,
+            _ => Self::default(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.fieldsortvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PivotSortType {
+    Manual,
+    Ascending,
+    Descending,
+}
+
+impl PivotSortType {
+    pub(crate) fn default() -> Self {
+        Self::Manual
+    }
+
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::default();
+        };
+
+        return match s.as_ref() {
+            "manual" => Self::Manual,
+            "ascending" => Self::Ascending,
+            "descending" => Self::Descending,
+            _ => Self::default(),
+        };
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    raw::spreadsheet::sheet::worksheet::scenario::{XlsxInputCells, XlsxScenario, XlsxScenarios},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.scenarios?view=openxml-3.0.1
+///
+/// What-if scenarios of a worksheet (Scenario Manager).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Scenarios {
+    pub scenarios: Vec<Scenario>,
+
+    /// 0-based index of the current scenario
+    pub current: Option<u64>,
+
+    /// 0-based index of the last shown scenario
+    pub shown: Option<u64>,
+
+    /// Result cells of the scenario summary
+    pub result_cells: Vec<Dimension>,
+}
+
+impl Scenarios {
+    pub(crate) fn from_raw(raw: XlsxScenarios) -> Self {
+        return Self {
+            scenarios: raw
+                .scenarios
+                .into_iter()
+                .map(|s| Scenario::from_raw(s))
+                .collect(),
+            current: raw.current,
+            shown: raw.show,
+            result_cells: raw
+                .sqref
+                .map(|s| Dimension::from_sqref(&s))
+                .unwrap_or(vec![]),
+        };
+    }
+
+    /// Scenario with the name
+    pub fn get_scenario(&self, name: &str) -> Option<Scenario> {
+        return self.scenarios.iter().find(|s| s.name == name).cloned();
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.scenario?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Scenario {
+    pub name: String,
+
+    pub comment: Option<String>,
+
+    /// User who last modified the scenario
+    pub user: Option<String>,
+
+    /// Whether the scenario can be edited when the sheet is protected
+    pub locked: bool,
+
+    /// Whether the scenario is hidden when the sheet is protected
+    pub hidden: bool,
+
+    /// Cells changed by the scenario with their values
+    pub input_cells: Vec<ScenarioInputCell>,
+}
+
+impl Scenario {
+    pub(crate) fn from_raw(raw: XlsxScenario) -> Self {
+        return Self {
+            name: raw.name.unwrap_or("".to_string()),
+            comment: raw.comment,
+            user: raw.user,
+            locked: raw.locked.unwrap_or(false),
+            hidden: raw.hidden.unwrap_or(false),
+            input_cells: raw
+                .input_cells
+                .into_iter()
+                .filter_map(|c| ScenarioInputCell::from_raw(c))
+                .collect(),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.inputcells?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ScenarioInputCell {
+    pub coordinate: Coordinate,
+
+    /// Value of the cell in the scenario
+    pub value: String,
+
+    /// Id of the number format applied to the value
+    pub number_format_id: Option<u64>,
+
+    /// Whether the cell was deleted from the sheet
+    pub deleted: bool,
+
+    /// Whether the change of the cell was undone
+    pub undone: bool,
+}
+
+impl ScenarioInputCell {
+    pub(crate) fn from_raw(raw: XlsxInputCells) -> Option<Self> {
+        return Some(Self {
+            coordinate: raw.reference?,
+            value: raw.value.unwrap_or("".to_string()),
+            number_format_id: raw.number_format_id,
+            deleted: raw.deleted.unwrap_or(false),
+            undone: raw.undone.unwrap_or(false),
+        });
+    }
+}
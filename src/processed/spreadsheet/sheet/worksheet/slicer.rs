@@ -0,0 +1,265 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::spreadsheet::pivot_cache::{cache_field::PivotCacheField, cache_value::PivotCacheValue},
+    raw::spreadsheet::slicer::{
+        slicer_cache::{
+            XlsxSlicerCacheDefinition, XlsxSlicerCachePivotTable, XlsxTabularSlicerCacheItem,
+        },
+        XlsxSlicer,
+    },
+};
+
+/// - Slicer: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicer?view=openxml-3.0.1
+/// - SlicerCacheDefinition: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicercachedefinition?view=openxml-3.0.1
+///
+/// A slicer of the worksheet parsed from xl/slicers/slicer{}.xml, joined with its cache parsed from xl/slicerCaches/slicerCache{}.xml.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Slicer {
+    /// Name referenced by the graphic frame of the slicer in the drawing (`GraphicFrame.slicer_name`)
+    pub name: String,
+
+    /// Caption displayed in the header of the slicer
+    pub caption: Option<String>,
+
+    /// Name of the slicer cache, shared by slicers filtering the same field
+    pub cache_name: String,
+
+    /// Name of the field filtered
+    pub source_name: Option<String>,
+
+    /// Pivot tables or table filtered by the slicer
+    pub source: SlicerSource,
+
+    /// Items of the field with their selection state.
+    ///
+    /// Empty for table slicers: their selection is the filter of the table column (`autoFilter`).
+    pub items: Vec<SlicerItem>,
+
+    pub sort_order: SlicerSortOrder,
+
+    /// How items without data are displayed
+    pub cross_filter: SlicerCrossFilter,
+
+    /// Ex: `SlicerStyleLight1`
+    pub style: Option<String>,
+
+    pub column_count: u64,
+
+    /// Height of each item in EMU
+    pub row_height: u64,
+
+    /// Whether the header of the slicer is displayed
+    pub show_caption: bool,
+
+    /// Whether the slicer can be moved or resized
+    pub locked_position: bool,
+
+    /// Index of the first item displayed
+    pub start_item: u64,
+}
+
+impl Slicer {
+    /// * cache_field: field of the pivot cache filtered by the slicer, used to resolve the items.
+    pub(crate) fn from_raw(
+        raw: XlsxSlicer,
+        cache: Option<XlsxSlicerCacheDefinition>,
+        cache_field: Option<&PivotCacheField>,
+    ) -> Self {
+        let tabular = cache
+            .as_ref()
+            .and_then(|c| c.data.clone())
+            .and_then(|d| d.tabular);
+        let table_cache = cache.as_ref().and_then(|c| c.table_slicer_cache.clone());
+
+        let (sort_order, cross_filter) = match (&tabular, &table_cache) {
+            (Some(tabular), _) => (tabular.sort_order.clone(), tabular.cross_filter.clone()),
+            (None, Some(table_cache)) => (
+                table_cache.sort_order.clone(),
+                table_cache.cross_filter.clone(),
+            ),
+            (None, None) => (None, None),
+        };
+
+        let items: Vec<SlicerItem> = tabular
+            .map(|t| t.items)
+            .unwrap_or(vec![])
+            .into_iter()
+            .map(|i| SlicerItem::from_raw(i, cache_field))
+            .collect();
+
+        return Self {
+            name: raw.name.unwrap_or_default(),
+            caption: raw.caption,
+            cache_name: raw.cache.unwrap_or_default(),
+            source_name: cache.as_ref().and_then(|c| c.source_name.clone()),
+            source: SlicerSource::from_raw(cache),
+            items,
+            sort_order: SlicerSortOrder::from_string(sort_order),
+            cross_filter: SlicerCrossFilter::from_string(cross_filter),
+            style: raw.style,
+            column_count: raw.column_count.unwrap_or(1),
+            row_height: raw.row_height.unwrap_or(0),
+            show_caption: raw.show_caption.unwrap_or(true),
+            locked_position: raw.locked_position.unwrap_or(false),
+            start_item: raw.start_item.unwrap_or(0),
+        };
+    }
+
+    /// Items selected. All items are selected when the slicer is not filtering.
+    pub fn selected_items(&self) -> Vec<SlicerItem> {
+        return self.items.iter().filter(|i| i.selected).cloned().collect();
+    }
+}
+
+/// Source filtered by a slicer
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SlicerSource {
+    /// Pivot tables sharing the pivot cache with `cacheId` of `pivot_cache_id`
+    PivotTables {
+        pivot_cache_id: Option<u64>,
+        pivot_tables: Vec<SlicerPivotTable>,
+    },
+
+    /// Column of a table
+    Table {
+        /// `Table.table_id`
+        table_id: Option<u64>,
+        /// id of the table column
+        column: Option<u64>,
+    },
+}
+
+impl SlicerSource {
+    pub(crate) fn from_raw(cache: Option<XlsxSlicerCacheDefinition>) -> Self {
+        let Some(cache) = cache else {
+            return Self::PivotTables {
+                pivot_cache_id: None,
+                pivot_tables: vec![],
+            };
+        };
+
+        if let Some(table_cache) = cache.table_slicer_cache {
+            return Self::Table {
+                table_id: table_cache.table_id,
+                column: table_cache.column,
+            };
+        }
+
+        let pivot_cache_id = cache
+            .data
+            .and_then(|d| d.tabular.and_then(|t| t.pivot_cache_id).or(d.olap_pivot_cache_id));
+
+        return Self::PivotTables {
+            pivot_cache_id,
+            pivot_tables: cache
+                .pivot_tables
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(SlicerPivotTable::from_raw)
+                .collect(),
+        };
+    }
+}
+
+/// A pivot table filtered by a slicer or a timeline
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SlicerPivotTable {
+    /// Sheet id of the worksheet containing the pivot table
+    pub sheet_id: Option<u64>,
+
+    /// `PivotTable.name`
+    pub name: Option<String>,
+}
+
+impl SlicerPivotTable {
+    pub(crate) fn from_raw(raw: XlsxSlicerCachePivotTable) -> Self {
+        return Self {
+            sheet_id: raw.tab_id,
+            name: raw.name,
+        };
+    }
+}
+
+/// An item of a pivot table slicer
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SlicerItem {
+    /// Index of the shared item in the pivot cache field
+    pub index: u64,
+
+    /// Value of the shared item.
+    ///
+    /// None if the pivot cache field is not available.
+    pub value: Option<PivotCacheValue>,
+
+    pub selected: bool,
+
+    /// Whether the item does not have data, after the filters of the other slicers
+    pub no_data: bool,
+}
+
+impl SlicerItem {
+    pub(crate) fn from_raw(
+        raw: XlsxTabularSlicerCacheItem,
+        cache_field: Option<&PivotCacheField>,
+    ) -> Self {
+        let index = raw.index.unwrap_or(0);
+        return Self {
+            index,
+            value: cache_field.and_then(|f| f.shared_items.get(index as usize).cloned()),
+            selected: raw.selected.unwrap_or(false),
+            no_data: raw.no_data.unwrap_or(false),
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.tabularslicercachesortordervalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SlicerSortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SlicerSortOrder {
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else { return Self::Ascending };
+        return match s.as_ref() {
+            "descending" => Self::Descending,
+            _ => Self::Ascending,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicercachecrossfiltervalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SlicerCrossFilter {
+    /// Items without data are not marked
+    None,
+    /// Items without data are marked and displayed after the items with data
+    ShowItemsWithDataAtTop,
+    /// Items without data are marked and displayed in place
+    ShowItemsWithNoData,
+}
+
+impl SlicerCrossFilter {
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else {
+            return Self::ShowItemsWithDataAtTop;
+        };
+        return match s.as_ref() {
+            "none" => Self::None,
+            "showItemsWithNoData" => Self::ShowItemsWithNoData,
+            _ => Self::ShowItemsWithDataAtTop,
+        };
+    }
+}
@@ -0,0 +1,265 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, HexColor},
+    raw::{
+        drawing::scheme::color_scheme::XlsxColorScheme,
+        spreadsheet::{
+            sheet::worksheet::sparkline::{XlsxSparkline, XlsxSparklineGroup},
+            stylesheet::color::{stylesheet_colors::XlsxStyleSheetColors, XlsxColor},
+        },
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.sparklinegroup?view=openxml-3.0.1
+///
+/// A group of sparklines sharing the same type and display settings.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SparklineGroup {
+    pub sparkline_type: SparklineType,
+
+    pub sparklines: Vec<Sparkline>,
+
+    pub colors: SparklineColors,
+
+    /// Whether markers are displayed on each point of line sparklines
+    pub show_markers: bool,
+
+    /// Whether the high point is highlighted with `colors.high`
+    pub show_high: bool,
+
+    /// Whether the low point is highlighted with `colors.low`
+    pub show_low: bool,
+
+    /// Whether the first point is highlighted with `colors.first`
+    pub show_first: bool,
+
+    /// Whether the last point is highlighted with `colors.last`
+    pub show_last: bool,
+
+    /// Whether negative points are highlighted with `colors.negative`
+    pub show_negative: bool,
+
+    /// Line weight in point
+    pub line_weight: f64,
+
+    /// How empty cells are displayed
+    pub empty_cells_as: SparklineEmptyCellsType,
+
+    /// Whether the data in hidden rows and columns are displayed
+    pub display_hidden: bool,
+
+    pub axis: SparklineAxis,
+}
+
+impl SparklineGroup {
+    pub(crate) fn from_raw(
+        raw: XlsxSparklineGroup,
+        stylesheet_colors: Option<XlsxStyleSheetColors>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Self {
+        let colors = SparklineColors::from_raw(&raw, stylesheet_colors, color_scheme);
+
+        return Self {
+            sparkline_type: SparklineType::from_string(raw.r#type.clone()),
+            sparklines: raw
+                .sparklines
+                .clone()
+                .into_iter()
+                .map(Sparkline::from_raw)
+                .collect(),
+            colors,
+            show_markers: raw.markers.unwrap_or(false),
+            show_high: raw.high.unwrap_or(false),
+            show_low: raw.low.unwrap_or(false),
+            show_first: raw.first.unwrap_or(false),
+            show_last: raw.last.unwrap_or(false),
+            show_negative: raw.negative.unwrap_or(false),
+            line_weight: raw.line_weight.unwrap_or(0.75),
+            empty_cells_as: SparklineEmptyCellsType::from_string(
+                raw.display_empty_cells_as.clone(),
+            ),
+            display_hidden: raw.display_hidden.unwrap_or(false),
+            axis: SparklineAxis::from_raw(raw),
+        };
+    }
+}
+
+/// A single sparkline of a group
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Sparkline {
+    /// Range of the data. Ex: `Sheet1!A2:E2`
+    pub data_range: Option<String>,
+
+    /// Cell the sparkline is displayed in
+    pub location: Option<Coordinate>,
+}
+
+impl Sparkline {
+    pub(crate) fn from_raw(raw: XlsxSparkline) -> Self {
+        return Self {
+            data_range: raw.formula,
+            location: raw
+                .sqref
+                .and_then(|s| Coordinate::from_a1(s.replace('$', "").trim().as_bytes())),
+        };
+    }
+}
+
+/// Colors of the series and the highlighted points of a sparkline group
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SparklineColors {
+    pub series: Option<HexColor>,
+    pub negative: Option<HexColor>,
+    pub axis: Option<HexColor>,
+    pub markers: Option<HexColor>,
+    pub first: Option<HexColor>,
+    pub last: Option<HexColor>,
+    pub high: Option<HexColor>,
+    pub low: Option<HexColor>,
+}
+
+impl SparklineColors {
+    pub(crate) fn from_raw(
+        raw: &XlsxSparklineGroup,
+        stylesheet_colors: Option<XlsxStyleSheetColors>,
+        color_scheme: Option<XlsxColorScheme>,
+    ) -> Self {
+        let to_hex = |color: &Option<XlsxColor>| -> Option<HexColor> {
+            return color
+                .as_ref()
+                .and_then(|c| c.to_hex(stylesheet_colors.clone(), color_scheme.clone()));
+        };
+
+        return Self {
+            series: to_hex(&raw.color_series),
+            negative: to_hex(&raw.color_negative),
+            axis: to_hex(&raw.color_axis),
+            markers: to_hex(&raw.color_markers),
+            first: to_hex(&raw.color_first),
+            last: to_hex(&raw.color_last),
+            high: to_hex(&raw.color_high),
+            low: to_hex(&raw.color_low),
+        };
+    }
+}
+
+/// Axis settings of a sparkline group
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SparklineAxis {
+    /// Whether the horizontal axis is displayed
+    pub display_x_axis: bool,
+
+    /// Range of the dates used as the horizontal axis. Ex: `Sheet1!A1:E1`
+    ///
+    /// None if the points are evenly spaced.
+    pub date_range: Option<String>,
+
+    /// Whether the data is plotted right to left
+    pub right_to_left: bool,
+
+    pub min_type: SparklineAxisMinMaxType,
+
+    pub max_type: SparklineAxisMinMaxType,
+
+    /// Minimum of the vertical axis when `min_type` is custom
+    pub manual_min: Option<f64>,
+
+    /// Maximum of the vertical axis when `max_type` is custom
+    pub manual_max: Option<f64>,
+}
+
+impl SparklineAxis {
+    pub(crate) fn from_raw(raw: XlsxSparklineGroup) -> Self {
+        let date_range = if raw.date_axis == Some(true) {
+            raw.date_axis_range
+        } else {
+            None
+        };
+
+        return Self {
+            display_x_axis: raw.display_x_axis.unwrap_or(false),
+            date_range,
+            right_to_left: raw.right_to_left.unwrap_or(false),
+            min_type: SparklineAxisMinMaxType::from_string(raw.min_axis_type),
+            max_type: SparklineAxisMinMaxType::from_string(raw.max_axis_type),
+            manual_min: raw.manual_min,
+            manual_max: raw.manual_max,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.sparklinetypevalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SparklineType {
+    Line,
+    Column,
+    /// `stacked`
+    WinLoss,
+}
+
+impl SparklineType {
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else { return Self::Line };
+        return match s.as_ref() {
+            "column" => Self::Column,
+            "stacked" => Self::WinLoss,
+            _ => Self::Line,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.displayblanksasvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SparklineEmptyCellsType {
+    /// Empty cells are displayed as gaps
+    Gap,
+    /// Empty cells are displayed as zero
+    Zero,
+    /// Empty cells are skipped, connecting the data points with a line
+    Span,
+}
+
+impl SparklineEmptyCellsType {
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else { return Self::Zero };
+        return match s.as_ref() {
+            "gap" => Self::Gap,
+            "span" => Self::Span,
+            _ => Self::Zero,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.sparklineaxisminmaxvalues?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SparklineAxisMinMaxType {
+    /// Automatic for each sparkline
+    Individual,
+    /// Same for all sparklines in the group
+    Group,
+    /// Specified by `manual_min` or `manual_max`
+    Custom,
+}
+
+impl SparklineAxisMinMaxType {
+    pub(crate) fn from_string(s: Option<String>) -> Self {
+        let Some(s) = s else { return Self::Individual };
+        return match s.as_ref() {
+            "group" => Self::Group,
+            "custom" => Self::Custom,
+            _ => Self::Individual,
+        };
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::slicer::SlicerPivotTable;
+use crate::{
+    common_types::XlsxDatetime,
+    raw::spreadsheet::timeline::{
+        timeline_cache::{XlsxTimelineCacheDefinition, XlsxTimelineRange},
+        XlsxTimeline,
+    },
+};
+
+/// - Timeline: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.timeline?view=openxml-3.0.1
+/// - TimelineCacheDefinition: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.timelinecachedefinition?view=openxml-3.0.1
+///
+/// A timeline of the worksheet parsed from xl/timelines/timeline{}.xml, joined with its cache parsed from xl/timelineCaches/timelineCache{}.xml.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Timeline {
+    /// Name referenced by the graphic frame of the timeline in the drawing (`GraphicFrame.timeline_name`)
+    pub name: String,
+
+    /// Caption displayed in the header of the timeline
+    pub caption: Option<String>,
+
+    /// Name of the timeline cache, shared by timelines filtering the same field
+    pub cache_name: String,
+
+    /// Name of the date field filtered
+    pub source_name: Option<String>,
+
+    /// `cacheId` of the pivot cache of the pivot tables filtered
+    pub pivot_cache_id: Option<u64>,
+
+    /// Pivot tables filtered by the timeline
+    pub pivot_tables: Vec<SlicerPivotTable>,
+
+    /// Range of the dates selected.
+    ///
+    /// None if the timeline is not filtering.
+    pub selection: Option<TimelineDateRange>,
+
+    /// Range of the dates available in the source
+    pub bounds: Option<TimelineDateRange>,
+
+    /// Time level displayed
+    pub level: TimelineLevel,
+
+    /// Time level of the selection
+    pub selection_level: TimelineLevel,
+
+    /// First date displayed
+    pub scroll_position: Option<XlsxDatetime>,
+
+    /// Ex: `TimeSlicerStyleLight1`
+    pub style: Option<String>,
+
+    pub show_header: bool,
+
+    pub show_selection_label: bool,
+
+    pub show_time_level: bool,
+
+    pub show_horizontal_scrollbar: bool,
+}
+
+impl Timeline {
+    pub(crate) fn from_raw(raw: XlsxTimeline, cache: Option<XlsxTimelineCacheDefinition>) -> Self {
+        let state = cache.as_ref().and_then(|c| c.state.clone());
+
+        let selection = match &state {
+            Some(state) if state.filter_type.as_deref().unwrap_or("unknown") != "unknown" => {
+                state.selection.clone().map(TimelineDateRange::from_raw)
+            }
+            _ => None,
+        };
+
+        return Self {
+            name: raw.name.unwrap_or_default(),
+            caption: raw.caption,
+            cache_name: raw.cache.unwrap_or_default(),
+            source_name: cache.as_ref().and_then(|c| c.source_name.clone()),
+            pivot_cache_id: state.as_ref().and_then(|s| s.pivot_cache_id),
+            pivot_tables: cache
+                .and_then(|c| c.pivot_tables)
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(SlicerPivotTable::from_raw)
+                .collect(),
+            selection,
+            bounds: state
+                .and_then(|s| s.bounds)
+                .map(TimelineDateRange::from_raw),
+            level: TimelineLevel::from_raw(raw.level),
+            selection_level: TimelineLevel::from_raw(raw.selection_level),
+            scroll_position: raw
+                .scroll_position
+                .and_then(|p| XlsxDatetime::from_string(&p)),
+            style: raw.style,
+            show_header: raw.show_header.unwrap_or(true),
+            show_selection_label: raw.show_selection_label.unwrap_or(true),
+            show_time_level: raw.show_time_level.unwrap_or(true),
+            show_horizontal_scrollbar: raw.show_horizontal_scrollbar.unwrap_or(true),
+        };
+    }
+}
+
+/// A range of dates of a timeline
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TimelineDateRange {
+    pub start: Option<XlsxDatetime>,
+    pub end: Option<XlsxDatetime>,
+}
+
+impl TimelineDateRange {
+    pub(crate) fn from_raw(raw: XlsxTimelineRange) -> Self {
+        return Self {
+            start: raw.start_date.and_then(|d| XlsxDatetime::from_string(&d)),
+            end: raw.end_date.and_then(|d| XlsxDatetime::from_string(&d)),
+        };
+    }
+}
+
+/// Time level of a timeline
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TimelineLevel {
+    Years,
+    Quarters,
+    Months,
+    Days,
+}
+
+impl TimelineLevel {
+    pub(crate) fn from_raw(level: Option<u64>) -> Self {
+        return match level {
+            Some(0) => Self::Years,
+            Some(1) => Self::Quarters,
+            Some(3) => Self::Days,
+            _ => Self::Months,
+        };
+    }
+}
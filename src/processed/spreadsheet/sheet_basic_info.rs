@@ -8,6 +8,8 @@ use crate::{
     raw::spreadsheet::workbook::sheet::XlsxSheet,
 };
 
+use super::sheet::sheet_properties::SheetProperties;
+
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SheetBasicInfo {
@@ -26,6 +28,9 @@ pub struct SheetBasicInfo {
     /// type
     pub r#type: SheetType,
 
+    /// sheetPr (Sheet Properties): tab color, code name, and etc.
+    pub properties: SheetProperties,
+
     /// xml path
     #[cfg_attr(feature = "serde", serde(skip_serializing))]
     pub(crate) path: String,
@@ -63,6 +68,7 @@ impl SheetBasicInfo {
             sheet_id,
             visible_state: visibility,
             r#type: sheet_type,
+            properties: SheetProperties::default(),
             path,
         });
     }
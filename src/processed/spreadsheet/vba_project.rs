@@ -0,0 +1,174 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    processed::spreadsheet::sheet_basic_info::SheetBasicInfo,
+    raw::spreadsheet::vba_project::{XlsxVbaModule, XlsxVbaProject},
+};
+
+/// https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-ovba/575462ba-bf67-4190-9fac-c275523c75fc
+///
+/// VBA project of a macro-enabled workbook (.xlsm, .xltm) parsed from xl/vbaProject.bin.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VbaProject {
+    /// Name of the project. Ex: `VBAProject`
+    pub name: Option<String>,
+
+    /// Description of the project
+    pub doc_string: Option<String>,
+
+    /// Conditional compilation constants. Ex: `DEBUG = 1`
+    pub constants: Option<String>,
+
+    /// Code page of the project. Ex: `1252`
+    pub code_page: u16,
+
+    /// Names of the referenced libraries and projects. Ex: `stdole`, `Office`
+    pub references: Vec<String>,
+
+    /// Modules in the order of the project
+    pub modules: Vec<VbaModule>,
+}
+
+impl VbaProject {
+    /// * workbook_code_name: `codeName` of the workbook properties, the name of the workbook document module. Ex: `ThisWorkbook`
+    /// * sheets: sheets of the workbook, mapped to document modules by the `codeName` of their properties
+    pub(crate) fn from_raw(
+        raw: XlsxVbaProject,
+        workbook_code_name: Option<String>,
+        sheets: &[SheetBasicInfo],
+    ) -> Self {
+        let modules: Vec<VbaModule> = raw
+            .modules
+            .into_iter()
+            .map(|m| VbaModule::from_raw(m, workbook_code_name.as_deref(), sheets))
+            .collect();
+
+        return Self {
+            name: raw.dir.name,
+            doc_string: raw.dir.doc_string.filter(|s| !s.is_empty()),
+            constants: raw.dir.constants.filter(|s| !s.is_empty()),
+            code_page: raw.dir.code_page,
+            references: raw.dir.references,
+            modules,
+        };
+    }
+
+    /// Get a module by name (case-insensitive).
+    pub fn get_module(&self, name: &str) -> Option<&VbaModule> {
+        return self
+            .modules
+            .iter()
+            .find(|m| m.name.eq_ignore_ascii_case(name));
+    }
+
+    /// Get the document module of a sheet.
+    pub fn get_module_for_sheet(&self, sheet_name: &str) -> Option<&VbaModule> {
+        return self
+            .modules
+            .iter()
+            .find(|m| m.sheet_name.as_deref() == Some(sheet_name));
+    }
+}
+
+/// A module of the VBA project with its source code.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VbaModule {
+    /// Name of the module. Ex: `Module1`, `Sheet1`, `ThisWorkbook`
+    pub name: String,
+
+    /// Type of the module
+    pub module_type: VbaModuleType,
+
+    /// Source code, including `Attribute` lines
+    pub source: String,
+
+    /// Description of the module
+    pub doc_string: Option<String>,
+
+    /// Name of the sheet of a document module.
+    ///
+    /// None for other modules and the workbook module.
+    pub sheet_name: Option<String>,
+
+    /// Whether the module is the document module of the workbook
+    pub is_workbook: bool,
+
+    /// Whether the module is read-only
+    pub read_only: bool,
+
+    /// Whether the module is only usable within the project
+    pub private: bool,
+}
+
+impl VbaModule {
+    pub(crate) fn from_raw(
+        raw: XlsxVbaModule,
+        workbook_code_name: Option<&str>,
+        sheets: &[SheetBasicInfo],
+    ) -> Self {
+        let module_type = VbaModuleType::from_raw(raw.record.procedural, raw.project_kind);
+        let name = raw.record.name;
+
+        let (sheet_name, is_workbook) = if module_type == VbaModuleType::Document {
+            let sheet_name = sheets
+                .iter()
+                .find(|s| {
+                    s.properties
+                        .code_name
+                        .as_deref()
+                        .is_some_and(|c| c.eq_ignore_ascii_case(&name))
+                })
+                .map(|s| s.name.clone());
+            let is_workbook = workbook_code_name.is_some_and(|c| c.eq_ignore_ascii_case(&name));
+            (sheet_name, is_workbook)
+        } else {
+            (None, false)
+        };
+
+        return Self {
+            name,
+            module_type,
+            source: raw.source,
+            doc_string: raw.record.doc_string,
+            sheet_name,
+            is_workbook,
+            read_only: raw.record.read_only,
+            private: raw.record.private,
+        };
+    }
+}
+
+/// https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-ovba/fcfabf0a-4ec2-4c7e-a2c6-d6eab8c54d8a
+///
+/// Type of a VBA module.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VbaModuleType {
+    /// Standard (procedural) module
+    Standard,
+    /// Class module
+    Class,
+    /// Document module of the workbook or a sheet
+    Document,
+    /// Designer module. Ex: UserForm
+    Designer,
+}
+
+impl VbaModuleType {
+    /// * procedural: MODULETYPE of the `dir` stream
+    /// * project_kind: kind of the module in the `PROJECT` stream. Ex: `Document`, `Class`, `BaseClass`
+    pub(crate) fn from_raw(procedural: bool, project_kind: Option<String>) -> Self {
+        if procedural {
+            return Self::Standard;
+        }
+        return match project_kind.as_deref() {
+            Some("Document") => Self::Document,
+            Some("BaseClass") => Self::Designer,
+            _ => Self::Class,
+        };
+    }
+}
@@ -0,0 +1,350 @@
+use anyhow::Context;
+use chrono::{NaiveDate, TimeDelta};
+use quick_xml::escape::escape;
+use std::collections::BTreeMap;
+
+use super::{XmlMap, XmlMappedTable};
+use crate::{
+    common_types::Coordinate,
+    processed::spreadsheet::sheet::worksheet::{cell::cell_value::CellValueType, Worksheet},
+};
+
+impl XmlMap {
+    /// Export the data of the mapped tables and cells as the XML document the map represents.
+    ///
+    /// * worksheets: worksheets containing the mapped ranges, by sheet name
+    ///
+    /// Each data row of a mapped table becomes a repeating element (the deepest element common to the non-denormalized columns),
+    /// denormalized columns are exported once with the value of the first row.
+    pub fn export(&self, worksheets: &BTreeMap<String, Worksheet>) -> anyhow::Result<String> {
+        // RootElement is not prefixed: use the qualified name of the xpaths
+        let root_name = self
+            .cells
+            .iter()
+            .map(|c| c.xpath.as_str())
+            .chain(
+                self.tables
+                    .iter()
+                    .flat_map(|t| t.columns.iter().map(|c| c.xpath.as_str())),
+            )
+            .find_map(|xpath| XmlPath::from_xpath(xpath).steps.first().cloned())
+            .unwrap_or(self.root_element.clone());
+        let mut root = XmlNode::new(&root_name);
+        for namespace in self.namespaces.iter() {
+            let name = if namespace.prefix.is_empty() {
+                "xmlns".to_string()
+            } else {
+                format!("xmlns:{}", namespace.prefix)
+            };
+            root.attributes.push((name, namespace.uri.clone()));
+        }
+
+        for cell in self.cells.iter() {
+            let worksheet = worksheets
+                .get(&cell.sheet_name)
+                .context(format!("worksheet {} not available", cell.sheet_name))?;
+            let value = cell_value_string(worksheet, cell.coordinate, &cell.data_type)?;
+            root.set(&XmlPath::from_xpath(&cell.xpath).steps, value);
+        }
+
+        for table in self.tables.iter() {
+            let worksheet = worksheets
+                .get(&table.sheet_name)
+                .context(format!("worksheet {} not available", table.sheet_name))?;
+            export_table(&mut root, table, worksheet)?;
+        }
+
+        let mut xml =
+            String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n");
+        root.write(&mut xml, 0);
+        return Ok(xml);
+    }
+}
+
+fn export_table(
+    root: &mut XmlNode,
+    table: &XmlMappedTable,
+    worksheet: &Worksheet,
+) -> anyhow::Result<()> {
+    let paths: Vec<XmlPath> = table
+        .columns
+        .iter()
+        .map(|c| XmlPath::from_xpath(&c.xpath))
+        .collect();
+
+    // repeating element: deepest element shared by the non-denormalized columns
+    let mut repeating: Option<Vec<String>> = None;
+    for (column, path) in table.columns.iter().zip(paths.iter()) {
+        if column.denormalized {
+            continue;
+        }
+        let candidate = path.record_candidate();
+        repeating = Some(match repeating {
+            None => candidate,
+            Some(current) => current
+                .into_iter()
+                .zip(candidate)
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a)
+                .collect(),
+        });
+    }
+    let repeating = repeating.unwrap_or(vec![]);
+
+    let rows: Vec<u64> = table.data_rows().collect();
+    let col_start = table.dimension.start.col;
+
+    // the root cannot repeat: only the first row is exported
+    if repeating.len() <= 1 {
+        if let Some(row) = rows.first() {
+            for (column, path) in table.columns.iter().zip(paths.iter()) {
+                let coordinate = Coordinate::from_point((*row, col_start + column.index));
+                let value = cell_value_string(worksheet, coordinate, &column.data_type)?;
+                root.set(&path.steps, value);
+            }
+        }
+        return Ok(());
+    }
+
+    for (column, path) in table.columns.iter().zip(paths.iter()) {
+        if !column.denormalized || path.starts_with(&repeating) {
+            continue;
+        }
+        if let Some(row) = rows.first() {
+            let coordinate = Coordinate::from_point((*row, col_start + column.index));
+            let value = cell_value_string(worksheet, coordinate, &column.data_type)?;
+            root.set(&path.steps, value);
+        }
+    }
+
+    let record_name = repeating.last().cloned().unwrap_or_default();
+    let container = root.get_or_create(&repeating[..repeating.len() - 1]);
+    for row in rows.into_iter() {
+        let mut record = XmlNode::new(&record_name);
+        for (column, path) in table.columns.iter().zip(paths.iter()) {
+            if !path.starts_with(&repeating) {
+                continue;
+            }
+            let coordinate = Coordinate::from_point((row, col_start + column.index));
+            let value = cell_value_string(worksheet, coordinate, &column.data_type)?;
+            // first step of the relative path is the record itself
+            record.set(&path.steps[repeating.len() - 1..], value);
+        }
+        container.children.push(record);
+    }
+
+    return Ok(());
+}
+
+/// value of the cell as the text of a xml element of the `data_type` (xsd type)
+fn cell_value_string(
+    worksheet: &Worksheet,
+    coordinate: Coordinate,
+    data_type: &str,
+) -> anyhow::Result<String> {
+    let value = match worksheet.get_cell(coordinate) {
+        Ok(cell) => cell.value,
+        // outside of the used range
+        Err(_) => CellValueType::Empty,
+    };
+
+    let string = match value {
+        CellValueType::Numeric(n) => {
+            serial_to_string(n, data_type, worksheet.is_1904).unwrap_or(n.to_string())
+        }
+        CellValueType::RichText(r) => r.runs.into_iter().map(|r| r.text).collect(),
+        CellValueType::Formula(f) => f.last_calculated_value.unwrap_or_default(),
+        CellValueType::PlainText(p) => p.text,
+        CellValueType::Bool(b) => b.to_string(),
+        CellValueType::DateTime(d) => d,
+        CellValueType::Error(e) => e.to_string(),
+        CellValueType::Empty => String::new(),
+    };
+    return Ok(string);
+}
+
+/// serial date value formatted for the date and time xsd types, None for other types
+fn serial_to_string(serial: f64, data_type: &str, is_1904: bool) -> Option<String> {
+    let format = match data_type {
+        "date" => "%Y-%m-%d",
+        "dateTime" => "%Y-%m-%dT%H:%M:%S",
+        "time" => "%H:%M:%S",
+        _ => return None,
+    };
+
+    let base = if is_1904 {
+        NaiveDate::from_ymd_opt(1904, 1, 1)?
+    } else if serial < 60.0 {
+        // 1900 is wrongly considered as a leap year
+        NaiveDate::from_ymd_opt(1899, 12, 31)?
+    } else {
+        NaiveDate::from_ymd_opt(1899, 12, 30)?
+    };
+    // out of the range of dates: exported as the number
+    let milliseconds = (serial * 86_400_000.0).round() as i64;
+    let datetime = base
+        .and_hms_opt(0, 0, 0)?
+        .checked_add_signed(TimeDelta::try_milliseconds(milliseconds)?)?;
+    return Some(datetime.format(format).to_string());
+}
+
+/// Steps of a xpath without predicates.
+///
+/// Ex: `/ns1:report/ns1:items/ns1:item/@id` => [ns1:report, ns1:items, ns1:item, @id]
+struct XmlPath {
+    steps: Vec<String>,
+}
+
+impl XmlPath {
+    fn from_xpath(xpath: &str) -> Self {
+        let steps = xpath
+            .split('/')
+            .map(|step| step.split('[').next().unwrap_or("").trim().to_string())
+            .filter(|step| !step.is_empty())
+            .collect();
+        return Self { steps };
+    }
+
+    /// element containing the value: the element holding the attribute, or the parent of the element
+    fn record_candidate(&self) -> Vec<String> {
+        return self.steps[..self.steps.len().saturating_sub(1)].to_vec();
+    }
+
+    fn starts_with(&self, prefix: &[String]) -> bool {
+        return self.steps.len() > prefix.len() && self.steps.starts_with(prefix);
+    }
+}
+
+struct XmlNode {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlNode>,
+    text: Option<String>,
+}
+
+impl XmlNode {
+    fn new(name: &str) -> Self {
+        return Self {
+            name: name.to_string(),
+            attributes: vec![],
+            children: vec![],
+            text: None,
+        };
+    }
+
+    /// set the text (or the attribute for a `@` step) at `steps`, the first step being this node
+    fn set(&mut self, steps: &[String], value: String) {
+        let Some((last, parents)) = steps.split_last() else {
+            return;
+        };
+        if let Some(attribute) = last.strip_prefix('@') {
+            let node = self.get_or_create(parents);
+            match node
+                .attributes
+                .iter_mut()
+                .find(|(name, _)| name == attribute)
+            {
+                Some(existing) => existing.1 = value,
+                None => node.attributes.push((attribute.to_string(), value)),
+            }
+        } else {
+            self.get_or_create(steps).text = Some(value);
+        }
+    }
+
+    /// descendant at `steps`, the first step being this node. Missing elements are created.
+    fn get_or_create(&mut self, steps: &[String]) -> &mut XmlNode {
+        let mut node = self;
+        for step in steps.iter().skip(1) {
+            let index = match node.children.iter().rposition(|c| &c.name == step) {
+                Some(index) => index,
+                None => {
+                    node.children.push(XmlNode::new(step));
+                    node.children.len() - 1
+                }
+            };
+            node = &mut node.children[index];
+        }
+        return node;
+    }
+
+    fn write(&self, xml: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        xml.push_str(&indent);
+        xml.push('<');
+        xml.push_str(&self.name);
+        for (name, value) in self.attributes.iter() {
+            xml.push_str(&format!(" {}=\"{}\"", name, escape(value.as_str())));
+        }
+
+        if self.children.is_empty() {
+            match &self.text {
+                Some(text) if !text.is_empty() => {
+                    xml.push_str(&format!(">{}</{}>\n", escape(text.as_str()), self.name));
+                }
+                _ => xml.push_str("/>\n"),
+            }
+            return;
+        }
+
+        xml.push_str(">\n");
+        if let Some(text) = &self.text {
+            if !text.is_empty() {
+                xml.push_str(&format!("{}  {}\n", indent, escape(text.as_str())));
+            }
+        }
+        for child in self.children.iter() {
+            child.write(xml, depth + 1);
+        }
+        xml.push_str(&format!("{}</{}>\n", indent, self.name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serial_to_string_around_the_1900_leap_year_bug() {
+        assert_eq!(
+            serial_to_string(1.0, "date", false),
+            Some("1900-01-01".to_string())
+        );
+        assert_eq!(
+            serial_to_string(59.0, "date", false),
+            Some("1900-02-28".to_string())
+        );
+        assert_eq!(
+            serial_to_string(61.0, "date", false),
+            Some("1900-03-01".to_string())
+        );
+        assert_eq!(
+            serial_to_string(45000.25, "dateTime", false),
+            Some("2023-03-15T06:00:00".to_string())
+        );
+        assert_eq!(
+            serial_to_string(0.5, "time", false),
+            Some("12:00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn serial_to_string_with_1904_date_system() {
+        assert_eq!(
+            serial_to_string(0.0, "date", true),
+            Some("1904-01-01".to_string())
+        );
+        assert_eq!(
+            serial_to_string(1461.0, "date", true),
+            Some("1908-01-01".to_string())
+        );
+    }
+
+    #[test]
+    fn serial_to_string_out_of_range() {
+        assert_eq!(serial_to_string(1e12, "date", false), None);
+        assert_eq!(serial_to_string(-1e300, "dateTime", false), None);
+        assert_eq!(serial_to_string(f64::INFINITY, "date", false), None);
+        assert_eq!(serial_to_string(45000.0, "string", false), None);
+    }
+}
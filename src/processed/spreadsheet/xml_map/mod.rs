@@ -0,0 +1,256 @@
+pub mod export;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    raw::spreadsheet::{
+        single_xml_cell::XlsxSingleXmlCell,
+        table::XlsxTable,
+        xml_map::{map::XlsxXmlMap, schema::XlsxXmlSchema},
+    },
+};
+
+/// - MapInfo: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.mapinfo?view=openxml-3.0.1
+/// - Map: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.map?view=openxml-3.0.1
+///
+/// A XML map parsed from xl/xmlMaps.xml,
+/// with the table columns (xl/tables/table{}.xml) and single cells (xl/tables/tableSingleCells{}.xml) mapped to its elements.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct XmlMap {
+    /// Id referenced by the mapped columns and cells
+    pub id: u64,
+
+    pub name: String,
+
+    /// Name of the root element of the mapped documents
+    pub root_element: String,
+
+    pub schema_id: Option<String>,
+
+    /// Target namespace of the schema
+    pub schema_namespace: Option<String>,
+
+    /// Xml of the schema (usually a `xsd:schema` element)
+    pub schema: Option<String>,
+
+    /// Namespace prefixes used in the xpaths of the mapped columns and cells
+    pub namespaces: Vec<XmlNamespace>,
+
+    /// Whether imported data is appended to the mapped tables instead of overwriting them
+    pub append: bool,
+
+    pub auto_fit: bool,
+
+    pub preserve_format: bool,
+
+    /// Tables with columns mapped to elements of the map
+    pub tables: Vec<XmlMappedTable>,
+
+    /// Single cells mapped to elements of the map
+    pub cells: Vec<XmlMappedCell>,
+}
+
+impl XmlMap {
+    /// * selection_namespaces: `SelectionNamespaces` of the map info. Ex: `xmlns:ns1='http://example.com/report'`
+    /// * tables: (sheet name, table) of all tables of the workbook
+    /// * cells: (sheet name, cell) of all single mapped cells of the workbook
+    pub(crate) fn from_raw(
+        raw: XlsxXmlMap,
+        schemas: &Vec<XlsxXmlSchema>,
+        selection_namespaces: Option<String>,
+        tables: &Vec<(String, XlsxTable)>,
+        cells: &Vec<(String, XlsxSingleXmlCell)>,
+    ) -> Self {
+        let id = raw.id.unwrap_or(0);
+        let schema = schemas
+            .iter()
+            .find(|s| s.id.is_some() && s.id == raw.schema_id);
+
+        let tables: Vec<XmlMappedTable> = tables
+            .iter()
+            .filter_map(|(sheet_name, table)| XmlMappedTable::from_raw(table, sheet_name, id))
+            .collect();
+
+        let cells: Vec<XmlMappedCell> = cells
+            .iter()
+            .filter_map(|(sheet_name, cell)| XmlMappedCell::from_raw(cell, sheet_name, id))
+            .collect();
+
+        return Self {
+            id,
+            name: raw.name.unwrap_or("".to_string()),
+            root_element: raw.root_element.unwrap_or("".to_string()),
+            schema_id: raw.schema_id,
+            schema_namespace: schema.and_then(|s| s.namespace.clone()),
+            schema: schema.map(|s| s.xml.clone()),
+            namespaces: XmlNamespace::from_selection_namespaces(selection_namespaces),
+            append: raw.append.unwrap_or(false),
+            auto_fit: raw.auto_fit.unwrap_or(true),
+            preserve_format: raw.preserve_format.unwrap_or(true),
+            tables,
+            cells,
+        };
+    }
+}
+
+/// A namespace prefix used in the xpaths
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct XmlNamespace {
+    /// Empty for the default namespace
+    pub prefix: String,
+
+    pub uri: String,
+}
+
+impl XmlNamespace {
+    /// * s: space separated declarations. Ex: `xmlns:ns1='http://example.com/a' xmlns:ns2='http://example.com/b'`
+    pub(crate) fn from_selection_namespaces(s: Option<String>) -> Vec<Self> {
+        let Some(s) = s else {
+            return vec![];
+        };
+
+        return s
+            .split_whitespace()
+            .filter_map(|declaration| {
+                let (name, uri) = declaration.split_once('=')?;
+                let prefix = if name == "xmlns" {
+                    ""
+                } else {
+                    name.strip_prefix("xmlns:")?
+                };
+                Some(Self {
+                    prefix: prefix.to_string(),
+                    uri: uri.trim_matches(|c| c == '\'' || c == '"').to_string(),
+                })
+            })
+            .collect();
+    }
+}
+
+/// A table with columns mapped to elements of a XML map: each row of the table is a repeating element.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct XmlMappedTable {
+    pub sheet_name: String,
+
+    /// Display name of the table
+    pub table_name: String,
+
+    /// Range of the table, including the header and totals rows
+    pub dimension: Dimension,
+
+    pub header_row_count: u64,
+
+    pub totals_row_count: u64,
+
+    /// Mapped columns
+    pub columns: Vec<XmlMappedColumn>,
+}
+
+impl XmlMappedTable {
+    /// None if none of the columns of the table is mapped to the map
+    pub(crate) fn from_raw(table: &XlsxTable, sheet_name: &str, map_id: u64) -> Option<Self> {
+        let columns: Vec<XmlMappedColumn> = table
+            .table_columns
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, column)| {
+                let properties = column.xml_column_properties?;
+                if properties.map_id != Some(map_id) {
+                    return None;
+                }
+                Some(XmlMappedColumn {
+                    index: index as u64,
+                    name: column.name.unwrap_or("".to_string()),
+                    xpath: properties.xml_path.unwrap_or("".to_string()),
+                    data_type: properties.xml_data_type.unwrap_or("string".to_string()),
+                    denormalized: properties.denormalized.unwrap_or(false),
+                })
+            })
+            .collect();
+        if columns.is_empty() {
+            return None;
+        }
+
+        return Some(Self {
+            sheet_name: sheet_name.to_string(),
+            table_name: table.display_name.clone().unwrap_or("".to_string()),
+            dimension: table.r#ref.unwrap_or(Dimension::default()),
+            header_row_count: table.header_row_count.unwrap_or(1),
+            totals_row_count: table.totals_row_count.unwrap_or(0),
+            columns,
+        });
+    }
+
+    /// Rows of the table containing data: the range without the header and totals rows
+    pub fn data_rows(&self) -> std::ops::RangeInclusive<u64> {
+        let start = self.dimension.start.row + self.header_row_count;
+        let end = self.dimension.end.row.saturating_sub(self.totals_row_count);
+        return start..=end;
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.xmlcolumnproperties?view=openxml-3.0.1
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct XmlMappedColumn {
+    /// 0-based index of the column in the table
+    pub index: u64,
+
+    pub name: String,
+
+    /// Path of the element or attribute. Ex: `/ns1:report/ns1:items/ns1:item/@id`
+    pub xpath: String,
+
+    /// Type of the element in the schema. Ex: string, integer, date
+    pub data_type: String,
+
+    /// Whether the element is not repeating: its value is repeated on every row
+    pub denormalized: bool,
+}
+
+/// A cell mapped to a non repeating element of a XML map
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct XmlMappedCell {
+    pub sheet_name: String,
+
+    pub coordinate: Coordinate,
+
+    pub unique_name: Option<String>,
+
+    /// Path of the element or attribute. Ex: `/ns1:report/ns1:title`
+    pub xpath: String,
+
+    /// Type of the element in the schema. Ex: string, integer, date
+    pub data_type: String,
+}
+
+impl XmlMappedCell {
+    /// None if the cell is not mapped to the map
+    pub(crate) fn from_raw(
+        cell: &XlsxSingleXmlCell,
+        sheet_name: &str,
+        map_id: u64,
+    ) -> Option<Self> {
+        let cell_properties = cell.xml_cell_properties.clone()?;
+        let properties = cell_properties.xml_properties?;
+        if properties.map_id != Some(map_id) {
+            return None;
+        }
+
+        return Some(Self {
+            sheet_name: sheet_name.to_string(),
+            coordinate: cell.reference?,
+            unique_name: cell_properties.unique_name,
+            xpath: properties.xml_path.unwrap_or("".to_string()),
+            data_type: properties.xml_data_type.unwrap_or("string".to_string()),
+        });
+    }
+}
@@ -0,0 +1,136 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{common_types::XlsxDatetime, excel::xml_reader, helper::extract_text_contents};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.packaging.coreFilePropertiesPart?view=openxml-3.0.1
+///
+/// Root element of the core properties part (docProps/core.xml): Dublin Core metadata of the package.
+///
+/// Example
+/// ```
+/// <cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+///     <dc:title>Budget</dc:title>
+///     <dc:creator>Jane Doe</dc:creator>
+///     <cp:lastModifiedBy>John Doe</cp:lastModifiedBy>
+///     <dcterms:created xsi:type="dcterms:W3CDTF">2024-01-15T09:30:00Z</dcterms:created>
+///     <dcterms:modified xsi:type="dcterms:W3CDTF">2024-02-01T17:00:00Z</dcterms:modified>
+/// </cp:coreProperties>
+/// ```
+/// coreProperties
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxCoreProperties {
+    // Child Elements
+    /// cp:category
+    pub category: Option<String>,
+
+    /// cp:contentStatus. Ex: `Draft`, `Final`
+    pub content_status: Option<String>,
+
+    /// dcterms:created
+    pub created: Option<XlsxDatetime>,
+
+    /// dc:creator (author)
+    pub creator: Option<String>,
+
+    /// dc:description (comments)
+    pub description: Option<String>,
+
+    /// dc:identifier
+    pub identifier: Option<String>,
+
+    /// cp:keywords
+    pub keywords: Option<String>,
+
+    /// dc:language
+    pub language: Option<String>,
+
+    /// cp:lastModifiedBy
+    pub last_modified_by: Option<String>,
+
+    /// cp:lastPrinted
+    pub last_printed: Option<XlsxDatetime>,
+
+    /// dcterms:modified
+    pub modified: Option<XlsxDatetime>,
+
+    /// cp:revision
+    pub revision: Option<String>,
+
+    /// dc:subject
+    pub subject: Option<String>,
+
+    /// dc:title
+    pub title: Option<String>,
+
+    /// cp:version
+    pub version: Option<String>,
+}
+
+impl XlsxCoreProperties {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            category: None,
+            content_status: None,
+            created: None,
+            creator: None,
+            description: None,
+            identifier: None,
+            keywords: None,
+            language: None,
+            last_modified_by: None,
+            last_printed: None,
+            modified: None,
+            revision: None,
+            subject: None,
+            title: None,
+            version: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(properties);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"coreProperties" => {}
+                Ok(Event::Start(ref e)) => {
+                    let tag = e.local_name().as_ref().to_vec();
+                    let text = extract_text_contents(&mut reader, &tag)?.trim().to_string();
+                    match tag.as_slice() {
+                        b"category" => properties.category = Some(text),
+                        b"contentStatus" => properties.content_status = Some(text),
+                        b"created" => properties.created = XlsxDatetime::from_string(&text),
+                        b"creator" => properties.creator = Some(text),
+                        b"description" => properties.description = Some(text),
+                        b"identifier" => properties.identifier = Some(text),
+                        b"keywords" => properties.keywords = Some(text),
+                        b"language" => properties.language = Some(text),
+                        b"lastModifiedBy" => properties.last_modified_by = Some(text),
+                        b"lastPrinted" => {
+                            properties.last_printed = XlsxDatetime::from_string(&text)
+                        }
+                        b"modified" => properties.modified = XlsxDatetime::from_string(&text),
+                        b"revision" => properties.revision = Some(text),
+                        b"subject" => properties.subject = Some(text),
+                        b"title" => properties.title = Some(text),
+                        b"version" => properties.version = Some(text),
+                        _ => {}
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"coreProperties" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(properties);
+    }
+}
@@ -0,0 +1,139 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{extract_text_contents, string_to_int},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.customproperties.properties?view=openxml-3.0.1
+///
+/// Root element of the custom properties part (docProps/custom.xml).
+///
+/// Example
+/// ```
+/// <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/custom-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+///     <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="2" name="Department">
+///         <vt:lpwstr>Finance</vt:lpwstr>
+///     </property>
+///     <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="3" name="Approved">
+///         <vt:bool>true</vt:bool>
+///     </property>
+/// </Properties>
+/// ```
+/// Properties
+pub type XlsxCustomProperties = Vec<XlsxCustomProperty>;
+
+pub(crate) fn load_custom_properties(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    path: &str,
+) -> anyhow::Result<XlsxCustomProperties> {
+    let mut properties: XlsxCustomProperties = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(properties);
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"property" => {
+                properties.push(XlsxCustomProperty::load(&mut reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"Properties" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(properties)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.customproperties.customdocumentproperty?view=openxml-3.0.1
+///
+/// A custom property, with its value as a variant type element.
+///
+/// Example
+/// ```
+/// <property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="2" name="Department">
+///     <vt:lpwstr>Finance</vt:lpwstr>
+/// </property>
+/// ```
+/// property
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxCustomProperty {
+    // Child Elements
+    /// Local name of the variant type element. Ex: `lpwstr`, `bool`, `i4`, `r8`, `filetime`
+    pub value_type: Option<String>,
+
+    /// Text of the variant type element
+    pub value: Option<String>,
+
+    // attributes
+    /// fmtid (Format ID)
+    pub fmtid: Option<String>,
+
+    /// linkTarget (Link Target): name of the defined name the property is linked to
+    pub link_target: Option<String>,
+
+    /// name (Custom File Property Name)
+    pub name: Option<String>,
+
+    /// pid (Property ID)
+    pub pid: Option<i64>,
+}
+
+impl XlsxCustomProperty {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut property = Self {
+            value_type: None,
+            value: None,
+            fmtid: None,
+            link_target: None,
+            name: None,
+            pid: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"fmtid" => property.fmtid = Some(string_value),
+                        b"linkTarget" => property.link_target = Some(string_value),
+                        b"name" => property.name = Some(string_value),
+                        b"pid" => property.pid = string_to_int(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    let tag = e.local_name().as_ref().to_vec();
+                    property.value = Some(extract_text_contents(reader, &tag)?);
+                    property.value_type = Some(String::from_utf8(tag)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"property" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(property)
+    }
+}
@@ -0,0 +1,180 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{extract_text_contents, string_to_bool, string_to_int, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.extendedproperties.properties?view=openxml-3.0.1
+///
+/// Root element of the extended (application specific) properties part (docProps/app.xml).
+///
+/// Example
+/// ```
+/// <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+///     <Application>Microsoft Excel</Application>
+///     <DocSecurity>0</DocSecurity>
+///     <ScaleCrop>false</ScaleCrop>
+///     <HeadingPairs>
+///         <vt:vector size="2" baseType="variant">
+///             <vt:variant><vt:lpstr>Worksheets</vt:lpstr></vt:variant>
+///             <vt:variant><vt:i4>2</vt:i4></vt:variant>
+///         </vt:vector>
+///     </HeadingPairs>
+///     <TitlesOfParts>
+///         <vt:vector size="2" baseType="lpstr">
+///             <vt:lpstr>Sheet1</vt:lpstr>
+///             <vt:lpstr>Sheet2</vt:lpstr>
+///         </vt:vector>
+///     </TitlesOfParts>
+///     <Company>Acme</Company>
+///     <LinksUpToDate>false</LinksUpToDate>
+///     <SharedDoc>false</SharedDoc>
+///     <HyperlinksChanged>false</HyperlinksChanged>
+///     <AppVersion>16.0300</AppVersion>
+/// </Properties>
+/// ```
+/// Properties
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExtendedProperties {
+    // Child Elements
+    /// Application: name of the application that created the document
+    pub application: Option<String>,
+
+    /// AppVersion. Ex: `16.0300`
+    pub app_version: Option<String>,
+
+    /// Company
+    pub company: Option<String>,
+
+    /// DocSecurity: security level (bit flags). 1: password protected, 2: read-only recommended, 4: read-only enforced, 8: locked for annotation
+    pub doc_security: Option<i64>,
+
+    /// HeadingPairs: names of the groups of `TitlesOfParts` followed by their counts. Ex: `Worksheets`, `2`
+    pub heading_pairs: Option<Vec<String>>,
+
+    /// HyperlinkBase
+    pub hyperlink_base: Option<String>,
+
+    /// HyperlinksChanged
+    pub hyperlinks_changed: Option<bool>,
+
+    /// LinksUpToDate
+    pub links_up_to_date: Option<bool>,
+
+    /// Manager
+    pub manager: Option<String>,
+
+    /// ScaleCrop: display mode of the thumbnail
+    pub scale_crop: Option<bool>,
+
+    /// SharedDoc
+    pub shared_doc: Option<bool>,
+
+    /// Template
+    pub template: Option<String>,
+
+    /// TitlesOfParts: names of the parts of the document. Ex: sheet names and named ranges
+    pub titles_of_parts: Option<Vec<String>>,
+
+    /// TotalTime: total editing time in minutes
+    pub total_time: Option<u64>,
+}
+
+impl XlsxExtendedProperties {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            application: None,
+            app_version: None,
+            company: None,
+            doc_security: None,
+            heading_pairs: None,
+            hyperlink_base: None,
+            hyperlinks_changed: None,
+            links_up_to_date: None,
+            manager: None,
+            scale_crop: None,
+            shared_doc: None,
+            template: None,
+            titles_of_parts: None,
+            total_time: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(properties);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Properties" => {}
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"HeadingPairs" => {
+                    properties.heading_pairs = Some(load_vector(&mut reader, b"HeadingPairs")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"TitlesOfParts" => {
+                    properties.titles_of_parts = Some(load_vector(&mut reader, b"TitlesOfParts")?);
+                }
+                Ok(Event::Start(ref e)) => {
+                    let tag = e.local_name().as_ref().to_vec();
+                    let text = extract_text_contents(&mut reader, &tag)?.trim().to_string();
+                    match tag.as_slice() {
+                        b"Application" => properties.application = Some(text),
+                        b"AppVersion" => properties.app_version = Some(text),
+                        b"Company" => properties.company = Some(text),
+                        b"DocSecurity" => properties.doc_security = string_to_int(&text),
+                        b"HyperlinkBase" => properties.hyperlink_base = Some(text),
+                        b"HyperlinksChanged" => {
+                            properties.hyperlinks_changed = string_to_bool(&text)
+                        }
+                        b"LinksUpToDate" => properties.links_up_to_date = string_to_bool(&text),
+                        b"Manager" => properties.manager = Some(text),
+                        b"ScaleCrop" => properties.scale_crop = string_to_bool(&text),
+                        b"SharedDoc" => properties.shared_doc = string_to_bool(&text),
+                        b"Template" => properties.template = Some(text),
+                        b"TotalTime" => properties.total_time = string_to_unsignedint(&text),
+                        _ => {}
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"Properties" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(properties);
+    }
+}
+
+/// Values of a `vt:vector` within `tag`, in order.
+///
+/// `vt:variant` wrappers are flattened: each value element (`vt:lpstr`, `vt:i4`, ...) is one value.
+fn load_vector(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Vec<String>> {
+    let mut values: Vec<String> = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e))
+                if matches!(e.local_name().as_ref(), b"vector" | b"variant") => {}
+            Ok(Event::Start(ref e)) => {
+                let value_tag = e.local_name().as_ref().to_vec();
+                values.push(extract_text_contents(reader, &value_tag)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(values)
+}
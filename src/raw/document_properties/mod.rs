@@ -0,0 +1,6 @@
+// root of docProps/core.xml
+pub mod core_properties;
+// root of docProps/app.xml
+pub mod extended_properties;
+// root of docProps/custom.xml
+pub mod custom_properties;
@@ -0,0 +1,259 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool, string_to_float, string_to_unsignedint},
+    raw::drawing::{
+        shape::shape_properties::XlsxShapeProperties, text::shape_text_body::XlsxShapeTextBody,
+    },
+};
+
+use super::{
+    chart_lines::XlsxChartLines, number_format::XlsxChartNumberFormat, scaling::XlsxScaling,
+    title::XlsxChartTitle,
+};
+
+/// - CategoryAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.categoryaxis?view=openxml-3.0.1
+/// - ValueAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.valueaxis?view=openxml-3.0.1
+/// - DateAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.dateaxis?view=openxml-3.0.1
+/// - SeriesAxis: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.seriesaxis?view=openxml-3.0.1
+///
+/// An axis of the plot area.
+/// The four axis types share most of their children, so they are loaded into the same struct.
+///
+/// Example:
+/// ```
+/// <c:valAx>
+///     <c:axId val="500000002"/>
+///     <c:scaling><c:orientation val="minMax"/></c:scaling>
+///     <c:delete val="0"/>
+///     <c:axPos val="l"/>
+///     <c:majorGridlines/>
+///     <c:numFmt formatCode="General" sourceLinked="1"/>
+///     <c:majorTickMark val="none"/>
+///     <c:minorTickMark val="none"/>
+///     <c:tickLblPos val="nextTo"/>
+///     <c:crossAx val="500000001"/>
+///     <c:crosses val="autoZero"/>
+///     <c:crossBetween val="between"/>
+/// </c:valAx>
+/// ```
+///
+/// catAx (Category Axis Data), valAx (Value Axis), dateAx (Date Axis), serAx (Series Axis)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartAxis {
+    /// local name of the element: `catAx`, `valAx`, `dateAx` or `serAx`
+    pub axis_type: String,
+
+    // extLst (Chart Extensibility) Not supported
+    // dispUnits (Display Units) Not supported
+
+    // Child Elements
+    /// axId (Axis ID)
+    pub axis_id: Option<u64>,
+
+    /// axPos (Axis Position)
+    ///
+    /// * b: bottom
+    /// * l: left
+    /// * r: right
+    /// * t: top
+    pub axis_position: Option<String>,
+
+    /// crossAx (Crossing Axis ID)
+    pub cross_axis_id: Option<u64>,
+
+    /// crossBetween (Cross Between)
+    ///
+    /// Only on valAx.
+    /// * between: the value axis crosses the category axis between categories
+    /// * midCat: the value axis crosses the category axis at the midpoint of a category
+    pub cross_between: Option<String>,
+
+    /// crosses (Crosses)
+    ///
+    /// * autoZero
+    /// * max
+    /// * min
+    pub crosses: Option<String>,
+
+    /// crossesAt (Crossing Value)
+    pub crosses_at: Option<f64>,
+
+    /// delete (Delete)
+    pub delete: Option<bool>,
+
+    /// majorGridlines (Major Gridlines)
+    pub major_gridlines: Option<XlsxChartLines>,
+
+    /// majorTickMark (Major Tick Mark)
+    ///
+    /// * cross
+    /// * in
+    /// * none
+    /// * out
+    pub major_tick_mark: Option<String>,
+
+    /// majorUnit (Major Unit)
+    pub major_unit: Option<f64>,
+
+    /// minorGridlines (Minor Gridlines)
+    pub minor_gridlines: Option<XlsxChartLines>,
+
+    /// minorTickMark (Minor Tick Mark)
+    pub minor_tick_mark: Option<String>,
+
+    /// minorUnit (Minor Unit)
+    pub minor_unit: Option<f64>,
+
+    /// numFmt (Number Format)
+    pub number_format: Option<XlsxChartNumberFormat>,
+
+    /// scaling (Scaling)
+    pub scaling: Option<XlsxScaling>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+
+    /// txPr (Text Properties)
+    pub text_properties: Option<XlsxShapeTextBody>,
+
+    /// tickLblPos (Tick Label Position)
+    ///
+    /// * high
+    /// * low
+    /// * nextTo
+    /// * none
+    pub tick_label_position: Option<String>,
+
+    /// title (Title)
+    pub title: Option<XlsxChartTitle>,
+}
+
+impl XlsxChartAxis {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Self> {
+        let mut axis = Self {
+            axis_type: String::from_utf8(tag.to_vec())?,
+            axis_id: None,
+            axis_position: None,
+            cross_axis_id: None,
+            cross_between: None,
+            crosses: None,
+            crosses_at: None,
+            delete: None,
+            major_gridlines: None,
+            major_tick_mark: None,
+            major_unit: None,
+            minor_gridlines: None,
+            minor_tick_mark: None,
+            minor_unit: None,
+            number_format: None,
+            scaling: None,
+            shape_properties: None,
+            text_properties: None,
+            tick_label_position: None,
+            title: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"dispUnits" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"axId" => {
+                    axis.axis_id = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"axPos" => {
+                    axis.axis_position = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"crossAx" => {
+                    axis.cross_axis_id = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"crossBetween" => {
+                    axis.cross_between = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"crosses" => {
+                    axis.crosses = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"crossesAt" => {
+                    axis.crosses_at = match extract_val_attribute(e)? {
+                        Some(s) => string_to_float(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"delete" => {
+                    axis.delete = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"majorGridlines" => {
+                    axis.major_gridlines = Some(XlsxChartLines::load(reader, b"majorGridlines")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"majorTickMark" => {
+                    axis.major_tick_mark = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"majorUnit" => {
+                    axis.major_unit = match extract_val_attribute(e)? {
+                        Some(s) => string_to_float(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"minorGridlines" => {
+                    axis.minor_gridlines = Some(XlsxChartLines::load(reader, b"minorGridlines")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"minorTickMark" => {
+                    axis.minor_tick_mark = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"minorUnit" => {
+                    axis.minor_unit = match extract_val_attribute(e)? {
+                        Some(s) => string_to_float(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"numFmt" => {
+                    axis.number_format = Some(XlsxChartNumberFormat::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"scaling" => {
+                    axis.scaling = Some(XlsxScaling::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    axis.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"txPr" => {
+                    axis.text_properties = Some(XlsxShapeTextBody::load_with_tag(reader, b"txPr")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tickLblPos" => {
+                    axis.tick_label_position = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"title" => {
+                    axis.title = Some(XlsxChartTitle::load(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+                Ok(Event::Eof) => bail!(
+                    "unexpected end of file at `{}`.",
+                    String::from_utf8(tag.to_vec())?
+                ),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(axis)
+    }
+}
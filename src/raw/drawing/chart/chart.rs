@@ -0,0 +1,131 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool},
+};
+
+use super::{legend::XlsxLegend, plot_area::XlsxPlotArea, title::XlsxChartTitle};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.chart?view=openxml-3.0.1
+///
+/// This element specifies a title, plot area and legend of the chart.
+///
+/// Example:
+/// ```
+/// <c:chart>
+///     <c:title>...</c:title>
+///     <c:autoTitleDeleted val="0"/>
+///     <c:plotArea>...</c:plotArea>
+///     <c:legend>...</c:legend>
+///     <c:plotVisOnly val="1"/>
+///     <c:dispBlanksAs val="gap"/>
+/// </c:chart>
+/// ```
+///
+/// chart (Chart)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChart {
+    // extLst (Chart Extensibility) Not supported
+    // backWall, floor, sideWall, view3D Not supported
+    // pivotFmts (Pivot Formats) Not supported
+
+    // Child Elements
+    /// autoTitleDeleted (Auto Title Is Deleted)
+    pub auto_title_deleted: Option<bool>,
+
+    /// dispBlanksAs (Display Blanks As)
+    ///
+    /// * gap
+    /// * span
+    /// * zero
+    pub display_blanks_as: Option<String>,
+
+    /// legend (Legend)
+    pub legend: Option<XlsxLegend>,
+
+    /// plotArea (Plot Area)
+    pub plot_area: Option<XlsxPlotArea>,
+
+    /// plotVisOnly (Plot Visible Only)
+    ///
+    /// Specifies that only visible cells shall be plotted on the chart.
+    pub plot_visible_only: Option<bool>,
+
+    /// showDLblsOverMax (Show Data Labels over Maximum)
+    pub show_data_labels_over_max: Option<bool>,
+
+    /// title (Title)
+    pub title: Option<XlsxChartTitle>,
+}
+
+impl XlsxChart {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut chart = Self {
+            auto_title_deleted: None,
+            display_blanks_as: None,
+            legend: None,
+            plot_area: None,
+            plot_visible_only: None,
+            show_data_labels_over_max: None,
+            title: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"backWall"
+                        || e.local_name().as_ref() == b"floor"
+                        || e.local_name().as_ref() == b"sideWall"
+                        || e.local_name().as_ref() == b"view3D"
+                        || e.local_name().as_ref() == b"pivotFmts" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"autoTitleDeleted" => {
+                    chart.auto_title_deleted = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dispBlanksAs" => {
+                    chart.display_blanks_as = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"legend" => {
+                    chart.legend = Some(XlsxLegend::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"plotArea" => {
+                    chart.plot_area = Some(XlsxPlotArea::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"plotVisOnly" => {
+                    chart.plot_visible_only = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showDLblsOverMax" => {
+                    chart.show_data_labels_over_max = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"title" => {
+                    chart.title = Some(XlsxChartTitle::load(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"chart" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `chart`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(chart)
+    }
+}
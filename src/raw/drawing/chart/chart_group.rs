@@ -0,0 +1,220 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool, string_to_int, string_to_unsignedint},
+};
+
+use super::{data_labels::XlsxDataLabels, marker::XlsxMarker, series::XlsxChartSeries};
+
+/// - BarChart: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.barchart?view=openxml-3.0.1
+/// - LineChart: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.linechart?view=openxml-3.0.1
+/// - PieChart: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.piechart?view=openxml-3.0.1
+/// - DoughnutChart: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.doughnutchart?view=openxml-3.0.1
+/// - ScatterChart: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.scatterchart?view=openxml-3.0.1
+/// - AreaChart: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.areachart?view=openxml-3.0.1
+///
+/// A group of series plotted with the same chart type.
+/// A plot area with more than one group is a combo chart.
+///
+/// Example:
+/// ```
+/// <c:barChart>
+///     <c:barDir val="col"/>
+///     <c:grouping val="clustered"/>
+///     <c:varyColors val="0"/>
+///     <c:ser>...</c:ser>
+///     <c:gapWidth val="219"/>
+///     <c:overlap val="-27"/>
+///     <c:axId val="500000001"/>
+///     <c:axId val="500000002"/>
+/// </c:barChart>
+/// ```
+///
+/// barChart, bar3DChart, lineChart, line3DChart, pieChart, pie3DChart, doughnutChart, ofPieChart,
+/// scatterChart, areaChart, area3DChart, radarChart, bubbleChart, stockChart, surfaceChart, surface3DChart
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartGroup {
+    /// local name of the element, ex: `barChart`
+    pub chart_type: String,
+
+    // extLst (Chart Extensibility) Not supported
+    // dropLines, hiLowLines, serLines, upDownBars Not supported
+
+    // Child Elements
+    /// axId (Axis ID)
+    ///
+    /// Ids of the axes this group is plotted against.
+    pub axis_ids: Option<Vec<u64>>,
+
+    /// barDir (Bar Direction)
+    ///
+    /// * bar: horizontal
+    /// * col: vertical
+    pub bar_direction: Option<String>,
+
+    /// dLbls (Data Labels)
+    pub data_labels: Option<XlsxDataLabels>,
+
+    /// firstSliceAng (First Slice Angle)
+    pub first_slice_angle: Option<u64>,
+
+    /// gapWidth (Gap Width)
+    ///
+    /// Space between bar or column clusters, as a percentage of the bar or column width.
+    pub gap_width: Option<u64>,
+
+    /// grouping (Grouping)
+    ///
+    /// * clustered
+    /// * percentStacked
+    /// * stacked
+    /// * standard
+    pub grouping: Option<String>,
+
+    /// holeSize (Hole Size)
+    pub hole_size: Option<u64>,
+
+    /// marker (Show Marker)
+    ///
+    /// Only on lineChart.
+    pub marker: Option<bool>,
+
+    /// overlap (Overlap)
+    ///
+    /// -100 - 100
+    pub overlap: Option<i64>,
+
+    /// radarStyle (Radar Style)
+    pub radar_style: Option<String>,
+
+    /// scatterStyle (Scatter Style)
+    ///
+    /// * line
+    /// * lineMarker
+    /// * marker
+    /// * none
+    /// * smooth
+    /// * smoothMarker
+    pub scatter_style: Option<String>,
+
+    /// ser (Series)
+    pub series: Option<Vec<XlsxChartSeries>>,
+
+    /// varyColors (Vary Colors by Point)
+    pub vary_colors: Option<bool>,
+}
+
+impl XlsxChartGroup {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Self> {
+        let mut group = Self {
+            chart_type: String::from_utf8(tag.to_vec())?,
+            axis_ids: None,
+            bar_direction: None,
+            data_labels: None,
+            first_slice_angle: None,
+            gap_width: None,
+            grouping: None,
+            hole_size: None,
+            marker: None,
+            overlap: None,
+            radar_style: None,
+            scatter_style: None,
+            series: None,
+            vary_colors: None,
+        };
+        let mut axis_ids: Vec<u64> = vec![];
+        let mut series: Vec<XlsxChartSeries> = vec![];
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"dropLines"
+                        || e.local_name().as_ref() == b"hiLowLines"
+                        || e.local_name().as_ref() == b"serLines"
+                        || e.local_name().as_ref() == b"upDownBars"
+                        || e.local_name().as_ref() == b"bandFmts" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"axId" => {
+                    if let Some(id) = extract_val_attribute(e)? {
+                        if let Some(id) = string_to_unsignedint(&id) {
+                            axis_ids.push(id);
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"barDir" => {
+                    group.bar_direction = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dLbls" => {
+                    group.data_labels = Some(XlsxDataLabels::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"firstSliceAng" => {
+                    group.first_slice_angle = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"gapWidth" => {
+                    group.gap_width = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"grouping" => {
+                    group.grouping = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"holeSize" => {
+                    group.hole_size = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"marker" => {
+                    group.marker = XlsxMarker::load_flag(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"overlap" => {
+                    group.overlap = match extract_val_attribute(e)? {
+                        Some(s) => string_to_int(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"radarStyle" => {
+                    group.radar_style = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"scatterStyle" => {
+                    group.scatter_style = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ser" => {
+                    series.push(XlsxChartSeries::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"varyColors" => {
+                    group.vary_colors = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+                Ok(Event::Eof) => bail!(
+                    "unexpected end of file at `{}`.",
+                    String::from_utf8(tag.to_vec())?
+                ),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        group.axis_ids = Some(axis_ids);
+        group.series = Some(series);
+
+        Ok(group)
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{excel::XmlReader, raw::drawing::shape::shape_properties::XlsxShapeProperties};
+
+/// - MajorGridlines: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.majorgridlines?view=openxml-3.0.1
+/// - MinorGridlines: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.minorgridlines?view=openxml-3.0.1
+///
+/// Lines drawn on the chart, such as gridlines.
+///
+/// Example:
+/// ```
+/// <c:majorGridlines>
+///     <c:spPr>
+///         <a:ln w="9525"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill></a:ln>
+///     </c:spPr>
+/// </c:majorGridlines>
+/// ```
+///
+/// majorGridlines (Major Gridlines), minorGridlines (Minor Gridlines)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartLines {
+    // Child Elements
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+}
+
+impl XlsxChartLines {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Self> {
+        let mut lines = Self {
+            shape_properties: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    lines.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+                Ok(Event::Eof) => bail!(
+                    "unexpected end of file at `{}`.",
+                    String::from_utf8(tag.to_vec())?
+                ),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(lines)
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader, helper::extract_text_contents,
+    raw::drawing::text::shape_text_body::XlsxShapeTextBody,
+};
+
+use super::string_reference::XlsxStringReference;
+
+/// - ChartText: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.charttext?view=openxml-3.0.1
+/// - SeriesText: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.seriestext?view=openxml-3.0.1
+///
+/// Text for a title, label or series name.
+/// Either rich text, a reference to a cell (with a cache), or a literal value (series text only).
+///
+/// Example:
+/// ```
+/// <c:tx>
+///     <c:strRef>
+///         <c:f>Sheet1!$B$1</c:f>
+///         <c:strCache>
+///             <c:ptCount val="1"/>
+///             <c:pt idx="0"><c:v>Sales</c:v></c:pt>
+///         </c:strCache>
+///     </c:strRef>
+/// </c:tx>
+/// ```
+///
+/// tx (Chart Text / Series Text)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartText {
+    // Child Elements
+    /// rich (Rich Text)
+    pub rich_text: Option<XlsxShapeTextBody>,
+
+    /// strRef (String Reference)
+    pub string_reference: Option<XlsxStringReference>,
+
+    /// v (Text Value)
+    pub value: Option<String>,
+}
+
+impl XlsxChartText {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut text = Self {
+            rich_text: None,
+            string_reference: None,
+            value: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"rich" => {
+                    text.rich_text = Some(XlsxShapeTextBody::load_with_tag(reader, b"rich")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"strRef" => {
+                    text.string_reference = Some(XlsxStringReference::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"v" => {
+                    text.value = Some(extract_text_contents(reader, b"v")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"tx" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `tx`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(text)
+    }
+}
@@ -0,0 +1,188 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_text_contents, extract_val_attribute, string_to_bool},
+    raw::drawing::{
+        shape::shape_properties::XlsxShapeProperties, text::shape_text_body::XlsxShapeTextBody,
+    },
+};
+
+use super::number_format::XlsxChartNumberFormat;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.datalabels?view=openxml-3.0.1
+///
+/// This element serves as a root element that specifies the settings for the data labels for an entire series or the entire chart.
+///
+/// Example:
+/// ```
+/// <c:dLbls>
+///     <c:numFmt formatCode="0%" sourceLinked="0"/>
+///     <c:spPr><a:noFill/><a:ln><a:noFill/></a:ln></c:spPr>
+///     <c:dLblPos val="outEnd"/>
+///     <c:showLegendKey val="0"/>
+///     <c:showVal val="1"/>
+///     <c:showCatName val="0"/>
+///     <c:showSerName val="0"/>
+///     <c:showPercent val="0"/>
+///     <c:showBubbleSize val="0"/>
+/// </c:dLbls>
+/// ```
+///
+/// dLbls (Data Labels)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDataLabels {
+    // extLst (Chart Extensibility) Not supported
+    // dLbl (Data Label) Not supported
+    // leaderLines (Leader Lines) Not supported
+
+    // Child Elements
+    /// delete (Delete)
+    pub delete: Option<bool>,
+
+    /// dLblPos (Data Label Position)
+    ///
+    /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.datalabelpositionvalues?view=openxml-3.0.1
+    pub position: Option<String>,
+
+    /// numFmt (Number Format)
+    pub number_format: Option<XlsxChartNumberFormat>,
+
+    /// separator (Separator)
+    pub separator: Option<String>,
+
+    /// showBubbleSize (Show Bubble Size)
+    pub show_bubble_size: Option<bool>,
+
+    /// showCatName (Show Category Name)
+    pub show_category_name: Option<bool>,
+
+    /// showLeaderLines (Show Leader Lines)
+    pub show_leader_lines: Option<bool>,
+
+    /// showLegendKey (Show Legend Key)
+    pub show_legend_key: Option<bool>,
+
+    /// showPercent (Show Percent)
+    pub show_percent: Option<bool>,
+
+    /// showSerName (Show Series Name)
+    pub show_series_name: Option<bool>,
+
+    /// showVal (Show Value)
+    pub show_value: Option<bool>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+
+    /// txPr (Text Properties)
+    pub text_properties: Option<XlsxShapeTextBody>,
+}
+
+impl XlsxDataLabels {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut labels = Self {
+            delete: None,
+            position: None,
+            number_format: None,
+            separator: None,
+            show_bubble_size: None,
+            show_category_name: None,
+            show_leader_lines: None,
+            show_legend_key: None,
+            show_percent: None,
+            show_series_name: None,
+            show_value: None,
+            shape_properties: None,
+            text_properties: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"dLbl"
+                        || e.local_name().as_ref() == b"leaderLines" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"delete" => {
+                    labels.delete = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dLblPos" => {
+                    labels.position = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"numFmt" => {
+                    labels.number_format = Some(XlsxChartNumberFormat::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"separator" => {
+                    labels.separator = Some(extract_text_contents(reader, b"separator")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showBubbleSize" => {
+                    labels.show_bubble_size = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showCatName" => {
+                    labels.show_category_name = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showLeaderLines" => {
+                    labels.show_leader_lines = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showLegendKey" => {
+                    labels.show_legend_key = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showPercent" => {
+                    labels.show_percent = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showSerName" => {
+                    labels.show_series_name = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"showVal" => {
+                    labels.show_value = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    labels.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"txPr" => {
+                    labels.text_properties =
+                        Some(XlsxShapeTextBody::load_with_tag(reader, b"txPr")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"dLbls" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `dLbls`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(labels)
+    }
+}
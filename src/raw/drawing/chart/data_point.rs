@@ -0,0 +1,119 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool, string_to_unsignedint},
+    raw::drawing::shape::shape_properties::XlsxShapeProperties,
+};
+
+use super::marker::XlsxMarker;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.datapoint?view=openxml-3.0.1
+///
+/// This element specifies a single data point, overriding the formatting of the series for that point.
+///
+/// Example:
+/// ```
+/// <c:dPt>
+///     <c:idx val="1"/>
+///     <c:invertIfNegative val="0"/>
+///     <c:bubble3D val="0"/>
+///     <c:spPr>
+///         <a:solidFill><a:srgbClr val="FF0000"/></a:solidFill>
+///     </c:spPr>
+/// </c:dPt>
+/// ```
+///
+/// dPt (Data Point)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDataPoint {
+    // extLst (Chart Extensibility) Not supported
+    // pictureOptions (Picture Options) Not supported
+
+    // Child Elements
+    /// bubble3D (3D Bubble)
+    pub bubble_3d: Option<bool>,
+
+    /// explosion (Explosion)
+    ///
+    /// Amount the data point shall be moved from the center of the pie in percent.
+    pub explosion: Option<u64>,
+
+    /// idx (Index)
+    pub index: Option<u64>,
+
+    /// invertIfNegative (Invert if Negative)
+    pub invert_if_negative: Option<bool>,
+
+    /// marker (Marker)
+    pub marker: Option<XlsxMarker>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+}
+
+impl XlsxDataPoint {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut point = Self {
+            bubble_3d: None,
+            explosion: None,
+            index: None,
+            invert_if_negative: None,
+            marker: None,
+            shape_properties: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"pictureOptions" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"bubble3D" => {
+                    point.bubble_3d = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"explosion" => {
+                    point.explosion = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"idx" => {
+                    point.index = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"invertIfNegative" => {
+                    point.invert_if_negative = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"marker" => {
+                    point.marker = Some(XlsxMarker::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    point.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"dPt" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `dPt`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(point)
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::excel::XmlReader;
+
+use super::{
+    multi_level_string_reference::XlsxMultiLevelStringReference,
+    number_reference::{XlsxNumberData, XlsxNumberReference},
+    string_reference::{XlsxStringData, XlsxStringReference},
+};
+
+/// - CategoryAxisData: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.categoryaxisdata?view=openxml-3.0.1
+/// - Values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.values?view=openxml-3.0.1
+/// - XValues: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.xvalues?view=openxml-3.0.1
+/// - YValues: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.yvalues?view=openxml-3.0.1
+/// - BubbleSize: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.bubblesize?view=openxml-3.0.1
+///
+/// Data used by a series, as a reference to cells (with a cache) or as literal values.
+/// Only one of the children is expected to exist.
+///
+/// Example:
+/// ```
+/// <c:val>
+///     <c:numRef>
+///         <c:f>Sheet1!$B$2:$B$4</c:f>
+///         <c:numCache>
+///             <c:formatCode>General</c:formatCode>
+///             <c:ptCount val="3"/>
+///             <c:pt idx="0"><c:v>4.3</c:v></c:pt>
+///         </c:numCache>
+///     </c:numRef>
+/// </c:val>
+/// ```
+///
+/// cat (Category Axis Data), val (Values), xVal (X Values), yVal (Y Values), bubbleSize (Bubble Size)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartDataSource {
+    // Child Elements
+    /// multiLvlStrRef (Multi Level String Reference)
+    pub multi_level_string_reference: Option<XlsxMultiLevelStringReference>,
+
+    /// numLit (Number Literal)
+    pub number_literal: Option<XlsxNumberData>,
+
+    /// numRef (Number Reference)
+    pub number_reference: Option<XlsxNumberReference>,
+
+    /// strLit (String Literal)
+    pub string_literal: Option<XlsxStringData>,
+
+    /// strRef (String Reference)
+    pub string_reference: Option<XlsxStringReference>,
+}
+
+impl XlsxChartDataSource {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Self> {
+        let mut source = Self {
+            multi_level_string_reference: None,
+            number_literal: None,
+            number_reference: None,
+            string_literal: None,
+            string_reference: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"multiLvlStrRef" => {
+                    source.multi_level_string_reference =
+                        Some(XlsxMultiLevelStringReference::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"numLit" => {
+                    source.number_literal = Some(XlsxNumberData::load(reader, b"numLit")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"numRef" => {
+                    source.number_reference = Some(XlsxNumberReference::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"strLit" => {
+                    source.string_literal = Some(XlsxStringData::load(reader, b"strLit")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"strRef" => {
+                    source.string_reference = Some(XlsxStringReference::load(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+                Ok(Event::Eof) => bail!(
+                    "unexpected end of file at `{}`.",
+                    String::from_utf8(tag.to_vec())?
+                ),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(source)
+    }
+}
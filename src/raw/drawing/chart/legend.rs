@@ -0,0 +1,173 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool, string_to_unsignedint},
+    raw::drawing::{
+        shape::shape_properties::XlsxShapeProperties, text::shape_text_body::XlsxShapeTextBody,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.legend?view=openxml-3.0.1
+///
+/// This element specifies the legend.
+///
+/// Example:
+/// ```
+/// <c:legend>
+///     <c:legendPos val="r"/>
+///     <c:legendEntry>
+///         <c:idx val="1"/>
+///         <c:delete val="1"/>
+///     </c:legendEntry>
+///     <c:overlay val="0"/>
+/// </c:legend>
+/// ```
+///
+/// legend (Legend)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxLegend {
+    // extLst (Chart Extensibility) Not supported
+    // layout (Layout) Not supported
+
+    // Child Elements
+    /// legendEntry (Legend Entry)
+    pub legend_entries: Option<Vec<XlsxLegendEntry>>,
+
+    /// legendPos (Legend Position)
+    ///
+    /// * b: bottom
+    /// * l: left
+    /// * r: right
+    /// * t: top
+    /// * tr: top right
+    pub legend_position: Option<String>,
+
+    /// overlay (Overlay)
+    pub overlay: Option<bool>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+
+    /// txPr (Text Properties)
+    pub text_properties: Option<XlsxShapeTextBody>,
+}
+
+impl XlsxLegend {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut legend = Self {
+            legend_entries: None,
+            legend_position: None,
+            overlay: None,
+            shape_properties: None,
+            text_properties: None,
+        };
+        let mut entries: Vec<XlsxLegendEntry> = vec![];
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"layout" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"legendEntry" => {
+                    entries.push(XlsxLegendEntry::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"legendPos" => {
+                    legend.legend_position = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"overlay" => {
+                    legend.overlay = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    legend.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"txPr" => {
+                    legend.text_properties =
+                        Some(XlsxShapeTextBody::load_with_tag(reader, b"txPr")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"legend" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `legend`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        legend.legend_entries = Some(entries);
+
+        Ok(legend)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.legendentry?view=openxml-3.0.1
+///
+/// This element specifies a legend entry.
+///
+/// legendEntry (Legend Entry)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxLegendEntry {
+    // extLst (Chart Extensibility) Not supported
+    // txPr (Text Properties) Not supported
+
+    // Child Elements
+    /// delete (Delete)
+    ///
+    /// Specifies that the legend entry shall be deleted.
+    pub delete: Option<bool>,
+
+    /// idx (Index)
+    pub index: Option<u64>,
+}
+
+impl XlsxLegendEntry {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut entry = Self {
+            delete: None,
+            index: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"txPr" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"delete" => {
+                    entry.delete = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"idx" => {
+                    entry.index = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"legendEntry" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `legendEntry`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(entry)
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool, string_to_unsignedint},
+    raw::drawing::shape::shape_properties::XlsxShapeProperties,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.marker?view=openxml-3.0.1
+///
+/// This element specifies a data marker.
+///
+/// Example:
+/// ```
+/// <c:marker>
+///     <c:symbol val="circle"/>
+///     <c:size val="5"/>
+///     <c:spPr>
+///         <a:solidFill><a:schemeClr val="accent1"/></a:solidFill>
+///     </c:spPr>
+/// </c:marker>
+/// ```
+///
+/// marker (Marker)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxMarker {
+    // extLst (Chart Extensibility) Not supported
+
+    // Child Elements
+    /// size (Size)
+    ///
+    /// 2 - 72 points
+    pub size: Option<u64>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+
+    /// symbol (Symbol)
+    ///
+    /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.markerstylevalues?view=openxml-3.0.1
+    pub symbol: Option<String>,
+}
+
+impl XlsxMarker {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut marker = Self {
+            size: None,
+            shape_properties: None,
+            symbol: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"size" => {
+                    marker.size = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    marker.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"symbol" => {
+                    marker.symbol = extract_val_attribute(e)?;
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"marker" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `marker`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(marker)
+    }
+
+    /// `marker` can also be a boolean flag (`<c:marker val="1"/>`) on line charts.
+    ///
+    /// None if the element does not specify a `val`.
+    pub(crate) fn load_flag(e: &BytesStart) -> anyhow::Result<Option<bool>> {
+        return Ok(match extract_val_attribute(e)? {
+            Some(s) => string_to_bool(&s),
+            None => None,
+        });
+    }
+}
@@ -0,0 +1,165 @@
+pub mod axis;
+pub mod chart;
+pub mod chart_group;
+pub mod chart_lines;
+pub mod chart_text;
+pub mod data_labels;
+pub mod data_point;
+pub mod data_source;
+pub mod legend;
+pub mod marker;
+pub mod multi_level_string_reference;
+pub mod number_format;
+pub mod number_reference;
+pub mod plot_area;
+pub mod scaling;
+pub mod series;
+pub mod string_reference;
+pub mod title;
+
+use anyhow::bail;
+use chart::XlsxChart;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{extract_val_attribute, string_to_bool, string_to_unsignedint},
+    raw::drawing::{
+        shape::shape_properties::XlsxShapeProperties, text::shape_text_body::XlsxShapeTextBody,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.chartspace?view=openxml-3.0.1
+///
+/// Root element of xl/charts/chart{}.xml.
+///
+/// Example:
+/// ```
+/// <c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart"
+///     xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+///     xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+///     <c:date1904 val="0"/>
+///     <c:lang val="en-US"/>
+///     <c:roundedCorners val="0"/>
+///     <c:chart>...</c:chart>
+///     <c:spPr>...</c:spPr>
+///     <c:txPr>...</c:txPr>
+/// </c:chartSpace>
+/// ```
+///
+/// chartSpace (Chart Space)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartSpace {
+    // clrMapOvr (Color Map Override) Not supported
+    // externalData (External Data Relationship) Not supported
+    // extLst (Chart Extensibility) Not supported
+    // pivotSource (Pivot Source) Not supported
+    // printSettings (Print Settings) Not supported
+    // protection (Protection) Not supported
+    // userShapes (User Shapes) Not supported
+
+    // Child Elements
+    /// chart (Chart)
+    pub chart: Option<XlsxChart>,
+
+    /// date1904 (Date1904)
+    pub date1904: Option<bool>,
+
+    /// lang (Editing Language)
+    pub lang: Option<String>,
+
+    /// roundedCorners (Rounded Corners)
+    pub rounded_corners: Option<bool>,
+
+    /// spPr (Shape Properties)
+    ///
+    /// Properties of the chart area.
+    pub shape_properties: Option<XlsxShapeProperties>,
+
+    /// style (Style)
+    ///
+    /// 1 - 48
+    pub style: Option<u64>,
+
+    /// txPr (Text Properties)
+    ///
+    /// Default text properties of the chart.
+    pub text_properties: Option<XlsxShapeTextBody>,
+}
+
+impl XlsxChartSpace {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut chart_space = Self {
+            chart: None,
+            date1904: None,
+            lang: None,
+            rounded_corners: None,
+            shape_properties: None,
+            style: None,
+            text_properties: None,
+        };
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(chart_space);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"AlternateContent"
+                        || e.local_name().as_ref() == b"clrMapOvr"
+                        || e.local_name().as_ref() == b"externalData"
+                        || e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"pivotSource"
+                        || e.local_name().as_ref() == b"printSettings"
+                        || e.local_name().as_ref() == b"protection"
+                        || e.local_name().as_ref() == b"userShapes" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"chart" => {
+                    chart_space.chart = Some(XlsxChart::load(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"date1904" => {
+                    chart_space.date1904 = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"lang" => {
+                    chart_space.lang = extract_val_attribute(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"roundedCorners" => {
+                    chart_space.rounded_corners = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    chart_space.shape_properties = Some(XlsxShapeProperties::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"style" => {
+                    chart_space.style = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"txPr" => {
+                    chart_space.text_properties =
+                        Some(XlsxShapeTextBody::load_with_tag(&mut reader, b"txPr")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"chartSpace" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(chart_space)
+    }
+}
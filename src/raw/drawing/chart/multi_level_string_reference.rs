@@ -0,0 +1,93 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_text_contents, extract_val_attribute, string_to_unsignedint},
+};
+
+use super::string_reference::XlsxStringData;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.multilevelstringreference?view=openxml-3.0.1
+///
+/// This element specifies a reference to data for multi-level category labels (ex: Year / Quarter) with a cache of the last values used.
+///
+/// Example:
+/// ```
+/// <c:multiLvlStrRef>
+///     <c:f>Sheet1!$A$2:$B$5</c:f>
+///     <c:multiLvlStrCache>
+///         <c:ptCount val="4"/>
+///         <c:lvl>
+///             <c:pt idx="0"><c:v>Q1</c:v></c:pt>
+///             <c:pt idx="1"><c:v>Q2</c:v></c:pt>
+///         </c:lvl>
+///         <c:lvl>
+///             <c:pt idx="0"><c:v>2024</c:v></c:pt>
+///         </c:lvl>
+///     </c:multiLvlStrCache>
+/// </c:multiLvlStrRef>
+/// ```
+///
+/// multiLvlStrRef (Multi Level String Reference)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxMultiLevelStringReference {
+    // extLst (Chart Extensibility) Not supported
+
+    // Child Elements
+    /// f (Formula)
+    pub formula: Option<String>,
+
+    // multiLvlStrCache (Multi Level String Cache)
+    /// ptCount (Point Count)
+    pub point_count: Option<u64>,
+
+    /// lvl (Level)
+    ///
+    /// The first level is the innermost one (closest to the axis).
+    pub levels: Option<Vec<XlsxStringData>>,
+}
+
+impl XlsxMultiLevelStringReference {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut reference = Self {
+            formula: None,
+            point_count: None,
+            levels: None,
+        };
+        let mut levels: Vec<XlsxStringData> = vec![];
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"f" => {
+                    reference.formula = Some(extract_text_contents(reader, b"f")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ptCount" => {
+                    reference.point_count = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"lvl" => {
+                    levels.push(XlsxStringData::load(reader, b"lvl")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"multiLvlStrRef" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `multiLvlStrRef`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        reference.levels = Some(levels);
+
+        Ok(reference)
+    }
+}
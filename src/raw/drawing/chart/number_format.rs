@@ -0,0 +1,54 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.numberingformat?view=openxml-3.0.1
+///
+/// This element specifies number formatting for the parent element.
+///
+/// Example:
+/// ```
+/// <c:numFmt formatCode="General" sourceLinked="1"/>
+/// ```
+///
+/// numFmt (Number Format)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartNumberFormat {
+    // Attributes
+    /// formatCode (Number Format Code)
+    pub format_code: Option<String>,
+
+    /// sourceLinked (Linked to Source)
+    ///
+    /// Specifies whether the number format is linked to the cells that hold the data.
+    pub source_linked: Option<bool>,
+}
+
+impl XlsxChartNumberFormat {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut format = Self {
+            format_code: None,
+            source_linked: None,
+        };
+
+        let attributes = e.attributes();
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"formatCode" => format.format_code = Some(string_value),
+                        b"sourceLinked" => format.source_linked = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(format)
+    }
+}
@@ -0,0 +1,209 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_text_contents, extract_val_attribute, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.numberreference?view=openxml-3.0.1
+///
+/// This element specifies a reference to numeric data with a cache of the last values used.
+///
+/// Example:
+/// ```
+/// <c:numRef>
+///     <c:f>Sheet1!$B$2:$B$4</c:f>
+///     <c:numCache>
+///         <c:formatCode>General</c:formatCode>
+///         <c:ptCount val="3"/>
+///         <c:pt idx="0"><c:v>4.3</c:v></c:pt>
+///         <c:pt idx="1"><c:v>2.5</c:v></c:pt>
+///         <c:pt idx="2"><c:v>3.5</c:v></c:pt>
+///     </c:numCache>
+/// </c:numRef>
+/// ```
+///
+/// numRef (Number Reference)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxNumberReference {
+    // extLst (Chart Extensibility) Not supported
+
+    // Child Elements
+    /// f (Formula)
+    pub formula: Option<String>,
+
+    /// numCache (Number Cache)
+    pub number_cache: Option<XlsxNumberData>,
+}
+
+impl XlsxNumberReference {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut reference = Self {
+            formula: None,
+            number_cache: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"f" => {
+                    reference.formula = Some(extract_text_contents(reader, b"f")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"numCache" => {
+                    reference.number_cache = Some(XlsxNumberData::load(reader, b"numCache")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"numRef" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `numRef`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(reference)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.numberingcache?view=openxml-3.0.1
+///
+/// Numeric data points, either cached from a reference (`numCache`) or given literally (`numLit`).
+///
+/// numCache (Number Cache), numLit (Number Literal)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxNumberData {
+    // extLst (Chart Extensibility) Not supported
+
+    // Child Elements
+    /// formatCode (Format Code)
+    pub format_code: Option<String>,
+
+    /// ptCount (Point Count)
+    pub point_count: Option<u64>,
+
+    /// pt (Numeric Point)
+    pub points: Option<Vec<XlsxNumericPoint>>,
+}
+
+impl XlsxNumberData {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Self> {
+        let mut data = Self {
+            format_code: None,
+            point_count: None,
+            points: None,
+        };
+        let mut points: Vec<XlsxNumericPoint> = vec![];
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"formatCode" => {
+                    data.format_code = Some(extract_text_contents(reader, b"formatCode")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ptCount" => {
+                    data.point_count = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pt" => {
+                    points.push(XlsxNumericPoint::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+                Ok(Event::Eof) => bail!(
+                    "unexpected end of file at `{}`.",
+                    String::from_utf8(tag.to_vec())?
+                ),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        data.points = Some(points);
+
+        Ok(data)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.numericpoint?view=openxml-3.0.1
+///
+/// This element specifies data for a particular data point.
+///
+/// Example:
+/// ```
+/// <c:pt idx="0" formatCode="0.0%">
+///     <c:v>0.25</c:v>
+/// </c:pt>
+/// ```
+///
+/// pt (Numeric Point)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxNumericPoint {
+    // Child Elements
+    /// v (Numeric Value)
+    pub value: Option<String>,
+
+    // Attributes
+    /// formatCode (Number Format)
+    pub format_code: Option<String>,
+
+    /// idx (Index)
+    pub index: Option<u64>,
+}
+
+impl XlsxNumericPoint {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut point = Self {
+            value: None,
+            format_code: None,
+            index: None,
+        };
+
+        let attributes = e.attributes();
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"formatCode" => point.format_code = Some(string_value),
+                        b"idx" => point.index = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"v" => {
+                    point.value = Some(extract_text_contents(reader, b"v")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pt" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `pt`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(point)
+    }
+}
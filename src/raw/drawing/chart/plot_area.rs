@@ -0,0 +1,110 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{excel::XmlReader, raw::drawing::shape::shape_properties::XlsxShapeProperties};
+
+use super::{axis::XlsxChartAxis, chart_group::XlsxChartGroup};
+
+/// chart group elements that can appear in a plot area
+pub(crate) const CHART_GROUP_TAGS: [&[u8]; 16] = [
+    b"areaChart",
+    b"area3DChart",
+    b"barChart",
+    b"bar3DChart",
+    b"bubbleChart",
+    b"doughnutChart",
+    b"lineChart",
+    b"line3DChart",
+    b"ofPieChart",
+    b"pieChart",
+    b"pie3DChart",
+    b"radarChart",
+    b"scatterChart",
+    b"stockChart",
+    b"surfaceChart",
+    b"surface3DChart",
+];
+
+/// axis elements that can appear in a plot area
+pub(crate) const AXIS_TAGS: [&[u8]; 4] = [b"catAx", b"dateAx", b"serAx", b"valAx"];
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.plotarea?view=openxml-3.0.1
+///
+/// This element specifies the plot area of the chart.
+///
+/// Example:
+/// ```
+/// <c:plotArea>
+///     <c:layout/>
+///     <c:barChart>...</c:barChart>
+///     <c:lineChart>...</c:lineChart>
+///     <c:catAx>...</c:catAx>
+///     <c:valAx>...</c:valAx>
+///     <c:spPr><a:noFill/></c:spPr>
+/// </c:plotArea>
+/// ```
+///
+/// plotArea (Plot Area)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPlotArea {
+    // extLst (Chart Extensibility) Not supported
+    // dTable (Data Table) Not supported
+    // layout (Layout) Not supported
+
+    // Child Elements
+    /// catAx, dateAx, serAx, valAx
+    pub axes: Option<Vec<XlsxChartAxis>>,
+
+    /// barChart, lineChart, pieChart, ...
+    pub chart_groups: Option<Vec<XlsxChartGroup>>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+}
+
+impl XlsxPlotArea {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut plot_area = Self {
+            axes: None,
+            chart_groups: None,
+            shape_properties: None,
+        };
+        let mut axes: Vec<XlsxChartAxis> = vec![];
+        let mut groups: Vec<XlsxChartGroup> = vec![];
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"dTable"
+                        || e.local_name().as_ref() == b"layout" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if CHART_GROUP_TAGS.contains(&e.local_name().as_ref()) => {
+                    groups.push(XlsxChartGroup::load(reader, e.local_name().as_ref())?);
+                }
+                Ok(Event::Start(ref e)) if AXIS_TAGS.contains(&e.local_name().as_ref()) => {
+                    axes.push(XlsxChartAxis::load(reader, e.local_name().as_ref())?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    plot_area.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"plotArea" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `plotArea`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        plot_area.axes = Some(axes);
+        plot_area.chart_groups = Some(groups);
+
+        Ok(plot_area)
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_float},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.scaling?view=openxml-3.0.1
+///
+/// This element contains additional axis settings.
+///
+/// Example:
+/// ```
+/// <c:scaling>
+///     <c:orientation val="minMax"/>
+///     <c:max val="100"/>
+///     <c:min val="0"/>
+/// </c:scaling>
+/// ```
+///
+/// scaling (Scaling)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxScaling {
+    // extLst (Chart Extensibility) Not supported
+
+    // Child Elements
+    /// logBase (Logarithmic Base)
+    pub log_base: Option<f64>,
+
+    /// max (Maximum)
+    pub max: Option<f64>,
+
+    /// min (Minimum)
+    pub min: Option<f64>,
+
+    /// orientation (Axis Orientation)
+    ///
+    /// * maxMin
+    /// * minMax
+    pub orientation: Option<String>,
+}
+
+impl XlsxScaling {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut scaling = Self {
+            log_base: None,
+            max: None,
+            min: None,
+            orientation: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"logBase" => {
+                    scaling.log_base = match extract_val_attribute(e)? {
+                        Some(s) => string_to_float(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"max" => {
+                    scaling.max = match extract_val_attribute(e)? {
+                        Some(s) => string_to_float(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"min" => {
+                    scaling.min = match extract_val_attribute(e)? {
+                        Some(s) => string_to_float(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"orientation" => {
+                    scaling.orientation = extract_val_attribute(e)?;
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"scaling" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `scaling`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(scaling)
+    }
+}
@@ -0,0 +1,218 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool, string_to_unsignedint},
+    raw::drawing::shape::shape_properties::XlsxShapeProperties,
+};
+
+use super::{
+    chart_text::XlsxChartText, data_labels::XlsxDataLabels, data_point::XlsxDataPoint,
+    data_source::XlsxChartDataSource, marker::XlsxMarker,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.barchartseries?view=openxml-3.0.1
+///
+/// A series of a chart group.
+/// Series of all chart types (bar, line, pie, scatter, area, ...) are loaded into the same struct,
+/// children not applicable to a chart type are None.
+///
+/// Example:
+/// ```
+/// <c:ser>
+///     <c:idx val="0"/>
+///     <c:order val="0"/>
+///     <c:tx>
+///         <c:strRef>
+///             <c:f>Sheet1!$B$1</c:f>
+///             <c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>Sales</c:v></c:pt></c:strCache>
+///         </c:strRef>
+///     </c:tx>
+///     <c:spPr><a:solidFill><a:schemeClr val="accent1"/></a:solidFill></c:spPr>
+///     <c:invertIfNegative val="0"/>
+///     <c:cat>
+///         <c:strRef><c:f>Sheet1!$A$2:$A$4</c:f></c:strRef>
+///     </c:cat>
+///     <c:val>
+///         <c:numRef><c:f>Sheet1!$B$2:$B$4</c:f></c:numRef>
+///     </c:val>
+/// </c:ser>
+/// ```
+///
+/// ser (Series)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartSeries {
+    // extLst (Chart Extensibility) Not supported
+    // errBars (Error Bars) Not supported
+    // pictureOptions (Picture Options) Not supported
+    // shape (Shape) Not supported
+    // trendline (Trendlines) Not supported
+
+    // Child Elements
+    /// bubble3D (3D Bubble)
+    pub bubble_3d: Option<bool>,
+
+    /// bubbleSize (Bubble Size)
+    pub bubble_size: Option<XlsxChartDataSource>,
+
+    /// cat (Category Axis Data)
+    pub categories: Option<XlsxChartDataSource>,
+
+    /// dLbls (Data Labels)
+    pub data_labels: Option<XlsxDataLabels>,
+
+    /// dPt (Data Point)
+    pub data_points: Option<Vec<XlsxDataPoint>>,
+
+    /// explosion (Explosion)
+    pub explosion: Option<u64>,
+
+    /// idx (Index)
+    ///
+    /// Index of the series, used to pick the default (theme) color.
+    pub index: Option<u64>,
+
+    /// invertIfNegative (Invert if Negative)
+    pub invert_if_negative: Option<bool>,
+
+    /// marker (Marker)
+    pub marker: Option<XlsxMarker>,
+
+    /// order (Order)
+    pub order: Option<u64>,
+
+    /// smooth (Smoothing)
+    pub smooth: Option<bool>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+
+    /// tx (Series Text)
+    pub text: Option<XlsxChartText>,
+
+    /// val (Values)
+    pub values: Option<XlsxChartDataSource>,
+
+    /// xVal (X Values)
+    pub x_values: Option<XlsxChartDataSource>,
+
+    /// yVal (Y Values)
+    pub y_values: Option<XlsxChartDataSource>,
+}
+
+impl XlsxChartSeries {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut series = Self {
+            bubble_3d: None,
+            bubble_size: None,
+            categories: None,
+            data_labels: None,
+            data_points: None,
+            explosion: None,
+            index: None,
+            invert_if_negative: None,
+            marker: None,
+            order: None,
+            smooth: None,
+            shape_properties: None,
+            text: None,
+            values: None,
+            x_values: None,
+            y_values: None,
+        };
+        let mut data_points: Vec<XlsxDataPoint> = vec![];
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"errBars"
+                        || e.local_name().as_ref() == b"pictureOptions"
+                        || e.local_name().as_ref() == b"trendline" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"bubble3D" => {
+                    series.bubble_3d = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"bubbleSize" => {
+                    series.bubble_size = Some(XlsxChartDataSource::load(reader, b"bubbleSize")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cat" => {
+                    series.categories = Some(XlsxChartDataSource::load(reader, b"cat")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dLbls" => {
+                    series.data_labels = Some(XlsxDataLabels::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dPt" => {
+                    data_points.push(XlsxDataPoint::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"explosion" => {
+                    series.explosion = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"idx" => {
+                    series.index = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"invertIfNegative" => {
+                    series.invert_if_negative = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"marker" => {
+                    series.marker = Some(XlsxMarker::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"order" => {
+                    series.order = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"smooth" => {
+                    series.smooth = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    series.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tx" => {
+                    series.text = Some(XlsxChartText::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"val" => {
+                    series.values = Some(XlsxChartDataSource::load(reader, b"val")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"xVal" => {
+                    series.x_values = Some(XlsxChartDataSource::load(reader, b"xVal")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"yVal" => {
+                    series.y_values = Some(XlsxChartDataSource::load(reader, b"yVal")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ser" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `ser`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        series.data_points = Some(data_points);
+
+        Ok(series)
+    }
+}
@@ -0,0 +1,194 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_text_contents, extract_val_attribute, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.stringreference?view=openxml-3.0.1
+///
+/// This element specifies a reference to data for a single data label or title with a cache of the last values used.
+///
+/// Example:
+/// ```
+/// <c:strRef>
+///     <c:f>Sheet1!$B$1</c:f>
+///     <c:strCache>
+///         <c:ptCount val="1"/>
+///         <c:pt idx="0"><c:v>Series 1</c:v></c:pt>
+///     </c:strCache>
+/// </c:strRef>
+/// ```
+///
+/// strRef (String Reference)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxStringReference {
+    // extLst (Chart Extensibility) Not supported
+
+    // Child Elements
+    /// f (Formula)
+    pub formula: Option<String>,
+
+    /// strCache (String Cache)
+    pub string_cache: Option<XlsxStringData>,
+}
+
+impl XlsxStringReference {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut reference = Self {
+            formula: None,
+            string_cache: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"f" => {
+                    reference.formula = Some(extract_text_contents(reader, b"f")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"strCache" => {
+                    reference.string_cache = Some(XlsxStringData::load(reader, b"strCache")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"strRef" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `strRef`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(reference)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.stringcache?view=openxml-3.0.1
+///
+/// String data points, either cached from a reference (`strCache`), given literally (`strLit`), or a level of a multi-level cache (`lvl`).
+///
+/// strCache (String Cache), strLit (String Literal), lvl (Level)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxStringData {
+    // extLst (Chart Extensibility) Not supported
+
+    // Child Elements
+    /// ptCount (Point Count)
+    pub point_count: Option<u64>,
+
+    /// pt (String Point)
+    pub points: Option<Vec<XlsxStringPoint>>,
+}
+
+impl XlsxStringData {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, tag: &[u8]) -> anyhow::Result<Self> {
+        let mut data = Self {
+            point_count: None,
+            points: None,
+        };
+        let mut points: Vec<XlsxStringPoint> = vec![];
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ptCount" => {
+                    data.point_count = match extract_val_attribute(e)? {
+                        Some(s) => string_to_unsignedint(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pt" => {
+                    points.push(XlsxStringPoint::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+                Ok(Event::Eof) => bail!(
+                    "unexpected end of file at `{}`.",
+                    String::from_utf8(tag.to_vec())?
+                ),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        data.points = Some(points);
+
+        Ok(data)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.stringpoint?view=openxml-3.0.1
+///
+/// This element specifies data for a particular data point.
+///
+/// Example:
+/// ```
+/// <c:pt idx="0">
+///     <c:v>Category 1</c:v>
+/// </c:pt>
+/// ```
+///
+/// pt (String Point)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxStringPoint {
+    // Child Elements
+    /// v (Text Value)
+    pub value: Option<String>,
+
+    // Attributes
+    /// idx (Index)
+    pub index: Option<u64>,
+}
+
+impl XlsxStringPoint {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut point = Self {
+            value: None,
+            index: None,
+        };
+
+        let attributes = e.attributes();
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"idx" => point.index = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"v" => {
+                    point.value = Some(extract_text_contents(reader, b"v")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pt" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `pt`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(point)
+    }
+}
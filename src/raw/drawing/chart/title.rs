@@ -0,0 +1,102 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{extract_val_attribute, string_to_bool},
+    raw::drawing::{
+        shape::shape_properties::XlsxShapeProperties, text::shape_text_body::XlsxShapeTextBody,
+    },
+};
+
+use super::chart_text::XlsxChartText;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.charts.title?view=openxml-3.0.1
+///
+/// This element specifies a title for the chart or an axis.
+///
+/// Example:
+/// ```
+/// <c:title>
+///     <c:tx>
+///         <c:rich>
+///             <a:bodyPr/>
+///             <a:p><a:r><a:t>Sales by Region</a:t></a:r></a:p>
+///         </c:rich>
+///     </c:tx>
+///     <c:overlay val="0"/>
+/// </c:title>
+/// ```
+///
+/// title (Title)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartTitle {
+    // extLst (Chart Extensibility) Not supported
+    // layout (Layout) Not supported
+
+    // Child Elements
+    /// overlay (Overlay)
+    ///
+    /// Specifies that the title shall be allowed to overlap the plot area.
+    pub overlay: Option<bool>,
+
+    /// spPr (Shape Properties)
+    pub shape_properties: Option<XlsxShapeProperties>,
+
+    /// tx (Chart Text)
+    ///
+    /// None if the title text is generated automatically (ex: series name for single-series charts).
+    pub text: Option<XlsxChartText>,
+
+    /// txPr (Text Properties)
+    pub text_properties: Option<XlsxShapeTextBody>,
+}
+
+impl XlsxChartTitle {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut title = Self {
+            overlay: None,
+            shape_properties: None,
+            text: None,
+            text_properties: None,
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"extLst"
+                        || e.local_name().as_ref() == b"layout" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"overlay" => {
+                    title.overlay = match extract_val_attribute(e)? {
+                        Some(s) => string_to_bool(&s),
+                        None => None,
+                    };
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"spPr" => {
+                    title.shape_properties = Some(XlsxShapeProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tx" => {
+                    title.text = Some(XlsxChartText::load(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"txPr" => {
+                    title.text_properties =
+                        Some(XlsxShapeTextBody::load_with_tag(reader, b"txPr")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"title" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `title`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(title)
+    }
+}
@@ -0,0 +1,331 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::string_to_unsignedint,
+    raw::drawing::text::shape_text_body::XlsxShapeTextBody,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.datamodelroot?view=openxml-3.0.1
+///
+/// Content of a diagram (SmartArt): the points (nodes, transitions and presentation points) and the connections between them.
+///
+/// Example:
+/// ```
+/// <dgm:dataModel xmlns:dgm="http://schemas.openxmlformats.org/drawingml/2006/diagram"
+///     xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+///     <dgm:ptLst>
+///         <dgm:pt modelId="{9F8D1A39-0A34-4C0F-9E3B-4B1A2C3D4E50}" type="doc">
+///             <dgm:prSet loTypeId="urn:microsoft.com/office/officeart/2005/8/layout/orgChart1" qsTypeId="urn:microsoft.com/office/officeart/2005/8/quickstyle/simple1" csTypeId="urn:microsoft.com/office/officeart/2005/8/colors/accent1_2"/>
+///             <dgm:spPr/>
+///             <dgm:t><a:bodyPr/><a:lstStyle/><a:p><a:endParaRPr lang="en-US"/></a:p></dgm:t>
+///         </dgm:pt>
+///         <dgm:pt modelId="{1}">
+///             <dgm:prSet phldrT="[Text]"/>
+///             <dgm:spPr/>
+///             <dgm:t><a:bodyPr/><a:lstStyle/><a:p><a:r><a:rPr lang="en-US"/><a:t>CEO</a:t></a:r></a:p></dgm:t>
+///         </dgm:pt>
+///     </dgm:ptLst>
+///     <dgm:cxnLst>
+///         <dgm:cxn modelId="{2}" srcId="{9F8D1A39-0A34-4C0F-9E3B-4B1A2C3D4E50}" destId="{1}" srcOrd="0" destOrd="0" parTransId="{3}" sibTransId="{4}"/>
+///     </dgm:cxnLst>
+///     <dgm:bg/>
+///     <dgm:whole/>
+///     <dgm:extLst>
+///         <a:ext uri="http://schemas.microsoft.com/office/drawing/2008/diagram">
+///             <dsp:dataModelExt xmlns:dsp="http://schemas.microsoft.com/office/drawing/2008/diagram" relId="rId6" minVer="http://schemas.openxmlformats.org/drawingml/2006/diagram"/>
+///         </a:ext>
+///     </dgm:extLst>
+/// </dgm:dataModel>
+/// ```
+/// dataModel (Data Model)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDiagramDataModel {
+    // Child Elements
+    // bg (Background Formatting), whole (Whole E2O Formatting) Not supported
+    /// pt (Point) of the ptLst
+    pub points: Vec<XlsxDiagramPoint>,
+
+    /// cxn (Connection) of the cxnLst
+    pub connections: Vec<XlsxDiagramConnection>,
+
+    /// dsp:dataModelExt relId
+    ///
+    /// Relationship id of the pre-rendered drawing part (xl/diagrams/drawing{}.xml) in the drawing relationships.
+    pub drawing_id: Option<String>,
+}
+
+impl XlsxDiagramDataModel {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut data_model = Self {
+            points: vec![],
+            connections: vec![],
+            drawing_id: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(data_model);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pt" => {
+                    data_model
+                        .points
+                        .push(XlsxDiagramPoint::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cxn" => {
+                    data_model.connections.push(XlsxDiagramConnection::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dataModelExt" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"relId" => data_model.drawing_id = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"bg" || e.local_name().as_ref() == b"whole" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"dataModel" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(data_model)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.point?view=openxml-3.0.1
+///
+/// A point of the data model: a node with its text, a transition between nodes or a presentation point of the layout.
+///
+/// Example:
+/// ```
+/// <dgm:pt modelId="{1}">
+///     <dgm:prSet phldrT="[Text]"/>
+///     <dgm:spPr/>
+///     <dgm:t><a:bodyPr/><a:lstStyle/><a:p><a:r><a:rPr lang="en-US"/><a:t>CEO</a:t></a:r></a:p></dgm:t>
+/// </dgm:pt>
+/// ```
+/// pt (Point)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDiagramPoint {
+    // Child Elements
+    // spPr (Shape Properties), extLst Not supported
+    /// t (Text Body)
+    pub text_body: Option<XlsxShapeTextBody>,
+
+    // prSet (Property Set) attributes
+    /// presName (Presentation Name): name of the layout node the presentation point is created for
+    pub presentation_name: Option<String>,
+
+    /// presAssocID (Presentation Element Identifier): model id of the point the presentation point represents
+    pub presentation_association_id: Option<String>,
+
+    /// loTypeId (Layout Definition Identifier). Only on the document point.
+    pub layout_type_id: Option<String>,
+
+    /// qsTypeId (Quick Style Identifier). Only on the document point.
+    pub quick_style_type_id: Option<String>,
+
+    /// csTypeId (Color Transform Identifier). Only on the document point.
+    pub color_type_id: Option<String>,
+
+    // attributes
+    /// cxnId (Connection Identifier): connection of a parTrans or sibTrans point
+    pub connection_id: Option<String>,
+
+    /// modelId (Model Identifier)
+    pub model_id: Option<String>,
+
+    /// type (Point Type)
+    ///
+    /// Possible values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.pointvalues?view=openxml-3.0.1
+    /// * node (default), asst, doc, pres, parTrans, sibTrans
+    pub r#type: Option<String>,
+}
+
+impl XlsxDiagramPoint {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut point = Self {
+            text_body: None,
+            presentation_name: None,
+            presentation_association_id: None,
+            layout_type_id: None,
+            quick_style_type_id: None,
+            color_type_id: None,
+            connection_id: None,
+            model_id: None,
+            r#type: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"cxnId" => point.connection_id = Some(string_value),
+                        b"modelId" => point.model_id = Some(string_value),
+                        b"type" => point.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"prSet" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"presName" => point.presentation_name = Some(string_value),
+                                    b"presAssocID" => {
+                                        point.presentation_association_id = Some(string_value)
+                                    }
+                                    b"loTypeId" => point.layout_type_id = Some(string_value),
+                                    b"qsTypeId" => point.quick_style_type_id = Some(string_value),
+                                    b"csTypeId" => point.color_type_id = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"t" => {
+                    point.text_body = Some(XlsxShapeTextBody::load_with_tag(reader, b"t")?);
+                }
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"spPr"
+                        || e.local_name().as_ref() == b"extLst" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pt" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(point)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.connection?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <dgm:cxn modelId="{2}" srcId="{0}" destId="{1}" srcOrd="0" destOrd="0" parTransId="{3}" sibTransId="{4}"/>
+/// ```
+/// cxn (Connection)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDiagramConnection {
+    // attributes
+    /// destId (Destination Identifier): model id of the child point
+    pub destination_id: Option<String>,
+
+    /// destOrd (Destination Position)
+    pub destination_order: Option<u64>,
+
+    /// modelId (Model Identifier)
+    pub model_id: Option<String>,
+
+    /// parTransId (Parent Transition Identifier)
+    pub parent_transition_id: Option<String>,
+
+    /// presId (Presentation Identifier)
+    pub presentation_id: Option<String>,
+
+    /// sibTransId (Sibling Transition Identifier)
+    pub sibling_transition_id: Option<String>,
+
+    /// srcId (Source Identifier): model id of the parent point
+    pub source_id: Option<String>,
+
+    /// srcOrd (Source Position): position of the child among the children of the parent
+    pub source_order: Option<u64>,
+
+    /// type (Connection Type)
+    ///
+    /// * parOf (default): the source is the parent of the destination
+    /// * presOf: the destination is a presentation point of the source
+    /// * presParOf: the source is the parent of the destination within the presentation points
+    pub r#type: Option<String>,
+}
+
+impl XlsxDiagramConnection {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut connection = Self {
+            destination_id: None,
+            destination_order: None,
+            model_id: None,
+            parent_transition_id: None,
+            presentation_id: None,
+            sibling_transition_id: None,
+            source_id: None,
+            source_order: None,
+            r#type: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"destId" => connection.destination_id = Some(string_value),
+                        b"destOrd" => {
+                            connection.destination_order = string_to_unsignedint(&string_value)
+                        }
+                        b"modelId" => connection.model_id = Some(string_value),
+                        b"parTransId" => connection.parent_transition_id = Some(string_value),
+                        b"presId" => connection.presentation_id = Some(string_value),
+                        b"sibTransId" => connection.sibling_transition_id = Some(string_value),
+                        b"srcId" => connection.source_id = Some(string_value),
+                        b"srcOrd" => connection.source_order = string_to_unsignedint(&string_value),
+                        b"type" => connection.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(connection)
+    }
+}
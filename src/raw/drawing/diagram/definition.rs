@@ -0,0 +1,117 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+/// - layoutDef: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.layoutdefinition?view=openxml-3.0.1
+/// - styleDef: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.stylepart?view=openxml-3.0.1
+/// - colorsDef: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.diagrams.colorsdefinition?view=openxml-3.0.1
+///
+/// Header of a layout, quick style or colors definition of a diagram.
+/// The layout algorithms, style labels and color transforms are not loaded: the pre-rendered drawing of the diagram is used instead.
+///
+/// Example:
+/// ```
+/// <dgm:layoutDef xmlns:dgm="http://schemas.openxmlformats.org/drawingml/2006/diagram"
+///     uniqueId="urn:microsoft.com/office/officeart/2005/8/layout/orgChart1">
+///     <dgm:title val=""/>
+///     <dgm:desc val=""/>
+///     <dgm:catLst>
+///         <dgm:cat type="hierarchy" pri="1000"/>
+///     </dgm:catLst>
+///     ...
+/// </dgm:layoutDef>
+/// ```
+/// layoutDef (Layout Definition), styleDef (Style Definition), colorsDef (Color Transform Definitions)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDiagramDefinition {
+    // Child Elements
+    /// title val (Title): first title of the definition
+    pub title: Option<String>,
+
+    /// desc val (Description): first description of the definition
+    pub description: Option<String>,
+
+    /// cat type (Category) of the catLst. Ex: `hierarchy`
+    pub categories: Vec<String>,
+
+    // attributes
+    /// uniqueId (Unique Identifier). Ex: `urn:microsoft.com/office/officeart/2005/8/layout/orgChart1`
+    pub unique_id: Option<String>,
+}
+
+impl XlsxDiagramDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self {
+            title: None,
+            description: None,
+            categories: vec![],
+            unique_id: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"layoutDef"
+                        || e.local_name().as_ref() == b"styleDef"
+                        || e.local_name().as_ref() == b"colorsDef" =>
+                {
+                    definition.unique_id = value_of(e, b"uniqueId")?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"title" => {
+                    if definition.title.is_none() {
+                        definition.title = value_of(e, b"val")?;
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"desc" => {
+                    if definition.description.is_none() {
+                        definition.description = value_of(e, b"val")?;
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cat" => {
+                    if let Some(category) = value_of(e, b"type")? {
+                        definition.categories.push(category);
+                    }
+                }
+                // the rest of the definition (layout nodes, style labels) is not needed for the header
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"catLst" => break,
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"layoutNode"
+                        || e.local_name().as_ref() == b"styleLbl" =>
+                {
+                    break
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(definition)
+    }
+}
+
+fn value_of(e: &BytesStart, key: &[u8]) -> anyhow::Result<Option<String>> {
+    for a in e.attributes() {
+        match a {
+            Ok(a) => {
+                if a.key.local_name().as_ref() == key {
+                    return Ok(Some(String::from_utf8(a.value.to_vec())?));
+                }
+            }
+            Err(error) => {
+                bail!(error.to_string())
+            }
+        }
+    }
+    Ok(None)
+}
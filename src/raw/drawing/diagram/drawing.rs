@@ -0,0 +1,93 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{excel::xml_reader, raw::drawing::worksheet_drawing::spreadsheet_shape::XlsxShape};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office.drawing.drawing?view=openxml-3.0.1
+///
+/// Shapes of a diagram as last rendered by the application, positioned relatively to the graphic frame.
+///
+/// Example:
+/// ```
+/// <dsp:drawing xmlns:dsp="http://schemas.microsoft.com/office/drawing/2008/diagram"
+///     xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+///     <dsp:spTree>
+///         <dsp:nvGrpSpPr><dsp:cNvPr id="0" name=""/><dsp:cNvGrpSpPr/></dsp:nvGrpSpPr>
+///         <dsp:grpSpPr/>
+///         <dsp:sp modelId="{5}">
+///             <dsp:nvSpPr><dsp:cNvPr id="0" name=""/><dsp:cNvSpPr/></dsp:nvSpPr>
+///             <dsp:spPr>
+///                 <a:xfrm><a:off x="1905000" y="0"/><a:ext cx="1524000" cy="762000"/></a:xfrm>
+///                 <a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
+///             </dsp:spPr>
+///             <dsp:style>...</dsp:style>
+///             <dsp:txBody><a:bodyPr/><a:lstStyle/><a:p><a:r><a:rPr lang="en-US"/><a:t>CEO</a:t></a:r></a:p></dsp:txBody>
+///             <dsp:txXfrm><a:off x="1905000" y="0"/><a:ext cx="1524000" cy="762000"/></dsp:txXfrm>
+///         </dsp:sp>
+///     </dsp:spTree>
+/// </dsp:drawing>
+/// ```
+/// drawing (Diagram Drawing)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDiagramDrawing {
+    // Child Elements
+    /// sp (Shape) of the spTree
+    pub shapes: Vec<XlsxDiagramShape>,
+}
+
+impl XlsxDiagramDrawing {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut drawing = Self { shapes: vec![] };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(drawing);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sp" => {
+                    let mut model_id: Option<String> = None;
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"modelId" => model_id = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                    let shape = XlsxShape::load(&mut reader, e)?;
+                    drawing.shapes.push(XlsxDiagramShape { model_id, shape });
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"drawing" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(drawing)
+    }
+}
+
+/// dsp:sp (Shape) of a diagram drawing
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDiagramShape {
+    /// modelId (Model Identifier): presentation point of the data model rendered by the shape
+    pub model_id: Option<String>,
+
+    pub shape: XlsxShape,
+}
@@ -0,0 +1,52 @@
+pub mod data_model;
+pub mod definition;
+pub mod drawing;
+
+use std::collections::BTreeMap;
+
+use data_model::XlsxDiagramDataModel;
+use definition::XlsxDiagramDefinition;
+use drawing::XlsxDiagramDrawing;
+
+use crate::packaging::relationship::XlsxRelationships;
+
+/// Parts of the diagrams (SmartArt) referenced by the graphic frames of a drawing.
+///
+/// Each map is keyed by the relationship id in the drawing relationships. Ex: `("rId1", data model parsed from xl/diagrams/data1.xml)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDiagrams {
+    /// xl/diagrams/data{}.xml
+    pub data_models: BTreeMap<String, XlsxDiagramDataModel>,
+
+    /// xl/diagrams/layout{}.xml
+    pub layouts: BTreeMap<String, XlsxDiagramDefinition>,
+
+    /// xl/diagrams/quickStyle{}.xml
+    pub quick_styles: BTreeMap<String, XlsxDiagramDefinition>,
+
+    /// xl/diagrams/colors{}.xml
+    pub colors: BTreeMap<String, XlsxDiagramDefinition>,
+
+    /// xl/diagrams/drawing{}.xml
+    pub drawings: BTreeMap<String, XlsxDiagramDrawing>,
+
+    /// xl/diagrams/_rels/drawing{}.xml.rels, with targets resolved to zip paths
+    pub drawing_relationships: BTreeMap<String, XlsxRelationships>,
+
+    /// bytes of the images used by the shapes of xl/diagrams/drawing{}.xml, by relationship id in the diagram drawing relationships
+    pub drawing_image_bytes: BTreeMap<String, BTreeMap<String, Vec<u8>>>,
+}
+
+impl XlsxDiagrams {
+    pub(crate) fn default() -> Self {
+        Self {
+            data_models: BTreeMap::new(),
+            layouts: BTreeMap::new(),
+            quick_styles: BTreeMap::new(),
+            colors: BTreeMap::new(),
+            drawings: BTreeMap::new(),
+            drawing_relationships: BTreeMap::new(),
+            drawing_image_bytes: BTreeMap::new(),
+        }
+    }
+}
@@ -1,5 +1,9 @@
+use std::io::Read;
+
 use anyhow::bail;
-use quick_xml::events::BytesStart;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::excel::XmlReader;
 
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.graphicdata?view=openxml-3.0.1
 ///
@@ -37,6 +41,58 @@ pub struct XlsxGraphicData {
     //         xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"
     //         r:id="rId1" />
     // </a:graphicData>
+    /// c:chart r:id
+    ///
+    /// Relationship id of the chart part (xl/charts/chart{}.xml) in the drawing relationships.
+    /// None if the graphic object is not a chart.
+    pub chart_id: Option<String>,
+
+    // sample xml:
+    // <a:graphicData uri="http://schemas.microsoft.com/office/drawing/2010/slicer">
+    //     <sle:slicer xmlns:sle="http://schemas.microsoft.com/office/drawing/2010/slicer" name="Region" />
+    // </a:graphicData>
+    /// sle:slicer name
+    ///
+    /// Name of the slicer (xl/slicers/slicer{}.xml) displayed in the frame.
+    /// None if the graphic object is not a slicer.
+    pub slicer_name: Option<String>,
+
+    // sample xml:
+    // <a:graphicData uri="http://schemas.microsoft.com/office/drawing/2012/timeslicer">
+    //     <tsle:timeslicer xmlns:tsle="http://schemas.microsoft.com/office/drawing/2012/timeslicer" name="Date" />
+    // </a:graphicData>
+    /// tsle:timeslicer name
+    ///
+    /// Name of the timeline (xl/timelines/timeline{}.xml) displayed in the frame.
+    /// None if the graphic object is not a timeline.
+    pub timeline_name: Option<String>,
+
+    // sample xml:
+    // <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/diagram">
+    //     <dgm:relIds xmlns:dgm="http://schemas.openxmlformats.org/drawingml/2006/diagram"
+    //         xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+    //         r:dm="rId1" r:lo="rId2" r:qs="rId3" r:cs="rId4" />
+    // </a:graphicData>
+    /// dgm:relIds r:dm
+    ///
+    /// Relationship id of the diagram data part (xl/diagrams/data{}.xml) in the drawing relationships.
+    /// None if the graphic object is not a diagram (SmartArt).
+    pub diagram_data_id: Option<String>,
+
+    /// dgm:relIds r:lo
+    ///
+    /// Relationship id of the diagram layout definition part (xl/diagrams/layout{}.xml).
+    pub diagram_layout_id: Option<String>,
+
+    /// dgm:relIds r:qs
+    ///
+    /// Relationship id of the diagram quick style part (xl/diagrams/quickStyle{}.xml).
+    pub diagram_quick_style_id: Option<String>,
+
+    /// dgm:relIds r:cs
+    ///
+    /// Relationship id of the diagram colors part (xl/diagrams/colors{}.xml).
+    pub diagram_colors_id: Option<String>,
 
     // Attributes
     /// uri (Uniform Resource Identifier)
@@ -47,8 +103,17 @@ pub struct XlsxGraphicData {
 }
 
 impl XlsxGraphicData {
-    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
-        let mut graphic_data = Self { uri: None };
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut graphic_data = Self {
+            chart_id: None,
+            slicer_name: None,
+            timeline_name: None,
+            diagram_data_id: None,
+            diagram_layout_id: None,
+            diagram_quick_style_id: None,
+            diagram_colors_id: None,
+            uri: None,
+        };
 
         let attributes = e.attributes();
         for a in attributes {
@@ -69,6 +134,88 @@ impl XlsxGraphicData {
             }
         }
 
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"chart" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"id" => graphic_data.chart_id = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"slicer" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => graphic_data.slicer_name = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"timeslicer" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => graphic_data.timeline_name = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"relIds" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"dm" => graphic_data.diagram_data_id = Some(string_value),
+                                    b"lo" => graphic_data.diagram_layout_id = Some(string_value),
+                                    b"qs" => {
+                                        graphic_data.diagram_quick_style_id = Some(string_value)
+                                    }
+                                    b"cs" => graphic_data.diagram_colors_id = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"graphicData" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `graphicData`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
         Ok(graphic_data)
     }
 }
@@ -31,7 +31,7 @@ impl XlsxGraphic {
 
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"graphicData" => {
-                    graphic.graphic_data = Some(XlsxGraphicData::load(e)?);
+                    graphic.graphic_data = Some(XlsxGraphicData::load(reader, e)?);
                 }
                 Ok(Event::End(ref e)) if e.local_name().as_ref() == b"graphic" => break,
                 Ok(Event::Eof) => {
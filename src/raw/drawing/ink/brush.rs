@@ -0,0 +1,140 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::excel::XmlReader;
+
+/// https://www.w3.org/TR/InkML/#brush
+///
+/// Drawing attributes of the traces referencing the brush.
+///
+/// Example:
+/// ```
+/// <inkml:brush xml:id="br0">
+///     <inkml:brushProperty name="width" value="0.05" units="cm"/>
+///     <inkml:brushProperty name="height" value="0.05" units="cm"/>
+///     <inkml:brushProperty name="color" value="#E71224"/>
+/// </inkml:brush>
+/// ```
+/// brush (Brush)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInkBrush {
+    // Child Elements
+    // annotation, annotationXML (Annotations) Not supported
+    /// brushProperty (Brush Property)
+    pub properties: Vec<XlsxInkBrushProperty>,
+
+    // attributes
+    /// brushRef (Brush Reference): brush the properties are inherited from. Ex: `#br0`
+    pub brush_ref: Option<String>,
+
+    /// xml:id (Identifier)
+    pub id: Option<String>,
+}
+
+impl XlsxInkBrush {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut brush = Self {
+            properties: vec![],
+            brush_ref: None,
+            id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"brushRef" => brush.brush_ref = Some(string_value),
+                        b"id" => brush.id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"brushProperty" => {
+                    brush.properties.push(XlsxInkBrushProperty::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"annotation"
+                        || e.local_name().as_ref() == b"annotationXML" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"brush" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(brush)
+    }
+}
+
+/// https://www.w3.org/TR/InkML/#brushProperty
+///
+/// Example:
+/// ```
+/// <inkml:brushProperty name="width" value="0.05" units="cm"/>
+/// ```
+/// brushProperty (Brush Property)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInkBrushProperty {
+    // attributes
+    /// name (Name)
+    ///
+    /// Properties written by Office:
+    /// * color: Ex: `#E71224`
+    /// * width, height: size of the pen tip
+    /// * transparency: 0 (opaque) - 255
+    /// * tip: ellipse or rectangle
+    /// * rasterOp: maskPen for highlighters
+    /// * fitToCurve, ignorePressure, antiAliased
+    pub name: Option<String>,
+
+    /// units (Units). Ex: `cm`
+    pub units: Option<String>,
+
+    /// value (Value)
+    pub value: Option<String>,
+}
+
+impl XlsxInkBrushProperty {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut property = Self {
+            name: None,
+            units: None,
+            value: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"name" => property.name = Some(string_value),
+                        b"units" => property.units = Some(string_value),
+                        b"value" => property.value = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(property)
+    }
+}
@@ -0,0 +1,216 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_float};
+
+/// https://www.w3.org/TR/InkML/#context
+///
+/// Shared context of traces: the channels of the ink source (coordinates, pressure, time and etc.) and their resolution.
+///
+/// Example:
+/// ```
+/// <inkml:context xml:id="ctx0">
+///     <inkml:inkSource xml:id="inkSrc0">
+///         <inkml:traceFormat>
+///             <inkml:channel name="X" type="integer" max="32767" units="cm"/>
+///             <inkml:channel name="Y" type="integer" max="32767" units="cm"/>
+///             <inkml:channel name="F" type="integer" max="32767" units="dev"/>
+///         </inkml:traceFormat>
+///         <inkml:channelProperties>
+///             <inkml:channelProperty channel="X" name="resolution" value="1000" units="1/cm"/>
+///             <inkml:channelProperty channel="Y" name="resolution" value="1000" units="1/cm"/>
+///         </inkml:channelProperties>
+///     </inkml:inkSource>
+///     <inkml:timestamp xml:id="ts0" timeString="2024-05-02T09:41:12.084"/>
+/// </inkml:context>
+/// ```
+/// context (Context)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInkContext {
+    // Child Elements
+    // canvas, canvasTransform, timestamp, annotation (Not supported)
+    /// channel (Channel) of the traceFormat, in the order of the values of each point
+    pub channels: Vec<XlsxInkChannel>,
+
+    /// channelProperty (Channel Property) of the inkSource
+    pub channel_properties: Vec<XlsxInkChannelProperty>,
+
+    // attributes
+    /// brushRef (Brush Reference): brush of the traces without their own. Ex: `#br0`
+    pub brush_ref: Option<String>,
+
+    /// xml:id (Identifier)
+    pub id: Option<String>,
+}
+
+impl XlsxInkContext {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut context = Self {
+            channels: vec![],
+            channel_properties: vec![],
+            brush_ref: None,
+            id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"brushRef" => context.brush_ref = Some(string_value),
+                        b"id" => context.id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"channel" => {
+                    context.channels.push(XlsxInkChannel::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"channelProperty" => {
+                    context
+                        .channel_properties
+                        .push(XlsxInkChannelProperty::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"canvas"
+                        || e.local_name().as_ref() == b"canvasTransform"
+                        || e.local_name().as_ref() == b"annotation"
+                        || e.local_name().as_ref() == b"annotationXML" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"context" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(context)
+    }
+}
+
+/// https://www.w3.org/TR/InkML/#channel
+///
+/// Example:
+/// ```
+/// <inkml:channel name="X" type="integer" max="32767" units="cm"/>
+/// ```
+/// channel (Channel)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInkChannel {
+    // attributes
+    /// name (Name)
+    ///
+    /// * X, Y: coordinates
+    /// * F: tip force (pressure)
+    /// * T: time
+    pub name: Option<String>,
+
+    /// max (Maximum Value). Ex: `32767` for the tip force
+    pub max: Option<f64>,
+
+    /// type (Type): integer, decimal, double or boolean
+    pub r#type: Option<String>,
+
+    /// units (Units)
+    pub units: Option<String>,
+}
+
+impl XlsxInkChannel {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut channel = Self {
+            name: None,
+            max: None,
+            r#type: None,
+            units: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"max" => channel.max = string_to_float(&string_value),
+                        b"name" => channel.name = Some(string_value),
+                        b"type" => channel.r#type = Some(string_value),
+                        b"units" => channel.units = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(channel)
+    }
+}
+
+/// https://www.w3.org/TR/InkML/#channelProperty
+///
+/// Example:
+/// ```
+/// <inkml:channelProperty channel="X" name="resolution" value="1000" units="1/cm"/>
+/// ```
+/// channelProperty (Channel Property)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInkChannelProperty {
+    // attributes
+    /// channel (Channel): name of the channel
+    pub channel: Option<String>,
+
+    /// name (Name). Ex: resolution
+    pub name: Option<String>,
+
+    /// units (Units). Ex: `1/cm`
+    pub units: Option<String>,
+
+    /// value (Value)
+    pub value: Option<f64>,
+}
+
+impl XlsxInkChannelProperty {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut property = Self {
+            channel: None,
+            name: None,
+            units: None,
+            value: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"channel" => property.channel = Some(string_value),
+                        b"name" => property.name = Some(string_value),
+                        b"units" => property.units = Some(string_value),
+                        b"value" => property.value = string_to_float(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(property)
+    }
+}
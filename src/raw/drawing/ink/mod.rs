@@ -0,0 +1,161 @@
+pub mod brush;
+pub mod context;
+pub mod trace;
+
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use brush::XlsxInkBrush;
+use context::XlsxInkContext;
+use trace::XlsxInkTrace;
+
+use crate::excel::{xml_reader, XmlReader};
+
+/// https://www.w3.org/TR/InkML/#ink
+///
+/// Ink annotation (InkML) referenced by a content part of a drawing (`contentPart` with `r:id`).
+///
+/// Example:
+/// ```
+/// <inkml:ink xmlns:inkml="http://www.w3.org/2003/InkML">
+///     <inkml:definitions>
+///         <inkml:context xml:id="ctx0">
+///             <inkml:inkSource xml:id="inkSrc0">
+///                 <inkml:traceFormat>
+///                     <inkml:channel name="X" type="integer" max="32767" units="cm"/>
+///                     <inkml:channel name="Y" type="integer" max="32767" units="cm"/>
+///                     <inkml:channel name="F" type="integer" max="32767" units="dev"/>
+///                 </inkml:traceFormat>
+///                 <inkml:channelProperties>
+///                     <inkml:channelProperty channel="X" name="resolution" value="1000" units="1/cm"/>
+///                     <inkml:channelProperty channel="Y" name="resolution" value="1000" units="1/cm"/>
+///                     <inkml:channelProperty channel="F" name="resolution" value="0" units="1/dev"/>
+///                 </inkml:channelProperties>
+///             </inkml:inkSource>
+///         </inkml:context>
+///         <inkml:brush xml:id="br0">
+///             <inkml:brushProperty name="width" value="0.05" units="cm"/>
+///             <inkml:brushProperty name="height" value="0.05" units="cm"/>
+///             <inkml:brushProperty name="color" value="#E71224"/>
+///         </inkml:brush>
+///     </inkml:definitions>
+///     <inkml:trace contextRef="#ctx0" brushRef="#br0">0 0 16383,'250'0,"-5"0</inkml:trace>
+/// </inkml:ink>
+/// ```
+/// ink (Ink)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInk {
+    // Child Elements
+    // annotation, annotationXML (Annotations) Not supported
+    /// brush (Brush) defined in definitions
+    pub brushes: Vec<XlsxInkBrush>,
+
+    /// context (Context) defined in definitions
+    pub contexts: Vec<XlsxInkContext>,
+
+    /// trace (Trace)
+    ///
+    /// Traces within a traceGroup are flattened, inheriting the brushRef and contextRef of the group.
+    pub traces: Vec<XlsxInkTrace>,
+}
+
+impl XlsxInk {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut ink = Self {
+            brushes: vec![],
+            contexts: vec![],
+            traces: vec![],
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(ink);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"brush" => {
+                    ink.brushes.push(XlsxInkBrush::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"context" => {
+                    ink.contexts.push(XlsxInkContext::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"trace" => {
+                    ink.traces.push(XlsxInkTrace::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"traceGroup" => {
+                    ink.traces.append(&mut load_trace_group(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"annotation"
+                        || e.local_name().as_ref() == b"annotationXML" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ink" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(ink)
+    }
+}
+
+/// traces of a traceGroup, inheriting the brushRef and contextRef of the group
+fn load_trace_group(
+    reader: &mut XmlReader<impl Read>,
+    e: &BytesStart,
+) -> anyhow::Result<Vec<XlsxInkTrace>> {
+    let mut traces: Vec<XlsxInkTrace> = vec![];
+    let mut brush_ref: Option<String> = None;
+    let mut context_ref: Option<String> = None;
+
+    for a in e.attributes() {
+        match a {
+            Ok(a) => {
+                let string_value = String::from_utf8(a.value.to_vec())?;
+                match a.key.local_name().as_ref() {
+                    b"brushRef" => brush_ref = Some(string_value),
+                    b"contextRef" => context_ref = Some(string_value),
+                    _ => {}
+                }
+            }
+            Err(error) => {
+                bail!(error.to_string())
+            }
+        }
+    }
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"trace" => {
+                let mut trace = XlsxInkTrace::load(reader, e)?;
+                if trace.brush_ref.is_none() {
+                    trace.brush_ref = brush_ref.clone();
+                }
+                if trace.context_ref.is_none() {
+                    trace.context_ref = context_ref.clone();
+                }
+                traces.push(trace);
+            }
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"annotationXML" => {
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"traceGroup" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(traces)
+}
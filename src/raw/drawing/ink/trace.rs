@@ -0,0 +1,81 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::excel::XmlReader;
+
+/// https://www.w3.org/TR/InkML/#trace
+///
+/// A stroke: the points sampled while the pen was down.
+///
+/// Points are separated by commas, with one value per channel of the context.
+/// Values may be prefixed by `'` (first difference) or `"` (second difference) to encode them relatively to the previous points.
+///
+/// Example:
+/// ```
+/// <inkml:trace contextRef="#ctx0" brushRef="#br0">0 0 16383,'250'0,"-5"0,0 0</inkml:trace>
+/// ```
+/// trace (Trace)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInkTrace {
+    // attributes
+    /// brushRef (Brush Reference). Ex: `#br0`
+    pub brush_ref: Option<String>,
+
+    /// contextRef (Context Reference). Ex: `#ctx0`
+    pub context_ref: Option<String>,
+
+    /// xml:id (Identifier)
+    pub id: Option<String>,
+
+    /// type (Type): penDown, penUp or indeterminate
+    pub r#type: Option<String>,
+
+    /// Text content: encoded points
+    pub value: String,
+}
+
+impl XlsxInkTrace {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut trace = Self {
+            brush_ref: None,
+            context_ref: None,
+            id: None,
+            r#type: None,
+            value: String::new(),
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"brushRef" => trace.brush_ref = Some(string_value),
+                        b"contextRef" => trace.context_ref = Some(string_value),
+                        b"id" => trace.id = Some(string_value),
+                        b"type" => trace.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Text(t)) => trace.value.push_str(&t.unescape()?),
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"trace" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(trace)
+    }
+}
@@ -5,6 +5,22 @@ pub mod theme;
 #[allow(dead_code)]
 pub mod worksheet_drawing;
 
+// root of xl/charts/chart{}.xml
+#[allow(dead_code)]
+pub mod chart;
+
+// root of xl/drawings/vmlDrawing{}.vml (legacy drawing)
+#[allow(dead_code)]
+pub mod vml;
+
+// root of xl/ink/ink{}.xml (ink annotations of content parts)
+#[allow(dead_code)]
+pub mod ink;
+
+// root of xl/diagrams/{data, layout, quickStyle, colors, drawing}{}.xml (SmartArt)
+#[allow(dead_code)]
+pub mod diagram;
+
 // others
 pub mod color;
 pub mod default;
@@ -14,7 +14,7 @@ use super::{
 ///
 /// A complex type that specifies non-visual properties of a contentPart element
 ///
-/// xdr14:nvPr
+/// xdr14:nvContentPartPr
 #[derive(Debug, Clone, PartialEq)]
 pub struct XlsxExcelNonVisualContentPartShapeProperties {
     // Child Elements:
@@ -51,9 +51,9 @@ impl XlsxExcelNonVisualContentPartShapeProperties {
                     properties.non_visual_ink_content_part_properties =
                         Some(XlsxNonVisualInkContentPartProperties::load(reader)?);
                 }
-                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"nvPr" => break,
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"nvContentPartPr" => break,
                 Ok(Event::Eof) => {
-                    bail!("unexpected end of file at XlsxExcelNonVisualContentPartShapeProperties: `nvPr`.")
+                    bail!("unexpected end of file at XlsxExcelNonVisualContentPartShapeProperties: `nvContentPartPr`.")
                 }
                 Err(e) => bail!(e.to_string()),
                 _ => (),
@@ -6,11 +6,60 @@ use quick_xml::events::{BytesStart, Event};
 use crate::{
     excel::XmlReader,
     helper::{string_to_bool, string_to_unsignedint},
-    raw::drawing::text::hyperlink_on_event::{
-        XlsxHyperlinkOnClick, XlsxHyperlinkOnHover, XlsxHyperlinkOnMouseOver,
+    raw::{
+        drawing::text::hyperlink_on_event::{
+            XlsxHyperlinkOnClick, XlsxHyperlinkOnHover, XlsxHyperlinkOnMouseOver,
+        },
+        extension_list::{
+            handler::ExtensionHandler, load_extension_list, XlsxExtension, XlsxExtensionList,
+        },
     },
 };
 
+/// uri of the drawing extension containing the creation id of the object
+pub const CREATION_ID_EXTENSION_URI: &str = "{FF2B5EF4-FFF2-40B4-BE49-F238E27FC236}";
+
+/// Built-in handler decoding the creation id of a drawing object.
+///
+/// Example
+/// ```
+/// <a:ext uri="{FF2B5EF4-FFF2-40B4-BE49-F238E27FC236}">
+///     <a16:creationId xmlns:a16="http://schemas.microsoft.com/office/drawing/2014/main" id="{00000000-0008-0000-0000-000002000000}" />
+/// </a:ext>
+/// ```
+pub struct CreationIdExtensionHandler;
+
+impl ExtensionHandler for CreationIdExtensionHandler {
+    type Output = String;
+
+    fn uri(&self) -> String {
+        return CREATION_ID_EXTENSION_URI.to_string();
+    }
+
+    fn decode(&self, extension: &XlsxExtension) -> anyhow::Result<Self::Output> {
+        return extension.with_xml_reader(|reader| {
+            let mut buf = Vec::new();
+            loop {
+                buf.clear();
+
+                match reader.read_event_into(&mut buf) {
+                    Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"creationId" => {
+                        for a in e.attributes() {
+                            let a = a?;
+                            if a.key.local_name().as_ref() == b"id" {
+                                return Ok(String::from_utf8(a.value.to_vec())?);
+                            }
+                        }
+                    }
+                    Ok(Event::Eof) => bail!("creation id not found."),
+                    Err(e) => bail!(e.to_string()),
+                    _ => (),
+                }
+            }
+        });
+    }
+}
+
 /// - NonVisualDrawingProperties: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.drawing.nonvisualdrawingproperties?view=openxml-3.0.1
 /// - SpreadSheet.NonVisualDrawingProperties: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.drawing.spreadsheet.nonvisualdrawingproperties?view=openxml-3.0.1
 ///
@@ -18,7 +67,9 @@ use crate::{
 #[derive(Debug, Clone, PartialEq)]
 pub struct XlsxNonVisualDrawingProperties {
     // children
-    // extLst (Not Supported)
+    /// extLst (Extension List). Ex: creation id of the object
+    pub extension_list: Option<XlsxExtensionList>,
+
     /// hlinkClick (HyperlinkOnClick)
     ///
     /// Hyperlink associated with clicking or selecting the element.
@@ -58,6 +109,7 @@ impl XlsxNonVisualDrawingProperties {
     pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
         let mut buf = Vec::new();
         let mut properties = Self {
+            extension_list: None,
             hlink_click: None,
             hlink_hover: None,
             description: None,
@@ -103,7 +155,7 @@ impl XlsxNonVisualDrawingProperties {
 
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
-                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                    properties.extension_list = Some(load_extension_list(reader)?);
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"hlinkClick" => {
                     properties.hlink_click = Some(XlsxHyperlinkOnClick::load(reader, e)?);
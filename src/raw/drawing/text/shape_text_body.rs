@@ -90,6 +90,16 @@ pub struct XlsxShapeTextBody {
 
 impl XlsxShapeTextBody {
     pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        return Self::load_with_tag(reader, b"txBody");
+    }
+
+    /// Load a text body (CT_TextBody) with a tag other than `txBody`.
+    ///
+    /// Example: `c:rich` and `c:txPr` within charts.
+    pub(crate) fn load_with_tag(
+        reader: &mut XmlReader<impl Read>,
+        tag: &[u8],
+    ) -> anyhow::Result<Self> {
         let mut text_body = Self {
             body_properties: None,
             text_list_style: None,
@@ -112,8 +122,11 @@ impl XlsxShapeTextBody {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"p" => {
                     paragraphs.push(XlsxTextParagraphs::load(reader)?);
                 }
-                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"txBody" => break,
-                Ok(Event::Eof) => bail!("unexpected end of file at `txBody`."),
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+                Ok(Event::Eof) => bail!(
+                    "unexpected end of file at `{}`.",
+                    String::from_utf8(tag.to_vec())?
+                ),
                 Err(e) => bail!(e.to_string()),
                 _ => (),
             }
@@ -0,0 +1,49 @@
+use crate::helper::string_to_unsignedint;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.spreadsheet.anchor?view=openxml-3.0.1
+///
+/// Anchor of an object in the client data of a shape.
+///
+/// Comma separated list of 8 integers: left column, left offset, top row, top offset, right column, right offset, bottom row and bottom offset.
+/// Columns and rows are 0 based indexes and offsets are in pixels.
+///
+/// Example:
+/// ```
+/// <x:Anchor>1, 15, 0, 2, 3, 15, 4, 16</x:Anchor>
+/// ```
+///
+/// Anchor (Anchor)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlAnchor {
+    pub left_column: u64,
+    pub left_offset: u64,
+    pub top_row: u64,
+    pub top_offset: u64,
+    pub right_column: u64,
+    pub right_offset: u64,
+    pub bottom_row: u64,
+    pub bottom_offset: u64,
+}
+
+impl XlsxVmlAnchor {
+    /// None if the text does not contain 8 integers.
+    pub(crate) fn from_string(str: &str) -> Option<Self> {
+        let values: Vec<u64> = str
+            .split(',')
+            .filter_map(|s| string_to_unsignedint(s.trim()))
+            .collect();
+        if values.len() != 8 {
+            return None;
+        }
+        return Some(Self {
+            left_column: values[0],
+            left_offset: values[1],
+            top_row: values[2],
+            top_offset: values[3],
+            right_column: values[4],
+            right_offset: values[5],
+            bottom_row: values[6],
+            bottom_offset: values[7],
+        });
+    }
+}
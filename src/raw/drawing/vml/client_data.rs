@@ -0,0 +1,316 @@
+use std::io::Read;
+
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+
+use super::{anchor::XlsxVmlAnchor, vml_string_to_bool};
+use crate::{
+    excel::XmlReader,
+    helper::{extract_text_contents, string_to_int, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.spreadsheet.clientdata?view=openxml-3.0.1
+///
+/// This element specifies the spreadsheet related data of a shape: the object type, the anchor, and the data of comments and form controls.
+///
+/// Boolean children are either empty (presence means `true`) or contain `True`/`False`.
+///
+/// Example:
+/// ```
+/// <x:ClientData ObjectType="Checkbox">
+///     <x:Anchor>1, 15, 0, 2, 3, 15, 1, 4</x:Anchor>
+///     <x:AutoFill>False</x:AutoFill>
+///     <x:AutoLine>False</x:AutoLine>
+///     <x:TextVAlign>Center</x:TextVAlign>
+///     <x:Checked>1</x:Checked>
+///     <x:FmlaLink>$C$1</x:FmlaLink>
+///     <x:NoThreeD />
+/// </x:ClientData>
+/// ```
+///
+/// ClientData (Attached Object Data)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlClientData {
+    // Attributes
+    /// ObjectType (Object type)
+    ///
+    /// Possible values: Button, Checkbox, Dialog, Drop, Edit, GBox, Label, LineA, List, Movie, Note, Pict, Radio, RectA, Scroll, Spin, Shape, Group, Rect
+    pub object_type: Option<String>,
+
+    // Children
+    /// Anchor (Anchor)
+    pub anchor: Option<XlsxVmlAnchor>,
+
+    /// AutoFill (AutoFill)
+    pub auto_fill: Option<bool>,
+
+    /// AutoLine (AutoLine)
+    pub auto_line: Option<bool>,
+
+    /// AutoPict (AutoPict)
+    pub auto_pict: Option<bool>,
+
+    /// Checked (Checked)
+    ///
+    /// 0: unchecked, 1: checked, 2: mixed
+    pub checked: Option<u64>,
+
+    /// Column (Comment Column Target)
+    ///
+    /// 0 based index of the column of the cell a comment belongs to.
+    pub column: Option<u64>,
+
+    /// Default (Default Button)
+    pub default: Option<bool>,
+
+    /// DefaultSize (Default Size Toggle)
+    pub default_size: Option<bool>,
+
+    /// Disabled (Disable Button)
+    pub disabled: Option<bool>,
+
+    /// DropLines (Dropdown Maximum Lines)
+    pub drop_lines: Option<u64>,
+
+    /// DropStyle (Dropdown Style)
+    ///
+    /// Possible values: Combo, ComboEdit, Simple
+    pub drop_style: Option<String>,
+
+    /// FirstButton (First Radio Button)
+    pub first_button: Option<bool>,
+
+    /// FmlaGroup (Reference to Control Group)
+    pub fmla_group: Option<String>,
+
+    /// FmlaLink (Linked Formula)
+    ///
+    /// The cell linked to the value of the control, ex: `$C$1`
+    pub fmla_link: Option<String>,
+
+    /// FmlaMacro (Reference to Custom Function)
+    ///
+    /// The macro assigned to the object.
+    pub fmla_macro: Option<String>,
+
+    /// FmlaRange (List Items Source Range)
+    ///
+    /// The input range of list boxes and dropdowns, ex: `$E$1:$E$5`
+    pub fmla_range: Option<String>,
+
+    /// FmlaTxbx (Linked Formula - Textbox)
+    pub fmla_txbx: Option<String>,
+
+    /// Horiz (Horizontal Scroll)
+    pub horizontal: Option<bool>,
+
+    /// Inc (Scroll bar Increment)
+    pub increment: Option<u64>,
+
+    /// ListItem (List Item)
+    ///
+    /// Items of list boxes and dropdowns that are not linked to an input range.
+    pub list_items: Vec<String>,
+
+    /// Locked (Lock Toggle)
+    pub locked: Option<bool>,
+
+    /// LockText (Text Lock)
+    pub lock_text: Option<bool>,
+
+    /// Max (Scroll bar Maximum)
+    pub max: Option<i64>,
+
+    /// Min (Scroll bar Minimum)
+    pub min: Option<i64>,
+
+    /// MoveWithCells (Move with Cells)
+    ///
+    /// Excel writes this element when the object does NOT move with the cells.
+    pub move_with_cells: Option<bool>,
+
+    /// MultiLine (Multi-line)
+    pub multi_line: Option<bool>,
+
+    /// MultiSel (Multiple Selections)
+    pub multi_selection: Option<String>,
+
+    /// NoThreeD (3D)
+    ///
+    /// true if the control is displayed without 3D effects.
+    pub no_three_d: Option<bool>,
+
+    /// Page (Scroll Bar Page Increment)
+    pub page: Option<u64>,
+
+    /// PrintObject (Print Toggle)
+    pub print_object: Option<bool>,
+
+    /// Row (Comment Row Target)
+    ///
+    /// 0 based index of the row of the cell a comment belongs to.
+    pub row: Option<u64>,
+
+    /// Sel (Selected Entry)
+    ///
+    /// 1 based index of the selected item of list boxes and dropdowns, 0 if nothing is selected.
+    pub selected: Option<u64>,
+
+    /// SelType (Selection Type)
+    ///
+    /// Possible values: Single, Multi, Extend
+    pub selection_type: Option<String>,
+
+    /// SizeWithCells (Resize with Cells)
+    ///
+    /// Excel writes this element when the object does NOT resize with the cells.
+    pub size_with_cells: Option<bool>,
+
+    /// TextHAlign (Horizontal Text Alignment)
+    pub text_horizontal_align: Option<String>,
+
+    /// TextVAlign (Vertical Text Alignment)
+    pub text_vertical_align: Option<String>,
+
+    /// Val (Scroll bar Position)
+    pub value: Option<i64>,
+
+    /// Visible (Comment Visibility Toggle)
+    pub visible: Option<bool>,
+}
+
+impl XlsxVmlClientData {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut client_data = Self {
+            object_type: None,
+            anchor: None,
+            auto_fill: None,
+            auto_line: None,
+            auto_pict: None,
+            checked: None,
+            column: None,
+            default: None,
+            default_size: None,
+            disabled: None,
+            drop_lines: None,
+            drop_style: None,
+            first_button: None,
+            fmla_group: None,
+            fmla_link: None,
+            fmla_macro: None,
+            fmla_range: None,
+            fmla_txbx: None,
+            horizontal: None,
+            increment: None,
+            list_items: vec![],
+            locked: None,
+            lock_text: None,
+            max: None,
+            min: None,
+            move_with_cells: None,
+            multi_line: None,
+            multi_selection: None,
+            no_three_d: None,
+            page: None,
+            print_object: None,
+            row: None,
+            selected: None,
+            selection_type: None,
+            size_with_cells: None,
+            text_horizontal_align: None,
+            text_vertical_align: None,
+            value: None,
+            visible: None,
+        };
+
+        let attributes = e.attributes();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ObjectType" => client_data.object_type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    let tag = e.local_name().as_ref().to_vec();
+                    let text = extract_text_contents(reader, &tag)?;
+                    let text = text.trim().to_string();
+                    // empty boolean elements: presence means true
+                    let bool_value = if text.is_empty() {
+                        Some(true)
+                    } else {
+                        vml_string_to_bool(&text)
+                    };
+                    let string_value = if text.is_empty() {
+                        None
+                    } else {
+                        Some(text.clone())
+                    };
+
+                    match tag.as_slice() {
+                        b"Anchor" => client_data.anchor = XlsxVmlAnchor::from_string(&text),
+                        b"AutoFill" => client_data.auto_fill = bool_value,
+                        b"AutoLine" => client_data.auto_line = bool_value,
+                        b"AutoPict" => client_data.auto_pict = bool_value,
+                        b"Checked" => client_data.checked = string_to_unsignedint(&text),
+                        b"Column" => client_data.column = string_to_unsignedint(&text),
+                        b"Default" => client_data.default = bool_value,
+                        b"DefaultSize" => client_data.default_size = bool_value,
+                        b"Disabled" => client_data.disabled = bool_value,
+                        b"DropLines" => client_data.drop_lines = string_to_unsignedint(&text),
+                        b"DropStyle" => client_data.drop_style = string_value,
+                        b"FirstButton" => client_data.first_button = bool_value,
+                        b"FmlaGroup" => client_data.fmla_group = string_value,
+                        b"FmlaLink" => client_data.fmla_link = string_value,
+                        b"FmlaMacro" => client_data.fmla_macro = string_value,
+                        b"FmlaRange" => client_data.fmla_range = string_value,
+                        b"FmlaTxbx" => client_data.fmla_txbx = string_value,
+                        b"Horiz" => client_data.horizontal = bool_value,
+                        b"Inc" => client_data.increment = string_to_unsignedint(&text),
+                        b"ListItem" => client_data.list_items.push(text),
+                        b"Locked" => client_data.locked = bool_value,
+                        b"LockText" => client_data.lock_text = bool_value,
+                        b"Max" => client_data.max = string_to_int(&text),
+                        b"Min" => client_data.min = string_to_int(&text),
+                        b"MoveWithCells" => client_data.move_with_cells = bool_value,
+                        b"MultiLine" => client_data.multi_line = bool_value,
+                        b"MultiSel" => client_data.multi_selection = string_value,
+                        b"NoThreeD" => client_data.no_three_d = bool_value,
+                        b"Page" => client_data.page = string_to_unsignedint(&text),
+                        b"PrintObject" => client_data.print_object = bool_value,
+                        b"Row" => client_data.row = string_to_unsignedint(&text),
+                        b"Sel" => client_data.selected = string_to_unsignedint(&text),
+                        b"SelType" => client_data.selection_type = string_value,
+                        b"SizeWithCells" => client_data.size_with_cells = bool_value,
+                        b"TextHAlign" => client_data.text_horizontal_align = string_value,
+                        b"TextVAlign" => client_data.text_vertical_align = string_value,
+                        b"Val" => client_data.value = string_to_int(&text),
+                        b"Visible" => client_data.visible = bool_value,
+                        _ => {}
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ClientData" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `ClientData`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(client_data);
+    }
+}
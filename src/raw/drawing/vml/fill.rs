@@ -0,0 +1,95 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use super::vml_string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.fill?view=openxml-3.0.1
+///
+/// This element specifies how the path of a shape is filled.
+///
+/// Example:
+/// ```
+/// <v:fill color2="#ffffe1" />
+/// <v:fill o:relid="rId1" o:title="logo" type="frame" />
+/// ```
+///
+/// fill (Shape Fill Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlFill {
+    // Attributes
+    /// angle (Gradient Angle)
+    pub angle: Option<String>,
+
+    /// color (Primary Color)
+    pub color: Option<String>,
+
+    /// color2 (Secondary Color)
+    pub color2: Option<String>,
+
+    /// r:id (Relationship to Part)
+    pub id: Option<String>,
+
+    /// on (Fill Toggle)
+    pub on: Option<bool>,
+
+    /// opacity (Primary Color Opacity)
+    ///
+    /// A fraction (`0.5`), a percentage (`50%`) or a 16.16 fixed number (`32768f`).
+    pub opacity: Option<String>,
+
+    /// o:relid (Relationship to Part)
+    ///
+    /// Relationship id of the image for picture, pattern, tile and frame fills.
+    pub relid: Option<String>,
+
+    /// o:title (Image Data Title)
+    pub title: Option<String>,
+
+    /// type (Fill Type)
+    ///
+    /// Possible values: solid, gradient, gradientRadial, tile, pattern, frame
+    pub r#type: Option<String>,
+}
+
+impl XlsxVmlFill {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut fill = Self {
+            angle: None,
+            color: None,
+            color2: None,
+            id: None,
+            on: None,
+            opacity: None,
+            relid: None,
+            title: None,
+            r#type: None,
+        };
+
+        let attributes = e.attributes();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"angle" => fill.angle = Some(string_value),
+                        b"color" => fill.color = Some(string_value),
+                        b"color2" => fill.color2 = Some(string_value),
+                        b"id" => fill.id = Some(string_value),
+                        b"on" => fill.on = vml_string_to_bool(&string_value),
+                        b"opacity" => fill.opacity = Some(string_value),
+                        b"relid" => fill.relid = Some(string_value),
+                        b"title" => fill.title = Some(string_value),
+                        b"type" => fill.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        return Ok(fill);
+    }
+}
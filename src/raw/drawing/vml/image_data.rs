@@ -0,0 +1,56 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.imagedata?view=openxml-3.0.1
+///
+/// This element is used to draw an image stored in a separate part, ex: header/footer images.
+///
+/// Example:
+/// ```
+/// <v:imagedata o:relid="rId1" o:title="logo" />
+/// ```
+///
+/// imagedata (Image Data)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlImageData {
+    // Attributes
+    /// r:id (Image Relationship ID)
+    pub id: Option<String>,
+
+    /// o:relid (Relationship to Part)
+    pub relid: Option<String>,
+
+    /// o:title (Image Data Title)
+    pub title: Option<String>,
+}
+
+impl XlsxVmlImageData {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut image_data = Self {
+            id: None,
+            relid: None,
+            title: None,
+        };
+
+        let attributes = e.attributes();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => image_data.id = Some(string_value),
+                        b"relid" => image_data.relid = Some(string_value),
+                        b"title" => image_data.title = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        return Ok(image_data);
+    }
+}
@@ -0,0 +1,108 @@
+pub mod anchor;
+pub mod client_data;
+pub mod fill;
+pub mod image_data;
+pub mod shape;
+pub mod shape_type;
+pub mod stroke;
+pub mod textbox;
+
+use anyhow::bail;
+use quick_xml::events::Event;
+use shape::XlsxVmlShape;
+use shape_type::XlsxVmlShapeType;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml?view=openxml-3.0.1
+///
+/// Root of a legacy (VML) drawing part, used by comment boxes, legacy form controls and header/footer images.
+///
+/// The root element is not namespaced and the content is not always well-formed (ex: unclosed `<br>` within textboxes).
+///
+/// Example:
+/// ```
+/// <xml xmlns:v="urn:schemas-microsoft-com:vml" xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:x="urn:schemas-microsoft-com:office:excel">
+///     <o:shapelayout v:ext="edit">
+///         <o:idmap v:ext="edit" data="1" />
+///     </o:shapelayout>
+///     <v:shapetype id="_x0000_t202" coordsize="21600,21600" o:spt="202" path="m,l,21600r21600,l21600,xe">
+///         <v:stroke joinstyle="miter" />
+///         <v:path gradientshapeok="t" o:connecttype="rect" />
+///     </v:shapetype>
+///     <v:shape id="_x0000_s1025" type="#_x0000_t202" style="position:absolute;margin-left:59.25pt;margin-top:1.5pt;width:108pt;height:59.25pt;z-index:1;visibility:hidden" fillcolor="#ffffe1" o:insetmode="auto">
+///         <v:fill color2="#ffffe1" />
+///         <v:shadow on="t" color="black" obscured="t" />
+///         <v:path o:connecttype="none" />
+///         <v:textbox style="mso-direction-alt:auto">
+///             <div style="text-align:left"></div>
+///         </v:textbox>
+///         <x:ClientData ObjectType="Note">
+///             <x:MoveWithCells />
+///             <x:SizeWithCells />
+///             <x:Anchor>1, 15, 0, 2, 3, 15, 4, 16</x:Anchor>
+///             <x:AutoFill>False</x:AutoFill>
+///             <x:Row>0</x:Row>
+///             <x:Column>0</x:Column>
+///         </x:ClientData>
+///     </v:shape>
+/// </xml>
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlDrawing {
+    // children
+    /// shapetype (Shape Template)
+    pub shape_types: Vec<XlsxVmlShapeType>,
+
+    /// shape (Shape Definition)
+    pub shapes: Vec<XlsxVmlShape>,
+}
+
+impl XlsxVmlDrawing {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut drawing = Self {
+            shape_types: vec![],
+            shapes: vec![],
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(drawing);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"shapetype" => {
+                    drawing
+                        .shape_types
+                        .push(XlsxVmlShapeType::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"shape" => {
+                    drawing.shapes.push(XlsxVmlShape::load(&mut reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"xml" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(drawing);
+    }
+}
+
+/// Converting VML boolean string to boolean.
+///
+/// VML uses `t`/`f` and `true`/`false` for attributes, and `True`/`False` for the text of `x:ClientData` children.
+pub(crate) fn vml_string_to_bool(str: &str) -> Option<bool> {
+    return match str.trim().to_ascii_lowercase().as_str() {
+        "f" | "false" | "0" => Some(false),
+        "t" | "true" | "1" => Some(true),
+        _ => None,
+    };
+}
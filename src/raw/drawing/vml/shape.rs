@@ -0,0 +1,176 @@
+use std::io::Read;
+
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+
+use super::{
+    client_data::XlsxVmlClientData, fill::XlsxVmlFill, image_data::XlsxVmlImageData,
+    stroke::XlsxVmlStroke, textbox::XlsxVmlTextbox, vml_string_to_bool,
+};
+use crate::excel::XmlReader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.shape?view=openxml-3.0.1
+///
+/// This element is the basic building block of VML: a comment box, a legacy form control, or a header/footer image.
+///
+/// Example:
+/// ```
+/// <v:shape id="_x0000_s1025" type="#_x0000_t202" style="position:absolute;margin-left:59.25pt;margin-top:1.5pt;width:108pt;height:59.25pt;z-index:1;visibility:hidden" fillcolor="#ffffe1" o:insetmode="auto">
+///     <v:fill color2="#ffffe1" />
+///     <v:shadow on="t" color="black" obscured="t" />
+///     <v:path o:connecttype="none" />
+///     <v:textbox style="mso-direction-alt:auto">
+///         <div style="text-align:left"></div>
+///     </v:textbox>
+///     <x:ClientData ObjectType="Note">
+///         <x:MoveWithCells />
+///         <x:SizeWithCells />
+///         <x:Anchor>1, 15, 0, 2, 3, 15, 4, 16</x:Anchor>
+///         <x:AutoFill>False</x:AutoFill>
+///         <x:Row>0</x:Row>
+///         <x:Column>0</x:Column>
+///     </x:ClientData>
+/// </v:shape>
+/// ```
+///
+/// shape (Shape Definition)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlShape {
+    // Attributes
+    /// alt (Alternate Text)
+    pub alt: Option<String>,
+
+    /// o:button (Button Behavior Toggle)
+    pub button: Option<bool>,
+
+    /// fillcolor (Fill Color)
+    pub fill_color: Option<String>,
+
+    /// filled (Shape Fill Toggle)
+    pub filled: Option<bool>,
+
+    /// id (Unique Identifier)
+    ///
+    /// Ex: `_x0000_s1025`, or the position of header/footer images (`LH`, `CH`, `RH`, `LF`, `CF`, `RF`)
+    pub id: Option<String>,
+
+    /// o:spid (Optional String)
+    ///
+    /// Shape id used by Office, ex: `_x0000_s1025` (1025 is referenced by `shapeId` of OLE objects and controls).
+    pub spid: Option<String>,
+
+    /// strokecolor (Stroke Color)
+    pub stroke_color: Option<String>,
+
+    /// stroked (Shape Stroke Toggle)
+    pub stroked: Option<bool>,
+
+    /// strokeweight (Shape Stroke Weight)
+    pub stroke_weight: Option<String>,
+
+    /// style (Shape Styling Properties)
+    ///
+    /// CSS styles, ex: `position:absolute;margin-left:59.25pt;width:108pt;visibility:hidden`
+    pub style: Option<String>,
+
+    /// type (Shape Type Reference)
+    ///
+    /// Reference to a shape template, ex: `#_x0000_t202`
+    pub r#type: Option<String>,
+
+    // Children
+    /// ClientData (Attached Object Data)
+    pub client_data: Option<XlsxVmlClientData>,
+
+    /// fill (Shape Fill Properties)
+    pub fill: Option<XlsxVmlFill>,
+
+    /// imagedata (Image Data)
+    pub image_data: Option<XlsxVmlImageData>,
+
+    /// stroke (Line Stroke Settings)
+    pub stroke: Option<XlsxVmlStroke>,
+
+    /// textbox (Text Box)
+    pub textbox: Option<XlsxVmlTextbox>,
+}
+
+impl XlsxVmlShape {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut shape = Self {
+            alt: None,
+            button: None,
+            fill_color: None,
+            filled: None,
+            id: None,
+            spid: None,
+            stroke_color: None,
+            stroked: None,
+            stroke_weight: None,
+            style: None,
+            r#type: None,
+            client_data: None,
+            fill: None,
+            image_data: None,
+            stroke: None,
+            textbox: None,
+        };
+
+        let attributes = e.attributes();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"alt" => shape.alt = Some(string_value),
+                        b"button" => shape.button = vml_string_to_bool(&string_value),
+                        b"fillcolor" => shape.fill_color = Some(string_value),
+                        b"filled" => shape.filled = vml_string_to_bool(&string_value),
+                        b"id" => shape.id = Some(string_value),
+                        b"spid" => shape.spid = Some(string_value),
+                        b"strokecolor" => shape.stroke_color = Some(string_value),
+                        b"stroked" => shape.stroked = vml_string_to_bool(&string_value),
+                        b"strokeweight" => shape.stroke_weight = Some(string_value),
+                        b"style" => shape.style = Some(string_value),
+                        b"type" => shape.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ClientData" => {
+                    shape.client_data = Some(XlsxVmlClientData::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"fill" => {
+                    shape.fill = Some(XlsxVmlFill::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"imagedata" => {
+                    shape.image_data = Some(XlsxVmlImageData::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"stroke" => {
+                    shape.stroke = Some(XlsxVmlStroke::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"textbox" => {
+                    shape.textbox = Some(XlsxVmlTextbox::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"shape" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `shape`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(shape);
+    }
+}
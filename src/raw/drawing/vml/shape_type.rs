@@ -0,0 +1,119 @@
+use std::io::Read;
+
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+
+use super::{fill::XlsxVmlFill, stroke::XlsxVmlStroke, vml_string_to_bool};
+use crate::{excel::XmlReader, helper::string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.shapetype?view=openxml-3.0.1
+///
+/// This element defines a shape template that can be used by shapes through their `type` attribute.
+///
+/// Example:
+/// ```
+/// <v:shapetype id="_x0000_t202" coordsize="21600,21600" o:spt="202" path="m,l,21600r21600,l21600,xe">
+///     <v:stroke joinstyle="miter" />
+///     <v:path gradientshapeok="t" o:connecttype="rect" />
+/// </v:shapetype>
+/// ```
+///
+/// shapetype (Shape Template)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlShapeType {
+    // Attributes
+    /// coordsize (Coordinate Space Size)
+    pub coordsize: Option<String>,
+
+    /// fillcolor (Fill Color)
+    pub fill_color: Option<String>,
+
+    /// filled (Shape Fill Toggle)
+    pub filled: Option<bool>,
+
+    /// id (Unique Identifier)
+    pub id: Option<String>,
+
+    /// path (Edge Path)
+    pub path: Option<String>,
+
+    /// o:spt (Optional Number)
+    ///
+    /// Preset shape type number. Ex: 202 for text boxes (comments), 201 for form controls, 75 for pictures.
+    pub spt: Option<u64>,
+
+    /// strokecolor (Stroke Color)
+    pub stroke_color: Option<String>,
+
+    /// stroked (Shape Stroke Toggle)
+    pub stroked: Option<bool>,
+
+    // Children
+    /// fill (Shape Fill Properties)
+    pub fill: Option<XlsxVmlFill>,
+
+    /// stroke (Line Stroke Settings)
+    pub stroke: Option<XlsxVmlStroke>,
+}
+
+impl XlsxVmlShapeType {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut shape_type = Self {
+            coordsize: None,
+            fill_color: None,
+            filled: None,
+            id: None,
+            path: None,
+            spt: None,
+            stroke_color: None,
+            stroked: None,
+            fill: None,
+            stroke: None,
+        };
+
+        let attributes = e.attributes();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"coordsize" => shape_type.coordsize = Some(string_value),
+                        b"fillcolor" => shape_type.fill_color = Some(string_value),
+                        b"filled" => shape_type.filled = vml_string_to_bool(&string_value),
+                        b"id" => shape_type.id = Some(string_value),
+                        b"path" => shape_type.path = Some(string_value),
+                        b"spt" => shape_type.spt = string_to_unsignedint(&string_value),
+                        b"strokecolor" => shape_type.stroke_color = Some(string_value),
+                        b"stroked" => shape_type.stroked = vml_string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"fill" => {
+                    shape_type.fill = Some(XlsxVmlFill::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"stroke" => {
+                    shape_type.stroke = Some(XlsxVmlStroke::load(e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"shapetype" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `shapetype`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(shape_type);
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use super::vml_string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.stroke?view=openxml-3.0.1
+///
+/// This element specifies how the path of a shape is outlined.
+///
+/// Example:
+/// ```
+/// <v:stroke joinstyle="miter" />
+/// <v:stroke color="#3465a4" weight="1pt" dashstyle="dash" />
+/// ```
+///
+/// stroke (Line Stroke Settings)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlStroke {
+    // Attributes
+    /// color (Stroke Color)
+    pub color: Option<String>,
+
+    /// dashstyle (Stroke Dash Pattern)
+    ///
+    /// Ex: solid, dot, dash, dashDot, longDash, or a custom pattern such as `1 1`
+    pub dash_style: Option<String>,
+
+    /// endcap (Line End Cap)
+    pub end_cap: Option<String>,
+
+    /// joinstyle (Line Join Type)
+    pub join_style: Option<String>,
+
+    /// linestyle (Stroke Line Style)
+    pub line_style: Option<String>,
+
+    /// on (Stroke Toggle)
+    pub on: Option<bool>,
+
+    /// opacity (Stroke Opacity)
+    pub opacity: Option<String>,
+
+    /// weight (Stroke Weight)
+    ///
+    /// A length with an unit, ex: `1pt`, `0.75pt`
+    pub weight: Option<String>,
+}
+
+impl XlsxVmlStroke {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut stroke = Self {
+            color: None,
+            dash_style: None,
+            end_cap: None,
+            join_style: None,
+            line_style: None,
+            on: None,
+            opacity: None,
+            weight: None,
+        };
+
+        let attributes = e.attributes();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"color" => stroke.color = Some(string_value),
+                        b"dashstyle" => stroke.dash_style = Some(string_value),
+                        b"endcap" => stroke.end_cap = Some(string_value),
+                        b"joinstyle" => stroke.join_style = Some(string_value),
+                        b"linestyle" => stroke.line_style = Some(string_value),
+                        b"on" => stroke.on = vml_string_to_bool(&string_value),
+                        b"opacity" => stroke.opacity = Some(string_value),
+                        b"weight" => stroke.weight = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        return Ok(stroke);
+    }
+}
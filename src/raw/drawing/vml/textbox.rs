@@ -0,0 +1,120 @@
+use std::io::Read;
+
+use anyhow::bail;
+use quick_xml::{
+    escape::resolve_predefined_entity,
+    events::{BytesStart, BytesText, Event},
+};
+
+use crate::excel::XmlReader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.vml.textbox?view=openxml-3.0.1
+///
+/// This element specifies the text displayed in a shape, as HTML-like content.
+///
+/// Example:
+/// ```
+/// <v:textbox style="mso-direction-alt:auto" inset="0,0,0,0">
+///     <div style="text-align:left">
+///         <font face="Tahoma" size="160" color="auto"><b>Author:</b></font>
+///         <font face="Tahoma" size="160" color="auto"><br />Check the total</font>
+///     </div>
+/// </v:textbox>
+/// ```
+///
+/// textbox (Text Box)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVmlTextbox {
+    // Attributes
+    /// inset (Text Box Inset)
+    ///
+    /// Inner margins, ex: `0,0,0,0` or `1mm,1mm,1mm,1mm`
+    pub inset: Option<String>,
+
+    /// style (Text Box CSS Styling)
+    pub style: Option<String>,
+
+    // Children
+    /// Plain text of the content, `<br>` as line breaks.
+    pub text: String,
+}
+
+impl XlsxVmlTextbox {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut textbox = Self {
+            inset: None,
+            style: None,
+            text: String::new(),
+        };
+
+        let attributes = e.attributes();
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"inset" => textbox.inset = Some(string_value),
+                        b"style" => textbox.style = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"br" => {
+                    textbox.text.push('\n');
+                }
+                Ok(Event::Text(t)) => textbox.text.push_str(&text_content(&t)),
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"textbox" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `textbox`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(textbox);
+    }
+}
+
+/// Text of the textbox content.
+///
+/// The content is HTML-like, so it may contain HTML entities (Ex: `&nbsp;`) that are not defined in XML.
+/// Those are resolved when known, otherwise the text is kept as written instead of failing the whole drawing.
+fn text_content(text: &BytesText) -> String {
+    if let Ok(unescaped) = text.unescape_with(|entity| {
+        return resolve_predefined_entity(entity).or_else(|| resolve_html_entity(entity));
+    }) {
+        return unescaped.into_owned();
+    }
+
+    return String::from_utf8_lossy(text).into_owned();
+}
+
+fn resolve_html_entity(entity: &str) -> Option<&'static str> {
+    return match entity {
+        "nbsp" => Some("\u{a0}"),
+        "copy" => Some("\u{a9}"),
+        "reg" => Some("\u{ae}"),
+        "trade" => Some("\u{2122}"),
+        "hellip" => Some("\u{2026}"),
+        "ndash" => Some("\u{2013}"),
+        "mdash" => Some("\u{2014}"),
+        "lsquo" => Some("\u{2018}"),
+        "rsquo" => Some("\u{2019}"),
+        "ldquo" => Some("\u{201c}"),
+        "rdquo" => Some("\u{201d}"),
+        "bull" => Some("\u{2022}"),
+        "euro" => Some("\u{20ac}"),
+        _ => None,
+    };
+}
@@ -157,6 +157,8 @@ impl XlsxWorksheetDrawing {
         };
 
         let mut drawings: Vec<XlsxWorksheetDrawingType> = vec![];
+        // number of anchors loaded before the choice of the current alternate content
+        let mut drawings_before_choice: Option<usize> = None;
 
         let mut buf = Vec::new();
 
@@ -176,6 +178,19 @@ impl XlsxWorksheetDrawing {
                     let drawing = XlsxTwoCellAnchor::load(&mut reader, e)?;
                     drawings.push(XlsxWorksheetDrawingType::TwoCellAnchor(drawing));
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Choice" => {
+                    drawings_before_choice = Some(drawings.len());
+                }
+                // skip the fallback anchor of an alternate content whose choice is loaded (ex: ink content parts)
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"Fallback"
+                        && drawings_before_choice.is_some_and(|count| drawings.len() > count) =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"AlternateContent" => {
+                    drawings_before_choice = None;
+                }
                 Ok(Event::End(ref e)) if e.local_name().as_ref() == b"wsDr" => break,
                 Ok(Event::Eof) => break,
                 Err(e) => bail!(e.to_string()),
@@ -149,6 +149,12 @@ impl XlsxTwoCellAnchor {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"contentPart" => {
                     anchor.content_part = Some(XlsxContentPart::load(reader, e)?);
                 }
+                // skip the fallback of an alternate content whose choice is loaded (ex: slicers)
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"Fallback" && anchor.drawing_content.is_some() =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
                 // load graphic frame first in case there are other type fall back available (ex: pic)
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"graphicFrame" => {
                     anchor.drawing_content = Some(XlsxWorksheetDrawingContentType::GraphicFrame(
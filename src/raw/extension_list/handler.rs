@@ -0,0 +1,121 @@
+use std::{any::Any, collections::BTreeMap};
+
+use super::XlsxExtension;
+use crate::raw::{
+    drawing::non_visual_properties::non_visual_drawing_properties::CreationIdExtensionHandler,
+    spreadsheet::{
+        sheet::worksheet::sparkline::SparklineGroupsExtensionHandler,
+        slicer::slicer_cache::TableSlicerCacheExtensionHandler,
+    },
+};
+
+/// Decoder of the extensions (`ext`) with a specific uri.
+///
+/// Example
+/// ```
+/// use excel_reader::raw::extension_list::{handler::ExtensionHandler, XlsxExtension};
+///
+/// struct ConditionalFormattingsHandler;
+///
+/// impl ExtensionHandler for ConditionalFormattingsHandler {
+///     type Output = String;
+///
+///     fn uri(&self) -> String {
+///         return "{78C0D931-6437-407d-A8EE-F0AAD7539E65}".to_string();
+///     }
+///
+///     fn decode(&self, extension: &XlsxExtension) -> anyhow::Result<Self::Output> {
+///         return Ok(extension.xml.clone());
+///     }
+/// }
+/// ```
+pub trait ExtensionHandler {
+    type Output: 'static;
+
+    /// uri of the extensions decoded by the handler. Ex: `{05C60535-1F16-4fd2-B633-F4F36F0B64E0}`
+    fn uri(&self) -> String;
+
+    fn decode(&self, extension: &XlsxExtension) -> anyhow::Result<Self::Output>;
+}
+
+type ErasedExtensionHandler = Box<dyn Fn(&XlsxExtension) -> anyhow::Result<Box<dyn Any>>>;
+
+/// Extension handlers by uri.
+///
+/// Contains the built-in handlers:
+/// - `{05C60535-1F16-4fd2-B633-F4F36F0B64E0}`: sparkline groups, decoded to `XlsxSparklineGroups`
+/// - `{FF2B5EF4-FFF2-40B4-BE49-F238E27FC236}`: creation id of drawing objects, decoded to `String`
+/// - `{2F2917AC-EB37-4324-AD4E-5DD8C200BD13}`: table source of a slicer cache, decoded to `Option<XlsxTableSlicerCache>`
+pub struct ExtensionHandlers {
+    // (uppercased uri, handler)
+    handlers: BTreeMap<String, ErasedExtensionHandler>,
+}
+
+impl Default for ExtensionHandlers {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl ExtensionHandlers {
+    /// Handlers containing the built-in ones
+    pub fn new() -> Self {
+        let mut handlers = Self::empty();
+        handlers.register(SparklineGroupsExtensionHandler);
+        handlers.register(CreationIdExtensionHandler);
+        handlers.register(TableSlicerCacheExtensionHandler);
+        return handlers;
+    }
+
+    /// Handlers without the built-in ones
+    pub fn empty() -> Self {
+        return Self {
+            handlers: BTreeMap::new(),
+        };
+    }
+
+    /// Register a handler for its uri, replacing the one previously registered for the same uri.
+    pub fn register<H: ExtensionHandler + 'static>(&mut self, handler: H) {
+        let uri = handler.uri().to_uppercase();
+        self.handlers.insert(
+            uri,
+            Box::new(move |extension| {
+                let output = handler.decode(extension)?;
+                return Ok(Box::new(output) as Box<dyn Any>);
+            }),
+        );
+    }
+
+    /// Whether a handler is registered for the uri (case insensitive)
+    pub fn contains(&self, uri: &str) -> bool {
+        return self.handlers.contains_key(&uri.to_uppercase());
+    }
+
+    /// Decode an extension with the handler registered for its uri.
+    ///
+    /// None if no handler is registered for the uri.
+    pub fn decode(&self, extension: &XlsxExtension) -> Option<anyhow::Result<Box<dyn Any>>> {
+        let Some(uri) = extension.uri.as_ref() else {
+            return None;
+        };
+        let Some(handler) = self.handlers.get(&uri.to_uppercase()) else {
+            return None;
+        };
+        return Some(handler(extension));
+    }
+
+    /// Decode an extension with the handler registered for its uri into `T`.
+    ///
+    /// None if no handler is registered for the uri, or the output of the handler is not `T`.
+    pub fn decode_as<T: 'static>(&self, extension: &XlsxExtension) -> Option<anyhow::Result<T>> {
+        return match self.decode(extension)? {
+            Ok(output) => output.downcast::<T>().ok().map(|o| Ok(*o)),
+            Err(error) => Some(Err(error)),
+        };
+    }
+
+    /// Decode the first extension of the list that can be decoded into `T`.
+    pub fn find_as<T: 'static>(&self, extensions: &[XlsxExtension]) -> Option<anyhow::Result<T>> {
+        return extensions.iter().find_map(|e| self.decode_as::<T>(e));
+    }
+}
@@ -0,0 +1,141 @@
+pub mod handler;
+
+use anyhow::bail;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader, Writer,
+};
+use std::io::Read;
+
+use crate::excel::{configure_xml_reader, XmlReader};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.extensionlist?view=openxml-3.0.1
+///
+/// Future features (ex: x14 conditional formats, data validations, slicer lists and sparklines) stored by newer versions of Excel.
+///
+/// Example
+/// ```
+/// <extLst>
+///     <ext uri="{05C60535-1F16-4fd2-B633-F4F36F0B64E0}" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main">
+///         <x14:sparklineGroups xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main">...</x14:sparklineGroups>
+///     </ext>
+/// </extLst>
+/// ```
+/// extLst (Future Feature Data Storage Area)
+pub type XlsxExtensionList = Vec<XlsxExtension>;
+
+pub(crate) fn load_extension_list(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxExtensionList> {
+    let mut extensions: XlsxExtensionList = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ext" => {
+                extensions.push(XlsxExtension::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"extLst" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(extensions)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.extension?view=openxml-3.0.1
+///
+/// A single extension kept as raw xml, to be decoded by an [`handler::ExtensionHandler`] registered for its uri.
+///
+/// Example
+/// ```
+/// <ext uri="{FF2B5EF4-FFF2-40B4-BE49-F238E27FC236}" xmlns:a16="http://schemas.microsoft.com/office/drawing/2014/main">
+///     <a16:creationId id="{6B5E8C0A-5E4B-4F43-9C5A-0F4B0E9C2D11}" />
+/// </ext>
+/// ```
+/// ext (Extension)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxExtension {
+    // attributes
+    /// uri (URI): identifier of the feature. Ex: `{05C60535-1F16-4fd2-B633-F4F36F0B64E0}` for sparklines
+    pub uri: Option<String>,
+
+    /// xml of the `ext` element, including the element itself.
+    ///
+    /// Namespace prefixes declared on the ancestors of the element are not included.
+    pub xml: String,
+}
+
+impl XlsxExtension {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut extension = Self {
+            uri: None,
+            xml: String::new(),
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"uri" => extension.uri = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_event(Event::Start(e.to_owned()))?;
+
+        let mut depth: u64 = 1;
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            let event = match reader.read_event_into(&mut buf) {
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                Ok(event) => event,
+            };
+            match event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) => depth -= 1,
+                _ => {}
+            }
+            writer.write_event(event)?;
+            if depth == 0 {
+                break;
+            }
+        }
+
+        extension.xml = String::from_utf8(writer.into_inner())?;
+
+        Ok(extension)
+    }
+
+    /// Whether the uri of the extension is the one specified (case insensitive)
+    pub fn has_uri(&self, uri: &str) -> bool {
+        return self
+            .uri
+            .as_ref()
+            .is_some_and(|u| u.eq_ignore_ascii_case(uri));
+    }
+
+    /// parse the xml of the extension with the loaders of the crate
+    pub(crate) fn with_xml_reader<T>(
+        &self,
+        load: impl FnOnce(&mut Reader<&[u8]>) -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let mut reader = Reader::from_reader(self.xml.as_bytes());
+        configure_xml_reader(&mut reader);
+        return load(&mut reader);
+    }
+}
@@ -1,2 +1,4 @@
+pub mod document_properties;
 pub mod drawing;
+pub mod extension_list;
 pub mod spreadsheet;
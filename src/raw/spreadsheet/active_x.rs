@@ -0,0 +1,144 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office.activex.activexcontroldata?view=openxml-3.0.1
+///
+/// An ActiveX control, root of xl/activeX/activeX{}.xml.
+///
+/// The properties are either stored in the xml (`persistPropertyBag`), or in a binary part referenced by `r:id` (`persistStorage`, `persistStream`).
+///
+/// Example
+/// ```
+/// <ax:ocx xmlns:ax="http://schemas.microsoft.com/office/2006/activeX" ax:classid="{D7053240-CE69-11CD-A777-00DD01143C57}" ax:persistence="persistPropertyBag">
+///     <ax:ocxPr ax:name="Caption" ax:value="Submit" />
+///     <ax:ocxPr ax:name="Size" ax:value="2540;846" />
+/// </ax:ocx>
+/// ```
+/// ocx (ActiveX Control Data)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxActiveXControl {
+    // Child Elements
+    /// ocxPr (Object Property)
+    pub properties: Vec<XlsxActiveXProperty>,
+
+    // attributes
+    /// classid (Class ID). Ex: `{8BD21D40-EC42-11CE-9E0D-00AA006002F3}` for a check box
+    pub class_id: Option<String>,
+
+    /// r:id: relationship id of the binary part of the control (xl/activeX/activeX{}.bin)
+    pub id: Option<String>,
+
+    /// license (License Key)
+    pub license: Option<String>,
+
+    /// persistence (Persistence). Possible values: persistPropertyBag, persistStream, persistStreamInit, persistStorage
+    pub persistence: Option<String>,
+}
+
+impl XlsxActiveXControl {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut control = Self {
+            properties: vec![],
+            class_id: None,
+            id: None,
+            license: None,
+            persistence: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(control);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ocx" => {
+                    control.load_attributes(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ocxPr" => {
+                    control.properties.push(XlsxActiveXProperty::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ocx" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(control)
+    }
+
+    fn load_attributes(&mut self, e: &BytesStart) -> anyhow::Result<()> {
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"classid" => self.class_id = Some(string_value),
+                        b"id" => self.id = Some(string_value),
+                        b"license" => self.license = Some(string_value),
+                        b"persistence" => self.persistence = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office.activex.activexobjectproperty?view=openxml-3.0.1
+///
+/// A property of an ActiveX control persisted as a property bag.
+///
+/// Example
+/// ```
+/// <ax:ocxPr ax:name="Caption" ax:value="Submit" />
+/// ```
+/// ocxPr (Object Property)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxActiveXProperty {
+    // attributes
+    /// name (Property Name)
+    pub name: Option<String>,
+
+    /// value (Property Value)
+    pub value: Option<String>,
+}
+
+impl XlsxActiveXProperty {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut property = Self {
+            name: None,
+            value: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"name" => property.name = Some(string_value),
+                        b"value" => property.value = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(property)
+    }
+}
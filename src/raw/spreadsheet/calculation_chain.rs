@@ -0,0 +1,120 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    common_types::Coordinate,
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.calculationchain?view=openxml-3.0.1
+///
+/// Root element of the calculation chain part (xl/calcChain.xml): the order in which the cells with formulas were last calculated.
+///
+/// Example:
+/// ```
+/// <calcChain xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+///     <c r="B2" i="1" l="1"/>
+///     <c r="B3"/>
+///     <c r="D1" i="2" a="1"/>
+/// </calcChain>
+/// ```
+/// calcChain (Calculation Chain Info)
+pub type XlsxCalculationChain = Vec<XlsxCalculationCell>;
+
+pub(crate) fn load_calculation_chain(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    path: &str,
+) -> anyhow::Result<XlsxCalculationChain> {
+    let mut chain: XlsxCalculationChain = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(chain);
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"c" => {
+                chain.push(XlsxCalculationCell::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"calcChain" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(chain)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.calculationcell?view=openxml-3.0.1
+///
+/// A cell with a formula in the calculation chain.
+///
+/// Example:
+/// ```
+/// <c r="B2" i="1" l="1"/>
+/// ```
+/// c (Cell)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxCalculationCell {
+    // attributes
+    /// a (Array): whether the cell is part of an array formula
+    pub array: Option<bool>,
+
+    /// s (Child Chain): whether the cell is calculated in a child chain
+    pub child_chain: Option<bool>,
+
+    /// l (New Dependency Level): whether the cell starts a new level of dependencies
+    pub new_dependency_level: Option<bool>,
+
+    /// t (New Thread): whether the cell is calculated on a new thread
+    pub new_thread: Option<bool>,
+
+    /// r (Cell Reference)
+    pub reference: Option<Coordinate>,
+
+    /// i (Sheet Id): `sheetId` of the sheet of the cell.
+    /// Same as the previous cell of the chain if not specified.
+    pub sheet_id: Option<u64>,
+}
+
+impl XlsxCalculationCell {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut cell = Self {
+            array: None,
+            child_chain: None,
+            new_dependency_level: None,
+            new_thread: None,
+            reference: None,
+            sheet_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"a" => cell.array = string_to_bool(&string_value),
+                        b"s" => cell.child_chain = string_to_bool(&string_value),
+                        b"l" => cell.new_dependency_level = string_to_bool(&string_value),
+                        b"t" => cell.new_thread = string_to_bool(&string_value),
+                        b"r" => cell.reference = Coordinate::from_a1(a.value.as_ref()),
+                        b"i" => cell.sheet_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(cell)
+    }
+}
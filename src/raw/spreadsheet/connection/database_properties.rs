@@ -0,0 +1,62 @@
+use anyhow::bail;
+use quick_xml::{escape::unescape, events::BytesStart};
+
+use crate::helper::string_to_unsignedint;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.databaseproperties?view=openxml-3.0.1
+///
+/// Connection string and command of an ODBC, OLE DB or ADO connection.
+///
+/// Example:
+/// ```
+/// <dbPr connection="Provider=SQLOLEDB.1;Integrated Security=SSPI;Initial Catalog=Sales;Data Source=db01" command="&quot;Sales&quot;.&quot;dbo&quot;.&quot;Orders&quot;" commandType="3"/>
+/// ```
+/// dbPr (Database Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDatabaseProperties {
+    // attributes
+    /// command (Command Text)
+    pub command: Option<String>,
+
+    /// commandType (Command Type). 1: cube, 2: SQL, 3: table, 4: default, 5: list
+    pub command_type: Option<u64>,
+
+    /// connection (Connection String)
+    pub connection: Option<String>,
+
+    /// serverCommand (Command Text) of OLAP servers
+    pub server_command: Option<String>,
+}
+
+impl XlsxDatabaseProperties {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            command: None,
+            command_type: None,
+            connection: None,
+            server_command: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                    match a.key.local_name().as_ref() {
+                        b"command" => properties.command = Some(string_value),
+                        b"commandType" => {
+                            properties.command_type = string_to_unsignedint(&string_value)
+                        }
+                        b"connection" => properties.connection = Some(string_value),
+                        b"serverCommand" => properties.server_command = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+}
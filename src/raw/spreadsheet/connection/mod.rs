@@ -0,0 +1,277 @@
+use anyhow::bail;
+use database_properties::XlsxDatabaseProperties;
+use olap_properties::XlsxOlapProperties;
+use parameter::{load_parameters, XlsxParameters};
+use quick_xml::{
+    escape::unescape,
+    events::{BytesStart, Event},
+};
+use std::io::{Read, Seek};
+use text_import_properties::XlsxTextImportProperties;
+use web_query_properties::XlsxWebQueryProperties;
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{string_to_bool, string_to_unsignedint},
+    raw::extension_list::{load_extension_list, XlsxExtensionList},
+};
+
+pub mod database_properties;
+pub mod olap_properties;
+pub mod parameter;
+pub mod text_import_properties;
+pub mod web_query_properties;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.connections?view=openxml-3.0.1
+///
+/// Root element of the connections part (xl/connections.xml): the external data sources of the workbook.
+///
+/// Example:
+/// ```
+/// <connections xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+///     <connection id="1" keepAlive="1" name="Query - Sales" description="Connection to the 'Sales' query in the workbook." type="5" refreshedVersion="8" background="1" saveData="1">
+///         <dbPr connection="Provider=Microsoft.Mashup.OleDb.1;Data Source=$Workbook$;Location=Sales;Extended Properties=&quot;&quot;" command="SELECT * FROM [Sales]"/>
+///     </connection>
+///     <connection id="2" name="Rates" type="4" refreshedVersion="8" background="1">
+///         <webPr sourceData="1" parsePre="1" consecutive="1" xl2000="1" url="https://example.com/rates.html" htmlTables="1"/>
+///     </connection>
+/// </connections>
+/// ```
+/// connections (Connections)
+pub type XlsxConnections = Vec<XlsxConnection>;
+
+pub(crate) fn load_connections(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    path: &str,
+) -> anyhow::Result<XlsxConnections> {
+    let mut connections: XlsxConnections = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(connections);
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"connection" => {
+                connections.push(XlsxConnection::load(&mut reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"connections" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(connections)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.connection?view=openxml-3.0.1
+///
+/// An external data connection: ODBC, OLE DB, web query, text file and etc.
+///
+/// Example:
+/// ```
+/// <connection id="1" keepAlive="1" name="Query - Sales" type="5" refreshedVersion="8" background="1" saveData="1">
+///     <dbPr connection="Provider=Microsoft.Mashup.OleDb.1;Data Source=$Workbook$;Location=Sales" command="SELECT * FROM [Sales]"/>
+/// </connection>
+/// ```
+/// connection (Connection)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxConnection {
+    // extLst (Future Feature Data Storage Area)
+    pub extension_list: Option<XlsxExtensionList>,
+
+    // Child Elements
+    /// dbPr (Database Properties)
+    pub database_properties: Option<XlsxDatabaseProperties>,
+
+    /// olapPr (OLAP Properties)
+    pub olap_properties: Option<XlsxOlapProperties>,
+
+    /// parameters (Query Parameters)
+    pub parameters: Option<XlsxParameters>,
+
+    /// textPr (Text Import Settings)
+    pub text_import_properties: Option<XlsxTextImportProperties>,
+
+    /// webPr (Web Query Properties)
+    pub web_query_properties: Option<XlsxWebQueryProperties>,
+
+    // attributes
+    /// background (Background Refresh)
+    pub background: Option<bool>,
+
+    /// credentials (Credentials Method). Possible values: integrated, none, stored, prompt
+    pub credentials: Option<String>,
+
+    /// deleted (Deleted): whether the connection is deleted but kept for the query tables using it
+    pub deleted: Option<bool>,
+
+    /// description (Connection Description)
+    pub description: Option<String>,
+
+    /// id (Connection Id): referenced by query tables and tables (`connectionId`)
+    pub id: Option<u64>,
+
+    /// interval (Automatic Refresh Interval) in minutes
+    pub interval: Option<u64>,
+
+    /// keepAlive (Keep Connection Open)
+    pub keep_alive: Option<bool>,
+
+    /// minRefreshableVersion (Minimum Version Required for Refresh)
+    pub min_refreshable_version: Option<u64>,
+
+    /// name (Connection Name)
+    pub name: Option<String>,
+
+    /// new (New Connection): whether the connection has not been refreshed yet
+    pub new: Option<bool>,
+
+    /// odcFile (Connection File): path of the office data connection file
+    pub odc_file: Option<String>,
+
+    /// onlyUseConnectionFile (Only Use Connection File)
+    pub only_use_connection_file: Option<bool>,
+
+    /// reconnectionMethod (Reconnection Method). 1: as required, 2: always, 3: never
+    pub reconnection_method: Option<u64>,
+
+    /// refreshedVersion (Last Refresh Version)
+    pub refreshed_version: Option<u64>,
+
+    /// refreshOnLoad (Refresh On Load)
+    pub refresh_on_load: Option<bool>,
+
+    /// saveData (Save Data)
+    pub save_data: Option<bool>,
+
+    /// savePassword (Save Password)
+    pub save_password: Option<bool>,
+
+    /// singleSignOnId (SSO Id)
+    pub single_sign_on_id: Option<String>,
+
+    /// sourceFile (Source File): path of the database file
+    pub source_file: Option<String>,
+
+    /// type (Database Source Type).
+    /// 1: ODBC, 2: DAO, 3: file, 4: web query, 5: OLE DB, 6: text, 7: ADO record set, 8: DSP
+    pub r#type: Option<u64>,
+}
+
+impl XlsxConnection {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut connection = Self {
+            extension_list: None,
+            database_properties: None,
+            olap_properties: None,
+            parameters: None,
+            text_import_properties: None,
+            web_query_properties: None,
+            background: None,
+            credentials: None,
+            deleted: None,
+            description: None,
+            id: None,
+            interval: None,
+            keep_alive: None,
+            min_refreshable_version: None,
+            name: None,
+            new: None,
+            odc_file: None,
+            only_use_connection_file: None,
+            reconnection_method: None,
+            refreshed_version: None,
+            refresh_on_load: None,
+            save_data: None,
+            save_password: None,
+            single_sign_on_id: None,
+            source_file: None,
+            r#type: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                    match a.key.local_name().as_ref() {
+                        b"background" => connection.background = string_to_bool(&string_value),
+                        b"credentials" => connection.credentials = Some(string_value),
+                        b"deleted" => connection.deleted = string_to_bool(&string_value),
+                        b"description" => connection.description = Some(string_value),
+                        b"id" => connection.id = string_to_unsignedint(&string_value),
+                        b"interval" => connection.interval = string_to_unsignedint(&string_value),
+                        b"keepAlive" => connection.keep_alive = string_to_bool(&string_value),
+                        b"minRefreshableVersion" => {
+                            connection.min_refreshable_version =
+                                string_to_unsignedint(&string_value)
+                        }
+                        b"name" => connection.name = Some(string_value),
+                        b"new" => connection.new = string_to_bool(&string_value),
+                        b"odcFile" => connection.odc_file = Some(string_value),
+                        b"onlyUseConnectionFile" => {
+                            connection.only_use_connection_file = string_to_bool(&string_value)
+                        }
+                        b"reconnectionMethod" => {
+                            connection.reconnection_method = string_to_unsignedint(&string_value)
+                        }
+                        b"refreshedVersion" => {
+                            connection.refreshed_version = string_to_unsignedint(&string_value)
+                        }
+                        b"refreshOnLoad" => {
+                            connection.refresh_on_load = string_to_bool(&string_value)
+                        }
+                        b"saveData" => connection.save_data = string_to_bool(&string_value),
+                        b"savePassword" => connection.save_password = string_to_bool(&string_value),
+                        b"singleSignOnId" => connection.single_sign_on_id = Some(string_value),
+                        b"sourceFile" => connection.source_file = Some(string_value),
+                        b"type" => connection.r#type = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dbPr" => {
+                    connection.database_properties = Some(XlsxDatabaseProperties::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"olapPr" => {
+                    connection.olap_properties = Some(XlsxOlapProperties::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"parameters" => {
+                    connection.parameters = Some(load_parameters(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"textPr" => {
+                    connection.text_import_properties =
+                        Some(XlsxTextImportProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"webPr" => {
+                    connection.web_query_properties =
+                        Some(XlsxWebQueryProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    connection.extension_list = Some(load_extension_list(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"connection" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(connection)
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::bail;
+use quick_xml::{escape::unescape, events::BytesStart};
+
+use crate::helper::{string_to_bool, string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.olapproperties?view=openxml-3.0.1
+///
+/// Settings of a connection to an OLAP server.
+///
+/// Example:
+/// ```
+/// <olapPr local="1" localConnection="Provider=MSOLAP.8;Data Source=C:\cubes\sales.cub" rowDrillCount="1000" serverFill="1" serverNumberFormat="1"/>
+/// ```
+/// olapPr (OLAP Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOlapProperties {
+    // attributes
+    /// local (Local Cube)
+    pub local: Option<bool>,
+
+    /// localConnection (Local Cube Connection)
+    pub local_connection: Option<String>,
+
+    /// localRefresh (Local Refresh)
+    pub local_refresh: Option<bool>,
+
+    /// rowDrillCount (Drill Through Count)
+    pub row_drill_count: Option<u64>,
+
+    /// sendLocale (Send Locale to OLAP)
+    pub send_locale: Option<bool>,
+
+    /// serverFill (OLAP Fill Formatting)
+    pub server_fill: Option<bool>,
+
+    /// serverFont (OLAP Server Font)
+    pub server_font: Option<bool>,
+
+    /// serverFontColor (OLAP Font Formatting)
+    pub server_font_color: Option<bool>,
+
+    /// serverNumberFormat (OLAP Number Format)
+    pub server_number_format: Option<bool>,
+}
+
+impl XlsxOlapProperties {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            local: None,
+            local_connection: None,
+            local_refresh: None,
+            row_drill_count: None,
+            send_locale: None,
+            server_fill: None,
+            server_font: None,
+            server_font_color: None,
+            server_number_format: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                    match a.key.local_name().as_ref() {
+                        b"local" => properties.local = string_to_bool(&string_value),
+                        b"localConnection" => properties.local_connection = Some(string_value),
+                        b"localRefresh" => properties.local_refresh = string_to_bool(&string_value),
+                        b"rowDrillCount" => {
+                            properties.row_drill_count = string_to_unsignedint(&string_value)
+                        }
+                        b"sendLocale" => properties.send_locale = string_to_bool(&string_value),
+                        b"serverFill" => properties.server_fill = string_to_bool(&string_value),
+                        b"serverFont" => properties.server_font = string_to_bool(&string_value),
+                        b"serverFontColor" => {
+                            properties.server_font_color = string_to_bool(&string_value)
+                        }
+                        b"serverNumberFormat" => {
+                            properties.server_number_format = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::bail;
+use quick_xml::{
+    escape::unescape,
+    events::{BytesStart, Event},
+};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_float, string_to_int},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.parameters?view=openxml-3.0.1
+///
+/// Parameters of a parameterized query (ODBC and web queries).
+///
+/// Example:
+/// ```
+/// <parameters count="2">
+///     <parameter name="Region" sqlType="12" parameterType="cell" cell="Inputs!$B$1" refreshOnChange="1"/>
+///     <parameter name="Year" sqlType="4" prompt="Enter the year" />
+/// </parameters>
+/// ```
+/// parameters (Query Parameters)
+pub type XlsxParameters = Vec<XlsxParameter>;
+
+pub(crate) fn load_parameters(reader: &mut XmlReader<impl Read>) -> anyhow::Result<XlsxParameters> {
+    let mut parameters: XlsxParameters = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"parameter" => {
+                parameters.push(XlsxParameter::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"parameters" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(parameters)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.parameter?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <parameter name="Region" sqlType="12" parameterType="cell" cell="Inputs!$B$1" refreshOnChange="1"/>
+/// ```
+/// parameter (Parameter Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxParameter {
+    // attributes
+    /// boolean (Boolean): value of a boolean parameter
+    pub boolean: Option<bool>,
+
+    /// cell (Cell Reference): cell containing the value
+    pub cell: Option<String>,
+
+    /// double (Double): value of a floating point parameter
+    pub double: Option<f64>,
+
+    /// integer (Integer): value of an integer parameter
+    pub integer: Option<i64>,
+
+    /// name (Parameter Name)
+    pub name: Option<String>,
+
+    /// parameterType (Parameter Type). Possible values: prompt, value, cell
+    pub parameter_type: Option<String>,
+
+    /// prompt (Prompt): text of the prompt
+    pub prompt: Option<String>,
+
+    /// refreshOnChange (Refresh on Change)
+    pub refresh_on_change: Option<bool>,
+
+    /// sqlType (SQL Data Type)
+    pub sql_type: Option<i64>,
+
+    /// string (String): value of a string parameter
+    pub string: Option<String>,
+}
+
+impl XlsxParameter {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut parameter = Self {
+            boolean: None,
+            cell: None,
+            double: None,
+            integer: None,
+            name: None,
+            parameter_type: None,
+            prompt: None,
+            refresh_on_change: None,
+            sql_type: None,
+            string: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                    match a.key.local_name().as_ref() {
+                        b"boolean" => parameter.boolean = string_to_bool(&string_value),
+                        b"cell" => parameter.cell = Some(string_value),
+                        b"double" => parameter.double = string_to_float(&string_value),
+                        b"integer" => parameter.integer = string_to_int(&string_value),
+                        b"name" => parameter.name = Some(string_value),
+                        b"parameterType" => parameter.parameter_type = Some(string_value),
+                        b"prompt" => parameter.prompt = Some(string_value),
+                        b"refreshOnChange" => {
+                            parameter.refresh_on_change = string_to_bool(&string_value)
+                        }
+                        b"sqlType" => parameter.sql_type = string_to_int(&string_value),
+                        b"string" => parameter.string = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(parameter)
+    }
+}
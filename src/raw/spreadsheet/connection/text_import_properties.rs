@@ -0,0 +1,168 @@
+use anyhow::bail;
+use quick_xml::{
+    escape::unescape,
+    events::{BytesStart, Event},
+};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.textproperties?view=openxml-3.0.1
+///
+/// Settings of a text file import connection.
+///
+/// Example:
+/// ```
+/// <textPr codePage="65001" sourceFile="C:\data\orders.csv" delimited="1" comma="1" tab="0" firstRow="2">
+///     <textFields count="2">
+///         <textField/>
+///         <textField type="YMD"/>
+///     </textFields>
+/// </textPr>
+/// ```
+/// textPr (Text Import Settings)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTextImportProperties {
+    // Child Elements
+    /// textFields (Fields): `type` of each field. Ex: general, text, skip, YMD
+    pub text_fields: Vec<Option<String>>,
+
+    // attributes
+    /// characterSet (Character Set)
+    pub character_set: Option<String>,
+
+    /// codePage (Code Page)
+    pub code_page: Option<u64>,
+
+    /// comma (Comma as Separator)
+    pub comma: Option<bool>,
+
+    /// consecutive (Consecutive Delimiters)
+    pub consecutive: Option<bool>,
+
+    /// decimal (Decimal Separator)
+    pub decimal: Option<String>,
+
+    /// delimited (Delimited File)
+    pub delimited: Option<bool>,
+
+    /// delimiter (Custom Delimiter)
+    pub delimiter: Option<String>,
+
+    /// fileType (File Type). Possible values: mac, win, dos, lin, other
+    pub file_type: Option<String>,
+
+    /// firstRow (First Row): 1-based row the import starts from
+    pub first_row: Option<u64>,
+
+    /// prompt (Prompt for File Name)
+    pub prompt: Option<bool>,
+
+    /// qualifier (Qualifier). Possible values: doubleQuote, singleQuote, none
+    pub qualifier: Option<String>,
+
+    /// semicolon (Semicolon as Separator)
+    pub semicolon: Option<bool>,
+
+    /// sourceFile (Source File Name)
+    pub source_file: Option<String>,
+
+    /// space (Space is Delimiter)
+    pub space: Option<bool>,
+
+    /// tab (Tab as Separator)
+    pub tab: Option<bool>,
+
+    /// thousands (Thousands Separator)
+    pub thousands: Option<String>,
+}
+
+impl XlsxTextImportProperties {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            text_fields: vec![],
+            character_set: None,
+            code_page: None,
+            comma: None,
+            consecutive: None,
+            decimal: None,
+            delimited: None,
+            delimiter: None,
+            file_type: None,
+            first_row: None,
+            prompt: None,
+            qualifier: None,
+            semicolon: None,
+            source_file: None,
+            space: None,
+            tab: None,
+            thousands: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                    match a.key.local_name().as_ref() {
+                        b"characterSet" => properties.character_set = Some(string_value),
+                        b"codePage" => properties.code_page = string_to_unsignedint(&string_value),
+                        b"comma" => properties.comma = string_to_bool(&string_value),
+                        b"consecutive" => properties.consecutive = string_to_bool(&string_value),
+                        b"decimal" => properties.decimal = Some(string_value),
+                        b"delimited" => properties.delimited = string_to_bool(&string_value),
+                        b"delimiter" => properties.delimiter = Some(string_value),
+                        b"fileType" => properties.file_type = Some(string_value),
+                        b"firstRow" => properties.first_row = string_to_unsignedint(&string_value),
+                        b"prompt" => properties.prompt = string_to_bool(&string_value),
+                        b"qualifier" => properties.qualifier = Some(string_value),
+                        b"semicolon" => properties.semicolon = string_to_bool(&string_value),
+                        b"sourceFile" => properties.source_file = Some(string_value),
+                        b"space" => properties.space = string_to_bool(&string_value),
+                        b"tab" => properties.tab = string_to_bool(&string_value),
+                        b"thousands" => properties.thousands = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"textField" => {
+                    let mut field_type: Option<String> = None;
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value =
+                                    unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                                match a.key.local_name().as_ref() {
+                                    b"type" => field_type = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                    properties.text_fields.push(field_type);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"textPr" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+}
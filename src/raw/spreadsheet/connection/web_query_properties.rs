@@ -0,0 +1,150 @@
+use anyhow::bail;
+use quick_xml::{
+    escape::unescape,
+    events::{BytesStart, Event},
+};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_bool};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.webqueryproperties?view=openxml-3.0.1
+///
+/// Settings of a web query connection.
+///
+/// Example:
+/// ```
+/// <webPr sourceData="1" parsePre="1" consecutive="1" xl2000="1" url="https://example.com/rates.html" htmlTables="1" htmlFormat="all">
+///     <tables count="2">
+///         <s v="rates"/>
+///         <x v="2"/>
+///     </tables>
+/// </webPr>
+/// ```
+/// webPr (Web Query Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxWebQueryProperties {
+    // Child Elements
+    /// tables (Tables): names (`s`) or 1-based indexes (`x`) of the html tables imported
+    pub tables: Vec<String>,
+
+    // attributes
+    /// consecutive (Consecutive Delimiters)
+    pub consecutive: Option<bool>,
+
+    /// editPage (Edit Query)
+    pub edit_page: Option<String>,
+
+    /// firstRow (Use First Row)
+    pub first_row: Option<bool>,
+
+    /// htmlFormat (HTML Formatting Handling). Possible values: none, rtf, all
+    pub html_format: Option<String>,
+
+    /// htmlTables (HTML Tables Only)
+    pub html_tables: Option<bool>,
+
+    /// parsePre (Parse PRE)
+    pub parse_pre: Option<bool>,
+
+    /// post (POST Method): the string sent with the POST method
+    pub post: Option<String>,
+
+    /// sourceData (Import Source Data)
+    pub source_data: Option<bool>,
+
+    /// textDates (Dates as Text)
+    pub text_dates: Option<bool>,
+
+    /// url (URL)
+    pub url: Option<String>,
+
+    /// xl2000 (Refreshed in Excel 2000)
+    pub xl2000: Option<bool>,
+
+    /// xl97 (Created in Excel 97)
+    pub xl97: Option<bool>,
+
+    /// xml (XML Source)
+    pub xml: Option<bool>,
+}
+
+impl XlsxWebQueryProperties {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            tables: vec![],
+            consecutive: None,
+            edit_page: None,
+            first_row: None,
+            html_format: None,
+            html_tables: None,
+            parse_pre: None,
+            post: None,
+            source_data: None,
+            text_dates: None,
+            url: None,
+            xl2000: None,
+            xl97: None,
+            xml: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                    match a.key.local_name().as_ref() {
+                        b"consecutive" => properties.consecutive = string_to_bool(&string_value),
+                        b"editPage" => properties.edit_page = Some(string_value),
+                        b"firstRow" => properties.first_row = string_to_bool(&string_value),
+                        b"htmlFormat" => properties.html_format = Some(string_value),
+                        b"htmlTables" => properties.html_tables = string_to_bool(&string_value),
+                        b"parsePre" => properties.parse_pre = string_to_bool(&string_value),
+                        b"post" => properties.post = Some(string_value),
+                        b"sourceData" => properties.source_data = string_to_bool(&string_value),
+                        b"textDates" => properties.text_dates = string_to_bool(&string_value),
+                        b"url" => properties.url = Some(string_value),
+                        b"xl2000" => properties.xl2000 = string_to_bool(&string_value),
+                        b"xl97" => properties.xl97 = string_to_bool(&string_value),
+                        b"xml" => properties.xml = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"s" || e.local_name().as_ref() == b"x" =>
+                {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value =
+                                    unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                                match a.key.local_name().as_ref() {
+                                    b"v" => properties.tables.push(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"webPr" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+}
@@ -0,0 +1,262 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ddelink?view=openxml-3.0.1
+///
+/// A Dynamic Data Exchange (DDE) link to an application.
+///
+/// Example:
+/// ```
+/// <ddeLink ddeService="Excel" ddeTopic="C:\Book1.xls">
+///     <ddeItems>
+///         <ddeItem name="R1C1" advise="1">
+///             <values rows="1" cols="1">
+///                 <value t="n"><val>12</val></value>
+///             </values>
+///         </ddeItem>
+///     </ddeItems>
+/// </ddeLink>
+/// ```
+/// ddeLink (DDE Connection)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDdeLink {
+    // Child Elements
+    // ddeItems (DDE Items Collection)	§18.14.6
+    pub dde_items: Vec<XlsxDdeItem>,
+
+    // attributes
+    /// ddeService (Service name)
+    pub dde_service: Option<String>,
+
+    /// ddeTopic (Topic for DDE server)
+    pub dde_topic: Option<String>,
+}
+
+impl XlsxDdeLink {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut link = Self {
+            dde_items: vec![],
+            dde_service: None,
+            dde_topic: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ddeService" => link.dde_service = Some(string_value),
+                        b"ddeTopic" => link.dde_topic = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ddeItem" => {
+                    link.dde_items.push(XlsxDdeItem::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ddeLink" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(link)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ddeitem?view=openxml-3.0.1
+///
+/// An item of a DDE link, with its cached values.
+///
+/// Example:
+/// ```
+/// <ddeItem name="R1C1" advise="1">
+///     <values rows="1" cols="1">
+///         <value t="n"><val>12</val></value>
+///     </values>
+/// </ddeItem>
+/// ```
+/// ddeItem (DDE Item definition)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDdeItem {
+    // Child Elements
+    // values (DDE Name Values)	§18.14.21
+    /// Values by row then column
+    pub values: Vec<XlsxDdeValue>,
+
+    // attributes of values
+    /// rows (Rows)
+    pub rows: Option<u64>,
+
+    /// cols (Columns)
+    pub cols: Option<u64>,
+
+    // attributes
+    /// advise (Advise)
+    ///
+    /// Whether the server notifies the application of changes.
+    pub advise: Option<bool>,
+
+    /// name (DDE Name)
+    pub name: Option<String>,
+
+    /// ole (OLE)
+    pub ole: Option<bool>,
+
+    /// preferPic (Data is an Image)
+    pub prefer_pic: Option<bool>,
+}
+
+impl XlsxDdeItem {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut item = Self {
+            values: vec![],
+            rows: None,
+            cols: None,
+            advise: None,
+            name: None,
+            ole: None,
+            prefer_pic: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"advise" => item.advise = string_to_bool(&string_value),
+                        b"name" => item.name = Some(string_value),
+                        b"ole" => item.ole = string_to_bool(&string_value),
+                        b"preferPic" => item.prefer_pic = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"values" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"rows" => item.rows = string_to_unsignedint(&string_value),
+                                    b"cols" => item.cols = string_to_unsignedint(&string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"value" => {
+                    item.values.push(XlsxDdeValue::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ddeItem" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(item)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.value?view=openxml-3.0.1
+///
+/// A cached value of a DDE item.
+///
+/// Example:
+/// ```
+/// <value t="str"><val>Apples</val></value>
+/// ```
+/// value (Value)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDdeValue {
+    // Child Elements
+    // val (DDE Link Value)	§18.14.20
+    pub value: Option<String>,
+
+    // attributes
+    /// t (DDE Value Type)
+    ///
+    /// b, e, n (default), nil, str
+    pub r#type: Option<String>,
+}
+
+impl XlsxDdeValue {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut value = Self {
+            value: None,
+            r#type: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"t" => value.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        let mut in_value = false;
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"val" => {
+                    in_value = true;
+                    value.value = Some(String::new());
+                }
+                Ok(Event::Text(t)) if in_value => {
+                    if let Some(v) = value.value.as_mut() {
+                        v.push_str(&t.unescape()?);
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"val" => in_value = false,
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"value" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,242 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_unsignedint};
+
+use super::external_sheet_data::{load_external_sheet_data_set, XlsxExternalSheetDataSet};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalbook?view=openxml-3.0.1
+///
+/// This element is a link to another workbook,
+/// with the cached sheet names, defined names and values of the cells referenced.
+///
+/// Example:
+/// ```
+/// <externalBook r:id="rId1">
+///     <sheetNames>
+///         <sheetName val="Prices"/>
+///     </sheetNames>
+///     <definedNames>
+///         <definedName name="Rate" refersTo="=Prices!$B$2"/>
+///     </definedNames>
+///     <sheetDataSet>
+///         <sheetData sheetId="0">
+///             <row r="2">
+///                 <cell r="B2"><v>12.5</v></cell>
+///             </row>
+///         </sheetData>
+///     </sheetDataSet>
+/// </externalBook>
+/// ```
+/// externalBook (External Workbook)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExternalBook {
+    // Child Elements
+    // definedNames (Defined Names)	§18.14.6
+    pub defined_names: Option<XlsxExternalDefinedNames>,
+
+    // sheetDataSet (Cached Worksheet Data)	§18.14.15
+    pub sheet_data_set: Option<XlsxExternalSheetDataSet>,
+
+    // sheetNames (Supporting Workbook Sheet Names)	§18.14.17
+    pub sheet_names: Option<XlsxExternalSheetNames>,
+
+    // attributes
+    /// id (Relationship to supporting book file path)
+    ///
+    /// Relationship id of the path of the external workbook, in the external link part relationships.
+    pub id: Option<String>,
+}
+
+impl XlsxExternalBook {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut book = Self {
+            defined_names: None,
+            sheet_data_set: None,
+            sheet_names: None,
+            id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => book.id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"definedNames" => {
+                    book.defined_names = Some(load_external_defined_names(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetDataSet" => {
+                    book.sheet_data_set = Some(load_external_sheet_data_set(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetNames" => {
+                    book.sheet_names = Some(load_external_sheet_names(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"externalBook" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(book)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetnames?view=openxml-3.0.1
+///
+/// Names of the sheets of the external workbook.
+/// `sheetId` of the cached data and defined names is the zero-based index in this list.
+///
+/// Example:
+/// ```
+/// <sheetNames>
+///     <sheetName val="Prices"/>
+///     <sheetName val="Rates"/>
+/// </sheetNames>
+/// ```
+/// sheetNames (Supporting Workbook Sheet Names)
+pub type XlsxExternalSheetNames = Vec<String>;
+
+pub(crate) fn load_external_sheet_names(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxExternalSheetNames> {
+    let mut names: XlsxExternalSheetNames = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetName" => {
+                let mut name = String::new();
+                for a in e.attributes() {
+                    match a {
+                        Ok(a) => {
+                            if a.key.local_name().as_ref() == b"val" {
+                                name = String::from_utf8(a.value.to_vec())?;
+                            }
+                        }
+                        Err(error) => {
+                            bail!(error.to_string())
+                        }
+                    }
+                }
+                names.push(name);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetNames" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(names)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externaldefinednames?view=openxml-3.0.1
+///
+/// Defined names of the external workbook referenced by this workbook.
+///
+/// Example:
+/// ```
+/// <definedNames>
+///     <definedName name="Rate" refersTo="=Prices!$B$2"/>
+///     <definedName name="Local" refersTo="=Rates!$A$1" sheetId="1"/>
+/// </definedNames>
+/// ```
+/// definedNames (Defined Names)
+pub type XlsxExternalDefinedNames = Vec<XlsxExternalDefinedName>;
+
+pub(crate) fn load_external_defined_names(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxExternalDefinedNames> {
+    let mut names: XlsxExternalDefinedNames = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"definedName" => {
+                names.push(XlsxExternalDefinedName::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"definedNames" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(names)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externaldefinedname?view=openxml-3.0.1
+///
+/// A defined name of the external workbook.
+///
+/// Example:
+/// ```
+/// <definedName name="Rate" refersTo="=Prices!$B$2"/>
+/// ```
+/// definedName (Defined Name)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExternalDefinedName {
+    // attributes
+    /// name (Defined Name)
+    pub name: Option<String>,
+
+    /// refersTo (Refers To)
+    ///
+    /// Formula of the defined name. Ex: `=Prices!$B$2`
+    pub refers_to: Option<String>,
+
+    /// sheetId (Sheet Id)
+    ///
+    /// Zero-based index of the sheet in `sheetNames` for names scoped to a sheet.
+    pub sheet_id: Option<u64>,
+}
+
+impl XlsxExternalDefinedName {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut name = Self {
+            name: None,
+            refers_to: None,
+            sheet_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"name" => name.name = Some(string_value),
+                        b"refersTo" => name.refers_to = Some(string_value),
+                        b"sheetId" => name.sheet_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(name)
+    }
+}
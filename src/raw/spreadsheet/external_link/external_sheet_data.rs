@@ -0,0 +1,269 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    common_types::Coordinate,
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetdataset?view=openxml-3.0.1
+///
+/// Cached values of the cells of the external workbook referenced by this workbook.
+///
+/// Example:
+/// ```
+/// <sheetDataSet>
+///     <sheetData sheetId="0">
+///         <row r="2">
+///             <cell r="B2"><v>12.5</v></cell>
+///             <cell r="C2" t="str"><v>Apples</v></cell>
+///         </row>
+///     </sheetData>
+///     <sheetData sheetId="1" refreshError="1"/>
+/// </sheetDataSet>
+/// ```
+/// sheetDataSet (Cached Worksheet Data)
+pub type XlsxExternalSheetDataSet = Vec<XlsxExternalSheetData>;
+
+pub(crate) fn load_external_sheet_data_set(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxExternalSheetDataSet> {
+    let mut set: XlsxExternalSheetDataSet = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetData" => {
+                set.push(XlsxExternalSheetData::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetDataSet" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(set)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalsheetdata?view=openxml-3.0.1
+///
+/// Cached values of a sheet of the external workbook.
+///
+/// Example:
+/// ```
+/// <sheetData sheetId="0">
+///     <row r="2">
+///         <cell r="B2"><v>12.5</v></cell>
+///     </row>
+/// </sheetData>
+/// ```
+/// sheetData (External Sheet Data Set)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExternalSheetData {
+    // Child Elements
+    // row (Row)	§18.14.13
+    pub rows: Vec<XlsxExternalRow>,
+
+    // attributes
+    /// refreshError (Last Refresh Resulted in Error)
+    pub refresh_error: Option<bool>,
+
+    /// sheetId (Sheet Id)
+    ///
+    /// Zero-based index of the sheet in `sheetNames`.
+    pub sheet_id: Option<u64>,
+}
+
+impl XlsxExternalSheetData {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut sheet_data = Self {
+            rows: vec![],
+            refresh_error: None,
+            sheet_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"refreshError" => sheet_data.refresh_error = string_to_bool(&string_value),
+                        b"sheetId" => sheet_data.sheet_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"row" => {
+                    sheet_data.rows.push(XlsxExternalRow::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetData" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(sheet_data)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalrow?view=openxml-3.0.1
+///
+/// A row of cached values.
+///
+/// Example:
+/// ```
+/// <row r="2">
+///     <cell r="B2"><v>12.5</v></cell>
+/// </row>
+/// ```
+/// row (Row)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExternalRow {
+    // Child Elements
+    // cell (External Cell Data)	§18.14.1
+    pub cells: Vec<XlsxExternalCell>,
+
+    // attributes
+    /// r (Row)
+    ///
+    /// 1-based row index
+    pub r: Option<u64>,
+}
+
+impl XlsxExternalRow {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut row = Self {
+            cells: vec![],
+            r: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"r" => row.r = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cell" => {
+                    row.cells.push(XlsxExternalCell::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"row" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(row)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalcell?view=openxml-3.0.1
+///
+/// A cached cell value.
+///
+/// Example:
+/// ```
+/// <cell r="C2" t="str"><v>Apples</v></cell>
+/// ```
+/// cell (External Cell Data)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExternalCell {
+    // Child Elements
+    // v (Value)	§18.14.14
+    pub value: Option<String>,
+
+    // attributes
+    /// r (Reference)
+    pub coordinate: Option<Coordinate>,
+
+    /// t (Type)
+    ///
+    /// Cell data type: b, d, e, n (default), s, str, inlineStr.
+    pub r#type: Option<String>,
+
+    /// vm (Value Metadata Index)
+    pub value_metadata: Option<u64>,
+}
+
+impl XlsxExternalCell {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut cell = Self {
+            value: None,
+            coordinate: None,
+            r#type: None,
+            value_metadata: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"r" => cell.coordinate = Coordinate::from_a1(&a.value),
+                        b"t" => cell.r#type = Some(string_value),
+                        b"vm" => cell.value_metadata = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        let mut in_value = false;
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"v" => {
+                    in_value = true;
+                    cell.value = Some(String::new());
+                }
+                Ok(Event::Text(t)) if in_value => {
+                    if let Some(value) = cell.value.as_mut() {
+                        value.push_str(&t.unescape()?);
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"v" => in_value = false,
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"cell" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(cell)
+    }
+}
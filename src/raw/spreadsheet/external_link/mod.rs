@@ -0,0 +1,97 @@
+use anyhow::bail;
+use dde_link::XlsxDdeLink;
+use external_book::XlsxExternalBook;
+use ole_link::XlsxOleLink;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+pub mod dde_link;
+pub mod external_book;
+pub mod external_sheet_data;
+pub mod ole_link;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externallink?view=openxml-3.0.1
+///
+/// Root element of the external link part (xl/externalLinks/externalLink{}.xml).
+///
+/// An external link is one of: a link to another workbook, a DDE link, or an OLE link.
+///
+/// Example:
+/// ```
+/// <externalLink xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+///     <externalBook r:id="rId1">
+///         <sheetNames>
+///             <sheetName val="Prices"/>
+///         </sheetNames>
+///         <definedNames>
+///             <definedName name="Rate" refersTo="=Prices!$B$2"/>
+///         </definedNames>
+///         <sheetDataSet>
+///             <sheetData sheetId="0">
+///                 <row r="2">
+///                     <cell r="B2"><v>12.5</v></cell>
+///                 </row>
+///             </sheetData>
+///         </sheetDataSet>
+///     </externalBook>
+/// </externalLink>
+/// ```
+/// externalLink (External Reference)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExternalLink {
+    // extLst (Future Feature Data Storage Area)	Not supported
+
+    // Child Elements
+    // ddeLink (DDE Connection)	§18.14.4
+    pub dde_link: Option<XlsxDdeLink>,
+
+    // externalBook (External Workbook)	§18.14.7
+    pub external_book: Option<XlsxExternalBook>,
+
+    // oleLink (OLE Link)	§18.14.10
+    pub ole_link: Option<XlsxOleLink>,
+}
+
+impl XlsxExternalLink {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut link = Self {
+            dde_link: None,
+            external_book: None,
+            ole_link: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(link);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ddeLink" => {
+                    link.dde_link = Some(XlsxDdeLink::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"externalBook" => {
+                    link.external_book = Some(XlsxExternalBook::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"oleLink" => {
+                    link.ole_link = Some(XlsxOleLink::load(&mut reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"externalLink" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(link);
+    }
+}
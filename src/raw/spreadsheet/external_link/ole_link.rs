@@ -0,0 +1,135 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_bool};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.olelink?view=openxml-3.0.1
+///
+/// A link to an OLE object (an object of another application).
+///
+/// Example:
+/// ```
+/// <oleLink r:id="rId1" progId="Word.Document.12">
+///     <oleItems>
+///         <oleItem name="Section1" advise="1" preferPic="1"/>
+///     </oleItems>
+/// </oleLink>
+/// ```
+/// oleLink (OLE Link)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOleLink {
+    // Child Elements
+    // oleItems (OLE Link Items)	§18.14.12
+    pub ole_items: Vec<XlsxOleItem>,
+
+    // attributes
+    /// id (OLE Link Relationship)
+    ///
+    /// Relationship id of the path of the linked object, in the external link part relationships.
+    pub id: Option<String>,
+
+    /// progId (OLE Link ProgID)
+    pub prog_id: Option<String>,
+}
+
+impl XlsxOleLink {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut link = Self {
+            ole_items: vec![],
+            id: None,
+            prog_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => link.id = Some(string_value),
+                        b"progId" => link.prog_id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"oleItem" => {
+                    link.ole_items.push(XlsxOleItem::load(e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"oleLink" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(link)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.oleitem?view=openxml-3.0.1
+///
+/// An item of an OLE link.
+///
+/// Example:
+/// ```
+/// <oleItem name="Section1" advise="1" preferPic="1"/>
+/// ```
+/// oleItem (OLE Link Item)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOleItem {
+    // attributes
+    /// advise (Advise)
+    pub advise: Option<bool>,
+
+    /// icon (Icon)
+    ///
+    /// Whether the linked object is displayed as an icon.
+    pub icon: Option<bool>,
+
+    /// name (OLE Name)
+    pub name: Option<String>,
+
+    /// preferPic (Object is an Image)
+    pub prefer_pic: Option<bool>,
+}
+
+impl XlsxOleItem {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut item = Self {
+            advise: None,
+            icon: None,
+            name: None,
+            prefer_pic: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"advise" => item.advise = string_to_bool(&string_value),
+                        b"icon" => item.icon = string_to_bool(&string_value),
+                        b"name" => item.name = Some(string_value),
+                        b"preferPic" => item.prefer_pic = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(item)
+    }
+}
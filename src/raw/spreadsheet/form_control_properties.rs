@@ -0,0 +1,234 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{extract_val_attribute, string_to_bool, string_to_int, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.formcontrolproperties?view=openxml-3.0.1
+///
+/// Properties of a form control, root of xl/ctrlProps/ctrlProp{}.xml.
+///
+/// Example
+/// ```
+/// <formControlPr xmlns="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main" objectType="Drop" dropStyle="combo" dx="16" fmlaLink="$D$1" fmlaRange="$E$1:$E$5" sel="3" val="0">
+///     <itemLst>
+///         <item val="North" />
+///         <item val="South" />
+///     </itemLst>
+/// </formControlPr>
+/// ```
+/// formControlPr (Form Control Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxFormControlProperties {
+    // Child Elements
+    /// itemLst (List Items): items of list boxes and dropdowns that are not linked to an input range
+    pub items: Vec<String>,
+
+    // attributes
+    /// checked (Checked). Possible values: Unchecked, Checked, Mixed
+    pub checked: Option<String>,
+
+    /// colored (Colored)
+    pub colored: Option<bool>,
+
+    /// dropLines (Dropdown Lines)
+    pub drop_lines: Option<u64>,
+
+    /// dropStyle (Dropdown Style). Possible values: combo, comboedit, simple
+    pub drop_style: Option<String>,
+
+    /// dx (Scroll Bar Width)
+    pub dx: Option<u64>,
+
+    /// firstButton (First Radio Button)
+    pub first_button: Option<bool>,
+
+    /// fmlaGroup (Linked Formula - Group Box)
+    pub fmla_group: Option<String>,
+
+    /// fmlaLink (Linked Formula): cell linked to the value of the control
+    pub fmla_link: Option<String>,
+
+    /// fmlaRange (List Items Source Range)
+    pub fmla_range: Option<String>,
+
+    /// fmlaTxbx (Linked Formula - Textbox)
+    pub fmla_txbx: Option<String>,
+
+    /// horiz (Horizontal)
+    pub horizontal: Option<bool>,
+
+    /// inc (Scroll Bar Increment)
+    pub increment: Option<u64>,
+
+    /// lockText (Lock Text)
+    pub lock_text: Option<bool>,
+
+    /// max (Maximum Value)
+    pub max: Option<i64>,
+
+    /// min (Minimum Value)
+    pub min: Option<i64>,
+
+    /// multiSel (Multiple Selection): comma separated 1 based indexes of the selected items
+    pub multi_selection: Option<String>,
+
+    /// noThreeD (Disable 3D)
+    pub no_three_d: Option<bool>,
+
+    /// objectType (Object Type)
+    ///
+    /// Possible values: Button, CheckBox, Dialog, Drop, EditBox, GBox, Label, List, Radio, Scroll, Spin
+    pub object_type: Option<String>,
+
+    /// page (Page)
+    pub page: Option<u64>,
+
+    /// sel (Selected Item): 1 based index of the selected item, 0 if nothing is selected
+    pub selected: Option<u64>,
+
+    /// selType (Selection Type). Possible values: single, multi, extended
+    pub selection_type: Option<String>,
+
+    /// textHAlign (Horizontal Text Alignment)
+    pub text_horizontal_align: Option<String>,
+
+    /// textVAlign (Vertical Text Alignment)
+    pub text_vertical_align: Option<String>,
+
+    /// val (Current Value)
+    pub value: Option<i64>,
+}
+
+impl XlsxFormControlProperties {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            items: vec![],
+            checked: None,
+            colored: None,
+            drop_lines: None,
+            drop_style: None,
+            dx: None,
+            first_button: None,
+            fmla_group: None,
+            fmla_link: None,
+            fmla_range: None,
+            fmla_txbx: None,
+            horizontal: None,
+            increment: None,
+            lock_text: None,
+            max: None,
+            min: None,
+            multi_selection: None,
+            no_three_d: None,
+            object_type: None,
+            page: None,
+            selected: None,
+            selection_type: None,
+            text_horizontal_align: None,
+            text_vertical_align: None,
+            value: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(properties);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"formControlPr" => {
+                    properties.load_attributes(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"itemLst" => {
+                    properties.items = load_items(&mut reader)?;
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"formControlPr" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+
+    fn load_attributes(&mut self, e: &BytesStart) -> anyhow::Result<()> {
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"checked" => self.checked = Some(string_value),
+                        b"colored" => self.colored = string_to_bool(&string_value),
+                        b"dropLines" => self.drop_lines = string_to_unsignedint(&string_value),
+                        b"dropStyle" => self.drop_style = Some(string_value),
+                        b"dx" => self.dx = string_to_unsignedint(&string_value),
+                        b"firstButton" => self.first_button = string_to_bool(&string_value),
+                        b"fmlaGroup" => self.fmla_group = Some(string_value),
+                        b"fmlaLink" => self.fmla_link = Some(string_value),
+                        b"fmlaRange" => self.fmla_range = Some(string_value),
+                        b"fmlaTxbx" => self.fmla_txbx = Some(string_value),
+                        b"horiz" => self.horizontal = string_to_bool(&string_value),
+                        b"inc" => self.increment = string_to_unsignedint(&string_value),
+                        b"lockText" => self.lock_text = string_to_bool(&string_value),
+                        b"max" => self.max = string_to_int(&string_value),
+                        b"min" => self.min = string_to_int(&string_value),
+                        b"multiSel" => self.multi_selection = Some(string_value),
+                        b"noThreeD" => self.no_three_d = string_to_bool(&string_value),
+                        b"objectType" => self.object_type = Some(string_value),
+                        b"page" => self.page = string_to_unsignedint(&string_value),
+                        b"sel" => self.selected = string_to_unsignedint(&string_value),
+                        b"selType" => self.selection_type = Some(string_value),
+                        b"textHAlign" => self.text_horizontal_align = Some(string_value),
+                        b"textVAlign" => self.text_vertical_align = Some(string_value),
+                        b"val" => self.value = string_to_int(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.listitems?view=openxml-3.0.1
+///
+/// Example
+/// ```
+/// <itemLst>
+///     <item val="North" />
+/// </itemLst>
+/// ```
+/// itemLst (List Items)
+fn load_items(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Vec<String>> {
+    let mut items: Vec<String> = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"item" => {
+                if let Some(item) = extract_val_attribute(e)? {
+                    items.push(item);
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"itemLst" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(items)
+}
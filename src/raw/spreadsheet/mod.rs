@@ -8,6 +8,34 @@ pub mod workbook;
 pub mod sheet;
 // root of table xmls
 pub mod table;
+// root of pivot table xmls
+pub mod pivot_table;
+// root of pivot cache definition and records xmls
+pub mod pivot_cache;
+// root of external link xmls
+pub mod external_link;
+// root of xl/embeddings/oleObject{}.bin (binary)
+pub mod ole_package;
+// root of xl/vbaProject.bin (binary)
+pub mod vba_project;
+// root of xl/ctrlProps/ctrlProp{}.xml
+pub mod form_control_properties;
+// root of xl/activeX/activeX{}.xml
+pub mod active_x;
+// root of xl/slicers/slicer{}.xml and xl/slicerCaches/slicerCache{}.xml
+pub mod slicer;
+// root of xl/timelines/timeline{}.xml and xl/timelineCaches/timelineCache{}.xml
+pub mod timeline;
+// root of xl/connections.xml
+pub mod connection;
+// root of xl/calcChain.xml
+pub mod calculation_chain;
+// root of xl/queryTables/queryTable{}.xml
+pub mod query_table;
+// root of xl/xmlMaps.xml
+pub mod xml_map;
+// root of xl/tables/tableSingleCells{}.xml
+pub mod single_xml_cell;
 
 // common
 pub mod ct_types;
@@ -0,0 +1,89 @@
+use anyhow::bail;
+use cfb::CompoundFile;
+use std::io::{Cursor, Read};
+
+/// https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleds/df4f6c8b-e6f8-4ed4-9cd4-5a5b4bb3c9a6
+///
+/// OLE1 "Package" object parsed from the `\x01Ole10Native` stream of an embedded xl/embeddings/oleObject{}.bin.
+///
+/// Wraps an arbitrary file (a text file, a zip...) with its original file name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOlePackage {
+    /// Label: original file name. Ex: `notes.txt`
+    pub file_name: String,
+
+    /// Full path of the file when it was embedded. Ex: `C:\Users\me\notes.txt`
+    pub source_path: String,
+
+    /// Path of the temporary file the object was extracted to
+    pub temp_path: String,
+
+    /// Content of the original file
+    pub contents: Vec<u8>,
+}
+
+impl XlsxOlePackage {
+    /// * bytes: content of the embedded part
+    ///
+    /// None if the part is not a Compound File Binary with an `\x01Ole10Native` stream.
+    pub(crate) fn load(bytes: &[u8]) -> anyhow::Result<Option<Self>> {
+        let Ok(mut compound_file) = CompoundFile::open(Cursor::new(bytes)) else {
+            return Ok(None);
+        };
+        let Ok(mut stream) = compound_file.open_stream("/\u{1}Ole10Native") else {
+            return Ok(None);
+        };
+        let mut native: Vec<u8> = vec![];
+        stream.read_to_end(&mut native)?;
+
+        // Size (4) of the native data, then Type (2)
+        let mut position: usize = 6;
+        let file_name = read_c_string(&native, &mut position)?;
+        let source_path = read_c_string(&native, &mut position)?;
+        // Reserved (2) and Type (2)
+        position += 4;
+        let temp_path_size = read_u32(&native, &mut position)? as usize;
+        let temp_path = read_bytes(&native, &mut position, temp_path_size)?;
+        let temp_path = decode_ansi(temp_path).trim_end_matches('\0').to_string();
+        let contents_size = read_u32(&native, &mut position)? as usize;
+        let contents = read_bytes(&native, &mut position, contents_size)?.to_vec();
+
+        return Ok(Some(Self {
+            file_name,
+            source_path,
+            temp_path,
+            contents,
+        }));
+    }
+}
+
+fn read_u32(bytes: &[u8], position: &mut usize) -> anyhow::Result<u32> {
+    let value = read_bytes(bytes, position, 4)?;
+    return Ok(u32::from_le_bytes([value[0], value[1], value[2], value[3]]));
+}
+
+fn read_bytes<'a>(bytes: &'a [u8], position: &mut usize, size: usize) -> anyhow::Result<&'a [u8]> {
+    let Some(value) = bytes.get(*position..*position + size) else {
+        bail!("Unexpected end of the Ole10Native stream.")
+    };
+    *position += size;
+    return Ok(value);
+}
+
+/// null-terminated ANSI string
+fn read_c_string(bytes: &[u8], position: &mut usize) -> anyhow::Result<String> {
+    let Some(length) = bytes
+        .get(*position..)
+        .and_then(|b| b.iter().position(|c| *c == 0))
+    else {
+        bail!("Unexpected end of the Ole10Native stream.")
+    };
+    let value = decode_ansi(&bytes[*position..*position + length]);
+    *position += length + 1;
+    return Ok(value);
+}
+
+fn decode_ansi(bytes: &[u8]) -> String {
+    let (text, _, _) = encoding_rs::WINDOWS_1252.decode(bytes);
+    return text.into_owned();
+}
@@ -0,0 +1,198 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_int, string_to_unsignedint},
+};
+
+use super::{field_group::XlsxFieldGroup, shared_items::XlsxSharedItems};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cachefields?view=openxml-3.0.1
+///
+/// Fields of the pivot cache, one per column of the source data plus calculated and grouped fields.
+///
+/// Example:
+/// ```
+/// <cacheFields count="3">
+///     <cacheField name="Region" numFmtId="0">
+///         <sharedItems count="2">
+///             <s v="East"/>
+///             <s v="West"/>
+///         </sharedItems>
+///     </cacheField>
+///     <cacheField name="Sales" numFmtId="0">
+///         <sharedItems containsSemiMixedTypes="0" containsString="0" containsNumber="1" minValue="10" maxValue="250"/>
+///     </cacheField>
+/// </cacheFields>
+/// ```
+///
+/// cacheFields (Cache Fields)
+pub type XlsxCacheFields = Vec<XlsxCacheField>;
+
+pub(crate) fn load_cache_fields(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxCacheFields> {
+    let mut fields: XlsxCacheFields = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cacheField" => {
+                fields.push(XlsxCacheField::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"cacheFields" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `cacheFields`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(fields)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cachefield?view=openxml-3.0.1
+///
+/// A single field of the pivot cache.
+///
+/// Example:
+/// ```
+/// <cacheField name="Region" numFmtId="0">
+///     <sharedItems count="2">
+///         <s v="East"/>
+///         <s v="West"/>
+///     </sharedItems>
+/// </cacheField>
+/// ```
+///
+/// cacheField (PivotCache Field)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxCacheField {
+    // extLst (Future Feature Data Storage Area)	Not supported
+    // mpMap (Member Properties Map)	Not supported
+
+    // Child Elements
+    /// fieldGroup (Field Group Properties)
+    pub field_group: Option<XlsxFieldGroup>,
+
+    /// sharedItems (Shared Items)
+    pub shared_items: Option<XlsxSharedItems>,
+
+    // Attributes
+    /// caption (Caption)
+    pub caption: Option<String>,
+
+    /// databaseField (Database Field)
+    ///
+    /// Whether the field comes from the source data.
+    /// Calculated and grouped fields are not database fields and do not appear in the records.
+    pub database_field: Option<bool>,
+
+    /// formula (Formula)
+    ///
+    /// Formula of a calculated field.
+    pub formula: Option<String>,
+
+    /// hierarchy (Hierarchy)
+    pub hierarchy: Option<i64>,
+
+    /// level (Hierarchy Level)
+    pub level: Option<u64>,
+
+    /// memberPropertyField (Member Property Field)
+    pub member_property_field: Option<bool>,
+
+    /// name (Name)
+    pub name: Option<String>,
+
+    /// numFmtId (Number Format Id)
+    pub num_fmt_id: Option<u64>,
+
+    /// propertyName (Property Name)
+    pub property_name: Option<String>,
+
+    /// serverField (Server-based Field)
+    pub server_field: Option<bool>,
+
+    /// sqlType (SQL Data Type)
+    pub sql_type: Option<i64>,
+
+    /// uniqueList (Unique List Retrieved)
+    pub unique_list: Option<bool>,
+}
+
+impl XlsxCacheField {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut field = Self {
+            field_group: None,
+            shared_items: None,
+            caption: None,
+            database_field: None,
+            formula: None,
+            hierarchy: None,
+            level: None,
+            member_property_field: None,
+            name: None,
+            num_fmt_id: None,
+            property_name: None,
+            server_field: None,
+            sql_type: None,
+            unique_list: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"caption" => field.caption = Some(string_value),
+                        b"databaseField" => field.database_field = string_to_bool(&string_value),
+                        b"formula" => field.formula = Some(string_value),
+                        b"hierarchy" => field.hierarchy = string_to_int(&string_value),
+                        b"level" => field.level = string_to_unsignedint(&string_value),
+                        b"memberPropertyField" => {
+                            field.member_property_field = string_to_bool(&string_value)
+                        }
+                        b"name" => field.name = Some(string_value),
+                        b"numFmtId" => field.num_fmt_id = string_to_unsignedint(&string_value),
+                        b"propertyName" => field.property_name = Some(string_value),
+                        b"serverField" => field.server_field = string_to_bool(&string_value),
+                        b"sqlType" => field.sql_type = string_to_int(&string_value),
+                        b"uniqueList" => field.unique_list = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sharedItems" => {
+                    field.shared_items = Some(XlsxSharedItems::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"fieldGroup" => {
+                    field.field_group = Some(XlsxFieldGroup::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"cacheField" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `cacheField`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(field)
+    }
+}
@@ -0,0 +1,153 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{common_types::Dimension, excel::XmlReader, helper::string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cachesource?view=openxml-3.0.1
+///
+/// Source of the data in the pivot cache.
+///
+/// Example:
+/// ```
+/// <cacheSource type="worksheet">
+///     <worksheetSource ref="A1:D101" sheet="Data"/>
+/// </cacheSource>
+/// ```
+///
+/// cacheSource (Cache Source)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxCacheSource {
+    // extLst (Future Feature Data Storage Area)	Not supported
+    // consolidation (Consolidation Source)	Not supported
+
+    // Child Elements
+    /// worksheetSource (Worksheet PivotCache Source)
+    pub worksheet_source: Option<XlsxWorksheetSource>,
+
+    // Attributes
+    /// connectionId (Connection Index)
+    ///
+    /// Id of the connection in xl/connections.xml for external sources.
+    pub connection_id: Option<u64>,
+
+    /// type (Cache Type)
+    ///
+    /// * worksheet
+    /// * external
+    /// * consolidation
+    /// * scenario
+    pub r#type: Option<String>,
+}
+
+impl XlsxCacheSource {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut source = Self {
+            worksheet_source: None,
+            connection_id: None,
+            r#type: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"connectionId" => {
+                            source.connection_id = string_to_unsignedint(&string_value)
+                        }
+                        b"type" => source.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"worksheetSource" => {
+                    source.worksheet_source = Some(XlsxWorksheetSource::load(e)?);
+                }
+                Ok(Event::Start(ref e)) => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"cacheSource" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `cacheSource`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(source)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.worksheetsource?view=openxml-3.0.1
+///
+/// Worksheet range, table or defined name the pivot cache is built from.
+///
+/// Example:
+/// ```
+/// <worksheetSource ref="A1:D101" sheet="Data"/>
+/// <worksheetSource name="SalesTable"/>
+/// ```
+///
+/// worksheetSource (Worksheet PivotCache Source)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxWorksheetSource {
+    // Attributes
+    /// id (Relationship Id)
+    ///
+    /// Relationship id of an external workbook.
+    pub id: Option<String>,
+
+    /// name (Named Range)
+    ///
+    /// Defined name or table name.
+    pub name: Option<String>,
+
+    /// ref (Reference)
+    pub r#ref: Option<Dimension>,
+
+    /// sheet (Sheet Name)
+    pub sheet: Option<String>,
+}
+
+impl XlsxWorksheetSource {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut source = Self {
+            id: None,
+            name: None,
+            r#ref: None,
+            sheet: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => source.id = Some(string_value),
+                        b"name" => source.name = Some(string_value),
+                        b"ref" => source.r#ref = Dimension::from_a1(a.value.as_ref()),
+                        b"sheet" => source.sheet = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(source)
+    }
+}
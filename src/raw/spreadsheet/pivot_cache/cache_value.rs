@@ -0,0 +1,94 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_float, string_to_unsignedint},
+};
+
+/// A single value in the shared items, group items or records of a pivot cache.
+///
+/// Example:
+/// ```
+/// <sharedItems containsBlank="1" count="4">
+///     <s v="North"/>
+///     <n v="12.5"/>
+///     <b v="1"/>
+///     <e v="#N/A"/>
+///     <d v="2024-01-01T00:00:00"/>
+///     <m/>
+/// </sharedItems>
+/// ```
+///
+/// * b (Boolean)
+/// * d (Date Time)
+/// * e (Error Value)
+/// * m (No Value)
+/// * n (Numeric)
+/// * s (Character Value)
+/// * x (Shared Items Index): records only
+#[derive(Debug, Clone, PartialEq)]
+pub enum XlsxPivotCacheValue {
+    Boolean(bool),
+    DateTime(String),
+    Error(String),
+    Missing,
+    Number(f64),
+    String(String),
+    SharedItemIndex(u64),
+}
+
+impl XlsxPivotCacheValue {
+    /// None if the tag is not a cache value.
+    ///
+    /// Child elements (OLAP tuples and member property indexes) are skipped.
+    pub(crate) fn load(
+        reader: &mut XmlReader<impl Read>,
+        e: &BytesStart,
+    ) -> anyhow::Result<Option<Self>> {
+        let tag = e.local_name().as_ref().to_vec();
+        if !matches!(
+            tag.as_slice(),
+            b"b" | b"d" | b"e" | b"m" | b"n" | b"s" | b"x"
+        ) {
+            return Ok(None);
+        }
+
+        let mut value: Option<String> = None;
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    if a.key.local_name().as_ref() == b"v" {
+                        value = Some(String::from_utf8(a.value.to_vec())?);
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+
+        let value = match tag.as_slice() {
+            b"b" => value
+                .and_then(|v| string_to_bool(&v))
+                .map(Self::Boolean)
+                .unwrap_or(Self::Missing),
+            b"d" => value.map(Self::DateTime).unwrap_or(Self::Missing),
+            b"e" => value.map(Self::Error).unwrap_or(Self::Missing),
+            b"n" => value
+                .and_then(|v| string_to_float(&v))
+                .map(Self::Number)
+                .unwrap_or(Self::Missing),
+            b"s" => Self::String(value.unwrap_or_default()),
+            b"x" => {
+                Self::SharedItemIndex(value.and_then(|v| string_to_unsignedint(&v)).unwrap_or(0))
+            }
+            _ => Self::Missing,
+        };
+
+        return Ok(Some(value));
+    }
+}
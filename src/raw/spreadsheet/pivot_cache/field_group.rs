@@ -0,0 +1,242 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_float, string_to_unsignedint},
+};
+
+use super::cache_value::XlsxPivotCacheValue;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.fieldgroup?view=openxml-3.0.1
+///
+/// Grouping of a cache field, ex: dates grouped by months or numbers grouped by ranges.
+///
+/// Example:
+/// ```
+/// <fieldGroup base="0">
+///     <rangePr groupBy="months" startDate="2024-01-01T00:00:00" endDate="2024-12-31T00:00:00"/>
+///     <groupItems count="14">
+///         <s v="&lt;1/1/2024"/>
+///         <s v="Jan"/>
+///         <s v="&gt;12/31/2024"/>
+///     </groupItems>
+/// </fieldGroup>
+/// ```
+///
+/// fieldGroup (Field Group Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxFieldGroup {
+    // Child Elements
+    // discretePr (Discrete Grouping Properties)
+    /// Group item index for each shared item of the base field
+    pub discrete_properties: Option<Vec<u64>>,
+
+    /// groupItems (OLAP Group Items)
+    pub group_items: Option<Vec<XlsxPivotCacheValue>>,
+
+    /// rangePr (Range Grouping Properties)
+    pub range_properties: Option<XlsxRangeProperties>,
+
+    // Attributes
+    /// base (Field Base)
+    ///
+    /// Index of the cache field the group is based on.
+    pub base: Option<u64>,
+
+    /// par (Parent)
+    ///
+    /// Index of the cache field of the parent group.
+    pub par: Option<u64>,
+}
+
+impl XlsxFieldGroup {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut group = Self {
+            discrete_properties: None,
+            group_items: None,
+            range_properties: None,
+            base: None,
+            par: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"base" => group.base = string_to_unsignedint(&string_value),
+                        b"par" => group.par = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"rangePr" => {
+                    group.range_properties = Some(XlsxRangeProperties::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"discretePr" => {
+                    group.discrete_properties = Some(load_discrete_properties(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"groupItems" => {
+                    group.group_items = Some(load_group_items(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"fieldGroup" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `fieldGroup`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(group)
+    }
+}
+
+fn load_discrete_properties(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Vec<u64>> {
+    let mut indexes: Vec<u64> = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                if let Some(XlsxPivotCacheValue::SharedItemIndex(index)) =
+                    XlsxPivotCacheValue::load(reader, e)?
+                {
+                    indexes.push(index);
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"discretePr" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `discretePr`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(indexes)
+}
+
+fn load_group_items(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Vec<XlsxPivotCacheValue>> {
+    let mut items: Vec<XlsxPivotCacheValue> = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                if let Some(value) = XlsxPivotCacheValue::load(reader, e)? {
+                    items.push(value);
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"groupItems" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `groupItems`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(items)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.rangeproperties?view=openxml-3.0.1
+///
+/// Properties of a numeric or date range grouping.
+///
+/// Example:
+/// ```
+/// <rangePr groupBy="months" startDate="2024-01-01T00:00:00" endDate="2024-12-31T00:00:00"/>
+/// <rangePr startNum="0" endNum="100" groupInterval="10"/>
+/// ```
+///
+/// rangePr (Range Grouping Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxRangeProperties {
+    // Attributes
+    /// autoEnd (Source Data Set Ending Range)
+    pub auto_end: Option<bool>,
+
+    /// autoStart (Source Data Set Beginning Range)
+    pub auto_start: Option<bool>,
+
+    /// endDate (Group By Ending Date)
+    pub end_date: Option<String>,
+
+    /// endNum (Ending Value)
+    pub end_num: Option<f64>,
+
+    /// groupBy (Group By)
+    ///
+    /// * range
+    /// * seconds
+    /// * minutes
+    /// * hours
+    /// * days
+    /// * months
+    /// * quarters
+    /// * years
+    pub group_by: Option<String>,
+
+    /// groupInterval (Grouping Interval)
+    pub group_interval: Option<f64>,
+
+    /// startDate (Start Date)
+    pub start_date: Option<String>,
+
+    /// startNum (Start Value)
+    pub start_num: Option<f64>,
+}
+
+impl XlsxRangeProperties {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut properties = Self {
+            auto_end: None,
+            auto_start: None,
+            end_date: None,
+            end_num: None,
+            group_by: None,
+            group_interval: None,
+            start_date: None,
+            start_num: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"autoEnd" => properties.auto_end = string_to_bool(&string_value),
+                        b"autoStart" => properties.auto_start = string_to_bool(&string_value),
+                        b"endDate" => properties.end_date = Some(string_value),
+                        b"endNum" => properties.end_num = string_to_float(&string_value),
+                        b"groupBy" => properties.group_by = Some(string_value),
+                        b"groupInterval" => {
+                            properties.group_interval = string_to_float(&string_value)
+                        }
+                        b"startDate" => properties.start_date = Some(string_value),
+                        b"startNum" => properties.start_num = string_to_float(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+}
@@ -0,0 +1,268 @@
+use anyhow::bail;
+use cache_field::{load_cache_fields, XlsxCacheFields};
+use cache_source::XlsxCacheSource;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_float, string_to_unsignedint},
+};
+
+pub mod cache_field;
+pub mod cache_source;
+pub mod cache_value;
+pub mod field_group;
+pub mod pivot_cache_records;
+pub mod shared_items;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotcachedefinition?view=openxml-3.0.1
+///
+/// Root element of the pivot cache definition part (xl/pivotCache/pivotCacheDefinition{}.xml).
+///
+/// Example:
+/// ```
+/// <pivotCacheDefinition xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+/// r:id="rId1" refreshedBy="Author" refreshedDate="45292.5" createdVersion="8" refreshedVersion="8" minRefreshableVersion="3" recordCount="3">
+///     <cacheSource type="worksheet">
+///         <worksheetSource ref="A1:C4" sheet="Data"/>
+///     </cacheSource>
+///     <cacheFields count="3">
+///         <cacheField name="Region" numFmtId="0">
+///             <sharedItems count="2">
+///                 <s v="East"/>
+///                 <s v="West"/>
+///             </sharedItems>
+///         </cacheField>
+///         <cacheField name="Product" numFmtId="0">
+///             <sharedItems/>
+///         </cacheField>
+///         <cacheField name="Sales" numFmtId="0">
+///             <sharedItems containsSemiMixedTypes="0" containsString="0" containsNumber="1" containsInteger="1" minValue="80" maxValue="120"/>
+///         </cacheField>
+///     </cacheFields>
+/// </pivotCacheDefinition>
+/// ```
+///
+/// pivotCacheDefinition (PivotCache Definition)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPivotCacheDefinition {
+    // extLst (Future Feature Data Storage Area)	Not supported
+
+    // Child Elements	Subclause
+    /// cacheFields (CacheFields)
+    pub cache_fields: Option<XlsxCacheFields>,
+
+    // cacheHierarchies (PivotCache Hierarchies)	§18.10.1.5
+    /// cacheSource (PivotCache Source Description)
+    pub cache_source: Option<XlsxCacheSource>,
+
+    // calculatedItems (Calculated Items)	§18.10.1.9
+    // calculatedMembers (Calculated Members)	§18.10.1.11
+    // dimensions (OLAP Dimensions)	§18.10.1.28
+    // kpis (OLAP KPIs)	§18.10.1.47
+    // maps (OLAP Measure Group)	§18.10.1.52
+    // measureGroups (OLAP Measure Groups)	§18.10.1.54
+    // tupleCache (Tuple Cache)	§18.10.1.94
+
+    // Attributes
+    /// backgroundQuery (Background Query)
+    pub background_query: Option<bool>,
+
+    /// createdVersion (PivotCache Created Version)
+    pub created_version: Option<u64>,
+
+    /// enableRefresh (Enable PivotCache Refresh)
+    pub enable_refresh: Option<bool>,
+
+    /// id (Relationship Id)
+    ///
+    /// Relationship id of the pivot cache records part.
+    pub id: Option<String>,
+
+    /// invalid (Invalid Cache)
+    ///
+    /// Whether the cache needs to be refreshed.
+    pub invalid: Option<bool>,
+
+    /// minRefreshableVersion (Minimum Version Required for Refresh)
+    pub min_refreshable_version: Option<u64>,
+
+    /// missingItemsLimit (Missing Items Limit)
+    pub missing_items_limit: Option<u64>,
+
+    /// optimizeMemory (Optimize Cache for Memory)
+    pub optimize_memory: Option<bool>,
+
+    /// recordCount (PivotCache Record Count)
+    pub record_count: Option<u64>,
+
+    /// refreshedBy (Last Refreshed By)
+    pub refreshed_by: Option<String>,
+
+    /// refreshedDate (PivotCache Last Refreshed Date)
+    ///
+    /// Serial date time of the last refresh.
+    pub refreshed_date: Option<f64>,
+
+    /// refreshedDateIso (PivotCache Last Refreshed Date ISO)
+    pub refreshed_date_iso: Option<String>,
+
+    /// refreshedVersion (PivotCache Last Refreshed Version)
+    pub refreshed_version: Option<u64>,
+
+    /// refreshOnLoad (Refresh On Load)
+    pub refresh_on_load: Option<bool>,
+
+    /// saveData (Save Pivot Records)
+    ///
+    /// Whether the records are saved with the workbook.
+    pub save_data: Option<bool>,
+
+    /// supportAdvancedDrill (Supports Attribute Drilldown)
+    pub support_advanced_drill: Option<bool>,
+
+    /// supportSubquery (Supports Subqueries)
+    pub support_subquery: Option<bool>,
+
+    /// tupleCache (Stores Cache for OLAP Functions)
+    pub tuple_cache: Option<bool>,
+
+    /// upgradeOnRefresh (Upgrade PivotCache on Refresh)
+    pub upgrade_on_refresh: Option<bool>,
+}
+
+impl XlsxPivotCacheDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self {
+            cache_fields: None,
+            cache_source: None,
+            background_query: None,
+            created_version: None,
+            enable_refresh: None,
+            id: None,
+            invalid: None,
+            min_refreshable_version: None,
+            missing_items_limit: None,
+            optimize_memory: None,
+            record_count: None,
+            refreshed_by: None,
+            refreshed_date: None,
+            refreshed_date_iso: None,
+            refreshed_version: None,
+            refresh_on_load: None,
+            save_data: None,
+            support_advanced_drill: None,
+            support_subquery: None,
+            tuple_cache: None,
+            upgrade_on_refresh: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotCacheDefinition" => {
+                    let attributes = e.attributes();
+                    for a in attributes {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"backgroundQuery" => {
+                                        definition.background_query = string_to_bool(&string_value)
+                                    }
+                                    b"createdVersion" => {
+                                        definition.created_version =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"enableRefresh" => {
+                                        definition.enable_refresh = string_to_bool(&string_value)
+                                    }
+                                    b"id" => definition.id = Some(string_value),
+                                    b"invalid" => {
+                                        definition.invalid = string_to_bool(&string_value)
+                                    }
+                                    b"minRefreshableVersion" => {
+                                        definition.min_refreshable_version =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"missingItemsLimit" => {
+                                        definition.missing_items_limit =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"optimizeMemory" => {
+                                        definition.optimize_memory = string_to_bool(&string_value)
+                                    }
+                                    b"recordCount" => {
+                                        definition.record_count =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"refreshedBy" => definition.refreshed_by = Some(string_value),
+                                    b"refreshedDate" => {
+                                        definition.refreshed_date = string_to_float(&string_value)
+                                    }
+                                    b"refreshedDateIso" => {
+                                        definition.refreshed_date_iso = Some(string_value)
+                                    }
+                                    b"refreshedVersion" => {
+                                        definition.refreshed_version =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"refreshOnLoad" => {
+                                        definition.refresh_on_load = string_to_bool(&string_value)
+                                    }
+                                    b"saveData" => {
+                                        definition.save_data = string_to_bool(&string_value)
+                                    }
+                                    b"supportAdvancedDrill" => {
+                                        definition.support_advanced_drill =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"supportSubquery" => {
+                                        definition.support_subquery = string_to_bool(&string_value)
+                                    }
+                                    b"tupleCache" => {
+                                        definition.tuple_cache = string_to_bool(&string_value)
+                                    }
+                                    b"upgradeOnRefresh" => {
+                                        definition.upgrade_on_refresh =
+                                            string_to_bool(&string_value)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cacheSource" => {
+                    definition.cache_source = Some(XlsxCacheSource::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cacheFields" => {
+                    definition.cache_fields = Some(load_cache_fields(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pivotCacheDefinition" => {
+                    break
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(definition);
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+use super::cache_value::XlsxPivotCacheValue;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotcacherecords?view=openxml-3.0.1
+///
+/// Root element of the pivot cache records part (xl/pivotCache/pivotCacheRecords{}.xml).
+///
+/// Each record holds one value per database field of the cache, in the order of the cache fields.
+/// `x` values are indexes into the shared items of the field.
+///
+/// Example:
+/// ```
+/// <pivotCacheRecords xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" count="2">
+///     <r>
+///         <x v="0"/>
+///         <s v="Apples"/>
+///         <n v="120"/>
+///     </r>
+///     <r>
+///         <x v="1"/>
+///         <s v="Pears"/>
+///         <n v="80"/>
+///     </r>
+/// </pivotCacheRecords>
+/// ```
+///
+/// pivotCacheRecords (PivotCache Records)
+pub type XlsxPivotCacheRecords = Vec<XlsxPivotCacheRecord>;
+
+/// r (PivotCache Record)
+pub type XlsxPivotCacheRecord = Vec<XlsxPivotCacheValue>;
+
+pub(crate) fn load_pivot_cache_records(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    path: &str,
+) -> anyhow::Result<XlsxPivotCacheRecords> {
+    let mut records: XlsxPivotCacheRecords = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(records);
+    };
+
+    let mut buf = Vec::new();
+    let mut record: Option<XlsxPivotCacheRecord> = None;
+
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"r" => {
+                record = Some(vec![]);
+            }
+            Ok(Event::Start(ref e)) => {
+                if let Some(value) = XlsxPivotCacheValue::load(&mut reader, e)? {
+                    if let Some(record) = record.as_mut() {
+                        record.push(value);
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"r" => {
+                if let Some(record) = record.take() {
+                    records.push(record);
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pivotCacheRecords" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    return Ok(records);
+}
@@ -0,0 +1,166 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_float, string_to_unsignedint},
+};
+
+use super::cache_value::XlsxPivotCacheValue;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.shareditems?view=openxml-3.0.1
+///
+/// Unique values of a cache field, referenced by index (`x`) from the cache records.
+///
+/// Example:
+/// ```
+/// <sharedItems count="3">
+///     <s v="East"/>
+///     <s v="North"/>
+///     <s v="West"/>
+/// </sharedItems>
+/// <sharedItems containsSemiMixedTypes="0" containsString="0" containsNumber="1" minValue="10" maxValue="250"/>
+/// ```
+///
+/// sharedItems (Shared Items)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSharedItems {
+    // Child Elements
+    /// b, d, e, m, n, s
+    pub items: Vec<XlsxPivotCacheValue>,
+
+    // Attributes
+    /// containsBlank (Contains Blank)
+    pub contains_blank: Option<bool>,
+
+    /// containsDate (Contains Date)
+    pub contains_date: Option<bool>,
+
+    /// containsInteger (Contains Integer)
+    pub contains_integer: Option<bool>,
+
+    /// containsMixedTypes (Contains Mixed Types)
+    pub contains_mixed_types: Option<bool>,
+
+    /// containsNonDate (Contains Non Date)
+    pub contains_non_date: Option<bool>,
+
+    /// containsNumber (Contains Numbers)
+    pub contains_number: Option<bool>,
+
+    /// containsSemiMixedTypes (Contains Semi Mixed Data Types)
+    pub contains_semi_mixed_types: Option<bool>,
+
+    /// containsString (Contains String)
+    pub contains_string: Option<bool>,
+
+    /// count (Shared Items Count)
+    pub count: Option<u64>,
+
+    /// longText (Long Text)
+    ///
+    /// Whether the field contains text longer than 255 characters.
+    pub long_text: Option<bool>,
+
+    /// maxDate (Maximum Date Time)
+    pub max_date: Option<String>,
+
+    /// maxValue (Maximum Numeric Value)
+    pub max_value: Option<f64>,
+
+    /// minDate (Minimum Date Time)
+    pub min_date: Option<String>,
+
+    /// minValue (Minimum Numeric Value)
+    pub min_value: Option<f64>,
+}
+
+impl XlsxSharedItems {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut shared_items = Self {
+            items: vec![],
+            contains_blank: None,
+            contains_date: None,
+            contains_integer: None,
+            contains_mixed_types: None,
+            contains_non_date: None,
+            contains_number: None,
+            contains_semi_mixed_types: None,
+            contains_string: None,
+            count: None,
+            long_text: None,
+            max_date: None,
+            max_value: None,
+            min_date: None,
+            min_value: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"containsBlank" => {
+                            shared_items.contains_blank = string_to_bool(&string_value)
+                        }
+                        b"containsDate" => {
+                            shared_items.contains_date = string_to_bool(&string_value)
+                        }
+                        b"containsInteger" => {
+                            shared_items.contains_integer = string_to_bool(&string_value)
+                        }
+                        b"containsMixedTypes" => {
+                            shared_items.contains_mixed_types = string_to_bool(&string_value)
+                        }
+                        b"containsNonDate" => {
+                            shared_items.contains_non_date = string_to_bool(&string_value)
+                        }
+                        b"containsNumber" => {
+                            shared_items.contains_number = string_to_bool(&string_value)
+                        }
+                        b"containsSemiMixedTypes" => {
+                            shared_items.contains_semi_mixed_types = string_to_bool(&string_value)
+                        }
+                        b"containsString" => {
+                            shared_items.contains_string = string_to_bool(&string_value)
+                        }
+                        b"count" => shared_items.count = string_to_unsignedint(&string_value),
+                        b"longText" => shared_items.long_text = string_to_bool(&string_value),
+                        b"maxDate" => shared_items.max_date = Some(string_value),
+                        b"maxValue" => shared_items.max_value = string_to_float(&string_value),
+                        b"minDate" => shared_items.min_date = Some(string_value),
+                        b"minValue" => shared_items.min_value = string_to_float(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    if let Some(value) = XlsxPivotCacheValue::load(reader, e)? {
+                        shared_items.items.push(value);
+                    } else {
+                        let _ =
+                            reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                    }
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sharedItems" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `sharedItems`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(shared_items)
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_int, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.datafields?view=openxml-3.0.1
+///
+/// Fields in the values area of the pivot table.
+///
+/// Example:
+/// ```
+/// <dataFields count="2">
+///     <dataField name="Sum of Sales" fld="2" baseField="0" baseItem="0"/>
+///     <dataField name="Count of Product" fld="1" subtotal="count" baseField="0" baseItem="0"/>
+/// </dataFields>
+/// ```
+///
+/// dataFields (Data Fields)
+pub type XlsxDataFields = Vec<XlsxDataField>;
+
+pub(crate) fn load_data_fields(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxDataFields> {
+    let mut fields: XlsxDataFields = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dataField" => {
+                fields.push(XlsxDataField::load(e)?);
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"dataFields" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `dataFields`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(fields)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.datafield?view=openxml-3.0.1
+///
+/// A field aggregated in the values area.
+///
+/// Example:
+/// ```
+/// <dataField name="Sum of Sales" fld="2" baseField="0" baseItem="0" numFmtId="3"/>
+/// ```
+///
+/// dataField (Data Field Item)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDataField {
+    // extLst (Future Feature Data Storage Area)	Not supported
+
+    // Attributes
+    /// baseField (Base Field)
+    ///
+    /// Field used by `showDataAs` calculations.
+    pub base_field: Option<i64>,
+
+    /// baseItem (Base Item)
+    ///
+    /// Item used by `showDataAs` calculations.
+    pub base_item: Option<u64>,
+
+    /// fld (Field)
+    ///
+    /// Index of the pivot field aggregated.
+    pub fld: Option<u64>,
+
+    /// name (Name)
+    pub name: Option<String>,
+
+    /// numFmtId (Number Format Id)
+    pub num_fmt_id: Option<u64>,
+
+    /// showDataAs (Show Data As Display Format)
+    ///
+    /// possible values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.showdataasvalues?view=openxml-3.0.1
+    pub show_data_as: Option<String>,
+
+    /// subtotal (Subtotal)
+    ///
+    /// possible values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.dataconsolidatefunctionvalues?view=openxml-3.0.1
+    pub subtotal: Option<String>,
+}
+
+impl XlsxDataField {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut field = Self {
+            base_field: None,
+            base_item: None,
+            fld: None,
+            name: None,
+            num_fmt_id: None,
+            show_data_as: None,
+            subtotal: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"baseField" => field.base_field = string_to_int(&string_value),
+                        b"baseItem" => field.base_item = string_to_unsignedint(&string_value),
+                        b"fld" => field.fld = string_to_unsignedint(&string_value),
+                        b"name" => field.name = Some(string_value),
+                        b"numFmtId" => field.num_fmt_id = string_to_unsignedint(&string_value),
+                        b"showDataAs" => field.show_data_as = Some(string_value),
+                        b"subtotal" => field.subtotal = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(field)
+    }
+}
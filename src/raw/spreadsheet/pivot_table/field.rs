@@ -0,0 +1,66 @@
+use anyhow::bail;
+use quick_xml::events::Event;
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_int};
+
+/// - rowFields: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.rowfields?view=openxml-3.0.1
+/// - colFields: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.columnfields?view=openxml-3.0.1
+///
+/// Fields on the row or column axis, in order.
+///
+/// Each value is an index into the pivot fields.
+/// `-2` refers to the virtual "Values" field, used when there is more than one data field.
+///
+/// Example:
+/// ```
+/// <rowFields count="2">
+///     <field x="0"/>
+///     <field x="-2"/>
+/// </rowFields>
+/// ```
+///
+/// rowFields (Row Fields), colFields (Column Fields)
+pub type XlsxFields = Vec<i64>;
+
+/// `tag`: rowFields or colFields
+pub(crate) fn load_fields(
+    reader: &mut XmlReader<impl Read>,
+    tag: &[u8],
+) -> anyhow::Result<XlsxFields> {
+    let mut fields: XlsxFields = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"field" => {
+                for a in e.attributes() {
+                    match a {
+                        Ok(a) => {
+                            if a.key.local_name().as_ref() == b"x" {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                if let Some(x) = string_to_int(&string_value) {
+                                    fields.push(x);
+                                }
+                            }
+                        }
+                        Err(error) => {
+                            bail!(error.to_string())
+                        }
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+            Ok(Event::Eof) => bail!(
+                "unexpected end of file at `{}`.",
+                String::from_utf8_lossy(tag)
+            ),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(fields)
+}
@@ -0,0 +1,90 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::{common_types::Dimension, helper::string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.location?view=openxml-3.0.1
+///
+/// Location of the pivot table on the sheet.
+///
+/// Example:
+/// ```
+/// <location ref="A3:C7" firstHeaderRow="1" firstDataRow="1" firstDataCol="1" rowPageCount="1" colPageCount="1"/>
+/// ```
+///
+/// location (PivotTable Location)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxLocation {
+    // Attributes
+    /// colPageCount (Columns Per Page)
+    pub col_page_count: Option<u64>,
+
+    /// firstDataCol (First Data Column)
+    ///
+    /// Zero-based column offset of the first data column from the top left of `ref`.
+    pub first_data_col: Option<u64>,
+
+    /// firstDataRow (First Data Row)
+    ///
+    /// Zero-based row offset of the first data row from the top left of `ref`.
+    pub first_data_row: Option<u64>,
+
+    /// firstHeaderRow (First Header Row)
+    ///
+    /// Zero-based row offset of the column header row from the top left of `ref`.
+    pub first_header_row: Option<u64>,
+
+    /// ref (Reference)
+    ///
+    /// Range of the pivot table, excluding the page fields area.
+    pub r#ref: Option<Dimension>,
+
+    /// rowPageCount (Rows Per Page Count)
+    pub row_page_count: Option<u64>,
+}
+
+impl XlsxLocation {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut location = Self {
+            col_page_count: None,
+            first_data_col: None,
+            first_data_row: None,
+            first_header_row: None,
+            r#ref: None,
+            row_page_count: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"colPageCount" => {
+                            location.col_page_count = string_to_unsignedint(&string_value)
+                        }
+                        b"firstDataCol" => {
+                            location.first_data_col = string_to_unsignedint(&string_value)
+                        }
+                        b"firstDataRow" => {
+                            location.first_data_row = string_to_unsignedint(&string_value)
+                        }
+                        b"firstHeaderRow" => {
+                            location.first_header_row = string_to_unsignedint(&string_value)
+                        }
+                        b"ref" => location.r#ref = Dimension::from_a1(a.value.as_ref()),
+                        b"rowPageCount" => {
+                            location.row_page_count = string_to_unsignedint(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(location)
+    }
+}
@@ -0,0 +1,430 @@
+use anyhow::bail;
+use data_field::{load_data_fields, XlsxDataFields};
+use field::{load_fields, XlsxFields};
+use location::XlsxLocation;
+use page_field::{load_page_fields, XlsxPageFields};
+use pivot_field::{load_pivot_fields, XlsxPivotFields};
+use pivot_table_style_info::XlsxPivotTableStyleInfo;
+use quick_xml::events::Event;
+use row_column_item::{load_row_column_items, XlsxRowColumnItems};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+pub mod data_field;
+pub mod field;
+pub mod location;
+pub mod page_field;
+pub mod pivot_field;
+pub mod pivot_table_style_info;
+pub mod row_column_item;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivottabledefinition?view=openxml-3.0.1
+///
+/// Root element of the pivot table part (xl/pivotTables/pivotTable{}.xml).
+///
+/// Example:
+/// ```
+/// <pivotTableDefinition xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" name="PivotTable1" cacheId="0"
+/// applyNumberFormats="0" applyBorderFormats="0" applyFontFormats="0" applyPatternFormats="0" applyAlignmentFormats="0" applyWidthHeightFormats="1"
+/// dataCaption="Values" updatedVersion="8" minRefreshableVersion="3" useAutoFormatting="1" itemPrintTitles="1" createdVersion="8" indent="0" outline="1" outlineData="1" multipleFieldFilters="0">
+///     <location ref="A3:B6" firstHeaderRow="1" firstDataRow="1" firstDataCol="1"/>
+///     <pivotFields count="2">
+///         <pivotField axis="axisRow" showAll="0">
+///             <items count="3">
+///                 <item x="0"/>
+///                 <item x="1"/>
+///                 <item t="default"/>
+///             </items>
+///         </pivotField>
+///         <pivotField dataField="1" showAll="0"/>
+///     </pivotFields>
+///     <rowFields count="1">
+///         <field x="0"/>
+///     </rowFields>
+///     <rowItems count="3">
+///         <i>
+///             <x/>
+///         </i>
+///         <i>
+///             <x v="1"/>
+///         </i>
+///         <i t="grand">
+///             <x/>
+///         </i>
+///     </rowItems>
+///     <colItems count="1">
+///         <i/>
+///     </colItems>
+///     <dataFields count="1">
+///         <dataField name="Sum of Sales" fld="1" baseField="0" baseItem="0"/>
+///     </dataFields>
+///     <pivotTableStyleInfo name="PivotStyleLight16" showRowHeaders="1" showColHeaders="1" showRowStripes="0" showColStripes="0" showLastColumn="1"/>
+/// </pivotTableDefinition>
+/// ```
+///
+/// pivotTableDefinition (PivotTable Definition)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPivotTableDefinition {
+    // extLst (Future Feature Data Storage Area)	Not supported
+
+    // Child Elements	Subclause
+    // chartFormats (Chart Formats)	§18.10.1.13
+    /// colFields (Column Fields)
+    pub col_fields: Option<XlsxFields>,
+
+    // colHierarchiesUsage (Column OLAP Hierarchy References)	§18.10.1.16
+    /// colItems (Column Items)
+    pub col_items: Option<XlsxRowColumnItems>,
+
+    // conditionalFormats (Conditional Formats)	§18.10.1.18
+    /// dataFields (Data Fields)
+    pub data_fields: Option<XlsxDataFields>,
+
+    // filters (Filters)	§18.10.1.35
+    // formats (Formats)	§18.10.1.37
+    /// location (PivotTable Location)
+    pub location: Option<XlsxLocation>,
+
+    /// pageFields (Page Field Items)
+    pub page_fields: Option<XlsxPageFields>,
+
+    /// pivotFields (PivotTable Fields)
+    pub pivot_fields: Option<XlsxPivotFields>,
+
+    // pivotHierarchies (PivotTable OLAP Hierarchies)	§18.10.1.64
+    /// pivotTableStyleInfo (PivotTable Style)
+    pub pivot_table_style_info: Option<XlsxPivotTableStyleInfo>,
+
+    /// rowFields (Row Fields)
+    pub row_fields: Option<XlsxFields>,
+
+    // rowHierarchiesUsage (Row OLAP Hierarchy References)	§18.10.1.82
+    /// rowItems (Row Items)
+    pub row_items: Option<XlsxRowColumnItems>,
+
+    // Attributes
+    /// cacheId (cacheId)
+    ///
+    /// Id of the pivot cache in the `pivotCaches` of the workbook.
+    pub cache_id: Option<u64>,
+
+    /// colGrandTotals (Grand Totals On Columns)
+    pub col_grand_totals: Option<bool>,
+
+    /// colHeaderCaption (Column Header Caption)
+    pub col_header_caption: Option<String>,
+
+    /// compact (Compact New Fields)
+    pub compact: Option<bool>,
+
+    /// compactData (Compact Data)
+    pub compact_data: Option<bool>,
+
+    /// createdVersion (PivotTable Creator Version)
+    pub created_version: Option<u64>,
+
+    /// dataCaption (Data Caption)
+    pub data_caption: Option<String>,
+
+    /// dataOnRows (Data On Rows)
+    ///
+    /// Whether the "Values" field is on the row axis.
+    pub data_on_rows: Option<bool>,
+
+    /// dataPosition (Data Field Position)
+    pub data_position: Option<u64>,
+
+    /// errorCaption (Error Caption)
+    pub error_caption: Option<String>,
+
+    /// grandTotalCaption (Grand Total Caption)
+    pub grand_total_caption: Option<String>,
+
+    /// gridDropZones (Show Classic PivotTable Layout)
+    pub grid_drop_zones: Option<bool>,
+
+    /// indent (Indentation for Compact Axis)
+    pub indent: Option<u64>,
+
+    /// itemPrintTitles (Item Print Titles)
+    pub item_print_titles: Option<bool>,
+
+    /// mergeItem (Merge Titles)
+    pub merge_item: Option<bool>,
+
+    /// minRefreshableVersion (Minimum Refreshable Version)
+    pub min_refreshable_version: Option<u64>,
+
+    /// missingCaption (Missing Caption)
+    pub missing_caption: Option<String>,
+
+    /// multipleFieldFilters (Allow Multiple Filters)
+    pub multiple_field_filters: Option<bool>,
+
+    /// name (name)
+    pub name: Option<String>,
+
+    /// outline (Outline New Fields)
+    pub outline: Option<bool>,
+
+    /// outlineData (Outline Data Fields)
+    pub outline_data: Option<bool>,
+
+    /// pageOverThenDown (Page Over Then Down)
+    pub page_over_then_down: Option<bool>,
+
+    /// pageStyle (Page Style Name)
+    pub page_style: Option<String>,
+
+    /// pageWrap (Fields Per Page Wrap)
+    pub page_wrap: Option<u64>,
+
+    /// pivotTableStyle (Pivot Table Style Name)
+    pub pivot_table_style: Option<String>,
+
+    /// rowGrandTotals (Grand Totals On Rows)
+    pub row_grand_totals: Option<bool>,
+
+    /// rowHeaderCaption (Row Header Caption)
+    pub row_header_caption: Option<String>,
+
+    /// showError (Show Error)
+    pub show_error: Option<bool>,
+
+    /// showHeaders (Show Headers)
+    pub show_headers: Option<bool>,
+
+    /// showMissing (Show Missing)
+    pub show_missing: Option<bool>,
+
+    /// subtotalHiddenItems (Subtotal Hidden Items)
+    pub subtotal_hidden_items: Option<bool>,
+
+    /// updatedVersion (PivotTable Last Updated Version)
+    pub updated_version: Option<u64>,
+
+    /// useAutoFormatting (Auto Format)
+    pub use_auto_formatting: Option<bool>,
+}
+
+impl XlsxPivotTableDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self {
+            col_fields: None,
+            col_items: None,
+            data_fields: None,
+            location: None,
+            page_fields: None,
+            pivot_fields: None,
+            pivot_table_style_info: None,
+            row_fields: None,
+            row_items: None,
+            cache_id: None,
+            col_grand_totals: None,
+            col_header_caption: None,
+            compact: None,
+            compact_data: None,
+            created_version: None,
+            data_caption: None,
+            data_on_rows: None,
+            data_position: None,
+            error_caption: None,
+            grand_total_caption: None,
+            grid_drop_zones: None,
+            indent: None,
+            item_print_titles: None,
+            merge_item: None,
+            min_refreshable_version: None,
+            missing_caption: None,
+            multiple_field_filters: None,
+            name: None,
+            outline: None,
+            outline_data: None,
+            page_over_then_down: None,
+            page_style: None,
+            page_wrap: None,
+            pivot_table_style: None,
+            row_grand_totals: None,
+            row_header_caption: None,
+            show_error: None,
+            show_headers: None,
+            show_missing: None,
+            subtotal_hidden_items: None,
+            updated_version: None,
+            use_auto_formatting: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotTableDefinition" => {
+                    let attributes = e.attributes();
+                    for a in attributes {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"cacheId" => {
+                                        definition.cache_id = string_to_unsignedint(&string_value)
+                                    }
+                                    b"colGrandTotals" => {
+                                        definition.col_grand_totals = string_to_bool(&string_value)
+                                    }
+                                    b"colHeaderCaption" => {
+                                        definition.col_header_caption = Some(string_value)
+                                    }
+                                    b"compact" => {
+                                        definition.compact = string_to_bool(&string_value)
+                                    }
+                                    b"compactData" => {
+                                        definition.compact_data = string_to_bool(&string_value)
+                                    }
+                                    b"createdVersion" => {
+                                        definition.created_version =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"dataCaption" => definition.data_caption = Some(string_value),
+                                    b"dataOnRows" => {
+                                        definition.data_on_rows = string_to_bool(&string_value)
+                                    }
+                                    b"dataPosition" => {
+                                        definition.data_position =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"errorCaption" => {
+                                        definition.error_caption = Some(string_value)
+                                    }
+                                    b"grandTotalCaption" => {
+                                        definition.grand_total_caption = Some(string_value)
+                                    }
+                                    b"gridDropZones" => {
+                                        definition.grid_drop_zones = string_to_bool(&string_value)
+                                    }
+                                    b"indent" => {
+                                        definition.indent = string_to_unsignedint(&string_value)
+                                    }
+                                    b"itemPrintTitles" => {
+                                        definition.item_print_titles = string_to_bool(&string_value)
+                                    }
+                                    b"mergeItem" => {
+                                        definition.merge_item = string_to_bool(&string_value)
+                                    }
+                                    b"minRefreshableVersion" => {
+                                        definition.min_refreshable_version =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"missingCaption" => {
+                                        definition.missing_caption = Some(string_value)
+                                    }
+                                    b"multipleFieldFilters" => {
+                                        definition.multiple_field_filters =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"name" => definition.name = Some(string_value),
+                                    b"outline" => {
+                                        definition.outline = string_to_bool(&string_value)
+                                    }
+                                    b"outlineData" => {
+                                        definition.outline_data = string_to_bool(&string_value)
+                                    }
+                                    b"pageOverThenDown" => {
+                                        definition.page_over_then_down =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"pageStyle" => definition.page_style = Some(string_value),
+                                    b"pageWrap" => {
+                                        definition.page_wrap = string_to_unsignedint(&string_value)
+                                    }
+                                    b"pivotTableStyle" => {
+                                        definition.pivot_table_style = Some(string_value)
+                                    }
+                                    b"rowGrandTotals" => {
+                                        definition.row_grand_totals = string_to_bool(&string_value)
+                                    }
+                                    b"rowHeaderCaption" => {
+                                        definition.row_header_caption = Some(string_value)
+                                    }
+                                    b"showError" => {
+                                        definition.show_error = string_to_bool(&string_value)
+                                    }
+                                    b"showHeaders" => {
+                                        definition.show_headers = string_to_bool(&string_value)
+                                    }
+                                    b"showMissing" => {
+                                        definition.show_missing = string_to_bool(&string_value)
+                                    }
+                                    b"subtotalHiddenItems" => {
+                                        definition.subtotal_hidden_items =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"updatedVersion" => {
+                                        definition.updated_version =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"useAutoFormatting" => {
+                                        definition.use_auto_formatting =
+                                            string_to_bool(&string_value)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"location" => {
+                    definition.location = Some(XlsxLocation::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotFields" => {
+                    definition.pivot_fields = Some(load_pivot_fields(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"rowFields" => {
+                    definition.row_fields = Some(load_fields(&mut reader, b"rowFields")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"rowItems" => {
+                    definition.row_items = Some(load_row_column_items(&mut reader, b"rowItems")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"colFields" => {
+                    definition.col_fields = Some(load_fields(&mut reader, b"colFields")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"colItems" => {
+                    definition.col_items = Some(load_row_column_items(&mut reader, b"colItems")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageFields" => {
+                    definition.page_fields = Some(load_page_fields(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dataFields" => {
+                    definition.data_fields = Some(load_data_fields(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotTableStyleInfo" => {
+                    definition.pivot_table_style_info = Some(XlsxPivotTableStyleInfo::load(e)?);
+                }
+                Ok(Event::Start(ref e)) => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pivotTableDefinition" => {
+                    break
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(definition);
+    }
+}
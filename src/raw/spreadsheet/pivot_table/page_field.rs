@@ -0,0 +1,115 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_int, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagefields?view=openxml-3.0.1
+///
+/// Fields in the filter (page) area of the pivot table.
+///
+/// Example:
+/// ```
+/// <pageFields count="1">
+///     <pageField fld="1" item="0" hier="-1"/>
+/// </pageFields>
+/// ```
+///
+/// pageFields (Page Field Items)
+pub type XlsxPageFields = Vec<XlsxPageField>;
+
+pub(crate) fn load_page_fields(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxPageFields> {
+    let mut fields: XlsxPageFields = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageField" => {
+                fields.push(XlsxPageField::load(e)?);
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pageFields" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `pageFields`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(fields)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagefield?view=openxml-3.0.1
+///
+/// A field in the filter area and the item selected.
+///
+/// Example:
+/// ```
+/// <pageField fld="1" item="0" hier="-1"/>
+/// ```
+///
+/// pageField (Page Field)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPageField {
+    // extLst (Future Feature Data Storage Area)	Not supported
+
+    // Attributes
+    /// cap (Hierarchy Display Name)
+    pub cap: Option<String>,
+
+    /// fld (Field)
+    ///
+    /// Index of the pivot field.
+    pub fld: Option<i64>,
+
+    /// hier (OLAP Hierarchy Index)
+    pub hier: Option<i64>,
+
+    /// item (Item Index)
+    ///
+    /// Index of the selected item of the field. None if all items are selected.
+    pub item: Option<u64>,
+
+    /// name (Hierarchy Unique Name)
+    pub name: Option<String>,
+}
+
+impl XlsxPageField {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut field = Self {
+            cap: None,
+            fld: None,
+            hier: None,
+            item: None,
+            name: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"cap" => field.cap = Some(string_value),
+                        b"fld" => field.fld = string_to_int(&string_value),
+                        b"hier" => field.hier = string_to_int(&string_value),
+                        b"item" => field.item = string_to_unsignedint(&string_value),
+                        b"name" => field.name = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(field)
+    }
+}
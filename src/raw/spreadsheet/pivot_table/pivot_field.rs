@@ -0,0 +1,414 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotfields?view=openxml-3.0.1
+///
+/// Fields of the pivot table, one per cache field in the same order.
+///
+/// Example:
+/// ```
+/// <pivotFields count="3">
+///     <pivotField axis="axisRow" showAll="0">
+///         <items count="3">
+///             <item x="0"/>
+///             <item x="1"/>
+///             <item t="default"/>
+///         </items>
+///     </pivotField>
+///     <pivotField showAll="0"/>
+///     <pivotField dataField="1" showAll="0"/>
+/// </pivotFields>
+/// ```
+///
+/// pivotFields (PivotTable Fields)
+pub type XlsxPivotFields = Vec<XlsxPivotField>;
+
+pub(crate) fn load_pivot_fields(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxPivotFields> {
+    let mut fields: XlsxPivotFields = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotField" => {
+                fields.push(XlsxPivotField::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pivotFields" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `pivotFields`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(fields)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotfield?view=openxml-3.0.1
+///
+/// A single field of the pivot table: axis, items, subtotals and layout settings.
+///
+/// Example:
+/// ```
+/// <pivotField axis="axisRow" showAll="0" sumSubtotal="1" defaultSubtotal="0">
+///     <items count="3">
+///         <item x="1"/>
+///         <item x="0"/>
+///         <item t="sum"/>
+///     </items>
+/// </pivotField>
+/// ```
+///
+/// pivotField (PivotTable Field)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPivotField {
+    // autoSortScope (AutoSort Scope)	Not supported
+    // extLst (Future Feature Data Storage Area)	Not supported
+
+    // Child Elements
+    /// items (Field Items)
+    pub items: Option<Vec<XlsxPivotItem>>,
+
+    // Attributes
+    /// allDrilled (All Items Expanded)
+    pub all_drilled: Option<bool>,
+
+    /// avgSubtotal (Average)
+    pub avg_subtotal: Option<bool>,
+
+    /// axis (Axis)
+    ///
+    /// * axisRow
+    /// * axisCol
+    /// * axisPage
+    /// * axisValues
+    pub axis: Option<String>,
+
+    /// compact (Compact)
+    pub compact: Option<bool>,
+
+    /// countASubtotal (CountA)
+    pub count_a_subtotal: Option<bool>,
+
+    /// countSubtotal (Count)
+    pub count_subtotal: Option<bool>,
+
+    /// dataField (Data Field)
+    ///
+    /// Whether the field is in the values area.
+    pub data_field: Option<bool>,
+
+    /// defaultSubtotal (Default Subtotal)
+    ///
+    /// Whether the default subtotal (the aggregation of the data field) is shown.
+    pub default_subtotal: Option<bool>,
+
+    /// hiddenLevel (Hidden Level)
+    pub hidden_level: Option<bool>,
+
+    /// includeNewItemsInFilter (Include New Items In Filter)
+    pub include_new_items_in_filter: Option<bool>,
+
+    /// insertBlankRow (Insert Blank Row)
+    pub insert_blank_row: Option<bool>,
+
+    /// insertPageBreak (Insert Item Page Break)
+    pub insert_page_break: Option<bool>,
+
+    /// maxSubtotal (Max)
+    pub max_subtotal: Option<bool>,
+
+    /// minSubtotal (Min)
+    pub min_subtotal: Option<bool>,
+
+    /// multipleItemSelectionAllowed (Multiple Field Filters)
+    pub multiple_item_selection_allowed: Option<bool>,
+
+    /// name (Field Name)
+    ///
+    /// Custom name of the field. The cache field name is used if not specified.
+    pub name: Option<String>,
+
+    /// numFmtId (Number Format Id)
+    pub num_fmt_id: Option<u64>,
+
+    /// outline (Outline Items)
+    pub outline: Option<bool>,
+
+    /// productSubtotal (Product)
+    pub product_subtotal: Option<bool>,
+
+    /// showAll (Show All Items)
+    ///
+    /// Whether items without data are shown.
+    pub show_all: Option<bool>,
+
+    /// sortType (Field Sort)
+    ///
+    /// * manual
+    /// * ascending
+    /// * descending
+    pub sort_type: Option<String>,
+
+    /// stdDevPSubtotal (StdDevP)
+    pub std_dev_p_subtotal: Option<bool>,
+
+    /// stdDevSubtotal (StdDev)
+    pub std_dev_subtotal: Option<bool>,
+
+    /// subtotalCaption (Custom Subtotal Caption)
+    pub subtotal_caption: Option<String>,
+
+    /// subtotalTop (Subtotal At Top)
+    pub subtotal_top: Option<bool>,
+
+    /// sumSubtotal (Sum)
+    pub sum_subtotal: Option<bool>,
+
+    /// varPSubtotal (VarP)
+    pub var_p_subtotal: Option<bool>,
+
+    /// varSubtotal (Variance)
+    pub var_subtotal: Option<bool>,
+}
+
+impl XlsxPivotField {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut field = Self {
+            items: None,
+            all_drilled: None,
+            avg_subtotal: None,
+            axis: None,
+            compact: None,
+            count_a_subtotal: None,
+            count_subtotal: None,
+            data_field: None,
+            default_subtotal: None,
+            hidden_level: None,
+            include_new_items_in_filter: None,
+            insert_blank_row: None,
+            insert_page_break: None,
+            max_subtotal: None,
+            min_subtotal: None,
+            multiple_item_selection_allowed: None,
+            name: None,
+            num_fmt_id: None,
+            outline: None,
+            product_subtotal: None,
+            show_all: None,
+            sort_type: None,
+            std_dev_p_subtotal: None,
+            std_dev_subtotal: None,
+            subtotal_caption: None,
+            subtotal_top: None,
+            sum_subtotal: None,
+            var_p_subtotal: None,
+            var_subtotal: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"allDrilled" => field.all_drilled = string_to_bool(&string_value),
+                        b"avgSubtotal" => field.avg_subtotal = string_to_bool(&string_value),
+                        b"axis" => field.axis = Some(string_value),
+                        b"compact" => field.compact = string_to_bool(&string_value),
+                        b"countASubtotal" => field.count_a_subtotal = string_to_bool(&string_value),
+                        b"countSubtotal" => field.count_subtotal = string_to_bool(&string_value),
+                        b"dataField" => field.data_field = string_to_bool(&string_value),
+                        b"defaultSubtotal" => {
+                            field.default_subtotal = string_to_bool(&string_value)
+                        }
+                        b"hiddenLevel" => field.hidden_level = string_to_bool(&string_value),
+                        b"includeNewItemsInFilter" => {
+                            field.include_new_items_in_filter = string_to_bool(&string_value)
+                        }
+                        b"insertBlankRow" => field.insert_blank_row = string_to_bool(&string_value),
+                        b"insertPageBreak" => {
+                            field.insert_page_break = string_to_bool(&string_value)
+                        }
+                        b"maxSubtotal" => field.max_subtotal = string_to_bool(&string_value),
+                        b"minSubtotal" => field.min_subtotal = string_to_bool(&string_value),
+                        b"multipleItemSelectionAllowed" => {
+                            field.multiple_item_selection_allowed = string_to_bool(&string_value)
+                        }
+                        b"name" => field.name = Some(string_value),
+                        b"numFmtId" => field.num_fmt_id = string_to_unsignedint(&string_value),
+                        b"outline" => field.outline = string_to_bool(&string_value),
+                        b"productSubtotal" => {
+                            field.product_subtotal = string_to_bool(&string_value)
+                        }
+                        b"showAll" => field.show_all = string_to_bool(&string_value),
+                        b"sortType" => field.sort_type = Some(string_value),
+                        b"stdDevPSubtotal" => {
+                            field.std_dev_p_subtotal = string_to_bool(&string_value)
+                        }
+                        b"stdDevSubtotal" => field.std_dev_subtotal = string_to_bool(&string_value),
+                        b"subtotalCaption" => field.subtotal_caption = Some(string_value),
+                        b"subtotalTop" => field.subtotal_top = string_to_bool(&string_value),
+                        b"sumSubtotal" => field.sum_subtotal = string_to_bool(&string_value),
+                        b"varPSubtotal" => field.var_p_subtotal = string_to_bool(&string_value),
+                        b"varSubtotal" => field.var_subtotal = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"items" => {
+                    field.items = Some(load_pivot_items(reader)?);
+                }
+                Ok(Event::Start(ref e)) => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pivotField" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `pivotField`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(field)
+    }
+}
+
+fn load_pivot_items(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Vec<XlsxPivotItem>> {
+    let mut items: Vec<XlsxPivotItem> = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"item" => {
+                items.push(XlsxPivotItem::load(e)?);
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"items" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `items`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(items)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.item?view=openxml-3.0.1
+///
+/// An item of a pivot field.
+/// Data items reference a shared item of the cache field with `x`,
+/// subtotal items are identified by `t`.
+///
+/// Example:
+/// ```
+/// <item x="1" h="1"/>
+/// <item t="default"/>
+/// ```
+///
+/// item (PivotTable Field Item)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPivotItem {
+    // Attributes
+    /// c (Child Items)
+    pub c: Option<bool>,
+
+    /// d (Expanded)
+    pub d: Option<bool>,
+
+    /// e (Drill Across Attributes)
+    pub e: Option<bool>,
+
+    /// f (Calculated Member)
+    pub f: Option<bool>,
+
+    /// h (Hidden)
+    pub h: Option<bool>,
+
+    /// m (Missing)
+    pub m: Option<bool>,
+
+    /// n (Item User Caption)
+    pub n: Option<String>,
+
+    /// s (Character)
+    pub s: Option<bool>,
+
+    /// sd (Hide Details)
+    pub sd: Option<bool>,
+
+    /// t (Item Type)
+    ///
+    /// possible values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.itemvalues?view=openxml-3.0.1
+    pub t: Option<String>,
+
+    /// x (Item Index)
+    ///
+    /// Index of the shared item in the cache field.
+    pub x: Option<u64>,
+}
+
+impl XlsxPivotItem {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut item = Self {
+            c: None,
+            d: None,
+            e: None,
+            f: None,
+            h: None,
+            m: None,
+            n: None,
+            s: None,
+            sd: None,
+            t: None,
+            x: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"c" => item.c = string_to_bool(&string_value),
+                        b"d" => item.d = string_to_bool(&string_value),
+                        b"e" => item.e = string_to_bool(&string_value),
+                        b"f" => item.f = string_to_bool(&string_value),
+                        b"h" => item.h = string_to_bool(&string_value),
+                        b"m" => item.m = string_to_bool(&string_value),
+                        b"n" => item.n = Some(string_value),
+                        b"s" => item.s = string_to_bool(&string_value),
+                        b"sd" => item.sd = string_to_bool(&string_value),
+                        b"t" => item.t = Some(string_value),
+                        b"x" => item.x = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(item)
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivottablestyle?view=openxml-3.0.1
+///
+/// Style applied to the pivot table.
+///
+/// Example:
+/// ```
+/// <pivotTableStyleInfo name="PivotStyleLight16" showRowHeaders="1" showColHeaders="1" showRowStripes="0" showColStripes="0" showLastColumn="1"/>
+/// ```
+///
+/// pivotTableStyleInfo (PivotTable Style)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPivotTableStyleInfo {
+    // Attributes
+    /// name (Style Name)
+    pub name: Option<String>,
+
+    /// showColHeaders (Show Column Header)
+    pub show_col_headers: Option<bool>,
+
+    /// showColStripes (Show Column Stripes)
+    pub show_col_stripes: Option<bool>,
+
+    /// showLastColumn (Show Last Column)
+    pub show_last_column: Option<bool>,
+
+    /// showRowHeaders (Show Row Header)
+    pub show_row_headers: Option<bool>,
+
+    /// showRowStripes (Show Row Stripes)
+    pub show_row_stripes: Option<bool>,
+}
+
+impl XlsxPivotTableStyleInfo {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut style = Self {
+            name: None,
+            show_col_headers: None,
+            show_col_stripes: None,
+            show_last_column: None,
+            show_row_headers: None,
+            show_row_stripes: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"name" => style.name = Some(string_value),
+                        b"showColHeaders" => style.show_col_headers = string_to_bool(&string_value),
+                        b"showColStripes" => style.show_col_stripes = string_to_bool(&string_value),
+                        b"showLastColumn" => style.show_last_column = string_to_bool(&string_value),
+                        b"showRowHeaders" => style.show_row_headers = string_to_bool(&string_value),
+                        b"showRowStripes" => style.show_row_stripes = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(style)
+    }
+}
@@ -0,0 +1,151 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_unsignedint};
+
+/// - rowItems: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.rowitems?view=openxml-3.0.1
+/// - colItems: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.columnitems?view=openxml-3.0.1
+///
+/// Items of each row or column of the rendered pivot table, in order.
+///
+/// Example:
+/// ```
+/// <rowItems count="3">
+///     <i>
+///         <x/>
+///     </i>
+///     <i>
+///         <x v="1"/>
+///     </i>
+///     <i t="grand">
+///         <x/>
+///     </i>
+/// </rowItems>
+/// ```
+///
+/// rowItems (Row Items), colItems (Column Items)
+pub type XlsxRowColumnItems = Vec<XlsxRowColumnItem>;
+
+/// `tag`: rowItems or colItems
+pub(crate) fn load_row_column_items(
+    reader: &mut XmlReader<impl Read>,
+    tag: &[u8],
+) -> anyhow::Result<XlsxRowColumnItems> {
+    let mut items: XlsxRowColumnItems = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"i" => {
+                items.push(XlsxRowColumnItem::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == tag => break,
+            Ok(Event::Eof) => bail!(
+                "unexpected end of file at `{}`.",
+                String::from_utf8_lossy(tag)
+            ),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(items)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.rowitem?view=openxml-3.0.1
+///
+/// A single row or column of the rendered pivot table.
+///
+/// Example:
+/// ```
+/// <i r="1" i="1">
+///     <x v="2"/>
+/// </i>
+/// ```
+///
+/// i (Row Items)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxRowColumnItem {
+    // Child Elements
+    /// x (Member Property Index)
+    ///
+    /// Item indexes of the fields on the axis, starting from the field at `r`.
+    pub member_property_indexes: Vec<u64>,
+
+    // Attributes
+    /// i (Data Field Index)
+    pub data_field_index: Option<u64>,
+
+    /// r (Repeated Items Count)
+    ///
+    /// Number of leading fields whose items are the same as the previous row or column.
+    pub repeated_items_count: Option<u64>,
+
+    /// t (Item Type)
+    ///
+    /// possible values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.itemvalues?view=openxml-3.0.1
+    pub r#type: Option<String>,
+}
+
+impl XlsxRowColumnItem {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut item = Self {
+            member_property_indexes: vec![],
+            data_field_index: None,
+            repeated_items_count: None,
+            r#type: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"i" => item.data_field_index = string_to_unsignedint(&string_value),
+                        b"r" => item.repeated_items_count = string_to_unsignedint(&string_value),
+                        b"t" => item.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"x" => {
+                    let mut index: u64 = 0;
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                if a.key.local_name().as_ref() == b"v" {
+                                    let string_value = String::from_utf8(a.value.to_vec())?;
+                                    index = string_to_unsignedint(&string_value).unwrap_or(0);
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                    item.member_property_indexes.push(index);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"i" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `i`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(item)
+    }
+}
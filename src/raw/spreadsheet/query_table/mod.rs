@@ -0,0 +1,257 @@
+use anyhow::bail;
+use query_table_refresh::XlsxQueryTableRefresh;
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_unsignedint},
+    raw::extension_list::{load_extension_list, XlsxExtensionList},
+};
+
+pub mod query_table_field;
+pub mod query_table_refresh;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.querytable?view=openxml-3.0.1
+///
+/// Root element of a query table part (xl/queryTables/queryTable{}.xml): a range (or table) filled with the data returned by a connection.
+///
+/// Related to the table it populates with the relationships of the table part (xl/tables/_rels/table{}.xml.rels).
+///
+/// Example:
+/// ```
+/// <queryTable xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" name="ExternalData_1" connectionId="1" autoFormatId="16" applyNumberFormats="0" applyBorderFormats="0" applyFontFormats="0" applyPatternFormats="0" applyAlignmentFormats="0" applyWidthHeightFormats="0">
+///     <queryTableRefresh nextId="3">
+///         <queryTableFields count="2">
+///             <queryTableField id="1" name="Region" tableColumnId="1"/>
+///             <queryTableField id="2" name="Amount" tableColumnId="2"/>
+///         </queryTableFields>
+///     </queryTableRefresh>
+/// </queryTable>
+/// ```
+/// queryTable (Query Table)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxQueryTable {
+    // extLst (Future Feature Data Storage Area)
+    pub extension_list: Option<XlsxExtensionList>,
+
+    // Child Elements
+    /// queryTableRefresh (QueryTable Refresh Information)
+    pub query_table_refresh: Option<XlsxQueryTableRefresh>,
+
+    // attributes
+    /// adjustColumnWidth (Adjust Column Width)
+    pub adjust_column_width: Option<bool>,
+
+    /// applyAlignmentFormats (Apply Alignment Formats)
+    pub apply_alignment_formats: Option<bool>,
+
+    /// applyBorderFormats (Apply Borders Formats)
+    pub apply_border_formats: Option<bool>,
+
+    /// applyFontFormats (Apply Font Formats)
+    pub apply_font_formats: Option<bool>,
+
+    /// applyNumberFormats (Apply Number Formats)
+    pub apply_number_formats: Option<bool>,
+
+    /// applyPatternFormats (Apply Pattern Formats)
+    pub apply_pattern_formats: Option<bool>,
+
+    /// applyWidthHeightFormats (Apply Width / Height Formats)
+    pub apply_width_height_formats: Option<bool>,
+
+    /// autoFormatId (Auto Format Id)
+    pub auto_format_id: Option<u64>,
+
+    /// backgroundRefresh (Background Refresh)
+    pub background_refresh: Option<bool>,
+
+    /// connectionId (Connection Id): id of the connection in xl/connections.xml
+    pub connection_id: Option<u64>,
+
+    /// disableEdit (Disable Edit)
+    pub disable_edit: Option<bool>,
+
+    /// disableRefresh (Disable Refresh)
+    pub disable_refresh: Option<bool>,
+
+    /// fillFormulas (Fill Adjacent Formulas)
+    pub fill_formulas: Option<bool>,
+
+    /// firstBackgroundRefresh (First Background Refresh)
+    pub first_background_refresh: Option<bool>,
+
+    /// growShrinkType (Grow Shrink Type). Possible values: insertDelete (default), insertClear, overwriteClear
+    pub grow_shrink_type: Option<String>,
+
+    /// headers (Headers)
+    pub headers: Option<bool>,
+
+    /// intermediate (Intermediate)
+    pub intermediate: Option<bool>,
+
+    /// name (QueryTable Name): also the name of the defined name of the range of a query table not in a table
+    pub name: Option<String>,
+
+    /// preserveFormatting (Preserve Formatting)
+    pub preserve_formatting: Option<bool>,
+
+    /// refreshOnLoad (Refresh On Load)
+    pub refresh_on_load: Option<bool>,
+
+    /// removeDataOnSave (Remove Data On Save)
+    pub remove_data_on_save: Option<bool>,
+
+    /// rowNumbers (Row Numbers)
+    pub row_numbers: Option<bool>,
+}
+
+impl XlsxQueryTable {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut query_table = Self {
+            extension_list: None,
+            query_table_refresh: None,
+            adjust_column_width: None,
+            apply_alignment_formats: None,
+            apply_border_formats: None,
+            apply_font_formats: None,
+            apply_number_formats: None,
+            apply_pattern_formats: None,
+            apply_width_height_formats: None,
+            auto_format_id: None,
+            background_refresh: None,
+            connection_id: None,
+            disable_edit: None,
+            disable_refresh: None,
+            fill_formulas: None,
+            first_background_refresh: None,
+            grow_shrink_type: None,
+            headers: None,
+            intermediate: None,
+            name: None,
+            preserve_formatting: None,
+            refresh_on_load: None,
+            remove_data_on_save: None,
+            row_numbers: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(query_table);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"queryTable" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"adjustColumnWidth" => {
+                                        query_table.adjust_column_width =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"applyAlignmentFormats" => {
+                                        query_table.apply_alignment_formats =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"applyBorderFormats" => {
+                                        query_table.apply_border_formats =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"applyFontFormats" => {
+                                        query_table.apply_font_formats =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"applyNumberFormats" => {
+                                        query_table.apply_number_formats =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"applyPatternFormats" => {
+                                        query_table.apply_pattern_formats =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"applyWidthHeightFormats" => {
+                                        query_table.apply_width_height_formats =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"autoFormatId" => {
+                                        query_table.auto_format_id =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"backgroundRefresh" => {
+                                        query_table.background_refresh =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"connectionId" => {
+                                        query_table.connection_id =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    b"disableEdit" => {
+                                        query_table.disable_edit = string_to_bool(&string_value)
+                                    }
+                                    b"disableRefresh" => {
+                                        query_table.disable_refresh = string_to_bool(&string_value)
+                                    }
+                                    b"fillFormulas" => {
+                                        query_table.fill_formulas = string_to_bool(&string_value)
+                                    }
+                                    b"firstBackgroundRefresh" => {
+                                        query_table.first_background_refresh =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"growShrinkType" => {
+                                        query_table.grow_shrink_type = Some(string_value)
+                                    }
+                                    b"headers" => {
+                                        query_table.headers = string_to_bool(&string_value)
+                                    }
+                                    b"intermediate" => {
+                                        query_table.intermediate = string_to_bool(&string_value)
+                                    }
+                                    b"name" => query_table.name = Some(string_value),
+                                    b"preserveFormatting" => {
+                                        query_table.preserve_formatting =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"refreshOnLoad" => {
+                                        query_table.refresh_on_load = string_to_bool(&string_value)
+                                    }
+                                    b"removeDataOnSave" => {
+                                        query_table.remove_data_on_save =
+                                            string_to_bool(&string_value)
+                                    }
+                                    b"rowNumbers" => {
+                                        query_table.row_numbers = string_to_bool(&string_value)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"queryTableRefresh" => {
+                    query_table.query_table_refresh =
+                        Some(XlsxQueryTableRefresh::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    query_table.extension_list = Some(load_extension_list(&mut reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"queryTable" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(query_table)
+    }
+}
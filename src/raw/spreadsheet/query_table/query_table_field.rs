@@ -0,0 +1,117 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.querytablefields?view=openxml-3.0.1
+///
+/// Fields (columns) returned by the query of a query table.
+///
+/// Example:
+/// ```
+/// <queryTableFields count="2">
+///     <queryTableField id="1" name="Region" tableColumnId="1"/>
+///     <queryTableField id="2" name="Amount" tableColumnId="2"/>
+/// </queryTableFields>
+/// ```
+/// queryTableFields (Query table fields)
+pub type XlsxQueryTableFields = Vec<XlsxQueryTableField>;
+
+pub(crate) fn load_query_table_fields(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxQueryTableFields> {
+    let mut fields: XlsxQueryTableFields = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"queryTableField" => {
+                fields.push(XlsxQueryTableField::load(e)?);
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"queryTableFields" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(fields)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.querytablefield?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <queryTableField id="1" name="Region" tableColumnId="1"/>
+/// ```
+/// queryTableField (QueryTable Field)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxQueryTableField {
+    // attributes
+    /// clipped (Clipped Column): whether the field is outside of the columns of the worksheet
+    pub clipped: Option<bool>,
+
+    /// dataBound (Data Bound Column): false for columns added by the user next to the returned data
+    pub data_bound: Option<bool>,
+
+    /// fillFormulas (Fill This Formula On Refresh)
+    pub fill_formulas: Option<bool>,
+
+    /// id (Field Id): referenced by the table columns (`queryTableFieldId`)
+    pub id: Option<u64>,
+
+    /// name (Name)
+    pub name: Option<String>,
+
+    /// rowNumbers (Row Numbers)
+    pub row_numbers: Option<bool>,
+
+    /// tableColumnId (Table Column Id)
+    pub table_column_id: Option<u64>,
+}
+
+impl XlsxQueryTableField {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut field = Self {
+            clipped: None,
+            data_bound: None,
+            fill_formulas: None,
+            id: None,
+            name: None,
+            row_numbers: None,
+            table_column_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"clipped" => field.clipped = string_to_bool(&string_value),
+                        b"dataBound" => field.data_bound = string_to_bool(&string_value),
+                        b"fillFormulas" => field.fill_formulas = string_to_bool(&string_value),
+                        b"id" => field.id = string_to_unsignedint(&string_value),
+                        b"name" => field.name = Some(string_value),
+                        b"rowNumbers" => field.row_numbers = string_to_bool(&string_value),
+                        b"tableColumnId" => {
+                            field.table_column_id = string_to_unsignedint(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(field)
+    }
+}
@@ -0,0 +1,153 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+    raw::spreadsheet::filter::sort_state::XlsxSortState,
+};
+
+use super::query_table_field::{load_query_table_fields, XlsxQueryTableFields};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.querytablerefresh?view=openxml-3.0.1
+///
+/// Information about the last refresh of a query table: the fields returned and the fields deleted by the user.
+///
+/// Example:
+/// ```
+/// <queryTableRefresh nextId="4" unboundColumnsRight="1">
+///     <queryTableFields count="3">
+///         <queryTableField id="1" name="Region" tableColumnId="1"/>
+///         <queryTableField id="2" name="Amount" tableColumnId="2"/>
+///         <queryTableField id="3" dataBound="0" tableColumnId="3"/>
+///     </queryTableFields>
+///     <queryTableDeletedFields count="1">
+///         <deletedField name="Comment"/>
+///     </queryTableDeletedFields>
+/// </queryTableRefresh>
+/// ```
+/// queryTableRefresh (QueryTable Refresh Information)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxQueryTableRefresh {
+    // Child Elements
+    /// queryTableDeletedFields (Deleted Fields): names of the deleted fields
+    pub deleted_fields: Vec<String>,
+
+    /// queryTableFields (Query table fields)
+    pub fields: Option<XlsxQueryTableFields>,
+
+    /// sortState (Sort State)
+    pub sort_state: Option<XlsxSortState>,
+
+    // attributes
+    /// fieldIdWrapped (Field Id Wrapped)
+    pub field_id_wrapped: Option<bool>,
+
+    /// headersInLastRefresh (Headers In Last Refresh)
+    pub headers_in_last_refresh: Option<bool>,
+
+    /// minimumVersion (Minimum Refresh Version)
+    pub minimum_version: Option<u64>,
+
+    /// nextId (Next Field Id)
+    pub next_id: Option<u64>,
+
+    /// preserveSortFilterLayout (Preserve Sort and Filter Layout)
+    pub preserve_sort_filter_layout: Option<bool>,
+
+    /// unboundColumnsLeft (Columns Left)
+    pub unbound_columns_left: Option<u64>,
+
+    /// unboundColumnsRight (Columns Right)
+    pub unbound_columns_right: Option<u64>,
+}
+
+impl XlsxQueryTableRefresh {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut refresh = Self {
+            deleted_fields: vec![],
+            fields: None,
+            sort_state: None,
+            field_id_wrapped: None,
+            headers_in_last_refresh: None,
+            minimum_version: None,
+            next_id: None,
+            preserve_sort_filter_layout: None,
+            unbound_columns_left: None,
+            unbound_columns_right: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"fieldIdWrapped" => {
+                            refresh.field_id_wrapped = string_to_bool(&string_value)
+                        }
+                        b"headersInLastRefresh" => {
+                            refresh.headers_in_last_refresh = string_to_bool(&string_value)
+                        }
+                        b"minimumVersion" => {
+                            refresh.minimum_version = string_to_unsignedint(&string_value)
+                        }
+                        b"nextId" => refresh.next_id = string_to_unsignedint(&string_value),
+                        b"preserveSortFilterLayout" => {
+                            refresh.preserve_sort_filter_layout = string_to_bool(&string_value)
+                        }
+                        b"unboundColumnsLeft" => {
+                            refresh.unbound_columns_left = string_to_unsignedint(&string_value)
+                        }
+                        b"unboundColumnsRight" => {
+                            refresh.unbound_columns_right = string_to_unsignedint(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"queryTableFields" => {
+                    refresh.fields = Some(load_query_table_fields(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"deletedField" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => refresh.deleted_fields.push(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sortState" => {
+                    refresh.sort_state = Some(XlsxSortState::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"queryTableRefresh" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(refresh)
+    }
+}
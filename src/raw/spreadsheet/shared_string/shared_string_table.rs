@@ -60,9 +60,7 @@ pub struct XlsxSharedStringTable {
 }
 
 impl XlsxSharedStringTable {
-    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
-        let path = "xl/sharedStrings.xml";
-
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
         let mut shared_string = Self {
             string_item: None,
             count: None,
@@ -0,0 +1,127 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::{string_to_bool, string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.chartsheetpagesetup?view=openxml-3.0.1
+///
+/// Page setup settings of a chartsheet.
+///
+/// Example:
+/// ```
+/// <pageSetup paperSize="9" orientation="landscape" horizontalDpi="300" verticalDpi="300" r:id="rId1"/>
+/// ```
+///
+/// pageSetup (Chart Sheet Page Setup)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartsheetPageSetup {
+    // Attributes
+    /// blackAndWhite (Black And White)
+    pub black_and_white: Option<bool>,
+
+    /// copies (Number Of Copies)
+    pub copies: Option<u64>,
+
+    /// draft (Draft)
+    pub draft: Option<bool>,
+
+    /// firstPageNumber (First Page Number)
+    pub first_page_number: Option<u64>,
+
+    /// horizontalDpi (Horizontal DPI)
+    pub horizontal_dpi: Option<u64>,
+
+    /// id (Id)
+    ///
+    /// Relationship id of the printer settings part.
+    pub id: Option<String>,
+
+    /// orientation (Orientation)
+    ///
+    /// * default
+    /// * landscape
+    /// * portrait
+    pub orientation: Option<String>,
+
+    /// paperHeight (Paper Height)
+    ///
+    /// Height of custom paper with units. Ex: `297mm`
+    pub paper_height: Option<String>,
+
+    /// paperSize (Paper Size)
+    ///
+    /// Index of a predefined paper size. Ex: 1 - Letter, 9 - A4
+    pub paper_size: Option<u64>,
+
+    /// paperWidth (Paper Width)
+    ///
+    /// Width of custom paper with units. Ex: `210mm`
+    pub paper_width: Option<String>,
+
+    /// useFirstPageNumber (Use First Page Number)
+    pub use_first_page_number: Option<bool>,
+
+    /// usePrinterDefaults (Use Printer Defaults)
+    pub use_printer_defaults: Option<bool>,
+
+    /// verticalDpi (Vertical DPI)
+    pub vertical_dpi: Option<u64>,
+}
+
+impl XlsxChartsheetPageSetup {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut setup = Self {
+            black_and_white: None,
+            copies: None,
+            draft: None,
+            first_page_number: None,
+            horizontal_dpi: None,
+            id: None,
+            orientation: None,
+            paper_height: None,
+            paper_size: None,
+            paper_width: None,
+            use_first_page_number: None,
+            use_printer_defaults: None,
+            vertical_dpi: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"blackAndWhite" => setup.black_and_white = string_to_bool(&string_value),
+                        b"copies" => setup.copies = string_to_unsignedint(&string_value),
+                        b"draft" => setup.draft = string_to_bool(&string_value),
+                        b"firstPageNumber" => {
+                            setup.first_page_number = string_to_unsignedint(&string_value)
+                        }
+                        b"horizontalDpi" => {
+                            setup.horizontal_dpi = string_to_unsignedint(&string_value)
+                        }
+                        b"id" => setup.id = Some(string_value),
+                        b"orientation" => setup.orientation = Some(string_value),
+                        b"paperHeight" => setup.paper_height = Some(string_value),
+                        b"paperSize" => setup.paper_size = string_to_unsignedint(&string_value),
+                        b"paperWidth" => setup.paper_width = Some(string_value),
+                        b"useFirstPageNumber" => {
+                            setup.use_first_page_number = string_to_bool(&string_value)
+                        }
+                        b"usePrinterDefaults" => {
+                            setup.use_printer_defaults = string_to_bool(&string_value)
+                        }
+                        b"verticalDpi" => setup.vertical_dpi = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(setup)
+    }
+}
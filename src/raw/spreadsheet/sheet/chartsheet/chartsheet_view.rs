@@ -0,0 +1,104 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+pub type XlsxChartsheetViews = Vec<XlsxChartsheetView>;
+
+pub(crate) fn load_chartsheet_views(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxChartsheetViews> {
+    let mut views: XlsxChartsheetViews = vec![];
+    let mut buf = Vec::new();
+
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetView" => {
+                views.push(XlsxChartsheetView::load(e)?);
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetViews" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file at `sheetViews`."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(views)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.chartsheetview?view=openxml-3.0.1
+///
+/// A single view of a chartsheet.
+///
+/// Example:
+/// ```
+/// <sheetViews>
+///     <sheetView tabSelected="1" zoomScale="115" workbookViewId="0" zoomToFit="1"/>
+/// </sheetViews>
+/// ```
+///
+/// sheetView (Chart Sheet View)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxChartsheetView {
+    // extLst (Future Feature Data Storage Area) Not supported
+
+    // Attributes
+    /// tabSelected (Sheet Tab Selected)
+    pub tab_selected: Option<bool>,
+
+    /// workbookViewId (Workbook View Id)
+    ///
+    /// Zero-based index of the workbook view (`bookViews`) this sheet view belongs to.
+    pub workbook_view_id: Option<u64>,
+
+    /// zoomScale (Window Zoom Scale)
+    ///
+    /// Zoom percentage, 10 - 400.
+    pub zoom_scale: Option<u64>,
+
+    /// zoomToFit (Zoom To Fit)
+    ///
+    /// Whether the chart is scaled to fit the window.
+    pub zoom_to_fit: Option<bool>,
+}
+
+impl XlsxChartsheetView {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut view = Self {
+            tab_selected: None,
+            workbook_view_id: None,
+            zoom_scale: None,
+            zoom_to_fit: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"tabSelected" => view.tab_selected = string_to_bool(&string_value),
+                        b"workbookViewId" => {
+                            view.workbook_view_id = string_to_unsignedint(&string_value)
+                        }
+                        b"zoomScale" => view.zoom_scale = string_to_unsignedint(&string_value),
+                        b"zoomToFit" => view.zoom_to_fit = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(view)
+    }
+}
@@ -0,0 +1,116 @@
+pub mod chartsheet_page_setup;
+pub mod chartsheet_view;
+
+use anyhow::bail;
+use chartsheet_page_setup::XlsxChartsheetPageSetup;
+use chartsheet_view::{load_chartsheet_views, XlsxChartsheetViews};
+use quick_xml::events::Event;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use super::{
+    drawing::XlsxDrawing, page_margins::XlsxPageMargins, sheet_properties::XlsxSheetProperties,
+};
+use crate::excel::xml_reader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.chartsheet?view=openxml-3.0.1
+///
+/// This is the root element of Chartsheet parts.
+/// A chartsheet contains a single chart, placed in the drawing part referenced by `drawing`.
+///
+/// Example:
+/// ```
+/// <chartsheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+///     <sheetPr>
+///         <tabColor rgb="FF00B050"/>
+///     </sheetPr>
+///     <sheetViews>
+///         <sheetView tabSelected="1" zoomScale="115" workbookViewId="0" zoomToFit="1"/>
+///     </sheetViews>
+///     <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+///     <pageSetup orientation="landscape"/>
+///     <drawing r:id="rId1"/>
+/// </chartsheet>
+/// ```
+/// chartsheet (Chart Sheet)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XlsxChartsheet {
+    // extLst (Future Feature Data Storage Area) Not supported
+
+    // Child Elements	Subclause
+    // customSheetViews (Custom Chart Sheet Views)	§18.3.1.26
+
+    // drawing (Drawing)
+    pub drawing: Option<XlsxDrawing>,
+
+    // drawingHF (Drawing Reference in Header Footer)	§18.3.1.37
+    // headerFooter (Header Footer Settings)	§18.3.1.46
+    // legacyDrawing (Legacy Drawing Reference)	§18.3.1.48
+    // legacyDrawingHF (Legacy Drawing Reference in Header Footer)	§18.3.1.49
+
+    // pageMargins (Page Margins)
+    pub page_margins: Option<XlsxPageMargins>,
+
+    // pageSetup (Chart Sheet Page Setup)
+    pub page_setup: Option<XlsxChartsheetPageSetup>,
+
+    // picture (Background Image)	§18.3.1.67
+    // sheetPr (Chart Sheet Properties)
+    pub sheet_properties: Option<XlsxSheetProperties>,
+
+    // sheetProtection (Chart Sheet Protection)	§18.3.1.86
+
+    // sheetViews (Chart Sheet Views)
+    pub sheet_views: Option<XlsxChartsheetViews>,
+    // webPublishItems (Web Publishing Items)	§18.3.1.99
+}
+
+impl XlsxChartsheet {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut chartsheet = Self {
+            drawing: None,
+            page_margins: None,
+            page_setup: None,
+            sheet_properties: None,
+            sheet_views: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(chartsheet);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"drawing" => {
+                    chartsheet.drawing = Some(XlsxDrawing::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageMargins" => {
+                    chartsheet.page_margins = Some(XlsxPageMargins::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageSetup" => {
+                    chartsheet.page_setup = Some(XlsxChartsheetPageSetup::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetPr" => {
+                    chartsheet.sheet_properties = Some(XlsxSheetProperties::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetViews" => {
+                    chartsheet.sheet_views = Some(load_chartsheet_views(&mut reader)?);
+                }
+
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"chartsheet" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        return Ok(chartsheet);
+    }
+}
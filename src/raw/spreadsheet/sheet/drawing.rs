@@ -1,6 +1,26 @@
 use anyhow::bail;
 use quick_xml::events::BytesStart;
 
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.legacydrawing?view=openxml-3.0.1
+///
+/// The sheet contains legacy (VML) drawing components, ex: comment boxes and legacy form controls.
+///
+/// Example:
+/// ```
+/// <legacyDrawing r:id="rId3" />
+/// ```
+pub type XlsxLegacyDrawing = XlsxDrawing;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.legacydrawingheaderfooter?view=openxml-3.0.1
+///
+/// The sheet contains legacy (VML) drawing components used in the header and footer, ex: header/footer images.
+///
+/// Example:
+/// ```
+/// <legacyDrawingHF r:id="rId4" />
+/// ```
+pub type XlsxLegacyDrawingHeaderFooter = XlsxDrawing;
+
 /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.drawing?view=openxml-3.0.1
 ///
 /// The sheet contains drawing components built on the drawingML platform
@@ -1,6 +1,14 @@
+// root of chartsheet
+pub mod chartsheet;
 pub mod worksheet;
 
 // common for sheet
 pub mod drawing;
+pub mod outline_properties;
+pub mod page_margins;
+pub mod page_setup;
+pub mod page_setup_properties;
+pub mod print_options;
 pub mod sheet_format_properties;
+pub mod sheet_properties;
 pub mod sheet_protection;
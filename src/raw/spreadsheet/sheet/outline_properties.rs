@@ -0,0 +1,72 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.outlineproperties?view=openxml-3.0.1
+///
+/// Sheet-level settings related to outlines (grouping).
+///
+/// Example:
+/// ```
+/// <outlinePr summaryBelow="0" summaryRight="0"/>
+/// ```
+///
+/// outlinePr (Outline Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOutlineProperties {
+    // Attributes
+    /// applyStyles (Apply Styles in Outline)
+    ///
+    /// Flag indicating whether to apply styles in this outline.
+    pub apply_styles: Option<bool>,
+
+    /// showOutlineSymbols (Show Outline Symbols)
+    ///
+    /// Flag indicating whether the sheet has outline symbols visible.
+    pub show_outline_symbols: Option<bool>,
+
+    /// summaryBelow (Summary Below)
+    ///
+    /// Flag indicating whether summary rows appear below detail in an outline, when applying an outline.
+    pub summary_below: Option<bool>,
+
+    /// summaryRight (Summary Right)
+    ///
+    /// Flag indicating whether summary columns appear to the right of detail in an outline, when applying an outline.
+    pub summary_right: Option<bool>,
+}
+
+impl XlsxOutlineProperties {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut properties = Self {
+            apply_styles: None,
+            show_outline_symbols: None,
+            summary_below: None,
+            summary_right: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"applyStyles" => properties.apply_styles = string_to_bool(&string_value),
+                        b"showOutlineSymbols" => {
+                            properties.show_outline_symbols = string_to_bool(&string_value)
+                        }
+                        b"summaryBelow" => properties.summary_below = string_to_bool(&string_value),
+                        b"summaryRight" => properties.summary_right = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+}
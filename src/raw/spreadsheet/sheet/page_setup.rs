@@ -0,0 +1,171 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::{string_to_bool, string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagesetup?view=openxml-3.0.1
+///
+/// Page setup settings of a worksheet or a custom sheet view.
+///
+/// Example:
+/// ```
+/// <pageSetup paperSize="9" scale="80" fitToWidth="1" fitToHeight="0" pageOrder="overThenDown" orientation="landscape" r:id="rId1"/>
+/// ```
+///
+/// pageSetup (Page Setup Settings)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPageSetup {
+    // Attributes
+    /// blackAndWhite (Black And White)
+    pub black_and_white: Option<bool>,
+
+    /// cellComments (Print Cell Comments)
+    ///
+    /// * asDisplayed
+    /// * atEnd
+    /// * none
+    pub cell_comments: Option<String>,
+
+    /// copies (Number Of Copies)
+    pub copies: Option<u64>,
+
+    /// draft (Draft)
+    pub draft: Option<bool>,
+
+    /// errors (Print Error Handling)
+    ///
+    /// * blank
+    /// * dash
+    /// * displayed
+    /// * NA
+    pub errors: Option<String>,
+
+    /// firstPageNumber (First Page Number)
+    pub first_page_number: Option<u64>,
+
+    /// fitToHeight (Fit To Height): number of pages tall, 0 for automatic
+    pub fit_to_height: Option<u64>,
+
+    /// fitToWidth (Fit To Width): number of pages wide, 0 for automatic
+    pub fit_to_width: Option<u64>,
+
+    /// horizontalDpi (Horizontal DPI)
+    pub horizontal_dpi: Option<u64>,
+
+    /// id (Id)
+    ///
+    /// Relationship id of the printer settings part.
+    pub id: Option<String>,
+
+    /// orientation (Orientation)
+    ///
+    /// * default
+    /// * landscape
+    /// * portrait
+    pub orientation: Option<String>,
+
+    /// pageOrder (Page Order)
+    ///
+    /// * downThenOver
+    /// * overThenDown
+    pub page_order: Option<String>,
+
+    /// paperHeight (Paper Height)
+    ///
+    /// Height of custom paper with units. Ex: `297mm`
+    pub paper_height: Option<String>,
+
+    /// paperSize (Paper Size)
+    ///
+    /// Index of a predefined paper size. Ex: 1 - Letter, 9 - A4
+    pub paper_size: Option<u64>,
+
+    /// paperWidth (Paper Width)
+    ///
+    /// Width of custom paper with units. Ex: `210mm`
+    pub paper_width: Option<String>,
+
+    /// scale (Print Scale): percentage, 10 - 400
+    pub scale: Option<u64>,
+
+    /// useFirstPageNumber (Use First Page Number)
+    pub use_first_page_number: Option<bool>,
+
+    /// usePrinterDefaults (Use Printer Defaults)
+    pub use_printer_defaults: Option<bool>,
+
+    /// verticalDpi (Vertical DPI)
+    pub vertical_dpi: Option<u64>,
+}
+
+impl XlsxPageSetup {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut setup = Self {
+            black_and_white: None,
+            cell_comments: None,
+            copies: None,
+            draft: None,
+            errors: None,
+            first_page_number: None,
+            fit_to_height: None,
+            fit_to_width: None,
+            horizontal_dpi: None,
+            id: None,
+            orientation: None,
+            page_order: None,
+            paper_height: None,
+            paper_size: None,
+            paper_width: None,
+            scale: None,
+            use_first_page_number: None,
+            use_printer_defaults: None,
+            vertical_dpi: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"blackAndWhite" => setup.black_and_white = string_to_bool(&string_value),
+                        b"cellComments" => setup.cell_comments = Some(string_value),
+                        b"copies" => setup.copies = string_to_unsignedint(&string_value),
+                        b"draft" => setup.draft = string_to_bool(&string_value),
+                        b"errors" => setup.errors = Some(string_value),
+                        b"firstPageNumber" => {
+                            setup.first_page_number = string_to_unsignedint(&string_value)
+                        }
+                        b"fitToHeight" => {
+                            setup.fit_to_height = string_to_unsignedint(&string_value)
+                        }
+                        b"fitToWidth" => setup.fit_to_width = string_to_unsignedint(&string_value),
+                        b"horizontalDpi" => {
+                            setup.horizontal_dpi = string_to_unsignedint(&string_value)
+                        }
+                        b"id" => setup.id = Some(string_value),
+                        b"orientation" => setup.orientation = Some(string_value),
+                        b"pageOrder" => setup.page_order = Some(string_value),
+                        b"paperHeight" => setup.paper_height = Some(string_value),
+                        b"paperSize" => setup.paper_size = string_to_unsignedint(&string_value),
+                        b"paperWidth" => setup.paper_width = Some(string_value),
+                        b"scale" => setup.scale = string_to_unsignedint(&string_value),
+                        b"useFirstPageNumber" => {
+                            setup.use_first_page_number = string_to_bool(&string_value)
+                        }
+                        b"usePrinterDefaults" => {
+                            setup.use_printer_defaults = string_to_bool(&string_value)
+                        }
+                        b"verticalDpi" => setup.vertical_dpi = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(setup)
+    }
+}
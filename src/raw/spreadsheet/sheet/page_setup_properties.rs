@@ -0,0 +1,58 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pagesetupproperties?view=openxml-3.0.1
+///
+/// Page setup properties of the worksheet.
+///
+/// Example:
+/// ```
+/// <pageSetUpPr fitToPage="1"/>
+/// ```
+///
+/// pageSetUpPr (Page Setup Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPageSetupProperties {
+    // Attributes
+    /// autoPageBreaks (Show Auto Page Breaks)
+    ///
+    /// Flag indicating whether the sheet displays Automatic Page Breaks.
+    pub auto_page_breaks: Option<bool>,
+
+    /// fitToPage (Fit To Page)
+    ///
+    /// Flag indicating whether the Fit to Page print option is enabled.
+    pub fit_to_page: Option<bool>,
+}
+
+impl XlsxPageSetupProperties {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut properties = Self {
+            auto_page_breaks: None,
+            fit_to_page: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"autoPageBreaks" => {
+                            properties.auto_page_breaks = string_to_bool(&string_value)
+                        }
+                        b"fitToPage" => properties.fit_to_page = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::helper::string_to_bool;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.printoptions?view=openxml-3.0.1
+///
+/// Print options of a worksheet or a custom sheet view.
+///
+/// Example:
+/// ```
+/// <printOptions horizontalCentered="1" gridLines="1" headings="1"/>
+/// ```
+///
+/// printOptions (Print Options)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPrintOptions {
+    // Attributes
+    /// gridLines (Print Grid Lines)
+    pub grid_lines: Option<bool>,
+
+    /// gridLinesSet (Grid Lines Set)
+    pub grid_lines_set: Option<bool>,
+
+    /// headings (Print Headings): row and column headings
+    pub headings: Option<bool>,
+
+    /// horizontalCentered (Horizontal Centered)
+    pub horizontal_centered: Option<bool>,
+
+    /// verticalCentered (Vertical Centered)
+    pub vertical_centered: Option<bool>,
+}
+
+impl XlsxPrintOptions {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut options = Self {
+            grid_lines: None,
+            grid_lines_set: None,
+            headings: None,
+            horizontal_centered: None,
+            vertical_centered: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"gridLines" => options.grid_lines = string_to_bool(&string_value),
+                        b"gridLinesSet" => options.grid_lines_set = string_to_bool(&string_value),
+                        b"headings" => options.headings = string_to_bool(&string_value),
+                        b"horizontalCentered" => {
+                            options.horizontal_centered = string_to_bool(&string_value)
+                        }
+                        b"verticalCentered" => {
+                            options.vertical_centered = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
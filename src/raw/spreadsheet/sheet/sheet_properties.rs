@@ -0,0 +1,200 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::string_to_bool,
+    raw::spreadsheet::stylesheet::color::XlsxColor,
+};
+
+use super::{
+    outline_properties::XlsxOutlineProperties, page_setup_properties::XlsxPageSetupProperties,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.sheetproperties?view=openxml-3.0.1
+///
+/// Sheet-level properties.
+///
+/// Example:
+/// ```
+/// <sheetPr codeName="Sheet1" filterMode="1" published="0">
+///     <tabColor rgb="FFFF0000"/>
+///     <outlinePr summaryBelow="0" summaryRight="0"/>
+///     <pageSetUpPr fitToPage="1"/>
+/// </sheetPr>
+/// ```
+///
+/// sheetPr (Sheet Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSheetProperties {
+    // Child Elements
+    /// outlinePr (Outline Properties)
+    pub outline_properties: Option<XlsxOutlineProperties>,
+
+    /// pageSetUpPr (Page Setup Properties)
+    pub page_setup_properties: Option<XlsxPageSetupProperties>,
+
+    /// tabColor (Sheet Tab Color)
+    pub tab_color: Option<XlsxColor>,
+
+    // Attributes
+    /// codeName (Code Name)
+    ///
+    /// Stable name of the sheet, which should not change over time, and does not change from user input.
+    /// This name should be used by code to reference a particular sheet.
+    pub code_name: Option<String>,
+
+    /// enableFormatConditionsCalculation (Enable Conditional Formatting Calculations)
+    ///
+    /// Flag indicating whether the conditional formatting calculations shall be evaluated.
+    pub enable_format_conditions_calculation: Option<bool>,
+
+    /// filterMode (Filter Mode)
+    ///
+    /// Flag indicating whether the worksheet has one or more autofilters or advanced filters on.
+    pub filter_mode: Option<bool>,
+
+    /// published (Published)
+    ///
+    /// Flag indicating whether the worksheet should be published.
+    pub published: Option<bool>,
+
+    /// syncHorizontal (Synch Horizontal)
+    ///
+    /// Flag indicating whether horizontal scrolling is synchronized across multiple grouped sheets.
+    pub sync_horizontal: Option<bool>,
+
+    /// syncRef (Synch Reference)
+    ///
+    /// Anchor cell for a worksheet when horizontal or vertical scrolling is synchronized.
+    pub sync_ref: Option<String>,
+
+    /// syncVertical (Synch Vertical)
+    ///
+    /// Flag indicating whether vertical scrolling is synchronized across multiple grouped sheets.
+    pub sync_vertical: Option<bool>,
+
+    /// transitionEntry (Transition Formula Entry)
+    ///
+    /// Flag indicating whether the Transition Formula Entry (Lotus compatibility) option is enabled.
+    pub transition_entry: Option<bool>,
+
+    /// transitionEvaluation (Transition Formula Evaluation)
+    ///
+    /// Flag indicating whether the Transition Formula Evaluation (Lotus compatibility) option is enabled.
+    pub transition_evaluation: Option<bool>,
+}
+
+impl XlsxSheetProperties {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut properties = Self {
+            outline_properties: None,
+            page_setup_properties: None,
+            tab_color: None,
+            code_name: None,
+            enable_format_conditions_calculation: None,
+            filter_mode: None,
+            published: None,
+            sync_horizontal: None,
+            sync_ref: None,
+            sync_vertical: None,
+            transition_entry: None,
+            transition_evaluation: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"codeName" => properties.code_name = Some(string_value),
+                        b"enableFormatConditionsCalculation" => {
+                            properties.enable_format_conditions_calculation =
+                                string_to_bool(&string_value)
+                        }
+                        b"filterMode" => properties.filter_mode = string_to_bool(&string_value),
+                        b"published" => properties.published = string_to_bool(&string_value),
+                        b"syncHorizontal" => {
+                            properties.sync_horizontal = string_to_bool(&string_value)
+                        }
+                        b"syncRef" => properties.sync_ref = Some(string_value),
+                        b"syncVertical" => properties.sync_vertical = string_to_bool(&string_value),
+                        b"transitionEntry" => {
+                            properties.transition_entry = string_to_bool(&string_value)
+                        }
+                        b"transitionEvaluation" => {
+                            properties.transition_evaluation = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"outlinePr" => {
+                    properties.outline_properties = Some(XlsxOutlineProperties::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageSetUpPr" => {
+                    properties.page_setup_properties = Some(XlsxPageSetupProperties::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tabColor" => {
+                    properties.tab_color = Some(XlsxColor::load(e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sheetPr" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `sheetPr`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+
+    /// Load only the `sheetPr` element of a sheet part.
+    ///
+    /// `sheetPr` is always the first child of the root element,
+    /// so reading stops at the first other element instead of parsing the whole sheet.
+    pub(crate) fn load_from_sheet(
+        zip: &mut ZipArchive<impl Read + Seek>,
+        path: &str,
+    ) -> anyhow::Result<Option<Self>> {
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(None);
+        };
+
+        let mut buf = Vec::new();
+        let mut depth = 0;
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetPr" => {
+                    return Ok(Some(Self::load(&mut reader, e)?));
+                }
+                Ok(Event::Start(_)) => {
+                    // root element (worksheet, chartsheet, dialogsheet)
+                    if depth > 0 {
+                        return Ok(None);
+                    }
+                    depth += 1;
+                }
+                Ok(Event::Eof) => return Ok(None),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+    }
+}
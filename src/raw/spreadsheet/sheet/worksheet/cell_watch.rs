@@ -0,0 +1,70 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{common_types::Coordinate, excel::XmlReader};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cellwatches?view=openxml-3.0.1
+///
+/// Cells displayed in the Watch Window.
+///
+/// Example:
+/// ```
+/// <cellWatches>
+///     <cellWatch r="B5"/>
+///     <cellWatch r="D12"/>
+/// </cellWatches>
+/// ```
+/// cellWatches (Cell Watch Items)
+pub type XlsxCellWatches = Vec<XlsxCellWatch>;
+
+pub(crate) fn load_cell_watches(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxCellWatches> {
+    let mut watches: XlsxCellWatches = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cellWatch" => {
+                if let Some(watch) = load_cell_watch(e)? {
+                    watches.push(watch);
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"cellWatches" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(watches)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.cellwatch?view=openxml-3.0.1
+///
+/// A watched cell
+///
+/// Example
+/// ```
+/// <cellWatch r="B5"/>
+/// ```
+/// cellWatch (Cell Watch Item)
+pub type XlsxCellWatch = Coordinate;
+
+pub(crate) fn load_cell_watch(e: &BytesStart) -> anyhow::Result<Option<XlsxCellWatch>> {
+    for a in e.attributes() {
+        match a {
+            Ok(a) => match a.key.local_name().as_ref() {
+                b"r" => return Ok(XlsxCellWatch::from_a1(a.value.as_ref())),
+                _ => {}
+            },
+            Err(error) => {
+                bail!(error.to_string())
+            }
+        }
+    }
+    Ok(None)
+}
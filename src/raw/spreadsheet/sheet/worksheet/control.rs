@@ -0,0 +1,268 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use super::object_anchor::XlsxObjectAnchor;
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.controls?view=openxml-3.0.1
+///
+/// Form controls and ActiveX controls of the sheet.
+///
+/// Controls within `mc:AlternateContent` are read from `mc:Choice`, the `mc:Fallback` copy is skipped.
+///
+/// Example
+/// ```
+/// <controls>
+///     <mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">
+///         <mc:Choice Requires="x14">
+///             <control shapeId="1026" r:id="rId4" name="Check Box 1">
+///                 <controlPr defaultSize="0" autoFill="0" autoLine="0" autoPict="0" macro="[0]!Approve">
+///                     <anchor moveWithCells="1">
+///                         <from><xdr:col>0</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>2</xdr:row><xdr:rowOff>0</xdr:rowOff></from>
+///                         <to><xdr:col>1</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>3</xdr:row><xdr:rowOff>0</xdr:rowOff></to>
+///                     </anchor>
+///                 </controlPr>
+///             </control>
+///         </mc:Choice>
+///         <mc:Fallback />
+///     </mc:AlternateContent>
+/// </controls>
+/// ```
+/// controls
+pub type XlsxControls = Vec<XlsxControl>;
+
+pub(crate) fn load_controls(reader: &mut XmlReader<impl Read>) -> anyhow::Result<XlsxControls> {
+    let mut controls: XlsxControls = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Fallback" => {
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"control" => {
+                controls.push(XlsxControl::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"controls" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(controls)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.control?view=openxml-3.0.1
+///
+/// A form control or an ActiveX control.
+///
+/// Example
+/// ```
+/// <control shapeId="1027" r:id="rId5" name="Drop Down 2">
+///     <controlPr defaultSize="0" autoLine="0" autoPict="0">
+///         <anchor moveWithCells="1">...</anchor>
+///     </controlPr>
+/// </control>
+/// ```
+/// control
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxControl {
+    // Child Elements
+    /// controlPr (Embedded Control Properties)
+    pub control_properties: Option<XlsxControlProperties>,
+
+    // attributes
+    /// r:id: relationship id of the form control properties (xl/ctrlProps/) or of the ActiveX control (xl/activeX/)
+    pub id: Option<String>,
+
+    /// name (Control Name)
+    pub name: Option<String>,
+
+    /// shapeId (Shape Id): id of the legacy (VML) shape of the control
+    pub shape_id: Option<u64>,
+}
+
+impl XlsxControl {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut control = Self {
+            control_properties: None,
+            id: None,
+            name: None,
+            shape_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => control.id = Some(string_value),
+                        b"name" => control.name = Some(string_value),
+                        b"shapeId" => control.shape_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"controlPr" => {
+                    control.control_properties = Some(XlsxControlProperties::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"control" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(control)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.controlproperties?view=openxml-3.0.1
+///
+/// Properties of an embedded control.
+///
+/// Example
+/// ```
+/// <controlPr defaultSize="0" print="0" autoLine="0" linkedCell="Sheet1!A1" r:id="rId6">
+///     <anchor moveWithCells="1">...</anchor>
+/// </controlPr>
+/// ```
+/// controlPr
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxControlProperties {
+    // Child Elements
+    /// anchor (Object Cell Anchor)
+    pub anchor: Option<XlsxObjectAnchor>,
+
+    // attributes
+    /// altText (Alternative Text)
+    pub alt_text: Option<String>,
+
+    /// autoFill (Auto Fill)
+    pub auto_fill: Option<bool>,
+
+    /// autoLine (Auto Line)
+    pub auto_line: Option<bool>,
+
+    /// autoPict (Automatic Picture Size)
+    pub auto_pict: Option<bool>,
+
+    /// cf (Clipboard Format)
+    pub cf: Option<String>,
+
+    /// defaultSize (Default Size)
+    pub default_size: Option<bool>,
+
+    /// disabled (Disabled)
+    pub disabled: Option<bool>,
+
+    /// r:id: relationship id of the image of the control
+    pub id: Option<String>,
+
+    /// linkedCell (Linked Cell): cell linked to the value of an ActiveX control
+    pub linked_cell: Option<String>,
+
+    /// listFillRange (List Items Source Range): input range of an ActiveX list control
+    pub list_fill_range: Option<String>,
+
+    /// locked (Locked)
+    pub locked: Option<bool>,
+
+    /// macro (Macro): name of the macro assigned to the control
+    pub r#macro: Option<String>,
+
+    /// print (Print)
+    pub print: Option<bool>,
+
+    /// recalcAlways (Recalculation Flag)
+    pub recalc_always: Option<bool>,
+
+    /// uiObject (UI Object)
+    pub ui_object: Option<bool>,
+}
+
+impl XlsxControlProperties {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            anchor: None,
+            alt_text: None,
+            auto_fill: None,
+            auto_line: None,
+            auto_pict: None,
+            cf: None,
+            default_size: None,
+            disabled: None,
+            id: None,
+            linked_cell: None,
+            list_fill_range: None,
+            locked: None,
+            r#macro: None,
+            print: None,
+            recalc_always: None,
+            ui_object: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"altText" => properties.alt_text = Some(string_value),
+                        b"autoFill" => properties.auto_fill = string_to_bool(&string_value),
+                        b"autoLine" => properties.auto_line = string_to_bool(&string_value),
+                        b"autoPict" => properties.auto_pict = string_to_bool(&string_value),
+                        b"cf" => properties.cf = Some(string_value),
+                        b"defaultSize" => properties.default_size = string_to_bool(&string_value),
+                        b"disabled" => properties.disabled = string_to_bool(&string_value),
+                        b"id" => properties.id = Some(string_value),
+                        b"linkedCell" => properties.linked_cell = Some(string_value),
+                        b"listFillRange" => properties.list_fill_range = Some(string_value),
+                        b"locked" => properties.locked = string_to_bool(&string_value),
+                        b"macro" => properties.r#macro = Some(string_value),
+                        b"print" => properties.print = string_to_bool(&string_value),
+                        b"recalcAlways" => properties.recalc_always = string_to_bool(&string_value),
+                        b"uiObject" => properties.ui_object = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"anchor" => {
+                    properties.anchor = Some(XlsxObjectAnchor::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"controlPr" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+}
@@ -0,0 +1,283 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use super::pane::{XlsxPane, XlsxSelection};
+use crate::{
+    common_types::Coordinate,
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+    raw::spreadsheet::{
+        filter::auto_filter::XlsxAutoFilter,
+        sheet::{
+            page_margins::XlsxPageMargins, page_setup::XlsxPageSetup,
+            print_options::XlsxPrintOptions,
+        },
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.customsheetviews?view=openxml-3.0.1
+///
+/// Settings of the worksheet for each custom workbook view (`customWorkbookView` of xl/workbook.xml with the same guid).
+///
+/// Example:
+/// ```
+/// <customSheetViews>
+///     <customSheetView guid="{CE6681F1-E999-414D-8446-68A031534B57}" scale="85" showGridLines="0" hiddenRows="1" hiddenColumns="1" topLeftCell="A3">
+///         <selection activeCell="B4" sqref="B4"/>
+///         <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+///         <pageSetup orientation="landscape"/>
+///     </customSheetView>
+/// </customSheetViews>
+/// ```
+/// customSheetViews (Custom Sheet Views)
+pub type XlsxCustomSheetViews = Vec<XlsxCustomSheetView>;
+
+pub(crate) fn load_custom_sheet_views(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxCustomSheetViews> {
+    let mut views: XlsxCustomSheetViews = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"customSheetView" => {
+                views.push(XlsxCustomSheetView::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"customSheetViews" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(views)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.customsheetview?view=openxml-3.0.1
+///
+/// Display and print settings of the worksheet in a custom view.
+///
+/// The rows and columns hidden, the print area and the print titles of the view are stored as hidden defined names of the workbook.
+/// Ex: `Z_CE6681F1_E999_414D_8446_68A031534B57_.wvu.Rows`
+///
+/// Example:
+/// ```
+/// <customSheetView guid="{CE6681F1-E999-414D-8446-68A031534B57}" scale="85" showGridLines="0" hiddenRows="1" hiddenColumns="1" topLeftCell="A3">
+///     <selection activeCell="B4" sqref="B4"/>
+///     <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+///     <pageSetup orientation="landscape"/>
+/// </customSheetView>
+/// ```
+/// customSheetView (Custom Sheet View)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxCustomSheetView {
+    // extLst (Future Feature Data Storage Area) Not supported
+
+    // Child Elements
+    /// autoFilter (AutoFilter Settings)
+    pub auto_filter: Option<XlsxAutoFilter>,
+
+    // colBreaks (Vertical Page Breaks) Not supported
+    // headerFooter (Header Footer Settings) Not supported
+    /// pageMargins (Page Margins)
+    pub page_margins: Option<XlsxPageMargins>,
+
+    /// pageSetup (Page Setup Settings)
+    pub page_setup: Option<XlsxPageSetup>,
+
+    /// pane (View Pane)
+    pub pane: Option<XlsxPane>,
+
+    /// printOptions (Print Options)
+    pub print_options: Option<XlsxPrintOptions>,
+
+    // rowBreaks (Horizontal Page Breaks) Not supported
+    /// selection (Selection)
+    pub selection: Option<XlsxSelection>,
+
+    // Attributes
+    /// colorId (Color Id): index of the color of the grid lines
+    pub color_id: Option<u64>,
+
+    /// filter (Filter): whether the view has an auto filter
+    pub filter: Option<bool>,
+
+    /// filterUnique (Show Unique Filter)
+    pub filter_unique: Option<bool>,
+
+    /// fitToPage (Fit To Page)
+    pub fit_to_page: Option<bool>,
+
+    /// guid (GUID): guid of the custom workbook view
+    pub guid: Option<String>,
+
+    /// hiddenColumns (Hidden Columns): whether some columns are hidden in the view
+    pub hidden_columns: Option<bool>,
+
+    /// hiddenRows (Hidden Rows): whether some rows are hidden in the view
+    pub hidden_rows: Option<bool>,
+
+    /// outlineSymbols (Show Outline Symbols)
+    pub outline_symbols: Option<bool>,
+
+    /// printArea (Print Area Defined): whether the view has a print area
+    pub print_area: Option<bool>,
+
+    /// scale (Print Scale): zoom percentage, 10 - 400
+    pub scale: Option<u64>,
+
+    /// showAutoFilter (Show AutoFitler Drop Down Controls)
+    pub show_auto_filter: Option<bool>,
+
+    /// showFormulas (Show Formulas)
+    pub show_formulas: Option<bool>,
+
+    /// showGridLines (Show Grid Lines)
+    pub show_grid_lines: Option<bool>,
+
+    /// showPageBreaks (Show Page Breaks)
+    pub show_page_breaks: Option<bool>,
+
+    /// showRowCol (Show Headers)
+    pub show_row_col: Option<bool>,
+
+    /// showRuler (Show Ruler)
+    pub show_ruler: Option<bool>,
+
+    /// showZeros (Show Zero Values)
+    pub show_zeros: Option<bool>,
+
+    /// state (Visible State)
+    ///
+    /// * hidden
+    /// * veryHidden
+    /// * visible
+    pub state: Option<String>,
+
+    /// topLeftCell (Top Left Visible Cell)
+    pub top_left_cell: Option<Coordinate>,
+
+    /// view (View Type)
+    ///
+    /// * normal
+    /// * pageBreakPreview
+    /// * pageLayout
+    pub view: Option<String>,
+}
+
+impl XlsxCustomSheetView {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut view = Self {
+            auto_filter: None,
+            page_margins: None,
+            page_setup: None,
+            pane: None,
+            print_options: None,
+            selection: None,
+            color_id: None,
+            filter: None,
+            filter_unique: None,
+            fit_to_page: None,
+            guid: None,
+            hidden_columns: None,
+            hidden_rows: None,
+            outline_symbols: None,
+            print_area: None,
+            scale: None,
+            show_auto_filter: None,
+            show_formulas: None,
+            show_grid_lines: None,
+            show_page_breaks: None,
+            show_row_col: None,
+            show_ruler: None,
+            show_zeros: None,
+            state: None,
+            top_left_cell: None,
+            view: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"colorId" => view.color_id = string_to_unsignedint(&string_value),
+                        b"filter" => view.filter = string_to_bool(&string_value),
+                        b"filterUnique" => view.filter_unique = string_to_bool(&string_value),
+                        b"fitToPage" => view.fit_to_page = string_to_bool(&string_value),
+                        b"guid" => view.guid = Some(string_value),
+                        b"hiddenColumns" => view.hidden_columns = string_to_bool(&string_value),
+                        b"hiddenRows" => view.hidden_rows = string_to_bool(&string_value),
+                        b"outlineSymbols" => view.outline_symbols = string_to_bool(&string_value),
+                        b"printArea" => view.print_area = string_to_bool(&string_value),
+                        b"scale" => view.scale = string_to_unsignedint(&string_value),
+                        b"showAutoFilter" => view.show_auto_filter = string_to_bool(&string_value),
+                        b"showFormulas" => view.show_formulas = string_to_bool(&string_value),
+                        b"showGridLines" => view.show_grid_lines = string_to_bool(&string_value),
+                        b"showPageBreaks" => view.show_page_breaks = string_to_bool(&string_value),
+                        b"showRowCol" => view.show_row_col = string_to_bool(&string_value),
+                        b"showRuler" => view.show_ruler = string_to_bool(&string_value),
+                        b"showZeros" => view.show_zeros = string_to_bool(&string_value),
+                        b"state" => view.state = Some(string_value),
+                        b"topLeftCell" => {
+                            view.top_left_cell = Coordinate::from_a1(a.value.as_ref())
+                        }
+                        b"view" => view.view = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"autoFilter" => {
+                    view.auto_filter = Some(XlsxAutoFilter::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageMargins" => {
+                    view.page_margins = Some(XlsxPageMargins::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pageSetup" => {
+                    view.page_setup = Some(XlsxPageSetup::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pane" => {
+                    view.pane = Some(XlsxPane::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"printOptions" => {
+                    view.print_options = Some(XlsxPrintOptions::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"selection" => {
+                    view.selection = Some(XlsxSelection::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"rowBreaks"
+                        || e.local_name().as_ref() == b"colBreaks"
+                        || e.local_name().as_ref() == b"headerFooter"
+                        || e.local_name().as_ref() == b"extLst" =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"customSheetView" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(view)
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_bool};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.dataconsolidate?view=openxml-3.0.1
+///
+/// Settings of the last data consolidation of the worksheet: the source ranges and the function aggregating them.
+///
+/// Example:
+/// ```
+/// <dataConsolidate function="average" leftLabels="1" topLabels="1" link="1">
+///     <dataRefs count="2">
+///         <dataRef ref="A1:C10" sheet="North"/>
+///         <dataRef ref="A1:C10" sheet="South"/>
+///     </dataRefs>
+/// </dataConsolidate>
+/// ```
+/// dataConsolidate (Data Consolidate)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDataConsolidate {
+    // Child Elements
+    /// dataRefs (Data Consolidation References)
+    pub data_references: Option<XlsxDataReferences>,
+
+    // attributes
+    /// function (Function Index)
+    ///
+    /// Possible values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.datafunctionvalues?view=openxml-3.0.1
+    pub function: Option<String>,
+
+    /// leftLabels (Use Left Column Labels)
+    pub left_labels: Option<bool>,
+
+    /// link (Link)
+    pub link: Option<bool>,
+
+    /// startLabels (Use Starting Column Labels)
+    pub start_labels: Option<bool>,
+
+    /// topLabels (Labels In Top Row)
+    pub top_labels: Option<bool>,
+}
+
+impl XlsxDataConsolidate {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut consolidate = Self {
+            data_references: None,
+            function: None,
+            left_labels: None,
+            link: None,
+            start_labels: None,
+            top_labels: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"function" => consolidate.function = Some(string_value),
+                        b"leftLabels" => consolidate.left_labels = string_to_bool(&string_value),
+                        b"link" => consolidate.link = string_to_bool(&string_value),
+                        b"startLabels" => consolidate.start_labels = string_to_bool(&string_value),
+                        b"topLabels" => consolidate.top_labels = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dataRefs" => {
+                    consolidate.data_references = Some(load_data_references(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"dataConsolidate" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(consolidate)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.datareferences?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <dataRefs count="2">
+///     <dataRef ref="A1:C10" sheet="North"/>
+///     <dataRef ref="A1:C10" sheet="South"/>
+/// </dataRefs>
+/// ```
+/// dataRefs (Data Consolidation References)
+pub type XlsxDataReferences = Vec<XlsxDataReference>;
+
+pub(crate) fn load_data_references(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxDataReferences> {
+    let mut references: XlsxDataReferences = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dataRef" => {
+                references.push(XlsxDataReference::load(e)?);
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"dataRefs" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(references)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.datareference?view=openxml-3.0.1
+///
+/// A source range of the consolidation.
+///
+/// Example:
+/// ```
+/// <dataRef ref="A1:C10" sheet="North"/>
+/// ```
+/// dataRef (Data Consolidation Reference)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDataReference {
+    // attributes
+    /// r:id (relationship Id): external workbook containing the range
+    pub r_id: Option<String>,
+
+    /// name (Named Range)
+    pub name: Option<String>,
+
+    /// ref (Reference)
+    pub reference: Option<String>,
+
+    /// sheet (Sheet Name)
+    pub sheet: Option<String>,
+}
+
+impl XlsxDataReference {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut reference = Self {
+            r_id: None,
+            name: None,
+            reference: None,
+            sheet: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => reference.r_id = Some(string_value),
+                        b"name" => reference.name = Some(string_value),
+                        b"ref" => reference.reference = Some(string_value),
+                        b"sheet" => reference.sheet = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(reference)
+    }
+}
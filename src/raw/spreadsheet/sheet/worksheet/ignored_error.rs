@@ -0,0 +1,142 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_bool};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ignorederrors?view=openxml-3.0.1
+///
+/// Error checking warnings ignored on ranges of the worksheet.
+///
+/// Example:
+/// ```
+/// <ignoredErrors>
+///     <ignoredError sqref="B2:B10 D4" numberStoredAsText="1"/>
+///     <ignoredError sqref="E2:E10" formulaRange="1" unlockedFormula="1"/>
+/// </ignoredErrors>
+/// ```
+/// ignoredErrors (Ignored Errors)
+pub type XlsxIgnoredErrors = Vec<XlsxIgnoredError>;
+
+pub(crate) fn load_ignored_errors(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxIgnoredErrors> {
+    let mut errors: XlsxIgnoredErrors = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ignoredError" => {
+                errors.push(XlsxIgnoredError::load(e)?);
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"ignoredErrors" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(errors)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.ignorederror?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <ignoredError sqref="B2:B10 D4" numberStoredAsText="1"/>
+/// ```
+/// ignoredError (Ignored Error)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxIgnoredError {
+    // attributes
+    /// calculatedColumn (Calculated Column): formula inconsistent with the calculated column formula of the table
+    pub calculated_column: Option<bool>,
+
+    /// emptyCellReference (Empty Cell Reference): formula referring to empty cells
+    pub empty_cell_reference: Option<bool>,
+
+    /// evalError (Evaluation Error): formula resulting in an error
+    pub eval_error: Option<bool>,
+
+    /// formula (Formula): formula inconsistent with the formulas of the region
+    pub formula: Option<bool>,
+
+    /// formulaRange (Formula Range): formula omitting adjacent cells
+    pub formula_range: Option<bool>,
+
+    /// listDataValidation (Data Validation): value not valid for the data validation of the table column
+    pub list_data_validation: Option<bool>,
+
+    /// numberStoredAsText (Number Stored As Text)
+    pub number_stored_as_text: Option<bool>,
+
+    /// sqref (Sequence of References)
+    pub sqref: Option<String>,
+
+    /// twoDigitTextYear (Two Digit Text Year)
+    pub two_digit_text_year: Option<bool>,
+
+    /// unlockedFormula (Unlocked Formula): unlocked cell containing a formula
+    pub unlocked_formula: Option<bool>,
+}
+
+impl XlsxIgnoredError {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut error = Self {
+            calculated_column: None,
+            empty_cell_reference: None,
+            eval_error: None,
+            formula: None,
+            formula_range: None,
+            list_data_validation: None,
+            number_stored_as_text: None,
+            sqref: None,
+            two_digit_text_year: None,
+            unlocked_formula: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"calculatedColumn" => {
+                            error.calculated_column = string_to_bool(&string_value)
+                        }
+                        b"emptyCellReference" => {
+                            error.empty_cell_reference = string_to_bool(&string_value)
+                        }
+                        b"evalError" => error.eval_error = string_to_bool(&string_value),
+                        b"formula" => error.formula = string_to_bool(&string_value),
+                        b"formulaRange" => error.formula_range = string_to_bool(&string_value),
+                        b"listDataValidation" => {
+                            error.list_data_validation = string_to_bool(&string_value)
+                        }
+                        b"numberStoredAsText" => {
+                            error.number_stored_as_text = string_to_bool(&string_value)
+                        }
+                        b"sqref" => error.sqref = Some(string_value),
+                        b"twoDigitTextYear" => {
+                            error.two_digit_text_year = string_to_bool(&string_value)
+                        }
+                        b"unlockedFormula" => {
+                            error.unlocked_formula = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(error)
+    }
+}
@@ -1,30 +1,56 @@
 pub mod cell;
+pub mod cell_watch;
 pub mod column_information;
+pub mod control;
+pub mod custom_sheet_view;
+pub mod data_consolidate;
 pub mod hyperlink;
+pub mod ignored_error;
 pub mod merge_cell;
+pub mod object_anchor;
+pub mod ole_object;
+pub mod pane;
 pub mod row;
+pub mod scenario;
 pub mod sheet_data;
 pub mod sheet_dimension;
 pub mod sheet_view;
+pub mod sparkline;
 pub mod table_part;
 
 use anyhow::bail;
+use cell_watch::{load_cell_watches, XlsxCellWatches};
 use column_information::{load_column_infos, XlsxColumnInformations};
+use control::{load_controls, XlsxControls};
+use custom_sheet_view::{load_custom_sheet_views, XlsxCustomSheetViews};
+use data_consolidate::XlsxDataConsolidate;
 use hyperlink::{load_hyperlinks, XlsxHyperlinks};
+use ignored_error::{load_ignored_errors, XlsxIgnoredErrors};
 use merge_cell::{load_merge_cells, XlsxMergeCells};
+use ole_object::{load_ole_objects, XlsxOleObjects};
 use quick_xml::events::Event;
+use row::XlsxRow;
+use scenario::XlsxScenarios;
 use sheet_data::XlsxSheetData;
 use sheet_dimension::{load_sheet_dimension, XlsxSheetDimension};
+use sparkline::XlsxSparklineGroups;
 use std::io::{Read, Seek};
 use table_part::{load_table_parts, XlsxTableParts};
 use zip::ZipArchive;
 
-use super::{drawing::XlsxDrawing, sheet_format_properties::XlsxSheetFormatProperties};
+use super::{
+    drawing::{XlsxDrawing, XlsxLegacyDrawing, XlsxLegacyDrawingHeaderFooter},
+    sheet_format_properties::XlsxSheetFormatProperties,
+    sheet_properties::XlsxSheetProperties,
+};
 use crate::{
     excel::xml_reader,
-    raw::spreadsheet::{
-        filter::auto_filter::XlsxAutoFilter,
-        string_item::phonetic_properties::XlsxPhoneticProperties,
+    raw::{
+        extension_list::{load_extension_list, XlsxExtensionList},
+        spreadsheet::{
+            filter::auto_filter::XlsxAutoFilter,
+            string_item::phonetic_properties::XlsxPhoneticProperties,
+        },
     },
 };
 
@@ -70,21 +96,31 @@ use crate::{
 /// worksheet (Worksheet)
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct XlsxWorksheet {
-    // extLst (Future Feature Data Storage Area) Not supported
+    // extLst (Future Feature Data Storage Area)
+    pub extension_list: Option<XlsxExtensionList>,
 
     // Child Elements	Subclause
     // autoFilter (AutoFilter Settings)	§18.3.1.2
     pub auto_filter: Option<XlsxAutoFilter>,
     // cellWatches (Cell Watch Items)	§18.3.1.9
+    pub cell_watches: Option<XlsxCellWatches>,
+
     // colBreaks (Vertical Page Breaks)	§18.3.1.14
 
     // cols (Column Information)	§18.3.1.17
     pub column_infos: Option<XlsxColumnInformations>,
     // conditionalFormatting (Conditional Formatting)	§18.3.1.18
-    // controls (Embedded Controls)	§18.3.1.21
+
+    // controls (Embedded Controls)
+    pub controls: Option<XlsxControls>,
+
     // customProperties (Custom Properties)	§18.3.1.23
     // customSheetViews (Custom Sheet Views)	§18.3.1.27
+    pub custom_sheet_views: Option<XlsxCustomSheetViews>,
+
     // dataConsolidate (Data Consolidate)	§18.3.1.29
+    pub data_consolidate: Option<XlsxDataConsolidate>,
+
     // dataValidations (Data Validations)	§18.3.1.33
 
     // dimension (Worksheet Dimensions)
@@ -100,11 +136,20 @@ pub struct XlsxWorksheet {
     pub hyperlinks: Option<XlsxHyperlinks>,
 
     // ignoredErrors (Ignored Errors)	§18.3.1.51
+    pub ignored_errors: Option<XlsxIgnoredErrors>,
+
+    // legacyDrawing (Legacy Drawing Reference)
+    pub legacy_drawing: Option<XlsxLegacyDrawing>,
+
+    // legacyDrawingHF (Legacy Drawing Reference in Header Footer)
+    pub legacy_drawing_header_footer: Option<XlsxLegacyDrawingHeaderFooter>,
 
     // mergeCells (Merge Cells)	§18.3.1.55
     pub merge_cells: Option<XlsxMergeCells>,
 
-    // oleObjects (Embedded Objects)	§18.3.1.60
+    // oleObjects (Embedded Objects)
+    pub ole_objects: Option<XlsxOleObjects>,
+
     // pageMargins (Page Margins)	§18.3.1.62
     // pageSetup (Page Setup Settings)	§18.3.1.63
     // phoneticPr (Phonetic Properties)	§18.4.3
@@ -115,6 +160,8 @@ pub struct XlsxWorksheet {
     // protectedRanges (Protected Ranges)	§18.3.1.72
     // rowBreaks (Horizontal Page Breaks (Row))	§18.3.1.74
     // scenarios (Scenarios)	§18.3.1.76
+    pub scenarios: Option<XlsxScenarios>,
+
     // sheetCalcPr (Sheet Calculation Properties)	§18.3.1.79
 
     // sheetData (Sheet Data)	§18.3.1.80
@@ -122,12 +169,18 @@ pub struct XlsxWorksheet {
 
     // sheetFormatPr (Sheet Format Properties)	§18.3.1.81
     pub sheet_format_properties: Option<XlsxSheetFormatProperties>,
+
     // sheetPr (Sheet Properties)	§18.3.1.82
+    pub sheet_properties: Option<XlsxSheetProperties>,
+
     // sheetProtection (Sheet Protection Options)	§18.3.1.85
     // sheetViews (Sheet Views)	§18.3.1.88
     // smartTags (Smart Tags)	§18.3.1.90
     // sortState (Sort State)	§18.3.1.92
 
+    // x14:sparklineGroups (Sparkline Groups) decoded from extLst with the extension handler registered for its uri
+    pub sparkline_groups: Option<XlsxSparklineGroups>,
+
     // tableParts (Table Parts)	§18.3.1.95
     pub table_parts: Option<XlsxTableParts>, // webPublishItems (Web Publishing Items)
 }
@@ -135,15 +188,27 @@ pub struct XlsxWorksheet {
 impl XlsxWorksheet {
     pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
         let mut worksheet = Self {
+            extension_list: None,
             auto_filter: None,
+            cell_watches: None,
             column_infos: None,
+            controls: None,
+            custom_sheet_views: None,
+            data_consolidate: None,
             dimension: None,
             drawing: None,
             hyperlinks: None,
+            ignored_errors: None,
+            legacy_drawing: None,
+            legacy_drawing_header_footer: None,
             merge_cells: None,
+            ole_objects: None,
             phonetic_properties: None,
+            scenarios: None,
             sheet_data: None,
             sheet_format_properties: None,
+            sheet_properties: None,
+            sparkline_groups: None,
             table_parts: None,
         };
 
@@ -158,14 +223,26 @@ impl XlsxWorksheet {
 
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
-                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                    worksheet.extension_list = Some(load_extension_list(&mut reader)?);
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"autoFilter" => {
                     worksheet.auto_filter = Some(XlsxAutoFilter::load(&mut reader, e)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cellWatches" => {
+                    worksheet.cell_watches = Some(load_cell_watches(&mut reader)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cols" => {
                     worksheet.column_infos = Some(load_column_infos(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"controls" => {
+                    worksheet.controls = Some(load_controls(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"customSheetViews" => {
+                    worksheet.custom_sheet_views = Some(load_custom_sheet_views(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dataConsolidate" => {
+                    worksheet.data_consolidate = Some(XlsxDataConsolidate::load(&mut reader, e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dimension" => {
                     worksheet.dimension = load_sheet_dimension(e)?;
                 }
@@ -175,21 +252,51 @@ impl XlsxWorksheet {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"hyperlinks" => {
                     worksheet.hyperlinks = Some(load_hyperlinks(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"ignoredErrors" => {
+                    worksheet.ignored_errors = Some(load_ignored_errors(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"legacyDrawing" => {
+                    worksheet.legacy_drawing = Some(XlsxLegacyDrawing::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"legacyDrawingHF" => {
+                    worksheet.legacy_drawing_header_footer =
+                        Some(XlsxLegacyDrawingHeaderFooter::load(e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"mergeCells" => {
                     worksheet.merge_cells = Some(load_merge_cells(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"oleObjects" => {
+                    worksheet.ole_objects = Some(load_ole_objects(&mut reader)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"phoneticPr" => {
                     worksheet.phonetic_properties = Some(XlsxPhoneticProperties::load(e)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"scenarios" => {
+                    worksheet.scenarios = Some(XlsxScenarios::load(&mut reader, e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetData" => {
                     worksheet.sheet_data = Some(XlsxSheetData::load(&mut reader)?);
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetFormatPr" => {
                     worksheet.sheet_format_properties = Some(XlsxSheetFormatProperties::load(e)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetPr" => {
+                    worksheet.sheet_properties = Some(XlsxSheetProperties::load(&mut reader, e)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tableParts" => {
                     worksheet.table_parts = Some(load_table_parts(&mut reader)?);
                 }
+                // skip unsupported elements (conditionalFormatting, sheetViews, ...) with their children,
+                // so that their own extLst is not taken as the one of the worksheet.
+                // mc:AlternateContent is walked through: Excel wraps controls and oleObjects in it.
+                Ok(Event::Start(ref e))
+                    if !matches!(
+                        e.local_name().as_ref(),
+                        b"worksheet" | b"AlternateContent" | b"Choice" | b"Fallback"
+                    ) =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
 
                 Ok(Event::End(ref e)) if e.local_name().as_ref() == b"worksheet" => break,
                 Ok(Event::Eof) => break,
@@ -200,4 +307,200 @@ impl XlsxWorksheet {
 
         return Ok(worksheet);
     }
+
+    /// Load only the elements of a worksheet used by its custom views:
+    /// `dimension`, `cols`, `customSheetViews` and the attributes of the rows, without their cells.
+    pub(crate) fn load_for_custom_views(
+        zip: &mut ZipArchive<impl Read + Seek>,
+        path: &str,
+    ) -> anyhow::Result<Self> {
+        let mut worksheet = Self::default();
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(worksheet);
+        };
+
+        let mut buf = Vec::new();
+        let mut rows: Vec<XlsxRow> = vec![];
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"cols" => {
+                    worksheet.column_infos = Some(load_column_infos(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"customSheetViews" => {
+                    worksheet.custom_sheet_views = Some(load_custom_sheet_views(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dimension" => {
+                    worksheet.dimension = load_sheet_dimension(e)?;
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"row" => {
+                    rows.push(XlsxRow::load_attributes(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e))
+                    if !matches!(e.local_name().as_ref(), b"worksheet" | b"sheetData") =>
+                {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"worksheet" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        worksheet.sheet_data = Some(XlsxSheetData { rows: Some(rows) });
+
+        return Ok(worksheet);
+    }
+
+    /// Load only the `dimension` element of a worksheet.
+    ///
+    /// `dimension` comes before `sheetData`, so reading stops at `sheetData` instead of parsing the whole sheet.
+    pub(crate) fn load_dimension(
+        zip: &mut ZipArchive<impl Read + Seek>,
+        path: &str,
+    ) -> anyhow::Result<Option<XlsxSheetDimension>> {
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(None);
+        };
+
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"dimension" => {
+                    return load_sheet_dimension(e);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheetData" => {
+                    return Ok(None);
+                }
+                Ok(Event::Eof) => return Ok(None),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_types::Dimension;
+    use std::io::{Cursor, Write};
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    fn worksheet_zip(xml: &str) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("xl/worksheets/sheet1.xml", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(xml.as_bytes()).unwrap();
+        return ZipArchive::new(writer.finish().unwrap()).unwrap();
+    }
+
+    fn load_worksheet(xml: &str) -> XlsxWorksheet {
+        let mut zip = worksheet_zip(xml);
+        return XlsxWorksheet::load(&mut zip, "xl/worksheets/sheet1.xml").unwrap();
+    }
+
+    #[test]
+    fn extension_list_of_conditional_formatting_rule_is_not_the_worksheet_one() {
+        let worksheet = load_worksheet(
+            r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                <dimension ref="A1:B2"/>
+                <sheetData/>
+                <conditionalFormatting sqref="A1:A2">
+                    <cfRule type="dataBar" priority="1">
+                        <dataBar><cfvo type="min"/><cfvo type="max"/><color rgb="FF638EC6"/></dataBar>
+                        <extLst>
+                            <ext uri="{B025F937-C7B1-47D3-B67F-A62EFF666E3E}"><x14:id xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main">{00000000-0000-0000-0000-000000000001}</x14:id></ext>
+                        </extLst>
+                    </cfRule>
+                </conditionalFormatting>
+                <mergeCells count="1"><mergeCell ref="B1:B2"/></mergeCells>
+            </worksheet>"#,
+        );
+
+        assert!(worksheet.extension_list.is_none());
+        assert!(worksheet.dimension.is_some());
+        assert_eq!(worksheet.merge_cells.map(|m| m.len()), Some(1));
+    }
+
+    #[test]
+    fn extension_list_of_worksheet_after_conditional_formatting_rule() {
+        let worksheet = load_worksheet(
+            r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                <sheetData/>
+                <conditionalFormatting sqref="A1:A2">
+                    <cfRule type="dataBar" priority="1">
+                        <extLst>
+                            <ext uri="{B025F937-C7B1-47D3-B67F-A62EFF666E3E}"/>
+                        </extLst>
+                    </cfRule>
+                </conditionalFormatting>
+                <extLst>
+                    <ext uri="{78C0D931-6437-407d-A8EE-F0AAD7539E65}"/>
+                    <ext uri="{05C60535-1F16-4fd2-B633-F4F36F0B64E0}"/>
+                </extLst>
+            </worksheet>"#,
+        );
+
+        let uris: Vec<String> = worksheet
+            .extension_list
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter_map(|e| e.uri)
+            .collect();
+        assert_eq!(
+            uris,
+            vec![
+                "{78C0D931-6437-407d-A8EE-F0AAD7539E65}".to_string(),
+                "{05C60535-1F16-4fd2-B633-F4F36F0B64E0}".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_views_elements_without_cells() {
+        let mut zip = worksheet_zip(
+            r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                <dimension ref="A1:C4"/>
+                <cols><col min="2" max="2" width="9" hidden="1"/></cols>
+                <sheetData>
+                    <row r="1"><c r="A1"><v>1</v></c></row>
+                    <row r="3" hidden="1"><c r="A3"><v>3</v></c></row>
+                </sheetData>
+                <conditionalFormatting sqref="A1"><cfRule type="expression" priority="1"><formula>TRUE</formula></cfRule></conditionalFormatting>
+                <customSheetViews>
+                    <customSheetView guid="{CE6681F1-E999-414D-8446-68A031534B57}" hiddenRows="1"/>
+                </customSheetViews>
+            </worksheet>"#,
+        );
+        let path = "xl/worksheets/sheet1.xml";
+
+        let worksheet = XlsxWorksheet::load_for_custom_views(&mut zip, path).unwrap();
+        let rows = worksheet.sheet_data.and_then(|d| d.rows).unwrap_or(vec![]);
+        assert_eq!(
+            rows.iter()
+                .map(|r| (r.row_index, r.hidden))
+                .collect::<Vec<_>>(),
+            vec![(Some(1), None), (Some(3), Some(true))]
+        );
+        assert!(rows.iter().all(|r| r.cells.is_none()));
+        assert_eq!(worksheet.column_infos.map(|c| c.len()), Some(1));
+        assert_eq!(worksheet.custom_sheet_views.map(|v| v.len()), Some(1));
+        assert_eq!(worksheet.dimension, Dimension::from_a1(b"A1:C4"));
+
+        assert_eq!(
+            XlsxWorksheet::load_dimension(&mut zip, path).unwrap(),
+            Dimension::from_a1(b"A1:C4")
+        );
+    }
 }
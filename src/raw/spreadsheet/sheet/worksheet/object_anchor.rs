@@ -0,0 +1,97 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::string_to_bool,
+    raw::drawing::worksheet_drawing::marker::{
+        load_from_marker, load_to_marker, XlsxFromMarker, XlsxToMarker,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.objectanchor?view=openxml-3.0.1
+///
+/// Anchor of an embedded object or a control within the sheet.
+///
+/// Example
+/// ```
+/// <anchor moveWithCells="1">
+///     <from>
+///         <xdr:col>1</xdr:col>
+///         <xdr:colOff>0</xdr:colOff>
+///         <xdr:row>1</xdr:row>
+///         <xdr:rowOff>0</xdr:rowOff>
+///     </from>
+///     <to>
+///         <xdr:col>4</xdr:col>
+///         <xdr:colOff>304800</xdr:colOff>
+///         <xdr:row>9</xdr:row>
+///         <xdr:rowOff>0</xdr:rowOff>
+///     </to>
+/// </anchor>
+/// ```
+/// anchor
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxObjectAnchor {
+    // Child Elements
+    /// from (Starting Anchor Point)
+    pub from: Option<XlsxFromMarker>,
+
+    /// to (Ending Anchor Point)
+    pub to: Option<XlsxToMarker>,
+
+    // attributes
+    /// moveWithCells (Move With Cells)
+    pub move_with_cells: Option<bool>,
+
+    /// sizeWithCells (Size With Cells)
+    pub size_with_cells: Option<bool>,
+}
+
+impl XlsxObjectAnchor {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut anchor = Self {
+            from: None,
+            to: None,
+            move_with_cells: None,
+            size_with_cells: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"moveWithCells" => anchor.move_with_cells = string_to_bool(&string_value),
+                        b"sizeWithCells" => anchor.size_with_cells = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"from" => {
+                    anchor.from = Some(load_from_marker(reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"to" => {
+                    anchor.to = Some(load_to_marker(reader)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"anchor" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(anchor)
+    }
+}
@@ -0,0 +1,277 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use super::object_anchor::XlsxObjectAnchor;
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.oleobjects?view=openxml-3.0.1
+///
+/// Embedded or linked OLE objects of the sheet.
+///
+/// Objects within `mc:AlternateContent` are read from `mc:Choice`, the `mc:Fallback` copy is skipped.
+///
+/// Example
+/// ```
+/// <oleObjects>
+///     <mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">
+///         <mc:Choice Requires="x14">
+///             <oleObject progId="Word.Document.12" shapeId="1025" r:id="rId3">
+///                 <objectPr defaultSize="0" r:id="rId4">
+///                     <anchor moveWithCells="1">
+///                         <from><xdr:col>1</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>1</xdr:row><xdr:rowOff>0</xdr:rowOff></from>
+///                         <to><xdr:col>4</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>9</xdr:row><xdr:rowOff>0</xdr:rowOff></to>
+///                     </anchor>
+///                 </objectPr>
+///             </oleObject>
+///         </mc:Choice>
+///         <mc:Fallback>
+///             <oleObject progId="Word.Document.12" shapeId="1025" r:id="rId3"/>
+///         </mc:Fallback>
+///     </mc:AlternateContent>
+/// </oleObjects>
+/// ```
+/// oleObjects
+pub type XlsxOleObjects = Vec<XlsxOleObject>;
+
+pub(crate) fn load_ole_objects(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxOleObjects> {
+    let mut objects: XlsxOleObjects = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Fallback" => {
+                let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+            }
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"oleObject" => {
+                objects.push(XlsxOleObject::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"oleObjects" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(objects)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.oleobject?view=openxml-3.0.1
+///
+/// An embedded or linked OLE object.
+///
+/// Example
+/// ```
+/// <oleObject progId="Package" dvAspect="DVASPECT_ICON" shapeId="1026" r:id="rId5">
+///     <objectPr defaultSize="0" autoPict="0" r:id="rId6">
+///         <anchor moveWithCells="1">...</anchor>
+///     </objectPr>
+/// </oleObject>
+/// ```
+/// oleObject
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxOleObject {
+    // Child Elements
+    /// objectPr (Embedded Object Properties)
+    pub object_properties: Option<XlsxObjectProperties>,
+
+    // attributes
+    /// autoLoad (Auto Load)
+    pub auto_load: Option<bool>,
+
+    /// dvAspect (Object Aspect). `DVASPECT_CONTENT` or `DVASPECT_ICON`
+    pub dv_aspect: Option<String>,
+
+    /// r:id: relationship id of the embedded part (xl/embeddings/) or of the linked file
+    pub id: Option<String>,
+
+    /// link (Link): reference to the external link of a linked object. Ex: `[1]!''''`
+    pub link: Option<String>,
+
+    /// oleUpdate (OLE Update). `OLEUPDATE_ALWAYS` or `OLEUPDATE_ONCALL`
+    pub ole_update: Option<String>,
+
+    /// progId (OLE ProgId). Ex: `Word.Document.12`, `Package`, `AcroExch.Document.DC`
+    pub prog_id: Option<String>,
+
+    /// shapeId (Shape Id): id of the legacy (VML) shape of the object
+    pub shape_id: Option<u64>,
+}
+
+impl XlsxOleObject {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut object = Self {
+            object_properties: None,
+            auto_load: None,
+            dv_aspect: None,
+            id: None,
+            link: None,
+            ole_update: None,
+            prog_id: None,
+            shape_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"autoLoad" => object.auto_load = string_to_bool(&string_value),
+                        b"dvAspect" => object.dv_aspect = Some(string_value),
+                        b"id" => object.id = Some(string_value),
+                        b"link" => object.link = Some(string_value),
+                        b"oleUpdate" => object.ole_update = Some(string_value),
+                        b"progId" => object.prog_id = Some(string_value),
+                        b"shapeId" => object.shape_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"objectPr" => {
+                    object.object_properties = Some(XlsxObjectProperties::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"oleObject" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(object)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.embeddedobjectproperties?view=openxml-3.0.1
+///
+/// Properties of an embedded object.
+///
+/// Example
+/// ```
+/// <objectPr defaultSize="0" autoPict="0" altText="Report" r:id="rId4">
+///     <anchor moveWithCells="1">...</anchor>
+/// </objectPr>
+/// ```
+/// objectPr
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxObjectProperties {
+    // Child Elements
+    /// anchor (Object Cell Anchor)
+    pub anchor: Option<XlsxObjectAnchor>,
+
+    // attributes
+    /// altText (Alternative Text)
+    pub alt_text: Option<String>,
+
+    /// autoFill (Auto Fill)
+    pub auto_fill: Option<bool>,
+
+    /// autoLine (Auto Line)
+    pub auto_line: Option<bool>,
+
+    /// autoPict (Automatic Picture Size)
+    pub auto_pict: Option<bool>,
+
+    /// dde (Dynamic Data Exchange)
+    pub dde: Option<bool>,
+
+    /// defaultSize (Default Size)
+    pub default_size: Option<bool>,
+
+    /// disabled (Disabled)
+    pub disabled: Option<bool>,
+
+    /// r:id: relationship id of the preview image of the object
+    pub id: Option<String>,
+
+    /// locked (Locked)
+    pub locked: Option<bool>,
+
+    /// macro (Macro): name of the macro run when the object is activated
+    pub r#macro: Option<String>,
+
+    /// print (Print)
+    pub print: Option<bool>,
+
+    /// uiObject (UI Object)
+    pub ui_object: Option<bool>,
+}
+
+impl XlsxObjectProperties {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            anchor: None,
+            alt_text: None,
+            auto_fill: None,
+            auto_line: None,
+            auto_pict: None,
+            dde: None,
+            default_size: None,
+            disabled: None,
+            id: None,
+            locked: None,
+            r#macro: None,
+            print: None,
+            ui_object: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"altText" => properties.alt_text = Some(string_value),
+                        b"autoFill" => properties.auto_fill = string_to_bool(&string_value),
+                        b"autoLine" => properties.auto_line = string_to_bool(&string_value),
+                        b"autoPict" => properties.auto_pict = string_to_bool(&string_value),
+                        b"dde" => properties.dde = string_to_bool(&string_value),
+                        b"defaultSize" => properties.default_size = string_to_bool(&string_value),
+                        b"disabled" => properties.disabled = string_to_bool(&string_value),
+                        b"id" => properties.id = Some(string_value),
+                        b"locked" => properties.locked = string_to_bool(&string_value),
+                        b"macro" => properties.r#macro = Some(string_value),
+                        b"print" => properties.print = string_to_bool(&string_value),
+                        b"uiObject" => properties.ui_object = string_to_bool(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"anchor" => {
+                    properties.anchor = Some(XlsxObjectAnchor::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"objectPr" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+}
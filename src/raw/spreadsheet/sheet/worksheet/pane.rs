@@ -0,0 +1,143 @@
+use anyhow::bail;
+use quick_xml::events::BytesStart;
+
+use crate::{
+    common_types::{Coordinate, Dimension},
+    helper::{string_to_float, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pane?view=openxml-3.0.1
+///
+/// Split or frozen panes of a sheet view.
+///
+/// Example:
+/// ```
+/// <pane xSplit="1" ySplit="2" topLeftCell="B3" activePane="bottomRight" state="frozen"/>
+/// ```
+/// pane (View Pane)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPane {
+    // Attributes
+    /// activePane (Active Pane)
+    ///
+    /// * bottomLeft
+    /// * bottomRight
+    /// * topLeft
+    /// * topRight
+    pub active_pane: Option<String>,
+
+    /// state (Split State)
+    ///
+    /// * frozen
+    /// * frozenSplit
+    /// * split
+    pub state: Option<String>,
+
+    /// topLeftCell (Top Left Visible Cell): top left visible cell of the bottom right pane
+    pub top_left_cell: Option<Coordinate>,
+
+    /// xSplit (Horizontal Split Position)
+    ///
+    /// Number of columns frozen, or position of the split in 1/20th of a point.
+    pub x_split: Option<f64>,
+
+    /// ySplit (Vertical Split Position)
+    ///
+    /// Number of rows frozen, or position of the split in 1/20th of a point.
+    pub y_split: Option<f64>,
+}
+
+impl XlsxPane {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut pane = Self {
+            active_pane: None,
+            state: None,
+            top_left_cell: None,
+            x_split: None,
+            y_split: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"activePane" => pane.active_pane = Some(string_value),
+                        b"state" => pane.state = Some(string_value),
+                        b"topLeftCell" => {
+                            pane.top_left_cell = Coordinate::from_a1(a.value.as_ref())
+                        }
+                        b"xSplit" => pane.x_split = string_to_float(&string_value),
+                        b"ySplit" => pane.y_split = string_to_float(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(pane)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.selection?view=openxml-3.0.1
+///
+/// Selection of a pane of a sheet view.
+///
+/// Example:
+/// ```
+/// <selection pane="bottomRight" activeCell="E13" sqref="E13:F15"/>
+/// ```
+/// selection (Selection)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSelection {
+    // Attributes
+    /// activeCell (Active Cell Location)
+    pub active_cell: Option<Coordinate>,
+
+    /// activeCellId (Active Cell Index): 0-based index of the range of `sqref` containing the active cell
+    pub active_cell_id: Option<u64>,
+
+    /// pane (Pane)
+    pub pane: Option<String>,
+
+    /// sqref (Sequence of References)
+    pub sqref: Option<Vec<Dimension>>,
+}
+
+impl XlsxSelection {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut selection = Self {
+            active_cell: None,
+            active_cell_id: None,
+            pane: None,
+            sqref: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"activeCell" => {
+                            selection.active_cell = Coordinate::from_a1(a.value.as_ref())
+                        }
+                        b"activeCellId" => {
+                            selection.active_cell_id = string_to_unsignedint(&string_value)
+                        }
+                        b"pane" => selection.pane = Some(string_value),
+                        b"sqref" => selection.sqref = Some(Dimension::from_sqref(&string_value)),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(selection)
+    }
+}
@@ -100,6 +100,38 @@ pub struct XlsxRow {
 
 impl XlsxRow {
     pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut row = Self::load_attributes(e)?;
+        let mut cells: Vec<XlsxCell> = vec![];
+
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"c" => {
+                    cells.push(XlsxCell::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"row" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file at `row`."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        if row.row_index.is_none() {
+            bail!("row of unknwon index.")
+        }
+
+        row.cells = Some(cells);
+
+        return Ok(row);
+    }
+
+    /// Load the attributes of a row, without its cells.
+    pub(crate) fn load_attributes(e: &BytesStart) -> anyhow::Result<Self> {
         let mut row = Self {
             cells: None,
             collapsed: None,
@@ -116,7 +148,6 @@ impl XlsxRow {
             thick_bottom: None,
             thick_top: None,
         };
-        let mut cells: Vec<XlsxCell> = vec![];
 
         let attributes = e.attributes();
         for a in attributes {
@@ -179,30 +210,6 @@ impl XlsxRow {
             }
         }
 
-        let mut buf: Vec<u8> = Vec::new();
-        loop {
-            buf.clear();
-
-            match reader.read_event_into(&mut buf) {
-                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
-                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
-                }
-                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"c" => {
-                    cells.push(XlsxCell::load(reader, e)?);
-                }
-                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"row" => break,
-                Ok(Event::Eof) => bail!("unexpected end of file at `row`."),
-                Err(e) => bail!(e.to_string()),
-                _ => (),
-            }
-        }
-
-        if row.row_index.is_none() {
-            bail!("row of unknwon index.")
-        }
-
-        row.cells = Some(cells);
-
         return Ok(row);
     }
 }
@@ -0,0 +1,236 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    common_types::Coordinate,
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.scenarios?view=openxml-3.0.1
+///
+/// What-if scenarios of a worksheet: each scenario replaces the values of a set of input cells.
+///
+/// Example:
+/// ```
+/// <scenarios current="0" show="0" sqref="B5">
+///     <scenario name="Best case" locked="1" count="2" user="Author" comment="Created by Author">
+///         <inputCells r="B1" val="120"/>
+///         <inputCells r="B2" val="0.1"/>
+///     </scenario>
+/// </scenarios>
+/// ```
+/// scenarios (Scenarios)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxScenarios {
+    // Child Elements
+    /// scenario (Scenario)
+    pub scenarios: Vec<XlsxScenario>,
+
+    // attributes
+    /// current (Current Scenario): 0-based index of the current scenario
+    pub current: Option<u64>,
+
+    /// show (Last Shown Scenario): 0-based index of the last shown scenario
+    pub show: Option<u64>,
+
+    /// sqref (Sequence of References): result cells of the scenario summary
+    pub sqref: Option<String>,
+}
+
+impl XlsxScenarios {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut scenarios = Self {
+            scenarios: vec![],
+            current: None,
+            show: None,
+            sqref: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"current" => scenarios.current = string_to_unsignedint(&string_value),
+                        b"show" => scenarios.show = string_to_unsignedint(&string_value),
+                        b"sqref" => scenarios.sqref = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"scenario" => {
+                    scenarios.scenarios.push(XlsxScenario::load(reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"scenarios" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(scenarios)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.scenario?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <scenario name="Best case" locked="1" count="2" user="Author" comment="Created by Author">
+///     <inputCells r="B1" val="120"/>
+///     <inputCells r="B2" val="0.1"/>
+/// </scenario>
+/// ```
+/// scenario (Scenario)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxScenario {
+    // Child Elements
+    /// inputCells (Input Cells)
+    pub input_cells: Vec<XlsxInputCells>,
+
+    // attributes
+    /// comment (Scenario Comment)
+    pub comment: Option<String>,
+
+    /// count (Changing Cell Count)
+    pub count: Option<u64>,
+
+    /// hidden (Hidden Scenario)
+    pub hidden: Option<bool>,
+
+    /// locked (Scenario Locked)
+    pub locked: Option<bool>,
+
+    /// name (Scenario Name)
+    pub name: Option<String>,
+
+    /// user (Last Modified By)
+    pub user: Option<String>,
+}
+
+impl XlsxScenario {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut scenario = Self {
+            input_cells: vec![],
+            comment: None,
+            count: None,
+            hidden: None,
+            locked: None,
+            name: None,
+            user: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"comment" => scenario.comment = Some(string_value),
+                        b"count" => scenario.count = string_to_unsignedint(&string_value),
+                        b"hidden" => scenario.hidden = string_to_bool(&string_value),
+                        b"locked" => scenario.locked = string_to_bool(&string_value),
+                        b"name" => scenario.name = Some(string_value),
+                        b"user" => scenario.user = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"inputCells" => {
+                    scenario.input_cells.push(XlsxInputCells::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"scenario" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(scenario)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.inputcells?view=openxml-3.0.1
+///
+/// A cell changed by a scenario and its value in the scenario.
+///
+/// Example:
+/// ```
+/// <inputCells r="B1" val="120"/>
+/// ```
+/// inputCells (Input Cells)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxInputCells {
+    // attributes
+    /// deleted (Deleted)
+    pub deleted: Option<bool>,
+
+    /// numFmtId (Number Format ID)
+    pub number_format_id: Option<u64>,
+
+    /// r (Reference)
+    pub reference: Option<Coordinate>,
+
+    /// undone (Undone)
+    pub undone: Option<bool>,
+
+    /// val (Value)
+    pub value: Option<String>,
+}
+
+impl XlsxInputCells {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut cells = Self {
+            deleted: None,
+            number_format_id: None,
+            reference: None,
+            undone: None,
+            value: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"deleted" => cells.deleted = string_to_bool(&string_value),
+                        b"numFmtId" => {
+                            cells.number_format_id = string_to_unsignedint(&string_value)
+                        }
+                        b"r" => cells.reference = Coordinate::from_a1(a.value.as_ref()),
+                        b"undone" => cells.undone = string_to_bool(&string_value),
+                        b"val" => cells.value = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(cells)
+    }
+}
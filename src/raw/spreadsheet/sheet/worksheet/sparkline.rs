@@ -0,0 +1,379 @@
+use anyhow::bail;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use std::io::BufRead;
+
+use crate::{
+    helper::{extract_text_contents, string_to_bool, string_to_float},
+    raw::{
+        extension_list::{handler::ExtensionHandler, XlsxExtension},
+        spreadsheet::stylesheet::color::XlsxColor,
+    },
+};
+
+/// uri of the worksheet extension containing the sparkline groups
+pub const SPARKLINE_GROUPS_EXTENSION_URI: &str = "{05C60535-1F16-4fd2-B633-F4F36F0B64E0}";
+
+/// Built-in handler decoding the sparkline groups of a worksheet extension
+pub struct SparklineGroupsExtensionHandler;
+
+impl ExtensionHandler for SparklineGroupsExtensionHandler {
+    type Output = XlsxSparklineGroups;
+
+    fn uri(&self) -> String {
+        return SPARKLINE_GROUPS_EXTENSION_URI.to_string();
+    }
+
+    fn decode(&self, extension: &XlsxExtension) -> anyhow::Result<Self::Output> {
+        return extension.with_xml_reader(|reader| {
+            let mut buf = Vec::new();
+            loop {
+                buf.clear();
+
+                match reader.read_event_into(&mut buf) {
+                    Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sparklineGroups" => {
+                        return load_sparkline_groups(reader);
+                    }
+                    Ok(Event::Eof) => return Ok(vec![]),
+                    Err(e) => bail!(e.to_string()),
+                    _ => (),
+                }
+            }
+        });
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.sparklinegroups?view=openxml-3.0.1
+///
+/// Sparkline groups of the sheet, stored within the extension list (`extLst`) of the worksheet.
+///
+/// Example
+/// ```
+/// <ext uri="{05C60535-1F16-4fd2-B633-F4F36F0B64E0}" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main">
+///     <x14:sparklineGroups xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main">
+///         <x14:sparklineGroup displayEmptyCellsAs="gap" markers="1">
+///             <x14:colorSeries theme="4" tint="-0.499984740745262"/>
+///             ...
+///             <x14:sparklines>
+///                 <x14:sparkline>
+///                     <xm:f>Sheet1!A2:E2</xm:f>
+///                     <xm:sqref>F2</xm:sqref>
+///                 </x14:sparkline>
+///             </x14:sparklines>
+///         </x14:sparklineGroup>
+///     </x14:sparklineGroups>
+/// </ext>
+/// ```
+/// sparklineGroups (Sparkline Groups)
+pub type XlsxSparklineGroups = Vec<XlsxSparklineGroup>;
+
+pub(crate) fn load_sparkline_groups(
+    reader: &mut Reader<impl BufRead>,
+) -> anyhow::Result<XlsxSparklineGroups> {
+    let mut groups: XlsxSparklineGroups = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sparklineGroup" => {
+                groups.push(XlsxSparklineGroup::load(reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sparklineGroups" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(groups)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.sparklinegroup?view=openxml-3.0.1
+///
+/// A group of sparklines sharing the same type and display settings.
+///
+/// Example
+/// ```
+/// <x14:sparklineGroup type="column" displayEmptyCellsAs="gap" high="1" low="1" negative="1">
+///     <x14:colorSeries theme="4" tint="-0.499984740745262"/>
+///     <x14:colorNegative theme="5"/>
+///     <x14:colorAxis rgb="FF000000"/>
+///     <x14:colorMarkers theme="4" tint="-0.499984740745262"/>
+///     <x14:colorFirst theme="4" tint="0.39997558519241921"/>
+///     <x14:colorLast theme="4" tint="0.39997558519241921"/>
+///     <x14:colorHigh theme="4"/>
+///     <x14:colorLow theme="4"/>
+///     <x14:sparklines>
+///         <x14:sparkline>
+///             <xm:f>Sheet1!A2:E2</xm:f>
+///             <xm:sqref>F2</xm:sqref>
+///         </x14:sparkline>
+///     </x14:sparklines>
+/// </x14:sparklineGroup>
+/// ```
+/// sparklineGroup (Sparkline Group)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSparklineGroup {
+    // Child Elements
+    /// colorAxis (Axis Color)
+    pub color_axis: Option<XlsxColor>,
+
+    /// colorFirst (First Point Color)
+    pub color_first: Option<XlsxColor>,
+
+    /// colorHigh (High Point Color)
+    pub color_high: Option<XlsxColor>,
+
+    /// colorLast (Last Point Color)
+    pub color_last: Option<XlsxColor>,
+
+    /// colorLow (Low Point Color)
+    pub color_low: Option<XlsxColor>,
+
+    /// colorMarkers (Markers Color)
+    pub color_markers: Option<XlsxColor>,
+
+    /// colorNegative (Negative Points Color)
+    pub color_negative: Option<XlsxColor>,
+
+    /// colorSeries (Series Color)
+    pub color_series: Option<XlsxColor>,
+
+    /// xm:f (Formula): range of the date axis
+    pub date_axis_range: Option<String>,
+
+    /// sparklines (Sparklines)
+    pub sparklines: Vec<XlsxSparkline>,
+
+    // attributes
+    /// dateAxis (Date Axis)
+    pub date_axis: Option<bool>,
+
+    /// displayEmptyCellsAs (Display Empty Cells As). Possible values: span, gap, zero
+    pub display_empty_cells_as: Option<String>,
+
+    /// displayHidden (Display Hidden)
+    pub display_hidden: Option<bool>,
+
+    /// displayXAxis (Display X Axis)
+    pub display_x_axis: Option<bool>,
+
+    /// first (First Point)
+    pub first: Option<bool>,
+
+    /// high (High Point)
+    pub high: Option<bool>,
+
+    /// last (Last Point)
+    pub last: Option<bool>,
+
+    /// lineWeight (Line Weight) in point
+    pub line_weight: Option<f64>,
+
+    /// low (Low Point)
+    pub low: Option<bool>,
+
+    /// manualMax (Manual Max)
+    pub manual_max: Option<f64>,
+
+    /// manualMin (Manual Min)
+    pub manual_min: Option<f64>,
+
+    /// markers (Markers)
+    pub markers: Option<bool>,
+
+    /// maxAxisType (Maximum Axis Type). Possible values: individual, group, custom
+    pub max_axis_type: Option<String>,
+
+    /// minAxisType (Minimum Axis Type). Possible values: individual, group, custom
+    pub min_axis_type: Option<String>,
+
+    /// negative (Negative Points)
+    pub negative: Option<bool>,
+
+    /// rightToLeft (Right To Left)
+    pub right_to_left: Option<bool>,
+
+    /// type (Type). Possible values: line, column, stacked
+    pub r#type: Option<String>,
+}
+
+impl XlsxSparklineGroup {
+    pub(crate) fn load(reader: &mut Reader<impl BufRead>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut group = Self {
+            color_axis: None,
+            color_first: None,
+            color_high: None,
+            color_last: None,
+            color_low: None,
+            color_markers: None,
+            color_negative: None,
+            color_series: None,
+            date_axis_range: None,
+            sparklines: vec![],
+            date_axis: None,
+            display_empty_cells_as: None,
+            display_hidden: None,
+            display_x_axis: None,
+            first: None,
+            high: None,
+            last: None,
+            line_weight: None,
+            low: None,
+            manual_max: None,
+            manual_min: None,
+            markers: None,
+            max_axis_type: None,
+            min_axis_type: None,
+            negative: None,
+            right_to_left: None,
+            r#type: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"dateAxis" => group.date_axis = string_to_bool(&string_value),
+                        b"displayEmptyCellsAs" => group.display_empty_cells_as = Some(string_value),
+                        b"displayHidden" => group.display_hidden = string_to_bool(&string_value),
+                        b"displayXAxis" => group.display_x_axis = string_to_bool(&string_value),
+                        b"first" => group.first = string_to_bool(&string_value),
+                        b"high" => group.high = string_to_bool(&string_value),
+                        b"last" => group.last = string_to_bool(&string_value),
+                        b"lineWeight" => group.line_weight = string_to_float(&string_value),
+                        b"low" => group.low = string_to_bool(&string_value),
+                        b"manualMax" => group.manual_max = string_to_float(&string_value),
+                        b"manualMin" => group.manual_min = string_to_float(&string_value),
+                        b"markers" => group.markers = string_to_bool(&string_value),
+                        b"maxAxisType" => group.max_axis_type = Some(string_value),
+                        b"minAxisType" => group.min_axis_type = Some(string_value),
+                        b"negative" => group.negative = string_to_bool(&string_value),
+                        b"rightToLeft" => group.right_to_left = string_to_bool(&string_value),
+                        b"type" => group.r#type = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => match e.local_name().as_ref() {
+                    b"colorAxis" => group.color_axis = Some(XlsxColor::load(e)?),
+                    b"colorFirst" => group.color_first = Some(XlsxColor::load(e)?),
+                    b"colorHigh" => group.color_high = Some(XlsxColor::load(e)?),
+                    b"colorLast" => group.color_last = Some(XlsxColor::load(e)?),
+                    b"colorLow" => group.color_low = Some(XlsxColor::load(e)?),
+                    b"colorMarkers" => group.color_markers = Some(XlsxColor::load(e)?),
+                    b"colorNegative" => group.color_negative = Some(XlsxColor::load(e)?),
+                    b"colorSeries" => group.color_series = Some(XlsxColor::load(e)?),
+                    b"f" => group.date_axis_range = Some(extract_text_contents(reader, b"f")?),
+                    b"sparklines" => group.sparklines = load_sparklines(reader)?,
+                    _ => {}
+                },
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sparklineGroup" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(group)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.sparklines?view=openxml-3.0.1
+///
+/// Example
+/// ```
+/// <x14:sparklines>
+///     <x14:sparkline>
+///         <xm:f>Sheet1!A2:E2</xm:f>
+///         <xm:sqref>F2</xm:sqref>
+///     </x14:sparkline>
+/// </x14:sparklines>
+/// ```
+/// sparklines (Sparklines)
+fn load_sparklines(reader: &mut Reader<impl BufRead>) -> anyhow::Result<Vec<XlsxSparkline>> {
+    let mut sparklines: Vec<XlsxSparkline> = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sparkline" => {
+                sparklines.push(XlsxSparkline::load(reader)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sparklines" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(sparklines)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.sparkline?view=openxml-3.0.1
+///
+/// A single sparkline.
+///
+/// Example
+/// ```
+/// <x14:sparkline>
+///     <xm:f>Sheet1!A2:E2</xm:f>
+///     <xm:sqref>F2</xm:sqref>
+/// </x14:sparkline>
+/// ```
+/// sparkline (Sparkline)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSparkline {
+    // Child Elements
+    /// xm:f (Formula): range of the data
+    pub formula: Option<String>,
+
+    /// xm:sqref (Sequence Of References): cell the sparkline is displayed in
+    pub sqref: Option<String>,
+}
+
+impl XlsxSparkline {
+    pub(crate) fn load(reader: &mut Reader<impl BufRead>) -> anyhow::Result<Self> {
+        let mut sparkline = Self {
+            formula: None,
+            sqref: None,
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"f" => {
+                    sparkline.formula = Some(extract_text_contents(reader, b"f")?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sqref" => {
+                    sparkline.sqref = Some(extract_text_contents(reader, b"sqref")?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"sparkline" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(sparkline)
+    }
+}
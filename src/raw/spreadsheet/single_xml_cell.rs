@@ -0,0 +1,254 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    common_types::Coordinate,
+    excel::{xml_reader, XmlReader},
+    helper::string_to_unsignedint,
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.singlexmlcells?view=openxml-3.0.1
+///
+/// Root element of a single cells part (xl/tables/tableSingleCells{}.xml): the cells of a worksheet mapped to elements of a XML map.
+///
+/// Example:
+/// ```
+/// <singleXmlCells xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+///     <singleXmlCell id="1" r="B2" connectionId="0">
+///         <xmlCellPr id="1" uniqueName="title">
+///             <xmlPr mapId="1" xpath="/ns1:report/ns1:title" xmlDataType="string"/>
+///         </xmlCellPr>
+///     </singleXmlCell>
+/// </singleXmlCells>
+/// ```
+/// singleXmlCells (Single Cells)
+pub type XlsxSingleXmlCells = Vec<XlsxSingleXmlCell>;
+
+pub(crate) fn load_single_xml_cells(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    path: &str,
+) -> anyhow::Result<XlsxSingleXmlCells> {
+    let mut cells: XlsxSingleXmlCells = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(cells);
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"singleXmlCell" => {
+                cells.push(XlsxSingleXmlCell::load(&mut reader, e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"singleXmlCells" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(cells)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.singlexmlcell?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <singleXmlCell id="1" r="B2" connectionId="0">
+///     <xmlCellPr id="1" uniqueName="title">
+///         <xmlPr mapId="1" xpath="/ns1:report/ns1:title" xmlDataType="string"/>
+///     </xmlCellPr>
+/// </singleXmlCell>
+/// ```
+/// singleXmlCell (Table Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSingleXmlCell {
+    // Child Elements
+    /// xmlCellPr (Cell Properties)
+    pub xml_cell_properties: Option<XlsxXmlCellProperties>,
+
+    // attributes
+    /// connectionId (Connection ID)
+    pub connection_id: Option<u64>,
+
+    /// id (Table Id)
+    pub id: Option<u64>,
+
+    /// r (Reference)
+    pub reference: Option<Coordinate>,
+}
+
+impl XlsxSingleXmlCell {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut cell = Self {
+            xml_cell_properties: None,
+            connection_id: None,
+            id: None,
+            reference: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"connectionId" => {
+                            cell.connection_id = string_to_unsignedint(&string_value)
+                        }
+                        b"id" => cell.id = string_to_unsignedint(&string_value),
+                        b"r" => cell.reference = Coordinate::from_a1(a.value.as_ref()),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"xmlCellPr" => {
+                    cell.xml_cell_properties = Some(XlsxXmlCellProperties::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"singleXmlCell" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(cell)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.xmlcellproperties?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <xmlCellPr id="1" uniqueName="title">
+///     <xmlPr mapId="1" xpath="/ns1:report/ns1:title" xmlDataType="string"/>
+/// </xmlCellPr>
+/// ```
+/// xmlCellPr (Cell Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxXmlCellProperties {
+    // Child Elements
+    /// xmlPr (Column XML Properties)
+    pub xml_properties: Option<XlsxXmlProperties>,
+
+    // attributes
+    /// id (Table Field Id)
+    pub id: Option<u64>,
+
+    /// uniqueName (Unique Table Name)
+    pub unique_name: Option<String>,
+}
+
+impl XlsxXmlCellProperties {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            xml_properties: None,
+            id: None,
+            unique_name: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => properties.id = string_to_unsignedint(&string_value),
+                        b"uniqueName" => properties.unique_name = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"xmlPr" => {
+                    properties.xml_properties = Some(XlsxXmlProperties::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"xmlCellPr" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(properties)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.xmlproperties?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <xmlPr mapId="1" xpath="/ns1:report/ns1:title" xmlDataType="string"/>
+/// ```
+/// xmlPr (Column XML Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxXmlProperties {
+    // attributes
+    /// mapId (XML Map Id)
+    pub map_id: Option<u64>,
+
+    /// xmlDataType (XML Data Type)
+    ///
+    /// Possible values: https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.xmldatavalues?view=openxml-3.0.1
+    pub xml_data_type: Option<String>,
+
+    /// xpath (XPath)
+    pub xml_path: Option<String>,
+}
+
+impl XlsxXmlProperties {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut properties = Self {
+            map_id: None,
+            xml_data_type: None,
+            xml_path: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"mapId" => properties.map_id = string_to_unsignedint(&string_value),
+                        b"xmlDataType" => properties.xml_data_type = Some(string_value),
+                        b"xpath" => properties.xml_path = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+}
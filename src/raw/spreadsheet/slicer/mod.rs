@@ -0,0 +1,139 @@
+pub mod slicer_cache;
+
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicers?view=openxml-3.0.1
+///
+/// Root element of the slicers part (xl/slicers/slicer{}.xml) of a worksheet.
+///
+/// Example:
+/// ```
+/// <slicers xmlns="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">
+///     <slicer name="Region" cache="Slicer_Region" caption="Region" rowHeight="241300" style="SlicerStyleLight1"/>
+///     <slicer name="Product" cache="Slicer_Product" caption="Product" columnCount="2" rowHeight="241300"/>
+/// </slicers>
+/// ```
+/// slicers (Slicers)
+pub type XlsxSlicers = Vec<XlsxSlicer>;
+
+pub(crate) fn load_slicers(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    path: &str,
+) -> anyhow::Result<XlsxSlicers> {
+    let mut slicers: XlsxSlicers = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(slicers);
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"slicer" => {
+                slicers.push(XlsxSlicer::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"slicers" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(slicers)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicer?view=openxml-3.0.1
+///
+/// A slicer displayed on the worksheet, filtering the pivot tables or the table of its slicer cache.
+///
+/// Example:
+/// ```
+/// <slicer name="Region" cache="Slicer_Region" caption="Region" rowHeight="241300" style="SlicerStyleLight1"/>
+/// ```
+/// slicer (Slicer)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSlicer {
+    // attributes
+    /// cache (Cache Name): name of the slicer cache definition
+    pub cache: Option<String>,
+
+    /// caption (Caption)
+    pub caption: Option<String>,
+
+    /// columnCount (Number of Columns)
+    pub column_count: Option<u64>,
+
+    /// level (Level): OLAP hierarchy level displayed
+    pub level: Option<u64>,
+
+    /// lockedPosition (Locked Position)
+    pub locked_position: Option<bool>,
+
+    /// name (Name): referenced by the graphic frame of the slicer in the drawing
+    pub name: Option<String>,
+
+    /// rowHeight (Row Height) in EMU
+    pub row_height: Option<u64>,
+
+    /// showCaption (Show Caption)
+    pub show_caption: Option<bool>,
+
+    /// startItem (Starting Item)
+    pub start_item: Option<u64>,
+
+    /// style (Style)
+    pub style: Option<String>,
+}
+
+impl XlsxSlicer {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut slicer = Self {
+            cache: None,
+            caption: None,
+            column_count: None,
+            level: None,
+            locked_position: None,
+            name: None,
+            row_height: None,
+            show_caption: None,
+            start_item: None,
+            style: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"cache" => slicer.cache = Some(string_value),
+                        b"caption" => slicer.caption = Some(string_value),
+                        b"columnCount" => slicer.column_count = string_to_unsignedint(&string_value),
+                        b"level" => slicer.level = string_to_unsignedint(&string_value),
+                        b"lockedPosition" => slicer.locked_position = string_to_bool(&string_value),
+                        b"name" => slicer.name = Some(string_value),
+                        b"rowHeight" => slicer.row_height = string_to_unsignedint(&string_value),
+                        b"showCaption" => slicer.show_caption = string_to_bool(&string_value),
+                        b"startItem" => slicer.start_item = string_to_unsignedint(&string_value),
+                        b"style" => slicer.style = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(slicer)
+    }
+}
@@ -0,0 +1,520 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{string_to_bool, string_to_unsignedint},
+    raw::extension_list::{
+        handler::ExtensionHandler, load_extension_list, XlsxExtension, XlsxExtensionList,
+    },
+};
+
+/// uri of the slicer cache extension containing the table source of a table slicer
+pub const TABLE_SLICER_CACHE_EXTENSION_URI: &str = "{2F2917AC-EB37-4324-AD4E-5DD8C200BD13}";
+
+/// Built-in handler decoding the table source of a slicer cache extension
+pub struct TableSlicerCacheExtensionHandler;
+
+impl ExtensionHandler for TableSlicerCacheExtensionHandler {
+    type Output = Option<XlsxTableSlicerCache>;
+
+    fn uri(&self) -> String {
+        return TABLE_SLICER_CACHE_EXTENSION_URI.to_string();
+    }
+
+    fn decode(&self, extension: &XlsxExtension) -> anyhow::Result<Self::Output> {
+        return extension.with_xml_reader(|reader| {
+            let mut buf = Vec::new();
+            loop {
+                buf.clear();
+
+                match reader.read_event_into(&mut buf) {
+                    Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tableSlicerCache" => {
+                        return Ok(Some(XlsxTableSlicerCache::load(e)?));
+                    }
+                    Ok(Event::Eof) => return Ok(None),
+                    Err(e) => bail!(e.to_string()),
+                    _ => (),
+                }
+            }
+        });
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicercachedefinition?view=openxml-3.0.1
+///
+/// Root element of the slicer cache part (xl/slicerCaches/slicerCache{}.xml).
+/// Defines the source field of the slicers sharing the cache and the selection state of its items.
+///
+/// Example:
+/// ```
+/// <slicerCacheDefinition xmlns="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main" name="Slicer_Region" sourceName="Region">
+///     <pivotTables>
+///         <pivotTable tabId="1" name="PivotTable1"/>
+///     </pivotTables>
+///     <data>
+///         <tabular pivotCacheId="1">
+///             <items count="2">
+///                 <i x="0" s="1"/>
+///                 <i x="1"/>
+///             </items>
+///         </tabular>
+///     </data>
+/// </slicerCacheDefinition>
+/// ```
+///
+/// Table slicers do not have `data`, their source is specified within the extension list:
+/// ```
+/// <slicerCacheDefinition name="Slicer_Product" sourceName="Product">
+///     <extLst>
+///         <x:ext uri="{2F2917AC-EB37-4324-AD4E-5DD8C200BD13}" xmlns:x15="http://schemas.microsoft.com/office/spreadsheetml/2010/11/main">
+///             <x15:tableSlicerCache tableId="1" column="2"/>
+///         </x:ext>
+///     </extLst>
+/// </slicerCacheDefinition>
+/// ```
+/// slicerCacheDefinition (Slicer Cache Definition)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSlicerCacheDefinition {
+    // extLst (Future Feature Data Storage Area)
+    pub extension_list: Option<XlsxExtensionList>,
+
+    // Child Elements
+    /// data (Slicer Data)
+    pub data: Option<XlsxSlicerCacheData>,
+
+    /// pivotTables (Pivot Tables)
+    pub pivot_tables: Option<XlsxSlicerCachePivotTables>,
+
+    /// x15:tableSlicerCache (Table Slicer Cache) decoded from extLst
+    pub table_slicer_cache: Option<XlsxTableSlicerCache>,
+
+    // attributes
+    /// name (Name): referenced by the `cache` of the slicers
+    pub name: Option<String>,
+
+    /// sourceName (Source Name): name of the field filtered
+    pub source_name: Option<String>,
+}
+
+impl XlsxSlicerCacheDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self {
+            extension_list: None,
+            data: None,
+            pivot_tables: None,
+            table_slicer_cache: None,
+            name: None,
+            source_name: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"slicerCacheDefinition" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => definition.name = Some(string_value),
+                                    b"sourceName" => definition.source_name = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotTables" => {
+                    definition.pivot_tables = Some(load_slicer_cache_pivot_tables(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"data" => {
+                    definition.data = Some(XlsxSlicerCacheData::load(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let extension_list = load_extension_list(&mut reader)?;
+                    definition.table_slicer_cache = match extension_list
+                        .iter()
+                        .find(|e| e.has_uri(TABLE_SLICER_CACHE_EXTENSION_URI))
+                    {
+                        Some(extension) => TableSlicerCacheExtensionHandler.decode(extension)?,
+                        None => None,
+                    };
+                    definition.extension_list = Some(extension_list);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"slicerCacheDefinition" => {
+                    break
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(definition)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicercachepivottables?view=openxml-3.0.1
+///
+/// Pivot tables filtered by a slicer or timeline cache.
+///
+/// Example:
+/// ```
+/// <pivotTables>
+///     <pivotTable tabId="1" name="PivotTable1"/>
+/// </pivotTables>
+/// ```
+/// pivotTables (Pivot Tables)
+pub type XlsxSlicerCachePivotTables = Vec<XlsxSlicerCachePivotTable>;
+
+pub(crate) fn load_slicer_cache_pivot_tables(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxSlicerCachePivotTables> {
+    let mut pivot_tables: XlsxSlicerCachePivotTables = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotTable" => {
+                pivot_tables.push(XlsxSlicerCachePivotTable::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pivotTables" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(pivot_tables)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicercachepivottable?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <pivotTable tabId="1" name="PivotTable1"/>
+/// ```
+/// pivotTable (PivotTable)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSlicerCachePivotTable {
+    // attributes
+    /// name (Name): name of the pivot table
+    pub name: Option<String>,
+
+    /// tabId (Tab Id): sheet id of the sheet containing the pivot table
+    pub tab_id: Option<u64>,
+}
+
+impl XlsxSlicerCachePivotTable {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut pivot_table = Self {
+            name: None,
+            tab_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"name" => pivot_table.name = Some(string_value),
+                        b"tabId" => pivot_table.tab_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(pivot_table)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.slicercachedata?view=openxml-3.0.1
+///
+/// Source data of a pivot table slicer cache.
+///
+/// Example:
+/// ```
+/// <data>
+///     <tabular pivotCacheId="1" sortOrder="descending" crossFilter="showItemsWithDataAtTop">
+///         <items count="2">
+///             <i x="0" s="1"/>
+///             <i x="1"/>
+///         </items>
+///     </tabular>
+/// </data>
+/// ```
+/// data (Slicer Data)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSlicerCacheData {
+    // Child Elements
+    /// olap (OLAP Slicer Cache): only the pivot cache id is read
+    pub olap_pivot_cache_id: Option<u64>,
+
+    /// tabular (Tabular Slicer Cache)
+    pub tabular: Option<XlsxTabularSlicerCache>,
+}
+
+impl XlsxSlicerCacheData {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>) -> anyhow::Result<Self> {
+        let mut data = Self {
+            olap_pivot_cache_id: None,
+            tabular: None,
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"tabular" => {
+                    data.tabular = Some(XlsxTabularSlicerCache::load(reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"olap" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"pivotCacheId" => {
+                                        data.olap_pivot_cache_id =
+                                            string_to_unsignedint(&string_value)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"data" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.tabularslicercache?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <tabular pivotCacheId="1" sortOrder="descending" crossFilter="showItemsWithDataAtTop">
+///     <items count="2">
+///         <i x="0" s="1"/>
+///         <i x="1" nd="1"/>
+///     </items>
+/// </tabular>
+/// ```
+/// tabular (Tabular Slicer Cache)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTabularSlicerCache {
+    // Child Elements
+    /// items (Tabular Slicer Cache Items)
+    pub items: Vec<XlsxTabularSlicerCacheItem>,
+
+    // attributes
+    /// crossFilter (Cross Filter). Possible values: none, showItemsWithDataAtTop, showItemsWithNoData
+    pub cross_filter: Option<String>,
+
+    /// customListSort (Custom List Sort)
+    pub custom_list_sort: Option<bool>,
+
+    /// pivotCacheId (Pivot Cache Id): `cacheId` of the pivot cache in the workbook `pivotCaches`
+    pub pivot_cache_id: Option<u64>,
+
+    /// showMissing (Show Missing)
+    pub show_missing: Option<bool>,
+
+    /// sortOrder (Sort Order). Possible values: natural, ascending, descending
+    pub sort_order: Option<String>,
+}
+
+impl XlsxTabularSlicerCache {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut tabular = Self {
+            items: vec![],
+            cross_filter: None,
+            custom_list_sort: None,
+            pivot_cache_id: None,
+            show_missing: None,
+            sort_order: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"crossFilter" => tabular.cross_filter = Some(string_value),
+                        b"customListSort" => {
+                            tabular.custom_list_sort = string_to_bool(&string_value)
+                        }
+                        b"pivotCacheId" => {
+                            tabular.pivot_cache_id = string_to_unsignedint(&string_value)
+                        }
+                        b"showMissing" => tabular.show_missing = string_to_bool(&string_value),
+                        b"sortOrder" => tabular.sort_order = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"i" => {
+                    tabular.items.push(XlsxTabularSlicerCacheItem::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"tabular" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(tabular)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2010.excel.tabularslicercacheitem?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <i x="0" s="1"/>
+/// ```
+/// i (Tabular Slicer Cache Item)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTabularSlicerCacheItem {
+    // attributes
+    /// nd (No Data): whether the item has no data in the source
+    pub no_data: Option<bool>,
+
+    /// s (Selected)
+    pub selected: Option<bool>,
+
+    /// x (Item Index): index of the shared item of the pivot cache field
+    pub index: Option<u64>,
+}
+
+impl XlsxTabularSlicerCacheItem {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut item = Self {
+            no_data: None,
+            selected: None,
+            index: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"nd" => item.no_data = string_to_bool(&string_value),
+                        b"s" => item.selected = string_to_bool(&string_value),
+                        b"x" => item.index = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(item)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.tableslicercache?view=openxml-3.0.1
+///
+/// Source of a table slicer, stored within the extension list (`extLst`) of the slicer cache definition.
+///
+/// Example:
+/// ```
+/// <x15:tableSlicerCache tableId="1" column="2" sortOrder="ascending"/>
+/// ```
+/// x15:tableSlicerCache (Table Slicer Cache)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTableSlicerCache {
+    // attributes
+    /// column (Column): id of the table column
+    pub column: Option<u64>,
+
+    /// crossFilter (Cross Filter). Possible values: none, showItemsWithDataAtTop, showItemsWithNoData
+    pub cross_filter: Option<String>,
+
+    /// customListSort (Custom List Sort)
+    pub custom_list_sort: Option<bool>,
+
+    /// sortOrder (Sort Order). Possible values: natural, ascending, descending
+    pub sort_order: Option<String>,
+
+    /// tableId (Table Id): `id` of the table
+    pub table_id: Option<u64>,
+}
+
+impl XlsxTableSlicerCache {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut cache = Self {
+            column: None,
+            cross_filter: None,
+            custom_list_sort: None,
+            sort_order: None,
+            table_id: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"column" => cache.column = string_to_unsignedint(&string_value),
+                        b"crossFilter" => cache.cross_filter = Some(string_value),
+                        b"customListSort" => cache.custom_list_sort = string_to_bool(&string_value),
+                        b"sortOrder" => cache.sort_order = Some(string_value),
+                        b"tableId" => cache.table_id = string_to_unsignedint(&string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(cache)
+    }
+}
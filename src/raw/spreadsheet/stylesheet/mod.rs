@@ -11,7 +11,10 @@ use quick_xml::events::Event;
 use std::io::{Read, Seek};
 use zip::ZipArchive;
 
-use crate::excel::xml_reader;
+use crate::{
+    excel::xml_reader,
+    raw::extension_list::{load_extension_list, XlsxExtensionList},
+};
 
 use border::{load_borders, XlsxBorder, XlsxBorders};
 use cell_style::{load_cell_styles, XlsxCellStyles};
@@ -34,7 +37,8 @@ use table_style::XlsxTableStyles;
 /// tag: styleSheet
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct XlsxStyleSheet {
-    // extLst (Future Feature Data Storage Area): Not supported
+    /// extLst (Future Feature Data Storage Area). Ex: x14 slicer styles and differential formats
+    pub extension_list: Option<XlsxExtensionList>,
 
     // children
     /// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.fills?view=openxml-3.0.1
@@ -90,9 +94,9 @@ pub struct XlsxStyleSheet {
 }
 
 impl XlsxStyleSheet {
-    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
-        let path = "xl/styles.xml";
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
         let mut style_sheet = Self {
+            extension_list: None,
             fills: None,
             borders: None,
             colors: None,
@@ -116,7 +120,7 @@ impl XlsxStyleSheet {
 
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
-                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                    style_sheet.extension_list = Some(load_extension_list(&mut reader)?);
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"fills" => {
                     let fills = load_fills(&mut reader)?;
@@ -9,6 +9,7 @@ use crate::{
     common_types::Dimension,
     excel::xml_reader,
     helper::{string_to_bool, string_to_unsignedint},
+    raw::extension_list::{load_extension_list, XlsxExtensionList},
 };
 
 use super::filter::{auto_filter::XlsxAutoFilter, sort_state::XlsxSortState};
@@ -41,7 +42,8 @@ pub mod xml_column_properties;
 /// table (Table)
 #[derive(Debug, Clone, PartialEq)]
 pub struct XlsxTable {
-    /// extLst (Future Feature Data Storage Area)	Not supported
+    /// extLst (Future Feature Data Storage Area)
+    pub extension_list: Option<XlsxExtensionList>,
 
     // Child Elements
     /// autoFilter (AutoFilter Settings)
@@ -198,6 +200,7 @@ pub struct XlsxTable {
 impl XlsxTable {
     pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
         let mut table = Self {
+            extension_list: None,
             auto_filter: None,
             sort_state: None,
             table_columns: None,
@@ -237,7 +240,7 @@ impl XlsxTable {
 
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
-                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                    table.extension_list = Some(load_extension_list(&mut reader)?);
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"table" => {
                     let attributes = e.attributes();
@@ -0,0 +1,149 @@
+pub mod timeline_cache;
+
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::xml_reader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.timelines?view=openxml-3.0.1
+///
+/// Root element of the timelines part (xl/timelines/timeline{}.xml) of a worksheet.
+///
+/// Example:
+/// ```
+/// <timelines xmlns="http://schemas.microsoft.com/office/spreadsheetml/2010/11/main" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">
+///     <timeline name="Date" cache="NativeTimeline_Date" caption="Date" level="2" selectionLevel="2" scrollPosition="2024-01-01T00:00:00" style="TimeSlicerStyleLight1"/>
+/// </timelines>
+/// ```
+/// timelines (Timelines)
+pub type XlsxTimelines = Vec<XlsxTimeline>;
+
+pub(crate) fn load_timelines(
+    zip: &mut ZipArchive<impl Read + Seek>,
+    path: &str,
+) -> anyhow::Result<XlsxTimelines> {
+    let mut timelines: XlsxTimelines = vec![];
+
+    let Some(mut reader) = xml_reader(zip, path) else {
+        return Ok(timelines);
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"timeline" => {
+                timelines.push(XlsxTimeline::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"timelines" => break,
+            Ok(Event::Eof) => break,
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(timelines)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.timeline?view=openxml-3.0.1
+///
+/// A timeline displayed on the worksheet, filtering the pivot tables of its timeline cache by a date range.
+///
+/// Example:
+/// ```
+/// <timeline name="Date" cache="NativeTimeline_Date" caption="Date" level="2" selectionLevel="2" scrollPosition="2024-01-01T00:00:00"/>
+/// ```
+/// timeline (Timeline)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTimeline {
+    // attributes
+    /// cache (Cache Name): name of the timeline cache definition
+    pub cache: Option<String>,
+
+    /// caption (Caption)
+    pub caption: Option<String>,
+
+    /// level (Level): time level displayed. 0: years, 1: quarters, 2: months, 3: days
+    pub level: Option<u64>,
+
+    /// name (Name): referenced by the graphic frame of the timeline in the drawing
+    pub name: Option<String>,
+
+    /// scrollPosition (Scroll Position): first date displayed
+    pub scroll_position: Option<String>,
+
+    /// selectionLevel (Selection Level): time level of the selection
+    pub selection_level: Option<u64>,
+
+    /// showHeader (Show Header)
+    pub show_header: Option<bool>,
+
+    /// showHorizontalScrollbar (Show Horizontal Scrollbar)
+    pub show_horizontal_scrollbar: Option<bool>,
+
+    /// showSelectionLabel (Show Selection Label)
+    pub show_selection_label: Option<bool>,
+
+    /// showTimeLevel (Show Time Level)
+    pub show_time_level: Option<bool>,
+
+    /// style (Style)
+    pub style: Option<String>,
+}
+
+impl XlsxTimeline {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut timeline = Self {
+            cache: None,
+            caption: None,
+            level: None,
+            name: None,
+            scroll_position: None,
+            selection_level: None,
+            show_header: None,
+            show_horizontal_scrollbar: None,
+            show_selection_label: None,
+            show_time_level: None,
+            style: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"cache" => timeline.cache = Some(string_value),
+                        b"caption" => timeline.caption = Some(string_value),
+                        b"level" => timeline.level = string_to_unsignedint(&string_value),
+                        b"name" => timeline.name = Some(string_value),
+                        b"scrollPosition" => timeline.scroll_position = Some(string_value),
+                        b"selectionLevel" => {
+                            timeline.selection_level = string_to_unsignedint(&string_value)
+                        }
+                        b"showHeader" => timeline.show_header = string_to_bool(&string_value),
+                        b"showHorizontalScrollbar" => {
+                            timeline.show_horizontal_scrollbar = string_to_bool(&string_value)
+                        }
+                        b"showSelectionLabel" => {
+                            timeline.show_selection_label = string_to_bool(&string_value)
+                        }
+                        b"showTimeLevel" => timeline.show_time_level = string_to_bool(&string_value),
+                        b"style" => timeline.style = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(timeline)
+    }
+}
@@ -0,0 +1,265 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::{
+    excel::{xml_reader, XmlReader},
+    helper::{string_to_bool, string_to_unsignedint},
+    raw::spreadsheet::slicer::slicer_cache::{
+        load_slicer_cache_pivot_tables, XlsxSlicerCachePivotTables,
+    },
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.timelinecachedefinition?view=openxml-3.0.1
+///
+/// Root element of the timeline cache part (xl/timelineCaches/timelineCache{}.xml).
+/// Defines the date field filtered by the timelines sharing the cache and the selected date range.
+///
+/// Example:
+/// ```
+/// <timelineCacheDefinition xmlns="http://schemas.microsoft.com/office/spreadsheetml/2010/11/main" name="NativeTimeline_Date" sourceName="Date">
+///     <pivotTables>
+///         <pivotTable tabId="1" name="PivotTable1"/>
+///     </pivotTables>
+///     <state minimalRefreshVersion="6" lastRefreshVersion="6" pivotCacheId="1" filterType="dateBetween">
+///         <selection startDate="2024-01-01T00:00:00" endDate="2024-03-31T00:00:00"/>
+///         <bounds startDate="2023-01-01T00:00:00" endDate="2025-01-01T00:00:00"/>
+///     </state>
+/// </timelineCacheDefinition>
+/// ```
+/// timelineCacheDefinition (Timeline Cache Definition)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTimelineCacheDefinition {
+    // Child Elements
+    /// pivotTables (Pivot Tables)
+    pub pivot_tables: Option<XlsxSlicerCachePivotTables>,
+
+    /// state (Timeline State)
+    pub state: Option<XlsxTimelineState>,
+
+    // attributes
+    /// name (Name): referenced by the `cache` of the timelines
+    pub name: Option<String>,
+
+    /// sourceName (Source Name): name of the date field filtered
+    pub source_name: Option<String>,
+}
+
+impl XlsxTimelineCacheDefinition {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut definition = Self {
+            pivot_tables: None,
+            state: None,
+            name: None,
+            source_name: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(definition);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e))
+                    if e.local_name().as_ref() == b"timelineCacheDefinition" =>
+                {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value = String::from_utf8(a.value.to_vec())?;
+                                match a.key.local_name().as_ref() {
+                                    b"name" => definition.name = Some(string_value),
+                                    b"sourceName" => definition.source_name = Some(string_value),
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotTables" => {
+                    definition.pivot_tables = Some(load_slicer_cache_pivot_tables(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"state" => {
+                    definition.state = Some(XlsxTimelineState::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"timelineCacheDefinition" => {
+                    break
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(definition)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.timelinestate?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <state minimalRefreshVersion="6" lastRefreshVersion="6" pivotCacheId="1" filterType="dateBetween">
+///     <selection startDate="2024-01-01T00:00:00" endDate="2024-03-31T00:00:00"/>
+///     <bounds startDate="2023-01-01T00:00:00" endDate="2025-01-01T00:00:00"/>
+/// </state>
+/// ```
+/// state (Timeline State)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTimelineState {
+    // Child Elements
+    /// bounds (Timeline Range): range of the dates available
+    pub bounds: Option<XlsxTimelineRange>,
+
+    /// selection (Timeline Range): range of the dates selected
+    pub selection: Option<XlsxTimelineRange>,
+
+    // attributes
+    /// filterId (Filter Id): id of the pivot filter applied
+    pub filter_id: Option<u64>,
+
+    /// filterPivotName (Filter Pivot Name): name of the pivot table holding the filter
+    pub filter_pivot_name: Option<String>,
+
+    /// filterTabId (Filter Tab Id): sheet id of the pivot table holding the filter
+    pub filter_tab_id: Option<u64>,
+
+    /// filterType (Filter Type). Ex: unknown, dateBetween
+    pub filter_type: Option<String>,
+
+    /// lastRefreshVersion (Last Refresh Version)
+    pub last_refresh_version: Option<u64>,
+
+    /// minimalRefreshVersion (Minimal Refresh Version)
+    pub minimal_refresh_version: Option<u64>,
+
+    /// pivotCacheId (Pivot Cache Id): `cacheId` of the pivot cache in the workbook `pivotCaches`
+    pub pivot_cache_id: Option<u64>,
+
+    /// singleRangeFilterState (Single Range Filter State)
+    pub single_range_filter_state: Option<bool>,
+}
+
+impl XlsxTimelineState {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut state = Self {
+            bounds: None,
+            selection: None,
+            filter_id: None,
+            filter_pivot_name: None,
+            filter_tab_id: None,
+            filter_type: None,
+            last_refresh_version: None,
+            minimal_refresh_version: None,
+            pivot_cache_id: None,
+            single_range_filter_state: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"filterId" => state.filter_id = string_to_unsignedint(&string_value),
+                        b"filterPivotName" => state.filter_pivot_name = Some(string_value),
+                        b"filterTabId" => state.filter_tab_id = string_to_unsignedint(&string_value),
+                        b"filterType" => state.filter_type = Some(string_value),
+                        b"lastRefreshVersion" => {
+                            state.last_refresh_version = string_to_unsignedint(&string_value)
+                        }
+                        b"minimalRefreshVersion" => {
+                            state.minimal_refresh_version = string_to_unsignedint(&string_value)
+                        }
+                        b"pivotCacheId" => {
+                            state.pivot_cache_id = string_to_unsignedint(&string_value)
+                        }
+                        b"singleRangeFilterState" => {
+                            state.single_range_filter_state = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"selection" => {
+                    state.selection = Some(XlsxTimelineRange::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"bounds" => {
+                    state.bounds = Some(XlsxTimelineRange::load(e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"state" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.office2013.excel.timelinerange?view=openxml-3.0.1
+///
+/// Example:
+/// ```
+/// <selection startDate="2024-01-01T00:00:00" endDate="2024-03-31T00:00:00"/>
+/// ```
+/// selection, bounds (Timeline Range)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxTimelineRange {
+    // attributes
+    /// endDate (End Date)
+    pub end_date: Option<String>,
+
+    /// startDate (Start Date)
+    pub start_date: Option<String>,
+}
+
+impl XlsxTimelineRange {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut range = Self {
+            end_date: None,
+            start_date: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"endDate" => range.end_date = Some(string_value),
+                        b"startDate" => range.start_date = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(range)
+    }
+}
@@ -0,0 +1,123 @@
+use anyhow::bail;
+
+/// https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-ovba/4742b896-b32b-4eb0-8372-fbf01e3c65fd
+///
+/// Decompress a CompressedContainer (MS-OVBA 2.4.1): a signature byte (0x01) followed by chunks of at most 4096 decompressed bytes.
+pub(crate) fn decompress(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    if data.first() != Some(&0x01) {
+        bail!("Invalid compressed container signature.")
+    }
+
+    let mut decompressed: Vec<u8> = vec![];
+    let mut position: usize = 1;
+
+    while position + 2 <= data.len() {
+        let header = u16::from_le_bytes([data[position], data[position + 1]]);
+        // CompressedChunkSize: size of the chunk including the header, minus 3
+        let chunk_size = (header & 0x0FFF) as usize + 3;
+        let is_compressed = header & 0x8000 != 0;
+        let chunk_end = (position + chunk_size).min(data.len());
+        position += 2;
+
+        if !is_compressed {
+            // raw chunk: always 4096 bytes, except for a truncated last chunk
+            let end = (position + 4096).min(data.len());
+            decompressed.extend_from_slice(&data[position..end]);
+            position = end;
+            continue;
+        }
+
+        let chunk_start = decompressed.len();
+        while position < chunk_end {
+            let flags = data[position];
+            position += 1;
+
+            for bit in 0..8 {
+                if position >= chunk_end {
+                    break;
+                }
+
+                // literal token
+                if flags & (1 << bit) == 0 {
+                    decompressed.push(data[position]);
+                    position += 1;
+                    continue;
+                }
+
+                // copy token
+                if position + 2 > chunk_end {
+                    bail!("Copy token exceeds the compressed chunk.")
+                }
+                let token = u16::from_le_bytes([data[position], data[position + 1]]);
+                position += 2;
+
+                let difference = decompressed.len() - chunk_start;
+                let bit_count = copy_token_bit_count(difference);
+                let length_mask: u16 = 0xFFFF >> bit_count;
+                let length = (token & length_mask) as usize + 3;
+                let offset = (token >> (16 - bit_count)) as usize + 1;
+                if offset > difference {
+                    bail!("Copy token offset is out of the decompressed chunk.")
+                }
+
+                let source = decompressed.len() - offset;
+                for index in 0..length {
+                    decompressed.push(decompressed[source + index]);
+                }
+            }
+        }
+        position = chunk_end;
+    }
+
+    return Ok(decompressed);
+}
+
+/// number of bits used for the offset of a copy token: the smallest integer ≥ 4 such that 2^bit_count ≥ `difference`
+///
+/// * difference: number of bytes already decompressed in the current chunk
+fn copy_token_bit_count(difference: usize) -> u32 {
+    let mut bit_count: u32 = 4;
+    while (1usize << bit_count) < difference {
+        bit_count += 1;
+    }
+    return bit_count.min(12);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// MS-OVBA 3.2.1 No Compression Example
+    #[test]
+    fn decompress_literals_only() {
+        let compressed: Vec<u8> = vec![
+            0x01, 0x19, 0xB0, 0x00, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x00, 0x69,
+            0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x00, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+            0x2E,
+        ];
+        assert_eq!(
+            decompress(&compressed).unwrap(),
+            b"abcdefghijklmnopqrstuv.".to_vec()
+        );
+    }
+
+    /// MS-OVBA 3.2.2 Normal Compression Example
+    #[test]
+    fn decompress_copy_tokens() {
+        let compressed: Vec<u8> = vec![
+            0x01, 0x2F, 0xB0, 0x00, 0x23, 0x61, 0x61, 0x61, 0x62, 0x63, 0x64, 0x65, 0x82, 0x66,
+            0x00, 0x70, 0x61, 0x67, 0x68, 0x69, 0x6A, 0x01, 0x38, 0x08, 0x61, 0x6B, 0x6C, 0x00,
+            0x30, 0x6D, 0x6E, 0x6F, 0x70, 0x06, 0x71, 0x02, 0x70, 0x04, 0x10, 0x72, 0x73, 0x74,
+            0x75, 0x76, 0x10, 0x77, 0x78, 0x79, 0x7A, 0x00, 0x3C,
+        ];
+        assert_eq!(
+            decompress(&compressed).unwrap(),
+            b"#aaabcdefaaaaghijaaaaaklaaamnopqaaaaaaaaaaaarstuvwxyzaaa".to_vec()
+        );
+    }
+
+    #[test]
+    fn decompress_invalid_signature() {
+        assert!(decompress(&[0x00, 0x19, 0xB0]).is_err());
+    }
+}
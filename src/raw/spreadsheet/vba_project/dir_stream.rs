@@ -0,0 +1,206 @@
+use anyhow::bail;
+
+use super::decode_code_page;
+
+/// https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-ovba/672ac6da-3ae9-4d2a-8e80-6a5e8a4c06c8
+///
+/// Project information and module records parsed from the decompressed `VBA/dir` stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVbaDirStream {
+    /// PROJECTCODEPAGE: code page of the MBCS strings of the project. Ex: `1252`
+    pub code_page: u16,
+
+    /// PROJECTNAME
+    pub name: Option<String>,
+
+    /// PROJECTDOCSTRING: description of the project
+    pub doc_string: Option<String>,
+
+    /// PROJECTCONSTANTS: conditional compilation constants. Ex: `DEBUG = 1`
+    pub constants: Option<String>,
+
+    /// Names of the referenced libraries and projects. Ex: `stdole`, `Office`
+    pub references: Vec<String>,
+
+    /// MODULE records
+    pub modules: Vec<XlsxVbaModuleRecord>,
+}
+
+/// https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-ovba/ba0d7e8c-7a40-4d2b-a2a1-06e4a0a6b4c6
+///
+/// A MODULE record of the `dir` stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVbaModuleRecord {
+    /// MODULENAME, or MODULENAMEUNICODE if present
+    pub name: String,
+
+    /// MODULESTREAMNAME: name of the stream in the `VBA` storage holding the module
+    pub stream_name: String,
+
+    /// MODULEDOCSTRING
+    pub doc_string: Option<String>,
+
+    /// MODULEOFFSET: offset of the compressed source code within the module stream
+    pub text_offset: u32,
+
+    /// MODULETYPE. true: procedural module (0x0021), false: document, class or designer module (0x0022)
+    pub procedural: bool,
+
+    /// MODULEREADONLY
+    pub read_only: bool,
+
+    /// MODULEPRIVATE
+    pub private: bool,
+}
+
+impl XlsxVbaModuleRecord {
+    fn new() -> Self {
+        Self {
+            name: String::new(),
+            stream_name: String::new(),
+            doc_string: None,
+            text_offset: 0,
+            procedural: true,
+            read_only: false,
+            private: false,
+        }
+    }
+}
+
+impl XlsxVbaDirStream {
+    /// * data: decompressed `dir` stream
+    pub(crate) fn load(data: &[u8]) -> anyhow::Result<Self> {
+        let mut dir = Self {
+            code_page: 1252,
+            name: None,
+            doc_string: None,
+            constants: None,
+            references: vec![],
+            modules: vec![],
+        };
+
+        let mut module: Option<XlsxVbaModuleRecord> = None;
+        let mut position: usize = 0;
+
+        while position + 2 <= data.len() {
+            let id = u16::from_le_bytes([data[position], data[position + 1]]);
+            position += 2;
+
+            // PROJECTVERSION: the Reserved field (4) is followed by VersionMajor (4) and VersionMinor (2)
+            if id == 0x0009 {
+                position += 10;
+                continue;
+            }
+
+            if position + 4 > data.len() {
+                bail!("Unexpected end of the dir stream.")
+            }
+            let size = u32::from_le_bytes([
+                data[position],
+                data[position + 1],
+                data[position + 2],
+                data[position + 3],
+            ]) as usize;
+            position += 4;
+            if position + size > data.len() {
+                bail!("Record 0x{:04X} exceeds the dir stream.", id)
+            }
+            let record = &data[position..position + size];
+            position += size;
+
+            match id {
+                // PROJECTCODEPAGE
+                0x0003 if size >= 2 => dir.code_page = u16::from_le_bytes([record[0], record[1]]),
+                // PROJECTNAME
+                0x0004 => dir.name = Some(decode_code_page(record, dir.code_page)),
+                // PROJECTDOCSTRING (unicode)
+                0x0040 => dir.doc_string = Some(decode_utf16(record)),
+                // PROJECTCONSTANTS (unicode)
+                0x003C => dir.constants = Some(decode_utf16(record)),
+                // REFERENCENAME (unicode)
+                0x003E => dir.references.push(decode_utf16(record)),
+                // MODULENAME: starts a new module
+                0x0019 => {
+                    if let Some(m) = module.take() {
+                        dir.modules.push(m);
+                    }
+                    let mut new_module = XlsxVbaModuleRecord::new();
+                    new_module.name = decode_code_page(record, dir.code_page);
+                    module = Some(new_module);
+                }
+                // MODULENAMEUNICODE
+                0x0047 => {
+                    if let Some(m) = module.as_mut() {
+                        m.name = decode_utf16(record);
+                    }
+                }
+                // MODULESTREAMNAME
+                0x001A => {
+                    if let Some(m) = module.as_mut() {
+                        m.stream_name = decode_code_page(record, dir.code_page);
+                    }
+                }
+                // MODULESTREAMNAME (unicode)
+                0x0032 => {
+                    if let Some(m) = module.as_mut() {
+                        m.stream_name = decode_utf16(record);
+                    }
+                }
+                // MODULEDOCSTRING (unicode)
+                0x0048 => {
+                    if let Some(m) = module.as_mut() {
+                        m.doc_string = Some(decode_utf16(record)).filter(|s| !s.is_empty());
+                    }
+                }
+                // MODULEOFFSET
+                0x0031 if size >= 4 => {
+                    if let Some(m) = module.as_mut() {
+                        m.text_offset =
+                            u32::from_le_bytes([record[0], record[1], record[2], record[3]]);
+                    }
+                }
+                // MODULETYPE
+                0x0021 | 0x0022 => {
+                    if let Some(m) = module.as_mut() {
+                        m.procedural = id == 0x0021;
+                    }
+                }
+                // MODULEREADONLY
+                0x0025 => {
+                    if let Some(m) = module.as_mut() {
+                        m.read_only = true;
+                    }
+                }
+                // MODULEPRIVATE
+                0x0028 => {
+                    if let Some(m) = module.as_mut() {
+                        m.private = true;
+                    }
+                }
+                // Module Terminator
+                0x002B => {
+                    if let Some(m) = module.take() {
+                        dir.modules.push(m);
+                    }
+                }
+                // dir stream Terminator
+                0x0010 => break,
+                _ => {}
+            }
+        }
+
+        if let Some(m) = module.take() {
+            dir.modules.push(m);
+        }
+
+        return Ok(dir);
+    }
+}
+
+fn decode_utf16(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    return String::from_utf16_lossy(&units);
+}
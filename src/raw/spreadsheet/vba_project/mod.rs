@@ -0,0 +1,148 @@
+pub mod compression;
+pub mod dir_stream;
+
+use anyhow::{bail, Context};
+use cfb::CompoundFile;
+use encoding_rs::Encoding;
+use std::io::{Cursor, Read};
+
+use compression::decompress;
+use dir_stream::{XlsxVbaDirStream, XlsxVbaModuleRecord};
+
+/// https://learn.microsoft.com/en-us/openspecs/office_file_formats/ms-ovba/575462ba-bf67-4190-9fac-c275523c75fc
+///
+/// VBA project parsed from xl/vbaProject.bin: a Compound File Binary container with
+/// - `PROJECT` stream: properties of the project, including the kind of each module (`Module=`, `Document=`, `Class=`, `BaseClass=`)
+/// - `VBA/dir` stream (compressed): project information and module records
+/// - `VBA/{module stream}` streams: compressed source code of each module
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVbaProject {
+    /// Project information and module records of the `dir` stream
+    pub dir: XlsxVbaDirStream,
+
+    /// Modules with their source code, in the order of the `dir` stream
+    pub modules: Vec<XlsxVbaModule>,
+}
+
+/// A module of the VBA project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxVbaModule {
+    /// MODULE record of the `dir` stream
+    pub record: XlsxVbaModuleRecord,
+
+    /// Kind of the module in the `PROJECT` stream. Ex: `Module`, `Document`, `Class`, `BaseClass`
+    pub project_kind: Option<String>,
+
+    /// Decompressed source code, including `Attribute` lines
+    pub source: String,
+}
+
+impl XlsxVbaProject {
+    /// * bytes: content of the vbaProject.bin part
+    pub(crate) fn load(bytes: Vec<u8>) -> anyhow::Result<Self> {
+        let mut compound_file = CompoundFile::open(Cursor::new(bytes))?;
+
+        let dir_bytes = read_stream(&mut compound_file, "/VBA/dir")?;
+        let dir = XlsxVbaDirStream::load(&decompress(&dir_bytes)?)?;
+
+        let project_kinds: Vec<(String, String)> = match read_stream(&mut compound_file, "/PROJECT")
+        {
+            Ok(bytes) => load_project_stream(&bytes, dir.code_page),
+            Err(_) => vec![],
+        };
+
+        let mut modules: Vec<XlsxVbaModule> = vec![];
+        for record in dir.modules.iter() {
+            let stream = read_stream(&mut compound_file, &format!("/VBA/{}", record.stream_name))
+                .with_context(|| {
+                format!("Module stream `{}` does not exist.", record.stream_name)
+            })?;
+            let offset = (record.text_offset as usize).min(stream.len());
+            let source = decode_code_page(&decompress(&stream[offset..])?, dir.code_page);
+
+            let project_kind = project_kinds
+                .iter()
+                .find(|(_, name)| name.eq_ignore_ascii_case(&record.name))
+                .map(|(kind, _)| kind.clone());
+
+            modules.push(XlsxVbaModule {
+                record: record.clone(),
+                project_kind,
+                source,
+            });
+        }
+
+        return Ok(Self { dir, modules });
+    }
+}
+
+fn read_stream(
+    compound_file: &mut CompoundFile<Cursor<Vec<u8>>>,
+    path: &str,
+) -> anyhow::Result<Vec<u8>> {
+    let Ok(mut stream) = compound_file.open_stream(path) else {
+        bail!("Stream `{}` does not exist.", path)
+    };
+    let mut bytes: Vec<u8> = vec![];
+    stream.read_to_end(&mut bytes)?;
+    return Ok(bytes);
+}
+
+/// (kind, module name) of the module lines of the `PROJECT` stream.
+///
+/// Example
+/// ```
+/// ID="{5DD90D76-4904-47A2-AF0D-D69B4673604E}"
+/// Document=ThisWorkbook/&H00000000
+/// Document=Sheet1/&H00000000
+/// Module=Module1
+/// Class=Class1
+/// BaseClass=UserForm1
+/// ```
+fn load_project_stream(bytes: &[u8], code_page: u16) -> Vec<(String, String)> {
+    let text = decode_code_page(bytes, code_page);
+    let mut kinds: Vec<(String, String)> = vec![];
+    for line in text.lines() {
+        // the host extender and workspace sections follow the module lines
+        if line.starts_with('[') {
+            break;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if !matches!(key, "Module" | "Document" | "Class" | "BaseClass") {
+            continue;
+        }
+        // Document=Sheet1/&H00000000
+        let name = value.split('/').next().unwrap_or(value).trim();
+        kinds.push((key.to_string(), name.to_string()));
+    }
+    return kinds;
+}
+
+/// Decode MBCS bytes of the project with the code page of the project (PROJECTCODEPAGE).
+pub(crate) fn decode_code_page(bytes: &[u8], code_page: u16) -> String {
+    let encoding: &'static Encoding = match code_page {
+        874 => encoding_rs::WINDOWS_874,
+        932 => encoding_rs::SHIFT_JIS,
+        936 => encoding_rs::GBK,
+        949 => encoding_rs::EUC_KR,
+        950 => encoding_rs::BIG5,
+        1200 => encoding_rs::UTF_16LE,
+        1250 => encoding_rs::WINDOWS_1250,
+        1251 => encoding_rs::WINDOWS_1251,
+        1253 => encoding_rs::WINDOWS_1253,
+        1254 => encoding_rs::WINDOWS_1254,
+        1255 => encoding_rs::WINDOWS_1255,
+        1256 => encoding_rs::WINDOWS_1256,
+        1257 => encoding_rs::WINDOWS_1257,
+        1258 => encoding_rs::WINDOWS_1258,
+        10000 => encoding_rs::MACINTOSH,
+        20866 => encoding_rs::KOI8_R,
+        28591 | 1252 => encoding_rs::WINDOWS_1252,
+        65001 => encoding_rs::UTF_8,
+        _ => encoding_rs::WINDOWS_1252,
+    };
+    let (text, _, _) = encoding.decode(bytes);
+    return text.into_owned();
+}
@@ -0,0 +1,85 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::excel::XmlReader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalreferences?view=openxml-3.0.1
+///
+/// This element enumerates external link parts of the workbook.
+///
+/// The position (1-based) of a reference is the index used in formulas. Ex: `[1]Prices!B2`
+///
+/// Example
+/// ```
+/// <externalReferences>
+///     <externalReference r:id="rId4"/>
+/// </externalReferences>
+/// ```
+/// externalReferences (External References)
+pub type XlsxExternalReferences = Vec<XlsxExternalReference>;
+
+pub(crate) fn load_external_references(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxExternalReferences> {
+    let mut references: XlsxExternalReferences = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"externalReference" => {
+                references.push(XlsxExternalReference::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"externalReferences" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(references)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.externalreference?view=openxml-3.0.1
+///
+/// This element references an external link part in the workbook relationships.
+///
+/// Example
+/// ```
+/// <externalReference r:id="rId4"/>
+/// ```
+/// externalReference (External Reference)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxExternalReference {
+    // attributes
+    /// id (Relationship Id)
+    ///
+    /// Relationship id of the external link part.
+    pub id: Option<String>,
+}
+
+impl XlsxExternalReference {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut reference = Self { id: None };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"id" => reference.id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(reference)
+    }
+}
@@ -4,17 +4,24 @@ use anyhow::bail;
 use calculation_properties::XlsxCalculationProperties;
 use custom_workbook_view::{load_custom_bookviews, XlsxCustomWorkbookViews};
 use defined_name::{load_defined_names, XlsxDefinedNames};
+use external_reference::{load_external_references, XlsxExternalReferences};
+use pivot_cache::{load_pivot_caches, XlsxPivotCaches};
 use quick_xml::events::Event;
 use sheet::{load_sheets, XlsxSheets};
 use workbook_properties::XlsxWorkbookProperties;
 use workbook_view::{load_bookviews, XlsxWorkbookViews};
 use zip::ZipArchive;
 
-use crate::excel::xml_reader;
+use crate::{
+    excel::xml_reader,
+    raw::extension_list::{load_extension_list, XlsxExtensionList},
+};
 
 pub mod calculation_properties;
 pub mod custom_workbook_view;
 pub mod defined_name;
+pub mod external_reference;
+pub mod pivot_cache;
 pub mod sheet;
 pub mod workbook_properties;
 pub mod workbook_view;
@@ -38,6 +45,9 @@ pub mod workbook_view;
 ///         <sheet name="Sheet5" sheetId="3" r:id="rId3"/>
 ///         <sheet name="Chart1" sheetId="4" type="chartsheet" r:id="rId4"/>
 ///     </sheets>
+///     <externalReferences>
+///         <externalReference r:id="rId9"/>
+///     </externalReferences>
 ///     <definedNames>
 ///         <definedName name="MyDefinedName">Sheet3!$A$1:$C$12</definedName>
 ///     </definedNames>
@@ -58,7 +68,8 @@ pub mod workbook_view;
 /// xml tag: workbook
 #[derive(Debug, Clone, PartialEq)]
 pub struct XlsxWorkbook {
-    // extLst (Future Feature Data Storage Area)	Not supported
+    // extLst (Future Feature Data Storage Area)
+    pub extension_list: Option<XlsxExtensionList>,
 
     // Child Elements
     // bookViews (Workbook Views)	§18.2.1
@@ -73,12 +84,14 @@ pub struct XlsxWorkbook {
     // definedNames (Defined Names)	§18.2.6
     pub defined_names: Option<XlsxDefinedNames>,
     // externalReferences (External References)	§18.2.9
+    pub external_references: Option<XlsxExternalReferences>,
     // fileRecoveryPr (File Recovery Properties)	§18.2.11
     // fileSharing (File Sharing)	§18.2.12
     // fileVersion (File Version)	§18.2.13
     // functionGroups (Function Groups)	§18.2.15
     // oleSize (Embedded Object Size)	§18.2.16
     // pivotCaches (PivotCaches)	§18.2.18
+    pub pivot_caches: Option<XlsxPivotCaches>,
     // sheets (Sheets)	§18.2.20
     pub sheets: Option<XlsxSheets>,
     // smartTagPr (Smart Tag Properties)	§18.2.21
@@ -91,13 +104,15 @@ pub struct XlsxWorkbook {
 }
 
 impl XlsxWorkbook {
-    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>) -> anyhow::Result<Self> {
-        let path = "xl/workbook.xml";
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
         let mut workbook = Self {
+            extension_list: None,
             bookviews: None,
             calculation_propertis: None,
             custom_workbook_views: None,
             defined_names: None,
+            external_references: None,
+            pivot_caches: None,
             sheets: None,
             workbook_properties: None,
         };
@@ -113,7 +128,7 @@ impl XlsxWorkbook {
 
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"extLst" => {
-                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                    workbook.extension_list = Some(load_extension_list(&mut reader)?);
                 }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"bookViews" => {
                     workbook.bookviews = Some(load_bookviews(&mut reader)?);
@@ -127,6 +142,12 @@ impl XlsxWorkbook {
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"definedNames" => {
                     workbook.defined_names = Some(load_defined_names(&mut reader)?);
                 }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"externalReferences" => {
+                    workbook.external_references = Some(load_external_references(&mut reader)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotCaches" => {
+                    workbook.pivot_caches = Some(load_pivot_caches(&mut reader)?);
+                }
                 Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"sheets" => {
                     workbook.sheets = Some(load_sheets(&mut reader)?);
                 }
@@ -0,0 +1,92 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{excel::XmlReader, helper::string_to_unsignedint};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotcaches?view=openxml-3.0.1
+///
+/// This element enumerates pivot cache definition parts used by pivot tables and formulas in this workbook.
+///
+/// Example
+/// ```
+/// <pivotCaches>
+///     <pivotCache cacheId="0" r:id="rId8"/>
+/// </pivotCaches>
+/// ```
+/// pivotCaches (PivotCaches)
+pub type XlsxPivotCaches = Vec<XlsxPivotCache>;
+
+pub(crate) fn load_pivot_caches(
+    reader: &mut XmlReader<impl Read>,
+) -> anyhow::Result<XlsxPivotCaches> {
+    let mut caches: XlsxPivotCaches = vec![];
+
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"pivotCache" => {
+                caches.push(XlsxPivotCache::load(e)?);
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"pivotCaches" => break,
+            Ok(Event::Eof) => bail!("unexpected end of file."),
+            Err(e) => bail!(e.to_string()),
+            _ => (),
+        }
+    }
+
+    Ok(caches)
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.pivotcache?view=openxml-3.0.1
+///
+/// This element references a pivot cache definition part in the workbook relationships.
+///
+/// Example
+/// ```
+/// <pivotCache cacheId="0" r:id="rId8"/>
+/// ```
+/// pivotCache (PivotCache)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxPivotCache {
+    // attributes
+    /// cacheId (PivotCache Id)
+    ///
+    /// Id referenced by `cacheId` of pivot table definitions.
+    pub cache_id: Option<u64>,
+
+    /// id (Relationship Id)
+    ///
+    /// Relationship id of the pivot cache definition part.
+    pub id: Option<String>,
+}
+
+impl XlsxPivotCache {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let attributes = e.attributes();
+        let mut cache = Self {
+            cache_id: None,
+            id: None,
+        };
+
+        for a in attributes {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"cacheId" => cache.cache_id = string_to_unsignedint(&string_value),
+                        b"id" => cache.id = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(cache)
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::bail;
+use quick_xml::events::{BytesStart, Event};
+use std::io::Read;
+
+use crate::{
+    excel::XmlReader,
+    helper::{string_to_bool, string_to_unsignedint},
+};
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.map?view=openxml-3.0.1
+///
+/// A XML map: the elements of a schema mapped to tables and cells of the worksheets.
+///
+/// Example:
+/// ```
+/// <Map ID="1" Name="report_Map" RootElement="report" SchemaID="Schema1" ShowImportExportValidationErrors="false" AutoFit="true" Append="false" PreserveSortAFLayout="true" PreserveFormat="true"/>
+/// ```
+/// Map (XML Mapping Properties)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxXmlMap {
+    // Child Elements
+    /// DataBinding (XML Mapping)
+    pub data_binding: Option<XlsxDataBinding>,
+
+    // attributes
+    /// Append (Append Data to Table)
+    pub append: Option<bool>,
+
+    /// AutoFit (AutoFit Table on Refresh)
+    pub auto_fit: Option<bool>,
+
+    /// ID (XML Mapping ID): referenced by the mapped columns and cells (`mapId`)
+    pub id: Option<u64>,
+
+    /// Name (XML Mapping Name)
+    pub name: Option<String>,
+
+    /// PreserveFormat (Preserve Cell Formatting)
+    pub preserve_format: Option<bool>,
+
+    /// PreserveSortAFLayout (Preserve AutoFilter State)
+    pub preserve_sort_auto_filter_layout: Option<bool>,
+
+    /// RootElement (Root Element Name)
+    pub root_element: Option<String>,
+
+    /// SchemaID (Schema Name): `ID` of the schema
+    pub schema_id: Option<String>,
+
+    /// ShowImportExportValidationErrors (Show Validation Errors)
+    pub show_import_export_validation_errors: Option<bool>,
+}
+
+impl XlsxXmlMap {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut map = Self {
+            data_binding: None,
+            append: None,
+            auto_fit: None,
+            id: None,
+            name: None,
+            preserve_format: None,
+            preserve_sort_auto_filter_layout: None,
+            root_element: None,
+            schema_id: None,
+            show_import_export_validation_errors: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"Append" => map.append = string_to_bool(&string_value),
+                        b"AutoFit" => map.auto_fit = string_to_bool(&string_value),
+                        b"ID" => map.id = string_to_unsignedint(&string_value),
+                        b"Name" => map.name = Some(string_value),
+                        b"PreserveFormat" => map.preserve_format = string_to_bool(&string_value),
+                        b"PreserveSortAFLayout" => {
+                            map.preserve_sort_auto_filter_layout = string_to_bool(&string_value)
+                        }
+                        b"RootElement" => map.root_element = Some(string_value),
+                        b"SchemaID" => map.schema_id = Some(string_value),
+                        b"ShowImportExportValidationErrors" => {
+                            map.show_import_export_validation_errors = string_to_bool(&string_value)
+                        }
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"DataBinding" => {
+                    map.data_binding = Some(XlsxDataBinding::load(e)?);
+                    let _ = reader.read_to_end_into(e.to_end().to_owned().name(), &mut Vec::new());
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"Map" => break,
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(map)
+    }
+}
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.databinding?view=openxml-3.0.1
+///
+/// Source of the xml data imported with a map.
+///
+/// Example:
+/// ```
+/// <DataBinding FileBinding="true" FileBindingName="C:\data\report.xml" DataBindingLoadMode="1"/>
+/// ```
+/// DataBinding (XML Mapping)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxDataBinding {
+    // attributes
+    /// ConnectionID (Connection ID): id of the connection in xl/connections.xml
+    pub connection_id: Option<u64>,
+
+    /// DataBindingLoadMode (Binding Load Mode)
+    pub data_binding_load_mode: Option<u64>,
+
+    /// DataBindingName (DataBindingName)
+    pub data_binding_name: Option<String>,
+
+    /// FileBinding (FileBinding)
+    pub file_binding: Option<bool>,
+
+    /// FileBindingName (FileBindingName): path of the xml file
+    pub file_binding_name: Option<String>,
+}
+
+impl XlsxDataBinding {
+    pub(crate) fn load(e: &BytesStart) -> anyhow::Result<Self> {
+        let mut binding = Self {
+            connection_id: None,
+            data_binding_load_mode: None,
+            data_binding_name: None,
+            file_binding: None,
+            file_binding_name: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ConnectionID" => {
+                            binding.connection_id = string_to_unsignedint(&string_value)
+                        }
+                        b"DataBindingLoadMode" => {
+                            binding.data_binding_load_mode = string_to_unsignedint(&string_value)
+                        }
+                        b"DataBindingName" => binding.data_binding_name = Some(string_value),
+                        b"FileBinding" => binding.file_binding = string_to_bool(&string_value),
+                        b"FileBindingName" => binding.file_binding_name = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        Ok(binding)
+    }
+}
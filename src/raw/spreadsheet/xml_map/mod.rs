@@ -0,0 +1,93 @@
+use anyhow::bail;
+use map::XlsxXmlMap;
+use quick_xml::{escape::unescape, events::Event};
+use schema::XlsxXmlSchema;
+use std::io::{Read, Seek};
+use zip::ZipArchive;
+
+use crate::excel::xml_reader;
+
+pub mod map;
+pub mod schema;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.mapinfo?view=openxml-3.0.1
+///
+/// Root element of the XML maps part (xl/xmlMaps.xml): the schemas and the maps of the workbook.
+///
+/// Example:
+/// ```
+/// <MapInfo xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" SelectionNamespaces="xmlns:ns1='http://example.com/report'">
+///     <Schema ID="Schema1" Namespace="http://example.com/report">
+///         <xsd:schema xmlns:xsd="http://www.w3.org/2001/XMLSchema">...</xsd:schema>
+///     </Schema>
+///     <Map ID="1" Name="report_Map" RootElement="report" SchemaID="Schema1" ShowImportExportValidationErrors="false" AutoFit="true" Append="false" PreserveSortAFLayout="true" PreserveFormat="true"/>
+/// </MapInfo>
+/// ```
+/// MapInfo (XML Mapping)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxMapInfo {
+    // Child Elements
+    /// Map (XML Mapping Properties)
+    pub maps: Vec<XlsxXmlMap>,
+
+    /// Schema (XML Schema)
+    pub schemas: Vec<XlsxXmlSchema>,
+
+    // attributes
+    /// SelectionNamespaces (Prefix Mappings for XPath Expressions).
+    /// Ex: `xmlns:ns1='http://example.com/report'`
+    pub selection_namespaces: Option<String>,
+}
+
+impl XlsxMapInfo {
+    pub(crate) fn load(zip: &mut ZipArchive<impl Read + Seek>, path: &str) -> anyhow::Result<Self> {
+        let mut map_info = Self {
+            maps: vec![],
+            schemas: vec![],
+            selection_namespaces: None,
+        };
+
+        let Some(mut reader) = xml_reader(zip, path) else {
+            return Ok(map_info);
+        };
+
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"MapInfo" => {
+                    for a in e.attributes() {
+                        match a {
+                            Ok(a) => {
+                                let string_value =
+                                    unescape(&String::from_utf8(a.value.to_vec())?)?.to_string();
+                                match a.key.local_name().as_ref() {
+                                    b"SelectionNamespaces" => {
+                                        map_info.selection_namespaces = Some(string_value)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(error) => {
+                                bail!(error.to_string())
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Schema" => {
+                    map_info.schemas.push(XlsxXmlSchema::load(&mut reader, e)?);
+                }
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"Map" => {
+                    map_info.maps.push(XlsxXmlMap::load(&mut reader, e)?);
+                }
+                Ok(Event::End(ref e)) if e.local_name().as_ref() == b"MapInfo" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => bail!(e.to_string()),
+                _ => (),
+            }
+        }
+
+        Ok(map_info)
+    }
+}
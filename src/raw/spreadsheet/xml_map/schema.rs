@@ -0,0 +1,96 @@
+use anyhow::bail;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Writer,
+};
+use std::io::Read;
+
+use crate::excel::XmlReader;
+
+/// https://learn.microsoft.com/en-us/dotnet/api/documentformat.openxml.spreadsheet.schema?view=openxml-3.0.1
+///
+/// A XML schema used by the XML maps, kept as raw xml.
+///
+/// Example:
+/// ```
+/// <Schema ID="Schema1" Namespace="http://example.com/report">
+///     <xsd:schema xmlns:xsd="http://www.w3.org/2001/XMLSchema" xmlns="http://example.com/report" targetNamespace="http://example.com/report">
+///         <xsd:element name="report">...</xsd:element>
+///     </xsd:schema>
+/// </Schema>
+/// ```
+/// Schema (Schema)
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxXmlSchema {
+    /// xml of the children of the element (usually a `xsd:schema` element)
+    pub xml: String,
+
+    // attributes
+    /// ID (Schema ID): referenced by the maps (`SchemaID`)
+    pub id: Option<String>,
+
+    /// Namespace (Schema Namespace)
+    pub namespace: Option<String>,
+
+    /// SchemaLanguage (Schema Language)
+    pub schema_language: Option<String>,
+
+    /// SchemaRef (Schema Reference)
+    pub schema_ref: Option<String>,
+}
+
+impl XlsxXmlSchema {
+    pub(crate) fn load(reader: &mut XmlReader<impl Read>, e: &BytesStart) -> anyhow::Result<Self> {
+        let mut schema = Self {
+            xml: String::new(),
+            id: None,
+            namespace: None,
+            schema_language: None,
+            schema_ref: None,
+        };
+
+        for a in e.attributes() {
+            match a {
+                Ok(a) => {
+                    let string_value = String::from_utf8(a.value.to_vec())?;
+                    match a.key.local_name().as_ref() {
+                        b"ID" => schema.id = Some(string_value),
+                        b"Namespace" => schema.namespace = Some(string_value),
+                        b"SchemaLanguage" => schema.schema_language = Some(string_value),
+                        b"SchemaRef" => schema.schema_ref = Some(string_value),
+                        _ => {}
+                    }
+                }
+                Err(error) => {
+                    bail!(error.to_string())
+                }
+            }
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        let mut depth: u64 = 1;
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+
+            let event = match reader.read_event_into(&mut buf) {
+                Ok(Event::Eof) => bail!("unexpected end of file."),
+                Err(e) => bail!(e.to_string()),
+                Ok(event) => event,
+            };
+            match event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+            writer.write_event(event)?;
+        }
+
+        schema.xml = String::from_utf8(writer.into_inner())?;
+
+        Ok(schema)
+    }
+}